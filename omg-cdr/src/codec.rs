@@ -0,0 +1,216 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Encodings of IDL types sharing their Rust type with another IDL type, i.e. `wchar`
+//! and `char` are both mapped to `char`. Generated code selects the codec of members
+//! whose IDL type needs one. The codecs of wide characters also encode the `u16` and
+//! `WString` of the UTF-16 mapping.
+use crate::{encode::decode_array, CdrError, CdrParameter, CdrReader, CdrWriter};
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use core::marker::PhantomData;
+#[cfg(feature = "alloc")]
+use omg_idl_rt::WString;
+
+/// Replaces wide characters outside of the Basic Multilingual Plane
+const REPLACEMENT: u16 = 0xFFFD;
+
+/// Encoding of a value of type `V`
+pub trait Codec<V> {
+    /// See [`CdrEncode::PRIMITIVE`](crate::CdrEncode::PRIMITIVE)
+    const PRIMITIVE: bool = false;
+
+    fn encode(value: &V, writer: &mut CdrWriter);
+    fn decode(reader: &mut CdrReader<'_>) -> Result<V, CdrError>;
+}
+
+/// `wchar` as a single UTF-16 code unit
+pub struct WideChar;
+
+impl Codec<char> for WideChar {
+    const PRIMITIVE: bool = true;
+
+    fn encode(value: &char, writer: &mut CdrWriter) {
+        crate::CdrEncode::encode(&u16::try_from(*value).unwrap_or(REPLACEMENT), writer);
+    }
+
+    fn decode(reader: &mut CdrReader<'_>) -> Result<char, CdrError> {
+        let unit = <u16 as crate::CdrDecode>::decode(reader)?;
+        char::from_u32(unit.into()).ok_or(CdrError::InvalidChar(unit.into()))
+    }
+}
+
+impl Codec<u16> for WideChar {
+    const PRIMITIVE: bool = true;
+
+    fn encode(value: &u16, writer: &mut CdrWriter) {
+        crate::CdrEncode::encode(value, writer);
+    }
+
+    fn decode(reader: &mut CdrReader<'_>) -> Result<u16, CdrError> {
+        crate::CdrDecode::decode(reader)
+    }
+}
+
+/// `wstring` as UTF-16 code units, prefixed by their length in bytes and without
+/// terminating NUL
+pub struct WideString;
+
+#[cfg(feature = "alloc")]
+impl Codec<String> for WideString {
+    fn encode(value: &String, writer: &mut CdrWriter) {
+        writer.write_length(2 * value.encode_utf16().count());
+        for unit in value.encode_utf16() {
+            crate::CdrEncode::encode(&unit, writer);
+        }
+    }
+
+    fn decode(reader: &mut CdrReader<'_>) -> Result<String, CdrError> {
+        let length = reader.read_length()? / 2;
+        let units = (0..length)
+            .map(|_| <u16 as crate::CdrDecode>::decode(reader))
+            .collect::<Result<Vec<u16>, CdrError>>()?;
+        char::decode_utf16(units)
+            .map(|unit| unit.map_err(|err| CdrError::InvalidChar(err.unpaired_surrogate().into())))
+            .collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Codec<WString> for WideString {
+    fn encode(value: &WString, writer: &mut CdrWriter) {
+        crate::CdrEncode::encode(value, writer);
+    }
+
+    fn decode(reader: &mut CdrReader<'_>) -> Result<WString, CdrError> {
+        crate::CdrDecode::decode(reader)
+    }
+}
+
+/// Sequence of elements encoded by `C`
+pub struct Sequence<C>(PhantomData<C>);
+
+#[cfg(feature = "alloc")]
+impl<V, C: Codec<V>> Codec<Vec<V>> for Sequence<C> {
+    fn encode(value: &Vec<V>, writer: &mut CdrWriter) {
+        writer.write_elements(C::PRIMITIVE, |writer| {
+            writer.write_length(value.len());
+            for element in value {
+                C::encode(element, writer);
+            }
+        });
+    }
+
+    fn decode(reader: &mut CdrReader<'_>) -> Result<Vec<V>, CdrError> {
+        reader.read_elements(C::PRIMITIVE, |reader| {
+            let length = reader.read_length()?;
+            let mut elements = Vec::with_capacity(length.min(reader.remaining()));
+            for _ in 0..length {
+                elements.push(C::decode(reader)?);
+            }
+            Ok(elements)
+        })
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, C: Codec<V>, const N: usize> Codec<heapless::Vec<V, N>> for Sequence<C> {
+    fn encode(value: &heapless::Vec<V, N>, writer: &mut CdrWriter) {
+        writer.write_elements(C::PRIMITIVE, |writer| {
+            writer.write_length(value.len());
+            for element in value {
+                C::encode(element, writer);
+            }
+        });
+    }
+
+    fn decode(reader: &mut CdrReader<'_>) -> Result<heapless::Vec<V, N>, CdrError> {
+        reader.read_elements(C::PRIMITIVE, |reader| {
+            let length = reader.read_length()?;
+            if length > N {
+                return Err(CdrError::BoundExceeded(length));
+            }
+            let mut elements = heapless::Vec::new();
+            for _ in 0..length {
+                // the length is checked, pushing cannot fail
+                let _ = elements.push(C::decode(reader)?);
+            }
+            Ok(elements)
+        })
+    }
+}
+
+/// Array of elements encoded by `C`
+pub struct Array<C>(PhantomData<C>);
+
+impl<V, C: Codec<V>, const N: usize> Codec<[V; N]> for Array<C> {
+    const PRIMITIVE: bool = C::PRIMITIVE;
+
+    fn encode(value: &[V; N], writer: &mut CdrWriter) {
+        writer.write_elements(C::PRIMITIVE, |writer| {
+            for element in value {
+                C::encode(element, writer);
+            }
+        });
+    }
+
+    fn decode(reader: &mut CdrReader<'_>) -> Result<[V; N], CdrError> {
+        reader.read_elements(C::PRIMITIVE, |reader| decode_array(|| C::decode(reader)))
+    }
+}
+
+/// Sequence of RTPS parameters, without length and terminated by `PID_SENTINEL`.
+/// Parameters of unknown IDs are skipped.
+pub struct ParameterSequence;
+
+#[cfg(feature = "alloc")]
+impl<P: CdrParameter> Codec<Vec<P>> for ParameterSequence {
+    fn encode(value: &Vec<P>, writer: &mut CdrWriter) {
+        for parameter in value {
+            parameter.encode_parameter(writer);
+        }
+        writer.write_pl_sentinel();
+    }
+
+    fn decode(reader: &mut CdrReader<'_>) -> Result<Vec<P>, CdrError> {
+        let mut parameters = Vec::new();
+        reader.read_pl_parameters(|id, reader| {
+            Ok(match P::decode_parameter(id, reader)? {
+                Some(parameter) => {
+                    parameters.push(parameter);
+                    true
+                }
+                None => false,
+            })
+        })?;
+        Ok(parameters)
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<P: CdrParameter, const N: usize> Codec<heapless::Vec<P, N>> for ParameterSequence {
+    fn encode(value: &heapless::Vec<P, N>, writer: &mut CdrWriter) {
+        for parameter in value {
+            parameter.encode_parameter(writer);
+        }
+        writer.write_pl_sentinel();
+    }
+
+    fn decode(reader: &mut CdrReader<'_>) -> Result<heapless::Vec<P, N>, CdrError> {
+        let mut parameters = heapless::Vec::new();
+        reader.read_pl_parameters(|id, reader| {
+            Ok(match P::decode_parameter(id, reader)? {
+                Some(parameter) => {
+                    parameters
+                        .push(parameter)
+                        .map_err(|_| CdrError::BoundExceeded(N + 1))?;
+                    true
+                }
+                None => false,
+            })
+        })?;
+        Ok(parameters)
+    }
+}
@@ -0,0 +1,400 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+use crate::{CdrError, CdrReader, CdrVersion, CdrView, CdrWriter, Endianness};
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use core::mem::size_of;
+#[cfg(feature = "alloc")]
+use omg_idl_rt::{ByteBuf, WString};
+use omg_idl_rt::{Extensible, Latin1, F128};
+
+/// Types which can be serialized as CDR
+pub trait CdrEncode {
+    /// Serialized as a single primitive value, sequences and arrays of primitives have no
+    /// DHEADER in XCDR2
+    const PRIMITIVE: bool = false;
+
+    fn encode(&self, writer: &mut CdrWriter);
+}
+
+/// Types which can be deserialized from CDR
+pub trait CdrDecode: Sized {
+    /// Serialized as a single primitive value, sequences and arrays of primitives have no
+    /// DHEADER in XCDR2
+    const PRIMITIVE: bool = false;
+
+    fn decode(reader: &mut CdrReader<'_>) -> Result<Self, CdrError>;
+}
+
+/// Generated for structs, serializes the members according to the extensibility kind.
+/// Derived structs serialize the members of their base first, as part of their own.
+pub trait CdrStruct: Extensible + Default {
+    /// Serialize all members, mutable structs write each behind a member header
+    fn encode_members(&self, writer: &mut CdrWriter);
+
+    /// Deserialize all members in order, mutable structs decode members by ID instead
+    fn decode_members(reader: &mut CdrReader<'_>) -> Result<Self, CdrError> {
+        let mut value = Self::default();
+        reader.read_members(|id, reader| value.decode_member(id, reader))?;
+        Ok(value)
+    }
+
+    /// Deserialize the member `id` of a mutable struct, `false` if the ID is unknown
+    fn decode_member(&mut self, id: u32, reader: &mut CdrReader<'_>) -> Result<bool, CdrError> {
+        let _ = (id, reader);
+        Ok(false)
+    }
+}
+
+/// Generated for structs encoded as RTPS parameter list (PL_CDR), every member is a
+/// parameter with the ID assigned by the configuration. Derived structs serialize the
+/// parameters of their base first.
+pub trait CdrParameterList: Default {
+    /// Serialize all members as parameters, absent `@optional` members are left out
+    fn encode_parameters(&self, writer: &mut CdrWriter);
+
+    /// Deserialize the parameter `id`, `false` if the ID is unknown
+    fn decode_parameter(&mut self, id: u16, reader: &mut CdrReader<'_>) -> Result<bool, CdrError>;
+}
+
+/// Generated for unions encoded as a single RTPS parameter, the discriminator is the
+/// parameter ID
+pub trait CdrParameter: Sized {
+    /// Serialize the selected member as parameter
+    fn encode_parameter(&self, writer: &mut CdrWriter);
+
+    /// Deserialize the value of the parameter `id`, `None` if the ID selects no member
+    fn decode_parameter(id: u16, reader: &mut CdrReader<'_>) -> Result<Option<Self>, CdrError>;
+}
+
+/// Generated for structs, unions and enums: the maximum serialized size of the type,
+/// computed by the code generator with all alignment padding. Sizes exclude the
+/// encapsulation header and, for XCDR2, the padding `to_bytes` appends.
+pub trait CdrBounds {
+    /// `false` if an unbounded string or sequence is reachable from the type, or the
+    /// type contains itself
+    const IS_BOUNDED: bool;
+
+    /// Maximum size of the XCDR1 encoding, `None` if unbounded
+    const MAX_XCDR1_SIZE: Option<usize>;
+
+    /// Maximum size of the XCDR2 encoding, `None` if unbounded
+    const MAX_XCDR2_SIZE: Option<usize>;
+
+    /// Maximum size of the encoding in the given version
+    fn max_serialized_size(version: CdrVersion) -> Option<usize> {
+        match version {
+            CdrVersion::Xcdr1 => Self::MAX_XCDR1_SIZE,
+            CdrVersion::Xcdr2 => Self::MAX_XCDR2_SIZE,
+        }
+    }
+}
+
+macro_rules! impl_cdr_number {
+    ($($typ:ty),*) => {
+        $(
+            impl CdrEncode for $typ {
+                const PRIMITIVE: bool = true;
+
+                fn encode(&self, writer: &mut CdrWriter) {
+                    writer.align(size_of::<$typ>());
+                    match writer.endianness() {
+                        Endianness::Big => writer.write_bytes(&self.to_be_bytes()),
+                        Endianness::Little => writer.write_bytes(&self.to_le_bytes()),
+                    }
+                }
+            }
+
+            impl CdrDecode for $typ {
+                const PRIMITIVE: bool = true;
+
+                fn decode(reader: &mut CdrReader<'_>) -> Result<Self, CdrError> {
+                    reader.align(size_of::<$typ>())?;
+                    let mut bytes = [0; size_of::<$typ>()];
+                    bytes.copy_from_slice(reader.read_bytes(size_of::<$typ>())?);
+                    Ok(match reader.endianness() {
+                        Endianness::Big => <$typ>::from_be_bytes(bytes),
+                        Endianness::Little => <$typ>::from_le_bytes(bytes),
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_cdr_number!(u8, i8, i16, u16, i32, u32, i64, u64, u128, f32, f64);
+
+#[cfg(feature = "f128")]
+impl_cdr_number!(f128);
+
+/// `long double` as the bytes of the binary128 number, aligned like a 16 byte primitive
+impl CdrEncode for F128 {
+    const PRIMITIVE: bool = true;
+
+    fn encode(&self, writer: &mut CdrWriter) {
+        self.to_bits().encode(writer);
+    }
+}
+
+impl CdrDecode for F128 {
+    const PRIMITIVE: bool = true;
+
+    fn decode(reader: &mut CdrReader<'_>) -> Result<Self, CdrError> {
+        u128::decode(reader).map(F128::from_bits)
+    }
+}
+
+impl CdrEncode for bool {
+    const PRIMITIVE: bool = true;
+
+    fn encode(&self, writer: &mut CdrWriter) {
+        writer.write_bytes(&[u8::from(*self)]);
+    }
+}
+
+impl CdrDecode for bool {
+    const PRIMITIVE: bool = true;
+
+    fn decode(reader: &mut CdrReader<'_>) -> Result<Self, CdrError> {
+        match u8::decode(reader)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            value => Err(CdrError::InvalidBool(value)),
+        }
+    }
+}
+
+/// IDL characters are 8 bit, characters outside of Latin-1 are replaced by '?'
+impl CdrEncode for char {
+    const PRIMITIVE: bool = true;
+
+    fn encode(&self, writer: &mut CdrWriter) {
+        writer.write_bytes(&[u8::try_from(*self).unwrap_or(b'?')]);
+    }
+}
+
+impl CdrDecode for char {
+    const PRIMITIVE: bool = true;
+
+    fn decode(reader: &mut CdrReader<'_>) -> Result<Self, CdrError> {
+        u8::decode(reader).map(char::from)
+    }
+}
+
+impl CdrEncode for Latin1 {
+    const PRIMITIVE: bool = true;
+
+    fn encode(&self, writer: &mut CdrWriter) {
+        writer.write_bytes(&[self.0]);
+    }
+}
+
+impl CdrDecode for Latin1 {
+    const PRIMITIVE: bool = true;
+
+    fn decode(reader: &mut CdrReader<'_>) -> Result<Self, CdrError> {
+        u8::decode(reader).map(Latin1)
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Strings are prefixed by their length including the terminating NUL
+impl CdrEncode for String {
+    fn encode(&self, writer: &mut CdrWriter) {
+        self.as_str().encode(writer);
+    }
+}
+
+impl CdrEncode for str {
+    fn encode(&self, writer: &mut CdrWriter) {
+        writer.write_length(self.len() + 1);
+        writer.write_bytes(self.as_bytes());
+        writer.write_bytes(&[0]);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl CdrDecode for String {
+    fn decode(reader: &mut CdrReader<'_>) -> Result<Self, CdrError> {
+        <&str>::view(reader).map(String::from)
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Wide strings are UTF-16 code units, prefixed by their length in bytes and without
+/// terminating NUL
+impl CdrEncode for WString {
+    fn encode(&self, writer: &mut CdrWriter) {
+        writer.write_length(2 * self.len());
+        for unit in self.as_units() {
+            unit.encode(writer);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl CdrDecode for WString {
+    fn decode(reader: &mut CdrReader<'_>) -> Result<Self, CdrError> {
+        let length = reader.read_length()? / 2;
+        let mut units = Vec::with_capacity(length.min(reader.remaining() / 2));
+        for _ in 0..length {
+            units.push(u16::decode(reader)?);
+        }
+        Ok(WString::from_units(units))
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Sequences are prefixed by their number of elements
+impl<T: CdrEncode> CdrEncode for Vec<T> {
+    fn encode(&self, writer: &mut CdrWriter) {
+        writer.write_elements(T::PRIMITIVE, |writer| {
+            writer.write_length(self.len());
+            for element in self {
+                element.encode(writer);
+            }
+        });
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: CdrDecode> CdrDecode for Vec<T> {
+    fn decode(reader: &mut CdrReader<'_>) -> Result<Self, CdrError> {
+        reader.read_elements(T::PRIMITIVE, |reader| {
+            let length = reader.read_length()?;
+            // every element takes at least one byte, a corrupt length must not allocate
+            let mut elements = Vec::with_capacity(length.min(reader.remaining()));
+            for _ in 0..length {
+                elements.push(T::decode(reader)?);
+            }
+            Ok(elements)
+        })
+    }
+}
+
+/// Octet sequences of the byte mappings are encoded like `Vec<u8>`, copied at once
+#[cfg(any(feature = "alloc", feature = "bytes"))]
+fn encode_octets(octets: &[u8], writer: &mut CdrWriter) {
+    writer.write_length(octets.len());
+    writer.write_bytes(octets);
+}
+
+#[cfg(any(feature = "alloc", feature = "bytes"))]
+fn decode_octets<'a>(reader: &mut CdrReader<'a>) -> Result<&'a [u8], CdrError> {
+    let length = reader.read_length()?;
+    reader.read_bytes(length)
+}
+
+#[cfg(feature = "alloc")]
+impl CdrEncode for ByteBuf {
+    fn encode(&self, writer: &mut CdrWriter) {
+        encode_octets(self, writer);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl CdrDecode for ByteBuf {
+    fn decode(reader: &mut CdrReader<'_>) -> Result<Self, CdrError> {
+        decode_octets(reader).map(ByteBuf::from)
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl CdrEncode for bytes::Bytes {
+    fn encode(&self, writer: &mut CdrWriter) {
+        encode_octets(self, writer);
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl CdrDecode for bytes::Bytes {
+    fn decode(reader: &mut CdrReader<'_>) -> Result<Self, CdrError> {
+        decode_octets(reader).map(bytes::Bytes::copy_from_slice)
+    }
+}
+
+/// Bounded strings of the heapless mapping, encoded like `String`
+#[cfg(feature = "heapless")]
+impl<const N: usize> CdrEncode for heapless::String<N> {
+    fn encode(&self, writer: &mut CdrWriter) {
+        self.as_str().encode(writer);
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> CdrDecode for heapless::String<N> {
+    fn decode(reader: &mut CdrReader<'_>) -> Result<Self, CdrError> {
+        let value = <&str>::view(reader)?;
+        heapless::String::try_from(value).map_err(|_| CdrError::BoundExceeded(value.len()))
+    }
+}
+
+/// Bounded sequences of the heapless mapping, encoded like `Vec`
+#[cfg(feature = "heapless")]
+impl<T: CdrEncode, const N: usize> CdrEncode for heapless::Vec<T, N> {
+    fn encode(&self, writer: &mut CdrWriter) {
+        writer.write_elements(T::PRIMITIVE, |writer| {
+            writer.write_length(self.len());
+            for element in self {
+                element.encode(writer);
+            }
+        });
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<T: CdrDecode, const N: usize> CdrDecode for heapless::Vec<T, N> {
+    fn decode(reader: &mut CdrReader<'_>) -> Result<Self, CdrError> {
+        reader.read_elements(T::PRIMITIVE, |reader| {
+            let length = reader.read_length()?;
+            if length > N {
+                return Err(CdrError::BoundExceeded(length));
+            }
+            let mut elements = heapless::Vec::new();
+            for _ in 0..length {
+                // the length is checked, pushing cannot fail
+                let _ = elements.push(T::decode(reader)?);
+            }
+            Ok(elements)
+        })
+    }
+}
+
+/// Arrays have a fixed number of elements, no length is serialized. Multi-dimensional
+/// arrays of primitives are a single array of primitives.
+impl<T: CdrEncode, const N: usize> CdrEncode for [T; N] {
+    const PRIMITIVE: bool = T::PRIMITIVE;
+
+    fn encode(&self, writer: &mut CdrWriter) {
+        writer.write_elements(T::PRIMITIVE, |writer| {
+            for element in self {
+                element.encode(writer);
+            }
+        });
+    }
+}
+
+impl<T: CdrDecode, const N: usize> CdrDecode for [T; N] {
+    const PRIMITIVE: bool = T::PRIMITIVE;
+
+    fn decode(reader: &mut CdrReader<'_>) -> Result<Self, CdrError> {
+        reader.read_elements(T::PRIMITIVE, |reader| decode_array(|| T::decode(reader)))
+    }
+}
+
+/// Decode the `N` elements of an array in place, without collecting them first
+pub(crate) fn decode_array<T, const N: usize>(
+    mut decode: impl FnMut() -> Result<T, CdrError>,
+) -> Result<[T; N], CdrError> {
+    let mut error = None;
+    let elements: [Option<T>; N] = core::array::from_fn(|_| match error {
+        Some(_) => None,
+        None => decode().map_err(|err| error = Some(err)).ok(),
+    });
+    match error {
+        Some(error) => Err(error),
+        None => Ok(elements.map(|element| element.expect("every element is decoded"))),
+    }
+}
@@ -0,0 +1,55 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+use core::fmt;
+
+/// Reasons deserializing CDR data or serializing it into a slice fails
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CdrError {
+    /// The data ends before the value is complete
+    UnexpectedEnd,
+    /// A boolean is neither 0 nor 1
+    InvalidBool(u8),
+    /// A wide character is a lone UTF-16 surrogate
+    InvalidChar(u32),
+    /// A string is not NUL terminated or not valid UTF-8
+    InvalidString,
+    /// An enum ordinal has no enumerator
+    InvalidEnum(u32),
+    /// A union discriminator selects no member
+    InvalidDiscriminator,
+    /// A member of a mutable type is unknown but flagged must-understand
+    UnknownMember(u32),
+    /// A member header carries another member ID than expected, or the expected member
+    /// is missing
+    UnexpectedMember(u32),
+    /// The encapsulation header names an unsupported representation
+    InvalidEncapsulation([u8; 2]),
+    /// A string or sequence is longer than the capacity of its bounded container
+    BoundExceeded(usize),
+    /// The slice serialized into is shorter than the serialized value of the given size
+    BufferTooSmall(usize),
+}
+
+impl fmt::Display for CdrError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CdrError::UnexpectedEnd => write!(f, "unexpected end of data"),
+            CdrError::InvalidBool(value) => write!(f, "invalid boolean {value}"),
+            CdrError::InvalidChar(value) => write!(f, "invalid wide character {value:#x}"),
+            CdrError::InvalidString => write!(f, "string is not NUL terminated UTF-8"),
+            CdrError::InvalidEnum(value) => write!(f, "invalid enumerator {value}"),
+            CdrError::InvalidDiscriminator => write!(f, "union discriminator selects no member"),
+            CdrError::UnknownMember(id) => write!(f, "unknown member {id} must be understood"),
+            CdrError::UnexpectedMember(id) => write!(f, "unexpected member {id}"),
+            CdrError::InvalidEncapsulation([high, low]) => {
+                write!(f, "unsupported encapsulation {high:02x}{low:02x}")
+            }
+            CdrError::BoundExceeded(length) => write!(f, "length {length} exceeds the bound"),
+            CdrError::BufferTooSmall(size) => write!(f, "buffer too small for {size} bytes"),
+        }
+    }
+}
+
+impl core::error::Error for CdrError {}
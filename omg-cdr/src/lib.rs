@@ -0,0 +1,232 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! OMG CDR encoding of the Rust code generated from OMG IDL.
+#![no_std]
+#![cfg_attr(feature = "f128", feature(f128))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod codec;
+mod encode;
+mod error;
+mod member_header;
+mod parameter;
+mod reader;
+mod view;
+mod writer;
+
+pub use codec::{Array, Codec, ParameterSequence, Sequence, WideChar, WideString};
+pub use encode::{CdrBounds, CdrDecode, CdrEncode, CdrParameter, CdrParameterList, CdrStruct};
+pub use error::CdrError;
+pub use reader::CdrReader;
+pub use view::{CdrPrimitive, CdrSlice, CdrStructView, CdrView, Lazy};
+pub use writer::CdrWriter;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use omg_idl_rt::{Extensibility, Extensible};
+
+/// Size of the encapsulation header preceding the serialized data
+pub const ENCAPSULATION_HEADER_SIZE: usize = 4;
+
+/// Byte order of the serialized data
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Endianness {
+    Big,
+    Little,
+}
+
+impl Endianness {
+    /// Byte order of the target
+    #[cfg(target_endian = "big")]
+    pub const NATIVE: Endianness = Endianness::Big;
+    /// Byte order of the target
+    #[cfg(target_endian = "little")]
+    pub const NATIVE: Endianness = Endianness::Little;
+}
+
+/// Version of the extended CDR representation (DDS-XTypes 7.4.3)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CdrVersion {
+    /// Classic CDR, mutable types as parameter lists
+    Xcdr1,
+    /// Alignment up to 4 bytes, appendable and mutable types delimited by a DHEADER
+    Xcdr2,
+}
+
+impl CdrVersion {
+    /// Largest alignment of a primitive
+    pub(crate) fn max_align(self) -> usize {
+        match self {
+            CdrVersion::Xcdr1 => 8,
+            CdrVersion::Xcdr2 => 4,
+        }
+    }
+}
+
+/// Representation identifiers of the encapsulation header (DDS-XTypes 7.6.3.1.2)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Encapsulation {
+    /// XCDR1 of final and appendable types
+    Cdr(Endianness),
+    /// XCDR1 of mutable types
+    PlCdr(Endianness),
+    /// XCDR2 of final types
+    Cdr2(Endianness),
+    /// XCDR2 of appendable types
+    DelimitedCdr2(Endianness),
+    /// XCDR2 of mutable types
+    PlCdr2(Endianness),
+}
+
+impl Encapsulation {
+    /// Encapsulation of a type with the given extensibility kind
+    pub fn of(extensibility: Extensibility, version: CdrVersion, endianness: Endianness) -> Self {
+        match (version, extensibility) {
+            (CdrVersion::Xcdr1, Extensibility::Mutable) => Encapsulation::PlCdr(endianness),
+            (CdrVersion::Xcdr1, _) => Encapsulation::Cdr(endianness),
+            (CdrVersion::Xcdr2, Extensibility::Final) => Encapsulation::Cdr2(endianness),
+            (CdrVersion::Xcdr2, Extensibility::Appendable) => {
+                Encapsulation::DelimitedCdr2(endianness)
+            }
+            (CdrVersion::Xcdr2, Extensibility::Mutable) => Encapsulation::PlCdr2(endianness),
+        }
+    }
+
+    pub fn endianness(&self) -> Endianness {
+        match self {
+            Encapsulation::Cdr(endianness)
+            | Encapsulation::PlCdr(endianness)
+            | Encapsulation::Cdr2(endianness)
+            | Encapsulation::DelimitedCdr2(endianness)
+            | Encapsulation::PlCdr2(endianness) => *endianness,
+        }
+    }
+
+    pub fn version(&self) -> CdrVersion {
+        match self {
+            Encapsulation::Cdr(_) | Encapsulation::PlCdr(_) => CdrVersion::Xcdr1,
+            _ => CdrVersion::Xcdr2,
+        }
+    }
+
+    /// The representation identifier followed by the empty representation options
+    pub fn header(&self) -> [u8; ENCAPSULATION_HEADER_SIZE] {
+        let identifier = match self {
+            Encapsulation::Cdr(_) => 0x00,
+            Encapsulation::PlCdr(_) => 0x02,
+            Encapsulation::Cdr2(_) => 0x06,
+            Encapsulation::DelimitedCdr2(_) => 0x08,
+            Encapsulation::PlCdr2(_) => 0x0A,
+        };
+        let little = u8::from(self.endianness() == Endianness::Little);
+        [0x00, identifier | little, 0x00, 0x00]
+    }
+
+    /// Parse an encapsulation header, the representation options are ignored
+    pub fn from_header(header: &[u8]) -> Result<Self, CdrError> {
+        let [high, low, _, _] = *header else {
+            return Err(CdrError::UnexpectedEnd);
+        };
+        let endianness = match low & 0x01 {
+            0 => Endianness::Big,
+            _ => Endianness::Little,
+        };
+        match [high, low & !0x01] {
+            [0x00, 0x00] => Ok(Encapsulation::Cdr(endianness)),
+            [0x00, 0x02] => Ok(Encapsulation::PlCdr(endianness)),
+            [0x00, 0x06] => Ok(Encapsulation::Cdr2(endianness)),
+            [0x00, 0x08] => Ok(Encapsulation::DelimitedCdr2(endianness)),
+            [0x00, 0x0A] => Ok(Encapsulation::PlCdr2(endianness)),
+            identifier => Err(CdrError::InvalidEncapsulation(identifier)),
+        }
+    }
+}
+
+/// Serialize `value` behind an encapsulation header. XCDR2 data is padded to a multiple
+/// of 4 bytes, the representation options hold the number of padding bytes.
+#[cfg(feature = "alloc")]
+pub fn to_bytes<T: CdrEncode + Extensible + ?Sized>(
+    value: &T,
+    version: CdrVersion,
+    endianness: Endianness,
+) -> Vec<u8> {
+    let mut writer = CdrWriter::new(version, endianness);
+    write_encapsulated(value, &mut writer);
+    writer.into_bytes()
+}
+
+/// Serialize `value` like `to_bytes` into `buffer` without allocating, the number of
+/// bytes written on success. `CdrBounds::max_serialized_size` plus the encapsulation
+/// header and padding is enough for bounded types, shorter buffers fail with
+/// `CdrError::BufferTooSmall` holding the required size.
+pub fn to_slice<T: CdrEncode + Extensible + ?Sized>(
+    value: &T,
+    version: CdrVersion,
+    endianness: Endianness,
+    buffer: &mut [u8],
+) -> Result<usize, CdrError> {
+    let mut writer = CdrWriter::from_slice(buffer, version, endianness);
+    write_encapsulated(value, &mut writer);
+    writer.finish()
+}
+
+fn write_encapsulated<T: CdrEncode + Extensible + ?Sized>(value: &T, writer: &mut CdrWriter) {
+    let version = writer.version();
+    writer.write_header(Encapsulation::of(T::EXTENSIBILITY, version, writer.endianness()).header());
+    value.encode(writer);
+    if version == CdrVersion::Xcdr2 {
+        writer.pad_data();
+    }
+}
+
+/// Serialize `value` behind the PL_CDR encapsulation header of RTPS discovery data, the
+/// value is expected to be an RTPS parameter list
+#[cfg(feature = "alloc")]
+pub fn to_pl_bytes<T: CdrEncode + ?Sized>(value: &T, endianness: Endianness) -> Vec<u8> {
+    let mut writer = CdrWriter::new(CdrVersion::Xcdr1, endianness);
+    writer.write_header(Encapsulation::PlCdr(endianness).header());
+    value.encode(&mut writer);
+    writer.into_bytes()
+}
+
+/// Serialize `value` like `to_pl_bytes` into `buffer` without allocating, see `to_slice`
+pub fn to_pl_slice<T: CdrEncode + ?Sized>(
+    value: &T,
+    endianness: Endianness,
+    buffer: &mut [u8],
+) -> Result<usize, CdrError> {
+    let mut writer = CdrWriter::from_slice(buffer, CdrVersion::Xcdr1, endianness);
+    writer.write_header(Encapsulation::PlCdr(endianness).header());
+    value.encode(&mut writer);
+    writer.finish()
+}
+
+/// Deserialize a value preceded by an encapsulation header, trailing padding is ignored
+pub fn from_bytes<T: CdrDecode>(bytes: &[u8]) -> Result<T, CdrError> {
+    T::decode(&mut encapsulated(bytes)?)
+}
+
+/// View a value preceded by an encapsulation header without copying it, i.e. the
+/// generated `FooRef<'a>` of a struct `Foo`
+pub fn view_bytes<'a, T: CdrView<'a>>(bytes: &'a [u8]) -> Result<T, CdrError> {
+    T::view(&mut encapsulated(bytes)?)
+}
+
+/// A reader of the data following the encapsulation header
+fn encapsulated(bytes: &[u8]) -> Result<CdrReader<'_>, CdrError> {
+    if bytes.len() < ENCAPSULATION_HEADER_SIZE {
+        return Err(CdrError::UnexpectedEnd);
+    }
+    let (header, data) = bytes.split_at(ENCAPSULATION_HEADER_SIZE);
+    let encapsulation = Encapsulation::from_header(header)?;
+    Ok(CdrReader::new(
+        data,
+        encapsulation.version(),
+        encapsulation.endianness(),
+    ))
+}
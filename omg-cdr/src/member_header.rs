@@ -0,0 +1,31 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Member headers (EMHEADER) of XCDR2 mutable types
+
+/// Flag of members a reader must not skip, i.e. keys
+pub const EMHEADER_MUST_UNDERSTAND: u32 = 0x8000_0000;
+
+/// Position of the length code
+pub const LENGTH_CODE_SHIFT: u32 = 28;
+
+/// Bits of the length code after shifting
+pub const LENGTH_CODE_MASK: u32 = 0x7;
+
+/// Bits of the member ID
+pub const MEMBER_ID_MASK: u32 = 0x0FFF_FFFF;
+
+/// Length codes 0 to 3 denote values of 1, 2, 4 and 8 bytes without NEXTINT
+pub const LENGTH_CODE_FIXED: u32 = 3;
+
+/// The length follows the header as NEXTINT
+pub const LENGTH_CODE_NEXTINT: u32 = 4;
+
+/// The NEXTINT is the first word of the value, a DHEADER holding its remaining length
+pub const LENGTH_CODE_DHEADER: u32 = 5;
+
+/// The NEXTINT is the first word of the value, a count of 4 byte elements, the length
+/// code 7 counts 8 byte elements
+pub const LENGTH_CODE_WORDS: u32 = 6;
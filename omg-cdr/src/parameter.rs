@@ -0,0 +1,40 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Parameter list headers of XCDR1 mutable types and optional members, as well as of
+//! RTPS parameter lists (PL_CDR)
+
+/// Parameter headers and values are aligned to 4 bytes
+pub const PARAMETER_ALIGN: usize = 4;
+
+/// Flag of members a reader must not skip, i.e. keys
+pub const PID_MUST_UNDERSTAND: u16 = 0x4000;
+
+/// Bits of the parameter ID without flags
+pub const PID_MASK: u16 = 0x3FFF;
+
+/// Member IDs from here on, or values longer than 64 KiB, use the extended header
+pub const SHORT_ID_LIMIT: u32 = 0x3F00;
+
+/// Header followed by the 32 bit member ID and length
+pub const PID_EXTENDED: u16 = 0x3F01;
+
+/// Length of the member ID and length of an extended header
+pub const EXTENDED_LENGTH: u16 = 8;
+
+/// Ends the parameter list of a mutable type
+pub const PID_LIST_END: u16 = 0x3F02;
+
+/// Member ID of the discriminator of a mutable union
+pub const DISCRIMINATOR_ID: u32 = 0;
+
+/// Padding within an RTPS parameter list, skipped when reading
+pub const PID_PAD: u16 = 0x0000;
+
+/// Ends an RTPS parameter list
+pub const PID_SENTINEL: u16 = 0x0001;
+
+/// Flag of vendor-specific RTPS parameters, skipped when unknown
+pub const PID_VENDOR_SPECIFIC: u16 = 0x8000;
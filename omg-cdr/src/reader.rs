@@ -0,0 +1,340 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+use crate::{
+    member_header::*, parameter::*, CdrDecode, CdrError, CdrParameter, CdrParameterList, CdrStruct,
+    CdrStructView, CdrVersion, Endianness,
+};
+use omg_idl_rt::{Extensibility, Extensible};
+
+/// Header of a member in a parameter list or member list
+struct Member {
+    id: u32,
+    must_understand: bool,
+    length: usize,
+}
+
+/// Deserializes CDR data. Alignment is relative to the start of the data, which is the
+/// first byte after the encapsulation header.
+#[derive(Clone, Debug)]
+pub struct CdrReader<'a> {
+    data: &'a [u8],
+    position: usize,
+    version: CdrVersion,
+    endianness: Endianness,
+}
+
+impl<'a> CdrReader<'a> {
+    pub fn new(data: &'a [u8], version: CdrVersion, endianness: Endianness) -> Self {
+        Self {
+            data,
+            position: 0,
+            version,
+            endianness,
+        }
+    }
+
+    pub fn version(&self) -> CdrVersion {
+        self.version
+    }
+
+    pub fn endianness(&self) -> Endianness {
+        self.endianness
+    }
+
+    /// Number of bytes not read yet
+    pub fn remaining(&self) -> usize {
+        self.data.len() - self.position
+    }
+
+    /// Skip the padding up to the alignment of a primitive of `size` bytes
+    pub fn align(&mut self, size: usize) -> Result<(), CdrError> {
+        let padded = self
+            .position
+            .next_multiple_of(size.clamp(1, self.version.max_align()));
+        if padded > self.data.len() {
+            return Err(CdrError::UnexpectedEnd);
+        }
+        self.position = padded;
+        Ok(())
+    }
+
+    /// The next `count` bytes as they are
+    pub fn read_bytes(&mut self, count: usize) -> Result<&'a [u8], CdrError> {
+        let end = self
+            .position
+            .checked_add(count)
+            .filter(|end| *end <= self.data.len())
+            .ok_or(CdrError::UnexpectedEnd)?;
+        let bytes = &self.data[self.position..end];
+        self.position = end;
+        Ok(bytes)
+    }
+
+    /// The length of a string or sequence
+    pub fn read_length(&mut self) -> Result<usize, CdrError> {
+        let length = u32::decode(self)?;
+        usize::try_from(length).map_err(|_| CdrError::UnexpectedEnd)
+    }
+
+    /// Deserialize a struct according to its extensibility, data following the members
+    /// of an XCDR2 appendable struct is skipped
+    pub fn read_struct<T: CdrStruct>(&mut self) -> Result<T, CdrError> {
+        match (self.version, T::EXTENSIBILITY) {
+            (CdrVersion::Xcdr2, Extensibility::Appendable | Extensibility::Mutable) => {
+                T::decode_members(&mut self.read_delimited()?)
+            }
+            _ => T::decode_members(self),
+        }
+    }
+
+    /// View a struct according to its extensibility, see [`read_struct`](Self::read_struct)
+    pub fn read_struct_view<T: CdrStructView<'a>>(&mut self) -> Result<T, CdrError> {
+        match (self.version, T::Owned::EXTENSIBILITY) {
+            (CdrVersion::Xcdr2, Extensibility::Appendable | Extensibility::Mutable) => {
+                T::view_members(&mut self.read_delimited()?)
+            }
+            _ => T::view_members(self),
+        }
+    }
+
+    /// Read the member list of a mutable type up to its end, `member` decodes the
+    /// member with the given ID and returns `false` for unknown members, which are
+    /// skipped unless flagged must-understand
+    pub fn read_members(
+        &mut self,
+        mut member: impl FnMut(u32, &mut CdrReader<'a>) -> Result<bool, CdrError>,
+    ) -> Result<(), CdrError> {
+        while let Some(header) = self.read_member()? {
+            let mut value = self.take(header.length)?;
+            if !member(header.id, &mut value)? && header.must_understand {
+                return Err(CdrError::UnknownMember(header.id));
+            }
+        }
+        Ok(())
+    }
+
+    /// Deserialize an `@optional` member of a final or appendable type
+    pub fn read_optional<T>(
+        &mut self,
+        id: u32,
+        decode: impl FnOnce(&mut CdrReader<'a>) -> Result<T, CdrError>,
+    ) -> Result<Option<T>, CdrError> {
+        if self.version == CdrVersion::Xcdr2 {
+            return match bool::decode(self)? {
+                true => decode(self).map(Some),
+                false => Ok(None),
+            };
+        }
+        let parameter = self
+            .read_parameter()?
+            .ok_or(CdrError::UnexpectedMember(id))?;
+        if parameter.id != id {
+            return Err(CdrError::UnexpectedMember(parameter.id));
+        }
+        if parameter.length == 0 {
+            return Ok(None);
+        }
+        decode(&mut self.take(parameter.length)?).map(Some)
+    }
+
+    /// Deserialize a union, `branch` decodes the member selected by the discriminator
+    pub fn read_union<D: CdrDecode, T>(
+        &mut self,
+        extensibility: Extensibility,
+        branch: impl FnOnce(D, &mut CdrReader<'a>) -> Result<T, CdrError>,
+    ) -> Result<T, CdrError> {
+        match (self.version, extensibility) {
+            (CdrVersion::Xcdr1, Extensibility::Mutable) => self.read_union_members(branch),
+            (CdrVersion::Xcdr2, Extensibility::Mutable) => {
+                self.read_delimited()?.read_union_members(branch)
+            }
+            (CdrVersion::Xcdr2, Extensibility::Appendable) => self
+                .read_delimited()?
+                .read_union(Extensibility::Final, branch),
+            _ => {
+                let discriminator = D::decode(self)?;
+                branch(discriminator, self)
+            }
+        }
+    }
+
+    /// Deserialize the union member `id` selected by the discriminator. Mutable unions
+    /// look for the member header of `id`, skipping other members unless flagged
+    /// must-understand.
+    pub fn read_branch<T>(
+        &mut self,
+        extensibility: Extensibility,
+        id: u32,
+        decode: impl FnOnce(&mut CdrReader<'a>) -> Result<T, CdrError>,
+    ) -> Result<T, CdrError> {
+        if extensibility != Extensibility::Mutable {
+            return decode(self);
+        }
+        while let Some(header) = self.read_member()? {
+            let mut value = self.take(header.length)?;
+            if header.id == id {
+                return decode(&mut value);
+            }
+            if header.must_understand {
+                return Err(CdrError::UnknownMember(header.id));
+            }
+        }
+        Err(CdrError::UnexpectedMember(id))
+    }
+
+    /// Deserialize the elements of a sequence or array, XCDR2 delimits elements of
+    /// non-primitive types by a DHEADER
+    pub fn read_elements<T>(
+        &mut self,
+        primitive: bool,
+        decode: impl FnOnce(&mut CdrReader<'a>) -> Result<T, CdrError>,
+    ) -> Result<T, CdrError> {
+        match self.version {
+            CdrVersion::Xcdr2 if !primitive => decode(&mut self.read_delimited()?),
+            _ => decode(self),
+        }
+    }
+
+    /// Deserialize a struct encoded as RTPS parameter list
+    pub fn read_parameter_list<T: CdrParameterList>(&mut self) -> Result<T, CdrError> {
+        let mut value = T::default();
+        self.read_pl_parameters(|id, reader| value.decode_parameter(id, reader))?;
+        Ok(value)
+    }
+
+    /// Deserialize a single RTPS parameter
+    pub fn read_pl_parameter<T: CdrParameter>(&mut self) -> Result<T, CdrError> {
+        self.align(PARAMETER_ALIGN)?;
+        let id = u16::decode(self)?;
+        let length = u16::decode(self)?;
+        T::decode_parameter(id, &mut self.take(length.into())?)?
+            .ok_or(CdrError::UnknownMember(id.into()))
+    }
+
+    /// Read an RTPS parameter list up to `PID_SENTINEL`, `parameter` decodes the
+    /// parameter with the given ID and returns `false` for unknown IDs. `PID_PAD`,
+    /// unknown and vendor-specific parameters are skipped, unknown parameters flagged
+    /// incompatible fail.
+    pub fn read_pl_parameters(
+        &mut self,
+        mut parameter: impl FnMut(u16, &mut CdrReader<'a>) -> Result<bool, CdrError>,
+    ) -> Result<(), CdrError> {
+        loop {
+            self.align(PARAMETER_ALIGN)?;
+            let id = u16::decode(self)?;
+            let length = u16::decode(self)?;
+            match id {
+                PID_SENTINEL => return Ok(()),
+                PID_PAD => {
+                    self.read_bytes(length.into())?;
+                }
+                _ => {
+                    let mut value = self.take(length.into())?;
+                    let incompatible =
+                        id & PID_MUST_UNDERSTAND != 0 && id & PID_VENDOR_SPECIFIC == 0;
+                    if !parameter(id, &mut value)? && incompatible {
+                        return Err(CdrError::UnknownMember(id.into()));
+                    }
+                }
+            }
+        }
+    }
+
+    /// The member list of a mutable union, the discriminator followed by the member.
+    /// Other members before the discriminator and after the member are skipped unless
+    /// flagged must-understand.
+    fn read_union_members<D: CdrDecode, T>(
+        &mut self,
+        branch: impl FnOnce(D, &mut CdrReader<'a>) -> Result<T, CdrError>,
+    ) -> Result<T, CdrError> {
+        let discriminator = loop {
+            let header = self.read_member()?.ok_or(CdrError::InvalidDiscriminator)?;
+            let mut value = self.take(header.length)?;
+            if header.id == DISCRIMINATOR_ID {
+                break D::decode(&mut value)?;
+            }
+            if header.must_understand {
+                return Err(CdrError::UnknownMember(header.id));
+            }
+        };
+        let value = branch(discriminator, self)?;
+        self.read_members(|_, _| Ok(false))?;
+        Ok(value)
+    }
+
+    /// A reader of the value following a DHEADER
+    fn read_delimited(&mut self) -> Result<CdrReader<'a>, CdrError> {
+        let length = self.read_length()?;
+        self.take(length)
+    }
+
+    /// The header of the next member, `None` at the end of the list
+    fn read_member(&mut self) -> Result<Option<Member>, CdrError> {
+        match self.version {
+            CdrVersion::Xcdr1 => self.read_parameter(),
+            CdrVersion::Xcdr2 => self.read_member_header(),
+        }
+    }
+
+    /// The next XCDR1 parameter header, `None` at the end of the list
+    fn read_parameter(&mut self) -> Result<Option<Member>, CdrError> {
+        self.align(PARAMETER_ALIGN)?;
+        let pid = u16::decode(self)?;
+        let length = u16::decode(self)?;
+        let must_understand = pid & PID_MUST_UNDERSTAND != 0;
+        match pid & PID_MASK {
+            PID_LIST_END => Ok(None),
+            PID_EXTENDED => Ok(Some(Member {
+                id: u32::decode(self)?,
+                must_understand,
+                length: self.read_length()?,
+            })),
+            id => Ok(Some(Member {
+                id: id.into(),
+                must_understand,
+                length: length.into(),
+            })),
+        }
+    }
+
+    /// The next XCDR2 EMHEADER, `None` at the end of the data. For the length codes 5
+    /// to 7 the NEXTINT is part of the value and not consumed.
+    fn read_member_header(&mut self) -> Result<Option<Member>, CdrError> {
+        if self.position.next_multiple_of(4) >= self.data.len() {
+            return Ok(None);
+        }
+        let header = u32::decode(self)?;
+        let length_code = header >> LENGTH_CODE_SHIFT & LENGTH_CODE_MASK;
+        let length = match length_code {
+            0..=LENGTH_CODE_FIXED => 1 << length_code,
+            LENGTH_CODE_NEXTINT => self.read_length()?,
+            _ => {
+                let next = self.clone().read_length()?;
+                let factor = match length_code {
+                    LENGTH_CODE_DHEADER => 1,
+                    LENGTH_CODE_WORDS => 4,
+                    _ => 8,
+                };
+                next.checked_mul(factor)
+                    .and_then(|length| length.checked_add(4))
+                    .ok_or(CdrError::UnexpectedEnd)?
+            }
+        };
+        Ok(Some(Member {
+            id: header & MEMBER_ID_MASK,
+            must_understand: header & EMHEADER_MUST_UNDERSTAND != 0,
+            length,
+        }))
+    }
+
+    /// A reader of the next `length` bytes, aligned relative to their start
+    fn take(&mut self, length: usize) -> Result<CdrReader<'a>, CdrError> {
+        Ok(CdrReader::new(
+            self.read_bytes(length)?,
+            self.version,
+            self.endianness,
+        ))
+    }
+}
@@ -0,0 +1,245 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Views reading structs from CDR data without copying it. Strings and sequences or
+//! arrays of primitives are borrowed from the data, members of other types are decoded
+//! when they are accessed.
+use crate::{CdrDecode, CdrError, CdrReader, CdrStruct, CdrVersion, Endianness};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{fmt, marker::PhantomData};
+
+/// Values read from CDR data without copying it
+pub trait CdrView<'a>: Sized {
+    /// Read the value at the position of `reader`, which is advanced past it. The value
+    /// is checked to decode, accessing it later does not fail.
+    fn view(reader: &mut CdrReader<'a>) -> Result<Self, CdrError>;
+}
+
+/// Generated for views of final and appendable structs, `FooRef<'a>` for the struct
+/// `Foo`. Views of derived structs hold the view of their base.
+pub trait CdrStructView<'a>: Sized {
+    /// The struct viewed
+    type Owned: CdrStruct;
+
+    /// Read the views of all members in order
+    fn view_members(reader: &mut CdrReader<'a>) -> Result<Self, CdrError>;
+}
+
+impl<'a, T: CdrStructView<'a>> CdrView<'a> for T {
+    fn view(reader: &mut CdrReader<'a>) -> Result<Self, CdrError> {
+        reader.read_struct_view()
+    }
+}
+
+macro_rules! impl_cdr_view {
+    ($($typ:ty),*) => {
+        $(
+            impl CdrView<'_> for $typ {
+                fn view(reader: &mut CdrReader<'_>) -> Result<Self, CdrError> {
+                    <$typ>::decode(reader)
+                }
+            }
+        )*
+    };
+}
+
+impl_cdr_view!(
+    u8,
+    i8,
+    i16,
+    u16,
+    i32,
+    u32,
+    i64,
+    u64,
+    f32,
+    f64,
+    bool,
+    char,
+    omg_idl_rt::Latin1
+);
+
+/// Strings are borrowed without their terminating NUL
+impl<'a> CdrView<'a> for &'a str {
+    fn view(reader: &mut CdrReader<'a>) -> Result<Self, CdrError> {
+        let length = reader.read_length()?;
+        // some implementations write empty strings without the NUL
+        if length == 0 {
+            return Ok("");
+        }
+        match reader.read_bytes(length)?.split_last() {
+            Some((0, text)) => core::str::from_utf8(text).map_err(|_| CdrError::InvalidString),
+            _ => Err(CdrError::InvalidString),
+        }
+    }
+}
+
+/// Primitive types of the elements of a [`CdrSlice`]
+pub trait CdrPrimitive: CdrDecode + Copy + 'static {
+    /// Size of the serialized value, which is also its alignment
+    const SIZE: usize;
+}
+
+macro_rules! impl_cdr_primitive {
+    ($($typ:ty => $size:expr),*) => {
+        $(
+            impl CdrPrimitive for $typ {
+                const SIZE: usize = $size;
+            }
+        )*
+    };
+}
+
+impl_cdr_primitive!(
+    u8 => 1, i8 => 1, i16 => 2, u16 => 2, i32 => 4, u32 => 4, i64 => 8, u64 => 8,
+    f32 => 4, f64 => 8, bool => 1, char => 1, omg_idl_rt::Latin1 => 1
+);
+
+/// Sequence or array of primitives borrowed from CDR data, the elements are decoded
+/// when they are accessed. Multi-dimensional arrays are a single slice of all elements.
+pub struct CdrSlice<'a, T> {
+    bytes: &'a [u8],
+    version: CdrVersion,
+    endianness: Endianness,
+    element: PhantomData<T>,
+}
+
+impl<'a, T: CdrPrimitive> CdrSlice<'a, T> {
+    /// Read a sequence, prefixed by its number of elements
+    pub fn read_sequence(reader: &mut CdrReader<'a>) -> Result<Self, CdrError> {
+        let length = reader.read_length()?;
+        Self::read(reader, length)
+    }
+
+    /// Read an array of `N` elements in total
+    pub fn read_array<const N: usize>(reader: &mut CdrReader<'a>) -> Result<Self, CdrError> {
+        Self::read(reader, N)
+    }
+
+    fn read(reader: &mut CdrReader<'a>, length: usize) -> Result<Self, CdrError> {
+        // the padding precedes the first element, empty sequences have none
+        if length > 0 {
+            reader.align(T::SIZE)?;
+        }
+        let size = length.checked_mul(T::SIZE).ok_or(CdrError::UnexpectedEnd)?;
+        let slice = Self {
+            bytes: reader.read_bytes(size)?,
+            version: reader.version(),
+            endianness: reader.endianness(),
+            element: PhantomData,
+        };
+        (0..length).try_for_each(|index| slice.decode(index).map(drop))?;
+        Ok(slice)
+    }
+
+    fn decode(&self, index: usize) -> Result<T, CdrError> {
+        let bytes = self
+            .bytes
+            .get(index * T::SIZE..(index + 1) * T::SIZE)
+            .ok_or(CdrError::UnexpectedEnd)?;
+        T::decode(&mut CdrReader::new(bytes, self.version, self.endianness))
+    }
+
+    /// Number of elements
+    pub fn len(&self) -> usize {
+        self.bytes.len() / T::SIZE
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// The element at `index`, `None` if out of bounds
+    pub fn get(&self, index: usize) -> Option<T> {
+        self.decode(index).ok()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = T> + 'a {
+        let slice = *self;
+        (0..self.len()).filter_map(move |index| slice.get(index))
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iter().collect()
+    }
+
+    /// The serialized elements in the byte order of the data
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+impl<'a> CdrSlice<'a, u8> {
+    /// Octets need no decoding
+    pub fn as_slice(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+impl<T> Clone for CdrSlice<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for CdrSlice<'_, T> {}
+
+impl<T: CdrPrimitive + fmt::Debug> fmt::Debug for CdrSlice<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: CdrPrimitive + PartialEq> PartialEq for CdrSlice<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+/// Member of a type without a view, decoded each time it is accessed
+pub struct Lazy<'a, T> {
+    reader: CdrReader<'a>,
+    decode: fn(&mut CdrReader<'_>) -> Result<T, CdrError>,
+}
+
+impl<'a, T> Lazy<'a, T> {
+    /// Skip the value at the position of `reader` after checking that `decode` succeeds
+    pub fn read(
+        reader: &mut CdrReader<'a>,
+        decode: fn(&mut CdrReader<'_>) -> Result<T, CdrError>,
+    ) -> Result<Self, CdrError> {
+        let start = reader.clone();
+        decode(reader)?;
+        Ok(Self {
+            reader: start,
+            decode,
+        })
+    }
+
+    /// Decode the value
+    pub fn get(&self) -> Result<T, CdrError> {
+        (self.decode)(&mut self.reader.clone())
+    }
+}
+
+impl<T> Clone for Lazy<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            reader: self.reader.clone(),
+            decode: self.decode,
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Lazy<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.get() {
+            Ok(value) => value.fmt(f),
+            Err(err) => write!(f, "<{err}>"),
+        }
+    }
+}
@@ -0,0 +1,395 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+use crate::{
+    member_header::*, parameter::*, CdrEncode, CdrError, CdrParameterList, CdrStruct, CdrVersion,
+    Endianness, ENCAPSULATION_HEADER_SIZE,
+};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use omg_idl_rt::Extensibility;
+
+/// Largest alignment of a primitive in any version
+const MAX_PADDING: usize = 8;
+
+/// Storage of the serialized bytes
+#[derive(Debug)]
+enum Buffer<'a> {
+    #[cfg(feature = "alloc")]
+    Vec(Vec<u8>),
+    /// Bytes beyond the end of the slice are counted but dropped
+    Slice(&'a mut [u8]),
+}
+
+/// Serializes values as CDR. Alignment is relative to the start of the writer, which
+/// is the first byte after the encapsulation header.
+#[derive(Debug)]
+pub struct CdrWriter<'a> {
+    buffer: Buffer<'a>,
+    /// Number of bytes written, may exceed the length of a slice
+    len: usize,
+    /// Position alignment is relative to, XCDR1 parameters align their value relative
+    /// to its own start
+    origin: usize,
+    version: CdrVersion,
+    endianness: Endianness,
+}
+
+#[cfg(feature = "alloc")]
+impl CdrWriter<'_> {
+    pub fn new(version: CdrVersion, endianness: Endianness) -> Self {
+        Self {
+            buffer: Buffer::Vec(Vec::new()),
+            len: 0,
+            origin: 0,
+            version,
+            endianness,
+        }
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        match self.buffer {
+            Buffer::Vec(buffer) => buffer,
+            Buffer::Slice(buffer) => buffer[..self.len.min(buffer.len())].to_vec(),
+        }
+    }
+}
+
+impl<'a> CdrWriter<'a> {
+    /// Serialize into `buffer` without allocating, `finish` tells whether it was long
+    /// enough
+    pub fn from_slice(buffer: &'a mut [u8], version: CdrVersion, endianness: Endianness) -> Self {
+        Self {
+            buffer: Buffer::Slice(buffer),
+            len: 0,
+            origin: 0,
+            version,
+            endianness,
+        }
+    }
+
+    pub fn version(&self) -> CdrVersion {
+        self.version
+    }
+
+    pub fn endianness(&self) -> Endianness {
+        self.endianness
+    }
+
+    /// Number of bytes written, fails with `CdrError::BufferTooSmall` holding the
+    /// number if they exceed the slice of `from_slice`
+    pub fn finish(self) -> Result<usize, CdrError> {
+        match self.buffer {
+            Buffer::Slice(ref buffer) if buffer.len() < self.len => {
+                Err(CdrError::BufferTooSmall(self.len))
+            }
+            _ => Ok(self.len),
+        }
+    }
+
+    /// Pad with zeros to the alignment of a primitive of `size` bytes
+    pub fn align(&mut self, size: usize) {
+        let offset = self.len - self.origin;
+        let padding = offset.next_multiple_of(size.clamp(1, self.version.max_align())) - offset;
+        self.write_bytes(&[0; MAX_PADDING][..padding]);
+    }
+
+    /// Append `bytes` as they are
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        self.put(self.len, bytes);
+        self.len += bytes.len();
+    }
+
+    /// Append the length of a string or sequence
+    pub fn write_length(&mut self, length: usize) {
+        u32::try_from(length).unwrap_or(u32::MAX).encode(self);
+    }
+
+    /// Serialize the members of a struct according to its extensibility kind
+    pub fn write_struct<T: CdrStruct>(&mut self, value: &T) {
+        match (self.version, T::EXTENSIBILITY) {
+            (CdrVersion::Xcdr1, Extensibility::Mutable) => {
+                value.encode_members(self);
+                self.write_list_end();
+            }
+            (CdrVersion::Xcdr2, Extensibility::Appendable | Extensibility::Mutable) => {
+                self.write_delimited(|writer| value.encode_members(writer));
+            }
+            _ => value.encode_members(self),
+        }
+    }
+
+    /// Serialize a member of a mutable type behind a header with its ID and length
+    pub fn write_member<T: ?Sized>(
+        &mut self,
+        id: u32,
+        must_understand: bool,
+        value: &T,
+        encode: impl FnOnce(&T, &mut CdrWriter),
+    ) {
+        match self.version {
+            CdrVersion::Xcdr1 => self.write_parameter(id, must_understand, Some(value), encode),
+            CdrVersion::Xcdr2 => self.write_member_header(id, must_understand, value, encode),
+        }
+    }
+
+    /// Serialize an `@optional` member of a final or appendable type, XCDR1 writes a
+    /// parameter header of length 0 for absent members, XCDR2 a presence flag
+    pub fn write_optional<T>(
+        &mut self,
+        id: u32,
+        value: &Option<T>,
+        encode: impl FnOnce(&T, &mut CdrWriter),
+    ) {
+        match self.version {
+            CdrVersion::Xcdr1 => self.write_parameter(id, false, value.as_ref(), encode),
+            CdrVersion::Xcdr2 => {
+                value.is_some().encode(self);
+                if let Some(value) = value {
+                    encode(value, self);
+                }
+            }
+        }
+    }
+
+    /// Serialize a union as its discriminator followed by the selected member, mutable
+    /// unions as member list of both
+    pub fn write_union<D: CdrEncode, T: ?Sized>(
+        &mut self,
+        extensibility: Extensibility,
+        discriminator: &D,
+        id: u32,
+        value: &T,
+        encode: impl FnOnce(&T, &mut CdrWriter),
+    ) {
+        match (self.version, extensibility) {
+            (CdrVersion::Xcdr1, Extensibility::Mutable) => {
+                self.write_member(DISCRIMINATOR_ID, true, discriminator, D::encode);
+                self.write_member(id, false, value, encode);
+                self.write_list_end();
+            }
+            (CdrVersion::Xcdr2, Extensibility::Mutable) => self.write_delimited(|writer| {
+                writer.write_member(DISCRIMINATOR_ID, true, discriminator, D::encode);
+                writer.write_member(id, false, value, encode);
+            }),
+            (CdrVersion::Xcdr2, Extensibility::Appendable) => self.write_delimited(|writer| {
+                writer.write_union(Extensibility::Final, discriminator, id, value, encode)
+            }),
+            _ => {
+                discriminator.encode(self);
+                encode(value, self);
+            }
+        }
+    }
+
+    /// Serialize the elements of a sequence or array, XCDR2 delimits elements of
+    /// non-primitive types by a DHEADER
+    pub fn write_elements(&mut self, primitive: bool, encode: impl FnOnce(&mut CdrWriter)) {
+        match self.version {
+            CdrVersion::Xcdr2 if !primitive => self.write_delimited(encode),
+            _ => encode(self),
+        }
+    }
+
+    /// Serialize a struct as RTPS parameter list, terminated by `PID_SENTINEL`
+    pub fn write_parameter_list<T: CdrParameterList>(&mut self, value: &T) {
+        value.encode_parameters(self);
+        self.write_pl_sentinel();
+    }
+
+    /// Serialize a value as RTPS parameter: its ID and length followed by the value,
+    /// padded to a multiple of 4 bytes. Values beyond 64 KiB cannot be represented.
+    pub fn write_pl_parameter<T: ?Sized>(
+        &mut self,
+        id: u16,
+        value: &T,
+        encode: impl FnOnce(&T, &mut CdrWriter),
+    ) {
+        id.encode(self);
+        0u16.encode(self);
+        let start = self.len;
+        self.write_nested(|writer| {
+            encode(value, writer);
+            writer.align(PARAMETER_ALIGN);
+        });
+        let length = u16::try_from(self.len - start).unwrap_or(u16::MAX);
+        self.put_u16(start - 2, length);
+    }
+
+    /// The end of an RTPS parameter list
+    pub fn write_pl_sentinel(&mut self) {
+        self.align(PARAMETER_ALIGN);
+        PID_SENTINEL.encode(self);
+        0u16.encode(self);
+    }
+
+    /// Write the encapsulation header, the data following it is aligned relative to its
+    /// own start
+    pub(crate) fn write_header(&mut self, header: [u8; ENCAPSULATION_HEADER_SIZE]) {
+        self.write_bytes(&header);
+        self.origin = self.len;
+    }
+
+    /// Pad XCDR2 data to a multiple of 4 bytes, the representation options of the
+    /// encapsulation header hold the number of padding bytes
+    pub(crate) fn pad_data(&mut self) {
+        let end = self.len;
+        self.align(4);
+        let padding = (self.len - end) as u8;
+        self.put(ENCAPSULATION_HEADER_SIZE - 1, &[padding]);
+    }
+
+    /// A DHEADER holding the length of the value
+    fn write_delimited(&mut self, encode: impl FnOnce(&mut CdrWriter)) {
+        self.write_length(0);
+        let start = self.len;
+        self.write_nested(encode);
+        self.put_length(start - 4, self.len - start);
+    }
+
+    /// The end of the parameter list of an XCDR1 mutable type
+    fn write_list_end(&mut self) {
+        self.align(PARAMETER_ALIGN);
+        PID_LIST_END.encode(self);
+        0u16.encode(self);
+    }
+
+    /// A parameter header followed by the value, the value is aligned relative to its
+    /// own start and padded to a multiple of 4 bytes
+    fn write_parameter<T: ?Sized>(
+        &mut self,
+        id: u32,
+        must_understand: bool,
+        value: Option<&T>,
+        encode: impl FnOnce(&T, &mut CdrWriter),
+    ) {
+        let flags = if must_understand {
+            PID_MUST_UNDERSTAND
+        } else {
+            0
+        };
+        self.align(PARAMETER_ALIGN);
+        let header = self.len;
+        // the short header, replaced by the extended one once the length is known
+        self.write_bytes(&[0; 4]);
+        let start = self.len;
+        if let Some(value) = value {
+            self.write_nested(|writer| {
+                encode(value, writer);
+                writer.align(PARAMETER_ALIGN);
+            });
+        }
+        let length = self.len - start;
+        match (u16::try_from(id), u16::try_from(length)) {
+            (Ok(pid), Ok(length)) if id < SHORT_ID_LIMIT => {
+                self.put_u16(header, flags | pid);
+                self.put_u16(header + 2, length);
+            }
+            _ => {
+                self.insert(start, 8);
+                self.put_u16(header, flags | PID_EXTENDED);
+                self.put_u16(header + 2, EXTENDED_LENGTH);
+                self.put_u32(header + 4, id);
+                self.put_length(header + 8, length);
+            }
+        }
+    }
+
+    /// An EMHEADER followed by the value, values of 1, 2, 4 or 8 bytes use the length
+    /// codes without NEXTINT
+    fn write_member_header<T: ?Sized>(
+        &mut self,
+        id: u32,
+        must_understand: bool,
+        value: &T,
+        encode: impl FnOnce(&T, &mut CdrWriter),
+    ) {
+        0u32.encode(self);
+        let start = self.len;
+        self.write_nested(|writer| encode(value, writer));
+        let length = self.len - start;
+        let length_code = match length {
+            1 => 0,
+            2 => 1,
+            4 => 2,
+            8 => 3,
+            _ => LENGTH_CODE_NEXTINT,
+        };
+        let flags = if must_understand {
+            EMHEADER_MUST_UNDERSTAND
+        } else {
+            0
+        };
+        self.put_u32(
+            start - 4,
+            flags | length_code << LENGTH_CODE_SHIFT | id & MEMBER_ID_MASK,
+        );
+        if length_code == LENGTH_CODE_NEXTINT {
+            self.insert(start, 4);
+            self.put_length(start, length);
+        }
+    }
+
+    /// Serialize a value aligned relative to its own start
+    fn write_nested(&mut self, encode: impl FnOnce(&mut CdrWriter)) {
+        let origin = core::mem::replace(&mut self.origin, self.len);
+        encode(self);
+        self.origin = origin;
+    }
+
+    /// Overwrite the bytes at `position`, the buffer grows if they reach beyond its end
+    fn put(&mut self, position: usize, bytes: &[u8]) {
+        match self.buffer {
+            #[cfg(feature = "alloc")]
+            Buffer::Vec(ref mut buffer) => {
+                let end = position + bytes.len();
+                if buffer.len() < end {
+                    buffer.resize(end, 0);
+                }
+                buffer[position..end].copy_from_slice(bytes);
+            }
+            Buffer::Slice(ref mut buffer) => {
+                let tail = buffer.get_mut(position..).unwrap_or_default();
+                let count = tail.len().min(bytes.len());
+                tail[..count].copy_from_slice(&bytes[..count]);
+            }
+        }
+    }
+
+    fn put_u16(&mut self, position: usize, value: u16) {
+        match self.endianness {
+            Endianness::Big => self.put(position, &value.to_be_bytes()),
+            Endianness::Little => self.put(position, &value.to_le_bytes()),
+        }
+    }
+
+    fn put_u32(&mut self, position: usize, value: u32) {
+        match self.endianness {
+            Endianness::Big => self.put(position, &value.to_be_bytes()),
+            Endianness::Little => self.put(position, &value.to_le_bytes()),
+        }
+    }
+
+    fn put_length(&mut self, position: usize, length: usize) {
+        self.put_u32(position, u32::try_from(length).unwrap_or(u32::MAX));
+    }
+
+    /// Make room for `count` bytes at `position` by moving the bytes after it
+    fn insert(&mut self, position: usize, count: usize) {
+        match self.buffer {
+            #[cfg(feature = "alloc")]
+            Buffer::Vec(ref mut buffer) => {
+                buffer.splice(position..position, core::iter::repeat_n(0, count));
+            }
+            Buffer::Slice(ref mut buffer) => {
+                // bytes moved beyond the end of the slice are dropped
+                let end = self.len.min(buffer.len().saturating_sub(count));
+                if position < end {
+                    buffer.copy_within(position..end, position + count);
+                }
+            }
+        }
+        self.len += count;
+    }
+}
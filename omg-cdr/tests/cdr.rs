@@ -0,0 +1,1046 @@
+use omg_cdr::{
+    from_bytes, to_bytes, to_pl_bytes, to_pl_slice, to_slice, view_bytes, CdrBounds, CdrDecode,
+    CdrEncode, CdrError, CdrParameter, CdrParameterList, CdrReader, CdrSlice, CdrStruct,
+    CdrStructView, CdrVersion, CdrView, CdrWriter, Codec, Endianness, Lazy, ParameterSequence,
+    WideChar, WideString,
+};
+use omg_idl_rt::{ByteBuf, Extensibility, Extensible, Latin1, WString, F128};
+
+/// As generated for `struct Sample { octet kind; long id; double value; string name; };`
+#[derive(Debug, Default, PartialEq)]
+struct Sample {
+    kind: u8,
+    id: i32,
+    value: f64,
+    name: String,
+}
+
+impl Extensible for Sample {
+    const EXTENSIBILITY: Extensibility = Extensibility::Final;
+}
+
+impl CdrEncode for Sample {
+    fn encode(&self, writer: &mut CdrWriter) {
+        writer.write_struct(self);
+    }
+}
+
+impl CdrDecode for Sample {
+    fn decode(reader: &mut CdrReader<'_>) -> Result<Self, CdrError> {
+        reader.read_struct()
+    }
+}
+
+impl CdrStruct for Sample {
+    fn encode_members(&self, writer: &mut CdrWriter) {
+        CdrEncode::encode(&self.kind, writer);
+        CdrEncode::encode(&self.id, writer);
+        CdrEncode::encode(&self.value, writer);
+        CdrEncode::encode(&self.name, writer);
+    }
+
+    fn decode_members(reader: &mut CdrReader<'_>) -> Result<Self, CdrError> {
+        Ok(Self {
+            kind: CdrDecode::decode(reader)?,
+            id: CdrDecode::decode(reader)?,
+            value: CdrDecode::decode(reader)?,
+            name: CdrDecode::decode(reader)?,
+        })
+    }
+}
+
+/// As generated for the view of `Sample`
+#[derive(Clone, Debug)]
+struct SampleRef<'a> {
+    reader: CdrReader<'a>,
+    kind: u8,
+    id: i32,
+    value: f64,
+    name: &'a str,
+}
+
+impl<'a> SampleRef<'a> {
+    fn to_owned(&self) -> Result<Sample, CdrError> {
+        CdrStruct::decode_members(&mut self.reader.clone())
+    }
+}
+
+impl<'a> CdrStructView<'a> for SampleRef<'a> {
+    type Owned = Sample;
+
+    fn view_members(reader: &mut CdrReader<'a>) -> Result<Self, CdrError> {
+        Ok(Self {
+            reader: reader.clone(),
+            kind: CdrView::view(reader)?,
+            id: CdrView::view(reader)?,
+            value: CdrView::view(reader)?,
+            name: CdrView::view(reader)?,
+        })
+    }
+}
+
+/// As generated for `@mutable struct Shape { @key long id; @optional long radius; };`
+#[derive(Debug, Default, PartialEq)]
+struct Shape {
+    id: i32,
+    radius: Option<i32>,
+}
+
+impl Shape {
+    const ID_MEMBER_ID: u32 = 0;
+    const RADIUS_MEMBER_ID: u32 = 1;
+}
+
+impl Extensible for Shape {
+    const EXTENSIBILITY: Extensibility = Extensibility::Mutable;
+}
+
+impl CdrEncode for Shape {
+    fn encode(&self, writer: &mut CdrWriter) {
+        writer.write_struct(self);
+    }
+}
+
+impl CdrDecode for Shape {
+    fn decode(reader: &mut CdrReader<'_>) -> Result<Self, CdrError> {
+        reader.read_struct()
+    }
+}
+
+impl CdrStruct for Shape {
+    fn encode_members(&self, writer: &mut CdrWriter) {
+        writer.write_member(Self::ID_MEMBER_ID, true, &self.id, CdrEncode::encode);
+        if let Some(value) = &self.radius {
+            writer.write_member(Self::RADIUS_MEMBER_ID, false, value, CdrEncode::encode);
+        }
+    }
+
+    fn decode_member(&mut self, id: u32, reader: &mut CdrReader<'_>) -> Result<bool, CdrError> {
+        match id {
+            Self::ID_MEMBER_ID => self.id = CdrDecode::decode(reader)?,
+            Self::RADIUS_MEMBER_ID => self.radius = Some(CdrDecode::decode(reader)?),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+}
+
+/// As generated for `@appendable struct Position { long x; long y; };`
+#[derive(Debug, Default, PartialEq)]
+struct Position {
+    x: i32,
+    y: i32,
+}
+
+impl Extensible for Position {
+    const EXTENSIBILITY: Extensibility = Extensibility::Appendable;
+}
+
+impl CdrEncode for Position {
+    fn encode(&self, writer: &mut CdrWriter) {
+        writer.write_struct(self);
+    }
+}
+
+impl CdrDecode for Position {
+    fn decode(reader: &mut CdrReader<'_>) -> Result<Self, CdrError> {
+        reader.read_struct()
+    }
+}
+
+impl CdrStruct for Position {
+    fn encode_members(&self, writer: &mut CdrWriter) {
+        CdrEncode::encode(&self.x, writer);
+        CdrEncode::encode(&self.y, writer);
+    }
+
+    fn decode_members(reader: &mut CdrReader<'_>) -> Result<Self, CdrError> {
+        Ok(Self {
+            x: CdrDecode::decode(reader)?,
+            y: CdrDecode::decode(reader)?,
+        })
+    }
+}
+
+impl CdrBounds for Position {
+    const IS_BOUNDED: bool = true;
+    const MAX_XCDR1_SIZE: Option<usize> = Some(8);
+    const MAX_XCDR2_SIZE: Option<usize> = Some(12);
+}
+
+/// As generated for `@mutable union Command switch (short) { case 1: long target; case 2: boolean stop; };`
+#[derive(Debug, PartialEq)]
+enum Command {
+    Move { target: i32 },
+    Halt { stop: bool },
+}
+
+impl Command {
+    const TARGET_MEMBER_ID: u32 = 1;
+    const STOP_MEMBER_ID: u32 = 2;
+}
+
+impl Extensible for Command {
+    const EXTENSIBILITY: Extensibility = Extensibility::Mutable;
+}
+
+impl CdrEncode for Command {
+    fn encode(&self, writer: &mut CdrWriter) {
+        let extensibility = <Self as Extensible>::EXTENSIBILITY;
+        match self {
+            Command::Move { target } => writer.write_union(
+                extensibility,
+                &1_i16,
+                Self::TARGET_MEMBER_ID,
+                target,
+                CdrEncode::encode,
+            ),
+            Command::Halt { stop } => writer.write_union(
+                extensibility,
+                &2_i16,
+                Self::STOP_MEMBER_ID,
+                stop,
+                CdrEncode::encode,
+            ),
+        }
+    }
+}
+
+impl CdrDecode for Command {
+    fn decode(reader: &mut CdrReader<'_>) -> Result<Self, CdrError> {
+        let extensibility = <Self as Extensible>::EXTENSIBILITY;
+        reader.read_union(extensibility, |discriminator: i16, reader| {
+            Ok(match discriminator {
+                1_i16 => Command::Move {
+                    target: reader.read_branch(
+                        extensibility,
+                        Self::TARGET_MEMBER_ID,
+                        CdrDecode::decode,
+                    )?,
+                },
+                2_i16 => Command::Halt {
+                    stop: reader.read_branch(
+                        extensibility,
+                        Self::STOP_MEMBER_ID,
+                        CdrDecode::decode,
+                    )?,
+                },
+                _ => return Err(CdrError::InvalidDiscriminator),
+            })
+        })
+    }
+}
+
+/// As generated for `struct Participant { string name; @optional unsigned long lease; };`
+/// configured as parameter list with the IDs 0x0005 and 0x0002
+#[derive(Debug, Default, PartialEq)]
+struct Participant {
+    name: String,
+    lease: Option<u32>,
+}
+
+impl Extensible for Participant {
+    const EXTENSIBILITY: Extensibility = Extensibility::Final;
+}
+
+impl CdrEncode for Participant {
+    fn encode(&self, writer: &mut CdrWriter) {
+        writer.write_parameter_list(self);
+    }
+}
+
+impl CdrDecode for Participant {
+    fn decode(reader: &mut CdrReader<'_>) -> Result<Self, CdrError> {
+        reader.read_parameter_list()
+    }
+}
+
+impl CdrParameterList for Participant {
+    fn encode_parameters(&self, writer: &mut CdrWriter) {
+        writer.write_pl_parameter(0x0005, &self.name, CdrEncode::encode);
+        if let Some(value) = &self.lease {
+            writer.write_pl_parameter(0x0002, value, CdrEncode::encode);
+        }
+    }
+
+    fn decode_parameter(&mut self, id: u16, reader: &mut CdrReader<'_>) -> Result<bool, CdrError> {
+        match id {
+            0x0005 => self.name = CdrDecode::decode(reader)?,
+            0x0002 => self.lease = Some(CdrDecode::decode(reader)?),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+}
+
+/// As generated for `union Parameter switch (unsigned short) { case 0x0005: string
+/// topic_name; case 0x0050: long count; };` configured as parameter
+#[derive(Debug, PartialEq)]
+enum Parameter {
+    TopicName { topic_name: String },
+    Count { count: i32 },
+}
+
+impl CdrParameter for Parameter {
+    fn encode_parameter(&self, writer: &mut CdrWriter) {
+        match self {
+            Parameter::TopicName { topic_name } => {
+                writer.write_pl_parameter(0x0005, topic_name, CdrEncode::encode)
+            }
+            Parameter::Count { count } => {
+                writer.write_pl_parameter(0x0050, count, CdrEncode::encode)
+            }
+        }
+    }
+
+    fn decode_parameter(id: u16, reader: &mut CdrReader<'_>) -> Result<Option<Self>, CdrError> {
+        Ok(Some(match id {
+            0x0005 => Parameter::TopicName {
+                topic_name: CdrDecode::decode(reader)?,
+            },
+            0x0050 => Parameter::Count {
+                count: CdrDecode::decode(reader)?,
+            },
+            _ => return Ok(None),
+        }))
+    }
+}
+
+fn encode<T: CdrEncode + ?Sized>(
+    value: &T,
+    version: CdrVersion,
+    endianness: Endianness,
+) -> Vec<u8> {
+    let mut writer = CdrWriter::new(version, endianness);
+    value.encode(&mut writer);
+    writer.into_bytes()
+}
+
+#[test]
+fn primitives_are_aligned_to_their_size() {
+    let sample = Sample {
+        kind: 7,
+        id: 0x0102,
+        value: 1.0,
+        name: "ab".to_owned(),
+    };
+    #[rustfmt::skip]
+    let expected = vec![
+        7, 0, 0, 0, 0, 0, 1, 2,
+        0x3F, 0xF0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 3, b'a', b'b', 0,
+    ];
+    assert_eq!(
+        encode(&sample, CdrVersion::Xcdr1, Endianness::Big),
+        expected
+    );
+
+    let decoded: Result<Sample, _> = CdrDecode::decode(&mut CdrReader::new(
+        &expected,
+        CdrVersion::Xcdr1,
+        Endianness::Big,
+    ));
+    assert_eq!(decoded, Ok(sample));
+}
+
+#[test]
+fn little_endian_round_trip() {
+    let sample = Sample {
+        kind: 1,
+        id: -2,
+        value: 0.5,
+        name: String::new(),
+    };
+    let bytes = encode(&sample, CdrVersion::Xcdr1, Endianness::Little);
+    assert_eq!(&bytes[4..8], &[0xFE, 0xFF, 0xFF, 0xFF]);
+    assert_eq!(&bytes[16..], &[1, 0, 0, 0, 0]);
+    let decoded: Result<Sample, _> = CdrDecode::decode(&mut CdrReader::new(
+        &bytes,
+        CdrVersion::Xcdr1,
+        Endianness::Little,
+    ));
+    assert_eq!(decoded, Ok(sample));
+}
+
+#[test]
+fn encapsulation_header_selects_byte_order() {
+    let sample = Sample::default();
+    let bytes = to_bytes(&sample, CdrVersion::Xcdr1, Endianness::Little);
+    assert_eq!(&bytes[..4], &[0x00, 0x01, 0x00, 0x00]);
+    assert_eq!(from_bytes::<Sample>(&bytes), Ok(Sample::default()));
+
+    let bytes = to_bytes(&Shape::default(), CdrVersion::Xcdr1, Endianness::Big);
+    assert_eq!(&bytes[..4], &[0x00, 0x02, 0x00, 0x00]);
+
+    assert_eq!(
+        from_bytes::<Sample>(&[0x00, 0x0C, 0x00, 0x00]),
+        Err(CdrError::InvalidEncapsulation([0x00, 0x0C]))
+    );
+}
+
+#[test]
+fn mutable_members_are_parameters() {
+    let shape = Shape {
+        id: 5,
+        radius: Some(3),
+    };
+    #[rustfmt::skip]
+    let expected = vec![
+        0x40, 0x00, 0, 4, 0, 0, 0, 5,
+        0x00, 0x01, 0, 4, 0, 0, 0, 3,
+        0x3F, 0x02, 0, 0,
+    ];
+    let bytes = encode(&shape, CdrVersion::Xcdr1, Endianness::Big);
+    assert_eq!(bytes, expected);
+    let decoded: Result<Shape, _> = CdrDecode::decode(&mut CdrReader::new(
+        &bytes,
+        CdrVersion::Xcdr1,
+        Endianness::Big,
+    ));
+    assert_eq!(decoded, Ok(shape));
+
+    let absent = Shape {
+        id: 5,
+        radius: None,
+    };
+    let bytes = encode(&absent, CdrVersion::Xcdr1, Endianness::Big);
+    assert_eq!(bytes.len(), 12);
+    let decoded: Result<Shape, _> = CdrDecode::decode(&mut CdrReader::new(
+        &bytes,
+        CdrVersion::Xcdr1,
+        Endianness::Big,
+    ));
+    assert_eq!(decoded, Ok(absent));
+}
+
+#[test]
+fn unknown_members_are_skipped_unless_must_understand() {
+    #[rustfmt::skip]
+    let unknown = [
+        0x00, 0x07, 0, 4, 0xAA, 0xBB, 0xCC, 0xDD,
+        0x40, 0x00, 0, 4, 0, 0, 0, 5,
+        0x3F, 0x02, 0, 0,
+    ];
+    let decoded: Result<Shape, _> = CdrDecode::decode(&mut CdrReader::new(
+        &unknown,
+        CdrVersion::Xcdr1,
+        Endianness::Big,
+    ));
+    assert_eq!(
+        decoded,
+        Ok(Shape {
+            id: 5,
+            radius: None
+        })
+    );
+
+    let mut must_understand = unknown;
+    must_understand[0] = 0x40;
+    let decoded: Result<Shape, _> = CdrDecode::decode(&mut CdrReader::new(
+        &must_understand,
+        CdrVersion::Xcdr1,
+        Endianness::Big,
+    ));
+    assert_eq!(decoded, Err(CdrError::UnknownMember(7)));
+}
+
+#[test]
+fn extended_parameter_header_for_large_ids() {
+    let mut writer = CdrWriter::new(CdrVersion::Xcdr1, Endianness::Big);
+    writer.write_member(0x0001_0000, false, &1_u8, CdrEncode::encode);
+    #[rustfmt::skip]
+    assert_eq!(
+        writer.into_bytes(),
+        vec![
+            0x3F, 0x01, 0, 8, 0, 1, 0, 0,
+            0, 0, 0, 4, 1, 0, 0, 0,
+        ]
+    );
+}
+
+#[test]
+fn optional_members_of_final_types() {
+    let mut writer = CdrWriter::new(CdrVersion::Xcdr1, Endianness::Big);
+    writer.write_optional(3, &Some(9_i16), CdrEncode::encode);
+    writer.write_optional::<i16>(4, &None, CdrEncode::encode);
+    let bytes = writer.into_bytes();
+    assert_eq!(bytes, vec![0, 3, 0, 4, 0, 9, 0, 0, 0, 4, 0, 0]);
+
+    let mut reader = CdrReader::new(&bytes, CdrVersion::Xcdr1, Endianness::Big);
+    assert_eq!(reader.read_optional(3, i16::decode), Ok(Some(9)));
+    assert_eq!(reader.read_optional(4, i16::decode), Ok(None));
+}
+
+#[test]
+fn mutable_union_round_trip() {
+    for command in [Command::Move { target: 12 }, Command::Halt { stop: true }] {
+        let bytes = encode(&command, CdrVersion::Xcdr1, Endianness::Little);
+        let decoded: Result<Command, _> = CdrDecode::decode(&mut CdrReader::new(
+            &bytes,
+            CdrVersion::Xcdr1,
+            Endianness::Little,
+        ));
+        assert_eq!(decoded, Ok(command));
+    }
+}
+
+#[test]
+fn mutable_union_members_are_found_by_id() {
+    // discriminator 1 selects `target`, the stream holds `stop` before it
+    #[rustfmt::skip]
+    let bytes = [
+        0x00, 0x40, 2, 0, 1, 0, 0, 0,
+        2, 0, 4, 0, 1, 0, 0, 0,
+        1, 0, 4, 0, 12, 0, 0, 0,
+        2, 0x3F, 0, 0,
+    ];
+    let decoded: Result<Command, _> = CdrDecode::decode(&mut CdrReader::new(
+        &bytes,
+        CdrVersion::Xcdr1,
+        Endianness::Little,
+    ));
+    assert_eq!(decoded, Ok(Command::Move { target: 12 }));
+
+    // only the wrong branch is present
+    let mut mismatched = bytes;
+    mismatched[16] = 2;
+    let decoded: Result<Command, _> = CdrDecode::decode(&mut CdrReader::new(
+        &mismatched,
+        CdrVersion::Xcdr1,
+        Endianness::Little,
+    ));
+    assert_eq!(decoded, Err(CdrError::UnexpectedMember(1)));
+
+    let mut must_understand = bytes;
+    must_understand[9] = 0x40;
+    let decoded: Result<Command, _> = CdrDecode::decode(&mut CdrReader::new(
+        &must_understand,
+        CdrVersion::Xcdr1,
+        Endianness::Little,
+    ));
+    assert_eq!(decoded, Err(CdrError::UnknownMember(2)));
+}
+
+#[test]
+fn wide_strings_are_utf16() {
+    let value = "aé".to_owned();
+    let mut writer = CdrWriter::new(CdrVersion::Xcdr1, Endianness::Big);
+    WideString::encode(&value, &mut writer);
+    let bytes = writer.into_bytes();
+    assert_eq!(bytes, vec![0, 0, 0, 4, 0, b'a', 0, 0xE9]);
+    let decoded = WideString::decode(&mut CdrReader::new(
+        &bytes,
+        CdrVersion::Xcdr1,
+        Endianness::Big,
+    ));
+    assert_eq!(decoded, Ok(value));
+}
+
+#[test]
+fn utf16_mapping_keeps_code_units() {
+    // an unpaired surrogate cannot be a Rust `char` or `String`
+    let value = WString::from_units(vec![u16::from(b'a'), 0xD800]);
+    let bytes = encode(&value, CdrVersion::Xcdr1, Endianness::Big);
+    assert_eq!(bytes, vec![0, 0, 0, 4, 0, b'a', 0xD8, 0]);
+    let mut reader = CdrReader::new(&bytes, CdrVersion::Xcdr1, Endianness::Big);
+    assert_eq!(WideString::decode(&mut reader), Ok(value));
+
+    let mut writer = CdrWriter::new(CdrVersion::Xcdr1, Endianness::Little);
+    WideChar::encode(&0xD800_u16, &mut writer);
+    assert_eq!(writer.into_bytes(), vec![0, 0xD8]);
+}
+
+#[test]
+fn latin1_characters_are_octets() {
+    let bytes = encode(
+        &[Latin1(b'a'), Latin1(0xE9)],
+        CdrVersion::Xcdr2,
+        Endianness::Big,
+    );
+    assert_eq!(bytes, vec![b'a', 0xE9]);
+    let decoded = <[Latin1; 2]>::decode(&mut CdrReader::new(
+        &bytes,
+        CdrVersion::Xcdr2,
+        Endianness::Big,
+    ));
+    assert_eq!(decoded.map(|chars| chars.map(char::from)), Ok(['a', 'é']));
+}
+
+#[test]
+fn long_double_is_binary128() {
+    let value = F128::from_f64(-2.0);
+    let bytes = encode(&value, CdrVersion::Xcdr1, Endianness::Little);
+    let mut expected = vec![0; 16];
+    expected[14..].copy_from_slice(&[0x00, 0xC0]);
+    assert_eq!(bytes, expected);
+
+    // aligned to 8 bytes in XCDR1 and 4 bytes in XCDR2
+    let mut writer = CdrWriter::new(CdrVersion::Xcdr1, Endianness::Big);
+    1_u8.encode(&mut writer);
+    value.encode(&mut writer);
+    let bytes = writer.into_bytes();
+    assert_eq!(bytes.len(), 24);
+    assert_eq!(bytes[8..10], [0xC0, 0x00]);
+    let mut reader = CdrReader::new(&bytes[8..], CdrVersion::Xcdr1, Endianness::Big);
+    assert_eq!(F128::decode(&mut reader).map(F128::to_f64), Ok(-2.0));
+
+    let mut writer = CdrWriter::new(CdrVersion::Xcdr2, Endianness::Big);
+    1_u8.encode(&mut writer);
+    value.encode(&mut writer);
+    assert_eq!(writer.into_bytes().len(), 20);
+}
+
+#[test]
+fn invalid_values_are_rejected() {
+    let decoded = bool::decode(&mut CdrReader::new(
+        &[2],
+        CdrVersion::Xcdr1,
+        Endianness::Big,
+    ));
+    assert_eq!(decoded, Err(CdrError::InvalidBool(2)));
+
+    let decoded = i32::decode(&mut CdrReader::new(
+        &[0, 0],
+        CdrVersion::Xcdr1,
+        Endianness::Big,
+    ));
+    assert_eq!(decoded, Err(CdrError::UnexpectedEnd));
+}
+
+#[test]
+fn xcdr2_aligns_to_at_most_4_bytes() {
+    let mut writer = CdrWriter::new(CdrVersion::Xcdr2, Endianness::Big);
+    1_u8.encode(&mut writer);
+    1.0_f64.encode(&mut writer);
+    assert_eq!(
+        writer.into_bytes(),
+        vec![1, 0, 0, 0, 0x3F, 0xF0, 0, 0, 0, 0, 0, 0]
+    );
+}
+
+#[test]
+fn xcdr2_appendable_types_are_delimited() {
+    let position = Position { x: 1, y: 2 };
+    let bytes = encode(&position, CdrVersion::Xcdr2, Endianness::Big);
+    assert_eq!(bytes, vec![0, 0, 0, 8, 0, 0, 0, 1, 0, 0, 0, 2]);
+
+    // members appended by a newer version of the type are skipped
+    #[rustfmt::skip]
+    let extended = [
+        0, 0, 0, 12, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 3,
+        0x2A,
+    ];
+    let mut reader = CdrReader::new(&extended, CdrVersion::Xcdr2, Endianness::Big);
+    assert_eq!(Position::decode(&mut reader), Ok(position));
+    assert_eq!(u8::decode(&mut reader), Ok(0x2A));
+}
+
+#[test]
+fn xcdr2_mutable_members_have_emheaders() {
+    let shape = Shape {
+        id: 5,
+        radius: Some(3),
+    };
+    #[rustfmt::skip]
+    let expected = vec![
+        0, 0, 0, 16,
+        0xA0, 0, 0, 0, 0, 0, 0, 5,
+        0x20, 0, 0, 1, 0, 0, 0, 3,
+    ];
+    let bytes = encode(&shape, CdrVersion::Xcdr2, Endianness::Big);
+    assert_eq!(bytes, expected);
+    let decoded: Result<Shape, _> = CdrDecode::decode(&mut CdrReader::new(
+        &bytes,
+        CdrVersion::Xcdr2,
+        Endianness::Big,
+    ));
+    assert_eq!(decoded, Ok(shape));
+}
+
+#[test]
+fn xcdr2_unknown_members_are_skipped_unless_must_understand() {
+    #[rustfmt::skip]
+    let unknown = [
+        0, 0, 0, 36,
+        0x40, 0, 0, 7, 0, 0, 0, 6, 0, 0, 0, 2, b'a', 0,
+        0, 0,
+        0x50, 0, 0, 8, 0, 0, 0, 4, 0, 0, 0, 1,
+        0xA0, 0, 0, 0, 0, 0, 0, 5,
+    ];
+    let decoded: Result<Shape, _> = CdrDecode::decode(&mut CdrReader::new(
+        &unknown,
+        CdrVersion::Xcdr2,
+        Endianness::Big,
+    ));
+    assert_eq!(
+        decoded,
+        Ok(Shape {
+            id: 5,
+            radius: None
+        })
+    );
+
+    let mut must_understand = unknown;
+    must_understand[4] = 0xC0;
+    let decoded: Result<Shape, _> = CdrDecode::decode(&mut CdrReader::new(
+        &must_understand,
+        CdrVersion::Xcdr2,
+        Endianness::Big,
+    ));
+    assert_eq!(decoded, Err(CdrError::UnknownMember(7)));
+}
+
+#[test]
+fn xcdr2_optional_members_have_presence_flag() {
+    let mut writer = CdrWriter::new(CdrVersion::Xcdr2, Endianness::Big);
+    writer.write_optional(3, &Some(9_i16), CdrEncode::encode);
+    writer.write_optional::<i16>(4, &None, CdrEncode::encode);
+    let bytes = writer.into_bytes();
+    assert_eq!(bytes, vec![1, 0, 0, 9, 0]);
+
+    let mut reader = CdrReader::new(&bytes, CdrVersion::Xcdr2, Endianness::Big);
+    assert_eq!(reader.read_optional(3, i16::decode), Ok(Some(9)));
+    assert_eq!(reader.read_optional(4, i16::decode), Ok(None));
+}
+
+#[test]
+fn xcdr2_sequences_of_non_primitives_are_delimited() {
+    let numbers = vec![1_i16, 2];
+    let bytes = encode(&numbers, CdrVersion::Xcdr2, Endianness::Big);
+    assert_eq!(bytes, vec![0, 0, 0, 2, 0, 1, 0, 2]);
+
+    let names = vec!["a".to_owned()];
+    let bytes = encode(&names, CdrVersion::Xcdr2, Endianness::Big);
+    assert_eq!(bytes, vec![0, 0, 0, 10, 0, 0, 0, 1, 0, 0, 0, 2, b'a', 0]);
+    let decoded = Vec::<String>::decode(&mut CdrReader::new(
+        &bytes,
+        CdrVersion::Xcdr2,
+        Endianness::Big,
+    ));
+    assert_eq!(decoded, Ok(names));
+}
+
+#[test]
+fn xcdr2_union_round_trip() {
+    for command in [Command::Move { target: 12 }, Command::Halt { stop: true }] {
+        let bytes = encode(&command, CdrVersion::Xcdr2, Endianness::Little);
+        let decoded: Result<Command, _> = CdrDecode::decode(&mut CdrReader::new(
+            &bytes,
+            CdrVersion::Xcdr2,
+            Endianness::Little,
+        ));
+        assert_eq!(decoded, Ok(command));
+    }
+}
+
+#[test]
+fn xcdr2_encapsulation_is_padded() {
+    let bytes = to_bytes(
+        &Position { x: 1, y: 2 },
+        CdrVersion::Xcdr2,
+        Endianness::Little,
+    );
+    assert_eq!(&bytes[..4], &[0x00, 0x09, 0x00, 0x00]);
+
+    let bytes = to_bytes(&Sample::default(), CdrVersion::Xcdr2, Endianness::Big);
+    assert_eq!(&bytes[..4], &[0x00, 0x06, 0x00, 0x03]);
+    assert_eq!(bytes.len() % 4, 0);
+    assert_eq!(from_bytes::<Sample>(&bytes), Ok(Sample::default()));
+
+    let bytes = to_bytes(&Shape::default(), CdrVersion::Xcdr2, Endianness::Big);
+    assert_eq!(&bytes[..4], &[0x00, 0x0A, 0x00, 0x00]);
+    assert_eq!(from_bytes::<Shape>(&bytes), Ok(Shape::default()));
+}
+
+#[test]
+fn pl_parameters_are_padded_and_terminated() {
+    let participant = Participant {
+        name: "ab".to_owned(),
+        lease: Some(7),
+    };
+    #[rustfmt::skip]
+    let expected = vec![
+        0, 5, 0, 8, 0, 0, 0, 3, b'a', b'b', 0, 0,
+        0, 2, 0, 4, 0, 0, 0, 7,
+        0, 1, 0, 0,
+    ];
+    assert_eq!(
+        encode(&participant, CdrVersion::Xcdr1, Endianness::Big),
+        expected
+    );
+
+    let bytes = to_pl_bytes(&participant, Endianness::Little);
+    assert_eq!(&bytes[..4], &[0x00, 0x03, 0x00, 0x00]);
+    assert_eq!(&bytes[4..8], &[5, 0, 8, 0]);
+    assert_eq!(from_bytes::<Participant>(&bytes), Ok(participant));
+}
+
+#[test]
+fn pl_unknown_parameters_are_skipped_unless_incompatible() {
+    #[rustfmt::skip]
+    let unknown = [
+        0, 0, 0, 4, 0, 0, 0, 0,
+        0, 0x77, 0, 4, 1, 2, 3, 4,
+        0xC0, 0x01, 0, 4, 1, 2, 3, 4,
+        0, 5, 0, 8, 0, 0, 0, 2, b'a', 0, 0, 0,
+        0, 1, 0, 0,
+    ];
+    let decoded: Result<Participant, _> = CdrDecode::decode(&mut CdrReader::new(
+        &unknown,
+        CdrVersion::Xcdr1,
+        Endianness::Big,
+    ));
+    assert_eq!(
+        decoded,
+        Ok(Participant {
+            name: "a".to_owned(),
+            lease: None
+        })
+    );
+
+    let mut incompatible = unknown;
+    incompatible[8] = 0x40;
+    let decoded: Result<Participant, _> = CdrDecode::decode(&mut CdrReader::new(
+        &incompatible,
+        CdrVersion::Xcdr1,
+        Endianness::Big,
+    ));
+    assert_eq!(decoded, Err(CdrError::UnknownMember(0x4077)));
+
+    let decoded: Result<Participant, _> = CdrDecode::decode(&mut CdrReader::new(
+        &unknown[..36],
+        CdrVersion::Xcdr1,
+        Endianness::Big,
+    ));
+    assert_eq!(decoded, Err(CdrError::UnexpectedEnd));
+}
+
+#[test]
+fn pl_parameter_sequence_round_trip() {
+    let parameters = vec![
+        Parameter::Count { count: -1 },
+        Parameter::TopicName {
+            topic_name: "topic".to_owned(),
+        },
+    ];
+    let mut writer = CdrWriter::new(CdrVersion::Xcdr1, Endianness::Little);
+    ParameterSequence::encode(&parameters, &mut writer);
+    let bytes = writer.into_bytes();
+    assert_eq!(&bytes[..8], &[0x50, 0, 4, 0, 0xFF, 0xFF, 0xFF, 0xFF]);
+    assert_eq!(&bytes[bytes.len() - 4..], &[1, 0, 0, 0]);
+
+    let decoded: Result<Vec<Parameter>, _> = ParameterSequence::decode(&mut CdrReader::new(
+        &bytes,
+        CdrVersion::Xcdr1,
+        Endianness::Little,
+    ));
+    assert_eq!(decoded, Ok(parameters));
+}
+
+#[test]
+fn bounded_types_fit_their_max_serialized_size() {
+    let position = Position { x: 1, y: 2 };
+    for version in [CdrVersion::Xcdr1, CdrVersion::Xcdr2] {
+        let bytes = encode(&position, version, Endianness::Big);
+        assert_eq!(Some(bytes.len()), Position::max_serialized_size(version));
+    }
+}
+
+#[test]
+fn slices_hold_the_bytes_of_to_bytes() {
+    let sample = Sample {
+        kind: 3,
+        id: -4,
+        value: 2.5,
+        name: "slice".to_owned(),
+    };
+    let shape = Shape {
+        id: 5,
+        radius: Some(3),
+    };
+    for version in [CdrVersion::Xcdr1, CdrVersion::Xcdr2] {
+        let mut buffer = [0xFF; 64];
+        let expected = to_bytes(&sample, version, Endianness::Little);
+        let len = to_slice(&sample, version, Endianness::Little, &mut buffer);
+        assert_eq!(&buffer[..len.unwrap()], expected);
+
+        let expected = to_bytes(&shape, version, Endianness::Big);
+        let len = to_slice(&shape, version, Endianness::Big, &mut buffer);
+        assert_eq!(&buffer[..len.unwrap()], expected);
+    }
+
+    let participant = Participant {
+        name: "ab".to_owned(),
+        lease: Some(7),
+    };
+    let mut buffer = [0; 64];
+    let len = to_pl_slice(&participant, Endianness::Big, &mut buffer).unwrap();
+    assert_eq!(&buffer[..len], to_pl_bytes(&participant, Endianness::Big));
+}
+
+#[test]
+fn short_slices_report_the_required_size() {
+    let required = to_bytes(&Sample::default(), CdrVersion::Xcdr1, Endianness::Big).len();
+    let mut buffer = [0; 8];
+    assert_eq!(
+        to_slice(
+            &Sample::default(),
+            CdrVersion::Xcdr1,
+            Endianness::Big,
+            &mut buffer
+        ),
+        Err(CdrError::BufferTooSmall(required))
+    );
+}
+
+#[test]
+fn member_headers_are_written_in_place() {
+    // the NEXTINT follows the EMHEADER once the length of the member is known
+    let mut buffer = [0; 16];
+    let mut writer = CdrWriter::from_slice(&mut buffer, CdrVersion::Xcdr2, Endianness::Big);
+    writer.write_member(1, false, "ab", CdrEncode::encode);
+    assert_eq!(writer.finish(), Ok(15));
+    assert_eq!(
+        buffer[..15],
+        [0x40, 0, 0, 1, 0, 0, 0, 7, 0, 0, 0, 3, b'a', b'b', 0]
+    );
+
+    // values beyond 64 KiB switch to the extended parameter header
+    let octets = [7u8; 70_000];
+    let mut buffer = vec![0; 70_012];
+    let mut writer = CdrWriter::from_slice(&mut buffer, CdrVersion::Xcdr1, Endianness::Big);
+    writer.write_member(1, false, &octets, CdrEncode::encode);
+    assert_eq!(writer.finish(), Ok(70_012));
+    assert_eq!(
+        buffer[..12],
+        [0x3F, 0x01, 0, 8, 0, 0, 0, 1, 0, 1, 0x11, 0x70]
+    );
+    let mut writer = CdrWriter::new(CdrVersion::Xcdr1, Endianness::Big);
+    writer.write_member(1, false, &octets, CdrEncode::encode);
+    assert_eq!(writer.into_bytes(), buffer);
+}
+
+#[test]
+fn arrays_decode_in_place() {
+    let names = ["a".to_owned(), "bc".to_owned(), "def".to_owned()];
+    let bytes = encode(&names, CdrVersion::Xcdr2, Endianness::Little);
+    let decoded: Result<[String; 3], _> = CdrDecode::decode(&mut CdrReader::new(
+        &bytes,
+        CdrVersion::Xcdr2,
+        Endianness::Little,
+    ));
+    assert_eq!(decoded, Ok(names));
+
+    let decoded: Result<[String; 3], _> = CdrDecode::decode(&mut CdrReader::new(
+        &bytes[..bytes.len() - 2],
+        CdrVersion::Xcdr2,
+        Endianness::Little,
+    ));
+    assert_eq!(decoded, Err(CdrError::UnexpectedEnd));
+}
+
+#[test]
+fn views_borrow_from_the_data() {
+    let sample = Sample {
+        kind: 3,
+        id: -4,
+        value: 2.5,
+        name: "view".to_owned(),
+    };
+    for version in [CdrVersion::Xcdr1, CdrVersion::Xcdr2] {
+        for endianness in [Endianness::Big, Endianness::Little] {
+            let bytes = to_bytes(&sample, version, endianness);
+            let view: SampleRef = view_bytes(&bytes).unwrap();
+            assert_eq!((view.kind, view.id, view.value), (3, -4, 2.5));
+            assert_eq!(view.name, "view");
+            assert!(bytes.as_ptr_range().contains(&view.name.as_ptr()));
+            assert_eq!(view.to_owned().as_ref(), Ok(&sample));
+        }
+    }
+
+    let mut truncated = to_bytes(&sample, CdrVersion::Xcdr1, Endianness::Big);
+    truncated.pop();
+    assert!(view_bytes::<SampleRef>(&truncated).is_err());
+}
+
+#[test]
+fn slices_decode_primitives_in_place() {
+    let values: Vec<u16> = vec![1, 0x0203, 0xFFFF];
+    let mut writer = CdrWriter::new(CdrVersion::Xcdr1, Endianness::Little);
+    7u8.encode(&mut writer);
+    values.encode(&mut writer);
+    let bytes = writer.into_bytes();
+    let mut reader = CdrReader::new(&bytes, CdrVersion::Xcdr1, Endianness::Little);
+    assert_eq!(u8::view(&mut reader), Ok(7));
+    let slice = CdrSlice::<u16>::read_sequence(&mut reader).unwrap();
+    assert_eq!(slice.len(), 3);
+    assert_eq!(slice.get(1), Some(0x0203));
+    assert_eq!(slice.get(3), None);
+    assert_eq!(slice.to_vec(), values);
+    assert_eq!(slice.as_bytes(), &[1, 0, 3, 2, 0xFF, 0xFF]);
+
+    let lazy = Lazy::read(
+        &mut CdrReader::new(&bytes[4..], CdrVersion::Xcdr1, Endianness::Little),
+        <Vec<u16>>::decode,
+    );
+    assert_eq!(lazy.unwrap().get(), Ok(values));
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn heapless_containers_are_encoded_like_unbounded_ones() {
+    let name: heapless::String<8> = "probe".try_into().unwrap();
+    let bytes = encode(&name, CdrVersion::Xcdr1, Endianness::Big);
+    assert_eq!(bytes, encode("probe", CdrVersion::Xcdr1, Endianness::Big));
+    let reader = CdrReader::new(&bytes, CdrVersion::Xcdr1, Endianness::Big);
+    assert_eq!(heapless::String::<8>::decode(&mut reader.clone()), Ok(name));
+    assert_eq!(
+        heapless::String::<4>::decode(&mut reader.clone()),
+        Err(CdrError::BoundExceeded(5))
+    );
+
+    let samples: heapless::Vec<u16, 4> = heapless::Vec::from_slice(&[1, 2]).unwrap();
+    let bytes = encode(&samples, CdrVersion::Xcdr2, Endianness::Little);
+    assert_eq!(
+        bytes,
+        encode(&vec![1u16, 2], CdrVersion::Xcdr2, Endianness::Little)
+    );
+    let reader = CdrReader::new(&bytes, CdrVersion::Xcdr2, Endianness::Little);
+    assert_eq!(
+        heapless::Vec::<u16, 4>::decode(&mut reader.clone()),
+        Ok(samples)
+    );
+    assert_eq!(
+        heapless::Vec::<u16, 1>::decode(&mut reader.clone()),
+        Err(CdrError::BoundExceeded(2))
+    );
+}
+
+#[test]
+fn byte_buffers_are_encoded_like_octet_sequences() {
+    let octets = vec![1u8, 2, 3];
+    let expected = encode(&octets, CdrVersion::Xcdr2, Endianness::Little);
+    let buffer = ByteBuf::from(octets);
+    let bytes = encode(&buffer, CdrVersion::Xcdr2, Endianness::Little);
+    assert_eq!(bytes, expected);
+    let reader = CdrReader::new(&bytes, CdrVersion::Xcdr2, Endianness::Little);
+    assert_eq!(ByteBuf::decode(&mut reader.clone()), Ok(buffer));
+    let mut truncated = CdrReader::new(&bytes[..6], CdrVersion::Xcdr2, Endianness::Little);
+    assert_eq!(
+        ByteBuf::decode(&mut truncated),
+        Err(CdrError::UnexpectedEnd)
+    );
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn bytes_are_encoded_like_octet_sequences() {
+    let octets = bytes::Bytes::from_static(b"payload");
+    let bytes = encode(&octets, CdrVersion::Xcdr1, Endianness::Big);
+    assert_eq!(
+        bytes,
+        encode(&octets.to_vec(), CdrVersion::Xcdr1, Endianness::Big)
+    );
+    let reader = CdrReader::new(&bytes, CdrVersion::Xcdr1, Endianness::Big);
+    assert_eq!(bytes::Bytes::decode(&mut reader.clone()), Ok(octets));
+}
@@ -3,8 +3,9 @@
 // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
 // http://www.apache.org/licenses/LICENSE-2.0>
 
-use omg_idl_code_gen::{generate_with_search_path, Configuration};
+use omg_idl_code_gen::{generate_with_search_path, Configuration, ParameterId};
 use std::{
+    collections::HashMap,
     env,
     fs::File,
     io::{Error, ErrorKind},
@@ -15,10 +16,119 @@ const IDL_DIR: &str = "files";
 const IDL_INFILE: &str = "dds/DdsCollection.idl";
 const RUST_OUTFILE: &str = "DdsCollection.rs";
 
+// RTPS parameter IDs of the discovery data, see Table 9.12 "ParameterId Values"
+const PID_TIME_BASED_FILTER: u16 = 0x0004;
+const PID_TOPIC_NAME: u16 = 0x0005;
+const PID_OWNERSHIP_STRENGTH: u16 = 0x0006;
+const PID_TYPE_NAME: u16 = 0x0007;
+const PID_RELIABILITY: u16 = 0x001a;
+const PID_LIVELINESS: u16 = 0x001b;
+const PID_DURABILITY: u16 = 0x001d;
+const PID_DURABILITY_SERVICE: u16 = 0x001e;
+const PID_OWNERSHIP: u16 = 0x001f;
+const PID_PRESENTATION: u16 = 0x0021;
+const PID_DEADLINE: u16 = 0x0023;
+const PID_DESTINATION_ORDER: u16 = 0x0025;
+const PID_LATENCY_BUDGET: u16 = 0x0027;
+const PID_PARTITION: u16 = 0x0029;
+const PID_LIFESPAN: u16 = 0x002b;
+const PID_USER_DATA: u16 = 0x002c;
+const PID_GROUP_DATA: u16 = 0x002d;
+const PID_TOPIC_DATA: u16 = 0x002e;
+const PID_HISTORY: u16 = 0x0040;
+const PID_RESOURCE_LIMITS: u16 = 0x0041;
+const PID_TRANSPORT_PRIORITY: u16 = 0x0049;
+const PID_PARTICIPANT_GUID: u16 = 0x0050;
+const PID_ENDPOINT_GUID: u16 = 0x005a;
+
+/// The builtin topic data exchanged as RTPS parameter lists during discovery
+const PARAMETER_LISTS: [(&str, &[(&str, u16)]); 4] = [
+    (
+        "DDS::ParticipantBuiltinTopicData",
+        &[("key", PID_PARTICIPANT_GUID), ("user_data", PID_USER_DATA)],
+    ),
+    (
+        "DDS::PublicationBuiltinTopicData",
+        &[
+            ("key", PID_ENDPOINT_GUID),
+            ("participant_key", PID_PARTICIPANT_GUID),
+            ("topic_name", PID_TOPIC_NAME),
+            ("type_name", PID_TYPE_NAME),
+            ("durability", PID_DURABILITY),
+            ("durability_service", PID_DURABILITY_SERVICE),
+            ("deadline", PID_DEADLINE),
+            ("latency_budget", PID_LATENCY_BUDGET),
+            ("liveliness", PID_LIVELINESS),
+            ("reliability", PID_RELIABILITY),
+            ("lifespan", PID_LIFESPAN),
+            ("user_data", PID_USER_DATA),
+            ("ownership", PID_OWNERSHIP),
+            ("ownership_strength", PID_OWNERSHIP_STRENGTH),
+            ("destination_order", PID_DESTINATION_ORDER),
+            ("presentation", PID_PRESENTATION),
+            ("partition", PID_PARTITION),
+            ("topic_data", PID_TOPIC_DATA),
+            ("group_data", PID_GROUP_DATA),
+        ],
+    ),
+    (
+        "DDS::SubscriptionBuiltinTopicData",
+        &[
+            ("key", PID_ENDPOINT_GUID),
+            ("participant_key", PID_PARTICIPANT_GUID),
+            ("topic_name", PID_TOPIC_NAME),
+            ("type_name", PID_TYPE_NAME),
+            ("durability", PID_DURABILITY),
+            ("deadline", PID_DEADLINE),
+            ("latency_budget", PID_LATENCY_BUDGET),
+            ("liveliness", PID_LIVELINESS),
+            ("reliability", PID_RELIABILITY),
+            ("ownership", PID_OWNERSHIP),
+            ("destination_order", PID_DESTINATION_ORDER),
+            ("user_data", PID_USER_DATA),
+            ("time_based_filter", PID_TIME_BASED_FILTER),
+            ("presentation", PID_PRESENTATION),
+            ("partition", PID_PARTITION),
+            ("topic_data", PID_TOPIC_DATA),
+            ("group_data", PID_GROUP_DATA),
+        ],
+    ),
+    (
+        "DDS::TopicBuiltinTopicData",
+        &[
+            ("key", PID_ENDPOINT_GUID),
+            ("name", PID_TOPIC_NAME),
+            ("type_name", PID_TYPE_NAME),
+            ("durability", PID_DURABILITY),
+            ("durability_service", PID_DURABILITY_SERVICE),
+            ("deadline", PID_DEADLINE),
+            ("latency_budget", PID_LATENCY_BUDGET),
+            ("liveliness", PID_LIVELINESS),
+            ("reliability", PID_RELIABILITY),
+            ("transport_priority", PID_TRANSPORT_PRIORITY),
+            ("lifespan", PID_LIFESPAN),
+            ("destination_order", PID_DESTINATION_ORDER),
+            ("history", PID_HISTORY),
+            ("resource_limits", PID_RESOURCE_LIMITS),
+            ("ownership", PID_OWNERSHIP),
+            ("topic_data", PID_TOPIC_DATA),
+        ],
+    ),
+];
+
 fn main() -> Result<(), Error> {
     let out_dir = env::var("OUT_DIR").unwrap();
     let dest_path = Path::new(&out_dir).join(RUST_OUTFILE);
-    let config = Configuration::new(Path::new(IDL_DIR), Path::new(IDL_INFILE), false);
+    let config = PARAMETER_LISTS.iter().fold(
+        Configuration::new(Path::new(IDL_DIR), Path::new(IDL_INFILE), false),
+        |config, (type_name, ids)| {
+            let ids = ids
+                .iter()
+                .map(|(member, id)| (member.to_string(), ParameterId::Value(*id)))
+                .collect::<HashMap<_, _>>();
+            config.with_parameter_list(type_name, ids)
+        },
+    );
     let mut out = File::create(dest_path)?;
 
     generate_with_search_path(&mut out, &config).map_err(|e| {
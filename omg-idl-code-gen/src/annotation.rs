@@ -0,0 +1,589 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+use crate::{ast::*, index::ConstIndex};
+use serde_derive::Deserialize;
+use std::collections::HashMap;
+
+/// Kind of value an annotation parameter accepts
+#[derive(Clone, Debug)]
+pub enum ParamKind {
+    Boolean,
+    Integer(i128, i128),
+    Float,
+    Char,
+    String,
+    Enum(Vec<String>),
+    Any,
+}
+
+impl ParamKind {
+    /// Map the type of a parameter of an `@annotation` declaration, `enums` are the
+    /// enumerations declared within the annotation body.
+    pub fn from_type_spec(type_spec: &IdlTypeSpec, enums: &HashMap<String, Vec<String>>) -> Self {
+        match type_spec {
+            IdlTypeSpec::BooleanType => ParamKind::Boolean,
+            IdlTypeSpec::OctetType => ParamKind::Integer(u8::MIN.into(), u8::MAX.into()),
+            IdlTypeSpec::I16Type => ParamKind::Integer(i16::MIN.into(), i16::MAX.into()),
+            IdlTypeSpec::I32Type => ParamKind::Integer(i32::MIN.into(), i32::MAX.into()),
+            IdlTypeSpec::I64Type => ParamKind::Integer(i64::MIN.into(), i64::MAX.into()),
+            IdlTypeSpec::U16Type => ParamKind::Integer(u16::MIN.into(), u16::MAX.into()),
+            IdlTypeSpec::U32Type => ParamKind::Integer(u32::MIN.into(), u32::MAX.into()),
+            IdlTypeSpec::U64Type => ParamKind::Integer(u64::MIN.into(), u64::MAX.into()),
+            IdlTypeSpec::F32Type | IdlTypeSpec::F64Type | IdlTypeSpec::F128Type => ParamKind::Float,
+            IdlTypeSpec::CharType | IdlTypeSpec::WideCharType => ParamKind::Char,
+            IdlTypeSpec::StringType(_) | IdlTypeSpec::WideStringType(_) => ParamKind::String,
+            IdlTypeSpec::ScopedName(name) => name
+                .0
+                .last()
+                .and_then(|id| enums.get(id))
+                .map(|enumerators| ParamKind::Enum(enumerators.clone()))
+                .unwrap_or(ParamKind::Any),
+            _ => ParamKind::Any,
+        }
+    }
+
+    /// Verify the value is acceptable, integers passed to floating point parameters
+    /// are converted.
+    fn check(&self, value: IdlConstValue) -> Result<IdlConstValue, String> {
+        match (self, value) {
+            (ParamKind::Boolean, value @ IdlConstValue::Boolean(_))
+            | (ParamKind::Float, value @ IdlConstValue::Float(_))
+            | (ParamKind::Char, value @ IdlConstValue::Char(_))
+            | (ParamKind::String, value @ IdlConstValue::String(_))
+            | (ParamKind::Any, value) => Ok(value),
+            (ParamKind::Float, IdlConstValue::Integer(val)) => Ok(IdlConstValue::Float(val as f64)),
+            (ParamKind::Integer(min, max), IdlConstValue::Integer(val)) => {
+                if (*min..=*max).contains(&val) {
+                    Ok(IdlConstValue::Integer(val))
+                } else {
+                    Err(format!("{val} is out of range [{min}, {max}]"))
+                }
+            }
+            (ParamKind::Enum(enumerators), IdlConstValue::Enumerator(val)) => {
+                if enumerators.contains(&val) {
+                    Ok(IdlConstValue::Enumerator(val))
+                } else {
+                    Err(format!("{val} is not one of {}", enumerators.join(", ")))
+                }
+            }
+            (kind, value) => Err(format!("{value} does not match the expected {kind:?}")),
+        }
+    }
+}
+
+/// A parameter of an annotation declaration
+#[derive(Clone, Debug)]
+pub struct IdlAnnotationMember {
+    pub name: String,
+    pub kind: ParamKind,
+    pub default: Option<IdlConstValue>,
+}
+
+/// Representation of an `@annotation` declaration
+#[derive(Clone, Debug)]
+pub struct IdlAnnotationDcl {
+    pub id: String,
+    pub members: Vec<IdlAnnotationMember>,
+}
+
+/// Rust code a user-defined annotation is mapped to. `{name}` in the text is replaced by
+/// the value of the annotation parameter `name`, a single unnamed parameter is `value`,
+/// `{{` and `}}` produce literal braces.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AnnotationMapping {
+    /// Attribute placed before the annotated element, i.e. `#[serde(rename = "{value}")]`
+    Attribute(String),
+    /// Comma separated derives added to the annotated struct, union or enum, i.e. `{value}`
+    Derive(String),
+}
+
+impl AnnotationMapping {
+    /// The mapped text with the parameters of the resolved `annotation` substituted
+    pub fn expand(&self, annotation: &IdlAnnotation) -> Result<String, String> {
+        let (AnnotationMapping::Attribute(text) | AnnotationMapping::Derive(text)) = self;
+        let mut expanded = String::new();
+        let mut rest = text.as_str();
+        while let Some(start) = rest.find(['{', '}']) {
+            expanded.push_str(&rest[..start]);
+            let tail = &rest[start..];
+            if tail.starts_with("{{") || tail.starts_with("}}") {
+                expanded.push_str(&tail[..1]);
+                rest = &tail[2..];
+                continue;
+            }
+            let end = match tail.find('}') {
+                Some(end) if tail.starts_with('{') => end,
+                _ => return Err(format!("unbalanced braces in '{text}'")),
+            };
+            let name = &tail[1..end];
+            match annotation.param(name) {
+                Some(IdlConstValue::String(value)) => expanded.push_str(value),
+                Some(IdlConstValue::Char(value)) => expanded.push(*value),
+                Some(IdlConstValue::Boolean(value)) => expanded.push_str(&value.to_string()),
+                Some(value) => expanded.push_str(&value.to_string()),
+                None => return Err(format!("missing parameter '{name}'")),
+            }
+            rest = &tail[end + 1..];
+        }
+        expanded.push_str(rest);
+        Ok(expanded)
+    }
+
+    /// Mapped derives of the annotations of a type, the text of `Derive` mappings is
+    /// split at commas
+    pub fn derives(
+        mappings: &HashMap<String, AnnotationMapping>,
+        annotations: &IdlAnnotations,
+    ) -> Vec<String> {
+        Self::mapped(mappings, annotations, false)
+            .iter()
+            .flat_map(|text| text.split(','))
+            .map(str::trim)
+            .filter(|derive| !derive.is_empty())
+            .map(str::to_owned)
+            .collect()
+    }
+
+    /// Mapped attributes of the annotations of an element, one line each
+    pub fn attributes(
+        mappings: &HashMap<String, AnnotationMapping>,
+        annotations: &IdlAnnotations,
+    ) -> Vec<String> {
+        Self::mapped(mappings, annotations, true)
+            .iter()
+            .flat_map(|text| text.lines())
+            .map(str::to_owned)
+            .collect()
+    }
+
+    /// Expanded text of all annotations mapped to attributes or derives, in order of
+    /// appearance. Builtin annotations are never mapped.
+    fn mapped(
+        mappings: &HashMap<String, AnnotationMapping>,
+        annotations: &IdlAnnotations,
+        attribute: bool,
+    ) -> Vec<String> {
+        annotations
+            .0
+            .iter()
+            .filter(|annotation| builtin(annotation.id()).is_none())
+            .filter_map(|annotation| {
+                let mapping = mappings.get(annotation.id())?;
+                if attribute != matches!(mapping, AnnotationMapping::Attribute(_)) {
+                    return None;
+                }
+                // the annotation resolver rejects mappings that cannot be expanded
+                mapping.expand(annotation).ok()
+            })
+            .collect()
+    }
+}
+
+/// Elements of the IDL an annotation can be applied to
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Target {
+    Module,
+    Const,
+    Struct,
+    Union,
+    Enum,
+    Typedef,
+    Member,
+    UnionMember,
+    Enumerator,
+}
+
+const ANY_TARGET: &[Target] = &[
+    Target::Module,
+    Target::Const,
+    Target::Struct,
+    Target::Union,
+    Target::Enum,
+    Target::Typedef,
+    Target::Member,
+    Target::UnionMember,
+    Target::Enumerator,
+];
+const TYPES: &[Target] = &[Target::Struct, Target::Union];
+const MEMBERS: &[Target] = &[Target::Member, Target::UnionMember];
+const VALUES: &[Target] = &[Target::Member, Target::UnionMember, Target::Typedef];
+
+fn member(name: &str, kind: ParamKind, default: Option<IdlConstValue>) -> IdlAnnotationMember {
+    IdlAnnotationMember {
+        name: name.to_owned(),
+        kind,
+        default,
+    }
+}
+
+fn flag(name: &str) -> IdlAnnotationMember {
+    member(name, ParamKind::Boolean, Some(IdlConstValue::Boolean(true)))
+}
+
+fn enumeration(values: &[&str]) -> ParamKind {
+    ParamKind::Enum(values.iter().map(|value| value.to_string()).collect())
+}
+
+fn enumerator(value: &str) -> Option<IdlConstValue> {
+    Some(IdlConstValue::Enumerator(value.to_owned()))
+}
+
+fn string(value: &str) -> Option<IdlConstValue> {
+    Some(IdlConstValue::String(value.to_owned()))
+}
+
+/// The standardized annotations of IDL4 (chapter 8.3) and DDS-XTypes with their
+/// parameters and the elements they apply to.
+fn builtin(id: &str) -> Option<(Vec<IdlAnnotationMember>, &'static [Target])> {
+    let u32_kind = ParamKind::Integer(u32::MIN.into(), u32::MAX.into());
+    let u16_kind = ParamKind::Integer(u16::MIN.into(), u16::MAX.into());
+    let builtin = match id {
+        "id" => (vec![member("value", u32_kind, None)], MEMBERS),
+        "autoid" => (
+            vec![member(
+                "value",
+                enumeration(&["SEQUENTIAL", "HASH"]),
+                enumerator("HASH"),
+            )],
+            &[Target::Module, Target::Struct, Target::Union][..],
+        ),
+        "optional" => (vec![flag("value")], MEMBERS),
+        "position" => (
+            vec![member("value", u16_kind, None)],
+            &[Target::Enumerator][..],
+        ),
+        "value" => (
+            vec![member("value", ParamKind::Any, None)],
+            &[Target::Enumerator][..],
+        ),
+        "extensibility" => (
+            vec![member(
+                "value",
+                enumeration(&["FINAL", "APPENDABLE", "MUTABLE"]),
+                None,
+            )],
+            &[Target::Struct, Target::Union, Target::Enum][..],
+        ),
+        "final" | "appendable" => (vec![], &[Target::Struct, Target::Union, Target::Enum][..]),
+        "mutable" => (vec![], TYPES),
+        "key" | "must_understand" | "non_serialized" => {
+            (vec![flag("value")], &[Target::Member][..])
+        }
+        "default_literal" => (vec![], &[Target::Enumerator][..]),
+        "default" => (vec![member("value", ParamKind::Any, None)], VALUES),
+        "range" => (
+            vec![
+                member("min", ParamKind::Any, None),
+                member("max", ParamKind::Any, None),
+            ],
+            VALUES,
+        ),
+        "min" | "max" => (vec![member("value", ParamKind::Any, None)], VALUES),
+        "unit" => (vec![member("value", ParamKind::String, None)], VALUES),
+        // not part of IDL4, mapped to rustdoc and `#[deprecated]`
+        "doc" => (vec![member("value", ParamKind::String, None)], ANY_TARGET),
+        "deprecated" => (
+            vec![member("value", ParamKind::String, string(""))],
+            ANY_TARGET,
+        ),
+        "bit_bound" => (
+            vec![member("value", ParamKind::Integer(1, 64), None)],
+            &[Target::Enum][..],
+        ),
+        "external" => (vec![flag("value")], MEMBERS),
+        "nested" => (vec![flag("value")], TYPES),
+        "verbatim" => (
+            vec![
+                member("language", ParamKind::String, string("*")),
+                member(
+                    "placement",
+                    enumeration(&[
+                        "BEGIN_FILE",
+                        "BEFORE_DECLARATION",
+                        "BEGIN_DECLARATION",
+                        "END_DECLARATION",
+                        "AFTER_DECLARATION",
+                        "END_FILE",
+                    ]),
+                    enumerator("BEFORE_DECLARATION"),
+                ),
+                member("text", ParamKind::String, None),
+            ],
+            ANY_TARGET,
+        ),
+        // interfaces and operations are not supported by the generator
+        "service" => (
+            vec![member("platform", ParamKind::String, string("*"))],
+            &[][..],
+        ),
+        "oneway" => (vec![flag("value")], &[][..]),
+        "ami" => (vec![flag("value")], &[][..]),
+        "hashid" => (
+            vec![member("value", ParamKind::String, string(""))],
+            MEMBERS,
+        ),
+        "default_nested" => (vec![flag("value")], &[Target::Module][..]),
+        "ignore_literal_names" => (vec![flag("value")], &[Target::Enum][..]),
+        "try_construct" => (
+            vec![member(
+                "value",
+                enumeration(&["DISCARD", "USE_DEFAULT", "TRIM"]),
+                enumerator("USE_DEFAULT"),
+            )],
+            VALUES,
+        ),
+        "data_representation" => (vec![member("allowed_kinds", ParamKind::Any, None)], TYPES),
+        "topic" => (
+            vec![
+                member("name", ParamKind::String, string("")),
+                member("platform", ParamKind::String, string("*")),
+            ],
+            TYPES,
+        ),
+        _ => return None,
+    };
+    Some(builtin)
+}
+
+/// Evaluates the parameters of all annotation applications and validates them against
+/// the builtin annotations and the `@annotation` declarations of the IDL.
+pub struct AnnotationResolver<'a> {
+    constants: ConstIndex,
+    declarations: &'a HashMap<String, IdlAnnotationDcl>,
+    mappings: &'a HashMap<String, AnnotationMapping>,
+    verbose: bool,
+}
+
+impl<'a> AnnotationResolver<'a> {
+    pub fn new(
+        root_module: &IdlModule,
+        declarations: &'a HashMap<String, IdlAnnotationDcl>,
+        mappings: &'a HashMap<String, AnnotationMapping>,
+        verbose: bool,
+    ) -> Self {
+        AnnotationResolver {
+            constants: ConstIndex::new(root_module),
+            declarations,
+            mappings,
+            verbose,
+        }
+    }
+
+    /// Resolve all annotations of the module, its types, constants and submodules
+    pub fn resolve_module(
+        &self,
+        module: &mut IdlModule,
+        scope: &mut Vec<String>,
+    ) -> Result<(), String> {
+        if let Some(ref id) = module.id {
+            let location = format!("module {id}");
+            self.resolve(&mut module.annotations, Target::Module, &location, scope)?;
+        }
+        for (_, cnst) in module.constants.iter_mut() {
+            let location = format!("const {}", cnst.id);
+            self.resolve(&mut cnst.annotations, Target::Const, &location, scope)?;
+        }
+        for (_, typ) in module.types.iter_mut() {
+            let IdlTypeDcl(ref mut kind, ref mut annotations) = typ;
+            match kind {
+                IdlTypeDclKind::TypeDcl(id, _) => {
+                    let location = format!("typedef {id}");
+                    self.resolve(annotations, Target::Typedef, &location, scope)?;
+                }
+                IdlTypeDclKind::StructDcl(id, _, members) => {
+                    let location = format!("struct {id}");
+                    self.resolve(annotations, Target::Struct, &location, scope)?;
+                    for member in members.iter_mut() {
+                        let location = format!("member {} of struct {id}", member.id);
+                        self.resolve(&mut member.annotations, Target::Member, &location, scope)?;
+                    }
+                }
+                IdlTypeDclKind::UnionDcl(id, _, switch_cases) => {
+                    let location = format!("union {id}");
+                    self.resolve(annotations, Target::Union, &location, scope)?;
+                    for case in switch_cases.iter_mut() {
+                        let element = &mut case.elem_spec;
+                        let location = format!("member {} of union {id}", element.id);
+                        self.resolve(
+                            &mut element.annotations,
+                            Target::UnionMember,
+                            &location,
+                            scope,
+                        )?;
+                    }
+                }
+                IdlTypeDclKind::EnumDcl(id, enumerators) => {
+                    let location = format!("enum {id}");
+                    self.resolve(annotations, Target::Enum, &location, scope)?;
+                    for enumerator in enumerators.iter_mut() {
+                        let location = format!("enumerator {} of enum {id}", enumerator.id);
+                        self.resolve(
+                            &mut enumerator.annotations,
+                            Target::Enumerator,
+                            &location,
+                            scope,
+                        )?;
+                    }
+                }
+                IdlTypeDclKind::None => {}
+            }
+        }
+        for (id, submodule) in module.modules.iter_mut() {
+            scope.push(id.clone());
+            self.resolve_module(submodule, scope)?;
+            scope.pop();
+        }
+        Ok(())
+    }
+
+    /// Evaluate, name and validate the parameters of the annotations of one element
+    fn resolve(
+        &self,
+        annotations: &mut IdlAnnotations,
+        target: Target,
+        location: &str,
+        scope: &[String],
+    ) -> Result<(), String> {
+        for annotation in annotations.0.iter_mut() {
+            let id = annotation.id().to_owned();
+            for param in annotation.params.iter_mut() {
+                let value = self
+                    .constants
+                    .evaluate(&param.expr, scope)
+                    .map_err(|e| format!("@{id} on {location}: {e}"))?;
+                param.value = Some(value);
+            }
+
+            let members = match (builtin(&id), self.declarations.get(&id)) {
+                (Some((members, targets)), _) => {
+                    if !targets.contains(&target) {
+                        return Err(format!("@{id} cannot be applied to {location}"));
+                    }
+                    members
+                }
+                (None, Some(declaration)) => declaration.members.clone(),
+                // unknown annotations are kept for custom mappings but not validated
+                (None, None) => {
+                    for param in annotation.params.iter_mut() {
+                        param.name.get_or_insert_with(|| "value".to_owned());
+                    }
+                    self.check_mapping(annotation, target, location)?;
+                    continue;
+                }
+            };
+            Self::check_params(annotation, &members)
+                .map_err(|e| format!("@{id} on {location}: {e}"))?;
+            if builtin(&id).is_none() {
+                self.check_mapping(annotation, target, location)?;
+            }
+
+            if self.verbose {
+                let params = annotation
+                    .params
+                    .iter()
+                    .map(|param| {
+                        format!(
+                            "{}={}",
+                            param.name.as_deref().unwrap_or_default(),
+                            param
+                                .value
+                                .as_ref()
+                                .map(|v| v.to_string())
+                                .unwrap_or_default()
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                println!(
+                    "{:indent$}@{id}({}) on {location}",
+                    "",
+                    params.join(", "),
+                    indent = 3 * scope.len()
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Verify the configured mapping of a user-defined annotation can be expanded and
+    /// derives are only added to structs, unions and enums
+    fn check_mapping(
+        &self,
+        annotation: &IdlAnnotation,
+        target: Target,
+        location: &str,
+    ) -> Result<(), String> {
+        let id = annotation.id();
+        let Some(mapping) = self.mappings.get(id) else {
+            return Ok(());
+        };
+        if matches!(mapping, AnnotationMapping::Derive(_))
+            && !matches!(target, Target::Struct | Target::Union | Target::Enum)
+        {
+            return Err(format!(
+                "@{id} adds derives and cannot be applied to {location}"
+            ));
+        }
+        mapping
+            .expand(annotation)
+            .map(|_| ())
+            .map_err(|e| format!("@{id} on {location}: {e}"))
+    }
+
+    /// Name positional parameters, check the parameter values and add missing
+    /// parameters from their defaults.
+    fn check_params(
+        annotation: &mut IdlAnnotation,
+        members: &[IdlAnnotationMember],
+    ) -> Result<(), String> {
+        let mut seen = Vec::new();
+        for param in annotation.params.iter_mut() {
+            if param.name.is_none() {
+                let positional = match members {
+                    [single] => Some(&single.name),
+                    _ => members
+                        .iter()
+                        .find(|member| member.name == "value")
+                        .map(|member| &member.name),
+                };
+                match positional {
+                    Some(name) => param.name = Some(name.clone()),
+                    None => return Err("parameters must be named".to_owned()),
+                }
+            }
+            let name = param.name.clone().unwrap_or_default();
+            let member = members
+                .iter()
+                .find(|member| member.name == name)
+                .ok_or_else(|| format!("unknown parameter '{name}'"))?;
+            if seen.contains(&name) {
+                return Err(format!("parameter '{name}' is set more than once"));
+            }
+            if let Some(value) = param.value.take() {
+                let value = member
+                    .kind
+                    .check(value)
+                    .map_err(|e| format!("parameter '{name}': {e}"))?;
+                param.value = Some(value);
+            }
+            seen.push(name);
+        }
+
+        for member in members {
+            if seen.contains(&member.name) {
+                continue;
+            }
+            match member.default {
+                Some(ref default) => annotation.params.push(IdlAnnotationParam {
+                    name: Some(member.name.clone()),
+                    expr: IdlValueExpr::None,
+                    value: Some(default.clone()),
+                }),
+                None => return Err(format!("missing parameter '{}'", member.name)),
+            }
+        }
+        Ok(())
+    }
+}
@@ -2,9 +2,33 @@
 //
 // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
 // http://www.apache.org/licenses/LICENSE-2.0>
+use crate::{
+    annotation::AnnotationMapping,
+    bounds::BoundsSupport,
+    bytes::{self, ByteSupport},
+    cdr::{self, Discriminator, IdlCdrBranch, IdlCdrMember},
+    derive::{Derive, DeriveSupport},
+    extensibility::{Extensibility, ExtensibilitySupport},
+    index::{relative_path, ConstIndex, TypeIndex},
+    key::{KeyEncoding, KeySupport},
+    layout::LayoutSupport,
+    member_id::{member_id_const, MemberIdSupport},
+    naming,
+    newtype::NewtypeSupport,
+    parameter_list::ParameterListSupport,
+    reflect,
+    repository_id::RepositoryIdSupport,
+    type_object::TypeObjectSupport,
+    view::ViewSupport,
+    ByteMapping, Configuration, SerdeSupport, WideCharMapping,
+};
 use linked_hash_map::LinkedHashMap;
 use serde_derive::Serialize;
-use std::{collections::HashSet, fmt};
+use std::{
+    cmp::Ordering,
+    collections::{BTreeSet, HashMap},
+    fmt,
+};
 
 const INDENTION: usize = 4;
 const IMPORT_VEC: &str = "use std::vec::Vec;";
@@ -69,21 +93,35 @@ impl BinaryOp {
 #[derive(Clone, Debug)]
 pub struct IdlScopedName(pub Vec<String>, pub bool);
 
-impl fmt::Display for IdlScopedName {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+impl IdlScopedName {
+    /// Render the name as Rust path, the last component (the named item itself) is
+    /// converted via `rename`, leading components are modules and kept as they are.
+    pub fn to_rust(&self, rename: impl Fn(&str) -> String) -> String {
         let is_absolute_path = self.1;
         let components = &self.0;
+        let mut path = String::new();
         for (idx, comp) in components.iter().enumerate() {
+            let comp = if idx + 1 == components.len() {
+                rename(comp)
+            } else {
+                comp.to_owned()
+            };
             // TODO, use paths according to "crate::" or "super::"
             if idx == 0 && !is_absolute_path {
-                write!(f, "{comp}")?
+                path.push_str(&comp)
             } else if idx == 0 && is_absolute_path {
-                write!(f, "crate::{comp}")?
+                path.push_str(&format!("crate::{comp}"))
             } else {
-                write!(f, "::{comp}")?
+                path.push_str(&format!("::{comp}"))
             }
         }
-        Ok(())
+        path
+    }
+}
+
+impl fmt::Display for IdlScopedName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_rust(|comp| comp.to_owned()))
     }
 }
 
@@ -129,12 +167,12 @@ impl fmt::Display for IdlValueExpr {
             IdlValueExpr::BinaryOp(op, expr) => &format!("{}{}", op.to_str(), expr),
             IdlValueExpr::Expr(expr1, expr2) => &format!("{}{}", expr1, expr2),
             IdlValueExpr::Brace(expr) => &format!("({})", expr),
-            IdlValueExpr::FloatLiteral(integral, fraction, exponent, suffix) => &format!(
-                "{}.{}e{}{}",
-                integral.as_ref().unwrap().clone(),
-                fraction.as_ref().unwrap().clone(),
-                exponent.as_ref().unwrap().clone(),
-                suffix.as_ref().unwrap().clone()
+            // the exponent keeps its 'e', the IDL suffix (f/d) has no Rust equivalent
+            IdlValueExpr::FloatLiteral(integral, fraction, exponent, _suffix) => &format!(
+                "{}.{}{}",
+                integral.as_deref().unwrap_or("0"),
+                fraction.as_deref().unwrap_or("0"),
+                exponent.as_deref().unwrap_or_default()
             ),
             IdlValueExpr::ScopedName(name) => &name.to_string(),
         };
@@ -142,11 +180,404 @@ impl fmt::Display for IdlValueExpr {
     }
 }
 
+impl IdlValueExpr {
+    /// Convert the expression into Rust, referenced constants are named according to the
+    /// configuration.
+    pub fn to_rust(&self, config: &Configuration) -> String {
+        self.to_rust_with(config, &|_| String::new())
+    }
+
+    /// Same as `to_rust`, `access` gives the suffix reading the value of a referenced
+    /// constant, i.e. `.0` for a constant of a newtype
+    fn to_rust_with(
+        &self,
+        config: &Configuration,
+        access: &dyn Fn(&IdlScopedName) -> String,
+    ) -> String {
+        match self {
+            IdlValueExpr::UnaryOp(op, expr) => {
+                format!("{}{}", op.to_str(), expr.to_rust_with(config, access))
+            }
+            IdlValueExpr::BinaryOp(op, expr) => {
+                format!("{}{}", op.to_str(), expr.to_rust_with(config, access))
+            }
+            IdlValueExpr::Expr(expr1, expr2) => format!(
+                "{}{}",
+                expr1.to_rust_with(config, access),
+                expr2.to_rust_with(config, access)
+            ),
+            IdlValueExpr::Brace(expr) => format!("({})", expr.to_rust_with(config, access)),
+            IdlValueExpr::ScopedName(name) => {
+                format!(
+                    "{}{}",
+                    name.to_rust(|id| config.const_name(id)),
+                    access(name)
+                )
+            }
+            // Rust has no wide literals, `L"text"` => `"text"`
+            IdlValueExpr::WideCharLiteral(val) | IdlValueExpr::WideStringLiteral(val) => {
+                val.strip_prefix('L').unwrap_or(val).to_owned()
+            }
+            _ => self.to_string(),
+        }
+    }
+
+    /// Convert a size into a const generic argument, anything but a literal is a block
+    fn to_rust_const_arg(&self, config: &Configuration) -> String {
+        match self {
+            IdlValueExpr::DecLiteral(_)
+            | IdlValueExpr::HexLiteral(_)
+            | IdlValueExpr::OctLiteral(_) => self.to_rust(config),
+            _ => format!("{{ {} as usize }}", self.to_rust(config)),
+        }
+    }
+}
+
+/// Evaluated value of a constant expression
+#[derive(Clone, Debug, PartialEq)]
+pub enum IdlConstValue {
+    Integer(i128),
+    Float(f64),
+    Boolean(bool),
+    Char(char),
+    String(String),
+    /// A name which does not refer to a constant, i.e. an enumerator
+    Enumerator(String),
+}
+
+impl fmt::Display for IdlConstValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IdlConstValue::Integer(val) => write!(f, "{val}"),
+            IdlConstValue::Float(val) => write!(f, "{val:?}"),
+            IdlConstValue::Boolean(val) => write!(f, "{}", if *val { "TRUE" } else { "FALSE" }),
+            IdlConstValue::Char(val) => write!(f, "{val:?}"),
+            IdlConstValue::String(val) => write!(f, "{val:?}"),
+            IdlConstValue::Enumerator(val) => write!(f, "{val}"),
+        }
+    }
+}
+
+impl BinaryOp {
+    /// Binding strength of the operator, IDL uses the precedence of C
+    fn precedence(&self) -> u8 {
+        match self {
+            BinaryOp::Or => 1,
+            BinaryOp::Xor => 2,
+            BinaryOp::And => 3,
+            BinaryOp::LShift | BinaryOp::RShift => 4,
+            BinaryOp::Add | BinaryOp::Sub => 5,
+            BinaryOp::Mul | BinaryOp::Div | BinaryOp::Mod => 6,
+        }
+    }
+
+    /// Apply the operator onto two evaluated values
+    fn apply(&self, lhs: IdlConstValue, rhs: IdlConstValue) -> Result<IdlConstValue, String> {
+        let overflow = || format!("Overflow evaluating {lhs} {} {rhs}", self.to_str());
+        match (&lhs, &rhs) {
+            (IdlConstValue::Integer(l), IdlConstValue::Integer(r)) => {
+                let (l, r) = (*l, *r);
+                let result = match self {
+                    BinaryOp::Add => l.checked_add(r),
+                    BinaryOp::Sub => l.checked_sub(r),
+                    BinaryOp::Mul => l.checked_mul(r),
+                    BinaryOp::Div => l.checked_div(r),
+                    BinaryOp::Mod => l.checked_rem(r),
+                    BinaryOp::LShift => u32::try_from(r).ok().and_then(|r| l.checked_shl(r)),
+                    BinaryOp::RShift => u32::try_from(r).ok().and_then(|r| l.checked_shr(r)),
+                    BinaryOp::Or => Some(l | r),
+                    BinaryOp::Xor => Some(l ^ r),
+                    BinaryOp::And => Some(l & r),
+                };
+                result.map(IdlConstValue::Integer).ok_or_else(overflow)
+            }
+            (
+                IdlConstValue::Integer(_) | IdlConstValue::Float(_),
+                IdlConstValue::Integer(_) | IdlConstValue::Float(_),
+            ) => {
+                let as_float = |value: &IdlConstValue| match value {
+                    IdlConstValue::Integer(val) => *val as f64,
+                    IdlConstValue::Float(val) => *val,
+                    _ => unreachable!(),
+                };
+                let (l, r) = (as_float(&lhs), as_float(&rhs));
+                match self {
+                    BinaryOp::Add => Ok(IdlConstValue::Float(l + r)),
+                    BinaryOp::Sub => Ok(IdlConstValue::Float(l - r)),
+                    BinaryOp::Mul => Ok(IdlConstValue::Float(l * r)),
+                    BinaryOp::Div => Ok(IdlConstValue::Float(l / r)),
+                    _ => Err(format!(
+                        "Operator '{}' is not defined for floating point values",
+                        self.to_str()
+                    )),
+                }
+            }
+            _ => Err(format!(
+                "Operator '{}' is not defined for {lhs} and {rhs}",
+                self.to_str()
+            )),
+        }
+    }
+}
+
+/// Resolve the escape sequences of a character or string literal
+fn unescape(literal: &str) -> Result<String, String> {
+    let mut result = String::new();
+    let mut chars = literal.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        let escaped = match chars.next() {
+            Some('n') => '\n',
+            Some('t') => '\t',
+            Some('v') => '\x0b',
+            Some('b') => '\x08',
+            Some('r') => '\r',
+            Some('f') => '\x0c',
+            Some('a') => '\x07',
+            Some(c @ ('\\' | '?' | '\'' | '"')) => c,
+            Some(c @ ('x' | 'u' | '0'..='7')) => {
+                let (radix, max_digits, mut digits) = match c {
+                    'x' => (16, 2, String::new()),
+                    'u' => (16, 4, String::new()),
+                    _ => (8, 3, c.to_string()),
+                };
+                while digits.len() < max_digits {
+                    match chars.peek() {
+                        Some(d) if d.is_digit(radix) => digits.push(chars.next().unwrap()),
+                        _ => break,
+                    }
+                }
+                u32::from_str_radix(&digits, radix)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| format!("Invalid escape sequence in {literal}"))?
+            }
+            _ => return Err(format!("Invalid escape sequence in {literal}")),
+        };
+        result.push(escaped);
+    }
+    Ok(result)
+}
+
+/// Strip the wide prefix and the quotes from a literal and resolve its escape sequences
+pub fn literal_content(literal: &str, quote: char) -> Result<String, String> {
+    literal
+        .trim_start_matches('L')
+        .strip_prefix(quote)
+        .and_then(|content| content.strip_suffix(quote))
+        .ok_or_else(|| format!("Malformed literal {literal}"))
+        .and_then(unescape)
+}
+
+impl IdlValueExpr {
+    /// Evaluate the expression. `lookup` resolves a scoped name onto the value of the
+    /// referenced constant, names not referring to a constant evaluate to an enumerator.
+    pub fn evaluate(
+        &self,
+        lookup: &dyn Fn(&IdlScopedName) -> Result<Option<IdlConstValue>, String>,
+    ) -> Result<IdlConstValue, String> {
+        match self {
+            IdlValueExpr::None => Err("Missing expression".to_owned()),
+            IdlValueExpr::DecLiteral(val) => val
+                .parse()
+                .map(IdlConstValue::Integer)
+                .map_err(|e| format!("Invalid integer {val}: {e}")),
+            IdlValueExpr::HexLiteral(val) => i128::from_str_radix(&val[2..], 16)
+                .map(IdlConstValue::Integer)
+                .map_err(|e| format!("Invalid integer {val}: {e}")),
+            IdlValueExpr::OctLiteral(val) => i128::from_str_radix(&val[1..], 8)
+                .map(IdlConstValue::Integer)
+                .map_err(|e| format!("Invalid integer {val}: {e}")),
+            IdlValueExpr::CharLiteral(val) | IdlValueExpr::WideCharLiteral(val) => {
+                let content = literal_content(val, '\'')?;
+                let mut chars = content.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Ok(IdlConstValue::Char(c)),
+                    _ => Err(format!("Invalid character literal {val}")),
+                }
+            }
+            IdlValueExpr::StringLiteral(val) | IdlValueExpr::WideStringLiteral(val) => {
+                literal_content(val, '"').map(IdlConstValue::String)
+            }
+            IdlValueExpr::BooleanLiteral(val) => Ok(IdlConstValue::Boolean(*val)),
+            IdlValueExpr::FloatLiteral(integral, fraction, exponent, _suffix) => {
+                let text = format!(
+                    "{}.{}{}",
+                    integral.as_deref().unwrap_or("0"),
+                    fraction.as_deref().unwrap_or("0"),
+                    exponent.as_deref().unwrap_or_default()
+                );
+                text.parse()
+                    .map(IdlConstValue::Float)
+                    .map_err(|e| format!("Invalid floating point number {text}: {e}"))
+            }
+            IdlValueExpr::UnaryOp(op, expr) => match (op, expr.evaluate(lookup)?) {
+                (UnaryOp::Pos, value @ (IdlConstValue::Integer(_) | IdlConstValue::Float(_))) => {
+                    Ok(value)
+                }
+                (UnaryOp::Neg, IdlConstValue::Integer(val)) => Ok(IdlConstValue::Integer(-val)),
+                (UnaryOp::Neg, IdlConstValue::Float(val)) => Ok(IdlConstValue::Float(-val)),
+                (UnaryOp::Inverse, IdlConstValue::Integer(val)) => Ok(IdlConstValue::Integer(!val)),
+                (op, value) => Err(format!(
+                    "Operator '{}' is not defined for {value}",
+                    op.to_str()
+                )),
+            },
+            IdlValueExpr::Brace(expr) => expr.evaluate(lookup),
+            IdlValueExpr::ScopedName(name) => Ok(lookup(name)?.unwrap_or_else(|| {
+                IdlConstValue::Enumerator(name.0.last().cloned().unwrap_or_default())
+            })),
+            IdlValueExpr::BinaryOp(_, _) | IdlValueExpr::Expr(_, _) => {
+                // The parser nests binary operations to the right, i.e. `a - b - c` is read
+                // as `a - (b - c)`. Flatten the chain and apply the operator precedence.
+                let mut operands = Vec::new();
+                let mut operators = Vec::new();
+                self.flatten(&mut operands, &mut operators)?;
+                let mut values = vec![operands[0].evaluate(lookup)?];
+                let mut pending: Vec<&BinaryOp> = Vec::new();
+                for (op, operand) in operators.into_iter().zip(&operands[1..]) {
+                    while let Some(top) = pending.last() {
+                        if top.precedence() < op.precedence() {
+                            break;
+                        }
+                        let rhs = values.pop().unwrap();
+                        let lhs = values.pop().unwrap();
+                        values.push(pending.pop().unwrap().apply(lhs, rhs)?);
+                    }
+                    pending.push(op);
+                    values.push(operand.evaluate(lookup)?);
+                }
+                while let Some(op) = pending.pop() {
+                    let rhs = values.pop().unwrap();
+                    let lhs = values.pop().unwrap();
+                    values.push(op.apply(lhs, rhs)?);
+                }
+                Ok(values.pop().unwrap())
+            }
+        }
+    }
+
+    /// Collect the operands and operators of a chain of binary operations
+    fn flatten<'a>(
+        &'a self,
+        operands: &mut Vec<&'a IdlValueExpr>,
+        operators: &mut Vec<&'a BinaryOp>,
+    ) -> Result<(), String> {
+        match self {
+            IdlValueExpr::Expr(lhs, rest) => {
+                operands.push(lhs);
+                match rest.as_ref() {
+                    IdlValueExpr::BinaryOp(op, rhs) => {
+                        operators.push(op);
+                        rhs.flatten(operands, operators)
+                    }
+                    _ => Err(format!("Malformed expression {self}")),
+                }
+            }
+            IdlValueExpr::BinaryOp(_, _) => Err(format!("Missing left operand in {self}")),
+            _ => {
+                operands.push(self);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// A parameter of an annotation application, i.e. `min = 0`. Positional parameters are
+/// named once the annotation is resolved.
+#[derive(Clone, Debug)]
+pub struct IdlAnnotationParam {
+    pub name: Option<String>,
+    pub expr: IdlValueExpr,
+    pub value: Option<IdlConstValue>,
+}
+
+/// Representation of an annotation application, i.e. `@range(min = 0, max = 10)`
+#[derive(Clone, Debug)]
+pub struct IdlAnnotation {
+    pub name: IdlScopedName,
+    pub params: Vec<IdlAnnotationParam>,
+}
+
+impl IdlAnnotation {
+    /// Name of the annotation without its scope
+    pub fn id(&self) -> &str {
+        self.name.0.last().map(String::as_str).unwrap_or_default()
+    }
+
+    /// Value of a named parameter, available once the annotation is resolved
+    pub fn param(&self, name: &str) -> Option<&IdlConstValue> {
+        self.params
+            .iter()
+            .find(|param| param.name.as_deref() == Some(name))
+            .and_then(|param| param.value.as_ref())
+    }
+}
+
+/// All annotations applied to a single element, in order of appearance
+#[derive(Clone, Debug, Default)]
+pub struct IdlAnnotations(pub Vec<IdlAnnotation>);
+
+impl IdlAnnotations {
+    /// The last application of the annotation, later applications override earlier ones
+    pub fn get(&self, id: &str) -> Option<&IdlAnnotation> {
+        self.0.iter().rev().find(|annotation| annotation.id() == id)
+    }
+
+    /// Whether a boolean annotation like `@optional` or `@optional(TRUE)` is applied
+    pub fn is_set(&self, id: &str) -> bool {
+        self.get(id).is_some_and(|annotation| {
+            !annotation
+                .params
+                .iter()
+                .any(|param| param.value == Some(IdlConstValue::Boolean(false)))
+        })
+    }
+}
+
+/// A `#pragma` directive together with the scope it appears in, the text excludes the
+/// leading `#pragma`, i.e. `DCPS_DATA_KEY "DDS::Foo id"`
+#[derive(Clone, Debug)]
+pub struct IdlPragma {
+    pub scope: Vec<String>,
+    pub text: String,
+}
+
+/// A `#pragma prefix` in effect for the definitions following it, the repository IDs of
+/// these definitions name them relative to the `scope` the pragma appears in
+#[derive(Clone, Debug)]
+pub struct IdlPrefix {
+    pub scope: Vec<String>,
+    pub prefix: String,
+}
+
+/// Kind of a `typeid` or `typeprefix` declaration
+#[derive(Clone, Debug)]
+pub enum IdlRepositoryIdKind {
+    /// `typeid`, the repository ID of the named type
+    TypeId(String),
+    /// `typeprefix`, the prefix of the repository IDs of the named scope and all
+    /// definitions within
+    TypePrefix(String),
+}
+
+/// A `typeid` or `typeprefix` declaration together with the scope it appears in
+#[derive(Clone, Debug)]
+pub struct IdlRepositoryIdDcl {
+    pub scope: Vec<String>,
+    pub name: IdlScopedName,
+    pub kind: IdlRepositoryIdKind,
+}
+
 /// Representation of an IDL Struct
 #[derive(Clone, Debug)]
 pub struct IdlStructMember {
     pub id: String,
     pub type_spec: IdlTypeSpec,
+    pub annotations: IdlAnnotations,
 }
 
 /// Representation of an IDL Switch
@@ -154,6 +585,7 @@ pub struct IdlStructMember {
 pub struct IdlSwitchElement {
     pub id: String,
     pub type_spec: IdlTypeSpec,
+    pub annotations: IdlAnnotations,
 }
 
 /// Representation of an IDL Switch Label
@@ -176,7 +608,7 @@ pub enum IdlTypeSpec {
     #[default]
     None,
     ArrayType(Box<IdlTypeSpec>, Vec<IdlValueExpr>),
-    SequenceType(Box<IdlTypeSpec>),
+    SequenceType(Box<IdlTypeSpec>, Option<Box<IdlValueExpr>>),
     StringType(Option<Box<IdlValueExpr>>),
     WideStringType(Option<Box<IdlValueExpr>>),
     // FixedPtType,
@@ -202,51 +634,98 @@ pub enum IdlTypeSpec {
     ScopedName(IdlScopedName),
 }
 
-impl fmt::Display for IdlTypeSpec {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let value_expr = match self {
+impl IdlTypeSpec {
+    /// Convert the type into its Rust representation. Referenced types and constants
+    /// are named according to the configuration.
+    pub fn to_rust(&self, config: &Configuration) -> Result<String, fmt::Error> {
+        match self {
             IdlTypeSpec::F32Type => Ok("f32".to_string()),
             IdlTypeSpec::F64Type => Ok("f64".to_string()),
-            IdlTypeSpec::F128Type => Ok("f128".to_string()),
+            IdlTypeSpec::F128Type => Ok(config.f128_type().to_string()),
             IdlTypeSpec::I16Type => Ok("i16".to_string()),
             IdlTypeSpec::I32Type => Ok("i32".to_string()),
             IdlTypeSpec::I64Type => Ok("i64".to_string()),
             IdlTypeSpec::U16Type => Ok("u16".to_string()),
             IdlTypeSpec::U32Type => Ok("u32".to_string()),
             IdlTypeSpec::U64Type => Ok("u64".to_string()),
-            IdlTypeSpec::CharType => Ok("char".to_string()),
-            IdlTypeSpec::WideCharType => Ok("char".to_string()),
+            IdlTypeSpec::CharType => Ok(config.char_type().to_string()),
+            IdlTypeSpec::WideCharType => Ok(config.wide_char_type().to_string()),
             IdlTypeSpec::BooleanType => Ok("bool".to_string()),
             IdlTypeSpec::OctetType => Ok("u8".to_string()),
-            IdlTypeSpec::StringType(None) => Ok("String".to_string()),
-            IdlTypeSpec::WideStringType(None) => Ok("String".to_string()),
+            IdlTypeSpec::WideStringType(_)
+                if config.wide_char_mapping == WideCharMapping::Utf16 =>
+            {
+                Ok("omg_idl_rt::WString".to_string())
+            }
+            IdlTypeSpec::StringType(None) => Ok(config.string_path().to_string()),
+            IdlTypeSpec::WideStringType(None) => Ok(config.string_path().to_string()),
+            IdlTypeSpec::StringType(Some(bound)) if config.heapless => Ok(format!(
+                "heapless::String<{}>",
+                bound.to_rust_const_arg(config)
+            )),
             // TODO implement String/Sequence bounds
-            IdlTypeSpec::StringType(_) => Ok("String".to_string()),
+            IdlTypeSpec::StringType(_) => Ok(config.string_path().to_string()),
             // TODO implement String/Sequence bounds for serializer and deserialzer
-            IdlTypeSpec::WideStringType(_) => Ok("String".to_string()),
-            IdlTypeSpec::SequenceType(typ_expr) => Ok(format!("Vec<{}>", typ_expr.as_ref())),
+            IdlTypeSpec::WideStringType(_) => Ok(config.string_path().to_string()),
+            IdlTypeSpec::SequenceType(typ_expr, Some(bound)) if config.heapless => Ok(format!(
+                "heapless::Vec<{}, {}>",
+                typ_expr.to_rust(config)?,
+                bound.to_rust_const_arg(config)
+            )),
+            IdlTypeSpec::SequenceType(typ_expr, _)
+                if matches!(**typ_expr, IdlTypeSpec::OctetType) =>
+            {
+                Ok(config.byte_sequence_type(config.byte_mapping))
+            }
+            IdlTypeSpec::SequenceType(typ_expr, _) => Ok(format!(
+                "{}<{}>",
+                config.vec_path(),
+                typ_expr.to_rust(config)?
+            )),
+            // the first dimension is the outermost, `long a[2][3]` => `[[i32; 3]; 2]`
             IdlTypeSpec::ArrayType(typ_expr, dim_expr_list) => {
                 let dim_list_str = dim_expr_list
                     .iter()
+                    .rev()
                     .map(|expr| match expr {
                         IdlValueExpr::DecLiteral(_)
                         | IdlValueExpr::HexLiteral(_)
                         | IdlValueExpr::OctLiteral(_)
                         | IdlValueExpr::Expr(_, _)
-                        | IdlValueExpr::BinaryOp(_, _) => Ok(format!(";{}_usize]", expr)),
-                        IdlValueExpr::ScopedName(_) => Ok(format!(";{} as usize]", expr)),
+                        | IdlValueExpr::BinaryOp(_, _) => {
+                            Ok(format!(";{}_usize]", expr.to_rust(config)))
+                        }
+                        IdlValueExpr::ScopedName(_) => {
+                            Ok(format!(";{} as usize]", expr.to_rust(config)))
+                        }
                         _ => Err(fmt::Error),
                     })
                     .collect::<Result<String, fmt::Error>>()?;
                 Ok(format!(
                     "{}{}{dim_list_str}",
                     "[".repeat(dim_expr_list.len()),
-                    typ_expr
+                    typ_expr.to_rust(config)?
                 ))
             }
-            IdlTypeSpec::ScopedName(name) => Ok(name.to_string()),
+            IdlTypeSpec::ScopedName(name) => Ok(name.to_rust(|id| config.type_name(id))),
             _ => unimplemented!(),
-        }?;
+        }
+    }
+
+    /// Same as `to_rust` but reported as render error
+    fn to_rust_type(&self, config: &Configuration) -> Result<String, minijinja::Error> {
+        self.to_rust(config).map_err(|_| {
+            minijinja::Error::new(
+                minijinja::ErrorKind::InvalidOperation,
+                format!("Failed to map IDL type {self:?} onto a Rust type"),
+            )
+        })
+    }
+}
+
+impl fmt::Display for IdlTypeSpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value_expr = self.to_rust(&Configuration::default())?;
         write!(f, "{value_expr}")
     }
 }
@@ -257,84 +736,975 @@ pub enum IdlTypeDclKind {
     #[default]
     None,
     TypeDcl(String, IdlTypeSpec),
-    StructDcl(String, Vec<IdlStructMember>),
+    /// Identifier, base struct and members
+    StructDcl(String, Option<IdlScopedName>, Vec<IdlStructMember>),
     UnionDcl(String, IdlTypeSpec, Vec<IdlSwitchCase>),
-    EnumDcl(String, Vec<String>),
+    EnumDcl(String, Vec<IdlEnumerator>),
+}
+
+/// Representation of an IDL Enumerator
+#[derive(Clone, Debug)]
+pub struct IdlEnumerator {
+    pub id: String,
+    pub annotations: IdlAnnotations,
 }
 
 /// Representation of an IDL Type
 #[derive(Clone, Debug, Default)]
-pub struct IdlTypeDcl(pub IdlTypeDclKind);
+pub struct IdlTypeDcl(pub IdlTypeDclKind, pub IdlAnnotations);
 
 /// Data storage to align with Jinja (IdlStruct)
 #[derive(Serialize)]
 struct IdlStructField {
     name: String,
+    idl_name: String,
     type_str: String,
-    directive: String,
+    value_type: String,
+    optional: bool,
+    /// A builder fails unless the member is set
+    required: bool,
+    directives: Vec<String>,
+    after: Vec<String>,
+    default: String,
+    bounds: Vec<IdlBound>,
+}
+
+/// Data storage to align with Jinja (IdlStruct), a `@range`, `@min` or `@max` bound
+#[derive(Serialize)]
+struct IdlBound {
+    bound: &'static str,
+    operator: &'static str,
+    literal: String,
+    limit: String,
 }
 
 /// Data storage to align with Jinja (IdlSwitch)
 #[derive(Serialize)]
 struct IdlSwitchField {
     name: String,
+    directives: Vec<String>,
+    after: Vec<String>,
     element_id: String,
+    element_directive: String,
     element_type: String,
 }
 
+/// Data storage to align with Jinja (IdlEnum)
+#[derive(Serialize)]
+struct IdlEnumVariant {
+    name: String,
+    idl_name: String,
+    directives: Vec<String>,
+    after: Vec<String>,
+    default: bool,
+    /// Explicit discriminant of enums with a C layout
+    value: Option<String>,
+}
+
+/// Member holding the base of a derived struct, its members are flattened by serde
+pub const BASE_MEMBER: &str = "base";
+
+/// Serde arguments of `@optional` members, absent members are omitted on the wire
+const SERDE_OPTIONAL: &str = "default, skip_serializing_if = \"Option::is_none\"";
+
+/// Serde attribute of a struct member or union element of an array type. Octet arrays
+/// are byte strings, serde implements other arrays up to 32 elements only. serde_arrays
+/// deserializes one dimension and does not support `Option<[T; N]>`, optional arrays
+/// are handled by omg-idl-rt.
+fn serde_array(
+    ctx: &RenderContext,
+    name: &[String],
+    spec: &IdlTypeSpec,
+    scope: &[String],
+    optional: bool,
+) -> Option<String> {
+    let config = ctx.config;
+    if ctx
+        .bytes
+        .is_byte_array(ctx.types, ctx.newtypes, name, spec, scope)
+    {
+        return config.serde_attribute("with = \"serde_bytes\"");
+    }
+    match ctx.newtypes.unalias(ctx.types, spec, scope) {
+        (IdlTypeSpec::ArrayType(_, ref dims), _) if dims.len() == 1 => {
+            config.serde_attribute(if optional {
+                "with = \"omg_idl_rt::serde_option_array\""
+            } else {
+                "with = \"serde_arrays\""
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Data storage to align with Jinja, the text an element gains from `@doc`, `@unit`,
+/// `@deprecated`, `@verbatim` and user-defined annotations mapped by the configuration
+#[derive(Debug, Default, Serialize)]
+struct IdlItemText {
+    /// Rustdoc, `#[deprecated]` and verbatim text placed before the declaration
+    before: Vec<String>,
+    /// Mapped attributes, placed after the derives of types
+    attributes: Vec<String>,
+    /// Mapped derives of types
+    derives: Vec<String>,
+    /// Verbatim text at the beginning of the declaration body
+    begin: Vec<String>,
+    /// Verbatim text at the end of the declaration body
+    end: Vec<String>,
+    /// Verbatim text placed after the declaration
+    after: Vec<String>,
+    deprecated: bool,
+}
+
+impl IdlItemText {
+    fn new(annotations: &IdlAnnotations, config: &Configuration) -> Self {
+        let mut text = IdlItemText {
+            attributes: AnnotationMapping::attributes(&config.annotation_mappings, annotations),
+            derives: AnnotationMapping::derives(&config.annotation_mappings, annotations),
+            ..Default::default()
+        };
+        let string_param = |annotation: &IdlAnnotation, name: &str| match annotation.param(name) {
+            Some(IdlConstValue::String(value)) => value.clone(),
+            _ => String::new(),
+        };
+        if let Some(doc) = annotations.get("doc") {
+            text.before.extend(
+                string_param(doc, "value")
+                    .lines()
+                    .map(|line| format!("/// {line}")),
+            );
+        }
+        if let Some(unit) = annotations.get("unit") {
+            if !text.before.is_empty() {
+                text.before.push("///".to_owned());
+            }
+            text.before
+                .push(format!("/// Unit: {}", string_param(unit, "value")));
+        }
+        if let Some(deprecated) = annotations.get("deprecated") {
+            text.deprecated = true;
+            text.before.push(match string_param(deprecated, "value") {
+                note if note.is_empty() => "#[deprecated]".to_owned(),
+                note => format!("#[deprecated(note = {note:?})]"),
+            });
+        }
+        for verbatim in annotations.0.iter().filter(|a| a.id() == "verbatim") {
+            if !matches!(string_param(verbatim, "language").as_str(), "rust" | "*") {
+                continue;
+            }
+            let lines = match verbatim.param("placement") {
+                Some(IdlConstValue::Enumerator(placement)) => match placement.as_str() {
+                    "BEGIN_DECLARATION" => &mut text.begin,
+                    "END_DECLARATION" => &mut text.end,
+                    "AFTER_DECLARATION" => &mut text.after,
+                    // placed by the root module
+                    "BEGIN_FILE" | "END_FILE" => continue,
+                    _ => &mut text.before,
+                },
+                _ => &mut text.before,
+            };
+            lines.extend(string_param(verbatim, "text").lines().map(str::to_owned));
+        }
+        text
+    }
+
+    /// Elements without a body place the text of its beginning and end before and
+    /// after themselves, mapped attributes go before them too
+    fn without_body(mut self) -> Self {
+        self.before.append(&mut self.attributes);
+        self.before.append(&mut self.begin);
+        self.end.append(&mut self.after);
+        std::mem::swap(&mut self.end, &mut self.after);
+        self
+    }
+
+    /// Surround a rendered declaration with the text before and after it
+    fn wrap(&self, rendered: String, level: usize) -> String {
+        let indent = |line: &String| match line.is_empty() {
+            true => String::new(),
+            false => format!("{:indent$}{line}", "", indent = level * INDENTION),
+        };
+        // the blank line separating the declaration from the previous one stays in front
+        let declaration = rendered.trim_start_matches('\n');
+        let mut wrapped = rendered[..rendered.len() - declaration.len()].to_owned();
+        for line in self.before.iter() {
+            wrapped.push_str(&indent(line));
+            wrapped.push('\n');
+        }
+        wrapped.push_str(declaration);
+        for line in self.after.iter() {
+            wrapped.push('\n');
+            wrapped.push_str(&indent(line));
+        }
+        wrapped
+    }
+}
+
+/// Verbatim text of an element placed at the beginning or end of the generated file
+fn file_text(annotations: &IdlAnnotations, file_placement: &str, lines: &mut Vec<String>) {
+    for verbatim in annotations.0.iter().filter(|a| a.id() == "verbatim") {
+        let language = verbatim.param("language");
+        let rust = matches!(language, Some(IdlConstValue::String(language)) if language == "rust" || language == "*");
+        let placement = verbatim.param("placement");
+        if rust
+            && matches!(placement, Some(IdlConstValue::Enumerator(placement)) if placement == file_placement)
+        {
+            if let Some(IdlConstValue::String(text)) = verbatim.param("text") {
+                lines.extend(text.lines().map(str::to_owned));
+            }
+        }
+    }
+}
+
+/// Everything shared while rendering the modules, the env must have the templates
+/// already loaded.
+pub struct RenderContext<'a> {
+    pub env: &'a minijinja::Environment<'a>,
+    pub config: &'a Configuration,
+    pub types: &'a TypeIndex<'a>,
+    pub constants: &'a ConstIndex,
+    pub keys: &'a KeySupport,
+    pub extensibility: &'a ExtensibilitySupport,
+    pub member_ids: &'a MemberIdSupport,
+    pub parameter_lists: &'a ParameterListSupport,
+    pub bounds: &'a BoundsSupport,
+    pub views: &'a ViewSupport,
+    pub repository_ids: &'a RepositoryIdSupport,
+    pub type_objects: &'a TypeObjectSupport,
+    pub layouts: &'a LayoutSupport,
+    pub derives: &'a DeriveSupport,
+    pub newtypes: &'a NewtypeSupport,
+    pub bytes: &'a ByteSupport,
+}
+
+/// Report a type mismatch of an annotation value as render error
+fn value_error(message: String) -> minijinja::Error {
+    minijinja::Error::new(minijinja::ErrorKind::InvalidOperation, message)
+}
+
+/// The annotation of a member, members of a typedef'd type inherit the annotations
+/// of the typedefs.
+fn member_annotation<'b>(
+    ctx: &'b RenderContext,
+    annotations: &'b IdlAnnotations,
+    spec: &IdlTypeSpec,
+    scope: &[String],
+    id: &str,
+) -> Option<&'b IdlAnnotation> {
+    annotations.get(id).or_else(|| {
+        ctx.types
+            .aliases(spec, scope)
+            .into_iter()
+            .find_map(|alias| alias.dcl.1.get(id))
+    })
+}
+
+/// IDL zero value of a type, used if no `@default` is given. Newtypes default to
+/// the zero value of the wrapped type.
+fn zero_value(ctx: &RenderContext, spec: &IdlTypeSpec, scope: &[String]) -> String {
+    match ctx.newtypes.unalias(ctx.types, spec, scope) {
+        (
+            IdlTypeSpec::I16Type
+            | IdlTypeSpec::I32Type
+            | IdlTypeSpec::I64Type
+            | IdlTypeSpec::U16Type
+            | IdlTypeSpec::U32Type
+            | IdlTypeSpec::U64Type
+            | IdlTypeSpec::OctetType,
+            _,
+        ) => "0".to_owned(),
+        (IdlTypeSpec::F32Type | IdlTypeSpec::F64Type, _) => "0.0".to_owned(),
+        (IdlTypeSpec::F128Type, _) if ctx.config.native_f128 => "0.0".to_owned(),
+        (IdlTypeSpec::F128Type, _) => "omg_idl_rt::F128::ZERO".to_owned(),
+        (IdlTypeSpec::BooleanType, _) => "false".to_owned(),
+        (IdlTypeSpec::CharType, _) => ctx.config.char_literal('\0', false).unwrap_or_default(),
+        (IdlTypeSpec::WideCharType, _) => ctx.config.char_literal('\0', true).unwrap_or_default(),
+        (IdlTypeSpec::WideStringType(_), _)
+            if ctx.config.wide_char_mapping == WideCharMapping::Utf16 =>
+        {
+            "omg_idl_rt::WString::new()".to_owned()
+        }
+        (IdlTypeSpec::StringType(Some(_)), _) if ctx.config.heapless => {
+            "heapless::String::new()".to_owned()
+        }
+        (IdlTypeSpec::StringType(_) | IdlTypeSpec::WideStringType(_), _) => {
+            format!("{}::new()", ctx.config.string_path())
+        }
+        (IdlTypeSpec::SequenceType(_, Some(_)), _) if ctx.config.heapless => {
+            "heapless::Vec::new()".to_owned()
+        }
+        (IdlTypeSpec::SequenceType(element, _), _)
+            if matches!(*element, IdlTypeSpec::OctetType) =>
+        {
+            format!(
+                "{}::new()",
+                ctx.config.byte_sequence_path(ctx.config.byte_mapping)
+            )
+        }
+        (IdlTypeSpec::SequenceType(..), _) => format!("{}::new()", ctx.config.vec_path()),
+        // the length is inferred, arrays of any length and element type are supported
+        (IdlTypeSpec::ArrayType(element, dims), scope) => {
+            let element = zero_value(ctx, &element, &scope);
+            // one nested array per dimension
+            dims.iter().fold(element, |element, _| {
+                format!("{}::array::from_fn(|_| {element})", ctx.config.core_crate())
+            })
+        }
+        _ => "Default::default()".to_owned(),
+    }
+}
+
+/// Wrap the Rust expression of a value into the newtypes the type `spec` refers to
+fn wrap_newtypes(
+    ctx: &RenderContext,
+    spec: &IdlTypeSpec,
+    scope: &[String],
+    value: String,
+) -> String {
+    ctx.newtypes
+        .wrappers(ctx.types, spec, scope)
+        .iter()
+        .rev()
+        .fold(value, |value, newtype| {
+            format!(
+                "{}({value})",
+                relative_path(newtype.name, scope, ctx.config)
+            )
+        })
+}
+
+/// Rust literal of an annotation value assigned to or compared with a value of the type
+/// `spec`. `value_type` is the Rust type, used to name enumerators.
+fn value_literal(
+    ctx: &RenderContext,
+    value: &IdlConstValue,
+    spec: &IdlTypeSpec,
+    scope: &[String],
+    value_type: &str,
+) -> Option<String> {
+    if !ctx.newtypes.wrappers(ctx.types, spec, scope).is_empty() {
+        // the literal of the wrapped type names enumerators by the enum
+        let (inner, inner_scope) = ctx.types.unalias(spec, scope);
+        let inner_type = match inner {
+            IdlTypeSpec::ScopedName(ref name) => relative_path(
+                ctx.types.resolve(name, &inner_scope)?.name,
+                scope,
+                ctx.config,
+            ),
+            _ => String::new(),
+        };
+        let literal = value_literal(ctx, value, &inner, &inner_scope, &inner_type)?;
+        return Some(wrap_newtypes(ctx, spec, scope, literal));
+    }
+    match (ctx.types.unalias(spec, scope), value) {
+        (
+            (
+                IdlTypeSpec::I16Type
+                | IdlTypeSpec::I32Type
+                | IdlTypeSpec::I64Type
+                | IdlTypeSpec::U16Type
+                | IdlTypeSpec::U32Type
+                | IdlTypeSpec::U64Type
+                | IdlTypeSpec::OctetType,
+                _,
+            ),
+            IdlConstValue::Integer(value),
+        ) => Some(value.to_string()),
+        ((IdlTypeSpec::F32Type | IdlTypeSpec::F64Type, _), IdlConstValue::Integer(value)) => {
+            Some(format!("{value}.0"))
+        }
+        ((IdlTypeSpec::F32Type | IdlTypeSpec::F64Type, _), IdlConstValue::Float(value)) => {
+            Some(format!("{value:?}"))
+        }
+        ((IdlTypeSpec::F128Type, _), IdlConstValue::Integer(value)) => {
+            Some(ctx.config.f128_literal(*value as f64))
+        }
+        ((IdlTypeSpec::F128Type, _), IdlConstValue::Float(value)) => {
+            Some(ctx.config.f128_literal(*value))
+        }
+        ((IdlTypeSpec::BooleanType, _), IdlConstValue::Boolean(value)) => Some(value.to_string()),
+        ((IdlTypeSpec::CharType, _), IdlConstValue::Char(value)) => {
+            ctx.config.char_literal(*value, false)
+        }
+        ((IdlTypeSpec::WideCharType, _), IdlConstValue::Char(value)) => {
+            ctx.config.char_literal(*value, true)
+        }
+        ((IdlTypeSpec::WideStringType(_), _), IdlConstValue::String(value))
+            if ctx.config.wide_char_mapping == WideCharMapping::Utf16 =>
+        {
+            Some(format!("omg_idl_rt::WString::from({value:?})"))
+        }
+        ((IdlTypeSpec::StringType(Some(bound)), scope), IdlConstValue::String(value))
+            if ctx.config.heapless =>
+        {
+            // a default exceeding the capacity does not match the type
+            let bound = ctx.constants.evaluate_size(&bound, &scope).ok()?;
+            (value.len() <= bound).then(|| format!("{value:?}.try_into().unwrap()"))
+        }
+        (
+            (IdlTypeSpec::StringType(_) | IdlTypeSpec::WideStringType(_), _),
+            IdlConstValue::String(value),
+        ) if ctx.config.no_std => Some(format!("{value:?}.into()")),
+        (
+            (IdlTypeSpec::StringType(_) | IdlTypeSpec::WideStringType(_), _),
+            IdlConstValue::String(value),
+        ) => Some(format!("{value:?}.to_owned()")),
+        ((IdlTypeSpec::ScopedName(name), scope), IdlConstValue::Enumerator(value)) => {
+            match ctx.types.resolve(&name, &scope)?.dcl.0 {
+                IdlTypeDclKind::EnumDcl(_, ref enumerators)
+                    if enumerators.iter().any(|enumerator| enumerator.id == *value) =>
+                {
+                    Some(format!("{value_type}::{}", ctx.config.type_name(value)))
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Whether values of the type can be checked against `@range`, `@min` and `@max`
+fn is_numeric(ctx: &RenderContext, spec: &IdlTypeSpec, scope: &[String]) -> bool {
+    matches!(
+        ctx.types.unalias(spec, scope).0,
+        IdlTypeSpec::I16Type
+            | IdlTypeSpec::I32Type
+            | IdlTypeSpec::I64Type
+            | IdlTypeSpec::U16Type
+            | IdlTypeSpec::U32Type
+            | IdlTypeSpec::U64Type
+            | IdlTypeSpec::OctetType
+            | IdlTypeSpec::F32Type
+            | IdlTypeSpec::F64Type
+    )
+}
+
+/// Default value of a struct member or union element, `@default` or the IDL zero value.
+/// A zero value below `@range`/`@min` defaults to the minimum, one above `@max` to the
+/// maximum, so that the default passes validation.
+fn default_value(
+    ctx: &RenderContext,
+    location: &str,
+    annotations: &IdlAnnotations,
+    spec: &IdlTypeSpec,
+    scope: &[String],
+    value_type: &str,
+) -> Result<String, minijinja::Error> {
+    let annotation = |id| member_annotation(ctx, annotations, spec, scope, id);
+    if let Some(value) = annotation("default").and_then(|default| default.param("value")) {
+        return value_literal(ctx, value, spec, scope, value_type).ok_or_else(|| {
+            value_error(format!(
+                "@default({value}) of {location} does not match its type"
+            ))
+        });
+    }
+    let min = annotation("range")
+        .and_then(|range| range.param("min"))
+        .or_else(|| annotation("min").and_then(|min| min.param("value")));
+    let max = annotation("range")
+        .and_then(|range| range.param("max"))
+        .or_else(|| annotation("max").and_then(|max| max.param("value")));
+    let bound = min
+        .filter(|min| zero_cmp(min) == Some(Ordering::Greater))
+        .or(max.filter(|max| zero_cmp(max) == Some(Ordering::Less)))
+        .filter(|_| is_numeric(ctx, spec, scope));
+    match bound {
+        Some(bound) => value_literal(ctx, bound, spec, scope, value_type).ok_or_else(|| {
+            value_error(format!(
+                "the bound {bound} of {location} does not match its type"
+            ))
+        }),
+        None => Ok(zero_value(ctx, spec, scope)),
+    }
+}
+
+/// Order of a numeric constant relative to zero, `None` for other constants
+fn zero_cmp(value: &IdlConstValue) -> Option<Ordering> {
+    match value {
+        IdlConstValue::Integer(value) => Some(value.cmp(&0)),
+        IdlConstValue::Float(value) => value.partial_cmp(&0.0),
+        _ => None,
+    }
+}
+
+/// Bounds of a struct member declared via `@range`, `@min` or `@max`
+fn member_bounds(
+    ctx: &RenderContext,
+    location: &str,
+    member: &IdlStructMember,
+    scope: &[String],
+    value_type: &str,
+) -> Result<Vec<IdlBound>, minijinja::Error> {
+    let spec = &member.type_spec;
+    let annotation = |id| member_annotation(ctx, &member.annotations, spec, scope, id);
+    let limits = [
+        (
+            "Min",
+            annotation("range").and_then(|range| range.param("min")),
+        ),
+        (
+            "Max",
+            annotation("range").and_then(|range| range.param("max")),
+        ),
+        ("Min", annotation("min").and_then(|min| min.param("value"))),
+        ("Max", annotation("max").and_then(|max| max.param("value"))),
+    ];
+    limits
+        .into_iter()
+        .filter_map(|(bound, limit)| limit.map(|limit| (bound, limit)))
+        .map(|(bound, limit)| {
+            let literal = value_literal(ctx, limit, spec, scope, value_type)
+                .filter(|_| is_numeric(ctx, spec, scope))
+                .ok_or_else(|| {
+                    value_error(format!(
+                        "the bound {limit} of {location} does not match its type"
+                    ))
+                })?;
+            Ok(IdlBound {
+                bound,
+                operator: if bound == "Min" { "<" } else { ">" },
+                literal,
+                limit: limit.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Serde attribute keeping the IDL spelling on the wire if the Rust name differs
+fn serde_rename(config: &Configuration, idl_name: &str, rust_name: &str) -> Option<String> {
+    (idl_name != rust_name)
+        .then(|| config.serde_attribute(&format!("rename = \"{idl_name}\"")))
+        .flatten()
+}
+
+/// Lints to be allowed on a generated item, `naming_lint` is omitted if the naming
+/// convention is applied by the generator.
+fn allowed_lints(naming_lint: &str, converted: bool) -> String {
+    if converted {
+        "dead_code".to_owned()
+    } else {
+        format!("dead_code, {naming_lint}")
+    }
+}
+
+/// Verify the converted Rust identifiers of one namespace are unique
+fn check_unique<'a>(
+    namespace: &str,
+    names: impl Iterator<Item = (&'a str, String)>,
+) -> Result<(), String> {
+    let mut seen: HashMap<String, &str> = HashMap::new();
+    for (idl_name, rust_name) in names {
+        if let Some(other) = seen.insert(rust_name.clone(), idl_name) {
+            return Err(format!(
+                "'{other}' and '{idl_name}' in {namespace} are both named '{rust_name}'"
+            ));
+        }
+    }
+    Ok(())
+}
+
+impl IdlSwitchLabel {
+    /// IDL spelling of the variant generated for the label
+    fn variant_idl_name(&self) -> String {
+        match self {
+            IdlSwitchLabel::Label(label) => label.to_string(),
+            IdlSwitchLabel::Default => "default".to_owned(),
+        }
+    }
+
+    /// Rust name of the variant generated for the label
+    fn variant_name(&self, config: &Configuration) -> String {
+        match self {
+            IdlSwitchLabel::Label(IdlValueExpr::ScopedName(name)) if config.camel_case_types => {
+                config.type_name(name.0.last().map(String::as_str).unwrap_or_default())
+            }
+            IdlSwitchLabel::Label(IdlValueExpr::ScopedName(_)) | IdlSwitchLabel::Default => {
+                config.type_name(&self.variant_idl_name())
+            }
+            // literals are no identifiers, i.e. `case 1:` => `Case1`, `case -1:` => `CaseMinus1`
+            IdlSwitchLabel::Label(label) => {
+                let words = label
+                    .to_string()
+                    .replace('-', "_minus_")
+                    .replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+                naming::to_upper_camel_case(&format!("case_{words}"))
+            }
+        }
+    }
+}
+
 impl IdlTypeDcl {
-    /// Convert the object to a Result<String> for output. The env must have the templates
-    /// already loaded.
+    /// Identifier of the declared type, empty if there is none
+    pub fn id(&self) -> &str {
+        match self.0 {
+            IdlTypeDclKind::TypeDcl(ref id, _)
+            | IdlTypeDclKind::StructDcl(ref id, _, _)
+            | IdlTypeDclKind::UnionDcl(ref id, _, _)
+            | IdlTypeDclKind::EnumDcl(ref id, _) => id,
+            IdlTypeDclKind::None => "",
+        }
+    }
+
+    /// The extensibility kind declared via `@extensibility`, `@final`, `@appendable` or
+    /// `@mutable`, `None` if the type has no such annotation
+    pub fn extensibility(&self) -> Result<Option<Extensibility>, String> {
+        let mut declared = None;
+        for annotation in self.1 .0.iter() {
+            let kind = match annotation.id() {
+                "final" => Extensibility::Final,
+                "appendable" => Extensibility::Appendable,
+                "mutable" => Extensibility::Mutable,
+                "extensibility" => match annotation.param("value") {
+                    Some(IdlConstValue::Enumerator(value)) if value == "FINAL" => {
+                        Extensibility::Final
+                    }
+                    Some(IdlConstValue::Enumerator(value)) if value == "MUTABLE" => {
+                        Extensibility::Mutable
+                    }
+                    _ => Extensibility::Appendable,
+                },
+                _ => continue,
+            };
+            if declared.replace(kind).is_some() {
+                return Err(format!(
+                    "{} has more than one extensibility annotation",
+                    self.location()
+                ));
+            }
+        }
+        Ok(declared)
+    }
+
+    /// Kind and identifier of the type for messages, i.e. "struct Foo"
+    fn location(&self) -> String {
+        match self.0 {
+            IdlTypeDclKind::TypeDcl(ref id, _) => format!("typedef {id}"),
+            IdlTypeDclKind::StructDcl(ref id, _, _) => format!("struct {id}"),
+            IdlTypeDclKind::UnionDcl(ref id, _, _) => format!("union {id}"),
+            IdlTypeDclKind::EnumDcl(ref id, _) => format!("enum {id}"),
+            IdlTypeDclKind::None => String::new(),
+        }
+    }
+
+    /// Verify the members/variants of the type keep unique names after conversion
+    fn check_naming(&self, config: &Configuration) -> Result<(), String> {
+        match self.0 {
+            IdlTypeDclKind::StructDcl(ref id, ref base, ref members) => {
+                check_unique(
+                    &format!("struct {id}"),
+                    base.iter()
+                        .map(|_| (BASE_MEMBER, BASE_MEMBER.to_owned()))
+                        .chain(
+                            members
+                                .iter()
+                                .map(|member| (member.id.as_str(), config.member_name(&member.id))),
+                        ),
+                )?;
+                check_unique(
+                    &format!("the member IDs of struct {id}"),
+                    members
+                        .iter()
+                        .map(|member| (member.id.as_str(), member_id_const(&member.id))),
+                )
+            }
+            IdlTypeDclKind::EnumDcl(ref id, ref enums) => check_unique(
+                &format!("enum {id}"),
+                enums
+                    .iter()
+                    .map(|variant| (variant.id.as_str(), config.type_name(&variant.id))),
+            ),
+            IdlTypeDclKind::UnionDcl(ref id, _, ref switch_cases) => {
+                let labels = switch_cases
+                    .iter()
+                    .flat_map(|case| case.labels.iter())
+                    .map(|label| (label.variant_idl_name(), label.variant_name(config)))
+                    .collect::<Vec<_>>();
+                check_unique(
+                    &format!("union {id}"),
+                    labels
+                        .iter()
+                        .map(|(idl_name, rust_name)| (idl_name.as_str(), rust_name.clone())),
+                )?;
+                check_unique(
+                    &format!("the member IDs of union {id}"),
+                    switch_cases.iter().map(|case| {
+                        let element = &case.elem_spec;
+                        (element.id.as_str(), member_id_const(&element.id))
+                    }),
+                )
+            }
+            IdlTypeDclKind::TypeDcl(_, _) | IdlTypeDclKind::None => Ok(()),
+        }
+    }
+
+    /// Convert the object to a Result<String> for output. `scope` is the module declaring
+    /// the type.
     pub fn render(
         &self,
-        env: &minijinja::Environment,
+        ctx: &RenderContext,
+        scope: &[String],
         level: usize,
     ) -> Result<String, minijinja::Error> {
-        match self.0 {
+        let (env, config) = (ctx.env, ctx.config);
+        let mut item = match self.0 {
+            IdlTypeDclKind::TypeDcl(_, _) => IdlItemText::new(&self.1, config).without_body(),
+            _ => IdlItemText::new(&self.1, config),
+        };
+        let mut name = scope.to_vec();
+        name.push(self.id().to_owned());
+        let holder = match self.0 {
+            IdlTypeDclKind::EnumDcl(..) => Some(cdr::enum_holder(&self.1)),
+            _ => None,
+        };
+        let (repr, layout_derives, report) = ctx.layouts.attributes(&name, holder);
+        item.before
+            .extend(report.map(|report| format!("// {report}")));
+        let mut derives = ctx.derives.derives(&name);
+        for derive in layout_derives.into_iter().chain(item.derives.drain(..)) {
+            if !derives.contains(&derive) {
+                derives.push(derive);
+            }
+        }
+        if ctx.keys.key(&name).is_some() {
+            // compared and hashed by their key, whichever derives were requested
+            derives.retain(|derive| {
+                let trait_name = derive.rsplit("::").next().unwrap_or_default();
+                trait_name.parse::<Derive>().is_err() || trait_name == "Copy"
+            });
+        }
+        item.derives = derives;
+        let newtype = ctx.newtypes.is_newtype(&name);
+        // newtypes are always transparent
+        item.attributes.splice(0..0, repr.filter(|_| !newtype));
+        let deprecated = self.deprecated();
+        let mut allow_lints = allowed_lints("non_camel_case_types", config.camel_case_types);
+        if deprecated {
+            allow_lints.push_str(", deprecated");
+        }
+        let rendered = match self.0 {
+            IdlTypeDclKind::TypeDcl(ref id, ref type_spec) if newtype => {
+                let tmpl = env.get_template("newtype.j2")?;
+                let inner_type = type_spec.to_rust_type(config)?;
+                let location = format!("typedef {id}");
+                let default =
+                    default_value(ctx, &location, &self.1, type_spec, scope, &inner_type)?;
+                // serde implements nested arrays up to 32 elements per dimension only
+                let serde_arrays = match type_spec {
+                    IdlTypeSpec::ArrayType(..)
+                        if config.byte_mapping != ByteMapping::Vec
+                            && bytes::is_octet_array(ctx.types, ctx.newtypes, type_spec, scope) =>
+                    {
+                        config.serde_attribute("with = \"serde_bytes\"")
+                    }
+                    IdlTypeSpec::ArrayType(_, ref dims) if dims.len() == 1 => {
+                        config.serde_attribute("with = \"serde_arrays\"")
+                    }
+                    _ => None,
+                };
+                let (encode, decode) = cdr::functions(
+                    ctx.types,
+                    ctx.newtypes,
+                    ctx.parameter_lists,
+                    type_spec,
+                    scope,
+                );
+                let mut rendered = tmpl.render(minijinja::context! {
+                    type_name => config.type_name(id),
+                    inner_type,
+                    serde_transparent => config.serde_attribute("transparent"),
+                    serde_arrays,
+                    default,
+                    codec => cdr::codec(ctx.types, ctx.newtypes, ctx.parameter_lists, type_spec, scope),
+                    encode,
+                    decode,
+                    item,
+                    deprecated,
+                    allow_lints,
+                    indent_level => level
+                })?;
+                rendered.push_str(&self.render_key(ctx, scope, id, level)?);
+                rendered.push_str(&self.render_type_name(ctx, scope, id, level)?);
+                rendered.push_str(&self.render_type_objects(ctx, scope, id, level)?);
+                rendered.push_str(&self.render_reflect(ctx, scope, id, level)?);
+                Ok(rendered)
+            }
             IdlTypeDclKind::TypeDcl(ref id, ref type_spec) => {
                 let tmpl = env.get_template("typedef.j2")?;
                 tmpl.render(minijinja::context! {
-                    typedef_name => id,
-                    typedef_type => type_spec.to_string(),
+                    typedef_name => config.type_name(id),
+                    typedef_type => type_spec.to_rust_type(config)?,
+                    allow_lints,
                     indent_level => level
                 })
             }
-            IdlTypeDclKind::StructDcl(ref id, ref type_spec) => {
+            IdlTypeDclKind::StructDcl(ref id, ref base, ref type_spec) => {
                 let tmpl = env.get_template("struct.j2")?;
+                let base = base
+                    .iter()
+                    .map(|base| {
+                        let type_str =
+                            IdlTypeSpec::ScopedName(base.clone()).to_rust_type(config)?;
+                        Ok(IdlStructField {
+                            name: BASE_MEMBER.to_owned(),
+                            idl_name: BASE_MEMBER.to_owned(),
+                            value_type: type_str.clone(),
+                            type_str,
+                            optional: false,
+                            required: false,
+                            directives: config.serde_attribute("flatten").into_iter().collect(),
+                            after: Vec::new(),
+                            default: "Default::default()".to_owned(),
+                            bounds: Vec::new(),
+                        })
+                    })
+                    .collect::<Result<Vec<IdlStructField>, minijinja::Error>>()?;
                 let fields = type_spec
                     .iter()
                     .map(|field| {
-                        // @todo this doesn't work because the type needs to be determined. But if
-                        //       the type is a ScopedName it can also be an array
-                        if let IdlTypeSpec::ArrayType(_, _) = field.type_spec {
-                            IdlStructField {
-                                name: field.id.clone(),
-                                type_str: field.type_spec.to_string(),
-                                directive: "#[serde(with = \"serde_arrays\")]".to_string(),
-                            }
-                        } else {
-                            IdlStructField {
-                                name: field.id.clone(),
-                                type_str: field.type_spec.to_string(),
-                                directive: String::new(),
-                            }
+                        let mut member_name = name.clone();
+                        member_name.push(field.id.clone());
+                        let name = config.member_name(&field.id);
+                        let optional = field.annotations.is_set("optional");
+                        let text = IdlItemText::new(&field.annotations, config).without_body();
+                        let mut directives = text.before;
+                        if !config.snake_case_members {
+                            directives.push("#[allow(non_snake_case)]".to_owned());
+                        }
+                        directives.extend(serde_array(
+                            ctx,
+                            &member_name,
+                            &field.type_spec,
+                            scope,
+                            optional,
+                        ));
+                        if optional {
+                            directives.extend(config.serde_attribute(SERDE_OPTIONAL));
                         }
+                        directives.extend(serde_rename(config, &field.id, &name));
+                        let byte_sequence = ctx.bytes.member_sequence(config, &member_name);
+                        let value_type = match byte_sequence {
+                            Some((ref value_type, _)) => value_type.clone(),
+                            None => field.type_spec.to_rust_type(config)?,
+                        };
+                        let location = format!("member {} of struct {id}", field.id);
+                        let has_default = member_annotation(
+                            ctx,
+                            &field.annotations,
+                            &field.type_spec,
+                            scope,
+                            "default",
+                        )
+                        .is_some();
+                        let default = match byte_sequence {
+                            Some((_, zero)) => zero,
+                            None => default_value(
+                                ctx,
+                                &location,
+                                &field.annotations,
+                                &field.type_spec,
+                                scope,
+                                &value_type,
+                            )?,
+                        };
+                        Ok(IdlStructField {
+                            name,
+                            type_str: if optional {
+                                format!("Option<{value_type}>")
+                            } else {
+                                value_type.clone()
+                            },
+                            default: match (optional, has_default) {
+                                (true, true) => format!("Some({default})"),
+                                (true, false) => "None".to_owned(),
+                                (false, _) => default,
+                            },
+                            bounds: member_bounds(ctx, &location, field, scope, &value_type)?,
+                            idl_name: field.id.clone(),
+                            value_type,
+                            required: !optional && !has_default && field.annotations.is_set("key"),
+                            optional,
+                            directives,
+                            after: text.after,
+                        })
                     })
-                    .collect::<Vec<IdlStructField>>();
+                    .collect::<Result<Vec<IdlStructField>, minijinja::Error>>()?;
+                let fields = base.into_iter().chain(fields).collect::<Vec<_>>();
 
-                tmpl.render(minijinja::context! {
-                    struct_name => id,
+                let struct_name = config.type_name(id);
+                let mut rendered = tmpl.render(minijinja::context! {
+                    serde_rename => serde_rename(config, id, &struct_name),
+                    struct_name,
                     fields,
+                    builder => config.builders,
+                    accessors => !config.no_accessors,
+                    item,
+                    deprecated,
+                    allow_lints,
                     indent_level => level
-                })
+                })?;
+                rendered.push_str(&self.render_key(ctx, scope, id, level)?);
+                rendered.push_str(&self.render_extensibility(ctx, scope, id, level)?);
+                rendered.push_str(&self.render_type_name(ctx, scope, id, level)?);
+                rendered.push_str(&self.render_type_objects(ctx, scope, id, level)?);
+                rendered.push_str(&self.render_member_ids(ctx, scope, id, level)?);
+                rendered.push_str(&self.render_cdr(ctx, scope, id, level)?);
+                rendered.push_str(&self.render_bounds(ctx, scope, id, level)?);
+                rendered.push_str(&self.render_view(ctx, scope, id, level)?);
+                rendered.push_str(&self.render_reflect(ctx, scope, id, level)?);
+                Ok(rendered)
             }
             IdlTypeDclKind::EnumDcl(ref id, ref enums) => {
                 let tmpl = env.get_template("enum.j2")?;
-                tmpl.render(minijinja::context! {
-                    enum_name => id,
-                    variants => enums,
+                let mut variants = enums
+                    .iter()
+                    .map(|variant| {
+                        let name = config.type_name(&variant.id);
+                        let text = IdlItemText::new(&variant.annotations, config).without_body();
+                        IdlEnumVariant {
+                            directives: text
+                                .before
+                                .into_iter()
+                                .chain(serde_rename(config, &variant.id, &name))
+                                .collect(),
+                            after: text.after,
+                            name,
+                            idl_name: variant.id.clone(),
+                            default: false,
+                            value: None,
+                        }
+                    })
+                    .collect::<Vec<IdlEnumVariant>>();
+                if ctx.layouts.is_copy(&name) {
+                    for (variant, value) in variants.iter_mut().zip(cdr::enumerator_values(enums)) {
+                        variant.value = Some(value.to_string());
+                    }
+                }
+                // the first enumerator is the default unless another one is marked
+                let default = enums
+                    .iter()
+                    .position(|variant| variant.annotations.is_set("default_literal"))
+                    .unwrap_or_default();
+                if let Some(variant) = variants.get_mut(default) {
+                    variant.default = true;
+                }
+                let enum_name = config.type_name(id);
+                let mut rendered = tmpl.render(minijinja::context! {
+                    serde_rename => serde_rename(config, id, &enum_name),
+                    enum_name,
+                    variants,
+                    item,
+                    deprecated,
+                    allow_lints,
                     indent_level => level
-                })
+                })?;
+                rendered.push_str(&self.render_key(ctx, scope, id, level)?);
+                rendered.push_str(&self.render_extensibility(ctx, scope, id, level)?);
+                rendered.push_str(&self.render_type_name(ctx, scope, id, level)?);
+                rendered.push_str(&self.render_type_objects(ctx, scope, id, level)?);
+                rendered.push_str(&self.render_cdr(ctx, scope, id, level)?);
+                rendered.push_str(&self.render_bounds(ctx, scope, id, level)?);
+                rendered.push_str(&self.render_reflect(ctx, scope, id, level)?);
+                Ok(rendered)
             }
             IdlTypeDclKind::UnionDcl(ref id, ref _type_spec, ref switch_cases) => {
                 let tmpl = env.get_template("union_switch.j2")?;
@@ -342,32 +1712,642 @@ impl IdlTypeDcl {
                     .iter()
                     .flat_map(|case| {
                         case.labels
-                            .clone()
                             .iter()
                             .map(|label| {
-                                let label = match label {
-                                    IdlSwitchLabel::Label(label) => label.to_string(),
-                                    IdlSwitchLabel::Default => "default".to_owned(),
-                                };
-
-                                IdlSwitchField {
-                                    name: label.to_string(),
-                                    element_id: case.elem_spec.id.clone(),
-                                    element_type: case.elem_spec.type_spec.to_string(),
-                                }
+                                let variant = label.variant_name(config);
+                                let element = &case.elem_spec;
+                                let element_id = config.member_name(&element.id);
+                                let optional = element.annotations.is_set("optional");
+                                let mut element_name = name.clone();
+                                element_name.push(element.id.clone());
+                                let element_type =
+                                    match ctx.bytes.member_sequence(config, &element_name) {
+                                        Some((element_type, _)) => element_type,
+                                        None => element.type_spec.to_rust_type(config)?,
+                                    };
+                                let serde_array = serde_array(
+                                    ctx,
+                                    &element_name,
+                                    &element.type_spec,
+                                    scope,
+                                    optional,
+                                );
+                                let text =
+                                    IdlItemText::new(&element.annotations, config).without_body();
+                                Ok(IdlSwitchField {
+                                    directives: text
+                                        .before
+                                        .into_iter()
+                                        .chain(serde_rename(
+                                            config,
+                                            &label.variant_idl_name(),
+                                            &variant,
+                                        ))
+                                        .collect(),
+                                    after: text.after,
+                                    name: variant,
+                                    element_directive: serde_array
+                                        .into_iter()
+                                        .chain(
+                                            optional
+                                                .then(|| config.serde_attribute(SERDE_OPTIONAL))
+                                                .flatten(),
+                                        )
+                                        .chain(serde_rename(config, &element.id, &element_id))
+                                        .map(|directive| directive + " ")
+                                        .collect(),
+                                    element_id,
+                                    element_type: if optional {
+                                        format!("Option<{element_type}>")
+                                    } else {
+                                        element_type
+                                    },
+                                })
                             })
-                            .collect::<Vec<IdlSwitchField>>()
+                            .collect::<Vec<_>>()
                     })
-                    .collect::<Vec<IdlSwitchField>>();
+                    .collect::<Result<Vec<IdlSwitchField>, minijinja::Error>>()?;
 
-                tmpl.render(minijinja::context! {
-                    union_name => id,
+                // the default is the `default` branch, otherwise the first branch
+                let default_case = switch_cases
+                    .iter()
+                    .find(|case| {
+                        case.labels
+                            .iter()
+                            .any(|label| matches!(label, IdlSwitchLabel::Default))
+                    })
+                    .or(switch_cases.first());
+                let (default_variant, default_element, default_value) = match default_case {
+                    Some(case) => {
+                        let element = &case.elem_spec;
+                        let label = case
+                            .labels
+                            .iter()
+                            .find(|label| matches!(label, IdlSwitchLabel::Default))
+                            .or(case.labels.first());
+                        let location = format!("element {} of union {id}", element.id);
+                        let mut element_name = name.clone();
+                        element_name.push(element.id.clone());
+                        let value = match ctx.bytes.member_sequence(config, &element_name) {
+                            Some((_, zero)) => zero,
+                            None => default_value(
+                                ctx,
+                                &location,
+                                &element.annotations,
+                                &element.type_spec,
+                                scope,
+                                &element.type_spec.to_rust_type(config)?,
+                            )?,
+                        };
+                        let has_default = member_annotation(
+                            ctx,
+                            &element.annotations,
+                            &element.type_spec,
+                            scope,
+                            "default",
+                        )
+                        .is_some();
+                        let value = match (element.annotations.is_set("optional"), has_default) {
+                            (true, true) => format!("Some({value})"),
+                            (true, false) => "None".to_owned(),
+                            (false, _) => value,
+                        };
+                        (
+                            label.map(|label| label.variant_name(config)),
+                            config.member_name(&element.id),
+                            value,
+                        )
+                    }
+                    None => (None, String::new(), String::new()),
+                };
+
+                let union_name = config.type_name(id);
+                let mut rendered = tmpl.render(minijinja::context! {
+                    serde_rename => serde_rename(config, id, &union_name),
+                    union_name,
                     union_members,
+                    default_variant,
+                    default_element,
+                    default_value,
+                    item,
+                    deprecated,
+                    allow_lints,
                     indent_level => level
-                })
+                })?;
+                rendered.push_str(&self.render_extensibility(ctx, scope, id, level)?);
+                rendered.push_str(&self.render_type_name(ctx, scope, id, level)?);
+                rendered.push_str(&self.render_type_objects(ctx, scope, id, level)?);
+                rendered.push_str(&self.render_member_ids(ctx, scope, id, level)?);
+                rendered.push_str(&self.render_cdr(ctx, scope, id, level)?);
+                rendered.push_str(&self.render_bounds(ctx, scope, id, level)?);
+                rendered.push_str(&self.render_reflect(ctx, scope, id, level)?);
+                Ok(rendered)
             }
             IdlTypeDclKind::None => Ok(String::new()),
+        }?;
+        Ok(item.wrap(rendered, level))
+    }
+
+    /// Whether the type or any of its members is `@deprecated`, generated code using
+    /// the type has to allow the lint
+    fn deprecated(&self) -> bool {
+        let members = match self.0 {
+            IdlTypeDclKind::StructDcl(_, _, ref members) => members
+                .iter()
+                .any(|member| member.annotations.is_set("deprecated")),
+            IdlTypeDclKind::UnionDcl(_, _, ref switch_cases) => switch_cases
+                .iter()
+                .any(|case| case.elem_spec.annotations.is_set("deprecated")),
+            IdlTypeDclKind::EnumDcl(_, ref enums) => enums
+                .iter()
+                .any(|variant| variant.annotations.is_set("deprecated")),
+            IdlTypeDclKind::TypeDcl(_, _) | IdlTypeDclKind::None => false,
+        };
+        members || self.1.is_set("deprecated")
+    }
+
+    /// `Extensible` implementation announcing the extensibility kind of the type
+    fn render_extensibility(
+        &self,
+        ctx: &RenderContext,
+        scope: &[String],
+        id: &str,
+        level: usize,
+    ) -> Result<String, minijinja::Error> {
+        let mut name = scope.to_vec();
+        name.push(id.to_owned());
+        ctx.env
+            .get_template("extensibility.j2")?
+            .render(minijinja::context! {
+                type_name => ctx.config.type_name(id),
+                extensibility => ctx.extensibility.kind(&name).variant(),
+                deprecated => self.deprecated(),
+                indent_level => level
+            })
+    }
+
+    /// `TypeName` implementation announcing the IDL name and the repository ID of the type
+    fn render_type_name(
+        &self,
+        ctx: &RenderContext,
+        scope: &[String],
+        id: &str,
+        level: usize,
+    ) -> Result<String, minijinja::Error> {
+        let mut name = scope.to_vec();
+        name.push(id.to_owned());
+        ctx.env
+            .get_template("type_name.j2")?
+            .render(minijinja::context! {
+                type_name => ctx.config.type_name(id),
+                idl_name => format!("{:?}", name.join("::")),
+                repository_id => format!("{:?}", ctx.repository_ids.id(&name)),
+                deprecated => self.deprecated(),
+                indent_level => level
+            })
+    }
+
+    /// `TypeObjects` implementation holding the minimal and complete TypeObjects, empty
+    /// unless enabled
+    fn render_type_objects(
+        &self,
+        ctx: &RenderContext,
+        scope: &[String],
+        id: &str,
+        level: usize,
+    ) -> Result<String, minijinja::Error> {
+        let mut name = scope.to_vec();
+        name.push(id.to_owned());
+        let Some(objects) = ctx.type_objects.type_objects(&name) else {
+            return Ok(String::new());
+        };
+        ctx.env
+            .get_template("type_objects.j2")?
+            .render(minijinja::context! {
+                type_name => ctx.config.type_name(id),
+                minimal => objects.minimal,
+                complete => objects.complete,
+                dependencies => ctx.type_objects.dependencies(&name, scope, ctx.config),
+                deprecated => self.deprecated(),
+                indent_level => level
+            })
+    }
+
+    /// Associated constants holding the member IDs, empty for types without members
+    fn render_member_ids(
+        &self,
+        ctx: &RenderContext,
+        scope: &[String],
+        id: &str,
+        level: usize,
+    ) -> Result<String, minijinja::Error> {
+        let mut name = scope.to_vec();
+        name.push(id.to_owned());
+        let members = ctx.member_ids.ids(&name);
+        if members.is_empty() {
+            return Ok(String::new());
         }
+        ctx.env
+            .get_template("member_ids.j2")?
+            .render(minijinja::context! {
+                type_name => ctx.config.type_name(id),
+                members,
+                deprecated => self.deprecated(),
+                indent_level => level
+            })
+    }
+
+    /// CDR serialization of structs, enums and unions
+    fn render_cdr(
+        &self,
+        ctx: &RenderContext,
+        scope: &[String],
+        id: &str,
+        level: usize,
+    ) -> Result<String, minijinja::Error> {
+        let config = ctx.config;
+        let mut name = scope.to_vec();
+        name.push(id.to_owned());
+        let type_name = config.type_name(id);
+        let id_const = |idl_name: &str| {
+            ctx.member_ids
+                .ids(&name)
+                .iter()
+                .find(|member| member.idl_name == idl_name)
+                .map(|member| format!("Self::{}", member.const_name))
+                .unwrap_or_default()
+        };
+        match self.0 {
+            IdlTypeDclKind::StructDcl(_, ref base, ref members) => {
+                let parameter_ids = ctx.parameter_lists.ids(&name);
+                let base = base.iter().map(|_| IdlCdrMember {
+                    name: BASE_MEMBER.to_owned(),
+                    id_const: String::new(),
+                    base: true,
+                    optional: false,
+                    key: false,
+                    parameter_id: None,
+                    encode: String::new(),
+                    decode: String::new(),
+                });
+                let members = members.iter().map(|member| {
+                    let (encode, decode) = cdr::functions(
+                        ctx.types,
+                        ctx.newtypes,
+                        ctx.parameter_lists,
+                        &member.type_spec,
+                        scope,
+                    );
+                    IdlCdrMember {
+                        name: config.member_name(&member.id),
+                        id_const: id_const(&member.id),
+                        base: false,
+                        optional: member.annotations.is_set("optional"),
+                        key: member.annotations.is_set("key"),
+                        parameter_id: parameter_ids
+                            .and_then(|ids| ids.iter().find(|(name, _)| *name == member.id))
+                            .map(|(_, id)| format!("{id:#06x}")),
+                        encode,
+                        decode,
+                    }
+                });
+                ctx.env
+                    .get_template("cdr_struct.j2")?
+                    .render(minijinja::context! {
+                        type_name,
+                        members => base.chain(members).collect::<Vec<_>>(),
+                        mutable => ctx.extensibility.kind(&name) == Extensibility::Mutable,
+                        parameter_list => parameter_ids.is_some(),
+                        deprecated => self.deprecated(),
+                        indent_level => level
+                    })
+            }
+            IdlTypeDclKind::EnumDcl(_, ref enumerators) => ctx
+                .env
+                .get_template("cdr_enum.j2")?
+                .render(minijinja::context! {
+                    type_name,
+                    variants => enumerators
+                        .iter()
+                        .map(|enumerator| config.type_name(&enumerator.id))
+                        .zip(cdr::enumerator_values(enumerators))
+                        .collect::<Vec<_>>(),
+                    holder => cdr::enum_holder(&self.1),
+                    deprecated => self.deprecated(),
+                    indent_level => level
+                }),
+            IdlTypeDclKind::UnionDcl(_, ref switch_type, ref switch_cases) => {
+                let location = |e: String| value_error(format!("union {id}: {e}"));
+                let discriminator = Discriminator::new(
+                    ctx.types,
+                    ctx.newtypes,
+                    ctx.constants,
+                    config,
+                    switch_type,
+                    scope,
+                )
+                .map_err(location)?;
+                let labels = switch_cases
+                    .iter()
+                    .flat_map(|case| case.labels.iter())
+                    .filter_map(|label| match label {
+                        IdlSwitchLabel::Label(expr) => Some(expr),
+                        IdlSwitchLabel::Default => None,
+                    })
+                    .collect::<Vec<_>>();
+                let parameter = ctx.parameter_lists.is_parameter(&name);
+                let mut branches = Vec::new();
+                for case in switch_cases {
+                    let element = &case.elem_spec;
+                    let element_name = config.member_name(&element.id);
+                    let member_id = id_const(&element.id);
+                    let (encode, decode) = cdr::functions(
+                        ctx.types,
+                        ctx.newtypes,
+                        ctx.parameter_lists,
+                        &element.type_spec,
+                        scope,
+                    );
+                    let (encode, decode, read) = if element.annotations.is_set("optional") {
+                        (
+                            format!("|value, writer| writer.write_optional({member_id}, value, {encode})"),
+                            format!("reader.read_optional({member_id}, {decode})?"),
+                            format!("|reader| reader.read_optional({member_id}, {decode})"),
+                        )
+                    } else {
+                        (encode, format!("{decode}(reader)?"), decode)
+                    };
+                    // mutable unions look for the member header of the selected branch
+                    let read = format!("reader.read_branch(extensibility, {member_id}, {read})?");
+                    for label in case.labels.iter() {
+                        let parameter_id = match label {
+                            // the parameter list analysis checked the labels
+                            IdlSwitchLabel::Label(expr) if parameter => {
+                                match ctx.constants.evaluate(expr, scope).map_err(location)? {
+                                    IdlConstValue::Integer(id) => Some(format!("{id:#06x}")),
+                                    _ => None,
+                                }
+                            }
+                            _ => None,
+                        };
+                        let (discriminator, pattern) = match label {
+                            IdlSwitchLabel::Label(expr) => {
+                                let value = discriminator.value(expr).map_err(location)?;
+                                (value.clone(), Some(value))
+                            }
+                            IdlSwitchLabel::Default => (
+                                discriminator.default_value(&labels).map_err(location)?,
+                                None,
+                            ),
+                        };
+                        branches.push(IdlCdrBranch {
+                            variant: label.variant_name(config),
+                            element: element_name.clone(),
+                            id_const: member_id.clone(),
+                            discriminator,
+                            pattern,
+                            parameter_id,
+                            encode: encode.clone(),
+                            decode: decode.clone(),
+                            read: read.clone(),
+                        });
+                    }
+                }
+                ctx.env
+                    .get_template("cdr_union.j2")?
+                    .render(minijinja::context! {
+                        type_name,
+                        discriminator_type => discriminator.rust_type(),
+                        branches,
+                        parameter,
+                        deprecated => self.deprecated(),
+                        indent_level => level
+                    })
+            }
+            IdlTypeDclKind::TypeDcl(_, _) | IdlTypeDclKind::None => Ok(String::new()),
+        }
+    }
+
+    /// `CdrBounds` with the maximum serialized sizes of a struct, union or enum
+    fn render_bounds(
+        &self,
+        ctx: &RenderContext,
+        scope: &[String],
+        id: &str,
+        level: usize,
+    ) -> Result<String, minijinja::Error> {
+        let mut name = scope.to_vec();
+        name.push(id.to_owned());
+        let bounds = ctx.bounds.bounds(&name);
+        ctx.env
+            .get_template("cdr_bounds.j2")?
+            .render(minijinja::context! {
+                type_name => ctx.config.type_name(id),
+                bounded => bounds.bounded(),
+                xcdr1 => bounds.xcdr1,
+                xcdr2 => bounds.xcdr2,
+                deprecated => self.deprecated(),
+                indent_level => level
+            })
+    }
+
+    /// Zero-copy view of a struct, empty if the struct has none
+    fn render_view(
+        &self,
+        ctx: &RenderContext,
+        scope: &[String],
+        id: &str,
+        level: usize,
+    ) -> Result<String, minijinja::Error> {
+        let config = ctx.config;
+        let mut name = scope.to_vec();
+        name.push(id.to_owned());
+        let Some(fields) = ctx.views.fields(&name) else {
+            return Ok(String::new());
+        };
+        let type_name = config.type_name(id);
+        ctx.env
+            .get_template("cdr_view.j2")?
+            .render(minijinja::context! {
+                view_name => format!("{type_name}Ref"),
+                type_name,
+                fields,
+                allow_lints => allowed_lints("non_camel_case_types", config.camel_case_types),
+                snake_case => config.snake_case_members,
+                deprecated => self.deprecated(),
+                indent_level => level
+            })
+    }
+
+    /// `omg_idl_rt::Reflect` of structs, enums, unions and newtypes, empty without
+    /// reflection
+    fn render_reflect(
+        &self,
+        ctx: &RenderContext,
+        scope: &[String],
+        id: &str,
+        level: usize,
+    ) -> Result<String, minijinja::Error> {
+        let config = ctx.config;
+        if !config.reflection {
+            return Ok(String::new());
+        }
+        let mut name = scope.to_vec();
+        name.push(id.to_owned());
+        let location = |e: String| value_error(format!("{id}: {e}"));
+        let type_name = config.type_name(id);
+        match self.0 {
+            IdlTypeDclKind::StructDcl(_, ref base, ref members) => {
+                let members = members
+                    .iter()
+                    .map(|member| {
+                        let mut member_name = name.clone();
+                        member_name.push(member.id.clone());
+                        let value_type = match ctx.bytes.member_sequence(config, &member_name) {
+                            Some((value_type, _)) => value_type,
+                            None => member.type_spec.to_rust_type(config)?,
+                        };
+                        let optional = member.annotations.is_set("optional");
+                        let member_location = format!("member {} of struct {id}", member.id);
+                        Ok(minijinja::context! {
+                            idl_name => member.id,
+                            name => config.member_name(&member.id),
+                            type_str => if optional {
+                                format!("Option<{value_type}>")
+                            } else {
+                                value_type.clone()
+                            },
+                            optional,
+                            validated => !member_bounds(ctx, &member_location, member, scope, &value_type)?
+                                .is_empty(),
+                            length => reflect::length_bound(ctx, &member.type_spec, scope)
+                                .map_err(location)?,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, minijinja::Error>>()?;
+                ctx.env
+                    .get_template("reflect_struct.j2")?
+                    .render(minijinja::context! {
+                        type_name,
+                        descriptors => reflect::struct_members(ctx, &name).map_err(location)?,
+                        base => base.as_ref().map(|_| BASE_MEMBER),
+                        members,
+                        deprecated => self.deprecated(),
+                        indent_level => level
+                    })
+            }
+            IdlTypeDclKind::EnumDcl(_, ref enumerators) => ctx
+                .env
+                .get_template("reflect_enum.j2")?
+                .render(minijinja::context! {
+                    type_name,
+                    variants => enumerators
+                        .iter()
+                        .map(|enumerator| (config.type_name(&enumerator.id), &enumerator.id))
+                        .collect::<Vec<_>>(),
+                    deprecated => self.deprecated(),
+                    indent_level => level
+                }),
+            IdlTypeDclKind::UnionDcl(_, _, ref switch_cases) => {
+                let branches = switch_cases
+                    .iter()
+                    .flat_map(|case| {
+                        case.labels.iter().map(|label| {
+                            Ok(minijinja::context! {
+                                variant => label.variant_name(config),
+                                label => label.variant_idl_name(),
+                                element => config.member_name(&case.elem_spec.id),
+                                idl_element => case.elem_spec.id,
+                                length => reflect::length_bound(ctx, &case.elem_spec.type_spec, scope)
+                                    .map_err(location)?,
+                            })
+                        })
+                    })
+                    .collect::<Result<Vec<_>, minijinja::Error>>()?;
+                ctx.env
+                    .get_template("reflect_union.j2")?
+                    .render(minijinja::context! {
+                        type_name,
+                        descriptors => reflect::union_elements(ctx, &name, switch_cases)
+                            .map_err(location)?,
+                        branches,
+                        deprecated => self.deprecated(),
+                        indent_level => level
+                    })
+            }
+            IdlTypeDclKind::TypeDcl(_, ref aliased) => ctx
+                .env
+                .get_template("reflect_newtype.j2")?
+                .render(minijinja::context! {
+                    type_name,
+                    length => reflect::length_bound(ctx, aliased, scope).map_err(location)?,
+                    deprecated => self.deprecated(),
+                    indent_level => level
+                }),
+            IdlTypeDclKind::None => Ok(String::new()),
+        }
+    }
+
+    /// Key holder, `Keyed` and key based comparisons of keyed structs as well as the key
+    /// encoding of types nested in keys. Empty for all other types.
+    fn render_key(
+        &self,
+        ctx: &RenderContext,
+        scope: &[String],
+        id: &str,
+        level: usize,
+    ) -> Result<String, minijinja::Error> {
+        let config = ctx.config;
+        let mut name = scope.to_vec();
+        name.push(id.to_owned());
+        let type_name = config.type_name(id);
+        let key_name = format!("{type_name}Key");
+
+        let mut rendered = String::new();
+        if let Some(fields) = ctx.keys.key(&name) {
+            let mut allow_lints = allowed_lints("non_camel_case_types", config.camel_case_types);
+            if !config.snake_case_members {
+                allow_lints.push_str(", non_snake_case");
+            }
+            rendered.push_str(
+                &ctx.env
+                    .get_template("key.j2")?
+                    .render(minijinja::context! {
+                        struct_name => type_name,
+                        key_name,
+                        fields,
+                        allow_lints,
+                        deprecated => self.deprecated(),
+                        indent_level => level
+                    })?,
+            );
+        }
+        if let Some(encoding) = ctx.keys.encoding(&name) {
+            let tmpl = ctx.env.get_template("key_hash_encode.j2")?;
+            rendered.push_str(&match encoding {
+                KeyEncoding::Members(fields) => tmpl.render(minijinja::context! {
+                    type_name,
+                    fields,
+                    deprecated => self.deprecated(),
+                    indent_level => level
+                })?,
+                // encoded like its key, read from the struct itself
+                KeyEncoding::Delegate => tmpl.render(minijinja::context! {
+                    type_name,
+                    fields => ctx.keys.key(&name).unwrap_or_default(),
+                    deprecated => self.deprecated(),
+                    indent_level => level
+                })?,
+                KeyEncoding::Ordinal(ordinal) => tmpl.render(minijinja::context! {
+                    type_name,
+                    ordinal,
+                    copy => ctx.derives.is_copy(&name),
+                    deprecated => self.deprecated(),
+                    indent_level => level
+                })?,
+            });
+        }
+        Ok(rendered)
     }
 }
 
@@ -377,30 +2357,103 @@ pub struct IdlConstDcl {
     pub id: String,
     pub typedcl: IdlTypeSpec,
     pub value: IdlValueExpr,
+    pub annotations: IdlAnnotations,
 }
 
 impl IdlConstDcl {
-    /// Convert the object to a Result<String> for output. The env must have the templates
-    /// already loaded.
+    /// Convert the object to a Result<String> for output. `scope` is the module declaring
+    /// the constant.
     pub fn render(
         &self,
-        env: &minijinja::Environment,
+        ctx: &RenderContext,
+        scope: &[String],
         level: usize,
     ) -> Result<String, minijinja::Error> {
-        let tmpl = env.get_template("const.j2")?;
+        let config = ctx.config;
+        let tmpl = ctx.env.get_template("const.j2")?;
 
-        // Rust does not support const String's. Convert them to &str
-        let type_str = match &self.typedcl {
-            IdlTypeSpec::StringType(_) | IdlTypeSpec::WideStringType(_) => "&str".to_owned(),
-            _ => self.typedcl.to_string(),
+        // Rust does not support const String's. Convert them to &str, also if typedef'd.
+        // Wide strings of the UTF-16 mapping are slices of code units.
+        let utf16 = config.wide_char_mapping == WideCharMapping::Utf16;
+        let is_string = |spec: &IdlTypeSpec, scope: &[String]| {
+            matches!(
+                ctx.types.unalias(spec, scope).0,
+                IdlTypeSpec::StringType(_) | IdlTypeSpec::WideStringType(_)
+            )
+        };
+        let unaliased = ctx.types.unalias(&self.typedcl, scope).0;
+        let type_str = match unaliased {
+            IdlTypeSpec::WideStringType(_) if utf16 => "&[u16]".to_owned(),
+            _ if is_string(&self.typedcl, scope) => "&str".to_owned(),
+            _ => self.typedcl.to_rust_type(config)?,
+        };
+        // constants of newtypes are wrapped, their values are read from the newtype
+        let access = |name: &IdlScopedName| match ctx.constants.type_spec(name, scope) {
+            Some((spec, const_scope)) if !is_string(spec, const_scope) => {
+                ".0".repeat(ctx.newtypes.wrappers(ctx.types, spec, const_scope).len())
+            }
+            _ => String::new(),
+        };
+        // character literals are written as the mapped type
+        let literal = |value: &IdlValueExpr| {
+            value
+                .evaluate(&|_| Ok(None))
+                .map_err(|err| value_error(format!("constant {}: {err}", self.id)))
+        };
+        let value = match (&unaliased, &self.value) {
+            (
+                IdlTypeSpec::CharType | IdlTypeSpec::WideCharType,
+                IdlValueExpr::CharLiteral(_) | IdlValueExpr::WideCharLiteral(_),
+            ) => match literal(&self.value)? {
+                IdlConstValue::Char(value) => config
+                    .char_literal(value, matches!(unaliased, IdlTypeSpec::WideCharType))
+                    .ok_or_else(|| {
+                        value_error(format!(
+                            "constant {}: {value:?} exceeds {type_str}",
+                            self.id
+                        ))
+                    })?,
+                value => return Err(value_error(format!("constant {}: {value}", self.id))),
+            },
+            (
+                IdlTypeSpec::WideStringType(_),
+                IdlValueExpr::StringLiteral(_) | IdlValueExpr::WideStringLiteral(_),
+            ) if utf16 => match literal(&self.value)? {
+                IdlConstValue::String(value) => format!(
+                    "&[{}]",
+                    value
+                        .encode_utf16()
+                        .map(|unit| format!("0x{unit:04X}"))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ),
+                value => return Err(value_error(format!("constant {}: {value}", self.id))),
+            },
+            // binary128 has no literals on stable Rust, the wrapper is built from the value
+            (IdlTypeSpec::F128Type, _) if !config.native_f128 => {
+                match ctx.constants.evaluate(&self.value, scope) {
+                    Ok(IdlConstValue::Float(value)) => config.f128_literal(value),
+                    Ok(IdlConstValue::Integer(value)) => config.f128_literal(value as f64),
+                    Ok(value) => return Err(value_error(format!("constant {}: {value}", self.id))),
+                    Err(err) => return Err(value_error(format!("constant {}: {err}", self.id))),
+                }
+            }
+            _ => self.value.to_rust_with(config, &access),
+        };
+        let value = match is_string(&self.typedcl, scope) {
+            true => value,
+            false => wrap_newtypes(ctx, &self.typedcl, scope, value),
         };
 
-        tmpl.render(minijinja::context! {
-            const_name => self.id,
+        let rendered = tmpl.render(minijinja::context! {
+            const_name => config.const_name(&self.id),
             const_type => type_str,
-            const_value => self.value.to_string(),
+            const_value => value,
+            allow_lints => allowed_lints("non_upper_case_globals", config.screaming_case_constants),
             indent_level => level
-        })
+        })?;
+        let item = IdlItemText::new(&self.annotations, config).without_body();
+        Ok(item.wrap(rendered, level))
     }
 }
 
@@ -411,43 +2464,120 @@ pub struct IdlModule {
     pub modules: LinkedHashMap<String, IdlModule>,
     pub types: LinkedHashMap<String, IdlTypeDcl>,
     pub constants: LinkedHashMap<String, IdlConstDcl>,
+    pub annotations: IdlAnnotations,
 }
 
 impl IdlModule {
+    /// Collect the verbatim text of all elements of the module and its submodules placed
+    /// at `placement`, BEGIN_FILE or END_FILE
+    fn file_text(&self, placement: &str, lines: &mut Vec<String>) {
+        file_text(&self.annotations, placement, lines);
+        for cnst in self.constants.values() {
+            file_text(&cnst.annotations, placement, lines);
+        }
+        for typ in self.types.values() {
+            file_text(&typ.1, placement, lines);
+            match typ.0 {
+                IdlTypeDclKind::StructDcl(_, _, ref members) => members
+                    .iter()
+                    .for_each(|member| file_text(&member.annotations, placement, lines)),
+                IdlTypeDclKind::UnionDcl(_, _, ref switch_cases) => switch_cases
+                    .iter()
+                    .for_each(|case| file_text(&case.elem_spec.annotations, placement, lines)),
+                IdlTypeDclKind::EnumDcl(_, ref enums) => enums
+                    .iter()
+                    .for_each(|variant| file_text(&variant.annotations, placement, lines)),
+                IdlTypeDclKind::TypeDcl(_, _) | IdlTypeDclKind::None => {}
+            }
+        }
+        for module in self.modules.values() {
+            module.file_text(placement, lines);
+        }
+    }
+
     pub fn new(id: Option<String>) -> IdlModule {
         IdlModule {
             id,
             modules: LinkedHashMap::default(),
             types: LinkedHashMap::default(),
             constants: LinkedHashMap::default(),
+            annotations: IdlAnnotations::default(),
         }
     }
 
-    /// Convert the object to a Result<String> for output. The env must have the templates
-    /// already loaded.
+    /// Verify that converting the IDL identifiers into Rust naming conventions does not
+    /// map two different IDL identifiers of the same namespace onto the same Rust name.
+    pub fn check_naming(&self, config: &Configuration) -> Result<(), String> {
+        let namespace = match self.id {
+            Some(ref id) => format!("module {id}"),
+            None => "the root module".to_owned(),
+        };
+        // Rust modules and types share the same namespace
+        check_unique(
+            &namespace,
+            self.types
+                .keys()
+                .map(|id| (id.as_str(), config.type_name(id)))
+                .chain(self.modules.keys().map(|id| (id.as_str(), id.clone()))),
+        )?;
+        check_unique(
+            &namespace,
+            self.constants
+                .keys()
+                .map(|id| (id.as_str(), config.const_name(id))),
+        )?;
+
+        for typ in self.types.values() {
+            typ.check_naming(config)?;
+        }
+        for module in self.modules.values() {
+            module.check_naming(config)?;
+        }
+        Ok(())
+    }
+
+    /// Convert the object to a Result<String> for output. `scope` is the path of the
+    /// module, empty for the root module.
     pub fn render(
         &self,
-        env: &minijinja::Environment,
+        ctx: &RenderContext,
+        scope: &mut Vec<String>,
         level: usize,
     ) -> Result<String, minijinja::Error> {
         let mut module_info = String::new();
         let add = if self.id.is_some() { 1 } else { 0 };
 
-        let mut uses = HashSet::new();
-        for typ in self.types.values() {
-            if let IdlTypeDcl(IdlTypeDclKind::TypeDcl(_, IdlTypeSpec::SequenceType(_))) = typ {
-                uses.insert(IMPORT_VEC);
-            } else if let IdlTypeDcl(IdlTypeDclKind::StructDcl(_, _)) = typ {
-                uses.insert(IMPORT_SERDE);
-            } else if let IdlTypeDcl(IdlTypeDclKind::EnumDcl(_, _)) = typ {
-                uses.insert(IMPORT_SERDE);
-            } else if let IdlTypeDcl(IdlTypeDclKind::UnionDcl(_, _, _)) = typ {
+        // ordered, the imports must not depend on the hash seed
+        let mut uses = BTreeSet::new();
+        // sequences name `alloc::vec::Vec` without std, bounded ones may be heapless
+        let import_vec = |bound: &Option<Box<IdlValueExpr>>| {
+            !ctx.config.no_std && (bound.is_none() || !ctx.config.heapless)
+        };
+        for (id, typ) in self.types.iter() {
+            if let IdlTypeDclKind::TypeDcl(_, IdlTypeSpec::SequenceType(_, ref bound)) = typ.0 {
+                if import_vec(bound) {
+                    uses.insert(IMPORT_VEC);
+                }
+            }
+            let derives = match typ.0 {
+                IdlTypeDclKind::TypeDcl(..) => {
+                    let mut name = scope.clone();
+                    name.push(id.clone());
+                    ctx.newtypes.is_newtype(&name)
+                }
+                IdlTypeDclKind::None => false,
+                _ => true,
+            };
+            // behind a feature the derives name serde_derive
+            if derives && ctx.config.serde == SerdeSupport::Always {
                 uses.insert(IMPORT_SERDE);
             }
         }
         for cnsts in self.constants.values() {
-            if let IdlTypeSpec::SequenceType(_) = cnsts.typedcl {
-                uses.insert(IMPORT_VEC);
+            if let IdlTypeSpec::SequenceType(_, bound) = &cnsts.typedcl {
+                if import_vec(bound) {
+                    uses.insert(IMPORT_VEC);
+                }
                 break;
             }
         }
@@ -462,33 +2592,55 @@ impl IdlModule {
         }
 
         for typ in self.types.values() {
-            let rendered = typ.render(env, level + add)?;
+            let rendered = typ.render(ctx, scope, level + add)?;
             module_info.push_str(&rendered);
             module_info.push('\n');
         }
 
-        for module in self.modules.values() {
-            let rendered = module.render(env, level + add)?;
+        for (id, module) in self.modules.iter() {
+            scope.push(id.clone());
+            let rendered = module.render(ctx, scope, level + add);
+            scope.pop();
+            let rendered = rendered?;
             module_info.push_str(&rendered);
             module_info.push('\n');
         }
 
         for cnst in self.constants.values() {
-            let rendered = cnst.render(env, level + add)?;
+            let rendered = cnst.render(ctx, scope, level + add)?;
             module_info.push_str(&rendered);
             module_info.push('\n');
         }
 
         match self.id {
             Some(ref id_str) => {
-                let tmpl = env.get_template("module.j2")?;
-                tmpl.render(minijinja::context! {
+                let tmpl = ctx.env.get_template("module.j2")?;
+                let item = IdlItemText::new(&self.annotations, ctx.config);
+                let rendered = tmpl.render(minijinja::context! {
                     module_name => id_str,
                     module_information => module_info,
+                    item,
                     indent_level => level
-                })
+                })?;
+                Ok(item.wrap(rendered, level))
+            }
+            None => {
+                let mut begin = Vec::new();
+                let mut end = Vec::new();
+                self.file_text("BEGIN_FILE", &mut begin);
+                self.file_text("END_FILE", &mut end);
+                let mut file = String::new();
+                for line in begin {
+                    file.push_str(&line);
+                    file.push('\n');
+                }
+                file.push_str(&module_info);
+                for line in end {
+                    file.push_str(&line);
+                    file.push('\n');
+                }
+                Ok(file)
             }
-            None => Ok(module_info),
         }
     }
 }
@@ -0,0 +1,587 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+use crate::{
+    ast::*,
+    cdr,
+    extensibility::{Extensibility, ExtensibilitySupport},
+    index::{ConstIndex, TypeIndex},
+    member_id::MemberIdSupport,
+    parameter_list::ParameterListSupport,
+};
+use serde_derive::Serialize;
+use std::collections::{HashMap, HashSet};
+
+/// XCDR1 parameters with an ID from here on use the extended header
+const SHORT_ID_LIMIT: u32 = 0x3F00;
+
+/// Length of a short and an extended XCDR1 parameter header
+const PARAMETER_HEADER: usize = 4;
+const EXTENDED_PARAMETER_HEADER: usize = 12;
+
+/// Parameters, DHEADERs, EMHEADERs and lengths are 4 byte aligned
+const HEADER_ALIGN: usize = 4;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Version {
+    Xcdr1,
+    Xcdr2,
+}
+
+impl Version {
+    /// Largest alignment of a primitive, alignment only depends on the offset modulo it
+    fn max_align(self) -> usize {
+        match self {
+            Version::Xcdr1 => 8,
+            Version::Xcdr2 => 4,
+        }
+    }
+
+    /// Offset of a primitive of `size` bytes written at `offset`
+    fn align(self, offset: usize, size: usize) -> usize {
+        offset.next_multiple_of(size.clamp(1, self.max_align()))
+    }
+}
+
+/// Why the end of a value cannot be computed
+enum NoBound {
+    /// An unbounded string or sequence is reachable, the type is recursive or the size
+    /// exceeds `usize`
+    Unbounded,
+    Invalid(String),
+}
+
+type End = Result<usize, NoBound>;
+
+fn add(offset: usize, size: usize) -> End {
+    offset.checked_add(size).ok_or(NoBound::Unbounded)
+}
+
+fn mul(count: usize, size: usize) -> End {
+    count.checked_mul(size).ok_or(NoBound::Unbounded)
+}
+
+/// Data storage to align with Jinja (cdr_bounds.j2), the maximum serialized sizes of a
+/// type without encapsulation header, `None` if unbounded
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct IdlBounds {
+    pub xcdr1: Option<usize>,
+    pub xcdr2: Option<usize>,
+}
+
+impl IdlBounds {
+    /// Whether no unbounded string or sequence is reachable from the type
+    pub fn bounded(&self) -> bool {
+        self.xcdr1.is_some() && self.xcdr2.is_some()
+    }
+}
+
+/// The maximum serialized sizes of all structs, unions and enums
+#[derive(Debug, Default)]
+pub struct BoundsSupport {
+    bounds: HashMap<Vec<String>, IdlBounds>,
+}
+
+impl BoundsSupport {
+    /// Maximum sizes of the type with the fully qualified `name`
+    pub fn bounds(&self, name: &[String]) -> IdlBounds {
+        self.bounds.get(name).cloned().unwrap_or_default()
+    }
+}
+
+/// A struct member or union element as far as its size is concerned
+struct SizedMember {
+    type_spec: IdlTypeSpec,
+    /// Scope the type is declared in, the base's members use the scope of the base
+    scope: Vec<String>,
+    optional: bool,
+    id: u32,
+}
+
+struct BoundsAnalysis<'a> {
+    types: TypeIndex<'a>,
+    constants: ConstIndex,
+    extensibility: &'a ExtensibilitySupport,
+    member_ids: &'a MemberIdSupport,
+    parameter_lists: &'a ParameterListSupport,
+    /// End of a struct or union by version and start offset modulo the maximum
+    /// alignment, relative to the start offset rounded down. `None` if unbounded.
+    ends: HashMap<(Vec<String>, Version, usize), Option<usize>>,
+    /// Types being analyzed, reaching one of them again means the type is recursive
+    visiting: HashSet<Vec<String>>,
+}
+
+impl BoundsAnalysis<'_> {
+    /// End offset of a value of the IDL type written at `offset`, the encoding follows
+    /// `omg_cdr::CdrWriter` and is maximized over all values of the type
+    fn spec_end(
+        &mut self,
+        spec: &IdlTypeSpec,
+        scope: &[String],
+        offset: usize,
+        version: Version,
+    ) -> End {
+        let (spec, scope) = self.types.unalias(spec, scope);
+        let primitive = |size: usize| add(version.align(offset, size), size);
+        match spec {
+            IdlTypeSpec::BooleanType | IdlTypeSpec::OctetType | IdlTypeSpec::CharType => {
+                primitive(1)
+            }
+            IdlTypeSpec::I16Type | IdlTypeSpec::U16Type | IdlTypeSpec::WideCharType => primitive(2),
+            IdlTypeSpec::I32Type | IdlTypeSpec::U32Type | IdlTypeSpec::F32Type => primitive(4),
+            IdlTypeSpec::I64Type | IdlTypeSpec::U64Type | IdlTypeSpec::F64Type => primitive(8),
+            IdlTypeSpec::F128Type => primitive(16),
+            // the length includes the terminating NUL
+            IdlTypeSpec::StringType(ref bound) => {
+                let length = add(self.bound(bound, &scope)?, 1)?;
+                add(primitive(4)?, length)
+            }
+            // UTF-16 code units without NUL, the length counts bytes
+            IdlTypeSpec::WideStringType(ref bound) => {
+                let length = mul(self.bound(bound, &scope)?, 2)?;
+                add(primitive(4)?, length)
+            }
+            IdlTypeSpec::SequenceType(ref element, ref bound) => {
+                let count = self.bound(bound, &scope)?;
+                if self.is_parameter(element, &scope) {
+                    let end = self.repeat(offset, count, version, |analysis, offset| {
+                        let value = analysis.spec_end(element, &scope, 0, version)?;
+                        let value = value.next_multiple_of(HEADER_ALIGN);
+                        add(version.align(offset, 2) + 4, value)
+                    })?;
+                    return add(version.align(end, HEADER_ALIGN), 4);
+                }
+                let primitive = self.is_primitive(element, &scope);
+                self.elements(offset, primitive, version, |analysis, offset| {
+                    let start = add(version.align(offset, HEADER_ALIGN), 4)?;
+                    analysis.repeat(start, count, version, |analysis, offset| {
+                        analysis.spec_end(element, &scope, offset, version)
+                    })
+                })
+            }
+            IdlTypeSpec::ArrayType(ref element, ref dims) => {
+                let dims = dims
+                    .iter()
+                    .map(|dim| self.constants.evaluate_size(dim, &scope))
+                    .collect::<Result<Vec<usize>, String>>()
+                    .map_err(NoBound::Invalid)?;
+                self.array_end(element, &dims, &scope, offset, version)
+            }
+            IdlTypeSpec::ScopedName(ref name) => {
+                let Some(typ) = self.types.resolve(name, &scope) else {
+                    return Err(NoBound::Invalid(format!("unknown type {name}")));
+                };
+                match typ.dcl.0 {
+                    IdlTypeDclKind::EnumDcl(..) => match cdr::enum_holder(&typ.dcl.1) {
+                        "u8" => primitive(1),
+                        "u16" => primitive(2),
+                        _ => primitive(4),
+                    },
+                    _ => {
+                        let name = typ.name.to_vec();
+                        self.type_end(&name, offset, version)
+                    }
+                }
+            }
+            IdlTypeSpec::None => Err(NoBound::Invalid("missing type".to_owned())),
+        }
+    }
+
+    /// Value of the bound of a string or sequence
+    fn bound(&self, bound: &Option<Box<IdlValueExpr>>, scope: &[String]) -> End {
+        match bound {
+            Some(bound) => self
+                .constants
+                .evaluate_size(bound, scope)
+                .map_err(NoBound::Invalid),
+            None => Err(NoBound::Unbounded),
+        }
+    }
+
+    /// An array is nested per dimension, the first dimension being the outermost
+    fn array_end(
+        &mut self,
+        element: &IdlTypeSpec,
+        dims: &[usize],
+        scope: &[String],
+        offset: usize,
+        version: Version,
+    ) -> End {
+        let Some((count, inner)) = dims.split_first() else {
+            return self.spec_end(element, scope, offset, version);
+        };
+        let primitive = self.is_primitive(element, scope);
+        self.elements(offset, primitive, version, |analysis, offset| {
+            analysis.repeat(offset, *count, version, |analysis, offset| {
+                analysis.array_end(element, inner, scope, offset, version)
+            })
+        })
+    }
+
+    /// Elements of a sequence or array, XCDR2 delimits elements of non-primitive types
+    fn elements(
+        &mut self,
+        offset: usize,
+        primitive: bool,
+        version: Version,
+        end: impl FnOnce(&mut Self, usize) -> End,
+    ) -> End {
+        match version {
+            Version::Xcdr2 if !primitive => {
+                let length = end(self, 0)?;
+                add(version.align(offset, HEADER_ALIGN) + 4, length)
+            }
+            _ => end(self, offset),
+        }
+    }
+
+    /// End of `count` consecutive values. As the end of a value only depends on its
+    /// start modulo the maximum alignment, the growth repeats after at most that many
+    /// values and the remaining cycles are skipped.
+    fn repeat(
+        &mut self,
+        offset: usize,
+        count: usize,
+        version: Version,
+        mut end: impl FnMut(&mut Self, usize) -> End,
+    ) -> End {
+        let mut seen = HashMap::new();
+        let mut offset = offset;
+        let mut index = 0;
+        while index < count {
+            let residue = offset % version.max_align();
+            if let Some((previous, start)) = seen.insert(residue, (index, offset)) {
+                let period = index - previous;
+                let cycles = (count - index) / period;
+                offset = add(offset, mul(cycles, offset - start)?)?;
+                // fewer values than a period remain
+                for _ in index + cycles * period..count {
+                    offset = end(self, offset)?;
+                }
+                return Ok(offset);
+            }
+            offset = end(self, offset)?;
+            index += 1;
+        }
+        Ok(offset)
+    }
+
+    /// End of a struct or union, computed once per version and alignment residue
+    fn type_end(&mut self, name: &[String], offset: usize, version: Version) -> End {
+        let residue = offset % version.max_align();
+        let key = (name.to_vec(), version, residue);
+        let end = match self.ends.get(&key) {
+            Some(end) => *end,
+            None => {
+                if !self.visiting.insert(name.to_vec()) {
+                    return Err(NoBound::Unbounded);
+                }
+                let end = self.constructed_end(name, residue, version);
+                self.visiting.remove(name);
+                let end = match end {
+                    Ok(end) => Some(end),
+                    Err(NoBound::Unbounded) => None,
+                    Err(invalid) => return Err(invalid),
+                };
+                self.ends.insert(key, end);
+                end
+            }
+        };
+        add(offset - residue, end.ok_or(NoBound::Unbounded)?)
+    }
+
+    fn constructed_end(&mut self, name: &[String], offset: usize, version: Version) -> End {
+        let Some(typ) = self.types.resolve(&IdlScopedName(name.to_vec(), true), &[]) else {
+            return Err(NoBound::Invalid(format!(
+                "unknown type {}",
+                name.join("::")
+            )));
+        };
+        let scope = typ.scope().to_vec();
+        let kind = self.extensibility.kind(name);
+        match typ.dcl.0.clone() {
+            IdlTypeDclKind::StructDcl(..) => {
+                let members = self.struct_members(name)?;
+                if self.parameter_lists.ids(name).is_some() {
+                    return self.parameter_list_end(&members, offset, version);
+                }
+                match (version, kind) {
+                    (Version::Xcdr1, Extensibility::Mutable) => {
+                        let mut offset = offset;
+                        for member in members.iter() {
+                            let value =
+                                self.spec_end(&member.type_spec, &member.scope, 0, version)?;
+                            offset = parameter_end(offset, member.id, value)?;
+                        }
+                        add(version.align(offset, HEADER_ALIGN), 4)
+                    }
+                    (Version::Xcdr2, Extensibility::Mutable) => {
+                        let mut length = 0;
+                        for member in members.iter() {
+                            let value =
+                                self.spec_end(&member.type_spec, &member.scope, 0, version)?;
+                            let fixed = self.is_primitive(&member.type_spec, &member.scope);
+                            length = member_header_end(length, value, fixed)?;
+                        }
+                        add(version.align(offset, HEADER_ALIGN) + 4, length)
+                    }
+                    (Version::Xcdr2, Extensibility::Appendable) => {
+                        let length = self.members_end(&members, 0, version)?;
+                        add(version.align(offset, HEADER_ALIGN) + 4, length)
+                    }
+                    _ => self.members_end(&members, offset, version),
+                }
+            }
+            IdlTypeDclKind::UnionDcl(_, switch_type, switch_cases) => {
+                let ids = self.member_ids.ids(name);
+                let mut end = None;
+                for case in switch_cases.iter() {
+                    let element = &case.elem_spec;
+                    let member = SizedMember {
+                        type_spec: element.type_spec.clone(),
+                        scope: scope.clone(),
+                        optional: element.annotations.is_set("optional"),
+                        id: ids
+                            .iter()
+                            .find(|member| member.idl_name == element.id)
+                            .map(|member| member.id)
+                            .unwrap_or_default(),
+                    };
+                    let case_end =
+                        self.union_end(&switch_type, &scope, &member, offset, kind, version)?;
+                    end = end.max(Some(case_end));
+                }
+                match end {
+                    Some(end) => Ok(end),
+                    None => self.spec_end(&switch_type, &scope, offset, version),
+                }
+            }
+            _ => Ok(offset),
+        }
+    }
+
+    /// All members of a struct including those of its bases, in order of encoding
+    fn struct_members(&self, name: &[String]) -> Result<Vec<SizedMember>, NoBound> {
+        let Some(typ) = self.types.resolve(&IdlScopedName(name.to_vec(), true), &[]) else {
+            return Err(NoBound::Invalid(format!(
+                "unknown type {}",
+                name.join("::")
+            )));
+        };
+        let scope = typ.scope().to_vec();
+        let IdlTypeDclKind::StructDcl(ref id, ref base, ref members) = typ.dcl.0 else {
+            return Ok(Vec::new());
+        };
+        let mut sized = match base {
+            Some(base) => {
+                let (spec, base_scope) = self
+                    .types
+                    .unalias(&IdlTypeSpec::ScopedName(base.clone()), &scope);
+                let base_name = match spec {
+                    IdlTypeSpec::ScopedName(ref base) => self
+                        .types
+                        .resolve(base, &base_scope)
+                        .map(|typ| typ.name.to_vec()),
+                    _ => None,
+                }
+                .ok_or_else(|| NoBound::Invalid(format!("unknown base {base} of struct {id}")))?;
+                self.struct_members(&base_name)?
+            }
+            None => Vec::new(),
+        };
+        let ids = self.member_ids.ids(name);
+        sized.extend(members.iter().map(|member| {
+            SizedMember {
+                type_spec: member.type_spec.clone(),
+                scope: scope.clone(),
+                optional: member.annotations.is_set("optional"),
+                id: ids
+                    .iter()
+                    .find(|id| id.idl_name == member.id)
+                    .map(|id| id.id)
+                    .unwrap_or_default(),
+            }
+        }));
+        Ok(sized)
+    }
+
+    /// Members of a final or appendable struct, one after the other
+    fn members_end(&mut self, members: &[SizedMember], offset: usize, version: Version) -> End {
+        let mut offset = offset;
+        for member in members {
+            offset = self.member_end(member, offset, version)?;
+        }
+        Ok(offset)
+    }
+
+    /// A member of a final or appendable type, `@optional` members are preceded by a
+    /// parameter header in XCDR1 and a presence flag in XCDR2
+    fn member_end(&mut self, member: &SizedMember, offset: usize, version: Version) -> End {
+        match (version, member.optional) {
+            (_, false) => self.spec_end(&member.type_spec, &member.scope, offset, version),
+            (Version::Xcdr1, true) => {
+                let value = self.spec_end(&member.type_spec, &member.scope, 0, version)?;
+                parameter_end(offset, member.id, value)
+            }
+            (Version::Xcdr2, true) => {
+                self.spec_end(&member.type_spec, &member.scope, offset + 1, version)
+            }
+        }
+    }
+
+    /// Every member as RTPS parameter followed by the sentinel
+    fn parameter_list_end(
+        &mut self,
+        members: &[SizedMember],
+        offset: usize,
+        version: Version,
+    ) -> End {
+        let mut offset = offset;
+        for member in members {
+            let value = self.spec_end(&member.type_spec, &member.scope, 0, version)?;
+            offset = add(
+                version.align(offset, 2) + 4,
+                value.next_multiple_of(HEADER_ALIGN),
+            )?;
+        }
+        add(version.align(offset, HEADER_ALIGN), 4)
+    }
+
+    /// A union with the selected `member`
+    fn union_end(
+        &mut self,
+        switch_type: &IdlTypeSpec,
+        scope: &[String],
+        member: &SizedMember,
+        offset: usize,
+        kind: Extensibility,
+        version: Version,
+    ) -> End {
+        match (version, kind) {
+            (Version::Xcdr1, Extensibility::Mutable) => {
+                let discriminator = self.spec_end(switch_type, scope, 0, version)?;
+                let offset = parameter_end(offset, 0, discriminator)?;
+                let value = self.member_end(member, 0, version)?;
+                let offset = parameter_end(offset, member.id, value)?;
+                add(version.align(offset, HEADER_ALIGN), 4)
+            }
+            (Version::Xcdr2, Extensibility::Mutable) => {
+                let discriminator = self.spec_end(switch_type, scope, 0, version)?;
+                let length = member_header_end(0, discriminator, true)?;
+                let value = self.member_end(member, 0, version)?;
+                let fixed = !member.optional && self.is_primitive(&member.type_spec, &member.scope);
+                let length = member_header_end(length, value, fixed)?;
+                add(version.align(offset, HEADER_ALIGN) + 4, length)
+            }
+            (Version::Xcdr2, Extensibility::Appendable) => {
+                let length =
+                    self.union_end(switch_type, scope, member, 0, Extensibility::Final, version)?;
+                add(version.align(offset, HEADER_ALIGN) + 4, length)
+            }
+            _ => {
+                let offset = self.spec_end(switch_type, scope, offset, version)?;
+                self.member_end(member, offset, version)
+            }
+        }
+    }
+
+    /// Whether the IDL type has a fixed size and is encoded without headers, as
+    /// `omg_cdr::CdrEncode::PRIMITIVE` tells
+    fn is_primitive(&self, spec: &IdlTypeSpec, scope: &[String]) -> bool {
+        match self.types.unalias(spec, scope) {
+            (IdlTypeSpec::ArrayType(ref element, _), scope) => self.is_primitive(element, &scope),
+            (IdlTypeSpec::ScopedName(ref name), scope) => self
+                .types
+                .resolve(name, &scope)
+                .is_some_and(|typ| matches!(typ.dcl.0, IdlTypeDclKind::EnumDcl(..))),
+            (
+                IdlTypeSpec::StringType(_)
+                | IdlTypeSpec::WideStringType(_)
+                | IdlTypeSpec::SequenceType(..)
+                | IdlTypeSpec::None,
+                _,
+            ) => false,
+            _ => true,
+        }
+    }
+
+    /// Whether the IDL type is a union encoded as RTPS parameter
+    fn is_parameter(&self, spec: &IdlTypeSpec, scope: &[String]) -> bool {
+        match self.types.unalias(spec, scope) {
+            (IdlTypeSpec::ScopedName(ref name), scope) => self
+                .types
+                .resolve(name, &scope)
+                .is_some_and(|typ| self.parameter_lists.is_parameter(typ.name)),
+            _ => false,
+        }
+    }
+}
+
+/// An XCDR1 parameter holding a value of `length` bytes, padded to 4 bytes
+fn parameter_end(offset: usize, id: u32, length: usize) -> End {
+    let length = length.next_multiple_of(HEADER_ALIGN);
+    let header = if id < SHORT_ID_LIMIT && length <= u16::MAX.into() {
+        PARAMETER_HEADER
+    } else {
+        EXTENDED_PARAMETER_HEADER
+    };
+    add(offset.next_multiple_of(HEADER_ALIGN) + header, length)
+}
+
+/// An EMHEADER and a value of at most `length` bytes. Values of 1, 2, 4 or 8 bytes
+/// omit the NEXTINT, which is only assumed for values of a fixed size.
+fn member_header_end(offset: usize, length: usize, fixed: bool) -> End {
+    let header = match length {
+        1 | 2 | 4 | 8 if fixed => 4,
+        _ => 8,
+    };
+    add(offset.next_multiple_of(HEADER_ALIGN) + header, length)
+}
+
+/// Compute the maximum XCDR1 and XCDR2 sizes of all structs, unions and enums. Types
+/// reaching an unbounded string or sequence, or themselves, have no maximum size.
+pub fn analyze(
+    root_module: &IdlModule,
+    extensibility: &ExtensibilitySupport,
+    member_ids: &MemberIdSupport,
+    parameter_lists: &ParameterListSupport,
+) -> Result<BoundsSupport, String> {
+    let mut analysis = BoundsAnalysis {
+        types: TypeIndex::new(root_module),
+        constants: ConstIndex::new(root_module),
+        extensibility,
+        member_ids,
+        parameter_lists,
+        ends: HashMap::new(),
+        visiting: HashSet::new(),
+    };
+    let names = analysis
+        .types
+        .iter()
+        .filter(|typ| {
+            matches!(
+                typ.dcl.0,
+                IdlTypeDclKind::StructDcl(..)
+                    | IdlTypeDclKind::UnionDcl(..)
+                    | IdlTypeDclKind::EnumDcl(..)
+            )
+        })
+        .map(|typ| typ.name.to_vec())
+        .collect::<Vec<_>>();
+    let mut support = BoundsSupport::default();
+    for name in names {
+        let spec = IdlTypeSpec::ScopedName(IdlScopedName(name.clone(), true));
+        let mut size = |version| match analysis.spec_end(&spec, &[], 0, version) {
+            Ok(size) => Ok(Some(size)),
+            Err(NoBound::Unbounded) => Ok(None),
+            Err(NoBound::Invalid(e)) => Err(format!("{}: {e}", name.join("::"))),
+        };
+        let bounds = IdlBounds {
+            xcdr1: size(Version::Xcdr1)?,
+            xcdr2: size(Version::Xcdr2)?,
+        };
+        support.bounds.insert(name, bounds);
+    }
+    Ok(support)
+}
@@ -0,0 +1,142 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+use crate::{ast::*, index::TypeIndex, newtype::NewtypeSupport, ByteMapping, Configuration};
+use std::collections::HashMap;
+
+/// Byte mapping of a single struct member or union element
+#[derive(Debug)]
+struct MemberMapping {
+    mapping: ByteMapping,
+    /// The member is an octet sequence and gets the type of the mapping, arrays keep
+    /// their type
+    sequence: bool,
+}
+
+/// Byte mappings of the octet sequences and arrays of struct members and union elements,
+/// the configured one unless given for the member by its fully qualified name
+#[derive(Debug, Default)]
+pub struct ByteSupport {
+    default: ByteMapping,
+    members: HashMap<Vec<String>, MemberMapping>,
+}
+
+impl ByteSupport {
+    /// Mapping of the struct member or union element with the fully qualified `name`
+    pub fn mapping(&self, name: &[String]) -> ByteMapping {
+        self.members
+            .get(name)
+            .map_or(self.default, |member| member.mapping)
+    }
+
+    /// Rust type and zero value of the member `name` if it maps its octet sequence by
+    /// itself, `None` if the member has the Rust type of its IDL type
+    pub fn member_sequence(
+        &self,
+        config: &Configuration,
+        name: &[String],
+    ) -> Option<(String, String)> {
+        self.members
+            .get(name)
+            .filter(|member| member.sequence)
+            .map(|member| {
+                (
+                    config.byte_sequence_type(member.mapping),
+                    format!("{}::new()", config.byte_sequence_path(member.mapping)),
+                )
+            })
+    }
+
+    /// Whether the member `name` of type `spec` is an octet array serialized by
+    /// serde_bytes
+    pub fn is_byte_array(
+        &self,
+        types: &TypeIndex,
+        newtypes: &NewtypeSupport,
+        name: &[String],
+        spec: &IdlTypeSpec,
+        scope: &[String],
+    ) -> bool {
+        self.mapping(name) != ByteMapping::Vec && is_octet_array(types, newtypes, spec, scope)
+    }
+}
+
+/// Whether the type is a one-dimensional array of octets, serde_bytes supports no nested
+/// arrays. Typedefs are followed unless they are newtypes.
+pub fn is_octet_array(
+    types: &TypeIndex,
+    newtypes: &NewtypeSupport,
+    spec: &IdlTypeSpec,
+    scope: &[String],
+) -> bool {
+    matches!(
+        newtypes.unalias(types, spec, scope).0,
+        IdlTypeSpec::ArrayType(ref element, ref dims)
+            if dims.len() == 1 && matches!(**element, IdlTypeSpec::OctetType)
+    )
+}
+
+/// Collect the byte mappings configured per member. Configured names must refer to struct
+/// members or union elements of an octet sequence or one-dimensional octet array, the
+/// type may be a typedef but no newtype.
+pub fn analyze(
+    root_module: &IdlModule,
+    config: &Configuration,
+    newtypes: &NewtypeSupport,
+) -> Result<ByteSupport, String> {
+    let types = TypeIndex::new(root_module);
+    let mut support = ByteSupport {
+        default: config.byte_mapping,
+        members: HashMap::new(),
+    };
+    for (member_name, mapping) in config.member_byte_mappings.iter() {
+        let mut name = member_name
+            .trim_start_matches("::")
+            .split("::")
+            .map(str::to_owned)
+            .collect::<Vec<_>>();
+        let id = name.pop().unwrap_or_default();
+        let unknown = || format!("unknown member {member_name}");
+        let typ = types
+            .resolve(&IdlScopedName(name, true), &[])
+            .ok_or_else(unknown)?;
+        let spec = match typ.dcl.0 {
+            IdlTypeDclKind::StructDcl(_, _, ref members) => members
+                .iter()
+                .find(|member| member.id == id)
+                .map(|member| &member.type_spec),
+            IdlTypeDclKind::UnionDcl(_, _, ref switch_cases) => switch_cases
+                .iter()
+                .map(|case| &case.elem_spec)
+                .find(|element| element.id == id)
+                .map(|element| &element.type_spec),
+            _ => None,
+        }
+        .ok_or_else(unknown)?;
+        let sequence = match newtypes.unalias(&types, spec, typ.scope()).0 {
+            // bounded sequences of the heapless mapping need no allocator, they stay
+            IdlTypeSpec::SequenceType(ref element, ref bound)
+                if matches!(**element, IdlTypeSpec::OctetType) =>
+            {
+                bound.is_none() || !config.heapless
+            }
+            _ if is_octet_array(&types, newtypes, spec, typ.scope()) => false,
+            _ => {
+                return Err(format!(
+                    "{member_name} is no octet sequence or one-dimensional octet array"
+                ))
+            }
+        };
+        let mut name = typ.name.to_vec();
+        name.push(id);
+        support.members.insert(
+            name,
+            MemberMapping {
+                mapping: *mapping,
+                sequence,
+            },
+        );
+    }
+    Ok(support)
+}
@@ -0,0 +1,334 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+use crate::{
+    ast::*,
+    index::{relative_path, ConstIndex, TypeIndex},
+    newtype::NewtypeSupport,
+    parameter_list::ParameterListSupport,
+    Configuration,
+};
+use serde_derive::Serialize;
+
+/// Data storage to align with Jinja (cdr_struct.j2), a struct member or the base
+#[derive(Serialize)]
+pub struct IdlCdrMember {
+    pub name: String,
+    /// Associated constant holding the member ID, empty for the base
+    pub id_const: String,
+    pub base: bool,
+    pub optional: bool,
+    pub key: bool,
+    /// Parameter ID of the member of a parameter list, `None` otherwise
+    pub parameter_id: Option<String>,
+    pub encode: String,
+    pub decode: String,
+}
+
+/// Data storage to align with Jinja (cdr_union.j2), a union variant
+#[derive(Serialize)]
+pub struct IdlCdrBranch {
+    pub variant: String,
+    pub element: String,
+    pub id_const: String,
+    /// Discriminator value selecting the branch
+    pub discriminator: String,
+    /// Match pattern of the discriminator, `None` for the `default` branch
+    pub pattern: Option<String>,
+    /// Parameter ID of the branch of a parameter union, `None` otherwise
+    pub parameter_id: Option<String>,
+    pub encode: String,
+    /// Decodes the element of the parameter read by `decode_parameter`
+    pub decode: String,
+    /// Decodes the element selected by the discriminator read by `read_union`
+    pub read: String,
+}
+
+/// Codec of IDL types sharing their Rust type with another IDL type, `None` if the
+/// `CdrEncode` and `CdrDecode` implementations of the Rust type apply. Sequences of
+/// parameter unions are RTPS parameter lists, newtypes implement both traits.
+pub fn codec(
+    types: &TypeIndex,
+    newtypes: &NewtypeSupport,
+    parameters: &ParameterListSupport,
+    spec: &IdlTypeSpec,
+    scope: &[String],
+) -> Option<String> {
+    let (spec, scope) = newtypes.unalias(types, spec, scope);
+    match spec {
+        IdlTypeSpec::WideCharType => Some("omg_cdr::WideChar".to_owned()),
+        IdlTypeSpec::WideStringType(_) => Some("omg_cdr::WideString".to_owned()),
+        IdlTypeSpec::SequenceType(ref element, _)
+            if is_parameter(types, newtypes, parameters, element, &scope) =>
+        {
+            Some("omg_cdr::ParameterSequence".to_owned())
+        }
+        IdlTypeSpec::SequenceType(ref element, _) => {
+            codec(types, newtypes, parameters, element, &scope)
+                .map(|codec| format!("omg_cdr::Sequence<{codec}>"))
+        }
+        IdlTypeSpec::ArrayType(ref element, ref dims) => {
+            codec(types, newtypes, parameters, element, &scope).map(|codec| {
+                dims.iter()
+                    .fold(codec, |codec, _| format!("omg_cdr::Array<{codec}>"))
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Whether the IDL type is a union encoded as RTPS parameter
+fn is_parameter(
+    types: &TypeIndex,
+    newtypes: &NewtypeSupport,
+    parameters: &ParameterListSupport,
+    spec: &IdlTypeSpec,
+    scope: &[String],
+) -> bool {
+    match newtypes.unalias(types, spec, scope) {
+        (IdlTypeSpec::ScopedName(ref name), scope) => types
+            .resolve(name, &scope)
+            .is_some_and(|typ| parameters.is_parameter(typ.name)),
+        _ => false,
+    }
+}
+
+/// Paths of the functions serializing and deserializing a value of the IDL type
+pub fn functions(
+    types: &TypeIndex,
+    newtypes: &NewtypeSupport,
+    parameters: &ParameterListSupport,
+    spec: &IdlTypeSpec,
+    scope: &[String],
+) -> (String, String) {
+    match codec(types, newtypes, parameters, spec, scope) {
+        Some(codec) => (
+            format!("<{codec} as omg_cdr::Codec<_>>::encode"),
+            format!("<{codec} as omg_cdr::Codec<_>>::decode"),
+        ),
+        None => (
+            "omg_cdr::CdrEncode::encode".to_owned(),
+            "omg_cdr::CdrDecode::decode".to_owned(),
+        ),
+    }
+}
+
+/// Integer type holding the ordinal of an enum, selected by `@bit_bound`
+pub fn enum_holder(annotations: &IdlAnnotations) -> &'static str {
+    let bit_bound = annotations
+        .get("bit_bound")
+        .and_then(|annotation| annotation.params.first())
+        .and_then(|param| param.value.as_ref());
+    match bit_bound {
+        Some(IdlConstValue::Integer(bits)) if *bits <= 8 => "u8",
+        Some(IdlConstValue::Integer(bits)) if *bits <= 16 => "u16",
+        _ => "u32",
+    }
+}
+
+/// Ordinals of the enumerators, counting up from the previous one unless set by `@value`
+pub fn enumerator_values(enumerators: &[IdlEnumerator]) -> Vec<i128> {
+    let mut next = 0;
+    enumerators
+        .iter()
+        .map(|enumerator| {
+            let value = match enumerator
+                .annotations
+                .get("value")
+                .and_then(|annotation| annotation.params.first())
+                .and_then(|param| param.value.as_ref())
+            {
+                Some(IdlConstValue::Integer(value)) => *value,
+                _ => next,
+            };
+            next = value + 1;
+            value
+        })
+        .collect()
+}
+
+/// Rust expressions of the discriminator values of a union's labels, the `default`
+/// label gets a value no other label uses
+pub struct Discriminator<'a> {
+    types: &'a TypeIndex<'a>,
+    constants: &'a ConstIndex,
+    config: &'a Configuration,
+    /// The discriminator type with typedefs resolved
+    spec: IdlTypeSpec,
+    spec_scope: Vec<String>,
+    /// The discriminator type as Rust type of the union's scope
+    rust_type: String,
+    /// Rust type of the unwrapped values of a newtype discriminator, `rust_type` else
+    value_type: String,
+    /// Paths of the newtypes wrapping the values, the outermost first
+    wrappers: Vec<String>,
+    scope: &'a [String],
+}
+
+impl<'a> Discriminator<'a> {
+    pub fn new(
+        types: &'a TypeIndex<'a>,
+        newtypes: &NewtypeSupport,
+        constants: &'a ConstIndex,
+        config: &'a Configuration,
+        spec: &IdlTypeSpec,
+        scope: &'a [String],
+    ) -> Result<Self, String> {
+        let rust_type = spec
+            .to_rust(config)
+            .map_err(|_| format!("invalid type {spec:?}"))?;
+        let wrappers = newtypes.wrappers(types, spec, scope);
+        let (spec, spec_scope) = types.unalias(spec, scope);
+        let value_type = match (wrappers.is_empty(), &spec) {
+            (true, _) => rust_type.clone(),
+            (false, IdlTypeSpec::ScopedName(name)) => types
+                .resolve(name, &spec_scope)
+                .map(|typ| relative_path(typ.name, scope, config))
+                .ok_or_else(|| format!("unknown type {name}"))?,
+            (false, spec) => spec
+                .to_rust(config)
+                .map_err(|_| format!("invalid type {spec:?}"))?,
+        };
+        Ok(Self {
+            types,
+            constants,
+            config,
+            spec,
+            spec_scope,
+            rust_type,
+            value_type,
+            wrappers: wrappers
+                .iter()
+                .map(|typ| relative_path(typ.name, scope, config))
+                .collect(),
+            scope,
+        })
+    }
+
+    /// The Rust type of the discriminator
+    pub fn rust_type(&self) -> &str {
+        &self.rust_type
+    }
+
+    /// Rust expression of the value of a label, also usable as match pattern
+    pub fn value(&self, label: &IdlValueExpr) -> Result<String, String> {
+        self.unwrapped_value(label).map(|value| self.wrap(value))
+    }
+
+    fn unwrapped_value(&self, label: &IdlValueExpr) -> Result<String, String> {
+        if let Some(enumerators) = self.enumerators() {
+            let IdlValueExpr::ScopedName(name) = label else {
+                return Err(format!("label {label} is not an enumerator"));
+            };
+            let enumerator = name.0.last().cloned().unwrap_or_default();
+            if !enumerators.contains(&enumerator) {
+                return Err(format!("label {label} is not an enumerator"));
+            }
+            return Ok(self.enumerator(&enumerator));
+        }
+        let value = self.constants.evaluate(label, self.scope)?;
+        self.literal(&value)
+    }
+
+    /// Rust expression of a value selecting none of the `labels`
+    pub fn default_value(&self, labels: &[&IdlValueExpr]) -> Result<String, String> {
+        let used = labels
+            .iter()
+            .map(|label| self.value(label))
+            .collect::<Result<Vec<String>, String>>()?;
+        let candidates: Box<dyn Iterator<Item = String>> =
+            match self.enumerators() {
+                Some(enumerators) => Box::new(
+                    enumerators
+                        .into_iter()
+                        .map(|enumerator| self.enumerator(&enumerator)),
+                ),
+                None => match self.spec {
+                    IdlTypeSpec::BooleanType => {
+                        Box::new(["false", "true"].map(str::to_owned).into_iter())
+                    }
+                    IdlTypeSpec::CharType | IdlTypeSpec::WideCharType => {
+                        let wide = matches!(self.spec, IdlTypeSpec::WideCharType);
+                        Box::new((0..=u8::MAX).filter_map(move |value| {
+                            self.config.char_literal(char::from(value), wide)
+                        }))
+                    }
+                    _ => Box::new(
+                        (0..=i128::from(u8::MAX))
+                            .filter_map(|value| self.literal(&IdlConstValue::Integer(value)).ok()),
+                    ),
+                },
+            };
+        let mut candidates = candidates.map(|value| self.wrap(value)).peekable();
+        let first = candidates.peek().cloned();
+        // all values are used, the default branch can never be selected
+        candidates
+            .find(|candidate| !used.contains(candidate))
+            .or(first)
+            .ok_or_else(|| format!("no discriminator value of type {}", self.rust_type))
+    }
+
+    /// Enumerators of an enum discriminator, `None` for other types
+    fn enumerators(&self) -> Option<Vec<String>> {
+        let IdlTypeSpec::ScopedName(ref name) = self.spec else {
+            return None;
+        };
+        match self.types.resolve(name, &self.spec_scope)?.dcl.0 {
+            IdlTypeDclKind::EnumDcl(_, ref enumerators) => Some(
+                enumerators
+                    .iter()
+                    .map(|enumerator| enumerator.id.clone())
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+
+    fn enumerator(&self, enumerator: &str) -> String {
+        format!("{}::{}", self.value_type, self.config.type_name(enumerator))
+    }
+
+    /// Wrap an unwrapped value into the newtypes of the discriminator
+    fn wrap(&self, value: String) -> String {
+        self.wrappers
+            .iter()
+            .rev()
+            .fold(value, |value, wrapper| format!("{wrapper}({value})"))
+    }
+
+    fn literal(&self, value: &IdlConstValue) -> Result<String, String> {
+        let suffix = match self.spec {
+            IdlTypeSpec::I16Type => "i16",
+            IdlTypeSpec::I32Type => "i32",
+            IdlTypeSpec::I64Type => "i64",
+            IdlTypeSpec::U16Type => "u16",
+            IdlTypeSpec::U32Type => "u32",
+            IdlTypeSpec::U64Type => "u64",
+            IdlTypeSpec::OctetType => "u8",
+            _ => "",
+        };
+        match (&self.spec, value) {
+            (_, IdlConstValue::Integer(value)) if !suffix.is_empty() => {
+                Ok(format!("{value}_{suffix}"))
+            }
+            (IdlTypeSpec::BooleanType, IdlConstValue::Boolean(value)) => Ok(value.to_string()),
+            (IdlTypeSpec::CharType | IdlTypeSpec::WideCharType, IdlConstValue::Char(value)) => {
+                let wide = matches!(self.spec, IdlTypeSpec::WideCharType);
+                self.config.char_literal(*value, wide).ok_or_else(|| {
+                    format!(
+                        "label {value:?} exceeds the discriminator type {}",
+                        self.rust_type
+                    )
+                })
+            }
+            (_, IdlConstValue::Enumerator(name)) => {
+                Err(format!("label {name} is not a declared constant"))
+            }
+            _ => Err(format!(
+                "label {value} does not match the discriminator type {}",
+                self.rust_type
+            )),
+        }
+    }
+}
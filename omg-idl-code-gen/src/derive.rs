@@ -0,0 +1,239 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+use crate::{
+    ast::*, index::TypeIndex, layout::LayoutSupport, newtype::NewtypeSupport, Configuration,
+};
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt,
+    str::FromStr,
+};
+
+/// Standard derives added to the generated structs, unions and enums on request. All
+/// generated types implement `Clone`, `Debug` and `Default`, enums also `PartialEq`,
+/// `Eq`, `PartialOrd` and `Ord`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Derive {
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+}
+
+impl Derive {
+    pub const ALL: [Derive; 6] = [
+        Derive::Copy,
+        Derive::PartialEq,
+        Derive::Eq,
+        Derive::Hash,
+        Derive::PartialOrd,
+        Derive::Ord,
+    ];
+
+    /// Derives `self` is a subtrait of, which must be derived as well
+    fn requires(self) -> &'static [Derive] {
+        match self {
+            Derive::Eq | Derive::PartialOrd => &[Derive::PartialEq],
+            Derive::Ord => &[Derive::Eq, Derive::PartialOrd],
+            Derive::Copy | Derive::PartialEq | Derive::Hash => &[],
+        }
+    }
+
+    /// Whether floating point numbers implement the trait
+    fn allows_floats(self) -> bool {
+        matches!(self, Derive::Copy | Derive::PartialEq | Derive::PartialOrd)
+    }
+}
+
+impl fmt::Display for Derive {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl FromStr for Derive {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Derive::ALL
+            .into_iter()
+            .find(|derive| derive.to_string() == name)
+            .ok_or_else(|| {
+                let names = Derive::ALL.map(|derive| derive.to_string()).join(", ");
+                format!("unknown derive {name}, expected one of {names}")
+            })
+    }
+}
+
+/// The requested derives every struct, union and enum supports, newtypes derive
+/// everything the wrapped type implements
+#[derive(Debug, Default)]
+pub struct DeriveSupport {
+    derives: HashMap<Vec<String>, BTreeSet<Derive>>,
+    layouts_copy: BTreeSet<Vec<String>>,
+}
+
+impl DeriveSupport {
+    /// Requested derives of the type with the fully qualified `name` all of its members
+    /// implement, without the ones enums always derive
+    pub fn derives(&self, name: &[String]) -> Vec<String> {
+        self.derives
+            .get(name)
+            .into_iter()
+            .flatten()
+            .map(Derive::to_string)
+            .collect()
+    }
+
+    /// Whether the type with the fully qualified `name` is `Copy`, by request or layout
+    pub fn is_copy(&self, name: &[String]) -> bool {
+        self.layouts_copy.contains(name)
+            || self
+                .derives
+                .get(name)
+                .is_some_and(|derives| derives.contains(&Derive::Copy))
+    }
+}
+
+struct DeriveAnalysis<'a> {
+    types: TypeIndex<'a>,
+    support: DeriveSupport,
+}
+
+impl DeriveAnalysis<'_> {
+    /// Whether values of the IDL type implement the trait of `derive`
+    fn implements(&self, derive: Derive, spec: &IdlTypeSpec, scope: &[String]) -> bool {
+        let (spec, scope) = self.types.unalias(spec, scope);
+        match spec {
+            IdlTypeSpec::F32Type | IdlTypeSpec::F64Type | IdlTypeSpec::F128Type => {
+                derive.allows_floats()
+            }
+            IdlTypeSpec::StringType(_)
+            | IdlTypeSpec::WideStringType(_)
+            | IdlTypeSpec::SequenceType(..)
+                if derive == Derive::Copy =>
+            {
+                false
+            }
+            IdlTypeSpec::SequenceType(ref element, _) | IdlTypeSpec::ArrayType(ref element, _) => {
+                self.implements(derive, element, &scope)
+            }
+            IdlTypeSpec::ScopedName(ref name) => {
+                self.types.resolve(name, &scope).is_some_and(|typ| {
+                    let derived = (derive == Derive::Copy && self.support.is_copy(typ.name))
+                        || self
+                            .support
+                            .derives
+                            .get(typ.name)
+                            .is_some_and(|derives| derives.contains(&derive));
+                    match typ.dcl.0 {
+                        IdlTypeDclKind::EnumDcl(..) => {
+                            derived || !matches!(derive, Derive::Copy | Derive::Hash)
+                        }
+                        _ => derived,
+                    }
+                })
+            }
+            IdlTypeSpec::None => false,
+            _ => true,
+        }
+    }
+
+    /// The derives of `derives` a struct or union with the members `specs` supports
+    fn supported(
+        &self,
+        derives: &BTreeSet<Derive>,
+        specs: &[IdlTypeSpec],
+        scope: &[String],
+    ) -> BTreeSet<Derive> {
+        let mut supported = derives
+            .iter()
+            .copied()
+            .filter(|derive| {
+                specs
+                    .iter()
+                    .all(|spec| self.implements(*derive, spec, scope))
+            })
+            .collect::<BTreeSet<_>>();
+        // dropping a derive may drop the ones requiring it
+        while let Some(derive) = supported
+            .iter()
+            .copied()
+            .find(|derive| !derive.requires().iter().all(|r| supported.contains(r)))
+        {
+            supported.remove(&derive);
+        }
+        supported
+    }
+}
+
+/// Compute the requested derives of all structs, unions and enums. A derive is dropped
+/// from a type if one of its members does not implement the trait, i.e. `Eq` with a
+/// floating point member. Recursive types keep a derive unless a member prevents it.
+/// Newtypes are treated like structs requesting all derives.
+pub fn analyze(
+    root_module: &IdlModule,
+    config: &Configuration,
+    layouts: &LayoutSupport,
+    newtypes: &NewtypeSupport,
+) -> DeriveSupport {
+    let mut analysis = DeriveAnalysis {
+        types: TypeIndex::new(root_module),
+        support: DeriveSupport::default(),
+    };
+    let mut members = Vec::new();
+    for typ in analysis.types.iter() {
+        let name = typ.name.to_vec();
+        if layouts.is_copy(&name) {
+            analysis.support.layouts_copy.insert(name.clone());
+        }
+        let mut requested = config.derives.clone();
+        let specs: Vec<IdlTypeSpec> = match typ.dcl.0 {
+            IdlTypeDclKind::StructDcl(_, ref base, ref struct_members) => base
+                .iter()
+                .map(|base| IdlTypeSpec::ScopedName(base.clone()))
+                .chain(struct_members.iter().map(|member| member.type_spec.clone()))
+                .collect(),
+            IdlTypeDclKind::UnionDcl(_, _, ref cases) => cases
+                .iter()
+                .map(|case| case.elem_spec.type_spec.clone())
+                .collect(),
+            IdlTypeDclKind::EnumDcl(..) => {
+                // the other derives are part of every enum
+                let derives = config
+                    .derives
+                    .iter()
+                    .copied()
+                    .filter(|derive| matches!(derive, Derive::Copy | Derive::Hash))
+                    .collect();
+                analysis.support.derives.insert(name, derives);
+                continue;
+            }
+            IdlTypeDclKind::TypeDcl(_, ref aliased) if newtypes.is_newtype(&name) => {
+                requested = Derive::ALL.into();
+                vec![aliased.clone()]
+            }
+            IdlTypeDclKind::TypeDcl(..) | IdlTypeDclKind::None => continue,
+        };
+        analysis.support.derives.insert(name.clone(), requested);
+        members.push((name, typ.scope().to_vec(), specs));
+    }
+    // start from all requested derives and drop the unsupported ones until none changes
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (name, scope, specs) in members.iter() {
+            let derives = &analysis.support.derives[name];
+            let supported = analysis.supported(derives, specs, scope);
+            if supported != *derives {
+                analysis.support.derives.insert(name.clone(), supported);
+                changed = true;
+            }
+        }
+    }
+    analysis.support
+}
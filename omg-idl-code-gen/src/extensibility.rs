@@ -0,0 +1,136 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+use crate::{ast::*, index::TypeIndex};
+use std::{collections::HashMap, fmt};
+
+/// Extensibility kind of a constructed type as defined by DDS-XTypes
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Extensibility {
+    Final,
+    /// The kind of types without an extensibility annotation
+    #[default]
+    Appendable,
+    Mutable,
+}
+
+impl Extensibility {
+    /// Variant of `omg_idl_rt::Extensibility`
+    pub fn variant(&self) -> &'static str {
+        match self {
+            Extensibility::Final => "Final",
+            Extensibility::Appendable => "Appendable",
+            Extensibility::Mutable => "Mutable",
+        }
+    }
+}
+
+impl fmt::Display for Extensibility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.variant().to_lowercase())
+    }
+}
+
+/// The extensibility kinds of all structs, unions and enums
+#[derive(Debug, Default)]
+pub struct ExtensibilitySupport {
+    kinds: HashMap<Vec<String>, Extensibility>,
+}
+
+impl ExtensibilitySupport {
+    /// Kind of the type with the fully qualified `name`
+    pub fn kind(&self, name: &[String]) -> Extensibility {
+        self.kinds.get(name).copied().unwrap_or_default()
+    }
+}
+
+struct ExtensibilityAnalysis<'a> {
+    types: TypeIndex<'a>,
+    /// Derived structs whose base is being resolved
+    deriving: Vec<Vec<String>>,
+    support: ExtensibilitySupport,
+}
+
+impl ExtensibilityAnalysis<'_> {
+    /// Kind of a type, derived structs without an annotation take the kind of their base
+    fn kind(&mut self, name: &[String]) -> Result<Extensibility, String> {
+        if let Some(kind) = self.support.kinds.get(name) {
+            return Ok(*kind);
+        }
+        let typ = self
+            .types
+            .resolve(&IdlScopedName(name.to_vec(), true), &[])
+            .ok_or_else(|| format!("unknown type {}", name.join("::")))?;
+        let dcl = typ.dcl.clone();
+        let declared = dcl.extensibility()?;
+        let kind = match dcl.0 {
+            IdlTypeDclKind::StructDcl(ref id, Some(ref base), _) => {
+                let base_name = self.base(base, &name[..name.len() - 1], id)?;
+                self.deriving.push(name.to_vec());
+                if self.deriving.contains(&base_name) {
+                    return Err(format!("struct {id} derives from itself"));
+                }
+                let base_kind = self.kind(&base_name);
+                self.deriving.pop();
+                let base_kind = base_kind?;
+                match declared {
+                    Some(kind) if kind != base_kind => {
+                        return Err(format!(
+                            "struct {id} is {kind} but its base {} is {base_kind}",
+                            base_name.join("::")
+                        ))
+                    }
+                    _ => base_kind,
+                }
+            }
+            IdlTypeDclKind::EnumDcl(ref id, _) if declared == Some(Extensibility::Mutable) => {
+                return Err(format!("enum {id} cannot be mutable"))
+            }
+            _ => declared.unwrap_or_default(),
+        };
+        self.support.kinds.insert(name.to_vec(), kind);
+        Ok(kind)
+    }
+
+    /// Fully qualified name of the base of struct `id`, typedefs are followed
+    fn base(
+        &self,
+        base: &IdlScopedName,
+        scope: &[String],
+        id: &str,
+    ) -> Result<Vec<String>, String> {
+        let spec = IdlTypeSpec::ScopedName(base.clone());
+        let (spec, scope) = self.types.unalias(&spec, scope);
+        let typ = match spec {
+            IdlTypeSpec::ScopedName(ref name) => self.types.resolve(name, &scope),
+            _ => None,
+        };
+        match typ {
+            Some(typ) if matches!(typ.dcl.0, IdlTypeDclKind::StructDcl(_, _, _)) => {
+                Ok(typ.name.to_vec())
+            }
+            _ => Err(format!("the base {base} of struct {id} is not a struct")),
+        }
+    }
+}
+
+/// Determine the extensibility kinds of all types and verify them against the rules of
+/// DDS-XTypes: a derived struct has the kind of its base and enums are never mutable.
+pub fn analyze(root_module: &IdlModule) -> Result<ExtensibilitySupport, String> {
+    let mut analysis = ExtensibilityAnalysis {
+        types: TypeIndex::new(root_module),
+        deriving: Vec::new(),
+        support: ExtensibilitySupport::default(),
+    };
+    let names = analysis
+        .types
+        .iter()
+        .filter(|typ| !matches!(typ.dcl.0, IdlTypeDclKind::TypeDcl(_, _)))
+        .map(|typ| typ.name.to_vec())
+        .collect::<Vec<_>>();
+    for name in names {
+        analysis.kind(&name)?;
+    }
+    Ok(analysis.support)
+}
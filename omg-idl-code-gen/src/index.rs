@@ -0,0 +1,218 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+use crate::{ast::*, Configuration};
+use std::collections::HashMap;
+
+/// Path to the Rust type `name` as seen from the module `scope`
+pub fn relative_path(name: &[String], scope: &[String], config: &Configuration) -> String {
+    let (id, modules) = name.split_last().expect("type names are never empty");
+    let common = modules
+        .iter()
+        .zip(scope)
+        .take_while(|(lhs, rhs)| lhs == rhs)
+        .count();
+    let mut path = vec!["super".to_owned(); scope.len() - common];
+    path.extend(modules[common..].iter().cloned());
+    path.push(config.type_name(id));
+    path.join("::")
+}
+
+/// A type declaration together with its fully qualified name, i.e. ["DDS", "GUID_t"]
+#[derive(Clone, Copy, Debug)]
+pub struct IndexedType<'a> {
+    pub name: &'a [String],
+    pub dcl: &'a IdlTypeDcl,
+}
+
+impl IndexedType<'_> {
+    /// Scope of the module declaring the type
+    pub fn scope(&self) -> &[String] {
+        &self.name[..self.name.len() - 1]
+    }
+}
+
+/// All types of the specification by their fully qualified name
+#[derive(Debug, Default)]
+pub struct TypeIndex<'a> {
+    names: Vec<Vec<String>>,
+    types: HashMap<Vec<String>, &'a IdlTypeDcl>,
+}
+
+impl<'a> TypeIndex<'a> {
+    pub fn new(root_module: &'a IdlModule) -> Self {
+        let mut index = TypeIndex::default();
+        index.collect(root_module, &mut Vec::new());
+        index
+    }
+
+    fn collect(&mut self, module: &'a IdlModule, scope: &mut Vec<String>) {
+        for (id, typ) in module.types.iter() {
+            let mut name = scope.clone();
+            name.push(id.clone());
+            self.names.push(name.clone());
+            self.types.insert(name, typ);
+        }
+        for (id, submodule) in module.modules.iter() {
+            scope.push(id.clone());
+            self.collect(submodule, scope);
+            scope.pop();
+        }
+    }
+
+    /// All types in order of declaration
+    pub fn iter(&self) -> impl Iterator<Item = IndexedType<'_>> {
+        self.names.iter().map(|name| IndexedType {
+            name,
+            dcl: self.types[name],
+        })
+    }
+
+    /// Find the type a name refers to, relative names are searched from the current
+    /// scope outwards.
+    pub fn resolve(&self, name: &IdlScopedName, scope: &[String]) -> Option<IndexedType<'_>> {
+        let depths = if name.1 { 0..=0 } else { 0..=scope.len() };
+        depths.rev().find_map(|depth| {
+            let mut qualified = scope[..depth].to_vec();
+            qualified.extend(name.0.iter().cloned());
+            self.types
+                .get_key_value(&qualified)
+                .map(|(name, dcl)| IndexedType { name, dcl })
+        })
+    }
+
+    /// The typedefs a type refers to, directly or via other typedefs. Stops at the first
+    /// name which is not a typedef or unknown.
+    pub fn aliases(&self, spec: &IdlTypeSpec, scope: &[String]) -> Vec<IndexedType<'_>> {
+        let mut aliases: Vec<IndexedType> = Vec::new();
+        let (mut spec, mut scope) = (spec, scope);
+        // the bound protects against typedefs referring to each other
+        while aliases.len() <= self.names.len() {
+            let IdlTypeSpec::ScopedName(name) = spec else {
+                break;
+            };
+            match self.resolve(name, scope) {
+                Some(
+                    typ @ IndexedType {
+                        dcl: IdlTypeDcl(IdlTypeDclKind::TypeDcl(_, aliased), _),
+                        ..
+                    },
+                ) => {
+                    spec = aliased;
+                    scope = &typ.name[..typ.name.len() - 1];
+                    aliases.push(typ);
+                }
+                _ => break,
+            }
+        }
+        aliases
+    }
+
+    /// Follow typedefs until the type is not an alias anymore, returns the type and the
+    /// scope its names are relative to
+    pub fn unalias(&self, spec: &IdlTypeSpec, scope: &[String]) -> (IdlTypeSpec, Vec<String>) {
+        match self.aliases(spec, scope).last() {
+            Some(IndexedType {
+                dcl: IdlTypeDcl(IdlTypeDclKind::TypeDcl(_, aliased), _),
+                name,
+            }) => (aliased.clone(), name[..name.len() - 1].to_vec()),
+            _ => (spec.clone(), scope.to_vec()),
+        }
+    }
+}
+
+/// Maximum nesting of constants referring to other constants
+const MAX_CONST_DEPTH: usize = 64;
+
+/// All constants of the specification by their fully qualified name, together with
+/// their type and the scope they are declared in.
+#[derive(Debug, Default)]
+pub struct ConstIndex {
+    constants: HashMap<Vec<String>, (IdlValueExpr, IdlTypeSpec, Vec<String>)>,
+}
+
+impl ConstIndex {
+    pub fn new(root_module: &IdlModule) -> Self {
+        let mut index = ConstIndex::default();
+        index.collect(root_module, &mut Vec::new());
+        index
+    }
+
+    fn collect(&mut self, module: &IdlModule, scope: &mut Vec<String>) {
+        for (id, cnst) in module.constants.iter() {
+            let mut name = scope.clone();
+            name.push(id.clone());
+            self.constants.insert(
+                name,
+                (cnst.value.clone(), cnst.typedcl.clone(), scope.clone()),
+            );
+        }
+        for (id, submodule) in module.modules.iter() {
+            scope.push(id.clone());
+            self.collect(submodule, scope);
+            scope.pop();
+        }
+    }
+
+    /// Find the constant a name refers to, relative names are searched from the
+    /// current scope outwards.
+    fn lookup(
+        &self,
+        name: &IdlScopedName,
+        scope: &[String],
+    ) -> Option<&(IdlValueExpr, IdlTypeSpec, Vec<String>)> {
+        if name.1 {
+            return self.constants.get(&name.0);
+        }
+        (0..=scope.len()).rev().find_map(|depth| {
+            let mut qualified = scope[..depth].to_vec();
+            qualified.extend(name.0.iter().cloned());
+            self.constants.get(&qualified)
+        })
+    }
+
+    /// Type of the constant a name refers to and the scope the type is relative to,
+    /// `None` if the name is no constant
+    pub fn type_spec(
+        &self,
+        name: &IdlScopedName,
+        scope: &[String],
+    ) -> Option<(&IdlTypeSpec, &[String])> {
+        self.lookup(name, scope)
+            .map(|(_, type_spec, const_scope)| (type_spec, const_scope.as_slice()))
+    }
+
+    /// Evaluate an expression within the given scope
+    pub fn evaluate(&self, expr: &IdlValueExpr, scope: &[String]) -> Result<IdlConstValue, String> {
+        self.evaluate_nested(expr, scope, 0)
+    }
+
+    fn evaluate_nested(
+        &self,
+        expr: &IdlValueExpr,
+        scope: &[String],
+        depth: usize,
+    ) -> Result<IdlConstValue, String> {
+        if depth > MAX_CONST_DEPTH {
+            return Err(format!("Constant {expr} is defined recursively"));
+        }
+        expr.evaluate(&|name| match self.lookup(name, scope) {
+            Some((value, _, const_scope)) => self
+                .evaluate_nested(value, const_scope, depth + 1)
+                .map(Some),
+            None => Ok(None),
+        })
+    }
+
+    /// Evaluate an expression which must result in a non-negative integer, i.e. an
+    /// array dimension or a string bound
+    pub fn evaluate_size(&self, expr: &IdlValueExpr, scope: &[String]) -> Result<usize, String> {
+        match self.evaluate(expr, scope)? {
+            IdlConstValue::Integer(value) => {
+                usize::try_from(value).map_err(|_| format!("{expr} = {value} is not a valid size"))
+            }
+            value => Err(format!("{expr} = {value} is not an integer")),
+        }
+    }
+}
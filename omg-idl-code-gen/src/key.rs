@@ -0,0 +1,623 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+use crate::{
+    ast::*,
+    bytes::ByteSupport,
+    cdr,
+    derive::DeriveSupport,
+    index::{relative_path, ConstIndex, IndexedType, TypeIndex},
+    newtype::NewtypeSupport,
+    Configuration, WideCharMapping,
+};
+use serde_derive::Serialize;
+use std::collections::{HashMap, HashSet};
+
+/// Pragma declaring a key member of a type, i.e. `DCPS_DATA_KEY "DDS::Foo key.value[0]"`
+const DATA_KEY_PRAGMA: &str = "DCPS_DATA_KEY";
+
+/// Data storage to align with Jinja (key.j2 and key_hash_encode.j2)
+#[derive(Clone, Debug, Serialize)]
+pub struct IdlKeyField {
+    /// Name of the member within the key holder
+    pub name: String,
+    pub type_str: String,
+    /// Access path of the member below the keyed value, i.e. `.key.value[0]`, the
+    /// templates read it from `self` and `other`
+    pub access: String,
+    /// Copy types are taken without `clone()`
+    pub copy: bool,
+    /// `KeySize` builder call appending the member, i.e. `.field::<i32>()`
+    pub size: String,
+}
+
+/// How a type referenced by a key member is written into the key holder
+#[derive(Clone, Debug)]
+pub enum KeyEncoding {
+    /// Structs without keys contribute all of their members, newtypes the wrapped value
+    Members(Vec<IdlKeyField>),
+    /// Structs with keys contribute their key members only
+    Delegate,
+    /// Enumerations are written as their ordinal of the given Rust type
+    Ordinal(&'static str),
+}
+
+/// Key members of all keyed structs and the encoding of all types nested in keys,
+/// both by the fully qualified type name.
+#[derive(Debug, Default)]
+pub struct KeySupport {
+    keys: HashMap<Vec<String>, Vec<IdlKeyField>>,
+    encodings: HashMap<Vec<String>, KeyEncoding>,
+}
+
+impl KeySupport {
+    /// Key members of a struct, `None` if the struct has no keys
+    pub fn key(&self, name: &[String]) -> Option<&[IdlKeyField]> {
+        self.keys.get(name).map(Vec::as_slice)
+    }
+
+    /// Encoding of a type nested in a key, `None` if the type is not part of any key
+    pub fn encoding(&self, name: &[String]) -> Option<&KeyEncoding> {
+        self.encodings.get(name)
+    }
+}
+
+/// Element of a `DCPS_DATA_KEY` path, i.e. `key.value[0]`
+#[derive(Clone, Debug, PartialEq)]
+enum PathElement {
+    Member(String),
+    Index(usize),
+}
+
+/// Split a key path into its members and array indexes
+fn parse_path(path: &str) -> Result<Vec<PathElement>, String> {
+    let mut elements = Vec::new();
+    for part in path.split('.') {
+        let (member, indexes) = part.split_once('[').unwrap_or((part, ""));
+        if member.is_empty() {
+            return Err(format!("key path '{path}' has an empty member name"));
+        }
+        elements.push(PathElement::Member(member.to_owned()));
+        if !indexes.is_empty() {
+            for index in format!("[{indexes}").split_terminator(']') {
+                let index = index
+                    .strip_prefix('[')
+                    .and_then(|index| index.parse().ok())
+                    .ok_or_else(|| format!("key path '{path}' has an invalid array index"))?;
+                elements.push(PathElement::Index(index));
+            }
+        }
+    }
+    Ok(elements)
+}
+
+/// Member holding the base of a derived struct
+fn base_member(base: &IdlScopedName) -> IdlStructMember {
+    IdlStructMember {
+        id: BASE_MEMBER.to_owned(),
+        type_spec: IdlTypeSpec::ScopedName(base.clone()),
+        annotations: IdlAnnotations::default(),
+    }
+}
+
+struct KeyAnalysis<'a> {
+    types: TypeIndex<'a>,
+    constants: ConstIndex,
+    config: &'a Configuration,
+    derives: &'a DeriveSupport,
+    newtypes: &'a NewtypeSupport,
+    bytes: &'a ByteSupport,
+    /// Types nested in keys still to be analyzed
+    pending: Vec<Vec<String>>,
+    /// Types nested in keys found so far
+    seen: HashSet<Vec<String>>,
+    support: KeySupport,
+}
+
+impl<'a> KeyAnalysis<'a> {
+    fn resolve(
+        &self,
+        name: &IdlScopedName,
+        scope: &[String],
+        location: &str,
+    ) -> Result<IndexedType<'_>, String> {
+        self.types
+            .resolve(name, scope)
+            .ok_or_else(|| format!("unknown type {name} in the key of {location}"))
+    }
+
+    /// Follow typedefs until the type is not an alias anymore, unknown names are an error
+    fn unalias(
+        &self,
+        spec: &IdlTypeSpec,
+        scope: &[String],
+        location: &str,
+    ) -> Result<(IdlTypeSpec, Vec<String>), String> {
+        let (spec, scope) = self.types.unalias(spec, scope);
+        if let IdlTypeSpec::ScopedName(ref name) = spec {
+            self.resolve(name, &scope, location)?;
+        }
+        Ok((spec, scope))
+    }
+
+    /// Whether the base of a derived struct declared in `scope` has a key, the base is
+    /// declared and analyzed before the derived struct
+    fn is_keyed(
+        &self,
+        base: &IdlScopedName,
+        scope: &[String],
+        location: &str,
+    ) -> Result<bool, String> {
+        Ok(self
+            .base(base, scope, location)?
+            .is_some_and(|base| self.support.keys.contains_key(base.name)))
+    }
+
+    /// The struct a derived struct declared in `scope` is based on
+    fn base(
+        &self,
+        base: &IdlScopedName,
+        scope: &[String],
+        location: &str,
+    ) -> Result<Option<IndexedType<'_>>, String> {
+        match self.unalias(&IdlTypeSpec::ScopedName(base.clone()), scope, location)? {
+            (IdlTypeSpec::ScopedName(ref name), scope) => {
+                self.resolve(name, &scope, location).map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Number of structs a struct is derived from, bases are analyzed first
+    fn depth(&self, typ: IndexedType, location: &str) -> Result<usize, String> {
+        let mut depth = 0;
+        let mut typ = typ;
+        while let IdlTypeDclKind::StructDcl(_, Some(ref base), _) = typ.dcl.0 {
+            match self.base(base, typ.scope(), location)? {
+                Some(base) => typ = base,
+                None => break,
+            }
+            depth += 1;
+        }
+        Ok(depth)
+    }
+
+    /// Verify the type can be part of a key and remember the named types it contains
+    fn visit(
+        &mut self,
+        spec: &IdlTypeSpec,
+        scope: &[String],
+        location: &str,
+    ) -> Result<(), String> {
+        match spec {
+            // UTF-16 code units are plain `u16`
+            IdlTypeSpec::WideCharType
+                if self.config.wide_char_mapping == WideCharMapping::Utf16 =>
+            {
+                Ok(())
+            }
+            IdlTypeSpec::WideCharType | IdlTypeSpec::WideStringType(_) | IdlTypeSpec::F128Type => {
+                Err(format!(
+                    "the key of {location} contains the unsupported type {spec:?}"
+                ))
+            }
+            IdlTypeSpec::ArrayType(element, _) | IdlTypeSpec::SequenceType(element, _) => {
+                self.visit(element, scope, location)
+            }
+            IdlTypeSpec::ScopedName(name) => {
+                let typ = self.resolve(name, scope, location)?;
+                let (qualified, dcl) = (typ.name.to_vec(), typ.dcl.clone());
+                match dcl.0 {
+                    IdlTypeDclKind::TypeDcl(..) if self.newtypes.is_newtype(&qualified) => {
+                        if self.seen.insert(qualified.clone()) {
+                            self.pending.push(qualified);
+                        }
+                        Ok(())
+                    }
+                    IdlTypeDclKind::TypeDcl(_, ref aliased) => {
+                        self.visit(aliased, &qualified[..qualified.len() - 1], location)
+                    }
+                    IdlTypeDclKind::StructDcl(_, _, _) | IdlTypeDclKind::EnumDcl(_, _) => {
+                        if self.seen.insert(qualified.clone()) {
+                            self.pending.push(qualified);
+                        }
+                        Ok(())
+                    }
+                    IdlTypeDclKind::UnionDcl(ref id, _, _) => {
+                        Err(format!("the key of {location} contains the union {id}"))
+                    }
+                    IdlTypeDclKind::None => Ok(()),
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Primitives, types with a C layout or deriving `Copy` and arrays of them are `Copy`
+    fn is_copy(
+        &self,
+        spec: &IdlTypeSpec,
+        scope: &[String],
+        location: &str,
+    ) -> Result<bool, String> {
+        let (spec, scope) = self.unalias(spec, scope, location)?;
+        match spec {
+            IdlTypeSpec::ArrayType(ref element, _) => self.is_copy(element, &scope, location),
+            IdlTypeSpec::ScopedName(ref name) => Ok(self
+                .derives
+                .is_copy(self.resolve(name, &scope, location)?.name)),
+            IdlTypeSpec::SequenceType(..)
+            | IdlTypeSpec::StringType(_)
+            | IdlTypeSpec::WideStringType(_)
+            | IdlTypeSpec::None => Ok(false),
+            _ => Ok(true),
+        }
+    }
+
+    /// `KeySize` builder call for a member, bounded strings have a known maximum size
+    fn size(
+        &self,
+        spec: &IdlTypeSpec,
+        scope: &[String],
+        type_str: &str,
+        location: &str,
+    ) -> Result<String, String> {
+        match self.unalias(spec, scope, location)? {
+            (IdlTypeSpec::StringType(Some(bound)), scope) => {
+                let bound = self.constants.evaluate_size(&bound, &scope)?;
+                Ok(format!(".bounded_string({bound})"))
+            }
+            _ => Ok(format!(".field::<{type_str}>()")),
+        }
+    }
+
+    /// Key holder member for a member of the struct `name`
+    fn field(
+        &mut self,
+        member: &IdlStructMember,
+        name: &[String],
+        location: &str,
+    ) -> Result<IdlKeyField, String> {
+        let scope = &name[..name.len() - 1];
+        if member.annotations.is_set("optional") {
+            return Err(format!(
+                "the optional member {} cannot be part of the key of {location}",
+                member.id
+            ));
+        }
+        self.visit(&member.type_spec, scope, location)?;
+        let mut member_name = name.to_vec();
+        member_name.push(member.id.clone());
+        let type_str = match self.bytes.member_sequence(self.config, &member_name) {
+            Some((type_str, _)) => type_str,
+            None => member
+                .type_spec
+                .to_rust(self.config)
+                .map_err(|_| format!("the key of {location} has an unsupported type"))?,
+        };
+        let name = self.config.member_name(&member.id);
+        Ok(IdlKeyField {
+            access: format!(".{name}"),
+            copy: self.is_copy(&member.type_spec, scope, location)?,
+            size: self.size(&member.type_spec, scope, &type_str, location)?,
+            name,
+            type_str,
+        })
+    }
+
+    /// Rust type of a pragma key member, named types are addressed relative to the
+    /// module of the keyed struct
+    fn relocated_type(
+        &self,
+        spec: &IdlTypeSpec,
+        spec_scope: &[String],
+        scope: &[String],
+        location: &str,
+    ) -> Result<String, String> {
+        match spec {
+            IdlTypeSpec::ScopedName(name) => {
+                let typ = self.resolve(name, spec_scope, location)?;
+                Ok(relative_path(typ.name, scope, self.config))
+            }
+            IdlTypeSpec::SequenceType(element, Some(bound)) if self.config.heapless => {
+                let element = self.relocated_type(element, spec_scope, scope, location)?;
+                let bound = self.constants.evaluate_size(bound, spec_scope)?;
+                Ok(format!("heapless::Vec<{element}, {bound}>"))
+            }
+            IdlTypeSpec::SequenceType(element, _)
+                if matches!(**element, IdlTypeSpec::OctetType) =>
+            {
+                Ok(self.config.byte_sequence_type(self.config.byte_mapping))
+            }
+            IdlTypeSpec::SequenceType(element, _) => Ok(format!(
+                "{}<{}>",
+                self.config.vec_path(),
+                self.relocated_type(element, spec_scope, scope, location)?
+            )),
+            IdlTypeSpec::StringType(Some(bound)) if self.config.heapless => {
+                let bound = self.constants.evaluate_size(bound, spec_scope)?;
+                Ok(format!("heapless::String<{bound}>"))
+            }
+            IdlTypeSpec::ArrayType(element, dims) => {
+                let mut type_str = self.relocated_type(element, spec_scope, scope, location)?;
+                for dim in dims.iter().rev() {
+                    let dim = self.constants.evaluate_size(dim, spec_scope)?;
+                    type_str = format!("[{type_str}; {dim}]");
+                }
+                Ok(type_str)
+            }
+            _ => spec
+                .to_rust(self.config)
+                .map_err(|_| format!("the key of {location} has an unsupported type")),
+        }
+    }
+
+    /// Key holder member for a `DCPS_DATA_KEY` path starting at the struct `name`
+    fn path_field(&mut self, name: &[String], path: &str) -> Result<IdlKeyField, String> {
+        let location = format!("struct {}", name.join("::"));
+        let struct_scope = &name[..name.len() - 1];
+        let mut elements = parse_path(path)?.into_iter().peekable();
+        let mut spec = IdlTypeSpec::ScopedName(IdlScopedName(name.to_vec(), true));
+        let mut scope = Vec::new();
+        let mut access = String::new();
+        let mut field_name = Vec::new();
+        // the member the path ends at, if any, may map its octet sequence itself
+        let mut member_name = None;
+
+        while let Some(element) = elements.next() {
+            let (resolved, resolved_scope) = self.unalias(&spec, &scope, &location)?;
+            match (element, resolved) {
+                (PathElement::Member(id), IdlTypeSpec::ScopedName(ref typ)) => {
+                    let typ = self.resolve(typ, &resolved_scope, &location)?;
+                    let member = match typ.dcl.0 {
+                        IdlTypeDclKind::StructDcl(_, _, ref members) => {
+                            members.iter().find(|member| member.id == id)
+                        }
+                        _ => None,
+                    }
+                    .ok_or_else(|| format!("the key path '{path}' of {location} does not exist"))?;
+                    if member.annotations.is_set("optional") {
+                        return Err(format!(
+                            "the optional member {id} cannot be part of the key of {location}"
+                        ));
+                    }
+                    let rust_name = self.config.member_name(&id);
+                    access = format!("{access}.{rust_name}");
+                    field_name.push(rust_name);
+                    spec = member.type_spec.clone();
+                    scope = typ.scope().to_vec();
+                    member_name = Some([typ.name, &[id]].concat());
+                }
+                (PathElement::Index(first), IdlTypeSpec::ArrayType(ref element, ref dims)) => {
+                    // all dimensions must be indexed
+                    let mut indexes = vec![first];
+                    while indexes.len() < dims.len() {
+                        match elements.next() {
+                            Some(PathElement::Index(index)) => indexes.push(index),
+                            _ => {
+                                return Err(format!(
+                                    "the key path '{path}' of {location} must index all array dimensions"
+                                ))
+                            }
+                        }
+                    }
+                    for (index, dim) in indexes.iter().zip(dims) {
+                        if *index >= self.constants.evaluate_size(dim, &resolved_scope)? {
+                            return Err(format!(
+                                "the key path '{path}' of {location} is out of bounds"
+                            ));
+                        }
+                    }
+                    for index in &indexes {
+                        access = format!("{access}[{index}]");
+                    }
+                    field_name.extend(indexes.iter().map(usize::to_string));
+                    spec = element.as_ref().clone();
+                    scope = resolved_scope;
+                    member_name = None;
+                }
+                _ => {
+                    return Err(format!(
+                        "the key path '{path}' of {location} does not match its type"
+                    ))
+                }
+            }
+        }
+
+        self.visit(&spec, &scope, &location)?;
+        let byte_sequence = member_name
+            .and_then(|member_name| self.bytes.member_sequence(self.config, &member_name));
+        let type_str = match byte_sequence {
+            Some((type_str, _)) => type_str,
+            None => self.relocated_type(&spec, &scope, struct_scope, &location)?,
+        };
+        Ok(IdlKeyField {
+            name: field_name.join("_"),
+            copy: self.is_copy(&spec, &scope, &location)?,
+            size: self.size(&spec, &scope, &type_str, &location)?,
+            access,
+            type_str,
+        })
+    }
+
+    /// Collect the `DCPS_DATA_KEY` paths of all structs
+    fn pragma_keys(
+        &self,
+        pragmas: &[IdlPragma],
+    ) -> Result<HashMap<Vec<String>, Vec<String>>, String> {
+        let mut keys: HashMap<Vec<String>, Vec<String>> = HashMap::new();
+        for pragma in pragmas {
+            let Some(args) = pragma.text.strip_prefix(DATA_KEY_PRAGMA) else {
+                continue;
+            };
+            let args = args.trim();
+            let declaration = args
+                .strip_prefix('"')
+                .and_then(|args| args.strip_suffix('"'))
+                .ok_or_else(|| format!("#pragma {DATA_KEY_PRAGMA} {args} is not quoted"))?;
+            let (type_name, path) = declaration
+                .trim()
+                .split_once(char::is_whitespace)
+                .ok_or_else(|| format!("#pragma {DATA_KEY_PRAGMA} {args} is missing the key"))?;
+            let name = IdlScopedName(
+                type_name
+                    .trim_start_matches("::")
+                    .split("::")
+                    .map(str::to_owned)
+                    .collect(),
+                type_name.starts_with("::"),
+            );
+            let typ = self
+                .types
+                .resolve(&name, &pragma.scope)
+                .filter(|typ| matches!(typ.dcl.0, IdlTypeDclKind::StructDcl(_, _, _)))
+                .ok_or_else(|| format!("#pragma {DATA_KEY_PRAGMA} {args} names no struct"))?;
+            let path = path.chars().filter(|c| !c.is_whitespace()).collect();
+            keys.entry(typ.name.to_vec()).or_default().push(path);
+        }
+        Ok(keys)
+    }
+
+    /// Encoding of a struct, enum or newtype nested in a key
+    fn encoding(&mut self, name: &[String]) -> Result<KeyEncoding, String> {
+        let location = format!("struct {}", name.join("::"));
+        let dcl = match self.types.resolve(&IdlScopedName(name.to_vec(), true), &[]) {
+            Some(typ) => typ.dcl.clone(),
+            None => return Err(format!("unknown type {}", name.join("::"))),
+        };
+        match dcl.0 {
+            IdlTypeDclKind::StructDcl(_, _, _) if self.support.keys.contains_key(name) => {
+                Ok(KeyEncoding::Delegate)
+            }
+            IdlTypeDclKind::StructDcl(_, ref base, ref members) => base
+                .iter()
+                .map(base_member)
+                .collect::<Vec<_>>()
+                .iter()
+                .chain(members)
+                .map(|member| self.field(member, name, &location))
+                .collect::<Result<Vec<_>, _>>()
+                .map(KeyEncoding::Members),
+            IdlTypeDclKind::EnumDcl(_, _) => Ok(KeyEncoding::Ordinal(cdr::enum_holder(&dcl.1))),
+            IdlTypeDclKind::TypeDcl(ref id, ref aliased) => {
+                let location = format!("typedef {}", name.join("::"));
+                let scope = &name[..name.len() - 1];
+                self.visit(aliased, scope, &location)?;
+                let type_str = aliased
+                    .to_rust(self.config)
+                    .map_err(|_| format!("{location} has an unsupported type"))?;
+                Ok(KeyEncoding::Members(vec![IdlKeyField {
+                    name: id.clone(),
+                    access: ".0".to_owned(),
+                    copy: self.is_copy(aliased, scope, &location)?,
+                    size: self.size(aliased, scope, &type_str, &location)?,
+                    type_str,
+                }]))
+            }
+            _ => Err(format!("{} cannot be part of a key", name.join("::"))),
+        }
+    }
+}
+
+/// Determine the key members of all structs, declared via `@key` or
+/// `#pragma DCPS_DATA_KEY`, and how the types nested in keys are encoded.
+pub fn analyze(
+    root_module: &IdlModule,
+    pragmas: &[IdlPragma],
+    config: &Configuration,
+    derives: &DeriveSupport,
+    newtypes: &NewtypeSupport,
+    bytes: &ByteSupport,
+) -> Result<KeySupport, String> {
+    let mut analysis = KeyAnalysis {
+        types: TypeIndex::new(root_module),
+        constants: ConstIndex::new(root_module),
+        config,
+        derives,
+        newtypes,
+        bytes,
+        pending: Vec::new(),
+        seen: HashSet::new(),
+        support: KeySupport::default(),
+    };
+    let mut pragma_keys = analysis.pragma_keys(pragmas)?;
+
+    let mut structs = Vec::new();
+    for typ in analysis.types.iter() {
+        if let IdlTypeDclKind::StructDcl(ref id, ref base, ref members) = typ.dcl.0 {
+            let depth = analysis.depth(typ, &format!("struct {id}"))?;
+            structs.push((
+                depth,
+                typ.name.to_vec(),
+                id.clone(),
+                base.clone(),
+                members.clone(),
+            ));
+        }
+    }
+    structs.sort_by_key(|(depth, ..)| *depth);
+    for (_, name, id, base, members) in structs {
+        let location = format!("struct {}", name.join("::"));
+        let scope = &name[..name.len() - 1];
+        // derived structs extend the key of their base
+        let base = match base {
+            Some(base) if analysis.is_keyed(&base, scope, &location)? => Some(base_member(&base)),
+            _ => None,
+        };
+        let key_members = members
+            .iter()
+            .filter(|member| member.annotations.is_set("key"))
+            .collect::<Vec<_>>();
+        let fields = match (key_members.is_empty(), pragma_keys.remove(&name)) {
+            (true, None) if base.is_none() => continue,
+            (true, None) => Vec::new(),
+            (false, Some(_)) => {
+                return Err(format!(
+                    "{location} declares keys via @key and #pragma {DATA_KEY_PRAGMA}"
+                ))
+            }
+            (false, None) => key_members
+                .into_iter()
+                .map(|member| analysis.field(member, &name, &location))
+                .collect::<Result<Vec<_>, _>>()?,
+            (true, Some(paths)) => paths
+                .iter()
+                .map(|path| analysis.path_field(&name, path))
+                .collect::<Result<Vec<_>, _>>()?,
+        };
+        let fields = match base {
+            Some(ref base) => std::iter::once(analysis.field(base, &name, &location))
+                .chain(fields.into_iter().map(Ok))
+                .collect::<Result<Vec<_>, _>>()?,
+            None => fields,
+        };
+
+        let mut names = HashSet::new();
+        if let Some(field) = fields
+            .iter()
+            .find(|field| !names.insert(field.name.clone()))
+        {
+            return Err(format!(
+                "{location} has the key member {} twice",
+                field.name
+            ));
+        }
+        let key_name = format!("{}Key", config.type_name(&id));
+        let collision = analysis.types.iter().any(|typ| {
+            typ.scope() == scope && config.type_name(&typ.name[scope.len()]) == key_name
+        });
+        if collision {
+            return Err(format!(
+                "the key type {key_name} of {location} collides with a type"
+            ));
+        }
+        analysis.support.keys.insert(name, fields);
+    }
+
+    while let Some(name) = analysis.pending.pop() {
+        let encoding = analysis.encoding(&name)?;
+        analysis.support.encodings.insert(name, encoding);
+    }
+    Ok(analysis.support)
+}
@@ -0,0 +1,464 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+use crate::{
+    ast::*,
+    cdr,
+    index::{ConstIndex, TypeIndex},
+    newtype::NewtypeSupport,
+    CharMapping, Configuration, WideCharMapping,
+};
+use serde_derive::Serialize;
+use std::collections::{HashMap, HashSet};
+
+/// How a struct or enum is laid out when generated with `#[repr(C)]`
+#[derive(Clone, Debug, PartialEq)]
+pub enum Layout {
+    /// No padding and every bit pattern is valid, `bytemuck::Pod`
+    Pod,
+    /// No padding, but enums or booleans restrict the valid bit patterns,
+    /// `bytemuck::NoUninit` and `bytemuck::CheckedBitPattern`
+    Checked,
+    /// Fixed size, but neither of the above for the given reason
+    Fixed(String),
+    /// Not fixed size, the type keeps the Rust layout for the given reason
+    Unsupported(String),
+}
+
+impl Layout {
+    /// Whether the type is generated with `#[repr(C)]`
+    pub fn is_fixed(&self) -> bool {
+        !matches!(self, Layout::Unsupported(_))
+    }
+
+    /// Rust attribute fixing the layout, enums are represented by their CDR holder
+    fn repr(&self, holder: Option<&str>) -> Option<String> {
+        match (self, holder) {
+            (Layout::Unsupported(_), _) => None,
+            (_, Some(holder)) => Some(format!("#[repr({holder})]")),
+            (_, None) => Some("#[repr(C)]".to_owned()),
+        }
+    }
+
+    /// Derives the layout allows
+    fn derives(&self) -> &'static [&'static str] {
+        match self {
+            Layout::Pod => &["Copy", "bytemuck::Pod", "bytemuck::Zeroable"],
+            Layout::Checked => &["Copy", "bytemuck::NoUninit", "bytemuck::CheckedBitPattern"],
+            Layout::Fixed(_) => &["Copy"],
+            Layout::Unsupported(_) => &[],
+        }
+    }
+
+    /// Comment telling why the type does not get all traits
+    fn report(&self) -> Option<String> {
+        match self {
+            Layout::Pod | Layout::Checked => None,
+            Layout::Fixed(reason) => Some(format!("Not plain old data: {reason}")),
+            Layout::Unsupported(reason) => Some(format!("No C layout: {reason}")),
+        }
+    }
+}
+
+/// Data storage to align with Jinja (c_header.j2), a struct member
+#[derive(Clone, Debug, Serialize)]
+pub struct IdlCField {
+    pub name: String,
+    pub c_type: String,
+    /// Array dimensions, i.e. `[3][2]`
+    pub dims: String,
+}
+
+/// Data storage to align with Jinja (c_header.j2), a declaration of the C header
+#[derive(Clone, Debug, Serialize)]
+pub struct IdlCDecl {
+    /// `struct`, `enum`, `typedef` or `unsupported`
+    pub kind: &'static str,
+    pub name: String,
+    pub idl_name: String,
+    pub fields: Vec<IdlCField>,
+    /// Aliased type of a typedef or holder of an enum
+    pub c_type: String,
+    pub dims: String,
+    pub values: Vec<(String, i128)>,
+    pub size: usize,
+    pub report: Option<String>,
+}
+
+/// The C layout of a type as used by a member
+#[derive(Clone, Debug)]
+struct Shape {
+    size: usize,
+    align: usize,
+    layout: Layout,
+    c_type: String,
+    /// C array dimensions, outermost first
+    dims: Vec<usize>,
+}
+
+/// Layouts of all structs, enums and typedefs. Rendering only uses them with
+/// `Configuration::with_repr_c`, the C header always does.
+#[derive(Debug, Default)]
+pub struct LayoutSupport {
+    enabled: bool,
+    layouts: HashMap<Vec<String>, Layout>,
+    /// Declarations of the C header, every type after the types it uses
+    decls: Vec<IdlCDecl>,
+}
+
+impl LayoutSupport {
+    /// Layout of the type with the fully qualified `name` if generated with `#[repr(C)]`
+    pub fn layout(&self, name: &[String]) -> Option<&Layout> {
+        self.layouts.get(name).filter(|_| self.enabled)
+    }
+
+    /// Whether the type with the fully qualified `name` is generated as `Copy`
+    pub fn is_copy(&self, name: &[String]) -> bool {
+        self.layout(name).is_some_and(Layout::is_fixed)
+    }
+
+    /// `#[repr]` attribute, derives and report comment of a type
+    pub fn attributes(
+        &self,
+        name: &[String],
+        holder: Option<&str>,
+    ) -> (Option<String>, Vec<String>, Option<String>) {
+        match self.layout(name) {
+            Some(layout) => (
+                layout.repr(holder),
+                layout.derives().iter().map(|d| d.to_string()).collect(),
+                layout.report(),
+            ),
+            None => (None, Vec::new(), None),
+        }
+    }
+
+    pub fn decls(&self) -> &[IdlCDecl] {
+        &self.decls
+    }
+}
+
+/// C name of a type, its scope joined by underscores
+fn c_name(name: &[String]) -> String {
+    name.join("_")
+}
+
+fn c_dims(dims: &[usize]) -> String {
+    dims.iter().map(|dim| format!("[{dim}]")).collect()
+}
+
+struct LayoutAnalysis<'a> {
+    config: &'a Configuration,
+    types: TypeIndex<'a>,
+    constants: ConstIndex,
+    newtypes: &'a NewtypeSupport,
+    shapes: HashMap<Vec<String>, Result<Shape, String>>,
+    /// Types being analyzed, reaching one of them again means the type is recursive
+    visiting: HashSet<Vec<String>>,
+    support: LayoutSupport,
+}
+
+impl LayoutAnalysis<'_> {
+    /// Layout of a member of the IDL type, `Err` tells why it is not fixed size
+    fn shape(&mut self, spec: &IdlTypeSpec, scope: &[String]) -> Result<Shape, String> {
+        let primitive = |size: usize, c_type: &str, layout: Layout| {
+            Ok(Shape {
+                size,
+                align: size,
+                layout,
+                c_type: c_type.to_owned(),
+                dims: Vec::new(),
+            })
+        };
+        match spec {
+            IdlTypeSpec::OctetType => primitive(1, "uint8_t", Layout::Pod),
+            IdlTypeSpec::BooleanType => primitive(1, "bool", Layout::Checked),
+            IdlTypeSpec::I16Type => primitive(2, "int16_t", Layout::Pod),
+            IdlTypeSpec::U16Type => primitive(2, "uint16_t", Layout::Pod),
+            IdlTypeSpec::I32Type => primitive(4, "int32_t", Layout::Pod),
+            IdlTypeSpec::U32Type => primitive(4, "uint32_t", Layout::Pod),
+            IdlTypeSpec::F32Type => primitive(4, "float", Layout::Pod),
+            IdlTypeSpec::I64Type => primitive(8, "int64_t", Layout::Pod),
+            IdlTypeSpec::U64Type => primitive(8, "uint64_t", Layout::Pod),
+            IdlTypeSpec::F64Type => primitive(8, "double", Layout::Pod),
+            IdlTypeSpec::CharType => match self.config.char_mapping {
+                CharMapping::Octet => primitive(1, "char", Layout::Pod),
+                CharMapping::Latin1 => {
+                    Err("a character, `Latin1` is no bytemuck plain old data".to_owned())
+                }
+                CharMapping::Char => Err(
+                    "a character, Rust `char` has no C equivalent, map `char` to `u8`".to_owned(),
+                ),
+            },
+            IdlTypeSpec::WideCharType => match self.config.wide_char_mapping {
+                WideCharMapping::Utf16 => primitive(2, "uint16_t", Layout::Pod),
+                WideCharMapping::Char => Err(
+                    "a wide character, Rust `char` has no C equivalent, map `wchar` to UTF-16"
+                        .to_owned(),
+                ),
+            },
+            IdlTypeSpec::F128Type => {
+                Err("a `long double`, which is not binary128 in C on every platform".to_owned())
+            }
+            IdlTypeSpec::StringType(_) | IdlTypeSpec::WideStringType(_) => {
+                Err("a string".to_owned())
+            }
+            IdlTypeSpec::SequenceType(..) => Err("a sequence".to_owned()),
+            IdlTypeSpec::ArrayType(element, dims) => {
+                let element = self.shape(element, scope)?;
+                let dims = dims
+                    .iter()
+                    .map(|dim| self.constants.evaluate_size(dim, scope))
+                    .collect::<Result<Vec<usize>, String>>()?;
+                let size = dims
+                    .iter()
+                    .try_fold(element.size, |size, dim| size.checked_mul(*dim))
+                    .ok_or_else(|| "an array too large for the address space".to_owned())?;
+                // bytemuck only checks single values, not arrays of them
+                let layout = match element.layout {
+                    Layout::Checked => Layout::Fixed(format!(
+                        "an array of `{}`, bytemuck cannot check arrays",
+                        element.c_type
+                    )),
+                    layout => layout,
+                };
+                // the first dimension is the outermost in Rust and C alike
+                let mut c_dims = dims;
+                c_dims.extend(element.dims);
+                Ok(Shape {
+                    size,
+                    align: element.align,
+                    layout,
+                    c_type: element.c_type,
+                    dims: c_dims,
+                })
+            }
+            IdlTypeSpec::ScopedName(name) => {
+                let Some(typ) = self.types.resolve(name, scope) else {
+                    return Err(format!("the unknown type `{name}`"));
+                };
+                let name = typ.name.to_vec();
+                self.named_shape(&name)
+            }
+            IdlTypeSpec::None => Err("missing a type".to_owned()),
+        }
+    }
+
+    /// Layout of a declared type, computed once
+    fn named_shape(&mut self, name: &[String]) -> Result<Shape, String> {
+        if let Some(shape) = self.shapes.get(name) {
+            return shape.clone();
+        }
+        let qualified = name.join("::");
+        if !self.visiting.insert(name.to_vec()) {
+            return Err(format!("the recursive type `{qualified}`"));
+        }
+        let shape = self.declare(name);
+        self.visiting.remove(name);
+        let shape = match shape {
+            Ok(shape) => Ok(shape),
+            Err(reason) => {
+                self.support.decls.push(IdlCDecl {
+                    kind: "unsupported",
+                    name: c_name(name),
+                    idl_name: qualified.clone(),
+                    fields: Vec::new(),
+                    c_type: String::new(),
+                    dims: String::new(),
+                    values: Vec::new(),
+                    size: 0,
+                    report: Some(reason.clone()),
+                });
+                self.support
+                    .layouts
+                    .insert(name.to_vec(), Layout::Unsupported(reason));
+                Err(format!("the type `{qualified}`, which is not fixed size"))
+            }
+        };
+        self.shapes.insert(name.to_vec(), shape.clone());
+        shape
+    }
+
+    /// Compute the layout of a declared type and add its declaration to the C header,
+    /// after the declarations of the types it uses
+    fn declare(&mut self, name: &[String]) -> Result<Shape, String> {
+        let typ = self
+            .types
+            .resolve(&IdlScopedName(name.to_vec(), true), &[])
+            .map(|typ| (typ.scope().to_vec(), typ.dcl.clone()))
+            .ok_or_else(|| format!("unknown type {}", name.join("::")))?;
+        let (scope, IdlTypeDcl(kind, annotations)) = typ;
+        let qualified = name.join("::");
+        let mut decl = IdlCDecl {
+            kind: "struct",
+            name: c_name(name),
+            idl_name: qualified.clone(),
+            fields: Vec::new(),
+            c_type: String::new(),
+            dims: String::new(),
+            values: Vec::new(),
+            size: 0,
+            report: None,
+        };
+        match kind {
+            IdlTypeDclKind::TypeDcl(_, ref aliased) => {
+                let shape = self.shape(aliased, &scope)?;
+                decl.kind = "typedef";
+                decl.c_type = shape.c_type;
+                decl.dims = c_dims(&shape.dims);
+                decl.size = shape.size;
+                self.support.decls.push(decl);
+                if self.newtypes.is_newtype(name) {
+                    self.support
+                        .layouts
+                        .insert(name.to_vec(), shape.layout.clone());
+                }
+                // members of the typedef's type refer to it by name
+                Ok(Shape {
+                    c_type: c_name(name),
+                    dims: Vec::new(),
+                    ..shape
+                })
+            }
+            IdlTypeDclKind::EnumDcl(_, ref enumerators) => {
+                let holder = cdr::enum_holder(&annotations);
+                let (size, max) = match holder {
+                    "u8" => (1, u8::MAX.into()),
+                    "u16" => (2, u16::MAX.into()),
+                    _ => (4, u32::MAX.into()),
+                };
+                let values = enumerators
+                    .iter()
+                    .map(|enumerator| format!("{}_{}", c_name(name), enumerator.id))
+                    .zip(cdr::enumerator_values(enumerators))
+                    .collect::<Vec<_>>();
+                if let Some((id, value)) =
+                    values.iter().find(|(_, value)| !(0..=max).contains(value))
+                {
+                    return Err(format!("{id} = {value} does not fit into `{holder}`"));
+                }
+                decl.kind = "enum";
+                decl.c_type = format!("uint{}_t", size * 8);
+                decl.values = values;
+                decl.size = size;
+                self.support.layouts.insert(name.to_vec(), Layout::Checked);
+                self.support.decls.push(decl);
+                Ok(Shape {
+                    size,
+                    align: size,
+                    layout: Layout::Checked,
+                    c_type: c_name(name),
+                    dims: Vec::new(),
+                })
+            }
+            IdlTypeDclKind::StructDcl(_, ref base, ref members) => {
+                let mut fields = Vec::new();
+                if let Some(base) = base {
+                    fields.push((
+                        BASE_MEMBER.to_owned(),
+                        IdlTypeSpec::ScopedName(base.clone()),
+                        false,
+                    ));
+                }
+                fields.extend(members.iter().map(|member| {
+                    (
+                        member.id.clone(),
+                        member.type_spec.clone(),
+                        member.annotations.is_set("optional"),
+                    )
+                }));
+                if fields.is_empty() {
+                    return Err("a struct without members has no C equivalent".to_owned());
+                }
+                let (mut offset, mut align, mut layout) = (0usize, 1, Layout::Pod);
+                for (id, spec, optional) in fields {
+                    if optional {
+                        return Err(format!("member `{id}` is optional"));
+                    }
+                    let shape = self
+                        .shape(&spec, &scope)
+                        .map_err(|reason| format!("member `{id}` is {reason}"))?;
+                    let start = offset.next_multiple_of(shape.align);
+                    layout = match (layout, shape.layout) {
+                        (Layout::Fixed(reason), _) => Layout::Fixed(reason),
+                        (_, Layout::Fixed(reason)) => {
+                            Layout::Fixed(format!("member `{id}` is {reason}"))
+                        }
+                        _ if start > offset => Layout::Fixed(format!(
+                            "{} bytes of padding before member `{id}`",
+                            start - offset
+                        )),
+                        (Layout::Checked, _) | (_, Layout::Checked) => Layout::Checked,
+                        _ => Layout::Pod,
+                    };
+                    offset = start
+                        .checked_add(shape.size)
+                        .ok_or_else(|| "too large for the address space".to_owned())?;
+                    align = align.max(shape.align);
+                    decl.fields.push(IdlCField {
+                        name: id,
+                        c_type: shape.c_type,
+                        dims: c_dims(&shape.dims),
+                    });
+                }
+                let size = offset.next_multiple_of(align);
+                if size > offset && !matches!(layout, Layout::Fixed(_)) {
+                    layout = Layout::Fixed(format!("{} bytes of trailing padding", size - offset));
+                }
+                decl.size = size;
+                decl.report = layout.report();
+                self.support.layouts.insert(name.to_vec(), layout.clone());
+                self.support.decls.push(decl);
+                Ok(Shape {
+                    size,
+                    align,
+                    layout: match layout {
+                        Layout::Fixed(_) => {
+                            Layout::Fixed(format!("`{qualified}`, which is not plain old data"))
+                        }
+                        layout => layout,
+                    },
+                    c_type: c_name(name),
+                    dims: Vec::new(),
+                })
+            }
+            IdlTypeDclKind::UnionDcl(..) => {
+                Err("a union, which maps to a Rust enum with data".to_owned())
+            }
+            IdlTypeDclKind::None => Err("not a type".to_owned()),
+        }
+    }
+}
+
+/// Compute the `#[repr(C)]` layouts of all structs, enums and typedefs. Types reaching
+/// a string, sequence, union, character or `@optional` member have none.
+pub fn analyze(
+    root_module: &IdlModule,
+    config: &Configuration,
+    newtypes: &NewtypeSupport,
+) -> LayoutSupport {
+    let mut analysis = LayoutAnalysis {
+        config,
+        types: TypeIndex::new(root_module),
+        constants: ConstIndex::new(root_module),
+        newtypes,
+        shapes: HashMap::new(),
+        visiting: HashSet::new(),
+        support: LayoutSupport {
+            enabled: config.repr_c,
+            ..Default::default()
+        },
+    };
+    let names = analysis
+        .types
+        .iter()
+        .filter(|typ| !matches!(typ.dcl.0, IdlTypeDclKind::None))
+        .map(|typ| typ.name.to_vec())
+        .collect::<Vec<_>>();
+    for name in names {
+        // the reasons are kept by the support
+        let _ = analysis.named_shape(&name);
+    }
+    analysis.support
+}
@@ -3,19 +3,44 @@
 //
 // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
 // http://www.apache.org/licenses/LICENSE-2.0>
+mod annotation;
 mod ast;
-
+mod bounds;
+mod bytes;
+mod cdr;
+mod derive;
+mod extensibility;
+mod index;
+mod key;
+mod layout;
+mod member_id;
+mod naming;
+mod newtype;
+mod parameter_list;
+mod reflect;
+mod repository_id;
+mod type_object;
+mod view;
+
+pub use annotation::AnnotationMapping;
+use annotation::{AnnotationResolver, IdlAnnotationDcl, IdlAnnotationMember, ParamKind};
 use ast::*;
+pub use derive::Derive;
+use index::{ConstIndex, TypeIndex};
 use omg_idl_grammar::{IdlParser, Rule};
+pub use parameter_list::ParameterId;
 use pest::{
     error::ErrorVariant,
     iterators::{Pair, Pairs},
     Parser, RuleType,
 };
+use serde_derive::Deserialize;
 use std::{
+    collections::{BTreeSet, HashMap},
     fs::File,
     io::{self, Read, Write},
     path::{Path, PathBuf},
+    str::FromStr,
 };
 use thiserror::Error;
 
@@ -29,6 +54,30 @@ pub enum IdlError<R: RuleType> {
     RenderError(#[from] minijinja::Error),
     #[error("Failed to write generated code.")]
     WriteError(#[from] io::Error),
+    #[error("Identifier collision after Rust naming conversion: {0}")]
+    NamingCollision(String),
+    #[error("Invalid annotation: {0}")]
+    AnnotationError(String),
+    #[error("Invalid key: {0}")]
+    KeyError(String),
+    #[error("Invalid extensibility: {0}")]
+    ExtensibilityError(String),
+    #[error("Invalid member ID: {0}")]
+    MemberIdError(String),
+    #[error("Invalid parameter list: {0}")]
+    ParameterListError(String),
+    #[error("Invalid bound: {0}")]
+    BoundError(String),
+    #[error("Invalid view: {0}")]
+    ViewError(String),
+    #[error("Invalid newtype: {0}")]
+    NewtypeError(String),
+    #[error("Invalid byte mapping: {0}")]
+    ByteMappingError(String),
+    #[error("Invalid repository ID: {0}")]
+    RepositoryIdError(String),
+    #[error("Invalid TypeObject: {0}")]
+    TypeObjectError(String),
 }
 
 /// All IDL Loader must be capable of reading data into the system
@@ -36,12 +85,130 @@ pub trait IdlLoader {
     fn load(&self, filename: &Path) -> Result<String, io::Error>;
 }
 
-/// Container for where to find a file and if extra logging occur
+/// Container for where to find a file, if extra logging occur and how the
+/// generated Rust code shall look like
 #[derive(Debug, Default)]
 pub struct Configuration {
     search_path: PathBuf,
     idl_file: PathBuf,
     verbose: bool,
+    snake_case_members: bool,
+    camel_case_types: bool,
+    screaming_case_constants: bool,
+    annotation_mappings: HashMap<String, AnnotationMapping>,
+    parameter_lists: HashMap<String, HashMap<String, ParameterId>>,
+    zero_copy_views: bool,
+    repr_c: bool,
+    no_std: bool,
+    heapless: bool,
+    serde: SerdeSupport,
+    derives: BTreeSet<Derive>,
+    newtype_typedefs: bool,
+    newtypes: BTreeSet<String>,
+    char_mapping: CharMapping,
+    wide_char_mapping: WideCharMapping,
+    native_f128: bool,
+    byte_mapping: ByteMapping,
+    member_byte_mappings: HashMap<String, ByteMapping>,
+    builders: bool,
+    no_accessors: bool,
+    reflection: bool,
+    type_objects: bool,
+}
+
+/// Whether the generated types derive serde's `Serialize` and `Deserialize`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum SerdeSupport {
+    /// Always, the generated code depends on serde_derive and serde_arrays
+    #[default]
+    Always,
+    /// Only if the named cargo feature of the crate compiling the generated code is
+    /// enabled, i.e. `Feature("serde".to_owned())`
+    Feature(String),
+    /// Never, no serde derives or attributes are generated
+    Never,
+}
+
+/// Rust type of IDL `char`, an 8 bit code unit
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CharMapping {
+    /// `char`, characters outside of Latin-1 are encoded as '?'
+    #[default]
+    Char,
+    /// `u8`
+    Octet,
+    /// `omg_idl_rt::Latin1`, the code unit read as Latin-1 character
+    Latin1,
+}
+
+impl FromStr for CharMapping {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "char" => Ok(CharMapping::Char),
+            "u8" => Ok(CharMapping::Octet),
+            "latin1" => Ok(CharMapping::Latin1),
+            _ => Err(format!(
+                "unknown char mapping {name}, expected one of char, u8, latin1"
+            )),
+        }
+    }
+}
+
+/// Rust types of IDL `wchar` and `wstring`, 16 bit UTF-16 code units
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WideCharMapping {
+    /// `char` and `String`, characters outside of the Basic Multilingual Plane are encoded
+    /// as U+FFFD
+    #[default]
+    Char,
+    /// `u16` and `omg_idl_rt::WString`, keeping the code units as received
+    Utf16,
+}
+
+impl FromStr for WideCharMapping {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "char" => Ok(WideCharMapping::Char),
+            "utf16" => Ok(WideCharMapping::Utf16),
+            _ => Err(format!(
+                "unknown wchar mapping {name}, expected one of char, utf16"
+            )),
+        }
+    }
+}
+
+/// Rust types of IDL `sequence<octet>` and `octet[N]`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ByteMapping {
+    /// `Vec<u8>` and `[u8; N]`, serialized by serde element by element
+    #[default]
+    Vec,
+    /// `omg_idl_rt::ByteBuf` and `[u8; N]`, serialized by serde as byte string. Arrays
+    /// require serde_bytes.
+    ByteBuf,
+    /// `bytes::Bytes` and `[u8; N]`, serialized by serde as byte string. Requires the
+    /// `bytes` feature of omg-cdr, arrays require serde_bytes.
+    Bytes,
+}
+
+impl FromStr for ByteMapping {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "vec" => Ok(ByteMapping::Vec),
+            "bytebuf" => Ok(ByteMapping::ByteBuf),
+            "bytes" => Ok(ByteMapping::Bytes),
+            _ => Err(format!(
+                "unknown byte mapping {name}, expected one of vec, bytebuf, bytes"
+            )),
+        }
+    }
 }
 
 impl Configuration {
@@ -50,6 +217,320 @@ impl Configuration {
             search_path: search_path.to_path_buf(),
             idl_file: idl_file.to_path_buf(),
             verbose,
+            ..Default::default()
+        }
+    }
+
+    /// Convert struct members, union elements and the generated accessors to snake_case
+    pub fn with_snake_case_members(mut self, enable: bool) -> Self {
+        self.snake_case_members = enable;
+        self
+    }
+
+    /// Convert structs, enums, unions, typedefs and enum/union variants to UpperCamelCase
+    pub fn with_camel_case_types(mut self, enable: bool) -> Self {
+        self.camel_case_types = enable;
+        self
+    }
+
+    /// Convert constants to SCREAMING_SNAKE_CASE
+    pub fn with_screaming_case_constants(mut self, enable: bool) -> Self {
+        self.screaming_case_constants = enable;
+        self
+    }
+
+    /// Enable or disable all Rust naming conventions at once
+    pub fn with_rust_naming(self, enable: bool) -> Self {
+        self.with_snake_case_members(enable)
+            .with_camel_case_types(enable)
+            .with_screaming_case_constants(enable)
+    }
+
+    /// Map the user-defined annotation `name` to Rust attributes or derives
+    pub fn with_annotation_mapping(mut self, name: &str, mapping: AnnotationMapping) -> Self {
+        self.annotation_mappings.insert(name.to_owned(), mapping);
+        self
+    }
+
+    /// Encode the struct or union `type_name` (fully qualified, i.e. `DDS::Foo`) as RTPS
+    /// parameter list (PL_CDR). `ids` maps every member of a struct to its parameter ID,
+    /// a union takes the IDs from its labels and is given no `ids`.
+    pub fn with_parameter_list(
+        mut self,
+        type_name: &str,
+        ids: HashMap<String, ParameterId>,
+    ) -> Self {
+        self.parameter_lists.insert(type_name.to_owned(), ids);
+        self
+    }
+
+    /// Generate a borrowed view `FooRef<'a>` of every final and appendable struct `Foo`,
+    /// reading its members from CDR data without copying it. Also enabled by
+    /// `#pragma DCPS_SUPPORT_ZERO_COPY_READ`.
+    pub fn with_zero_copy_views(mut self, enable: bool) -> Self {
+        self.zero_copy_views = enable;
+        self
+    }
+
+    /// Generate `#[repr(C)]` structs and enums for every type whose members all have a
+    /// fixed size, plain old data derives `bytemuck::Pod` and `bytemuck::Zeroable`.
+    /// Types without a C layout are commented with the reason, see also
+    /// `generate_c_header_with_search_path`.
+    pub fn with_repr_c(mut self, enable: bool) -> Self {
+        self.repr_c = enable;
+        self
+    }
+
+    /// Generate `core` and `alloc` paths only, for `#![no_std]` crates declaring
+    /// `extern crate alloc;` in their root.
+    pub fn with_no_std(mut self, enable: bool) -> Self {
+        self.no_std = enable;
+        self
+    }
+
+    /// Map bounded strings and sequences to `heapless::String<N>` and
+    /// `heapless::Vec<T, N>`, requires the `heapless` feature of omg-cdr. Together with
+    /// `with_no_std` fully bounded types need no allocator once the `alloc` features of
+    /// omg-cdr and omg-idl-rt are disabled.
+    pub fn with_heapless(mut self, enable: bool) -> Self {
+        self.heapless = enable;
+        self
+    }
+
+    /// Derive `Serialize` and `Deserialize` always, behind a cargo feature or never
+    pub fn with_serde(mut self, serde: SerdeSupport) -> Self {
+        self.serde = serde;
+        self
+    }
+
+    /// Add the derives to every struct, union and enum whose members implement them,
+    /// i.e. no `Eq` or `Hash` for types containing floating point numbers
+    pub fn with_derives(mut self, derives: impl IntoIterator<Item = Derive>) -> Self {
+        self.derives.extend(derives);
+        self
+    }
+
+    /// Generate every typedef as newtype struct wrapping the aliased type instead of a
+    /// type alias, so that typedefs of the same type cannot be mixed up
+    pub fn with_newtype_typedefs(mut self, enable: bool) -> Self {
+        self.newtype_typedefs = enable;
+        self
+    }
+
+    /// Generate the typedef `type_name` (fully qualified, i.e. `DDS::DomainId_t`) as
+    /// newtype struct, see `with_newtype_typedefs`
+    pub fn with_newtype(mut self, type_name: &str) -> Self {
+        self.newtypes.insert(type_name.to_owned());
+        self
+    }
+
+    /// Map IDL `char` to `char`, `u8` or `omg_idl_rt::Latin1`
+    pub fn with_char_mapping(mut self, mapping: CharMapping) -> Self {
+        self.char_mapping = mapping;
+        self
+    }
+
+    /// Map IDL `wchar` and `wstring` to `char` and `String` or to `u16` and
+    /// `omg_idl_rt::WString`
+    pub fn with_wide_char_mapping(mut self, mapping: WideCharMapping) -> Self {
+        self.wide_char_mapping = mapping;
+        self
+    }
+
+    /// Map IDL `long double` to the unstable `f128` instead of `omg_idl_rt::F128`,
+    /// requires a nightly toolchain and the `f128` feature of omg-cdr
+    pub fn with_native_f128(mut self, enable: bool) -> Self {
+        self.native_f128 = enable;
+        self
+    }
+
+    /// Map IDL `sequence<octet>` to `Vec<u8>`, `omg_idl_rt::ByteBuf` or `bytes::Bytes`.
+    /// Octet arrays stay `[u8; N]`, serialized by serde_bytes unless mapped to `Vec`.
+    /// Bounded sequences of the heapless mapping stay `heapless::Vec<u8, N>`.
+    pub fn with_byte_mapping(mut self, mapping: ByteMapping) -> Self {
+        self.byte_mapping = mapping;
+        self
+    }
+
+    /// Map the octet sequence or array of the struct member or union element `member`
+    /// (fully qualified, i.e. `RTPS::Data::serializedData`) independent of
+    /// `with_byte_mapping`. A member typed by a typedef of an octet sequence gets the
+    /// mapped type instead of the typedef.
+    pub fn with_member_byte_mapping(mut self, member: &str, mapping: ByteMapping) -> Self {
+        self.member_byte_mappings.insert(member.to_owned(), mapping);
+        self
+    }
+
+    /// Generate a builder `FooBuilder` with chainable setters for every struct `Foo`.
+    /// Members not set take their `@default` or zero value, `@key` members without
+    /// `@default` must be set for `build()` to succeed.
+    pub fn with_builders(mut self, enable: bool) -> Self {
+        self.builders = enable;
+        self
+    }
+
+    /// Generate a getter and a setter per struct member, enabled by default. Setters of
+    /// bounded members validate the value either way through `validate()`.
+    pub fn with_accessors(mut self, enable: bool) -> Self {
+        self.no_accessors = !enable;
+        self
+    }
+
+    /// Implement `omg_idl_rt::Reflect` for every struct, union, enum and newtype, giving
+    /// access to the type name, the member descriptors and fields by path
+    pub fn with_reflection(mut self, enable: bool) -> Self {
+        self.reflection = enable;
+        self
+    }
+
+    /// Implement `omg_idl_rt::TypeObjects` for every struct, union, enum and newtype,
+    /// holding the serialized DDS-XTypes TypeObjects and the TypeIdentifiers referring
+    /// to them
+    pub fn with_type_objects(mut self, enable: bool) -> Self {
+        self.type_objects = enable;
+        self
+    }
+
+    /// `#[serde(...)]` attribute with the arguments `args`, `None` without serde
+    fn serde_attribute(&self, args: &str) -> Option<String> {
+        match self.serde {
+            SerdeSupport::Always => Some(format!("#[serde({args})]")),
+            SerdeSupport::Feature(ref feature) => Some(format!(
+                "#[cfg_attr(feature = \"{feature}\", serde({args}))]"
+            )),
+            SerdeSupport::Never => None,
+        }
+    }
+
+    /// Crate of the generated `fmt`, `str`, `cmp`, `hash` and `array` paths
+    fn core_crate(&self) -> &'static str {
+        if self.no_std {
+            "core"
+        } else {
+            "std"
+        }
+    }
+
+    /// Path of the unbounded string type
+    fn string_path(&self) -> &'static str {
+        if self.no_std {
+            "alloc::string::String"
+        } else {
+            "String"
+        }
+    }
+
+    /// Rust type of IDL `long double`
+    fn f128_type(&self) -> &'static str {
+        if self.native_f128 {
+            "f128"
+        } else {
+            "omg_idl_rt::F128"
+        }
+    }
+
+    /// Rust expression of the `long double` value, rounded to `f64` precision
+    fn f128_literal(&self, value: f64) -> String {
+        if self.native_f128 {
+            format!("{value:?}")
+        } else {
+            format!("omg_idl_rt::F128::from_f64({value:?})")
+        }
+    }
+
+    /// Rust type of IDL `char`
+    fn char_type(&self) -> &'static str {
+        match self.char_mapping {
+            CharMapping::Char => "char",
+            CharMapping::Octet => "u8",
+            CharMapping::Latin1 => "omg_idl_rt::Latin1",
+        }
+    }
+
+    /// Rust type of IDL `wchar`
+    fn wide_char_type(&self) -> &'static str {
+        match self.wide_char_mapping {
+            WideCharMapping::Char => "char",
+            WideCharMapping::Utf16 => "u16",
+        }
+    }
+
+    /// Rust literal of the character `value` of an IDL `wchar` if `wide`, of a `char`
+    /// otherwise. The literals are patterns as well. `None` if the mapped type cannot
+    /// hold the character.
+    fn char_literal(&self, value: char, wide: bool) -> Option<String> {
+        let octet = |value: char| {
+            let octet = u8::try_from(value).ok()?;
+            match value.is_ascii() && !value.is_ascii_control() {
+                true => Some(format!("b{value:?}")),
+                false => Some(format!("0x{octet:02X}")),
+            }
+        };
+        match (wide, self.char_mapping, self.wide_char_mapping) {
+            (false, CharMapping::Char, _) | (true, _, WideCharMapping::Char) => {
+                Some(format!("{value:?}"))
+            }
+            (false, CharMapping::Octet, _) => octet(value),
+            (false, CharMapping::Latin1, _) => {
+                Some(format!("omg_idl_rt::Latin1({})", octet(value)?))
+            }
+            (true, _, WideCharMapping::Utf16) => {
+                let unit = u16::try_from(u32::from(value)).ok()?;
+                Some(format!("0x{unit:04X}"))
+            }
+        }
+    }
+
+    /// Path of the unbounded sequence type
+    fn vec_path(&self) -> &'static str {
+        if self.no_std {
+            "alloc::vec::Vec"
+        } else {
+            "Vec"
+        }
+    }
+
+    /// Path of the unbounded `sequence<octet>` type of `mapping`
+    fn byte_sequence_path(&self, mapping: ByteMapping) -> &'static str {
+        match mapping {
+            ByteMapping::Vec => self.vec_path(),
+            ByteMapping::ByteBuf => "omg_idl_rt::ByteBuf",
+            ByteMapping::Bytes => "bytes::Bytes",
+        }
+    }
+
+    /// Rust type of the unbounded `sequence<octet>` of `mapping`
+    fn byte_sequence_type(&self, mapping: ByteMapping) -> String {
+        match mapping {
+            ByteMapping::Vec => format!("{}<u8>", self.vec_path()),
+            _ => self.byte_sequence_path(mapping).to_owned(),
+        }
+    }
+
+    /// Rust identifier of a struct member or union element
+    fn member_name(&self, id: &str) -> String {
+        if self.snake_case_members {
+            naming::to_snake_case(id)
+        } else {
+            id.to_owned()
+        }
+    }
+
+    /// Rust identifier of a type or an enum/union variant
+    fn type_name(&self, id: &str) -> String {
+        if self.camel_case_types {
+            naming::to_upper_camel_case(id)
+        } else {
+            id.to_owned()
+        }
+    }
+
+    /// Rust identifier of a constant
+    fn const_name(&self, id: &str) -> String {
+        if self.screaming_case_constants {
+            naming::to_screaming_snake_case(id)
+        } else {
+            id.to_owned()
         }
     }
 }
@@ -62,6 +543,20 @@ type Scope = Vec<String>;
 struct Context<'i> {
     config: &'i Configuration,
     root_module: IdlModule,
+    /// Annotations preceding the definition currently processed
+    pending_annotations: IdlAnnotations,
+    /// Annotations declared via `@annotation`
+    annotation_dcls: HashMap<String, IdlAnnotationDcl>,
+    /// Pragmas in order of appearance
+    pragmas: Vec<IdlPragma>,
+    /// `#pragma prefix` directives in effect, the innermost last
+    prefixes: Vec<IdlPrefix>,
+    /// The `#pragma prefix` in effect where a type is declared
+    type_prefixes: HashMap<Vec<String>, IdlPrefix>,
+    /// `typeid` and `typeprefix` declarations in order of appearance
+    repository_id_dcls: Vec<IdlRepositoryIdDcl>,
+    /// Enclosing conditional directives, `false` if the branch is excluded
+    conditions: Vec<bool>,
 }
 
 impl<'i> Context<'i> {
@@ -69,6 +564,13 @@ impl<'i> Context<'i> {
         Context {
             config,
             root_module: IdlModule::new(None),
+            pending_annotations: IdlAnnotations::default(),
+            annotation_dcls: HashMap::new(),
+            pragmas: Vec::new(),
+            prefixes: Vec::new(),
+            type_prefixes: HashMap::new(),
+            repository_id_dcls: Vec::new(),
+            conditions: Vec::new(),
         }
     }
 
@@ -91,6 +593,13 @@ impl<'i> Context<'i> {
 
     /// Add a new entry to the module for the discovered type
     fn add_type_dcl(&mut self, scope: &Scope, key: String, type_dcl: IdlTypeDcl) {
+        if let Some(prefix) = self.prefixes.last() {
+            let mut name = scope.clone();
+            name.push(key.clone());
+            self.type_prefixes
+                .entry(name)
+                .or_insert_with(|| prefix.clone());
+        }
         self.lookup_module(scope)
             .types
             .entry(key)
@@ -155,12 +664,15 @@ impl<'i> Context<'i> {
                 match (inner.next(), inner.next()) {
                     (Some(typ), None) => {
                         let typ_expr = self.read_type_spec(scope, typ)?;
-                        Ok(IdlTypeSpec::SequenceType(Box::new(typ_expr)))
+                        Ok(IdlTypeSpec::SequenceType(Box::new(typ_expr), None))
                     }
                     (Some(typ), Some(bound)) => {
                         let typ_expr = self.read_type_spec(scope, typ)?;
-                        let _bound_expr = self.read_const_expr(scope, bound)?;
-                        Ok(IdlTypeSpec::SequenceType(Box::new(typ_expr)))
+                        let bound_expr = self.read_const_expr(scope, bound)?;
+                        Ok(IdlTypeSpec::SequenceType(
+                            Box::new(typ_expr),
+                            Some(Box::new(bound_expr)),
+                        ))
                     }
                     _ => Err(pest::error::Error::new_from_pos(
                         ErrorVariant::CustomError {
@@ -200,6 +712,7 @@ impl<'i> Context<'i> {
         scope: &Scope,
         pair: Pair<Rule>,
         type_spec: &IdlTypeSpec,
+        annotations: &IdlAnnotations,
     ) -> Result<IdlStructMember, pest::error::Error<Rule>> {
         let pos = pair.as_span().start_pos();
         match pair.into_inner().next() {
@@ -221,6 +734,7 @@ impl<'i> Context<'i> {
                         Some(pair) => Ok(IdlStructMember {
                             id: self.read_identifier(scope, pair)?,
                             type_spec: type_spec.clone(),
+                            annotations: annotations.clone(),
                         }),
                         _ => Err(pest::error::Error::new_from_pos(
                             ErrorVariant::CustomError {
@@ -258,6 +772,7 @@ impl<'i> Context<'i> {
                                 Ok(IdlStructMember {
                                     id: self.read_identifier(scope, pair)?,
                                     type_spec: array_type_spec,
+                                    annotations: annotations.clone(),
                                 })
                             }
                             _ => Err(pest::error::Error::new_from_pos(
@@ -287,7 +802,7 @@ impl<'i> Context<'i> {
         }
     }
 
-    // member = { type_spec ~ declarators ~ ";" }
+    // member = { annotation_appl* ~ type_spec ~ declarators ~ ";" }
     // declarators = { declarator ~ ("," ~ declarator )* }
     // declarator = { array_declarator | simple_declarator }
     fn read_struct_member(
@@ -307,6 +822,7 @@ impl<'i> Context<'i> {
         }
 
         let mut inner = pair.into_inner();
+        let annotations = self.read_annotations(scope, &mut inner)?;
         let type_spec = match inner.next() {
             Some(pair) => self.read_type_spec(scope, pair),
             _ => Err(pest::error::Error::new_from_pos(
@@ -329,7 +845,9 @@ impl<'i> Context<'i> {
         }?;
 
         declarators
-            .map(|declarator| self.read_struct_member_declarator(scope, declarator, &type_spec))
+            .map(|declarator| {
+                self.read_struct_member_declarator(scope, declarator, &type_spec, &annotations)
+            })
             .collect()
     }
 
@@ -344,7 +862,7 @@ impl<'i> Context<'i> {
             println!("{:indent$}{:?}", "", rule, indent = 3 * scope.len());
         }
         match rule {
-            Rule::identifier | Rule::enumerator => Ok(pair.as_str().to_owned()),
+            Rule::identifier => Ok(pair.as_str().to_owned()),
             _ => Err(pest::error::Error::new_from_pos(
                 ErrorVariant::CustomError {
                     message: "Pair did not contain a valid scoped name rule".to_string(),
@@ -550,6 +1068,7 @@ impl<'i> Context<'i> {
         scope: &Scope,
         pair: Pair<Rule>,
         type_spec: &IdlTypeSpec,
+        annotations: IdlAnnotations,
     ) -> Result<IdlSwitchElement, pest::error::Error<Rule>> {
         let pos = pair.as_span().start_pos();
 
@@ -575,6 +1094,7 @@ impl<'i> Context<'i> {
                                 Ok(IdlSwitchElement {
                                     id: self.read_identifier(scope, pair)?,
                                     type_spec: type_spec.clone(),
+                                    annotations,
                                 })
                             }
                             _ => {
@@ -611,6 +1131,7 @@ impl<'i> Context<'i> {
                                 Ok(IdlSwitchElement {
                                     id,
                                     type_spec: array_type_spec,
+                                    annotations,
                                 })
                             }
                             _ => Err(pest::error::Error::new_from_pos(
@@ -635,11 +1156,12 @@ impl<'i> Context<'i> {
         }
     }
 
-    /// element_spec = { type_spec ~ declarator }
+    /// element_spec = { annotation_appl* ~ type_spec ~ declarator }
     fn read_switch_element_spec(
         &mut self,
         scope: &Scope,
         pair: Pair<Rule>,
+        mut annotations: IdlAnnotations,
     ) -> Result<IdlSwitchElement, pest::error::Error<Rule>> {
         let rule = pair.as_rule();
         let pos = pair.as_span().start_pos();
@@ -647,11 +1169,16 @@ impl<'i> Context<'i> {
             println!("{:indent$}{:?}", "", rule, indent = 3 * scope.len());
         }
         let mut inner = pair.into_inner();
+        annotations
+            .0
+            .extend(self.read_annotations(scope, &mut inner)?.0);
         match inner.next() {
             Some(pair) => {
                 let type_spec = self.read_type_spec(scope, pair)?;
                 match inner.next() {
-                    Some(pair) => self.read_switch_element_declarator(scope, pair, &type_spec),
+                    Some(pair) => {
+                        self.read_switch_element_declarator(scope, pair, &type_spec, annotations)
+                    }
                     _ => Err(pest::error::Error::new_from_pos(
                         ErrorVariant::CustomError {
                             message: "Failed to read declarator from the switch element spec"
@@ -732,7 +1259,7 @@ impl<'i> Context<'i> {
         }
     }
 
-    /// case = { case_label+ ~ element_spec ~ ";" }
+    /// case = { annotation_appl* ~ case_label+ ~ element_spec ~ ";" }
     fn read_switch_case(
         &mut self,
         scope: &Scope,
@@ -747,7 +1274,8 @@ impl<'i> Context<'i> {
             );
         }
 
-        let inner = pair.into_inner();
+        let mut inner = pair.into_inner();
+        let annotations = self.read_annotations(scope, &mut inner)?;
         let case_labels: Result<Vec<IdlSwitchLabel>, pest::error::Error<Rule>> = inner
             .clone()
             .filter(|p| p.as_rule() == Rule::case_label)
@@ -757,7 +1285,7 @@ impl<'i> Context<'i> {
         // there will be only one in the list, choose the last
         let elem_spec = inner
             .filter(|p| p.as_rule() == Rule::element_spec)
-            .map(|p| self.read_switch_element_spec(scope, p))
+            .map(|p| self.read_switch_element_spec(scope, p, annotations.clone()))
             .last()
             .unwrap();
 
@@ -767,6 +1295,231 @@ impl<'i> Context<'i> {
         })
     }
 
+    /// annotation_appl = { "@" ~ scoped_name ~ ("(" ~ annotation_appl_params? ~ ")")? }
+    /// annotation_appl_params = { annotation_appl_param ~ ("," ~ annotation_appl_param)* | const_expr }
+    /// annotation_appl_param = { identifier ~ "=" ~ const_expr }
+    fn read_annotation_appl(
+        &mut self,
+        scope: &Scope,
+        pair: Pair<Rule>,
+    ) -> Result<IdlAnnotation, pest::error::Error<Rule>> {
+        let pos = pair.as_span().start_pos();
+        if self.config.verbose {
+            println!(
+                "{:indent$}{:?} '{}'",
+                "",
+                pair.as_rule(),
+                pair.as_str(),
+                indent = 3 * scope.len()
+            );
+        }
+
+        let mut inner = pair.into_inner();
+        let name = match inner.next() {
+            Some(pair) => self.read_scoped_name(scope, pair),
+            None => Err(pest::error::Error::new_from_pos(
+                ErrorVariant::CustomError {
+                    message: "Annotation application is missing the annotation name".to_string(),
+                },
+                pos,
+            )),
+        }?;
+
+        let mut params = Vec::new();
+        for param in inner.flat_map(|params| params.into_inner()) {
+            match param.as_rule() {
+                Rule::annotation_appl_param => {
+                    let mut param_inner = param.into_inner();
+                    match (param_inner.next(), param_inner.next()) {
+                        (Some(id), Some(expr)) => params.push(IdlAnnotationParam {
+                            name: Some(self.read_identifier(scope, id)?),
+                            expr: self.read_const_expr(scope, expr)?,
+                            value: None,
+                        }),
+                        _ => {
+                            return Err(pest::error::Error::new_from_pos(
+                                ErrorVariant::CustomError {
+                                    message: "Annotation parameter is missing name or value"
+                                        .to_string(),
+                                },
+                                pos,
+                            ))
+                        }
+                    }
+                }
+                _ => params.push(IdlAnnotationParam {
+                    name: None,
+                    expr: self.read_const_expr(scope, param)?,
+                    value: None,
+                }),
+            }
+        }
+
+        Ok(IdlAnnotation { name, params })
+    }
+
+    /// Read the annotation applications leading the remaining pairs
+    fn read_annotations(
+        &mut self,
+        scope: &Scope,
+        inner: &mut Pairs<Rule>,
+    ) -> Result<IdlAnnotations, pest::error::Error<Rule>> {
+        let mut annotations = IdlAnnotations::default();
+        while let Some(pair) = inner.peek() {
+            if pair.as_rule() != Rule::annotation_appl {
+                break;
+            }
+            let _ = inner.next();
+            annotations.0.push(self.read_annotation_appl(scope, pair)?);
+        }
+        Ok(annotations)
+    }
+
+    /// enumerator = { annotation_appl* ~ identifier }
+    fn read_enumerator(
+        &mut self,
+        scope: &Scope,
+        pair: Pair<Rule>,
+    ) -> Result<IdlEnumerator, pest::error::Error<Rule>> {
+        let pos = pair.as_span().start_pos();
+        let mut inner = pair.into_inner();
+        let annotations = self.read_annotations(scope, &mut inner)?;
+        match inner.next() {
+            Some(pair) => Ok(IdlEnumerator {
+                id: self.read_identifier(scope, pair)?,
+                annotations,
+            }),
+            None => Err(pest::error::Error::new_from_pos(
+                ErrorVariant::CustomError {
+                    message: "Enumerator is missing its identifier".to_string(),
+                },
+                pos,
+            )),
+        }
+    }
+
+    /// annotation_dcl = { annotation_header ~ "{" ~ annotation_body ~ "}" }
+    /// annotation_body = { ( annotation_member | enum_dcl ~ ";" | const_dcl ~ ";" | typedef_dcl ~ ";" )* }
+    /// annotation_member = { annotation_member_type ~ simple_declarator ~ ( "default" ~ const_expr )? ~ ";" }
+    fn read_annotation_dcl(
+        &mut self,
+        scope: &Scope,
+        pair: Pair<Rule>,
+    ) -> Result<IdlAnnotationDcl, pest::error::Error<Rule>> {
+        let pos = pair.as_span().start_pos();
+        let custom_error = |message: String| {
+            pest::error::Error::new_from_pos(ErrorVariant::CustomError { message }, pos)
+        };
+
+        let mut inner = pair.into_inner();
+        let id = match inner.next().and_then(|header| header.into_inner().next()) {
+            Some(pair) => self.read_identifier(scope, pair),
+            None => Err(custom_error(
+                "Annotation declaration is missing its name".to_string(),
+            )),
+        }?;
+        let body = inner
+            .next()
+            .map(|body| body.into_inner().collect::<Vec<_>>());
+        let body = body.unwrap_or_default();
+
+        // enumerations declared within the body may be used as parameter types
+        let mut enums = HashMap::new();
+        for pair in body.iter().filter(|p| p.as_rule() == Rule::enum_dcl) {
+            let mut enum_inner = pair.clone().into_inner();
+            let enum_id = enum_inner.next().map(|p| p.as_str().to_owned());
+            let enumerators = enum_inner
+                .map(|p| self.read_enumerator(scope, p).map(|e| e.id))
+                .collect::<Result<Vec<_>, _>>()?;
+            enums.insert(enum_id.unwrap_or_default(), enumerators);
+        }
+
+        let mut members = Vec::new();
+        for pair in body
+            .into_iter()
+            .filter(|p| p.as_rule() == Rule::annotation_member)
+        {
+            let mut member_inner = pair.into_inner();
+            let (member_type, declarator) = match (member_inner.next(), member_inner.next()) {
+                (Some(member_type), Some(declarator)) => (member_type, declarator),
+                _ => {
+                    return Err(custom_error(format!(
+                        "Annotation member of @{id} is missing type or name"
+                    )))
+                }
+            };
+            let kind = match member_type.into_inner().next() {
+                Some(pair) if pair.as_rule() == Rule::any_const_type => ParamKind::Any,
+                Some(pair) => ParamKind::from_type_spec(&self.read_type_spec(scope, pair)?, &enums),
+                None => ParamKind::Any,
+            };
+            let name = match declarator.into_inner().next() {
+                Some(pair) => self.read_identifier(scope, pair)?,
+                None => {
+                    return Err(custom_error(format!(
+                        "Annotation member of @{id} is missing its name"
+                    )))
+                }
+            };
+            let default = match member_inner.next() {
+                // constants are not known yet, unresolved names are taken as enumerators
+                Some(pair) => Some(
+                    self.read_const_expr(scope, pair)?
+                        .evaluate(&|_| Ok(None))
+                        .map_err(custom_error)?,
+                ),
+                None => None,
+            };
+            members.push(IdlAnnotationMember {
+                name,
+                kind,
+                default,
+            });
+        }
+
+        Ok(IdlAnnotationDcl { id, members })
+    }
+
+    /// other_directive = @{ "#" ~ (!NEWLINE ~ ANY)* }
+    ///
+    /// There is no preprocessor, definitions are read regardless of conditionals. Only
+    /// pragmas within `#if 0` branches are dropped as they are commonly used to disable
+    /// pragmas for some IDL compilers.
+    fn read_directive(&mut self, scope: &Scope, directive: &str) {
+        let directive = directive.trim_start_matches('#').trim();
+        let (name, args) = directive
+            .split_once(char::is_whitespace)
+            .unwrap_or((directive, ""));
+        match name {
+            "if" => self.conditions.push(args.trim() != "0"),
+            "ifdef" | "ifndef" => self.conditions.push(true),
+            "else" | "elif" => {
+                if let Some(active) = self.conditions.last_mut() {
+                    *active = !*active;
+                }
+            }
+            "endif" => {
+                let _ = self.conditions.pop();
+            }
+            "pragma" if self.conditions.iter().all(|active| *active) => {
+                // replaces a prefix set in the same scope
+                if let Some(("prefix", prefix)) = args.trim().split_once(char::is_whitespace) {
+                    self.prefixes
+                        .retain(|outer| outer.scope.len() < scope.len());
+                    self.prefixes.push(IdlPrefix {
+                        scope: scope.clone(),
+                        prefix: prefix.trim().to_owned(),
+                    });
+                }
+                self.pragmas.push(IdlPragma {
+                    scope: scope.clone(),
+                    text: args.trim().to_owned(),
+                });
+            }
+            _ => (),
+        }
+    }
+
     /// declarator = { array_declarator | simple_declarator }
     /// array_declarator = { identifier ~ fixed_array_size+ }
     /// simple_declarator = { identifier }
@@ -775,6 +1528,7 @@ impl<'i> Context<'i> {
         scope: &Scope,
         pair: Pair<Rule>,
         type_spec: &IdlTypeSpec,
+        annotations: &IdlAnnotations,
     ) -> Result<(), pest::error::Error<Rule>> {
         let pos = pair.as_span().start_pos();
         match pair.into_inner().next() {
@@ -791,7 +1545,7 @@ impl<'i> Context<'i> {
                         match inner.next() {
                             Some(pair) => {
                                 let id = self.read_identifier(scope, pair)?;
-                                let type_dcl = IdlTypeDcl(IdlTypeDclKind::TypeDcl(id.clone(), type_spec.clone()));
+                                let type_dcl = IdlTypeDcl(IdlTypeDclKind::TypeDcl(id.clone(), type_spec.clone()), annotations.clone());
                                 self.add_type_dcl(scope, id, type_dcl);
                                 Ok(())
                             },
@@ -828,7 +1582,7 @@ impl<'i> Context<'i> {
                                     .collect();
                                 let array_type_spec =
                                     IdlTypeSpec::ArrayType(Box::new(type_spec.clone()), array_sizes?);
-                                let type_dcl = IdlTypeDcl(IdlTypeDclKind::TypeDcl(id, array_type_spec));
+                                let type_dcl = IdlTypeDcl(IdlTypeDclKind::TypeDcl(id, array_type_spec), annotations.clone());
                                 self.add_type_dcl(scope, key, type_dcl);
                                 Ok(())
                             },
@@ -876,26 +1630,57 @@ impl<'i> Context<'i> {
             );
         }
         match pair.as_rule() {
+            // definition = { annotation_appl* ~ ( module_dcl ~ ";" | const_dcl ~ ";" | ... ) }
+            Rule::definition => {
+                let mut annotations = IdlAnnotations::default();
+                for p in iter {
+                    if p.as_rule() == Rule::annotation_appl {
+                        annotations.0.push(self.read_annotation_appl(scope, p)?);
+                        continue;
+                    }
+                    // handed over to the declaration processed next
+                    self.pending_annotations = std::mem::take(&mut annotations);
+                    let result = self.process::<L>(scope, loader, p);
+                    self.pending_annotations = IdlAnnotations::default();
+                    result?;
+                }
+                Ok(())
+            }
             // module_dcl = { "module" ~ identifier ~ "{" ~ definition* ~ "}" }
             Rule::module_dcl => {
                 let id = iter.next().unwrap().as_str();
+                let annotations = std::mem::take(&mut self.pending_annotations);
 
                 scope.push(id.to_owned());
 
-                let _ = self.lookup_module(scope);
+                // a module may be reopened, collect the annotations of all occurrences
+                self.lookup_module(scope)
+                    .annotations
+                    .0
+                    .extend(annotations.0);
 
                 for p in iter {
                     let _ = self.process::<L>(scope, loader, p);
                 }
 
                 let _ = scope.pop();
+                // a prefix set within the module ends with it
+                self.prefixes
+                    .retain(|prefix| prefix.scope.len() <= scope.len());
 
                 Ok(())
             }
             // struct_def = { "struct" ~ identifier ~ (":" ~ scoped_name)? ~ "{" ~ member* ~ "}" }
             Rule::struct_def => {
+                let annotations = std::mem::take(&mut self.pending_annotations);
                 let id = iter.next().unwrap().as_str().to_owned();
                 let key = id.clone();
+                let base = match iter.peek() {
+                    Some(pair) if pair.as_rule() == Rule::scoped_name => {
+                        Some(self.read_scoped_name(scope, iter.next().unwrap())?)
+                    }
+                    _ => None,
+                };
                 let m1: Result<Vec<Vec<IdlStructMember>>, _> = iter
                     .map(|p| {
                         // skip the member-node and read sibbling directly
@@ -906,47 +1691,55 @@ impl<'i> Context<'i> {
                 let m2 = m1?;
                 let members = m2.into_iter().flatten().collect::<Vec<_>>();
 
-                let typedcl = IdlTypeDcl(IdlTypeDclKind::StructDcl(id, members));
+                let typedcl = IdlTypeDcl(IdlTypeDclKind::StructDcl(id, base, members), annotations);
                 self.add_type_dcl(scope, key, typedcl);
                 Ok(())
             }
-            // union_def = { "union" ~ identifier ~ "switch" ~ "(" ~ switch_type_spec ~ ")" ~ "{" ~ switch_body ~ "}" }
+            // union_def = { "union" ~ identifier ~ "switch" ~ "(" ~ annotation_appl* ~ switch_type_spec ~ ")" ~ "{" ~ switch_body ~ "}" }
             Rule::union_def => {
+                let annotations = std::mem::take(&mut self.pending_annotations);
                 let id = self.read_identifier(scope, iter.next().unwrap())?;
                 let key = id.to_owned();
+                // annotations of the discriminator are not represented in the AST
+                let _ = self.read_annotations(scope, &mut iter)?;
                 let switch_type_spec = self.read_switch_type_spec(scope, iter.next().unwrap())?;
                 let switch_body = self.read_switch_body(scope, iter.next().unwrap())?;
-                let union_def =
-                    IdlTypeDcl(IdlTypeDclKind::UnionDcl(id, switch_type_spec, switch_body));
+                let union_def = IdlTypeDcl(
+                    IdlTypeDclKind::UnionDcl(id, switch_type_spec, switch_body),
+                    annotations,
+                );
 
                 self.add_type_dcl(scope, key, union_def);
                 Ok(())
             }
             // type_declarator = { (template_type_spec | constr_type_dcl | simple_type_spec) ~ any_declarators }
             Rule::type_declarator => {
+                let annotations = std::mem::take(&mut self.pending_annotations);
                 let type_spec = self.read_type_spec(scope, iter.next().unwrap())?;
 
                 let any_declarators_pair = &iter.next().unwrap();
 
                 for p in any_declarators_pair.clone().into_inner() {
-                    let _ = self.process_declarator(scope, p, &type_spec);
+                    let _ = self.process_declarator(scope, p, &type_spec, &annotations);
                 }
                 Ok(())
             }
             // enum_dcl = { "enum" ~ identifier ~ "{" ~ enumerator ~ ("," ~ enumerator)* ~ ","? ~ "}" }
-            // enumerator = { identifier }
+            // enumerator = { annotation_appl* ~ identifier }
             Rule::enum_dcl => {
+                let annotations = std::mem::take(&mut self.pending_annotations);
                 let id = iter.next().unwrap().as_str().to_owned();
                 let key = id.clone();
                 let enums: Result<Vec<_>, pest::error::Error<Rule>> =
-                    iter.map(|p| self.read_identifier(scope, p)).collect();
+                    iter.map(|p| self.read_enumerator(scope, p)).collect();
 
-                let typedcl = IdlTypeDcl(IdlTypeDclKind::EnumDcl(id, enums?));
+                let typedcl = IdlTypeDcl(IdlTypeDclKind::EnumDcl(id, enums?), annotations);
                 self.add_type_dcl(scope, key, typedcl);
                 Ok(())
             }
             // const_dcl = { "const" ~ const_type ~ identifier ~ "=" ~ const_expr }
             Rule::const_dcl => {
+                let annotations = std::mem::take(&mut self.pending_annotations);
                 let type_spec = self.read_type_spec(scope, iter.next().unwrap())?;
                 let id = self.read_identifier(scope, iter.next().unwrap())?;
                 let key = id.clone();
@@ -955,10 +1748,18 @@ impl<'i> Context<'i> {
                     id,
                     typedcl: type_spec,
                     value: const_expr,
+                    annotations,
                 };
                 self.add_const_dcl(scope, key, const_dcl);
                 Ok(())
             }
+            // annotation_dcl = { annotation_header ~ "{" ~ annotation_body ~ "}" }
+            Rule::annotation_dcl => {
+                let declaration = self.read_annotation_dcl(scope, pair)?;
+                self.annotation_dcls
+                    .insert(declaration.id.clone(), declaration);
+                Ok(())
+            }
             // include_directive = !{ "#" ~ "include" ~ (("<" ~ path_spec ~ ">") | ("\"" ~ path_spec ~ "\"")) }
             Rule::include_directive => {
                 if let Some(ref p) = pair.clone().into_inner().nth(0) {
@@ -968,12 +1769,36 @@ impl<'i> Context<'i> {
                         .map_err(|_| IdlError::FileNotFound(fname))?;
 
                     let idl: Pairs<Rule> = IdlParser::parse(Rule::specification, &data)?;
+                    // a prefix set within the included file ends with it
+                    let prefixes = self.prefixes.clone();
                     for p in idl {
                         self.process::<L>(scope, loader, p)?;
                     }
+                    self.prefixes = prefixes;
                 }
                 Ok(())
             }
+            // type_id_dcl = { "typeid" ~ scoped_name ~ string_literal }
+            // type_prefix_dcl = { "typeprefix" ~ scoped_name ~ string_literal }
+            Rule::type_id_dcl | Rule::type_prefix_dcl => {
+                let name = self.read_scoped_name(scope, iter.next().unwrap())?;
+                let literal = iter.next().unwrap().as_str().to_owned();
+                let kind = match pair.as_rule() {
+                    Rule::type_id_dcl => IdlRepositoryIdKind::TypeId(literal),
+                    _ => IdlRepositoryIdKind::TypePrefix(literal),
+                };
+                self.repository_id_dcls.push(IdlRepositoryIdDcl {
+                    scope: scope.clone(),
+                    name,
+                    kind,
+                });
+                Ok(())
+            }
+            // other_directive = @{ "#" ~ (!NEWLINE ~ ANY)* }
+            Rule::other_directive => {
+                self.read_directive(scope, pair.as_str());
+                Ok(())
+            }
             // anything else
             _ => {
                 for p in iter {
@@ -983,22 +1808,13 @@ impl<'i> Context<'i> {
             }
         }
     }
-
-    // enum_dcl = { "enum" ~ identifier ~ "{" ~ enumerator ~ ("," ~ enumerator)* ~ ","? ~ "}" }
-    // enumerator = { identifier }
 }
 
-/// Provided w/ an object that supports writing, an IDL Loader, and an OMG Gen Config,
-/// generate Rust Types for the requested OMG IDL files.
-///
-/// @param out: An object that supports writing
-/// @param loader: Library Object to read IDL
-/// @param config: Library config
-fn generate_with_loader<W: Write, L: IdlLoader>(
-    out: &mut W,
+/// Parse the requested IDL file and resolve its annotations
+fn parse_with_loader<'i, L: IdlLoader>(
     loader: &mut L,
-    config: &Configuration,
-) -> Result<(), IdlError<Rule>> {
+    config: &'i Configuration,
+) -> Result<Context<'i>, IdlError<Rule>> {
     let mut ctx = Context::new(config);
 
     let idl_file = config.idl_file.clone();
@@ -1013,13 +1829,160 @@ fn generate_with_loader<W: Write, L: IdlLoader>(
         let _ = ctx.process::<L>(&mut scope, loader, p);
     }
 
+    AnnotationResolver::new(
+        &ctx.root_module,
+        &ctx.annotation_dcls,
+        &config.annotation_mappings,
+        config.verbose,
+    )
+    .resolve_module(&mut ctx.root_module, &mut Scope::new())
+    .map_err(IdlError::AnnotationError)?;
+
+    ctx.root_module
+        .check_naming(config)
+        .map_err(IdlError::NamingCollision)?;
+    Ok(ctx)
+}
+
+/// Provided w/ an object that supports writing, an IDL Loader, and an OMG Gen Config,
+/// generate Rust Types for the requested OMG IDL files.
+///
+/// @param out: An object that supports writing
+/// @param loader: Library Object to read IDL
+/// @param config: Library config
+fn generate_with_loader<W: Write, L: IdlLoader>(
+    out: &mut W,
+    loader: &mut L,
+    config: &Configuration,
+) -> Result<(), IdlError<Rule>> {
+    let ctx = parse_with_loader(loader, config)?;
+
+    let extensibility =
+        extensibility::analyze(&ctx.root_module).map_err(IdlError::ExtensibilityError)?;
+    // relies on the extensibility analysis rejecting inheritance cycles
+    let member_ids = member_id::analyze(&ctx.root_module).map_err(IdlError::MemberIdError)?;
+    let newtypes = newtype::analyze(&ctx.root_module, config).map_err(IdlError::NewtypeError)?;
+    let layouts = layout::analyze(&ctx.root_module, config, &newtypes);
+    let derives = derive::analyze(&ctx.root_module, config, &layouts, &newtypes);
+    let bytes =
+        bytes::analyze(&ctx.root_module, config, &newtypes).map_err(IdlError::ByteMappingError)?;
+    let keys = key::analyze(
+        &ctx.root_module,
+        &ctx.pragmas,
+        config,
+        &derives,
+        &newtypes,
+        &bytes,
+    )
+    .map_err(IdlError::KeyError)?;
+    let repository_ids = repository_id::analyze(
+        &ctx.root_module,
+        &ctx.pragmas,
+        &ctx.type_prefixes,
+        &ctx.repository_id_dcls,
+    )
+    .map_err(IdlError::RepositoryIdError)?;
+    let type_objects = type_object::analyze(
+        &ctx.root_module,
+        config,
+        &extensibility,
+        &member_ids,
+        &newtypes,
+    )
+    .map_err(IdlError::TypeObjectError)?;
+    let parameter_lists = parameter_list::analyze(&ctx.root_module, &config.parameter_lists)
+        .map_err(IdlError::ParameterListError)?;
+    let bounds = bounds::analyze(
+        &ctx.root_module,
+        &extensibility,
+        &member_ids,
+        &parameter_lists,
+    )
+    .map_err(IdlError::BoundError)?;
+    let views = view::analyze(
+        &ctx.root_module,
+        &ctx.pragmas,
+        config,
+        &extensibility,
+        &member_ids,
+        &parameter_lists,
+        &newtypes,
+    )
+    .map_err(IdlError::ViewError)?;
+
     let mut env = minijinja::Environment::new();
     minijinja_embed::load_templates!(&mut env);
-    let root_module_text = ctx.root_module.render(&env, 0)?;
+    env.add_global("core_crate", config.core_crate());
+    env.add_global("serde_derive", config.serde == SerdeSupport::Always);
+    env.add_global(
+        "serde_cfg_derive",
+        match config.serde {
+            SerdeSupport::Feature(ref feature) => format!(
+                "#[cfg_attr(feature = \"{feature}\", derive(serde_derive::Serialize, serde_derive::Deserialize))]"
+            ),
+            _ => String::new(),
+        },
+    );
+    let types = TypeIndex::new(&ctx.root_module);
+    let constants = ConstIndex::new(&ctx.root_module);
+    let render_ctx = RenderContext {
+        env: &env,
+        config,
+        types: &types,
+        constants: &constants,
+        keys: &keys,
+        extensibility: &extensibility,
+        member_ids: &member_ids,
+        parameter_lists: &parameter_lists,
+        bounds: &bounds,
+        views: &views,
+        repository_ids: &repository_ids,
+        type_objects: &type_objects,
+        layouts: &layouts,
+        derives: &derives,
+        newtypes: &newtypes,
+        bytes: &bytes,
+    };
+    let root_module_text = ctx.root_module.render(&render_ctx, &mut Scope::new(), 0)?;
 
     Ok(write!(out, "{root_module_text}")?)
 }
 
+/// Write a C header declaring the `#[repr(C)]` layouts of all types with a fixed size,
+/// types without one are listed in comments with the reason.
+fn generate_c_header_with_loader<W: Write, L: IdlLoader>(
+    out: &mut W,
+    loader: &mut L,
+    config: &Configuration,
+) -> Result<(), IdlError<Rule>> {
+    let ctx = parse_with_loader(loader, config)?;
+    let newtypes = newtype::analyze(&ctx.root_module, config).map_err(IdlError::NewtypeError)?;
+    let layouts = layout::analyze(&ctx.root_module, config, &newtypes);
+
+    let mut env = minijinja::Environment::new();
+    minijinja_embed::load_templates!(&mut env);
+    let stem = config
+        .idl_file
+        .file_stem()
+        .map(|stem| stem.to_string_lossy())
+        .unwrap_or_default();
+    let guard = stem
+        .chars()
+        .map(|c| match c.is_ascii_alphanumeric() {
+            true => c.to_ascii_uppercase(),
+            false => '_',
+        })
+        .collect::<String>();
+    let header = env
+        .get_template("c_header.j2")?
+        .render(minijinja::context! {
+            idl_file => config.idl_file.display().to_string(),
+            guard => format!("{guard}_H"),
+            decls => layouts.decls(),
+        })?;
+    Ok(writeln!(out, "{header}")?)
+}
+
 /// Object used to input the request IDL file into the library.
 #[derive(Debug, Clone, Default)]
 struct Loader {
@@ -1061,3 +2024,16 @@ pub fn generate_with_search_path<W: Write>(
     minijinja_embed::load_templates!(&mut env);
     generate_with_loader(out, &mut loader, config)
 }
+
+/// Provided w/ an object that supports writing and a OMG Gen Config generate a C header
+/// with the layouts of the Rust types generated with `Configuration::with_repr_c`.
+///
+/// @param out: An object that supports writing
+/// @param config: Library config
+pub fn generate_c_header_with_search_path<W: Write>(
+    out: &mut W,
+    config: &Configuration,
+) -> Result<(), IdlError<Rule>> {
+    let mut loader = Loader::new(&config.search_path);
+    generate_c_header_with_loader(out, &mut loader, config)
+}
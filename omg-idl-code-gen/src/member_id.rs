@@ -0,0 +1,227 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+use crate::{ast::*, index::TypeIndex, naming::to_screaming_snake_case};
+use md5::{Digest, Md5};
+use serde_derive::Serialize;
+use std::collections::{HashMap, HashSet};
+
+/// Member IDs have 28 bits, the remaining bits of an EMHEADER carry flags
+const MEMBER_ID_MASK: u32 = 0x0FFF_FFFF;
+
+/// Member ID of the discriminator of a union
+const DISCRIMINATOR_ID: u32 = 0;
+
+/// How members without `@id` or `@hashid` are numbered, selected via `@autoid`
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+enum AutoId {
+    /// One more than the member before, the first member is 0
+    #[default]
+    Sequential,
+    /// Hash of the member name
+    Hash,
+}
+
+impl AutoId {
+    /// Kind given by an `@autoid` annotation, `None` without annotation
+    fn from_annotations(annotations: &IdlAnnotations) -> Option<Self> {
+        annotations
+            .get("autoid")
+            .map(|annotation| match annotation.param("value") {
+                Some(IdlConstValue::Enumerator(value)) if value == "SEQUENTIAL" => {
+                    AutoId::Sequential
+                }
+                _ => AutoId::Hash,
+            })
+    }
+}
+
+/// Associated constant holding the member ID of `member`, i.e. `RADIUS_MEMBER_ID`
+pub fn member_id_const(member: &str) -> String {
+    format!("{}_MEMBER_ID", to_screaming_snake_case(member))
+}
+
+/// Member ID of a name as computed by DDS-XTypes: the first four bytes of the MD5 hash
+/// of the name, little endian, limited to 28 bits
+pub fn hash_id(name: &str) -> u32 {
+    let digest = Md5::digest(name.as_bytes());
+    u32::from_le_bytes([digest[0], digest[1], digest[2], digest[3]]) & MEMBER_ID_MASK
+}
+
+/// Data storage to align with Jinja (member_ids.j2)
+#[derive(Clone, Debug, Serialize)]
+pub struct IdlMemberId {
+    pub idl_name: String,
+    /// Associated constant holding the ID, i.e. `RADIUS_MEMBER_ID`
+    pub const_name: String,
+    pub id: u32,
+}
+
+/// The member IDs of all structs and unions
+#[derive(Debug, Default)]
+pub struct MemberIdSupport {
+    ids: HashMap<Vec<String>, Vec<IdlMemberId>>,
+    /// Structs and unions numbering their members by hash
+    hashed: HashSet<Vec<String>>,
+}
+
+impl MemberIdSupport {
+    /// IDs of the members declared by the type with the fully qualified `name`, members
+    /// of a base struct are not included
+    pub fn ids(&self, name: &[String]) -> &[IdlMemberId] {
+        self.ids.get(name).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Whether the struct or union with the fully qualified `name` is `@autoid(HASH)`
+    pub fn is_hashed(&self, name: &[String]) -> bool {
+        self.hashed.contains(name)
+    }
+}
+
+struct MemberIdAnalysis<'a> {
+    types: TypeIndex<'a>,
+    /// The `@autoid` kind of every type, given on the type or an enclosing module
+    autoids: HashMap<Vec<String>, AutoId>,
+    /// IDs of all members of a struct including the members of its bases
+    all_ids: HashMap<Vec<String>, Vec<(String, u32)>>,
+    support: MemberIdSupport,
+}
+
+impl MemberIdAnalysis<'_> {
+    fn collect_autoids(&mut self, module: &IdlModule, scope: &mut Vec<String>, inherited: AutoId) {
+        let inherited = AutoId::from_annotations(&module.annotations).unwrap_or(inherited);
+        for (id, typ) in module.types.iter() {
+            let mut name = scope.clone();
+            name.push(id.clone());
+            let autoid = AutoId::from_annotations(&typ.1).unwrap_or(inherited);
+            self.autoids.insert(name, autoid);
+        }
+        for (id, submodule) in module.modules.iter() {
+            scope.push(id.clone());
+            self.collect_autoids(submodule, scope, inherited);
+            scope.pop();
+        }
+    }
+
+    /// IDs of all members of the struct or union `name`, the bases of structs are
+    /// analyzed first. Inheritance cycles are already rejected by the extensibility
+    /// analysis.
+    fn analyze(&mut self, name: &[String]) -> Result<Vec<(String, u32)>, String> {
+        if let Some(ids) = self.all_ids.get(name) {
+            return Ok(ids.clone());
+        }
+        let typ = self
+            .types
+            .resolve(&IdlScopedName(name.to_vec(), true), &[])
+            .ok_or_else(|| format!("unknown type {}", name.join("::")))?;
+        let dcl = typ.dcl.clone();
+        let scope = &name[..name.len() - 1];
+        let autoid = self.autoids.get(name).copied().unwrap_or_default();
+        let (location, mut ids, members) = match dcl.0 {
+            IdlTypeDclKind::StructDcl(ref id, ref base, ref members) => {
+                let inherited = match base {
+                    Some(base) => {
+                        let (spec, base_scope) = self
+                            .types
+                            .unalias(&IdlTypeSpec::ScopedName(base.clone()), scope);
+                        let base_name = match spec {
+                            IdlTypeSpec::ScopedName(ref base) => self
+                                .types
+                                .resolve(base, &base_scope)
+                                .map(|typ| typ.name.to_vec()),
+                            _ => None,
+                        }
+                        .ok_or_else(|| format!("unknown base {base} of struct {id}"))?;
+                        self.analyze(&base_name)?
+                    }
+                    None => Vec::new(),
+                };
+                let members = members
+                    .iter()
+                    .map(|member| (member.id.clone(), member.annotations.clone()))
+                    .collect::<Vec<_>>();
+                (format!("struct {id}"), inherited, members)
+            }
+            IdlTypeDclKind::UnionDcl(ref id, _, ref switch_cases) => {
+                let members = switch_cases
+                    .iter()
+                    .map(|case| {
+                        let element = &case.elem_spec;
+                        (element.id.clone(), element.annotations.clone())
+                    })
+                    .collect::<Vec<_>>();
+                let discriminator = vec![("discriminator".to_owned(), DISCRIMINATOR_ID)];
+                (format!("union {id}"), discriminator, members)
+            }
+            _ => return Ok(Vec::new()),
+        };
+
+        let mut next = ids.last().map(|(_, id)| id + 1).unwrap_or_default();
+        let mut own = Vec::new();
+        for (member, annotations) in members {
+            let member_location = format!("member {member} of {location}");
+            let id = match (annotations.get("id"), annotations.get("hashid")) {
+                (Some(_), Some(_)) => {
+                    return Err(format!("{member_location} has both @id and @hashid"))
+                }
+                (Some(annotation), None) => match annotation.param("value") {
+                    Some(IdlConstValue::Integer(id)) if *id <= MEMBER_ID_MASK.into() => *id as u32,
+                    _ => {
+                        return Err(format!(
+                            "the @id of {member_location} exceeds {MEMBER_ID_MASK:#x}"
+                        ))
+                    }
+                },
+                (None, Some(annotation)) => match annotation.param("value") {
+                    Some(IdlConstValue::String(hashed)) if !hashed.is_empty() => hash_id(hashed),
+                    _ => hash_id(&member),
+                },
+                (None, None) if autoid == AutoId::Hash => hash_id(&member),
+                (None, None) if next > MEMBER_ID_MASK => {
+                    return Err(format!("{member_location} exceeds the member IDs"))
+                }
+                (None, None) => next,
+            };
+            if let Some((other, _)) = ids.iter().find(|(_, other)| *other == id) {
+                return Err(format!(
+                    "{member_location} has the member ID {id} of {other}"
+                ));
+            }
+            next = id + 1;
+            ids.push((member.clone(), id));
+            own.push(IdlMemberId {
+                const_name: member_id_const(&member),
+                idl_name: member,
+                id,
+            });
+        }
+        if autoid == AutoId::Hash {
+            self.support.hashed.insert(name.to_vec());
+        }
+        self.support.ids.insert(name.to_vec(), own);
+        self.all_ids.insert(name.to_vec(), ids.clone());
+        Ok(ids)
+    }
+}
+
+/// Compute the member IDs of all struct members and union branches from `@id`,
+/// `@hashid` and `@autoid`, members sharing an ID are rejected.
+pub fn analyze(root_module: &IdlModule) -> Result<MemberIdSupport, String> {
+    let mut analysis = MemberIdAnalysis {
+        types: TypeIndex::new(root_module),
+        autoids: HashMap::new(),
+        all_ids: HashMap::new(),
+        support: MemberIdSupport::default(),
+    };
+    analysis.collect_autoids(root_module, &mut Vec::new(), AutoId::default());
+    let names = analysis
+        .types
+        .iter()
+        .map(|typ| typ.name.to_vec())
+        .collect::<Vec<_>>();
+    for name in names {
+        analysis.analyze(&name)?;
+    }
+    Ok(analysis.support)
+}
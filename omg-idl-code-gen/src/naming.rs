@@ -0,0 +1,85 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+/// Rust keywords that cannot be used as plain identifiers
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while", "abstract", "become", "box", "do", "final", "macro",
+    "override", "priv", "try", "typeof", "unsized", "virtual", "yield",
+];
+
+/// Split an IDL identifier into its words. Words are separated by underscores,
+/// lower-to-upper case transitions and the end of an acronym, i.e.
+/// "GUID_t" => ["GUID", "t"], "entityKey" => ["entity", "Key"],
+/// "HTTPServer" => ["HTTP", "Server"]. Digits stick to the preceding word.
+fn split_words(id: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    for part in id.split('_').filter(|part| !part.is_empty()) {
+        let chars: Vec<char> = part.chars().collect();
+        let mut word = String::new();
+        for (idx, &c) in chars.iter().enumerate() {
+            if !word.is_empty() && c.is_ascii_uppercase() {
+                let prev = chars[idx - 1];
+                let next_is_lower = chars.get(idx + 1).is_some_and(|n| n.is_ascii_lowercase());
+                if prev.is_ascii_lowercase()
+                    || prev.is_ascii_digit()
+                    || (prev.is_ascii_uppercase() && next_is_lower)
+                {
+                    words.push(std::mem::take(&mut word));
+                }
+            }
+            word.push(c);
+        }
+        if !word.is_empty() {
+            words.push(word);
+        }
+    }
+    words
+}
+
+/// Make sure the converted identifier is usable as a Rust identifier.
+fn escape_keyword(id: String) -> String {
+    if RUST_KEYWORDS.contains(&id.as_str()) {
+        format!("{id}_")
+    } else {
+        id
+    }
+}
+
+/// Convert an IDL identifier into snake_case, i.e. "entityKey" => "entity_key"
+pub fn to_snake_case(id: &str) -> String {
+    let words = split_words(id)
+        .iter()
+        .map(|word| word.to_ascii_lowercase())
+        .collect::<Vec<_>>();
+    escape_keyword(words.join("_"))
+}
+
+/// Convert an IDL identifier into UpperCamelCase, i.e. "GUID_t" => "GuidT"
+pub fn to_upper_camel_case(id: &str) -> String {
+    let converted = split_words(id)
+        .iter()
+        .map(|word| {
+            let lower = word.to_ascii_lowercase();
+            let mut chars = lower.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect::<String>();
+    escape_keyword(converted)
+}
+
+/// Convert an IDL identifier into SCREAMING_SNAKE_CASE, i.e. "maxLen" => "MAX_LEN"
+pub fn to_screaming_snake_case(id: &str) -> String {
+    split_words(id)
+        .iter()
+        .map(|word| word.to_ascii_uppercase())
+        .collect::<Vec<_>>()
+        .join("_")
+}
@@ -0,0 +1,112 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+use crate::{
+    ast::*,
+    index::{IndexedType, TypeIndex},
+    Configuration,
+};
+use std::collections::HashSet;
+
+/// Typedefs generated as newtype structs instead of type aliases, by their fully
+/// qualified name
+#[derive(Debug, Default)]
+pub struct NewtypeSupport {
+    newtypes: HashSet<Vec<String>>,
+}
+
+impl NewtypeSupport {
+    /// Whether the typedef with the fully qualified `name` is generated as newtype
+    pub fn is_newtype(&self, name: &[String]) -> bool {
+        self.newtypes.contains(name)
+    }
+
+    /// The newtypes a type refers to, directly or via other typedefs, the outermost
+    /// first. Values of the type are wrapped by all of them.
+    pub fn wrappers<'t>(
+        &self,
+        types: &'t TypeIndex,
+        spec: &IdlTypeSpec,
+        scope: &[String],
+    ) -> Vec<IndexedType<'t>> {
+        types
+            .aliases(spec, scope)
+            .into_iter()
+            .filter(|alias| self.is_newtype(alias.name))
+            .collect()
+    }
+
+    /// Follow typedefs until the type is not an alias anymore or names a newtype,
+    /// returns the type and the scope its names are relative to. Unlike
+    /// `TypeIndex::unalias` the result has the Rust type of the values.
+    pub fn unalias(
+        &self,
+        types: &TypeIndex,
+        spec: &IdlTypeSpec,
+        scope: &[String],
+    ) -> (IdlTypeSpec, Vec<String>) {
+        let aliases = types.aliases(spec, scope);
+        match aliases.iter().position(|alias| self.is_newtype(alias.name)) {
+            Some(0) => (spec.clone(), scope.to_vec()),
+            Some(index) => match aliases[index - 1].dcl.0 {
+                IdlTypeDclKind::TypeDcl(_, ref aliased) => {
+                    (aliased.clone(), aliases[index - 1].scope().to_vec())
+                }
+                _ => unreachable!("aliases are typedefs"),
+            },
+            None => types.unalias(spec, scope),
+        }
+    }
+}
+
+/// Select the typedefs generated as newtypes, all of them or the ones configured by
+/// name. Configured names must refer to typedefs. Typedefs naming the base of a struct
+/// stay aliases, the derived struct embeds the base itself.
+pub fn analyze(root_module: &IdlModule, config: &Configuration) -> Result<NewtypeSupport, String> {
+    let types = TypeIndex::new(root_module);
+    let bases = types
+        .iter()
+        .filter_map(|typ| match typ.dcl.0 {
+            IdlTypeDclKind::StructDcl(_, Some(ref base), _) => {
+                Some(types.aliases(&IdlTypeSpec::ScopedName(base.clone()), typ.scope()))
+            }
+            _ => None,
+        })
+        .flatten()
+        .map(|alias| alias.name.to_vec())
+        .collect::<HashSet<_>>();
+    let mut support = NewtypeSupport::default();
+    if config.newtype_typedefs {
+        support.newtypes.extend(
+            types
+                .iter()
+                .filter(|typ| matches!(typ.dcl.0, IdlTypeDclKind::TypeDcl(..)))
+                .map(|typ| typ.name.to_vec())
+                .filter(|name| !bases.contains(name)),
+        );
+    }
+    for type_name in config.newtypes.iter() {
+        let name = type_name
+            .trim_start_matches("::")
+            .split("::")
+            .map(str::to_owned)
+            .collect();
+        match types.resolve(&IdlScopedName(name, true), &[]) {
+            Some(typ) if bases.contains(typ.name) => {
+                return Err(format!("{type_name} is the base of a struct"))
+            }
+            Some(
+                typ @ IndexedType {
+                    dcl: IdlTypeDcl(IdlTypeDclKind::TypeDcl(..), _),
+                    ..
+                },
+            ) => {
+                support.newtypes.insert(typ.name.to_vec());
+            }
+            Some(_) => return Err(format!("{type_name} is not a typedef")),
+            None => return Err(format!("unknown type {type_name}")),
+        }
+    }
+    Ok(support)
+}
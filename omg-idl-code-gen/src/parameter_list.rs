@@ -0,0 +1,256 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+use crate::{
+    ast::*,
+    index::{ConstIndex, IndexedType, TypeIndex},
+};
+use serde_derive::Deserialize;
+use std::collections::{HashMap, HashSet};
+
+/// Parameter IDs reserved by RTPS for padding and the end of a parameter list
+const PID_PAD: u16 = 0x0000;
+const PID_SENTINEL: u16 = 0x0001;
+
+/// ID of a parameter in an RTPS parameter list (PL_CDR), either a number or the name of
+/// an IDL constant, i.e. `PID_PARTICIPANT_GUID` or `RTPS::PID_USER_DATA`. Relative
+/// constant names are searched from the scope of the configured type outwards.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum ParameterId {
+    Value(u16),
+    Constant(String),
+}
+
+/// The structs encoded as RTPS parameter lists and the unions encoded as a single
+/// parameter, selected by the configuration
+#[derive(Debug, Default)]
+pub struct ParameterListSupport {
+    /// Parameter IDs of the members a struct declares, members of a base are not included
+    lists: HashMap<Vec<String>, Vec<(String, u16)>>,
+    parameters: HashSet<Vec<String>>,
+}
+
+impl ParameterListSupport {
+    /// Parameter IDs of the members declared by the struct with the fully qualified
+    /// `name`, `None` if the struct is not a parameter list
+    pub fn ids(&self, name: &[String]) -> Option<&[(String, u16)]> {
+        self.lists.get(name).map(Vec::as_slice)
+    }
+
+    /// Whether the union with the fully qualified `name` is encoded as parameter
+    pub fn is_parameter(&self, name: &[String]) -> bool {
+        self.parameters.contains(name)
+    }
+}
+
+struct ParameterListAnalysis<'a> {
+    types: TypeIndex<'a>,
+    constants: ConstIndex,
+    /// Configured parameter IDs by the fully qualified name of the type
+    mappings: HashMap<Vec<String>, &'a HashMap<String, ParameterId>>,
+    /// Parameter IDs of all members of a struct including the members of its bases
+    all_ids: HashMap<Vec<String>, Vec<(String, u16)>>,
+    support: ParameterListSupport,
+}
+
+impl ParameterListAnalysis<'_> {
+    /// Fully qualified name of a configured type, `::` separated
+    fn resolve(&self, type_name: &str) -> Result<Vec<String>, String> {
+        let name = type_name
+            .trim_start_matches("::")
+            .split("::")
+            .map(str::to_owned)
+            .collect();
+        self.types
+            .resolve(&IdlScopedName(name, true), &[])
+            .map(|typ| typ.name.to_vec())
+            .ok_or_else(|| format!("unknown type {type_name}"))
+    }
+
+    /// Value of a parameter ID, constants are evaluated in the scope of the type
+    fn value(&self, id: &ParameterId, scope: &[String]) -> Result<u16, String> {
+        let value = match id {
+            ParameterId::Value(value) => *value,
+            ParameterId::Constant(constant) => {
+                let name = IdlScopedName(
+                    constant
+                        .trim_start_matches("::")
+                        .split("::")
+                        .map(str::to_owned)
+                        .collect(),
+                    constant.starts_with("::"),
+                );
+                self.integer(&IdlValueExpr::ScopedName(name), scope)?
+            }
+        };
+        match value {
+            PID_PAD | PID_SENTINEL => Err(format!("parameter ID {value:#06x} is reserved")),
+            value => Ok(value),
+        }
+    }
+
+    /// Value of a constant expression as parameter ID
+    fn integer(&self, expr: &IdlValueExpr, scope: &[String]) -> Result<u16, String> {
+        match self.constants.evaluate(expr, scope)? {
+            IdlConstValue::Integer(value) => u16::try_from(value)
+                .map_err(|_| format!("{expr} = {value} exceeds the parameter IDs")),
+            value => Err(format!("{expr} = {value} is not a parameter ID")),
+        }
+    }
+
+    /// Parameter IDs of all members of the struct `name`, bases are analyzed first.
+    /// Inheritance cycles are already rejected by the extensibility analysis.
+    fn analyze_struct(&mut self, name: &[String]) -> Result<Vec<(String, u16)>, String> {
+        if let Some(ids) = self.all_ids.get(name) {
+            return Ok(ids.clone());
+        }
+        let type_name = name.join("::");
+        let Some(mapping) = self.mappings.get(name).copied() else {
+            return Err(format!("struct {type_name} is not a parameter list"));
+        };
+        let typ = self
+            .types
+            .resolve(&IdlScopedName(name.to_vec(), true), &[])
+            .ok_or_else(|| format!("unknown type {type_name}"))?;
+        let scope = typ.scope().to_vec();
+        let IdlTypeDclKind::StructDcl(_, ref base, ref members) = typ.dcl.0 else {
+            return Err(format!("{type_name} is not a struct"));
+        };
+        let members = members
+            .iter()
+            .map(|member| member.id.clone())
+            .collect::<Vec<_>>();
+        let base = match base {
+            Some(base) => {
+                let (spec, base_scope) = self
+                    .types
+                    .unalias(&IdlTypeSpec::ScopedName(base.clone()), &scope);
+                match spec {
+                    IdlTypeSpec::ScopedName(ref base) => self
+                        .types
+                        .resolve(base, &base_scope)
+                        .map(|typ| typ.name.to_vec()),
+                    _ => None,
+                }
+                .ok_or_else(|| format!("unknown base {base} of struct {type_name}"))
+                .map(Some)?
+            }
+            None => None,
+        };
+
+        if let Some(unknown) = mapping.keys().find(|member| !members.contains(member)) {
+            return Err(format!("struct {type_name} has no member {unknown}"));
+        }
+        let mut ids = match base {
+            Some(base) => self.analyze_struct(&base).map_err(|e| {
+                format!("the base of parameter list {type_name} must be one as well: {e}")
+            })?,
+            None => Vec::new(),
+        };
+        let mut own = Vec::new();
+        for member in members {
+            let location = format!("member {member} of struct {type_name}");
+            let id = mapping
+                .get(&member)
+                .ok_or_else(|| format!("{location} has no parameter ID"))?;
+            let id = self
+                .value(id, &scope)
+                .map_err(|e| format!("{location}: {e}"))?;
+            if let Some((other, _)) = ids.iter().find(|(_, other)| *other == id) {
+                return Err(format!(
+                    "{location} has the parameter ID {id:#06x} of {other}"
+                ));
+            }
+            ids.push((member.clone(), id));
+            own.push((member, id));
+        }
+        self.support.lists.insert(name.to_vec(), own);
+        self.all_ids.insert(name.to_vec(), ids.clone());
+        Ok(ids)
+    }
+
+    /// Check that the labels of the union `name` are parameter IDs, the discriminator
+    /// is the ID of the parameter
+    fn analyze_union(&mut self, name: &[String]) -> Result<(), String> {
+        let type_name = name.join("::");
+        let typ = self
+            .types
+            .resolve(&IdlScopedName(name.to_vec(), true), &[])
+            .ok_or_else(|| format!("unknown type {type_name}"))?;
+        let scope = typ.scope().to_vec();
+        let IdlTypeDclKind::UnionDcl(_, _, ref switch_cases) = typ.dcl.0 else {
+            return Err(format!("{type_name} is not a union"));
+        };
+        if self.mappings.get(name).is_some_and(|ids| !ids.is_empty()) {
+            return Err(format!(
+                "union {type_name} takes the parameter IDs from its labels"
+            ));
+        }
+        let mut ids = Vec::new();
+        for label in switch_cases.iter().flat_map(|case| case.labels.iter()) {
+            let IdlSwitchLabel::Label(expr) = label else {
+                return Err(format!(
+                    "union {type_name} has a default branch, its parameter ID is unknown"
+                ));
+            };
+            let id = self
+                .integer(expr, &scope)
+                .map_err(|e| format!("union {type_name}: {e}"))?;
+            if matches!(id, PID_PAD | PID_SENTINEL) || ids.contains(&id) {
+                return Err(format!(
+                    "union {type_name}: label {expr} = {id:#06x} is reserved or repeated"
+                ));
+            }
+            ids.push(id);
+        }
+        self.support.parameters.insert(name.to_vec());
+        Ok(())
+    }
+}
+
+/// Resolve the parameter IDs of the structs and unions configured as RTPS parameter
+/// lists. Every member of a struct needs an ID, unions use their labels.
+pub fn analyze(
+    root_module: &IdlModule,
+    config: &HashMap<String, HashMap<String, ParameterId>>,
+) -> Result<ParameterListSupport, String> {
+    let mut analysis = ParameterListAnalysis {
+        types: TypeIndex::new(root_module),
+        constants: ConstIndex::new(root_module),
+        mappings: HashMap::new(),
+        all_ids: HashMap::new(),
+        support: ParameterListSupport::default(),
+    };
+    let mut type_names = config.keys().collect::<Vec<_>>();
+    type_names.sort();
+    let mut names = Vec::new();
+    for type_name in type_names {
+        let name = analysis.resolve(type_name)?;
+        analysis.mappings.insert(name.clone(), &config[type_name]);
+        names.push((type_name, name));
+    }
+    for (type_name, name) in names {
+        let is_struct = match analysis
+            .types
+            .resolve(&IdlScopedName(name.clone(), true), &[])
+        {
+            Some(IndexedType {
+                dcl: IdlTypeDcl(IdlTypeDclKind::StructDcl(..), _),
+                ..
+            }) => true,
+            Some(IndexedType {
+                dcl: IdlTypeDcl(IdlTypeDclKind::UnionDcl(..), _),
+                ..
+            }) => false,
+            _ => return Err(format!("{type_name} is neither a struct nor a union")),
+        };
+        if is_struct {
+            analysis.analyze_struct(&name)?;
+        } else {
+            analysis.analyze_union(&name)?;
+        }
+    }
+    Ok(analysis.support)
+}
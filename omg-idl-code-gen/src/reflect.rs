@@ -0,0 +1,168 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+use crate::{ast::*, index::IndexedType, member_id::IdlMemberId};
+use serde_derive::Serialize;
+
+/// Data storage to align with Jinja (reflect_struct.j2, reflect_union.j2), an
+/// `omg_idl_rt::MemberDescriptor`
+#[derive(Debug, Serialize)]
+pub struct IdlMemberDescriptor {
+    pub idl_name: String,
+    pub id: u32,
+    /// Expression of the `omg_idl_rt::TypeDescriptor`
+    pub descriptor: String,
+    pub key: bool,
+    pub optional: bool,
+}
+
+/// `omg_idl_rt::TypeDescriptor` expression of a type declared in `scope`. Typedefs are
+/// described by the type they alias unless they are newtypes.
+pub fn descriptor(
+    ctx: &RenderContext,
+    spec: &IdlTypeSpec,
+    scope: &[String],
+) -> Result<String, String> {
+    let (spec, scope) = ctx.newtypes.unalias(ctx.types, spec, scope);
+    let bound = |bound: &Option<Box<IdlValueExpr>>| -> Result<String, String> {
+        match bound {
+            Some(bound) => Ok(format!(
+                "Some({})",
+                ctx.constants.evaluate_size(bound, &scope)?
+            )),
+            None => Ok("None".to_owned()),
+        }
+    };
+    let descriptor = match spec {
+        IdlTypeSpec::BooleanType => "Boolean".to_owned(),
+        IdlTypeSpec::OctetType => "Octet".to_owned(),
+        IdlTypeSpec::I16Type => "Short".to_owned(),
+        IdlTypeSpec::U16Type => "UShort".to_owned(),
+        IdlTypeSpec::I32Type => "Long".to_owned(),
+        IdlTypeSpec::U32Type => "ULong".to_owned(),
+        IdlTypeSpec::I64Type => "LongLong".to_owned(),
+        IdlTypeSpec::U64Type => "ULongLong".to_owned(),
+        IdlTypeSpec::F32Type => "Float".to_owned(),
+        IdlTypeSpec::F64Type => "Double".to_owned(),
+        IdlTypeSpec::F128Type => "LongDouble".to_owned(),
+        IdlTypeSpec::CharType => "Char".to_owned(),
+        IdlTypeSpec::WideCharType => "WChar".to_owned(),
+        IdlTypeSpec::StringType(ref length) => format!("String({})", bound(length)?),
+        IdlTypeSpec::WideStringType(ref length) => format!("WString({})", bound(length)?),
+        IdlTypeSpec::SequenceType(ref element, ref length) => format!(
+            "Sequence(&{}, {})",
+            descriptor(ctx, element, &scope)?,
+            bound(length)?
+        ),
+        IdlTypeSpec::ArrayType(ref element, ref dims) => format!(
+            "Array(&{}, &[{}])",
+            descriptor(ctx, element, &scope)?,
+            dims.iter()
+                .map(|dim| ctx.constants.evaluate_size(dim, &scope))
+                .map(|dim| dim.map(|dim| dim.to_string()))
+                .collect::<Result<Vec<_>, _>>()?
+                .join(", ")
+        ),
+        IdlTypeSpec::ScopedName(ref name) => {
+            let typ = ctx
+                .types
+                .resolve(name, &scope)
+                .ok_or_else(|| format!("unknown type {name}"))?;
+            format!("Named(\"{}\")", typ.name.join("::"))
+        }
+        IdlTypeSpec::None => return Err("missing type".to_owned()),
+    };
+    Ok(format!("omg_idl_rt::TypeDescriptor::{descriptor}"))
+}
+
+/// Bound of a bounded string or sequence, directly or via typedefs, `None` for other
+/// types. Newtypes check their own bound.
+pub fn length_bound(
+    ctx: &RenderContext,
+    spec: &IdlTypeSpec,
+    scope: &[String],
+) -> Result<Option<usize>, String> {
+    let (spec, scope) = ctx.newtypes.unalias(ctx.types, spec, scope);
+    match spec {
+        IdlTypeSpec::StringType(Some(ref bound))
+        | IdlTypeSpec::WideStringType(Some(ref bound))
+        | IdlTypeSpec::SequenceType(_, Some(ref bound)) => {
+            ctx.constants.evaluate_size(bound, &scope).map(Some)
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Descriptors of the members of the struct with the fully qualified `name`, the members
+/// of its bases first
+pub fn struct_members(
+    ctx: &RenderContext,
+    name: &[String],
+) -> Result<Vec<IdlMemberDescriptor>, String> {
+    let mut chain: Vec<(IndexedType, &Vec<IdlStructMember>)> = Vec::new();
+    let mut next = ctx.types.resolve(&IdlScopedName(name.to_vec(), true), &[]);
+    while let Some(typ) = next {
+        let IdlTypeDclKind::StructDcl(_, ref base, ref members) = typ.dcl.0 else {
+            break;
+        };
+        // structs deriving from each other
+        if chain.iter().any(|(derived, _)| derived.name == typ.name) {
+            break;
+        }
+        chain.push((typ, members));
+        next = base.as_ref().and_then(|base| {
+            match ctx
+                .types
+                .unalias(&IdlTypeSpec::ScopedName(base.clone()), typ.scope())
+            {
+                (IdlTypeSpec::ScopedName(base), scope) => ctx.types.resolve(&base, &scope),
+                _ => None,
+            }
+        });
+    }
+    let mut descriptors = Vec::new();
+    for (typ, members) in chain.into_iter().rev() {
+        let ids = ctx.member_ids.ids(typ.name);
+        for member in members {
+            descriptors.push(IdlMemberDescriptor {
+                idl_name: member.id.clone(),
+                id: member_id(ids, &member.id),
+                descriptor: descriptor(ctx, &member.type_spec, typ.scope())?,
+                key: member.annotations.is_set("key"),
+                optional: member.annotations.is_set("optional"),
+            });
+        }
+    }
+    Ok(descriptors)
+}
+
+/// Descriptors of the elements of the union with the fully qualified `name`
+pub fn union_elements(
+    ctx: &RenderContext,
+    name: &[String],
+    switch_cases: &[IdlSwitchCase],
+) -> Result<Vec<IdlMemberDescriptor>, String> {
+    let ids = ctx.member_ids.ids(name);
+    let scope = &name[..name.len() - 1];
+    switch_cases
+        .iter()
+        .map(|case| {
+            let element = &case.elem_spec;
+            Ok(IdlMemberDescriptor {
+                idl_name: element.id.clone(),
+                id: member_id(ids, &element.id),
+                descriptor: descriptor(ctx, &element.type_spec, scope)?,
+                key: false,
+                optional: element.annotations.is_set("optional"),
+            })
+        })
+        .collect()
+}
+
+fn member_id(ids: &[IdlMemberId], idl_name: &str) -> u32 {
+    ids.iter()
+        .find(|member| member.idl_name == idl_name)
+        .map(|member| member.id)
+        .unwrap_or_default()
+}
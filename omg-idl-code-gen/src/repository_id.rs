@@ -0,0 +1,218 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+use crate::{ast::*, index::TypeIndex};
+use std::collections::HashMap;
+
+/// Pragma giving the repository ID of a definition, i.e. `ID DDS::Foo "IDL:Foo:1.0"`
+const ID_PRAGMA: &str = "ID";
+
+/// Pragma giving the version of the repository ID of a definition, i.e.
+/// `version DDS::Foo 2.1`
+const VERSION_PRAGMA: &str = "version";
+
+/// Version of repository IDs without `#pragma version`
+const DEFAULT_VERSION: &str = "1.0";
+
+/// The repository IDs of all types
+#[derive(Debug, Default)]
+pub struct RepositoryIdSupport {
+    ids: HashMap<Vec<String>, String>,
+}
+
+impl RepositoryIdSupport {
+    /// Repository ID of the type with the fully qualified `name`
+    pub fn id(&self, name: &[String]) -> &str {
+        self.ids.get(name).map(String::as_str).unwrap_or_default()
+    }
+}
+
+struct RepositoryIdAnalysis<'a> {
+    root_module: &'a IdlModule,
+    types: TypeIndex<'a>,
+    /// IDs given via `typeid` or `#pragma ID`
+    ids: HashMap<Vec<String>, String>,
+    /// Prefixes given via `typeprefix`, keyed by the module or type they apply to
+    prefixes: HashMap<Vec<String>, String>,
+    /// Versions given via `#pragma version`
+    versions: HashMap<Vec<String>, String>,
+}
+
+impl RepositoryIdAnalysis<'_> {
+    /// Find the module or type a name refers to, relative names are searched from the
+    /// current scope outwards.
+    fn resolve(&self, name: &IdlScopedName, scope: &[String]) -> Option<Vec<String>> {
+        if let Some(typ) = self.types.resolve(name, scope) {
+            return Some(typ.name.to_vec());
+        }
+        let depths = if name.1 { 0..=0 } else { 0..=scope.len() };
+        depths.rev().find_map(|depth| {
+            let mut qualified = scope[..depth].to_vec();
+            qualified.extend(name.0.iter().cloned());
+            let mut module = self.root_module;
+            for id in &qualified {
+                module = module.modules.get(id)?;
+            }
+            Some(qualified)
+        })
+    }
+
+    /// Record an ID, prefix or version of a definition, declaring it again is only valid
+    /// with the same value
+    fn declare(
+        declarations: &mut HashMap<Vec<String>, String>,
+        name: Vec<String>,
+        value: String,
+        kind: &str,
+    ) -> Result<(), String> {
+        let location = name.join("::");
+        match declarations.insert(name, value.clone()) {
+            Some(previous) if previous != value => Err(format!(
+                "{location} is given the {kind}s {previous} and {value}"
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    fn collect_dcls(&mut self, dcls: &[IdlRepositoryIdDcl]) -> Result<(), String> {
+        for dcl in dcls {
+            let (keyword, literal) = match dcl.kind {
+                IdlRepositoryIdKind::TypeId(ref literal) => ("typeid", literal),
+                IdlRepositoryIdKind::TypePrefix(ref literal) => ("typeprefix", literal),
+            };
+            let location = format!("{keyword} {} {literal}", dcl.name.0.join("::"));
+            let name = self
+                .resolve(&dcl.name, &dcl.scope)
+                .ok_or_else(|| format!("{location} names no module or type"))?;
+            let value = literal_content(literal, '"')?;
+            match dcl.kind {
+                IdlRepositoryIdKind::TypeId(_) => {
+                    Self::declare(&mut self.ids, name, value, "repository ID")?
+                }
+                IdlRepositoryIdKind::TypePrefix(_) => {
+                    Self::declare(&mut self.prefixes, name, value, "prefix")?
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Collect the `#pragma ID` and `#pragma version` directives
+    fn collect_pragmas(&mut self, pragmas: &[IdlPragma]) -> Result<(), String> {
+        for pragma in pragmas {
+            let Some((directive, args)) = pragma.text.split_once(char::is_whitespace) else {
+                continue;
+            };
+            if directive != ID_PRAGMA && directive != VERSION_PRAGMA {
+                continue;
+            }
+            let args = args.trim();
+            let location = format!("#pragma {directive} {args}");
+            let (type_name, value) = args
+                .split_once(char::is_whitespace)
+                .ok_or_else(|| format!("{location} is missing the {directive}"))?;
+            let scoped_name = IdlScopedName(
+                type_name
+                    .trim_start_matches("::")
+                    .split("::")
+                    .map(str::to_owned)
+                    .collect(),
+                type_name.starts_with("::"),
+            );
+            let name = self
+                .resolve(&scoped_name, &pragma.scope)
+                .ok_or_else(|| format!("{location} names no module or type"))?;
+            let value = value.trim();
+            if directive == ID_PRAGMA {
+                let value =
+                    literal_content(value, '"').map_err(|_| format!("{location} is not quoted"))?;
+                Self::declare(&mut self.ids, name, value, "repository ID")?;
+            } else {
+                let valid = value.split_once('.').is_some_and(|(major, minor)| {
+                    major.parse::<u16>().is_ok() && minor.parse::<u16>().is_ok()
+                });
+                if !valid {
+                    return Err(format!("{location} is no <major>.<minor> version"));
+                }
+                Self::declare(&mut self.versions, name, value.to_owned(), "version")?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Repository ID of the type with the fully qualified `name`, `pragma_prefix` is the
+    /// `#pragma prefix` in effect where it is declared
+    fn repository_id(
+        &self,
+        name: &[String],
+        pragma_prefix: Option<&IdlPrefix>,
+    ) -> Result<String, String> {
+        let location = name.join("::");
+        if let Some(id) = self.ids.get(name) {
+            if self.versions.contains_key(name) {
+                return Err(format!("{location} is given a repository ID and a version"));
+            }
+            return Ok(id.clone());
+        }
+        // the innermost typeprefix applies to the scope it names, the names in the ID
+        // start with that scope
+        let type_prefix = (1..=name.len()).rev().find_map(|depth| {
+            self.prefixes
+                .get(&name[..depth])
+                .map(|prefix| (depth, prefix.clone()))
+        });
+        let (start, prefix) = match (type_prefix, pragma_prefix) {
+            (Some((depth, prefix)), Some(pragma)) if depth > pragma.scope.len() => {
+                (depth - 1, prefix)
+            }
+            (_, Some(pragma)) => (
+                pragma.scope.len(),
+                literal_content(&pragma.prefix, '"')
+                    .map_err(|_| format!("#pragma prefix {} is not quoted", pragma.prefix))?,
+            ),
+            (Some((depth, prefix)), None) => (depth - 1, prefix),
+            (None, None) => (0, String::new()),
+        };
+        let mut id = String::from("IDL:");
+        if !prefix.is_empty() {
+            id.push_str(&prefix);
+            id.push('/');
+        }
+        id.push_str(&name[start..].join("/"));
+        id.push(':');
+        id.push_str(
+            self.versions
+                .get(name)
+                .map(String::as_str)
+                .unwrap_or(DEFAULT_VERSION),
+        );
+        Ok(id)
+    }
+}
+
+/// Compute the repository IDs of all types from their names, `typeid`, `typeprefix`,
+/// `#pragma ID`, `#pragma prefix` and `#pragma version`. `pragma_prefixes` holds the
+/// `#pragma prefix` in effect where a type is declared.
+pub fn analyze(
+    root_module: &IdlModule,
+    pragmas: &[IdlPragma],
+    pragma_prefixes: &HashMap<Vec<String>, IdlPrefix>,
+    dcls: &[IdlRepositoryIdDcl],
+) -> Result<RepositoryIdSupport, String> {
+    let mut analysis = RepositoryIdAnalysis {
+        root_module,
+        types: TypeIndex::new(root_module),
+        ids: HashMap::new(),
+        prefixes: HashMap::new(),
+        versions: HashMap::new(),
+    };
+    analysis.collect_dcls(dcls)?;
+    analysis.collect_pragmas(pragmas)?;
+    let mut support = RepositoryIdSupport::default();
+    for typ in analysis.types.iter() {
+        let id = analysis.repository_id(typ.name, pragma_prefixes.get(typ.name))?;
+        support.ids.insert(typ.name.to_vec(), id);
+    }
+    Ok(support)
+}
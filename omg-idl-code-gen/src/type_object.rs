@@ -0,0 +1,1226 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+use crate::{
+    ast::*,
+    cdr::enumerator_values,
+    extensibility::{Extensibility, ExtensibilitySupport},
+    index::{relative_path, ConstIndex, TypeIndex},
+    member_id::MemberIdSupport,
+    newtype::NewtypeSupport,
+    Configuration,
+};
+use md5::{Digest, Md5};
+use std::collections::{HashMap, HashSet};
+
+// TypeKind of primitive types and TypeObjects
+const TK_NONE: u8 = 0x00;
+const TK_BOOLEAN: u8 = 0x01;
+const TK_BYTE: u8 = 0x02;
+const TK_INT16: u8 = 0x03;
+const TK_INT32: u8 = 0x04;
+const TK_INT64: u8 = 0x05;
+const TK_UINT16: u8 = 0x06;
+const TK_UINT32: u8 = 0x07;
+const TK_UINT64: u8 = 0x08;
+const TK_FLOAT32: u8 = 0x09;
+const TK_FLOAT64: u8 = 0x0A;
+const TK_FLOAT128: u8 = 0x0B;
+const TK_CHAR8: u8 = 0x10;
+const TK_CHAR16: u8 = 0x11;
+const TK_ALIAS: u8 = 0x30;
+const TK_ENUM: u8 = 0x40;
+const TK_STRUCTURE: u8 = 0x51;
+const TK_UNION: u8 = 0x52;
+
+// TypeIdentifier kinds of strings, plain collections and recursive types
+const TI_STRING8_SMALL: u8 = 0x70;
+const TI_STRING8_LARGE: u8 = 0x71;
+const TI_STRING16_SMALL: u8 = 0x72;
+const TI_STRING16_LARGE: u8 = 0x73;
+const TI_PLAIN_SEQUENCE_SMALL: u8 = 0x80;
+const TI_PLAIN_SEQUENCE_LARGE: u8 = 0x81;
+const TI_PLAIN_ARRAY_SMALL: u8 = 0x90;
+const TI_PLAIN_ARRAY_LARGE: u8 = 0x91;
+const TI_STRONGLY_CONNECTED_COMPONENT: u8 = 0xB0;
+
+// EquivalenceKind
+const EK_MINIMAL: u8 = 0xF1;
+const EK_COMPLETE: u8 = 0xF2;
+const EK_BOTH: u8 = 0xF3;
+
+// MemberFlag
+const TRY_CONSTRUCT1: u16 = 1 << 0;
+const IS_EXTERNAL: u16 = 1 << 2;
+const IS_OPTIONAL: u16 = 1 << 3;
+const IS_MUST_UNDERSTAND: u16 = 1 << 4;
+const IS_KEY: u16 = 1 << 5;
+const IS_DEFAULT: u16 = 1 << 6;
+const MEMBER_FLAG_MINIMAL_MASK: u16 = 0x003f;
+
+// TypeFlag
+const IS_FINAL: u16 = 1 << 0;
+const IS_APPENDABLE: u16 = 1 << 1;
+const IS_MUTABLE: u16 = 1 << 2;
+const IS_NESTED: u16 = 1 << 3;
+const IS_AUTOID_HASH: u16 = 1 << 4;
+const TYPE_FLAG_MINIMAL_MASK: u16 = 0x0007;
+
+/// Bounds up to this value are described by the small TypeIdentifier variants
+const SMALL_BOUND: u32 = 255;
+
+/// Enumerations without `@bit_bound` are 32 bits wide
+const DEFAULT_BIT_BOUND: u16 = 32;
+
+/// Whether a TypeObject holds the minimal or the complete description of a type
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Equivalence {
+    Minimal,
+    Complete,
+}
+
+impl Equivalence {
+    /// EquivalenceKind
+    fn kind(self) -> u8 {
+        match self {
+            Equivalence::Minimal => EK_MINIMAL,
+            Equivalence::Complete => EK_COMPLETE,
+        }
+    }
+
+    /// Variant of `omg_idl_rt::EquivalenceKind`
+    fn variant(self) -> &'static str {
+        match self {
+            Equivalence::Minimal => "Minimal",
+            Equivalence::Complete => "Complete",
+        }
+    }
+}
+
+/// The first 14 bytes of the MD5 hash of a serialized TypeObject
+fn equivalence_hash(serialized: &[u8]) -> [u8; 14] {
+    let digest = Md5::digest(serialized);
+    let mut hash = [0; 14];
+    hash.copy_from_slice(&digest[..14]);
+    hash
+}
+
+/// The first 4 bytes of the MD5 hash of a member name
+fn name_hash(name: &str) -> [u8; 4] {
+    let digest = Md5::digest(name.as_bytes());
+    [digest[0], digest[1], digest[2], digest[3]]
+}
+
+/// XCDR2 little endian serialization as used for TypeObjects. Alignment is relative to
+/// the start of the TypeObject and at most 4 bytes.
+#[derive(Default)]
+struct Writer(Vec<u8>);
+
+impl Writer {
+    fn align(&mut self, alignment: usize) {
+        while self.0.len() % alignment != 0 {
+            self.0.push(0);
+        }
+    }
+
+    fn octet(&mut self, value: u8) {
+        self.0.push(value);
+    }
+
+    fn boolean(&mut self, value: bool) {
+        self.0.push(u8::from(value));
+    }
+
+    fn bytes(&mut self, value: &[u8]) {
+        self.0.extend_from_slice(value);
+    }
+
+    fn ushort(&mut self, value: u16) {
+        self.align(2);
+        self.bytes(&value.to_le_bytes());
+    }
+
+    fn ulong(&mut self, value: u32) {
+        self.align(4);
+        self.bytes(&value.to_le_bytes());
+    }
+
+    fn long(&mut self, value: i32) {
+        self.align(4);
+        self.bytes(&value.to_le_bytes());
+    }
+
+    /// Length including the terminating NUL, the characters and the NUL
+    fn string(&mut self, value: &str) {
+        self.ulong(value.len() as u32 + 1);
+        self.bytes(value.as_bytes());
+        self.octet(0);
+    }
+
+    /// Appendable types and sequences of non-primitive elements are preceded by a
+    /// DHEADER holding their size in bytes
+    fn delimited(&mut self, write: impl FnOnce(&mut Self)) {
+        self.ulong(0);
+        let start = self.0.len();
+        write(self);
+        let size = (self.0.len() - start) as u32;
+        self.0[start - 4..start].copy_from_slice(&size.to_le_bytes());
+    }
+
+    /// Sequence of non-primitive elements
+    fn sequence<T>(&mut self, elements: &[T], write: impl Fn(&mut Self, &T)) {
+        self.delimited(|writer| {
+            writer.ulong(elements.len() as u32);
+            for element in elements {
+                write(writer, element);
+            }
+        });
+    }
+}
+
+/// DDS-XTypes TypeIdentifier of a type referred to by a TypeObject
+#[derive(Clone, Debug, PartialEq)]
+enum TypeId {
+    None,
+    Primitive(u8),
+    String {
+        wide: bool,
+        bound: u32,
+    },
+    Sequence(Box<TypeId>, u32),
+    Array(Box<TypeId>, Vec<u32>),
+    Hash(Equivalence, [u8; 14]),
+    /// Hash of the component, number of types of the component and position of the type
+    StronglyConnected(Equivalence, [u8; 14], i32, i32),
+}
+
+impl TypeId {
+    /// Kind of the TypeObjects the identifier refers to, `EK_BOTH` if it describes the
+    /// type without TypeObject
+    fn equivalence(&self) -> u8 {
+        match self {
+            TypeId::Sequence(element, _) | TypeId::Array(element, _) => element.equivalence(),
+            TypeId::Hash(equivalence, _) | TypeId::StronglyConnected(equivalence, ..) => {
+                equivalence.kind()
+            }
+            _ => EK_BOTH,
+        }
+    }
+
+    fn write(&self, writer: &mut Writer) {
+        match self {
+            TypeId::None => writer.octet(TK_NONE),
+            TypeId::Primitive(kind) => writer.octet(*kind),
+            TypeId::String { wide, bound } if *bound <= SMALL_BOUND => {
+                writer.octet(if *wide {
+                    TI_STRING16_SMALL
+                } else {
+                    TI_STRING8_SMALL
+                });
+                writer.octet(*bound as u8);
+            }
+            TypeId::String { wide, bound } => {
+                writer.octet(if *wide {
+                    TI_STRING16_LARGE
+                } else {
+                    TI_STRING8_LARGE
+                });
+                writer.ulong(*bound);
+            }
+            TypeId::Sequence(element, bound) => {
+                let small = *bound <= SMALL_BOUND;
+                writer.octet(if small {
+                    TI_PLAIN_SEQUENCE_SMALL
+                } else {
+                    TI_PLAIN_SEQUENCE_LARGE
+                });
+                self.write_collection_header(writer);
+                if small {
+                    writer.octet(*bound as u8);
+                } else {
+                    writer.ulong(*bound);
+                }
+                element.write(writer);
+            }
+            TypeId::Array(element, bounds) => {
+                let small = bounds.iter().all(|bound| *bound <= SMALL_BOUND);
+                writer.octet(if small {
+                    TI_PLAIN_ARRAY_SMALL
+                } else {
+                    TI_PLAIN_ARRAY_LARGE
+                });
+                self.write_collection_header(writer);
+                writer.ulong(bounds.len() as u32);
+                for bound in bounds {
+                    if small {
+                        writer.octet(*bound as u8);
+                    } else {
+                        writer.ulong(*bound);
+                    }
+                }
+                element.write(writer);
+            }
+            TypeId::Hash(equivalence, hash) => {
+                writer.octet(equivalence.kind());
+                writer.bytes(hash);
+            }
+            TypeId::StronglyConnected(equivalence, hash, length, index) => {
+                writer.octet(TI_STRONGLY_CONNECTED_COMPONENT);
+                writer.octet(equivalence.kind());
+                writer.bytes(hash);
+                writer.long(*length);
+                writer.long(*index);
+            }
+        }
+    }
+
+    /// PlainCollectionHeader
+    fn write_collection_header(&self, writer: &mut Writer) {
+        writer.octet(self.equivalence());
+        writer.ushort(TRY_CONSTRUCT1);
+    }
+
+    /// Expression of the `omg_idl_rt::TypeIdentifier`, only types with TypeObjects
+    /// have one
+    fn to_rust(&self) -> String {
+        let hash_array = |hash: &[u8; 14]| {
+            let bytes = hash
+                .iter()
+                .map(|byte| format!("{byte:#04x}"))
+                .collect::<Vec<_>>();
+            format!("[{}]", bytes.join(", "))
+        };
+        match self {
+            TypeId::Hash(equivalence, hash) => format!(
+                "omg_idl_rt::TypeIdentifier::EquivalenceHash(omg_idl_rt::EquivalenceKind::{}, {})",
+                equivalence.variant(),
+                hash_array(hash)
+            ),
+            TypeId::StronglyConnected(equivalence, hash, length, index) => format!(
+                "omg_idl_rt::TypeIdentifier::StronglyConnectedComponent(omg_idl_rt::StronglyConnectedComponentId {{ kind: omg_idl_rt::EquivalenceKind::{}, hash: {}, scc_length: {length}, scc_index: {index} }})",
+                equivalence.variant(),
+                hash_array(hash)
+            ),
+            _ => unreachable!("only constructed types have TypeObjects"),
+        }
+    }
+}
+
+/// Value of an `AnnotationParameterValue`, the bounds of `@range`, `@min` and `@max`
+enum AnnotationValue {
+    Byte(u8),
+    Int16(i16),
+    UInt16(u16),
+    Int32(i32),
+    UInt32(u32),
+    Int64(i64),
+    UInt64(u64),
+    Float32(f32),
+    Float64(f64),
+}
+
+impl AnnotationValue {
+    /// Value of a bound of a member of the primitive type `kind`, other types have no
+    /// representation
+    fn new(kind: u8, value: &IdlConstValue) -> Option<Self> {
+        let integer = match value {
+            IdlConstValue::Integer(value) => Some(*value),
+            _ => None,
+        };
+        let float = match value {
+            IdlConstValue::Integer(value) => Some(*value as f64),
+            IdlConstValue::Float(value) => Some(*value),
+            _ => None,
+        };
+        match kind {
+            TK_BYTE => integer?.try_into().ok().map(AnnotationValue::Byte),
+            TK_INT16 => integer?.try_into().ok().map(AnnotationValue::Int16),
+            TK_UINT16 => integer?.try_into().ok().map(AnnotationValue::UInt16),
+            TK_INT32 => integer?.try_into().ok().map(AnnotationValue::Int32),
+            TK_UINT32 => integer?.try_into().ok().map(AnnotationValue::UInt32),
+            TK_INT64 => integer?.try_into().ok().map(AnnotationValue::Int64),
+            TK_UINT64 => integer?.try_into().ok().map(AnnotationValue::UInt64),
+            TK_FLOAT32 => float.map(|value| AnnotationValue::Float32(value as f32)),
+            TK_FLOAT64 => float.map(AnnotationValue::Float64),
+            _ => None,
+        }
+    }
+
+    fn write(&self, writer: &mut Writer) {
+        match self {
+            AnnotationValue::Byte(value) => {
+                writer.octet(TK_BYTE);
+                writer.octet(*value);
+            }
+            AnnotationValue::Int16(value) => {
+                writer.octet(TK_INT16);
+                writer.align(2);
+                writer.bytes(&value.to_le_bytes());
+            }
+            AnnotationValue::UInt16(value) => {
+                writer.octet(TK_UINT16);
+                writer.ushort(*value);
+            }
+            AnnotationValue::Int32(value) => {
+                writer.octet(TK_INT32);
+                writer.long(*value);
+            }
+            AnnotationValue::UInt32(value) => {
+                writer.octet(TK_UINT32);
+                writer.ulong(*value);
+            }
+            AnnotationValue::Int64(value) => {
+                writer.octet(TK_INT64);
+                writer.align(4);
+                writer.bytes(&value.to_le_bytes());
+            }
+            AnnotationValue::UInt64(value) => {
+                writer.octet(TK_UINT64);
+                writer.align(4);
+                writer.bytes(&value.to_le_bytes());
+            }
+            AnnotationValue::Float32(value) => {
+                writer.octet(TK_FLOAT32);
+                writer.align(4);
+                writer.bytes(&value.to_le_bytes());
+            }
+            AnnotationValue::Float64(value) => {
+                writer.octet(TK_FLOAT64);
+                writer.align(4);
+                writer.bytes(&value.to_le_bytes());
+            }
+        }
+    }
+}
+
+/// `AppliedBuiltinMemberAnnotations` of a member
+#[derive(Default)]
+struct BuiltinAnnotations {
+    unit: Option<String>,
+    min: Option<AnnotationValue>,
+    max: Option<AnnotationValue>,
+    hash_id: Option<String>,
+}
+
+impl BuiltinAnnotations {
+    fn is_empty(&self) -> bool {
+        self.unit.is_none() && self.min.is_none() && self.max.is_none() && self.hash_id.is_none()
+    }
+
+    fn write(&self, writer: &mut Writer) {
+        writer.delimited(|writer| {
+            let strings = |writer: &mut Writer, value: &Option<String>| {
+                writer.boolean(value.is_some());
+                if let Some(value) = value {
+                    writer.string(value);
+                }
+            };
+            let values = |writer: &mut Writer, value: &Option<AnnotationValue>| {
+                writer.boolean(value.is_some());
+                if let Some(value) = value {
+                    value.write(writer);
+                }
+            };
+            strings(writer, &self.unit);
+            values(writer, &self.min);
+            values(writer, &self.max);
+            strings(writer, &self.hash_id);
+        });
+    }
+}
+
+/// A member of a struct or union, or a literal of an enumeration
+struct Member {
+    name: String,
+    /// Member ID or the value of a literal
+    id: u32,
+    flags: u16,
+    type_id: TypeId,
+    /// Labels of a union branch
+    labels: Vec<i32>,
+    annotations: BuiltinAnnotations,
+}
+
+impl Member {
+    fn flags(&self, equivalence: Equivalence) -> u16 {
+        match equivalence {
+            Equivalence::Minimal => self.flags & MEMBER_FLAG_MINIMAL_MASK,
+            Equivalence::Complete => self.flags,
+        }
+    }
+
+    /// MinimalMemberDetail or CompleteMemberDetail
+    fn write_detail(&self, writer: &mut Writer, equivalence: Equivalence) {
+        match equivalence {
+            Equivalence::Minimal => writer.bytes(&name_hash(&self.name)),
+            Equivalence::Complete => {
+                writer.string(&self.name);
+                writer.boolean(!self.annotations.is_empty());
+                if !self.annotations.is_empty() {
+                    self.annotations.write(writer);
+                }
+                // ann_custom
+                writer.boolean(false);
+            }
+        }
+    }
+}
+
+/// Serialized TypeObjects of a type
+#[derive(Debug)]
+pub struct IdlTypeObjects {
+    /// Expression of the `omg_idl_rt::TypeIdentifierTypeObjectPair` of the minimal
+    /// TypeObject
+    pub minimal: String,
+    /// Expression of the `omg_idl_rt::TypeIdentifierTypeObjectPair` of the complete
+    /// TypeObject
+    pub complete: String,
+    /// Whether the type implements `omg_idl_rt::TypeObjects`, typedefs which are no
+    /// newtypes have no Rust type to implement it for
+    implemented: bool,
+    /// The types the TypeObjects refer to, directly or indirectly, in order of
+    /// declaration
+    dependencies: Vec<Vec<String>>,
+}
+
+/// The TypeObjects of all types
+#[derive(Debug, Default)]
+pub struct TypeObjectSupport {
+    objects: HashMap<Vec<String>, IdlTypeObjects>,
+}
+
+impl TypeObjectSupport {
+    /// TypeObjects of the type with the fully qualified `name`, `None` unless enabled
+    pub fn type_objects(&self, name: &[String]) -> Option<&IdlTypeObjects> {
+        self.objects.get(name)
+    }
+
+    /// Expressions of the `omg_idl_rt::TypeIdentifierTypeObjectPair`s of the dependencies
+    /// of the type `name`, as seen from the module `scope`
+    pub fn dependencies(
+        &self,
+        name: &[String],
+        scope: &[String],
+        config: &Configuration,
+    ) -> Vec<String> {
+        let Some(objects) = self.objects.get(name) else {
+            return Vec::new();
+        };
+        objects
+            .dependencies
+            .iter()
+            .filter_map(|dependency| {
+                self.objects
+                    .get(dependency)
+                    .map(|objects| (dependency, objects))
+            })
+            .flat_map(|(dependency, objects)| {
+                if objects.implemented {
+                    let path = relative_path(dependency, scope, config);
+                    vec![
+                        format!("<{path} as omg_idl_rt::TypeObjects>::MINIMAL"),
+                        format!("<{path} as omg_idl_rt::TypeObjects>::COMPLETE"),
+                    ]
+                } else {
+                    vec![objects.minimal.clone(), objects.complete.clone()]
+                }
+            })
+            .collect()
+    }
+}
+
+struct TypeObjectAnalysis<'a> {
+    types: TypeIndex<'a>,
+    constants: ConstIndex,
+    extensibility: &'a ExtensibilitySupport,
+    member_ids: &'a MemberIdSupport,
+    /// Types which are `@nested`, given on the type or via `@default_nested` on an
+    /// enclosing module
+    nested: HashSet<Vec<String>>,
+    /// The types each type refers to
+    references: HashMap<Vec<String>, Vec<Vec<String>>>,
+    /// TypeIdentifiers of the types whose TypeObjects are known
+    ids: HashMap<(Vec<String>, Equivalence), TypeId>,
+    /// Serialized TypeObjects
+    objects: HashMap<(Vec<String>, Equivalence), Vec<u8>>,
+}
+
+impl TypeObjectAnalysis<'_> {
+    fn collect_nested(&mut self, module: &IdlModule, scope: &mut Vec<String>, inherited: bool) {
+        let inherited = match module.annotations.get("default_nested") {
+            Some(_) => module.annotations.is_set("default_nested"),
+            None => inherited,
+        };
+        for (id, typ) in module.types.iter() {
+            let nested = match typ.1.get("nested") {
+                Some(_) => typ.1.is_set("nested"),
+                None => inherited,
+            };
+            if nested {
+                let mut name = scope.clone();
+                name.push(id.clone());
+                self.nested.insert(name);
+            }
+        }
+        for (id, submodule) in module.modules.iter() {
+            scope.push(id.clone());
+            self.collect_nested(submodule, scope, inherited);
+            scope.pop();
+        }
+    }
+
+    /// Fully qualified name of the struct a base refers to, typedefs resolved
+    fn base_name(&self, base: &IdlScopedName, scope: &[String]) -> Result<Vec<String>, String> {
+        let (spec, base_scope) = self
+            .types
+            .unalias(&IdlTypeSpec::ScopedName(base.clone()), scope);
+        match spec {
+            IdlTypeSpec::ScopedName(ref base) => self
+                .types
+                .resolve(base, &base_scope)
+                .map(|typ| typ.name.to_vec()),
+            _ => None,
+        }
+        .ok_or_else(|| format!("unknown base {}", base.0.join("::")))
+    }
+
+    /// The named types a type specification refers to
+    fn spec_references(
+        &self,
+        spec: &IdlTypeSpec,
+        scope: &[String],
+        references: &mut Vec<Vec<String>>,
+    ) -> Result<(), String> {
+        match spec {
+            IdlTypeSpec::ScopedName(ref name) => {
+                let typ = self
+                    .types
+                    .resolve(name, scope)
+                    .ok_or_else(|| format!("unknown type {}", name.0.join("::")))?;
+                references.push(typ.name.to_vec());
+            }
+            IdlTypeSpec::SequenceType(ref element, _) | IdlTypeSpec::ArrayType(ref element, _) => {
+                self.spec_references(element, scope, references)?
+            }
+            _ => (),
+        }
+        Ok(())
+    }
+
+    /// The types the TypeObjects of the type `name` refer to
+    fn collect_references(
+        &self,
+        name: &[String],
+        dcl: &IdlTypeDcl,
+    ) -> Result<Vec<Vec<String>>, String> {
+        let scope = &name[..name.len() - 1];
+        let mut references = Vec::new();
+        match dcl.0 {
+            IdlTypeDclKind::StructDcl(_, ref base, ref members) => {
+                if let Some(base) = base {
+                    references.push(self.base_name(base, scope)?);
+                }
+                for member in members {
+                    self.spec_references(&member.type_spec, scope, &mut references)?;
+                }
+            }
+            IdlTypeDclKind::UnionDcl(_, ref switch_type, ref switch_cases) => {
+                self.spec_references(switch_type, scope, &mut references)?;
+                for case in switch_cases {
+                    self.spec_references(&case.elem_spec.type_spec, scope, &mut references)?;
+                }
+            }
+            IdlTypeDclKind::TypeDcl(_, ref spec) => {
+                self.spec_references(spec, scope, &mut references)?
+            }
+            IdlTypeDclKind::EnumDcl(..) | IdlTypeDclKind::None => (),
+        }
+        Ok(references)
+    }
+
+    fn bound(&self, bound: &Option<Box<IdlValueExpr>>, scope: &[String]) -> Result<u32, String> {
+        match bound {
+            Some(bound) => self
+                .constants
+                .evaluate_size(bound, scope)
+                .map(|bound| bound as u32),
+            None => Ok(0),
+        }
+    }
+
+    /// TypeIdentifier of a type specification, named types refer to their TypeObjects
+    fn type_id(
+        &self,
+        spec: &IdlTypeSpec,
+        scope: &[String],
+        equivalence: Equivalence,
+    ) -> Result<TypeId, String> {
+        Ok(match spec {
+            IdlTypeSpec::BooleanType => TypeId::Primitive(TK_BOOLEAN),
+            IdlTypeSpec::OctetType => TypeId::Primitive(TK_BYTE),
+            IdlTypeSpec::I16Type => TypeId::Primitive(TK_INT16),
+            IdlTypeSpec::U16Type => TypeId::Primitive(TK_UINT16),
+            IdlTypeSpec::I32Type => TypeId::Primitive(TK_INT32),
+            IdlTypeSpec::U32Type => TypeId::Primitive(TK_UINT32),
+            IdlTypeSpec::I64Type => TypeId::Primitive(TK_INT64),
+            IdlTypeSpec::U64Type => TypeId::Primitive(TK_UINT64),
+            IdlTypeSpec::F32Type => TypeId::Primitive(TK_FLOAT32),
+            IdlTypeSpec::F64Type => TypeId::Primitive(TK_FLOAT64),
+            IdlTypeSpec::F128Type => TypeId::Primitive(TK_FLOAT128),
+            IdlTypeSpec::CharType => TypeId::Primitive(TK_CHAR8),
+            IdlTypeSpec::WideCharType => TypeId::Primitive(TK_CHAR16),
+            IdlTypeSpec::StringType(ref bound) => TypeId::String {
+                wide: false,
+                bound: self.bound(bound, scope)?,
+            },
+            IdlTypeSpec::WideStringType(ref bound) => TypeId::String {
+                wide: true,
+                bound: self.bound(bound, scope)?,
+            },
+            IdlTypeSpec::SequenceType(ref element, ref bound) => TypeId::Sequence(
+                Box::new(self.type_id(element, scope, equivalence)?),
+                self.bound(bound, scope)?,
+            ),
+            IdlTypeSpec::ArrayType(ref element, ref dims) => TypeId::Array(
+                Box::new(self.type_id(element, scope, equivalence)?),
+                dims.iter()
+                    .map(|dim| self.constants.evaluate_size(dim, scope))
+                    .map(|dim| dim.map(|dim| dim as u32))
+                    .collect::<Result<_, _>>()?,
+            ),
+            IdlTypeSpec::ScopedName(ref name) => {
+                let typ = self
+                    .types
+                    .resolve(name, scope)
+                    .ok_or_else(|| format!("unknown type {}", name.0.join("::")))?;
+                self.named_id(typ.name, equivalence)?
+            }
+            IdlTypeSpec::None => return Err("missing type".to_owned()),
+        })
+    }
+
+    fn named_id(&self, name: &[String], equivalence: Equivalence) -> Result<TypeId, String> {
+        self.ids
+            .get(&(name.to_vec(), equivalence))
+            .cloned()
+            .ok_or_else(|| format!("no TypeObject of {}", name.join("::")))
+    }
+
+    /// TypeFlags of a struct or union
+    fn type_flags(&self, name: &[String], equivalence: Equivalence) -> u16 {
+        let mut flags = match self.extensibility.kind(name) {
+            Extensibility::Final => IS_FINAL,
+            Extensibility::Appendable => IS_APPENDABLE,
+            Extensibility::Mutable => IS_MUTABLE,
+        };
+        if self.nested.contains(name) {
+            flags |= IS_NESTED;
+        }
+        if self.member_ids.is_hashed(name) {
+            flags |= IS_AUTOID_HASH;
+        }
+        match equivalence {
+            Equivalence::Minimal => flags & TYPE_FLAG_MINIMAL_MASK,
+            Equivalence::Complete => flags,
+        }
+    }
+
+    fn member_id(&self, name: &[String], member: &str) -> u32 {
+        self.member_ids
+            .ids(name)
+            .iter()
+            .find(|id| id.idl_name == member)
+            .map(|id| id.id)
+            .unwrap_or_default()
+    }
+
+    /// Flags shared by struct members and union branches
+    fn member_flags(annotations: &IdlAnnotations) -> u16 {
+        let mut flags = TRY_CONSTRUCT1;
+        for (annotation, flag) in [
+            ("external", IS_EXTERNAL),
+            ("optional", IS_OPTIONAL),
+            ("must_understand", IS_MUST_UNDERSTAND),
+            ("key", IS_KEY),
+        ] {
+            if annotations.is_set(annotation) {
+                flags |= flag;
+            }
+        }
+        flags
+    }
+
+    /// `@unit`, `@range`, `@min`, `@max` and `@hashid` of a member
+    fn builtin_annotations(
+        &self,
+        annotations: &IdlAnnotations,
+        spec: &IdlTypeSpec,
+        scope: &[String],
+    ) -> BuiltinAnnotations {
+        let (spec, spec_scope) = self.types.unalias(spec, scope);
+        let kind = match self.type_id(&spec, &spec_scope, Equivalence::Minimal) {
+            Ok(TypeId::Primitive(kind)) => kind,
+            _ => TK_NONE,
+        };
+        let value = |annotation: &str, param: &str| {
+            annotations
+                .get(annotation)
+                .and_then(|annotation| annotation.param(param))
+                .and_then(|value| AnnotationValue::new(kind, value))
+        };
+        let string = |annotation: &str| {
+            annotations
+                .get(annotation)
+                .map(|annotation| match annotation.param("value") {
+                    Some(IdlConstValue::String(value)) => value.clone(),
+                    _ => String::new(),
+                })
+        };
+        BuiltinAnnotations {
+            unit: string("unit"),
+            min: value("min", "value").or_else(|| value("range", "min")),
+            max: value("max", "value").or_else(|| value("range", "max")),
+            hash_id: string("hashid"),
+        }
+    }
+
+    /// Value of a union label as member of the `UnionCaseLabelSeq`
+    fn label_value(
+        &self,
+        switch_type: &IdlTypeSpec,
+        label: &IdlValueExpr,
+        scope: &[String],
+    ) -> Result<i32, String> {
+        let (spec, spec_scope) = self.types.unalias(switch_type, scope);
+        if let IdlTypeSpec::ScopedName(ref name) = spec {
+            if let Some(IdlTypeDclKind::EnumDcl(_, ref enumerators)) =
+                self.types.resolve(name, &spec_scope).map(|typ| &typ.dcl.0)
+            {
+                let IdlValueExpr::ScopedName(label_name) = label else {
+                    return Err(format!("label {label} is not an enumerator"));
+                };
+                let enumerator = label_name.0.last().cloned().unwrap_or_default();
+                return enumerators
+                    .iter()
+                    .zip(enumerator_values(enumerators))
+                    .find(|(candidate, _)| candidate.id == enumerator)
+                    .map(|(_, value)| value as i32)
+                    .ok_or_else(|| format!("label {label} is not an enumerator"));
+            }
+        }
+        match self.constants.evaluate(label, scope)? {
+            IdlConstValue::Integer(value) => Ok(value as i32),
+            IdlConstValue::Boolean(value) => Ok(i32::from(value)),
+            IdlConstValue::Char(value) => Ok(value as i32),
+            value => Err(format!("label {value} is no discriminator value")),
+        }
+    }
+
+    /// The serialized TypeObject of the type `name`
+    fn type_object(&self, name: &[String], equivalence: Equivalence) -> Result<Vec<u8>, String> {
+        let typ = self
+            .types
+            .resolve(&IdlScopedName(name.to_vec(), true), &[])
+            .ok_or_else(|| format!("unknown type {}", name.join("::")))?;
+        let scope = typ.scope();
+        let type_name = name.join("::");
+        let complete = equivalence == Equivalence::Complete;
+        // CompleteTypeDetail without annotations
+        let write_type_detail = |writer: &mut Writer| {
+            if complete {
+                writer.boolean(false);
+                writer.boolean(false);
+                writer.string(&type_name);
+            }
+        };
+        let mut writer = Writer::default();
+        match typ.dcl.0 {
+            IdlTypeDclKind::StructDcl(_, ref base, ref members) => {
+                let base = match base {
+                    Some(base) => self.named_id(&self.base_name(base, scope)?, equivalence)?,
+                    None => TypeId::None,
+                };
+                let mut members = members
+                    .iter()
+                    .map(|member| {
+                        Ok(Member {
+                            name: member.id.clone(),
+                            id: self.member_id(name, &member.id),
+                            flags: Self::member_flags(&member.annotations),
+                            type_id: self.type_id(&member.type_spec, scope, equivalence)?,
+                            labels: Vec::new(),
+                            annotations: self.builtin_annotations(
+                                &member.annotations,
+                                &member.type_spec,
+                                scope,
+                            ),
+                        })
+                    })
+                    .collect::<Result<Vec<_>, String>>()?;
+                if !complete {
+                    members.sort_by_key(|member| member.id);
+                }
+                writer.delimited(|writer| {
+                    writer.octet(equivalence.kind());
+                    writer.octet(TK_STRUCTURE);
+                    writer.ushort(self.type_flags(name, equivalence));
+                    // header
+                    writer.delimited(|writer| {
+                        base.write(writer);
+                        write_type_detail(writer);
+                    });
+                    writer.sequence(&members, |writer, member| {
+                        writer.delimited(|writer| {
+                            writer.ulong(member.id);
+                            writer.ushort(member.flags(equivalence));
+                            member.type_id.write(writer);
+                            member.write_detail(writer, equivalence);
+                        })
+                    });
+                });
+            }
+            IdlTypeDclKind::UnionDcl(_, ref switch_type, ref switch_cases) => {
+                let discriminator = self.type_id(switch_type, scope, equivalence)?;
+                let mut members = switch_cases
+                    .iter()
+                    .map(|case| {
+                        let element = &case.elem_spec;
+                        let mut flags = Self::member_flags(&element.annotations) & !IS_KEY;
+                        let mut labels = Vec::new();
+                        for label in &case.labels {
+                            match label {
+                                IdlSwitchLabel::Label(label) => {
+                                    labels.push(self.label_value(switch_type, label, scope)?)
+                                }
+                                IdlSwitchLabel::Default => flags |= IS_DEFAULT,
+                            }
+                        }
+                        labels.sort_unstable();
+                        Ok(Member {
+                            name: element.id.clone(),
+                            id: self.member_id(name, &element.id),
+                            flags,
+                            type_id: self.type_id(&element.type_spec, scope, equivalence)?,
+                            labels,
+                            annotations: self.builtin_annotations(
+                                &element.annotations,
+                                &element.type_spec,
+                                scope,
+                            ),
+                        })
+                    })
+                    .collect::<Result<Vec<_>, String>>()?;
+                if !complete {
+                    members.sort_by_key(|member| member.id);
+                }
+                writer.delimited(|writer| {
+                    writer.octet(equivalence.kind());
+                    writer.octet(TK_UNION);
+                    writer.ushort(self.type_flags(name, equivalence));
+                    // header
+                    writer.delimited(write_type_detail);
+                    // discriminator
+                    writer.delimited(|writer| {
+                        writer.ushort(TRY_CONSTRUCT1);
+                        discriminator.write(writer);
+                        if complete {
+                            writer.boolean(false);
+                            writer.boolean(false);
+                        }
+                    });
+                    writer.sequence(&members, |writer, member| {
+                        writer.delimited(|writer| {
+                            writer.ulong(member.id);
+                            writer.ushort(member.flags(equivalence));
+                            member.type_id.write(writer);
+                            writer.ulong(member.labels.len() as u32);
+                            for label in &member.labels {
+                                writer.long(*label);
+                            }
+                            member.write_detail(writer, equivalence);
+                        })
+                    });
+                });
+            }
+            IdlTypeDclKind::EnumDcl(_, ref enumerators) => {
+                let bit_bound = match typ
+                    .dcl
+                    .1
+                    .get("bit_bound")
+                    .and_then(|annotation| annotation.param("value"))
+                {
+                    Some(IdlConstValue::Integer(bits)) => *bits as u16,
+                    _ => DEFAULT_BIT_BOUND,
+                };
+                // the first literal is the default unless another one is `@default_literal`
+                let explicit_default = enumerators
+                    .iter()
+                    .position(|enumerator| enumerator.annotations.get("default_literal").is_some());
+                let mut literals = enumerators
+                    .iter()
+                    .zip(enumerator_values(enumerators))
+                    .enumerate()
+                    .map(|(index, (enumerator, value))| Member {
+                        name: enumerator.id.clone(),
+                        id: value as u32,
+                        flags: if index == explicit_default.unwrap_or_default() {
+                            IS_DEFAULT
+                        } else {
+                            0
+                        },
+                        type_id: TypeId::None,
+                        labels: Vec::new(),
+                        annotations: BuiltinAnnotations::default(),
+                    })
+                    .collect::<Vec<_>>();
+                literals.sort_by_key(|literal| literal.id as i32);
+                writer.delimited(|writer| {
+                    writer.octet(equivalence.kind());
+                    writer.octet(TK_ENUM);
+                    // enum_flags are unused
+                    writer.ushort(0);
+                    // header
+                    writer.delimited(|writer| {
+                        writer.ushort(bit_bound);
+                        write_type_detail(writer);
+                    });
+                    writer.sequence(&literals, |writer, literal| {
+                        writer.delimited(|writer| {
+                            writer.long(literal.id as i32);
+                            writer.ushort(literal.flags(equivalence));
+                            literal.write_detail(writer, equivalence);
+                        })
+                    });
+                });
+            }
+            IdlTypeDclKind::TypeDcl(_, ref spec) => {
+                let related = self.type_id(spec, scope, equivalence)?;
+                writer.delimited(|writer| {
+                    writer.octet(equivalence.kind());
+                    writer.octet(TK_ALIAS);
+                    // alias_flags are unused
+                    writer.ushort(0);
+                    // header
+                    writer.delimited(write_type_detail);
+                    // body
+                    writer.delimited(|writer| {
+                        // related_flags are unused
+                        writer.ushort(0);
+                        related.write(writer);
+                        if complete {
+                            writer.boolean(false);
+                            writer.boolean(false);
+                        }
+                    });
+                });
+            }
+            IdlTypeDclKind::None => return Err(format!("{type_name} is not declared")),
+        }
+        Ok(writer.0)
+    }
+
+    /// Strongly connected components of the types in order of their dependencies, types
+    /// only refer to types of earlier components or of their own
+    fn components(&self, names: &[Vec<String>]) -> Vec<Vec<Vec<String>>> {
+        struct Tarjan<'t> {
+            references: &'t HashMap<Vec<String>, Vec<Vec<String>>>,
+            index: HashMap<Vec<String>, usize>,
+            low_link: HashMap<Vec<String>, usize>,
+            stack: Vec<Vec<String>>,
+            on_stack: HashSet<Vec<String>>,
+            components: Vec<Vec<Vec<String>>>,
+        }
+
+        impl Tarjan<'_> {
+            fn visit(&mut self, name: &[String]) {
+                let index = self.index.len();
+                self.index.insert(name.to_vec(), index);
+                self.low_link.insert(name.to_vec(), index);
+                self.stack.push(name.to_vec());
+                self.on_stack.insert(name.to_vec());
+                let references = self.references.get(name).cloned().unwrap_or_default();
+                for reference in references {
+                    if !self.index.contains_key(&reference) {
+                        self.visit(&reference);
+                        let low_link = self.low_link[name].min(self.low_link[&reference]);
+                        self.low_link.insert(name.to_vec(), low_link);
+                    } else if self.on_stack.contains(&reference) {
+                        let low_link = self.low_link[name].min(self.index[&reference]);
+                        self.low_link.insert(name.to_vec(), low_link);
+                    }
+                }
+                if self.low_link[name] == self.index[name] {
+                    let mut component = Vec::new();
+                    while let Some(member) = self.stack.pop() {
+                        self.on_stack.remove(&member);
+                        let last = member == name;
+                        component.push(member);
+                        if last {
+                            break;
+                        }
+                    }
+                    self.components.push(component);
+                }
+            }
+        }
+
+        let mut tarjan = Tarjan {
+            references: &self.references,
+            index: HashMap::new(),
+            low_link: HashMap::new(),
+            stack: Vec::new(),
+            on_stack: HashSet::new(),
+            components: Vec::new(),
+        };
+        for name in names {
+            if !tarjan.index.contains_key(name) {
+                tarjan.visit(name);
+            }
+        }
+        tarjan.components
+    }
+
+    /// Compute the TypeObjects of a strongly connected component. Types referring to
+    /// each other are identified by the hash of the `TypeObjectSeq` of all types of the
+    /// component, ordered by name, computed while they refer to each other by a zero hash.
+    fn analyze_component(&mut self, mut component: Vec<Vec<String>>) -> Result<(), String> {
+        let recursive =
+            component.len() > 1 || self.references[&component[0]].contains(&component[0]);
+        for equivalence in [Equivalence::Minimal, Equivalence::Complete] {
+            if !recursive {
+                let name = &component[0];
+                let object = self.type_object(name, equivalence)?;
+                let id = TypeId::Hash(equivalence, equivalence_hash(&object));
+                self.ids.insert((name.clone(), equivalence), id);
+                self.objects.insert((name.clone(), equivalence), object);
+                continue;
+            }
+            component.sort();
+            let length = component.len() as i32;
+            let assign = |hash: [u8; 14], ids: &mut HashMap<_, _>| {
+                for (index, name) in component.iter().enumerate() {
+                    let id = TypeId::StronglyConnected(equivalence, hash, length, index as i32 + 1);
+                    ids.insert((name.clone(), equivalence), id);
+                }
+            };
+            assign([0; 14], &mut self.ids);
+            let objects = component
+                .iter()
+                .map(|name| self.type_object(name, equivalence))
+                .collect::<Result<Vec<_>, _>>()?;
+            let mut writer = Writer::default();
+            writer.sequence(&objects, |writer, object| {
+                writer.align(4);
+                writer.bytes(object);
+            });
+            assign(equivalence_hash(&writer.0), &mut self.ids);
+            for name in &component {
+                let object = self.type_object(name, equivalence)?;
+                self.objects.insert((name.clone(), equivalence), object);
+            }
+        }
+        Ok(())
+    }
+
+    /// All types the type `name` refers to, directly or indirectly
+    fn dependencies(&self, name: &[String]) -> HashSet<Vec<String>> {
+        let mut dependencies = HashSet::new();
+        let mut pending = vec![name.to_vec()];
+        while let Some(next) = pending.pop() {
+            for reference in self.references.get(&next).into_iter().flatten() {
+                if dependencies.insert(reference.clone()) {
+                    pending.push(reference.clone());
+                }
+            }
+        }
+        dependencies.remove(name);
+        dependencies
+    }
+
+    fn pair(&self, name: &[String], equivalence: Equivalence) -> Result<String, String> {
+        let id = self.named_id(name, equivalence)?;
+        let object = self
+            .objects
+            .get(&(name.to_vec(), equivalence))
+            .ok_or_else(|| format!("no TypeObject of {}", name.join("::")))?;
+        let object = object
+            .iter()
+            .map(|byte| format!("\\x{byte:02x}"))
+            .collect::<String>();
+        Ok(format!(
+            "omg_idl_rt::TypeIdentifierTypeObjectPair {{ type_identifier: {}, type_object: b\"{object}\" }}",
+            id.to_rust()
+        ))
+    }
+}
+
+/// Compute the minimal and complete DDS-XTypes TypeObjects of all structs, unions, enums
+/// and typedefs as enabled by the configuration, along with the TypeIdentifiers
+/// referring to them.
+pub fn analyze(
+    root_module: &IdlModule,
+    config: &Configuration,
+    extensibility: &ExtensibilitySupport,
+    member_ids: &MemberIdSupport,
+    newtypes: &NewtypeSupport,
+) -> Result<TypeObjectSupport, String> {
+    if !config.type_objects {
+        return Ok(TypeObjectSupport::default());
+    }
+    let mut analysis = TypeObjectAnalysis {
+        types: TypeIndex::new(root_module),
+        constants: ConstIndex::new(root_module),
+        extensibility,
+        member_ids,
+        nested: HashSet::new(),
+        references: HashMap::new(),
+        ids: HashMap::new(),
+        objects: HashMap::new(),
+    };
+    analysis.collect_nested(root_module, &mut Vec::new(), false);
+    let names = analysis
+        .types
+        .iter()
+        .filter(|typ| !matches!(typ.dcl.0, IdlTypeDclKind::None))
+        .map(|typ| typ.name.to_vec())
+        .collect::<Vec<_>>();
+    for name in &names {
+        let typ = analysis
+            .types
+            .resolve(&IdlScopedName(name.clone(), true), &[])
+            .ok_or_else(|| format!("unknown type {}", name.join("::")))?;
+        let references = analysis.collect_references(name, typ.dcl)?;
+        analysis.references.insert(name.clone(), references);
+    }
+    for component in analysis.components(&names) {
+        analysis.analyze_component(component)?;
+    }
+
+    let mut support = TypeObjectSupport::default();
+    for name in &names {
+        let dependencies = analysis.dependencies(name);
+        let implemented = match analysis
+            .types
+            .resolve(&IdlScopedName(name.clone(), true), &[])
+            .map(|typ| &typ.dcl.0)
+        {
+            Some(IdlTypeDclKind::TypeDcl(..)) => newtypes.is_newtype(name),
+            _ => true,
+        };
+        support.objects.insert(
+            name.clone(),
+            IdlTypeObjects {
+                minimal: analysis.pair(name, Equivalence::Minimal)?,
+                complete: analysis.pair(name, Equivalence::Complete)?,
+                implemented,
+                dependencies: names
+                    .iter()
+                    .filter(|candidate| dependencies.contains(*candidate))
+                    .cloned()
+                    .collect(),
+            },
+        );
+    }
+    Ok(support)
+}
@@ -0,0 +1,378 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+use crate::{
+    ast::*,
+    cdr,
+    extensibility::{Extensibility, ExtensibilitySupport},
+    index::{relative_path, ConstIndex, IndexedType, TypeIndex},
+    member_id::MemberIdSupport,
+    newtype::NewtypeSupport,
+    parameter_list::ParameterListSupport,
+    Configuration, WideCharMapping,
+};
+use serde_derive::Serialize;
+use std::collections::HashMap;
+
+/// Pragma requesting zero-copy reads of the types of an IDL file, i.e. in DdsDcpsCore.idl
+const ZERO_COPY_PRAGMA: &str = "DCPS_SUPPORT_ZERO_COPY_READ";
+
+/// Names used by the generated view itself, members must not take them
+const RESERVED_NAMES: [&str; 2] = ["reader", "to_owned"];
+
+/// Data storage to align with Jinja (cdr_view.j2), a member of a view or its base
+#[derive(Clone, Debug, Serialize)]
+pub struct IdlViewField {
+    pub name: String,
+    /// Type of the member within the view, i.e. `&'a str`
+    pub type_str: String,
+    /// Expression reading the member from `reader`
+    pub view: String,
+    /// Type returned by the accessor of the member
+    pub return_type: String,
+    /// Expression returning the member from `self`
+    pub access: String,
+}
+
+/// The members of the views generated for structs, by the fully qualified name of the
+/// struct
+#[derive(Debug, Default)]
+pub struct ViewSupport {
+    views: HashMap<Vec<String>, Vec<IdlViewField>>,
+}
+
+impl ViewSupport {
+    /// Members of the view of a struct, `None` if the struct has no view
+    pub fn fields(&self, name: &[String]) -> Option<&[IdlViewField]> {
+        self.views.get(name).map(Vec::as_slice)
+    }
+}
+
+/// How the member of a view is accessed
+enum Access {
+    /// Copied out of the view, primitives, strings and slices of primitives
+    Copy,
+    /// Borrowed from the view, views of nested structs
+    Borrow,
+    /// Decoded on access into the given Rust type, members of all other types
+    Decode(String),
+}
+
+struct ViewAnalysis<'a> {
+    types: TypeIndex<'a>,
+    constants: ConstIndex,
+    config: &'a Configuration,
+    extensibility: &'a ExtensibilitySupport,
+    member_ids: &'a MemberIdSupport,
+    parameter_lists: &'a ParameterListSupport,
+    newtypes: &'a NewtypeSupport,
+    /// Whether a struct has a view, by its fully qualified name
+    viewable: HashMap<Vec<String>, bool>,
+}
+
+impl ViewAnalysis<'_> {
+    /// Final and appendable structs have a view if their base has one. Mutable structs
+    /// and parameter lists are decoded by member ID, which needs an owned value.
+    fn has_view(&mut self, name: &[String]) -> bool {
+        if let Some(viewable) = self.viewable.get(name) {
+            return *viewable;
+        }
+        let base = match self.types.resolve(&IdlScopedName(name.to_vec(), true), &[]) {
+            Some(IndexedType {
+                dcl: IdlTypeDcl(IdlTypeDclKind::StructDcl(_, ref base, _), _),
+                ..
+            }) => base
+                .as_ref()
+                .map(|base| self.base(base, &name[..name.len() - 1])),
+            _ => return false,
+        };
+        let viewable = self.extensibility.kind(name) != Extensibility::Mutable
+            && self.parameter_lists.ids(name).is_none()
+            && match base {
+                Some(Some(base)) => self.has_view(&base),
+                Some(None) => false,
+                None => true,
+            };
+        self.viewable.insert(name.to_vec(), viewable);
+        viewable
+    }
+
+    /// Fully qualified name of the base of a struct
+    fn base(&self, base: &IdlScopedName, scope: &[String]) -> Option<Vec<String>> {
+        match self
+            .types
+            .unalias(&IdlTypeSpec::ScopedName(base.clone()), scope)
+        {
+            (IdlTypeSpec::ScopedName(ref base), base_scope) => self
+                .types
+                .resolve(base, &base_scope)
+                .map(|typ| typ.name.to_vec()),
+            _ => None,
+        }
+    }
+
+    /// Rust type of a primitive, `None` for other types. Wide characters mapped to `char`
+    /// share their Rust type with characters and need a codec.
+    fn primitive(&self, spec: &IdlTypeSpec) -> Option<&'static str> {
+        match spec {
+            IdlTypeSpec::F32Type => Some("f32"),
+            IdlTypeSpec::F64Type => Some("f64"),
+            IdlTypeSpec::I16Type => Some("i16"),
+            IdlTypeSpec::I32Type => Some("i32"),
+            IdlTypeSpec::I64Type => Some("i64"),
+            IdlTypeSpec::U16Type => Some("u16"),
+            IdlTypeSpec::U32Type => Some("u32"),
+            IdlTypeSpec::U64Type => Some("u64"),
+            IdlTypeSpec::CharType => Some(self.config.char_type()),
+            IdlTypeSpec::WideCharType
+                if self.config.wide_char_mapping == WideCharMapping::Utf16 =>
+            {
+                Some("u16")
+            }
+            IdlTypeSpec::BooleanType => Some("bool"),
+            IdlTypeSpec::OctetType => Some("u8"),
+            _ => None,
+        }
+    }
+
+    /// Rust type and total number of elements of an array of primitives, nested arrays
+    /// are flattened. `None` for other arrays.
+    fn primitive_array(
+        &self,
+        spec: &IdlTypeSpec,
+        scope: &[String],
+    ) -> Result<Option<(&'static str, usize)>, String> {
+        let (spec, scope) = self.types.unalias(spec, scope);
+        if let Some(primitive) = self.primitive(&spec) {
+            return Ok(Some((primitive, 1)));
+        }
+        let IdlTypeSpec::ArrayType(ref element, ref dims) = spec else {
+            return Ok(None);
+        };
+        let Some((primitive, mut count)) = self.primitive_array(element, &scope)? else {
+            return Ok(None);
+        };
+        for dim in dims {
+            count = self
+                .constants
+                .evaluate_size(dim, &scope)?
+                .checked_mul(count)
+                .ok_or_else(|| format!("array {spec} is too large"))?;
+        }
+        Ok(Some((primitive, count)))
+    }
+
+    /// View type, expression reading the view from `reader` and access of a member
+    fn member(
+        &mut self,
+        spec: &IdlTypeSpec,
+        scope: &[String],
+    ) -> Result<(String, String, Access), String> {
+        let (unaliased, spec_scope) = self.types.unalias(spec, scope);
+        let view = "omg_cdr::CdrView::view(reader)".to_owned();
+        if let Some(primitive) = self.primitive(&unaliased) {
+            return Ok((primitive.to_owned(), view, Access::Copy));
+        }
+        match unaliased {
+            IdlTypeSpec::StringType(_) => Ok(("&'a str".to_owned(), view, Access::Copy)),
+            IdlTypeSpec::SequenceType(ref element, _) => {
+                match self.primitive(&self.types.unalias(element, &spec_scope).0) {
+                    Some(primitive) => Ok((
+                        format!("omg_cdr::CdrSlice<'a, {primitive}>"),
+                        "omg_cdr::CdrSlice::read_sequence(reader)".to_owned(),
+                        Access::Copy,
+                    )),
+                    None => self.lazy(spec, scope),
+                }
+            }
+            IdlTypeSpec::ArrayType(..) => match self.primitive_array(spec, scope)? {
+                Some((primitive, count)) => Ok((
+                    format!("omg_cdr::CdrSlice<'a, {primitive}>"),
+                    format!("omg_cdr::CdrSlice::read_array::<{count}>(reader)"),
+                    Access::Copy,
+                )),
+                None => self.lazy(spec, scope),
+            },
+            IdlTypeSpec::ScopedName(ref name) => {
+                let name = self
+                    .types
+                    .resolve(name, &spec_scope)
+                    .map(|typ| typ.name.to_vec());
+                match name {
+                    Some(name) if self.has_view(&name) => Ok((
+                        format!("{}Ref<'a>", relative_path(&name, scope, self.config)),
+                        view,
+                        Access::Borrow,
+                    )),
+                    _ => self.lazy(spec, scope),
+                }
+            }
+            _ => self.lazy(spec, scope),
+        }
+    }
+
+    /// A member decoded on access, with the codec of its IDL type
+    fn lazy(
+        &self,
+        spec: &IdlTypeSpec,
+        scope: &[String],
+    ) -> Result<(String, String, Access), String> {
+        let type_str = spec
+            .to_rust(self.config)
+            .map_err(|_| format!("unsupported type {spec:?}"))?;
+        let (_, decode) = cdr::functions(
+            &self.types,
+            self.newtypes,
+            self.parameter_lists,
+            spec,
+            scope,
+        );
+        Ok((
+            format!("omg_cdr::Lazy<'a, {type_str}>"),
+            format!("omg_cdr::Lazy::read(reader, {decode})"),
+            Access::Decode(type_str),
+        ))
+    }
+
+    /// The members of the view of the struct `name`, the view of the base first
+    fn fields(&mut self, name: &[String]) -> Result<Vec<IdlViewField>, String> {
+        let typ = self
+            .types
+            .resolve(&IdlScopedName(name.to_vec(), true), &[])
+            .ok_or_else(|| format!("unknown struct {}", name.join("::")))?;
+        let IdlTypeDclKind::StructDcl(ref id, ref base, ref members) = typ.dcl.0 else {
+            return Ok(Vec::new());
+        };
+        let (base, members) = (base.clone(), members.clone());
+        let scope = &name[..name.len() - 1];
+        let type_name = self.config.type_name(id);
+        let mut fields = Vec::new();
+        if let Some(base) = base.and_then(|base| self.base(&base, scope)) {
+            let base_view = format!("{}Ref<'a>", relative_path(&base, scope, self.config));
+            fields.push(IdlViewField {
+                name: BASE_MEMBER.to_owned(),
+                type_str: base_view.clone(),
+                view: "omg_cdr::CdrStructView::view_members(reader)".to_owned(),
+                return_type: format!("&{base_view}"),
+                access: format!("&self.{BASE_MEMBER}"),
+            });
+        }
+        for member in members {
+            let member_name = self.config.member_name(&member.id);
+            if RESERVED_NAMES.contains(&member_name.as_str()) {
+                return Err(format!(
+                    "member {} of struct {} collides with the {member_name} of its view",
+                    member.id,
+                    name.join("::")
+                ));
+            }
+            let (type_str, view, access) = self.member(&member.type_spec, scope)?;
+            let field = if member.annotations.is_set("optional") {
+                let id_const = self
+                    .member_ids
+                    .ids(name)
+                    .iter()
+                    .find(|id| id.idl_name == member.id)
+                    .map(|id| format!("{type_name}::{}", id.const_name))
+                    .unwrap_or_default();
+                let (return_type, access) = match access {
+                    Access::Copy => (format!("Option<{type_str}>"), format!("self.{member_name}")),
+                    Access::Borrow => (
+                        format!("Option<&{type_str}>"),
+                        format!("self.{member_name}.as_ref()"),
+                    ),
+                    Access::Decode(decoded) => (
+                        format!("Result<Option<{decoded}>, omg_cdr::CdrError>"),
+                        format!("self.{member_name}.as_ref().map(omg_cdr::Lazy::get).transpose()"),
+                    ),
+                };
+                let view = match view.strip_suffix("(reader)") {
+                    Some(function) => function.to_owned(),
+                    None => format!("|reader| {view}"),
+                };
+                IdlViewField {
+                    type_str: format!("Option<{type_str}>"),
+                    view: format!("reader.read_optional({id_const}, {view})"),
+                    return_type,
+                    access,
+                    name: member_name,
+                }
+            } else {
+                let (return_type, access) = match access {
+                    Access::Copy => (type_str.clone(), format!("self.{member_name}")),
+                    Access::Borrow => (format!("&{type_str}"), format!("&self.{member_name}")),
+                    Access::Decode(decoded) => (
+                        format!("Result<{decoded}, omg_cdr::CdrError>"),
+                        format!("self.{member_name}.get()"),
+                    ),
+                };
+                IdlViewField {
+                    type_str,
+                    view,
+                    return_type,
+                    access,
+                    name: member_name,
+                }
+            };
+            fields.push(field);
+        }
+        Ok(fields)
+    }
+}
+
+/// Determine the views of all final and appendable structs, generated if enabled by
+/// the configuration or `#pragma DCPS_SUPPORT_ZERO_COPY_READ`
+pub fn analyze(
+    root_module: &IdlModule,
+    pragmas: &[IdlPragma],
+    config: &Configuration,
+    extensibility: &ExtensibilitySupport,
+    member_ids: &MemberIdSupport,
+    parameter_lists: &ParameterListSupport,
+    newtypes: &NewtypeSupport,
+) -> Result<ViewSupport, String> {
+    let mut support = ViewSupport::default();
+    let requested = pragmas
+        .iter()
+        .any(|pragma| pragma.text.trim() == ZERO_COPY_PRAGMA);
+    if !config.zero_copy_views && !requested {
+        return Ok(support);
+    }
+    let mut analysis = ViewAnalysis {
+        types: TypeIndex::new(root_module),
+        constants: ConstIndex::new(root_module),
+        config,
+        extensibility,
+        member_ids,
+        parameter_lists,
+        newtypes,
+        viewable: HashMap::new(),
+    };
+    let names = analysis
+        .types
+        .iter()
+        .map(|typ| typ.name.to_vec())
+        .collect::<Vec<_>>();
+    for name in &names {
+        if !analysis.has_view(name) {
+            continue;
+        }
+        let scope = &name[..name.len() - 1];
+        let view_name = format!("{}Ref", config.type_name(&name[scope.len()]));
+        let collision = names.iter().any(|other| {
+            other.len() == name.len()
+                && other.starts_with(scope)
+                && config.type_name(&other[scope.len()]) == view_name
+        });
+        if collision {
+            return Err(format!(
+                "the view {view_name} of struct {} collides with a type",
+                name.join("::")
+            ));
+        }
+        let fields = analysis.fields(name)?;
+        support.views.insert(name.clone(), fields);
+    }
+    Ok(support)
+}
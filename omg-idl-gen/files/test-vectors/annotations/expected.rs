@@ -0,0 +1,393 @@
+
+#[allow(non_snake_case)]
+pub mod Vehicle {
+    use serde_derive::{Serialize, Deserialize};
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, PartialOrd, Eq, Ord)]
+    pub enum Gear {
+        First,
+        #[default]
+        Second,
+    }
+
+    #[allow(non_camel_case_types)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct GearError;
+
+    impl std::str::FromStr for Gear {
+        type Err = GearError;
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            match value {
+                "First" => Ok(Gear::First),
+                "Second" => Ok(Gear::Second),
+                _ => Err(GearError),
+            }
+        }
+    }
+
+    impl std::fmt::Display for Gear {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let enum_str = match self {
+                    Gear::First => "First",
+                    Gear::Second => "Second",
+            };
+            write!(f, "{enum_str}")
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Gear {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Gear {
+        const TYPE_NAME: &'static str = "Vehicle::Gear";
+        const REPOSITORY_ID: &'static str = "IDL:Vehicle/Gear:1.0";
+    }
+
+    impl omg_cdr::CdrEncode for Gear {
+        const PRIMITIVE: bool = true;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let value: u16 = match self {
+                Gear::First => 1,
+                Gear::Second => 2,
+            };
+            omg_cdr::CdrEncode::encode(&value, writer);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Gear {
+        const PRIMITIVE: bool = true;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            match <u16 as omg_cdr::CdrDecode>::decode(reader)? {
+                1 => Ok(Gear::First),
+                2 => Ok(Gear::Second),
+                value => Err(omg_cdr::CdrError::InvalidEnum(value.into())),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Gear {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(2);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(2);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    pub type Speed_t = i32;
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct State {
+        #[allow(non_snake_case)]
+        pub id: i32,
+        /// Unit: m/s
+        #[allow(non_snake_case)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub speed: Option<Speed_t>,
+        // unused
+        #[allow(non_snake_case)]
+        pub heading: f64,
+        #[allow(non_snake_case)]
+        pub gear: Gear,
+    }
+
+    #[allow(dead_code)]
+    impl State {
+
+        pub fn new(id: i32, heading: f64, gear: Gear, ) -> Self {
+            Self {
+                id,
+                speed: None,
+                heading,
+                gear,
+            }
+        }
+
+        pub fn id(&self) -> &i32 {
+            &self.id
+        }
+
+        pub fn set_id(&mut self, value: i32) {
+            self.id = value;
+        }
+
+        pub fn speed(&self) -> Option<&Speed_t> {
+            self.speed.as_ref()
+        }
+
+        pub fn set_speed(&mut self, value: Option<Speed_t>) -> Result<(), omg_idl_rt::ValidationError> {
+            if let Some(value) = &value {
+                Self::validate_speed(value)?;
+            }
+            self.speed = value;
+            Ok(())
+        }
+
+        fn validate_speed(value: &Speed_t) -> Result<(), omg_idl_rt::ValidationError> {
+            if *value < 0 {
+                return Err(omg_idl_rt::ValidationError::new("speed", omg_idl_rt::Bound::Min, "0"));
+            }
+            if *value > 16 {
+                return Err(omg_idl_rt::ValidationError::new("speed", omg_idl_rt::Bound::Max, "16"));
+            }
+            Ok(())
+        }
+
+        pub fn heading(&self) -> &f64 {
+            &self.heading
+        }
+
+        pub fn set_heading(&mut self, value: f64) {
+            self.heading = value;
+        }
+
+        pub fn gear(&self) -> &Gear {
+            &self.gear
+        }
+
+        pub fn set_gear(&mut self, value: Gear) {
+            self.gear = value;
+        }
+
+        /// Check all members against their `@range`, `@min` and `@max` bounds
+        pub fn validate(&self) -> Result<(), omg_idl_rt::ValidationError> {
+            if let Some(value) = &self.speed {
+                Self::validate_speed(value)?;
+            }
+            Ok(())
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for State {
+        fn default() -> Self {
+            Self {
+                id: 0,
+                speed: None,
+                heading: 0.0,
+                gear: Default::default(),
+            }
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug)]
+    pub struct StateKey {
+        pub id: i32,
+    }
+
+    impl omg_idl_rt::KeyHashEncode for StateKey {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<i32>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.id, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+        }
+    }
+
+    impl PartialEq for StateKey {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other).is_eq()
+        }
+    }
+
+    impl Eq for StateKey {}
+
+    impl PartialOrd for StateKey {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for StateKey {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other)
+        }
+    }
+
+    impl std::hash::Hash for StateKey {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            omg_idl_rt::hash_key(self, state);
+        }
+    }
+
+    impl omg_idl_rt::Keyed for State {
+        type Key = StateKey;
+
+        fn key(&self) -> Self::Key {
+            StateKey {
+                id: self.id,
+            }
+        }
+    }
+
+    /// Equal if the key members are equal, other members are ignored
+    impl PartialEq for State {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+                .is_eq()
+        }
+    }
+
+    impl Eq for State {}
+
+    impl PartialOrd for State {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    /// Orders by the key members, other members are ignored
+    impl Ord for State {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+        }
+    }
+
+    /// Hashes the key members only, consistent with `PartialEq`
+    impl std::hash::Hash for State {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            let mut writer = omg_idl_rt::KeyHolderWriter::hashing(state);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.id, &mut writer);
+        }
+    }
+
+    impl omg_idl_rt::Extensible for State {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for State {
+        const TYPE_NAME: &'static str = "Vehicle::State";
+        const REPOSITORY_ID: &'static str = "IDL:Vehicle/State:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl State {
+        /// Member ID of `id`
+        pub const ID_MEMBER_ID: u32 = 1;
+        /// Member ID of `speed`
+        pub const SPEED_MEMBER_ID: u32 = 2;
+        /// Member ID of `heading`
+        pub const HEADING_MEMBER_ID: u32 = 3;
+        /// Member ID of `gear`
+        pub const GEAR_MEMBER_ID: u32 = 4;
+    }
+
+    impl omg_cdr::CdrEncode for State {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for State {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for State {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.id, writer);
+            writer.write_optional(Self::SPEED_MEMBER_ID, &self.speed, omg_cdr::CdrEncode::encode);
+            omg_cdr::CdrEncode::encode(&self.heading, writer);
+            omg_cdr::CdrEncode::encode(&self.gear, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                id: omg_cdr::CdrDecode::decode(reader)?,
+                speed: reader.read_optional(Self::SPEED_MEMBER_ID, omg_cdr::CdrDecode::decode)?,
+                heading: omg_cdr::CdrDecode::decode(reader)?,
+                gear: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for State {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(26);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(26);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub enum Command {
+        MOVE{ target: i32, },
+        HALT{ stop: bool, },
+        default{ stop: bool, },
+    }
+
+    impl Default for Command {
+        fn default() -> Self {
+            Command::default { stop: false }
+        }
+    }
+//
+// TODO custom de-/serializer
+//
+
+    impl omg_idl_rt::Extensible for Command {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Mutable;
+    }
+
+    impl omg_idl_rt::TypeName for Command {
+        const TYPE_NAME: &'static str = "Vehicle::Command";
+        const REPOSITORY_ID: &'static str = "IDL:Vehicle/Command:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Command {
+        /// Member ID of `target`
+        pub const TARGET_MEMBER_ID: u32 = 10;
+        /// Member ID of `stop`
+        pub const STOP_MEMBER_ID: u32 = 11;
+    }
+
+    impl omg_cdr::CdrEncode for Command {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            match self {
+                Command::MOVE { target } => writer.write_union(extensibility, &1_i32, Self::TARGET_MEMBER_ID, target, omg_cdr::CdrEncode::encode),
+                Command::HALT { stop } => writer.write_union(extensibility, &2_i32, Self::STOP_MEMBER_ID, stop, omg_cdr::CdrEncode::encode),
+                Command::default { stop } => writer.write_union(extensibility, &0_i32, Self::STOP_MEMBER_ID, stop, omg_cdr::CdrEncode::encode),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Command {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            reader.read_union(extensibility, |discriminator: i32, reader| {
+                Ok(match discriminator {
+                    1_i32 => Command::MOVE { target: reader.read_branch(extensibility, Self::TARGET_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    2_i32 => Command::HALT { stop: reader.read_branch(extensibility, Self::STOP_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    #[allow(unreachable_patterns)]
+                    _ => Command::default { stop: reader.read_branch(extensibility, Self::STOP_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                })
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Command {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(20);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(20);
+    }
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const MOVE: i32 = 1;
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const HALT: i32 = 2;
+
+}
+
+#[allow(dead_code, non_upper_case_globals)]
+pub const MAX_SPEED: i32 = 10+2*3;
@@ -0,0 +1,909 @@
+
+#[allow(non_snake_case)]
+pub mod Bounds {
+    use serde_derive::{Serialize, Deserialize};
+
+    #[allow(dead_code, non_camel_case_types)]
+    pub type Name_t = String;
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, PartialOrd, Eq, Ord)]
+    pub enum Color {
+        #[default]
+        RED,
+        GREEN,
+    }
+
+    #[allow(non_camel_case_types)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct ColorError;
+
+    impl std::str::FromStr for Color {
+        type Err = ColorError;
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            match value {
+                "RED" => Ok(Color::RED),
+                "GREEN" => Ok(Color::GREEN),
+                _ => Err(ColorError),
+            }
+        }
+    }
+
+    impl std::fmt::Display for Color {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let enum_str = match self {
+                    Color::RED => "RED",
+                    Color::GREEN => "GREEN",
+            };
+            write!(f, "{enum_str}")
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Color {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Color {
+        const TYPE_NAME: &'static str = "Bounds::Color";
+        const REPOSITORY_ID: &'static str = "IDL:Bounds/Color:1.0";
+    }
+
+    impl omg_cdr::CdrEncode for Color {
+        const PRIMITIVE: bool = true;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let value: u32 = match self {
+                Color::RED => 0,
+                Color::GREEN => 1,
+            };
+            omg_cdr::CdrEncode::encode(&value, writer);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Color {
+        const PRIMITIVE: bool = true;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            match <u32 as omg_cdr::CdrDecode>::decode(reader)? {
+                0 => Ok(Color::RED),
+                1 => Ok(Color::GREEN),
+                value => Err(omg_cdr::CdrError::InvalidEnum(value)),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Color {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(4);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(4);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, PartialOrd, Eq, Ord)]
+    pub enum Size {
+        #[default]
+        SMALL,
+        LARGE,
+    }
+
+    #[allow(non_camel_case_types)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct SizeError;
+
+    impl std::str::FromStr for Size {
+        type Err = SizeError;
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            match value {
+                "SMALL" => Ok(Size::SMALL),
+                "LARGE" => Ok(Size::LARGE),
+                _ => Err(SizeError),
+            }
+        }
+    }
+
+    impl std::fmt::Display for Size {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let enum_str = match self {
+                    Size::SMALL => "SMALL",
+                    Size::LARGE => "LARGE",
+            };
+            write!(f, "{enum_str}")
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Size {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Size {
+        const TYPE_NAME: &'static str = "Bounds::Size";
+        const REPOSITORY_ID: &'static str = "IDL:Bounds/Size:1.0";
+    }
+
+    impl omg_cdr::CdrEncode for Size {
+        const PRIMITIVE: bool = true;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let value: u8 = match self {
+                Size::SMALL => 0,
+                Size::LARGE => 1,
+            };
+            omg_cdr::CdrEncode::encode(&value, writer);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Size {
+        const PRIMITIVE: bool = true;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            match <u8 as omg_cdr::CdrDecode>::decode(reader)? {
+                0 => Ok(Size::SMALL),
+                1 => Ok(Size::LARGE),
+                value => Err(omg_cdr::CdrError::InvalidEnum(value.into())),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Size {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(1);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(1);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Point {
+        #[allow(non_snake_case)]
+        pub tag: u8,
+        #[allow(non_snake_case)]
+        pub x: f64,
+    }
+
+    #[allow(dead_code)]
+    impl Point {
+
+        pub fn new(tag: u8, x: f64, ) -> Self {
+            Self {
+                tag,
+                x,
+            }
+        }
+
+        pub fn tag(&self) -> &u8 {
+            &self.tag
+        }
+
+        pub fn set_tag(&mut self, value: u8) {
+            self.tag = value;
+        }
+
+        pub fn x(&self) -> &f64 {
+            &self.x
+        }
+
+        pub fn set_x(&mut self, value: f64) {
+            self.x = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Point {
+        fn default() -> Self {
+            Self {
+                tag: 0,
+                x: 0.0,
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Point {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Final;
+    }
+
+    impl omg_idl_rt::TypeName for Point {
+        const TYPE_NAME: &'static str = "Bounds::Point";
+        const REPOSITORY_ID: &'static str = "IDL:Bounds/Point:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Point {
+        /// Member ID of `tag`
+        pub const TAG_MEMBER_ID: u32 = 0;
+        /// Member ID of `x`
+        pub const X_MEMBER_ID: u32 = 1;
+    }
+
+    impl omg_cdr::CdrEncode for Point {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Point {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Point {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.tag, writer);
+            omg_cdr::CdrEncode::encode(&self.x, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                tag: omg_cdr::CdrDecode::decode(reader)?,
+                x: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Point {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(16);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(12);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Label {
+        #[allow(non_snake_case)]
+        pub name: Name_t,
+        #[allow(non_snake_case)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub note: Option<String>,
+        #[allow(non_snake_case)]
+        pub points: Vec<Point>,
+        #[allow(non_snake_case)]
+        pub color: Color,
+    }
+
+    #[allow(dead_code)]
+    impl Label {
+
+        pub fn new(name: Name_t, points: Vec<Point>, color: Color, ) -> Self {
+            Self {
+                name,
+                note: None,
+                points,
+                color,
+            }
+        }
+
+        pub fn name(&self) -> &Name_t {
+            &self.name
+        }
+
+        pub fn set_name(&mut self, value: Name_t) {
+            self.name = value;
+        }
+
+        pub fn note(&self) -> Option<&String> {
+            self.note.as_ref()
+        }
+
+        pub fn set_note(&mut self, value: Option<String>) {
+            self.note = value;
+        }
+
+        pub fn points(&self) -> &Vec<Point> {
+            &self.points
+        }
+
+        pub fn set_points(&mut self, value: Vec<Point>) {
+            self.points = value;
+        }
+
+        pub fn color(&self) -> &Color {
+            &self.color
+        }
+
+        pub fn set_color(&mut self, value: Color) {
+            self.color = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Label {
+        fn default() -> Self {
+            Self {
+                name: String::new(),
+                note: None,
+                points: Vec::new(),
+                color: Default::default(),
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Label {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Label {
+        const TYPE_NAME: &'static str = "Bounds::Label";
+        const REPOSITORY_ID: &'static str = "IDL:Bounds/Label:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Label {
+        /// Member ID of `name`
+        pub const NAME_MEMBER_ID: u32 = 0;
+        /// Member ID of `note`
+        pub const NOTE_MEMBER_ID: u32 = 1;
+        /// Member ID of `points`
+        pub const POINTS_MEMBER_ID: u32 = 2;
+        /// Member ID of `color`
+        pub const COLOR_MEMBER_ID: u32 = 3;
+    }
+
+    impl omg_cdr::CdrEncode for Label {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Label {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Label {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.name, writer);
+            writer.write_optional(Self::NOTE_MEMBER_ID, &self.note, <omg_cdr::WideString as omg_cdr::Codec<_>>::encode);
+            omg_cdr::CdrEncode::encode(&self.points, writer);
+            omg_cdr::CdrEncode::encode(&self.color, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                name: omg_cdr::CdrDecode::decode(reader)?,
+                note: reader.read_optional(Self::NOTE_MEMBER_ID, <omg_cdr::WideString as omg_cdr::Codec<_>>::decode)?,
+                points: omg_cdr::CdrDecode::decode(reader)?,
+                color: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Label {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(68);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(68);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Entry {
+        #[allow(non_snake_case)]
+        pub id: i32,
+        #[allow(non_snake_case)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub label: Option<Label>,
+        #[allow(non_snake_case)]
+        pub values: Vec<i16>,
+    }
+
+    #[allow(dead_code)]
+    impl Entry {
+
+        pub fn new(id: i32, values: Vec<i16>, ) -> Self {
+            Self {
+                id,
+                label: None,
+                values,
+            }
+        }
+
+        pub fn id(&self) -> &i32 {
+            &self.id
+        }
+
+        pub fn set_id(&mut self, value: i32) {
+            self.id = value;
+        }
+
+        pub fn label(&self) -> Option<&Label> {
+            self.label.as_ref()
+        }
+
+        pub fn set_label(&mut self, value: Option<Label>) {
+            self.label = value;
+        }
+
+        pub fn values(&self) -> &Vec<i16> {
+            &self.values
+        }
+
+        pub fn set_values(&mut self, value: Vec<i16>) {
+            self.values = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Entry {
+        fn default() -> Self {
+            Self {
+                id: 0,
+                label: None,
+                values: Vec::new(),
+            }
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug)]
+    pub struct EntryKey {
+        pub id: i32,
+    }
+
+    impl omg_idl_rt::KeyHashEncode for EntryKey {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<i32>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.id, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+        }
+    }
+
+    impl PartialEq for EntryKey {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other).is_eq()
+        }
+    }
+
+    impl Eq for EntryKey {}
+
+    impl PartialOrd for EntryKey {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for EntryKey {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other)
+        }
+    }
+
+    impl std::hash::Hash for EntryKey {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            omg_idl_rt::hash_key(self, state);
+        }
+    }
+
+    impl omg_idl_rt::Keyed for Entry {
+        type Key = EntryKey;
+
+        fn key(&self) -> Self::Key {
+            EntryKey {
+                id: self.id,
+            }
+        }
+    }
+
+    /// Equal if the key members are equal, other members are ignored
+    impl PartialEq for Entry {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+                .is_eq()
+        }
+    }
+
+    impl Eq for Entry {}
+
+    impl PartialOrd for Entry {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    /// Orders by the key members, other members are ignored
+    impl Ord for Entry {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+        }
+    }
+
+    /// Hashes the key members only, consistent with `PartialEq`
+    impl std::hash::Hash for Entry {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            let mut writer = omg_idl_rt::KeyHolderWriter::hashing(state);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.id, &mut writer);
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Entry {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Mutable;
+    }
+
+    impl omg_idl_rt::TypeName for Entry {
+        const TYPE_NAME: &'static str = "Bounds::Entry";
+        const REPOSITORY_ID: &'static str = "IDL:Bounds/Entry:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Entry {
+        /// Member ID of `id`
+        pub const ID_MEMBER_ID: u32 = 0;
+        /// Member ID of `label`
+        pub const LABEL_MEMBER_ID: u32 = 1;
+        /// Member ID of `values`
+        pub const VALUES_MEMBER_ID: u32 = 2;
+    }
+
+    impl omg_cdr::CdrEncode for Entry {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Entry {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Entry {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_member(Self::ID_MEMBER_ID, true, &self.id, omg_cdr::CdrEncode::encode);
+            if let Some(value) = &self.label {
+                writer.write_member(Self::LABEL_MEMBER_ID, false, value, omg_cdr::CdrEncode::encode);
+            }
+            writer.write_member(Self::VALUES_MEMBER_ID, false, &self.values, omg_cdr::CdrEncode::encode);
+        }
+
+        fn decode_member(&mut self, id: u32, reader: &mut omg_cdr::CdrReader<'_>) -> Result<bool, omg_cdr::CdrError> {
+            match id {
+                Self::ID_MEMBER_ID => self.id = omg_cdr::CdrDecode::decode(reader)?,
+                Self::LABEL_MEMBER_ID => self.label = Some(omg_cdr::CdrDecode::decode(reader)?),
+                Self::VALUES_MEMBER_ID => self.values = omg_cdr::CdrDecode::decode(reader)?,
+                _ => return Ok(false),
+            }
+            Ok(true)
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Entry {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(100);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(106);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub enum Choice {
+        SMALL{ value: f64, },
+        LARGE{ name: Name_t, },
+    }
+
+    impl Default for Choice {
+        fn default() -> Self {
+            Choice::SMALL { value: 0.0 }
+        }
+    }
+//
+// TODO custom de-/serializer
+//
+
+    impl omg_idl_rt::Extensible for Choice {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Final;
+    }
+
+    impl omg_idl_rt::TypeName for Choice {
+        const TYPE_NAME: &'static str = "Bounds::Choice";
+        const REPOSITORY_ID: &'static str = "IDL:Bounds/Choice:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Choice {
+        /// Member ID of `value`
+        pub const VALUE_MEMBER_ID: u32 = 1;
+        /// Member ID of `name`
+        pub const NAME_MEMBER_ID: u32 = 2;
+    }
+
+    impl omg_cdr::CdrEncode for Choice {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            match self {
+                Choice::SMALL { value } => writer.write_union(extensibility, &Size::SMALL, Self::VALUE_MEMBER_ID, value, omg_cdr::CdrEncode::encode),
+                Choice::LARGE { name } => writer.write_union(extensibility, &Size::LARGE, Self::NAME_MEMBER_ID, name, omg_cdr::CdrEncode::encode),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Choice {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            reader.read_union(extensibility, |discriminator: Size, reader| {
+                Ok(match discriminator {
+                    Size::SMALL => Choice::SMALL { value: reader.read_branch(extensibility, Self::VALUE_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    Size::LARGE => Choice::LARGE { name: reader.read_branch(extensibility, Self::NAME_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    #[allow(unreachable_patterns)]
+                    _ => return Err(omg_cdr::CdrError::InvalidDiscriminator),
+                })
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Choice {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(17);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(17);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub enum Shape {
+        CIRCLE{ center: Point, },
+        SQUARE{ side: i64, },
+    }
+
+    impl Default for Shape {
+        fn default() -> Self {
+            Shape::CIRCLE { center: Default::default() }
+        }
+    }
+//
+// TODO custom de-/serializer
+//
+
+    impl omg_idl_rt::Extensible for Shape {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Mutable;
+    }
+
+    impl omg_idl_rt::TypeName for Shape {
+        const TYPE_NAME: &'static str = "Bounds::Shape";
+        const REPOSITORY_ID: &'static str = "IDL:Bounds/Shape:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Shape {
+        /// Member ID of `center`
+        pub const CENTER_MEMBER_ID: u32 = 1;
+        /// Member ID of `side`
+        pub const SIDE_MEMBER_ID: u32 = 2;
+    }
+
+    impl omg_cdr::CdrEncode for Shape {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            match self {
+                Shape::CIRCLE { center } => writer.write_union(extensibility, &1_i16, Self::CENTER_MEMBER_ID, center, omg_cdr::CdrEncode::encode),
+                Shape::SQUARE { side } => writer.write_union(extensibility, &2_i16, Self::SIDE_MEMBER_ID, side, omg_cdr::CdrEncode::encode),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Shape {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            reader.read_union(extensibility, |discriminator: i16, reader| {
+                Ok(match discriminator {
+                    1_i16 => Shape::CIRCLE { center: reader.read_branch(extensibility, Self::CENTER_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    2_i16 => Shape::SQUARE { side: reader.read_branch(extensibility, Self::SIDE_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    #[allow(unreachable_patterns)]
+                    _ => return Err(omg_cdr::CdrError::InvalidDiscriminator),
+                })
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Shape {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(32);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(32);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Grid {
+        #[allow(non_snake_case)]
+        #[serde(with = "serde_arrays")]
+        pub cells: [Point;3_usize],
+        #[allow(non_snake_case)]
+        #[serde(with = "serde_arrays")]
+        pub flags: [char;3_usize],
+    }
+
+    #[allow(dead_code)]
+    impl Grid {
+
+        pub fn new(cells: [Point;3_usize], flags: [char;3_usize], ) -> Self {
+            Self {
+                cells,
+                flags,
+            }
+        }
+
+        pub fn cells(&self) -> &[Point;3_usize] {
+            &self.cells
+        }
+
+        pub fn set_cells(&mut self, value: [Point;3_usize]) {
+            self.cells = value;
+        }
+
+        pub fn flags(&self) -> &[char;3_usize] {
+            &self.flags
+        }
+
+        pub fn set_flags(&mut self, value: [char;3_usize]) {
+            self.flags = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Grid {
+        fn default() -> Self {
+            Self {
+                cells: std::array::from_fn(|_| Default::default()),
+                flags: std::array::from_fn(|_| '\0'),
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Grid {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Final;
+    }
+
+    impl omg_idl_rt::TypeName for Grid {
+        const TYPE_NAME: &'static str = "Bounds::Grid";
+        const REPOSITORY_ID: &'static str = "IDL:Bounds/Grid:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Grid {
+        /// Member ID of `cells`
+        pub const CELLS_MEMBER_ID: u32 = 0;
+        /// Member ID of `flags`
+        pub const FLAGS_MEMBER_ID: u32 = 1;
+    }
+
+    impl omg_cdr::CdrEncode for Grid {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Grid {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Grid {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.cells, writer);
+            omg_cdr::CdrEncode::encode(&self.flags, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                cells: omg_cdr::CdrDecode::decode(reader)?,
+                flags: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Grid {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(51);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(43);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Unbounded {
+        #[allow(non_snake_case)]
+        pub text: String,
+        #[allow(non_snake_case)]
+        pub values: Vec<i32>,
+    }
+
+    #[allow(dead_code)]
+    impl Unbounded {
+
+        pub fn new(text: String, values: Vec<i32>, ) -> Self {
+            Self {
+                text,
+                values,
+            }
+        }
+
+        pub fn text(&self) -> &String {
+            &self.text
+        }
+
+        pub fn set_text(&mut self, value: String) {
+            self.text = value;
+        }
+
+        pub fn values(&self) -> &Vec<i32> {
+            &self.values
+        }
+
+        pub fn set_values(&mut self, value: Vec<i32>) {
+            self.values = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Unbounded {
+        fn default() -> Self {
+            Self {
+                text: String::new(),
+                values: Vec::new(),
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Unbounded {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Unbounded {
+        const TYPE_NAME: &'static str = "Bounds::Unbounded";
+        const REPOSITORY_ID: &'static str = "IDL:Bounds/Unbounded:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Unbounded {
+        /// Member ID of `text`
+        pub const TEXT_MEMBER_ID: u32 = 0;
+        /// Member ID of `values`
+        pub const VALUES_MEMBER_ID: u32 = 1;
+    }
+
+    impl omg_cdr::CdrEncode for Unbounded {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Unbounded {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Unbounded {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.text, writer);
+            omg_cdr::CdrEncode::encode(&self.values, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                text: omg_cdr::CdrDecode::decode(reader)?,
+                values: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Unbounded {
+        const IS_BOUNDED: bool = false;
+        const MAX_XCDR1_SIZE: Option<usize> = None;
+        const MAX_XCDR2_SIZE: Option<usize> = None;
+    }
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const NAME_LENGTH: i32 = 8;
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const CIRCLE: i16 = 1;
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const SQUARE: i16 = 2;
+
+}
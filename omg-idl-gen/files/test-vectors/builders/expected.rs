@@ -0,0 +1,849 @@
+
+#[allow(non_snake_case)]
+pub mod Qos {
+    use serde_derive::{Serialize, Deserialize};
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, PartialOrd, Eq, Ord)]
+    pub enum HistoryKind {
+        #[default]
+        KEEP_LAST,
+        KEEP_ALL,
+    }
+
+    #[allow(non_camel_case_types)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct HistoryKindError;
+
+    impl std::str::FromStr for HistoryKind {
+        type Err = HistoryKindError;
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            match value {
+                "KEEP_LAST" => Ok(HistoryKind::KEEP_LAST),
+                "KEEP_ALL" => Ok(HistoryKind::KEEP_ALL),
+                _ => Err(HistoryKindError),
+            }
+        }
+    }
+
+    impl std::fmt::Display for HistoryKind {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let enum_str = match self {
+                    HistoryKind::KEEP_LAST => "KEEP_LAST",
+                    HistoryKind::KEEP_ALL => "KEEP_ALL",
+            };
+            write!(f, "{enum_str}")
+        }
+    }
+
+    impl omg_idl_rt::Extensible for HistoryKind {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for HistoryKind {
+        const TYPE_NAME: &'static str = "Qos::HistoryKind";
+        const REPOSITORY_ID: &'static str = "IDL:Qos/HistoryKind:1.0";
+    }
+
+    impl omg_cdr::CdrEncode for HistoryKind {
+        const PRIMITIVE: bool = true;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let value: u32 = match self {
+                HistoryKind::KEEP_LAST => 0,
+                HistoryKind::KEEP_ALL => 1,
+            };
+            omg_cdr::CdrEncode::encode(&value, writer);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for HistoryKind {
+        const PRIMITIVE: bool = true;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            match <u32 as omg_cdr::CdrDecode>::decode(reader)? {
+                0 => Ok(HistoryKind::KEEP_LAST),
+                1 => Ok(HistoryKind::KEEP_ALL),
+                value => Err(omg_cdr::CdrError::InvalidEnum(value)),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrBounds for HistoryKind {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(4);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(4);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, PartialOrd, Eq, Ord)]
+    pub enum ReliabilityKind {
+        #[default]
+        BEST_EFFORT,
+        RELIABLE,
+    }
+
+    #[allow(non_camel_case_types)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct ReliabilityKindError;
+
+    impl std::str::FromStr for ReliabilityKind {
+        type Err = ReliabilityKindError;
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            match value {
+                "BEST_EFFORT" => Ok(ReliabilityKind::BEST_EFFORT),
+                "RELIABLE" => Ok(ReliabilityKind::RELIABLE),
+                _ => Err(ReliabilityKindError),
+            }
+        }
+    }
+
+    impl std::fmt::Display for ReliabilityKind {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let enum_str = match self {
+                    ReliabilityKind::BEST_EFFORT => "BEST_EFFORT",
+                    ReliabilityKind::RELIABLE => "RELIABLE",
+            };
+            write!(f, "{enum_str}")
+        }
+    }
+
+    impl omg_idl_rt::Extensible for ReliabilityKind {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for ReliabilityKind {
+        const TYPE_NAME: &'static str = "Qos::ReliabilityKind";
+        const REPOSITORY_ID: &'static str = "IDL:Qos/ReliabilityKind:1.0";
+    }
+
+    impl omg_cdr::CdrEncode for ReliabilityKind {
+        const PRIMITIVE: bool = true;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let value: u32 = match self {
+                ReliabilityKind::BEST_EFFORT => 0,
+                ReliabilityKind::RELIABLE => 1,
+            };
+            omg_cdr::CdrEncode::encode(&value, writer);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for ReliabilityKind {
+        const PRIMITIVE: bool = true;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            match <u32 as omg_cdr::CdrDecode>::decode(reader)? {
+                0 => Ok(ReliabilityKind::BEST_EFFORT),
+                1 => Ok(ReliabilityKind::RELIABLE),
+                value => Err(omg_cdr::CdrError::InvalidEnum(value)),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrBounds for ReliabilityKind {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(4);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(4);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Duration_t {
+        #[allow(non_snake_case)]
+        pub sec: i32,
+        #[allow(non_snake_case)]
+        pub nanosec: u32,
+    }
+
+    #[allow(dead_code)]
+    impl Duration_t {
+
+        pub fn new(sec: i32, nanosec: u32, ) -> Self {
+            Self {
+                sec,
+                nanosec,
+            }
+        }
+
+        pub fn builder() -> Duration_tBuilder {
+            Duration_tBuilder::default()
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Duration_t {
+        fn default() -> Self {
+            Self {
+                sec: 0,
+                nanosec: 0,
+            }
+        }
+    }
+
+    /// Builder of `Duration_t`, members not set take their default value
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug, Default)]
+    pub struct Duration_tBuilder {
+        sec: Option<i32>,
+        nanosec: Option<u32>,
+    }
+
+    #[allow(dead_code, non_snake_case)]
+    impl Duration_tBuilder {
+        pub fn sec(mut self, value: i32) -> Self {
+            self.sec = Some(value);
+            self
+        }
+
+        pub fn nanosec(mut self, value: u32) -> Self {
+            self.nanosec = Some(value);
+            self
+        }
+
+        /// Fails if a `@key` member without `@default` is not set or a value is out of bounds
+        pub fn build(self) -> Result<Duration_t, omg_idl_rt::BuildError> {
+            let mut value = Duration_t::default();
+            if let Some(member) = self.sec {
+                value.sec = member;
+            }
+            if let Some(member) = self.nanosec {
+                value.nanosec = member;
+            }
+            Ok(value)
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Duration_t {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Duration_t {
+        const TYPE_NAME: &'static str = "Qos::Duration_t";
+        const REPOSITORY_ID: &'static str = "IDL:Qos/Duration_t:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Duration_t {
+        /// Member ID of `sec`
+        pub const SEC_MEMBER_ID: u32 = 0;
+        /// Member ID of `nanosec`
+        pub const NANOSEC_MEMBER_ID: u32 = 1;
+    }
+
+    impl omg_cdr::CdrEncode for Duration_t {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Duration_t {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Duration_t {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.sec, writer);
+            omg_cdr::CdrEncode::encode(&self.nanosec, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                sec: omg_cdr::CdrDecode::decode(reader)?,
+                nanosec: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Duration_t {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(8);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(12);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct EntityQos {
+        #[allow(non_snake_case)]
+        pub entity_id: i32,
+        #[allow(non_snake_case)]
+        pub domain_id: i32,
+    }
+
+    #[allow(dead_code)]
+    impl EntityQos {
+
+        pub fn new(entity_id: i32, domain_id: i32, ) -> Self {
+            Self {
+                entity_id,
+                domain_id,
+            }
+        }
+
+        pub fn builder() -> EntityQosBuilder {
+            EntityQosBuilder::default()
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for EntityQos {
+        fn default() -> Self {
+            Self {
+                entity_id: 0,
+                domain_id: 0,
+            }
+        }
+    }
+
+    /// Builder of `EntityQos`, members not set take their default value
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug, Default)]
+    pub struct EntityQosBuilder {
+        entity_id: Option<i32>,
+        domain_id: Option<i32>,
+    }
+
+    #[allow(dead_code, non_snake_case)]
+    impl EntityQosBuilder {
+        pub fn entity_id(mut self, value: i32) -> Self {
+            self.entity_id = Some(value);
+            self
+        }
+
+        pub fn domain_id(mut self, value: i32) -> Self {
+            self.domain_id = Some(value);
+            self
+        }
+
+        /// Fails if a `@key` member without `@default` is not set or a value is out of bounds
+        pub fn build(self) -> Result<EntityQos, omg_idl_rt::BuildError> {
+            let mut value = EntityQos::default();
+            match self.entity_id {
+                Some(member) => value.entity_id = member,
+                None => return Err(omg_idl_rt::BuildError::Missing("entity_id")),
+            }
+            if let Some(member) = self.domain_id {
+                value.domain_id = member;
+            }
+            Ok(value)
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug)]
+    pub struct EntityQosKey {
+        pub entity_id: i32,
+        pub domain_id: i32,
+    }
+
+    impl omg_idl_rt::KeyHashEncode for EntityQosKey {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<i32>().field::<i32>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.entity_id, writer);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.domain_id, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.entity_id, &other.entity_id)
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.domain_id, &other.domain_id))
+        }
+    }
+
+    impl PartialEq for EntityQosKey {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other).is_eq()
+        }
+    }
+
+    impl Eq for EntityQosKey {}
+
+    impl PartialOrd for EntityQosKey {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for EntityQosKey {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other)
+        }
+    }
+
+    impl std::hash::Hash for EntityQosKey {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            omg_idl_rt::hash_key(self, state);
+        }
+    }
+
+    impl omg_idl_rt::Keyed for EntityQos {
+        type Key = EntityQosKey;
+
+        fn key(&self) -> Self::Key {
+            EntityQosKey {
+                entity_id: self.entity_id,
+                domain_id: self.domain_id,
+            }
+        }
+    }
+
+    /// Equal if the key members are equal, other members are ignored
+    impl PartialEq for EntityQos {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.entity_id, &other.entity_id)
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.domain_id, &other.domain_id))
+                .is_eq()
+        }
+    }
+
+    impl Eq for EntityQos {}
+
+    impl PartialOrd for EntityQos {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    /// Orders by the key members, other members are ignored
+    impl Ord for EntityQos {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.entity_id, &other.entity_id)
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.domain_id, &other.domain_id))
+        }
+    }
+
+    /// Hashes the key members only, consistent with `PartialEq`
+    impl std::hash::Hash for EntityQos {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            let mut writer = omg_idl_rt::KeyHolderWriter::hashing(state);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.entity_id, &mut writer);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.domain_id, &mut writer);
+        }
+    }
+
+    impl omg_idl_rt::KeyHashEncode for EntityQos {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<i32>().field::<i32>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.entity_id, writer);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.domain_id, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.entity_id, &other.entity_id)
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.domain_id, &other.domain_id))
+        }
+    }
+
+    impl omg_idl_rt::Extensible for EntityQos {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for EntityQos {
+        const TYPE_NAME: &'static str = "Qos::EntityQos";
+        const REPOSITORY_ID: &'static str = "IDL:Qos/EntityQos:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl EntityQos {
+        /// Member ID of `entity_id`
+        pub const ENTITY_ID_MEMBER_ID: u32 = 0;
+        /// Member ID of `domain_id`
+        pub const DOMAIN_ID_MEMBER_ID: u32 = 1;
+    }
+
+    impl omg_cdr::CdrEncode for EntityQos {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for EntityQos {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for EntityQos {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.entity_id, writer);
+            omg_cdr::CdrEncode::encode(&self.domain_id, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                entity_id: omg_cdr::CdrDecode::decode(reader)?,
+                domain_id: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for EntityQos {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(8);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(12);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct DataWriterQos {
+        #[serde(flatten)]
+        pub base: EntityQos,
+        #[allow(non_snake_case)]
+        pub history_kind: HistoryKind,
+        #[allow(non_snake_case)]
+        pub history_depth: i32,
+        #[allow(non_snake_case)]
+        pub reliability_kind: ReliabilityKind,
+        #[allow(non_snake_case)]
+        pub max_blocking_time: Duration_t,
+        #[allow(non_snake_case)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub deadline: Option<Duration_t>,
+        #[allow(non_snake_case)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub ownership_strength: Option<i32>,
+        #[allow(non_snake_case)]
+        pub user_data: String,
+        #[allow(non_snake_case)]
+        pub topic_data: Vec<u8>,
+        #[allow(non_snake_case)]
+        pub autodispose_unregistered_instances: bool,
+    }
+
+    #[allow(dead_code)]
+    impl DataWriterQos {
+        #[allow(clippy::too_many_arguments)]
+        pub fn new(base: EntityQos, history_kind: HistoryKind, history_depth: i32, reliability_kind: ReliabilityKind, max_blocking_time: Duration_t, user_data: String, topic_data: Vec<u8>, autodispose_unregistered_instances: bool, ) -> Self {
+            Self {
+                base,
+                history_kind,
+                history_depth,
+                reliability_kind,
+                max_blocking_time,
+                deadline: None,
+                ownership_strength: None,
+                user_data,
+                topic_data,
+                autodispose_unregistered_instances,
+            }
+        }
+
+        pub fn builder() -> DataWriterQosBuilder {
+            DataWriterQosBuilder::default()
+        }
+
+        fn validate_history_depth(value: &i32) -> Result<(), omg_idl_rt::ValidationError> {
+            if *value < 1 {
+                return Err(omg_idl_rt::ValidationError::new("history_depth", omg_idl_rt::Bound::Min, "1"));
+            }
+            if *value > 1000 {
+                return Err(omg_idl_rt::ValidationError::new("history_depth", omg_idl_rt::Bound::Max, "1000"));
+            }
+            Ok(())
+        }
+
+        /// Check all members against their `@range`, `@min` and `@max` bounds
+        pub fn validate(&self) -> Result<(), omg_idl_rt::ValidationError> {
+            Self::validate_history_depth(&self.history_depth)?;
+            Ok(())
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for DataWriterQos {
+        fn default() -> Self {
+            Self {
+                base: Default::default(),
+                history_kind: HistoryKind::KEEP_ALL,
+                history_depth: 1,
+                reliability_kind: Default::default(),
+                max_blocking_time: Default::default(),
+                deadline: None,
+                ownership_strength: Some(10),
+                user_data: String::new(),
+                topic_data: Vec::new(),
+                autodispose_unregistered_instances: false,
+            }
+        }
+    }
+
+    /// Builder of `DataWriterQos`, members not set take their default value
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug, Default)]
+    pub struct DataWriterQosBuilder {
+        base: Option<EntityQos>,
+        history_kind: Option<HistoryKind>,
+        history_depth: Option<i32>,
+        reliability_kind: Option<ReliabilityKind>,
+        max_blocking_time: Option<Duration_t>,
+        deadline: Option<Duration_t>,
+        ownership_strength: Option<i32>,
+        user_data: Option<String>,
+        topic_data: Option<Vec<u8>>,
+        autodispose_unregistered_instances: Option<bool>,
+    }
+
+    #[allow(dead_code, non_snake_case)]
+    impl DataWriterQosBuilder {
+        pub fn base(mut self, value: EntityQos) -> Self {
+            self.base = Some(value);
+            self
+        }
+
+        pub fn history_kind(mut self, value: HistoryKind) -> Self {
+            self.history_kind = Some(value);
+            self
+        }
+
+        pub fn history_depth(mut self, value: i32) -> Self {
+            self.history_depth = Some(value);
+            self
+        }
+
+        pub fn reliability_kind(mut self, value: ReliabilityKind) -> Self {
+            self.reliability_kind = Some(value);
+            self
+        }
+
+        pub fn max_blocking_time(mut self, value: Duration_t) -> Self {
+            self.max_blocking_time = Some(value);
+            self
+        }
+
+        pub fn deadline(mut self, value: Duration_t) -> Self {
+            self.deadline = Some(value);
+            self
+        }
+
+        pub fn ownership_strength(mut self, value: i32) -> Self {
+            self.ownership_strength = Some(value);
+            self
+        }
+
+        pub fn user_data(mut self, value: String) -> Self {
+            self.user_data = Some(value);
+            self
+        }
+
+        pub fn topic_data(mut self, value: Vec<u8>) -> Self {
+            self.topic_data = Some(value);
+            self
+        }
+
+        pub fn autodispose_unregistered_instances(mut self, value: bool) -> Self {
+            self.autodispose_unregistered_instances = Some(value);
+            self
+        }
+
+        /// Fails if a `@key` member without `@default` is not set or a value is out of bounds
+        pub fn build(self) -> Result<DataWriterQos, omg_idl_rt::BuildError> {
+            let mut value = DataWriterQos::default();
+            if let Some(member) = self.base {
+                value.base = member;
+            }
+            if let Some(member) = self.history_kind {
+                value.history_kind = member;
+            }
+            if let Some(member) = self.history_depth {
+                value.history_depth = member;
+            }
+            if let Some(member) = self.reliability_kind {
+                value.reliability_kind = member;
+            }
+            if let Some(member) = self.max_blocking_time {
+                value.max_blocking_time = member;
+            }
+            if let Some(member) = self.deadline {
+                value.deadline = Some(member);
+            }
+            if let Some(member) = self.ownership_strength {
+                value.ownership_strength = Some(member);
+            }
+            if let Some(member) = self.user_data {
+                value.user_data = member;
+            }
+            if let Some(member) = self.topic_data {
+                value.topic_data = member;
+            }
+            if let Some(member) = self.autodispose_unregistered_instances {
+                value.autodispose_unregistered_instances = member;
+            }
+            value.validate()?;
+            Ok(value)
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug)]
+    pub struct DataWriterQosKey {
+        pub base: EntityQos,
+    }
+
+    impl omg_idl_rt::KeyHashEncode for DataWriterQosKey {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<EntityQos>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.base, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.base, &other.base)
+        }
+    }
+
+    impl PartialEq for DataWriterQosKey {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other).is_eq()
+        }
+    }
+
+    impl Eq for DataWriterQosKey {}
+
+    impl PartialOrd for DataWriterQosKey {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for DataWriterQosKey {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other)
+        }
+    }
+
+    impl std::hash::Hash for DataWriterQosKey {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            omg_idl_rt::hash_key(self, state);
+        }
+    }
+
+    impl omg_idl_rt::Keyed for DataWriterQos {
+        type Key = DataWriterQosKey;
+
+        fn key(&self) -> Self::Key {
+            DataWriterQosKey {
+                base: self.base.clone(),
+            }
+        }
+    }
+
+    /// Equal if the key members are equal, other members are ignored
+    impl PartialEq for DataWriterQos {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.base, &other.base)
+                .is_eq()
+        }
+    }
+
+    impl Eq for DataWriterQos {}
+
+    impl PartialOrd for DataWriterQos {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    /// Orders by the key members, other members are ignored
+    impl Ord for DataWriterQos {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.base, &other.base)
+        }
+    }
+
+    /// Hashes the key members only, consistent with `PartialEq`
+    impl std::hash::Hash for DataWriterQos {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            let mut writer = omg_idl_rt::KeyHolderWriter::hashing(state);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.base, &mut writer);
+        }
+    }
+
+    impl omg_idl_rt::Extensible for DataWriterQos {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for DataWriterQos {
+        const TYPE_NAME: &'static str = "Qos::DataWriterQos";
+        const REPOSITORY_ID: &'static str = "IDL:Qos/DataWriterQos:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl DataWriterQos {
+        /// Member ID of `history_kind`
+        pub const HISTORY_KIND_MEMBER_ID: u32 = 2;
+        /// Member ID of `history_depth`
+        pub const HISTORY_DEPTH_MEMBER_ID: u32 = 3;
+        /// Member ID of `reliability_kind`
+        pub const RELIABILITY_KIND_MEMBER_ID: u32 = 4;
+        /// Member ID of `max_blocking_time`
+        pub const MAX_BLOCKING_TIME_MEMBER_ID: u32 = 5;
+        /// Member ID of `deadline`
+        pub const DEADLINE_MEMBER_ID: u32 = 6;
+        /// Member ID of `ownership_strength`
+        pub const OWNERSHIP_STRENGTH_MEMBER_ID: u32 = 7;
+        /// Member ID of `user_data`
+        pub const USER_DATA_MEMBER_ID: u32 = 8;
+        /// Member ID of `topic_data`
+        pub const TOPIC_DATA_MEMBER_ID: u32 = 9;
+        /// Member ID of `autodispose_unregistered_instances`
+        pub const AUTODISPOSE_UNREGISTERED_INSTANCES_MEMBER_ID: u32 = 10;
+    }
+
+    impl omg_cdr::CdrEncode for DataWriterQos {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for DataWriterQos {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for DataWriterQos {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrStruct::encode_members(&self.base, writer);
+            omg_cdr::CdrEncode::encode(&self.history_kind, writer);
+            omg_cdr::CdrEncode::encode(&self.history_depth, writer);
+            omg_cdr::CdrEncode::encode(&self.reliability_kind, writer);
+            omg_cdr::CdrEncode::encode(&self.max_blocking_time, writer);
+            writer.write_optional(Self::DEADLINE_MEMBER_ID, &self.deadline, omg_cdr::CdrEncode::encode);
+            writer.write_optional(Self::OWNERSHIP_STRENGTH_MEMBER_ID, &self.ownership_strength, omg_cdr::CdrEncode::encode);
+            omg_cdr::CdrEncode::encode(&self.user_data, writer);
+            omg_cdr::CdrEncode::encode(&self.topic_data, writer);
+            omg_cdr::CdrEncode::encode(&self.autodispose_unregistered_instances, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                base: omg_cdr::CdrStruct::decode_members(reader)?,
+                history_kind: omg_cdr::CdrDecode::decode(reader)?,
+                history_depth: omg_cdr::CdrDecode::decode(reader)?,
+                reliability_kind: omg_cdr::CdrDecode::decode(reader)?,
+                max_blocking_time: omg_cdr::CdrDecode::decode(reader)?,
+                deadline: reader.read_optional(Self::DEADLINE_MEMBER_ID, omg_cdr::CdrDecode::decode)?,
+                ownership_strength: reader.read_optional(Self::OWNERSHIP_STRENGTH_MEMBER_ID, omg_cdr::CdrDecode::decode)?,
+                user_data: omg_cdr::CdrDecode::decode(reader)?,
+                topic_data: omg_cdr::CdrDecode::decode(reader)?,
+                autodispose_unregistered_instances: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for DataWriterQos {
+        const IS_BOUNDED: bool = false;
+        const MAX_XCDR1_SIZE: Option<usize> = None;
+        const MAX_XCDR2_SIZE: Option<usize> = None;
+    }
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const MAX_DEPTH: i32 = 1000;
+
+}
@@ -0,0 +1,734 @@
+
+#[allow(non_snake_case)]
+pub mod RTPS {
+    use serde_derive::{Serialize, Deserialize};
+    use std::vec::Vec;
+
+    #[allow(dead_code, non_camel_case_types)]
+    pub type OctetSeq = omg_idl_rt::ByteBuf;
+
+    #[allow(dead_code, non_camel_case_types)]
+    pub type GuidPrefix_t = [u8;12_usize];
+
+    #[allow(dead_code, non_camel_case_types)]
+    pub type ShortPayload = omg_idl_rt::ByteBuf;
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Guid_t {
+        #[allow(non_snake_case)]
+        #[serde(with = "serde_bytes")]
+        pub prefix: GuidPrefix_t,
+        #[allow(non_snake_case)]
+        #[serde(with = "serde_bytes")]
+        pub entityId: [u8;4_usize],
+    }
+
+    #[allow(dead_code)]
+    impl Guid_t {
+
+        pub fn new(prefix: GuidPrefix_t, entityId: [u8;4_usize], ) -> Self {
+            Self {
+                prefix,
+                entityId,
+            }
+        }
+
+        pub fn prefix(&self) -> &GuidPrefix_t {
+            &self.prefix
+        }
+
+        pub fn set_prefix(&mut self, value: GuidPrefix_t) {
+            self.prefix = value;
+        }
+
+        pub fn entityId(&self) -> &[u8;4_usize] {
+            &self.entityId
+        }
+
+        pub fn set_entityId(&mut self, value: [u8;4_usize]) {
+            self.entityId = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Guid_t {
+        fn default() -> Self {
+            Self {
+                prefix: std::array::from_fn(|_| 0),
+                entityId: std::array::from_fn(|_| 0),
+            }
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug)]
+    pub struct Guid_tKey {
+        pub prefix: GuidPrefix_t,
+        pub entityId: [u8;4_usize],
+    }
+
+    impl omg_idl_rt::KeyHashEncode for Guid_tKey {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<GuidPrefix_t>().field::<[u8;4_usize]>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.prefix, writer);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.entityId, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.prefix, &other.prefix)
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.entityId, &other.entityId))
+        }
+    }
+
+    impl PartialEq for Guid_tKey {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other).is_eq()
+        }
+    }
+
+    impl Eq for Guid_tKey {}
+
+    impl PartialOrd for Guid_tKey {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Guid_tKey {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other)
+        }
+    }
+
+    impl std::hash::Hash for Guid_tKey {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            omg_idl_rt::hash_key(self, state);
+        }
+    }
+
+    impl omg_idl_rt::Keyed for Guid_t {
+        type Key = Guid_tKey;
+
+        fn key(&self) -> Self::Key {
+            Guid_tKey {
+                prefix: self.prefix,
+                entityId: self.entityId,
+            }
+        }
+    }
+
+    /// Equal if the key members are equal, other members are ignored
+    impl PartialEq for Guid_t {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.prefix, &other.prefix)
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.entityId, &other.entityId))
+                .is_eq()
+        }
+    }
+
+    impl Eq for Guid_t {}
+
+    impl PartialOrd for Guid_t {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    /// Orders by the key members, other members are ignored
+    impl Ord for Guid_t {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.prefix, &other.prefix)
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.entityId, &other.entityId))
+        }
+    }
+
+    /// Hashes the key members only, consistent with `PartialEq`
+    impl std::hash::Hash for Guid_t {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            let mut writer = omg_idl_rt::KeyHolderWriter::hashing(state);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.prefix, &mut writer);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.entityId, &mut writer);
+        }
+    }
+
+    impl omg_idl_rt::KeyHashEncode for Guid_t {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<GuidPrefix_t>().field::<[u8;4_usize]>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.prefix, writer);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.entityId, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.prefix, &other.prefix)
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.entityId, &other.entityId))
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Guid_t {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Guid_t {
+        const TYPE_NAME: &'static str = "RTPS::Guid_t";
+        const REPOSITORY_ID: &'static str = "IDL:RTPS/Guid_t:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Guid_t {
+        /// Member ID of `prefix`
+        pub const PREFIX_MEMBER_ID: u32 = 0;
+        /// Member ID of `entityId`
+        pub const ENTITY_ID_MEMBER_ID: u32 = 1;
+    }
+
+    impl omg_cdr::CdrEncode for Guid_t {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Guid_t {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Guid_t {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.prefix, writer);
+            omg_cdr::CdrEncode::encode(&self.entityId, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                prefix: omg_cdr::CdrDecode::decode(reader)?,
+                entityId: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Guid_t {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(16);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(20);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Data {
+        #[allow(non_snake_case)]
+        pub writerGuid: Guid_t,
+        #[allow(non_snake_case)]
+        pub serializedData: bytes::Bytes,
+        #[allow(non_snake_case)]
+        pub inlineQos: OctetSeq,
+        #[allow(non_snake_case)]
+        pub digest: ShortPayload,
+        #[allow(non_snake_case)]
+        #[serde(with = "serde_arrays")]
+        pub checksum: [u8;16_usize],
+        #[allow(non_snake_case)]
+        #[serde(with = "serde_bytes")]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub signature: Option<[u8;64_usize]>,
+    }
+
+    #[allow(dead_code)]
+    impl Data {
+
+        pub fn new(writerGuid: Guid_t, serializedData: bytes::Bytes, inlineQos: OctetSeq, digest: ShortPayload, checksum: [u8;16_usize], ) -> Self {
+            Self {
+                writerGuid,
+                serializedData,
+                inlineQos,
+                digest,
+                checksum,
+                signature: None,
+            }
+        }
+
+        pub fn writerGuid(&self) -> &Guid_t {
+            &self.writerGuid
+        }
+
+        pub fn set_writerGuid(&mut self, value: Guid_t) {
+            self.writerGuid = value;
+        }
+
+        pub fn serializedData(&self) -> &bytes::Bytes {
+            &self.serializedData
+        }
+
+        pub fn set_serializedData(&mut self, value: bytes::Bytes) {
+            self.serializedData = value;
+        }
+
+        pub fn inlineQos(&self) -> &OctetSeq {
+            &self.inlineQos
+        }
+
+        pub fn set_inlineQos(&mut self, value: OctetSeq) {
+            self.inlineQos = value;
+        }
+
+        pub fn digest(&self) -> &ShortPayload {
+            &self.digest
+        }
+
+        pub fn set_digest(&mut self, value: ShortPayload) {
+            self.digest = value;
+        }
+
+        pub fn checksum(&self) -> &[u8;16_usize] {
+            &self.checksum
+        }
+
+        pub fn set_checksum(&mut self, value: [u8;16_usize]) {
+            self.checksum = value;
+        }
+
+        pub fn signature(&self) -> Option<&[u8;64_usize]> {
+            self.signature.as_ref()
+        }
+
+        pub fn set_signature(&mut self, value: Option<[u8;64_usize]>) {
+            self.signature = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Data {
+        fn default() -> Self {
+            Self {
+                writerGuid: Default::default(),
+                serializedData: bytes::Bytes::new(),
+                inlineQos: omg_idl_rt::ByteBuf::new(),
+                digest: omg_idl_rt::ByteBuf::new(),
+                checksum: std::array::from_fn(|_| 0),
+                signature: None,
+            }
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug)]
+    pub struct DataKey {
+        pub writerGuid: Guid_t,
+    }
+
+    impl omg_idl_rt::KeyHashEncode for DataKey {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<Guid_t>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.writerGuid, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.writerGuid, &other.writerGuid)
+        }
+    }
+
+    impl PartialEq for DataKey {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other).is_eq()
+        }
+    }
+
+    impl Eq for DataKey {}
+
+    impl PartialOrd for DataKey {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for DataKey {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other)
+        }
+    }
+
+    impl std::hash::Hash for DataKey {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            omg_idl_rt::hash_key(self, state);
+        }
+    }
+
+    impl omg_idl_rt::Keyed for Data {
+        type Key = DataKey;
+
+        fn key(&self) -> Self::Key {
+            DataKey {
+                writerGuid: self.writerGuid.clone(),
+            }
+        }
+    }
+
+    /// Equal if the key members are equal, other members are ignored
+    impl PartialEq for Data {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.writerGuid, &other.writerGuid)
+                .is_eq()
+        }
+    }
+
+    impl Eq for Data {}
+
+    impl PartialOrd for Data {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    /// Orders by the key members, other members are ignored
+    impl Ord for Data {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.writerGuid, &other.writerGuid)
+        }
+    }
+
+    /// Hashes the key members only, consistent with `PartialEq`
+    impl std::hash::Hash for Data {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            let mut writer = omg_idl_rt::KeyHolderWriter::hashing(state);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.writerGuid, &mut writer);
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Data {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Data {
+        const TYPE_NAME: &'static str = "RTPS::Data";
+        const REPOSITORY_ID: &'static str = "IDL:RTPS/Data:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Data {
+        /// Member ID of `writerGuid`
+        pub const WRITER_GUID_MEMBER_ID: u32 = 0;
+        /// Member ID of `serializedData`
+        pub const SERIALIZED_DATA_MEMBER_ID: u32 = 1;
+        /// Member ID of `inlineQos`
+        pub const INLINE_QOS_MEMBER_ID: u32 = 2;
+        /// Member ID of `digest`
+        pub const DIGEST_MEMBER_ID: u32 = 3;
+        /// Member ID of `checksum`
+        pub const CHECKSUM_MEMBER_ID: u32 = 4;
+        /// Member ID of `signature`
+        pub const SIGNATURE_MEMBER_ID: u32 = 5;
+    }
+
+    impl omg_cdr::CdrEncode for Data {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Data {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Data {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.writerGuid, writer);
+            omg_cdr::CdrEncode::encode(&self.serializedData, writer);
+            omg_cdr::CdrEncode::encode(&self.inlineQos, writer);
+            omg_cdr::CdrEncode::encode(&self.digest, writer);
+            omg_cdr::CdrEncode::encode(&self.checksum, writer);
+            writer.write_optional(Self::SIGNATURE_MEMBER_ID, &self.signature, omg_cdr::CdrEncode::encode);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                writerGuid: omg_cdr::CdrDecode::decode(reader)?,
+                serializedData: omg_cdr::CdrDecode::decode(reader)?,
+                inlineQos: omg_cdr::CdrDecode::decode(reader)?,
+                digest: omg_cdr::CdrDecode::decode(reader)?,
+                checksum: omg_cdr::CdrDecode::decode(reader)?,
+                signature: reader.read_optional(Self::SIGNATURE_MEMBER_ID, omg_cdr::CdrDecode::decode)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Data {
+        const IS_BOUNDED: bool = false;
+        const MAX_XCDR1_SIZE: Option<usize> = None;
+        const MAX_XCDR2_SIZE: Option<usize> = None;
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct VendorPayload {
+        #[allow(non_snake_case)]
+        pub vendorId: bytes::Bytes,
+        #[allow(non_snake_case)]
+        pub payload: Vec<u8>,
+        #[allow(non_snake_case)]
+        pub fragments: Vec<OctetSeq>,
+    }
+
+    #[allow(dead_code)]
+    impl VendorPayload {
+
+        pub fn new(vendorId: bytes::Bytes, payload: Vec<u8>, fragments: Vec<OctetSeq>, ) -> Self {
+            Self {
+                vendorId,
+                payload,
+                fragments,
+            }
+        }
+
+        pub fn vendorId(&self) -> &bytes::Bytes {
+            &self.vendorId
+        }
+
+        pub fn set_vendorId(&mut self, value: bytes::Bytes) {
+            self.vendorId = value;
+        }
+
+        pub fn payload(&self) -> &Vec<u8> {
+            &self.payload
+        }
+
+        pub fn set_payload(&mut self, value: Vec<u8>) {
+            self.payload = value;
+        }
+
+        pub fn fragments(&self) -> &Vec<OctetSeq> {
+            &self.fragments
+        }
+
+        pub fn set_fragments(&mut self, value: Vec<OctetSeq>) {
+            self.fragments = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for VendorPayload {
+        fn default() -> Self {
+            Self {
+                vendorId: bytes::Bytes::new(),
+                payload: Vec::new(),
+                fragments: Vec::new(),
+            }
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug)]
+    pub struct VendorPayloadKey {
+        pub vendorId: bytes::Bytes,
+    }
+
+    impl omg_idl_rt::KeyHashEncode for VendorPayloadKey {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<bytes::Bytes>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.vendorId, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.vendorId, &other.vendorId)
+        }
+    }
+
+    impl PartialEq for VendorPayloadKey {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other).is_eq()
+        }
+    }
+
+    impl Eq for VendorPayloadKey {}
+
+    impl PartialOrd for VendorPayloadKey {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for VendorPayloadKey {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other)
+        }
+    }
+
+    impl std::hash::Hash for VendorPayloadKey {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            omg_idl_rt::hash_key(self, state);
+        }
+    }
+
+    impl omg_idl_rt::Keyed for VendorPayload {
+        type Key = VendorPayloadKey;
+
+        fn key(&self) -> Self::Key {
+            VendorPayloadKey {
+                vendorId: self.vendorId.clone(),
+            }
+        }
+    }
+
+    /// Equal if the key members are equal, other members are ignored
+    impl PartialEq for VendorPayload {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.vendorId, &other.vendorId)
+                .is_eq()
+        }
+    }
+
+    impl Eq for VendorPayload {}
+
+    impl PartialOrd for VendorPayload {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    /// Orders by the key members, other members are ignored
+    impl Ord for VendorPayload {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.vendorId, &other.vendorId)
+        }
+    }
+
+    /// Hashes the key members only, consistent with `PartialEq`
+    impl std::hash::Hash for VendorPayload {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            let mut writer = omg_idl_rt::KeyHolderWriter::hashing(state);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.vendorId, &mut writer);
+        }
+    }
+
+    impl omg_idl_rt::Extensible for VendorPayload {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for VendorPayload {
+        const TYPE_NAME: &'static str = "RTPS::VendorPayload";
+        const REPOSITORY_ID: &'static str = "IDL:RTPS/VendorPayload:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl VendorPayload {
+        /// Member ID of `vendorId`
+        pub const VENDOR_ID_MEMBER_ID: u32 = 0;
+        /// Member ID of `payload`
+        pub const PAYLOAD_MEMBER_ID: u32 = 1;
+        /// Member ID of `fragments`
+        pub const FRAGMENTS_MEMBER_ID: u32 = 2;
+    }
+
+    impl omg_cdr::CdrEncode for VendorPayload {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for VendorPayload {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for VendorPayload {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.vendorId, writer);
+            omg_cdr::CdrEncode::encode(&self.payload, writer);
+            omg_cdr::CdrEncode::encode(&self.fragments, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                vendorId: omg_cdr::CdrDecode::decode(reader)?,
+                payload: omg_cdr::CdrDecode::decode(reader)?,
+                fragments: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for VendorPayload {
+        const IS_BOUNDED: bool = false;
+        const MAX_XCDR1_SIZE: Option<usize> = None;
+        const MAX_XCDR2_SIZE: Option<usize> = None;
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub enum Content {
+        KIND_INLINE{ value: bytes::Bytes, },
+        KIND_REFERENCE{ #[serde(with = "serde_bytes")] hash: [u8;32_usize], },
+    }
+
+    impl Default for Content {
+        fn default() -> Self {
+            Content::KIND_INLINE { value: bytes::Bytes::new() }
+        }
+    }
+//
+// TODO custom de-/serializer
+//
+
+    impl omg_idl_rt::Extensible for Content {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Content {
+        const TYPE_NAME: &'static str = "RTPS::Content";
+        const REPOSITORY_ID: &'static str = "IDL:RTPS/Content:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Content {
+        /// Member ID of `value`
+        pub const VALUE_MEMBER_ID: u32 = 1;
+        /// Member ID of `hash`
+        pub const HASH_MEMBER_ID: u32 = 2;
+    }
+
+    impl omg_cdr::CdrEncode for Content {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            match self {
+                Content::KIND_INLINE { value } => writer.write_union(extensibility, &1_i16, Self::VALUE_MEMBER_ID, value, omg_cdr::CdrEncode::encode),
+                Content::KIND_REFERENCE { hash } => writer.write_union(extensibility, &2_i16, Self::HASH_MEMBER_ID, hash, omg_cdr::CdrEncode::encode),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Content {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            reader.read_union(extensibility, |discriminator: i16, reader| {
+                Ok(match discriminator {
+                    1_i16 => Content::KIND_INLINE { value: reader.read_branch(extensibility, Self::VALUE_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    2_i16 => Content::KIND_REFERENCE { hash: reader.read_branch(extensibility, Self::HASH_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    #[allow(unreachable_patterns)]
+                    _ => return Err(omg_cdr::CdrError::InvalidDiscriminator),
+                })
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Content {
+        const IS_BOUNDED: bool = false;
+        const MAX_XCDR1_SIZE: Option<usize> = None;
+        const MAX_XCDR2_SIZE: Option<usize> = None;
+    }
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const KIND_INLINE: i16 = 1;
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const KIND_REFERENCE: i16 = 2;
+
+}
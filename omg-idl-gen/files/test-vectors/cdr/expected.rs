@@ -0,0 +1,830 @@
+
+#[allow(non_snake_case)]
+pub mod Cdr {
+    use serde_derive::{Serialize, Deserialize};
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, PartialOrd, Eq, Ord)]
+    pub enum Kind {
+        #[default]
+        POINT,
+        CIRCLE,
+        LABEL,
+    }
+
+    #[allow(non_camel_case_types)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct KindError;
+
+    impl std::str::FromStr for Kind {
+        type Err = KindError;
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            match value {
+                "POINT" => Ok(Kind::POINT),
+                "CIRCLE" => Ok(Kind::CIRCLE),
+                "LABEL" => Ok(Kind::LABEL),
+                _ => Err(KindError),
+            }
+        }
+    }
+
+    impl std::fmt::Display for Kind {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let enum_str = match self {
+                    Kind::POINT => "POINT",
+                    Kind::CIRCLE => "CIRCLE",
+                    Kind::LABEL => "LABEL",
+            };
+            write!(f, "{enum_str}")
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Kind {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Kind {
+        const TYPE_NAME: &'static str = "Cdr::Kind";
+        const REPOSITORY_ID: &'static str = "IDL:Cdr/Kind:1.0";
+    }
+
+    impl omg_cdr::CdrEncode for Kind {
+        const PRIMITIVE: bool = true;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let value: u32 = match self {
+                Kind::POINT => 0,
+                Kind::CIRCLE => 1,
+                Kind::LABEL => 2,
+            };
+            omg_cdr::CdrEncode::encode(&value, writer);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Kind {
+        const PRIMITIVE: bool = true;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            match <u32 as omg_cdr::CdrDecode>::decode(reader)? {
+                0 => Ok(Kind::POINT),
+                1 => Ok(Kind::CIRCLE),
+                2 => Ok(Kind::LABEL),
+                value => Err(omg_cdr::CdrError::InvalidEnum(value)),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Kind {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(4);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(4);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    pub type Title_t = String;
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Header {
+        #[allow(non_snake_case)]
+        pub id: i32,
+        #[allow(non_snake_case)]
+        pub marker: char,
+        #[allow(non_snake_case)]
+        pub title: Title_t,
+        #[allow(non_snake_case)]
+        pub notes: Vec<String>,
+        #[allow(non_snake_case)]
+        #[serde(with = "serde_arrays")]
+        pub tags: [char;2_usize],
+    }
+
+    #[allow(dead_code)]
+    impl Header {
+
+        pub fn new(id: i32, marker: char, title: Title_t, notes: Vec<String>, tags: [char;2_usize], ) -> Self {
+            Self {
+                id,
+                marker,
+                title,
+                notes,
+                tags,
+            }
+        }
+
+        pub fn id(&self) -> &i32 {
+            &self.id
+        }
+
+        pub fn set_id(&mut self, value: i32) {
+            self.id = value;
+        }
+
+        pub fn marker(&self) -> &char {
+            &self.marker
+        }
+
+        pub fn set_marker(&mut self, value: char) {
+            self.marker = value;
+        }
+
+        pub fn title(&self) -> &Title_t {
+            &self.title
+        }
+
+        pub fn set_title(&mut self, value: Title_t) {
+            self.title = value;
+        }
+
+        pub fn notes(&self) -> &Vec<String> {
+            &self.notes
+        }
+
+        pub fn set_notes(&mut self, value: Vec<String>) {
+            self.notes = value;
+        }
+
+        pub fn tags(&self) -> &[char;2_usize] {
+            &self.tags
+        }
+
+        pub fn set_tags(&mut self, value: [char;2_usize]) {
+            self.tags = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Header {
+        fn default() -> Self {
+            Self {
+                id: 0,
+                marker: '\0',
+                title: String::new(),
+                notes: Vec::new(),
+                tags: std::array::from_fn(|_| '\0'),
+            }
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug)]
+    pub struct HeaderKey {
+        pub id: i32,
+    }
+
+    impl omg_idl_rt::KeyHashEncode for HeaderKey {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<i32>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.id, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+        }
+    }
+
+    impl PartialEq for HeaderKey {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other).is_eq()
+        }
+    }
+
+    impl Eq for HeaderKey {}
+
+    impl PartialOrd for HeaderKey {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for HeaderKey {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other)
+        }
+    }
+
+    impl std::hash::Hash for HeaderKey {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            omg_idl_rt::hash_key(self, state);
+        }
+    }
+
+    impl omg_idl_rt::Keyed for Header {
+        type Key = HeaderKey;
+
+        fn key(&self) -> Self::Key {
+            HeaderKey {
+                id: self.id,
+            }
+        }
+    }
+
+    /// Equal if the key members are equal, other members are ignored
+    impl PartialEq for Header {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+                .is_eq()
+        }
+    }
+
+    impl Eq for Header {}
+
+    impl PartialOrd for Header {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    /// Orders by the key members, other members are ignored
+    impl Ord for Header {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+        }
+    }
+
+    /// Hashes the key members only, consistent with `PartialEq`
+    impl std::hash::Hash for Header {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            let mut writer = omg_idl_rt::KeyHolderWriter::hashing(state);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.id, &mut writer);
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Header {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Final;
+    }
+
+    impl omg_idl_rt::TypeName for Header {
+        const TYPE_NAME: &'static str = "Cdr::Header";
+        const REPOSITORY_ID: &'static str = "IDL:Cdr/Header:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Header {
+        /// Member ID of `id`
+        pub const ID_MEMBER_ID: u32 = 0;
+        /// Member ID of `marker`
+        pub const MARKER_MEMBER_ID: u32 = 1;
+        /// Member ID of `title`
+        pub const TITLE_MEMBER_ID: u32 = 2;
+        /// Member ID of `notes`
+        pub const NOTES_MEMBER_ID: u32 = 3;
+        /// Member ID of `tags`
+        pub const TAGS_MEMBER_ID: u32 = 4;
+    }
+
+    impl omg_cdr::CdrEncode for Header {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Header {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Header {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.id, writer);
+            <omg_cdr::WideChar as omg_cdr::Codec<_>>::encode(&self.marker, writer);
+            <omg_cdr::WideString as omg_cdr::Codec<_>>::encode(&self.title, writer);
+            <omg_cdr::Sequence<omg_cdr::WideString> as omg_cdr::Codec<_>>::encode(&self.notes, writer);
+            <omg_cdr::Array<omg_cdr::WideChar> as omg_cdr::Codec<_>>::encode(&self.tags, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                id: omg_cdr::CdrDecode::decode(reader)?,
+                marker: <omg_cdr::WideChar as omg_cdr::Codec<_>>::decode(reader)?,
+                title: <omg_cdr::WideString as omg_cdr::Codec<_>>::decode(reader)?,
+                notes: <omg_cdr::Sequence<omg_cdr::WideString> as omg_cdr::Codec<_>>::decode(reader)?,
+                tags: <omg_cdr::Array<omg_cdr::WideChar> as omg_cdr::Codec<_>>::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Header {
+        const IS_BOUNDED: bool = false;
+        const MAX_XCDR1_SIZE: Option<usize> = None;
+        const MAX_XCDR2_SIZE: Option<usize> = None;
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Base {
+        #[allow(non_snake_case)]
+        pub id: i32,
+    }
+
+    #[allow(dead_code)]
+    impl Base {
+
+        pub fn new(id: i32, ) -> Self {
+            Self {
+                id,
+            }
+        }
+
+        pub fn id(&self) -> &i32 {
+            &self.id
+        }
+
+        pub fn set_id(&mut self, value: i32) {
+            self.id = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Base {
+        fn default() -> Self {
+            Self {
+                id: 0,
+            }
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug)]
+    pub struct BaseKey {
+        pub id: i32,
+    }
+
+    impl omg_idl_rt::KeyHashEncode for BaseKey {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<i32>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.id, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+        }
+    }
+
+    impl PartialEq for BaseKey {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other).is_eq()
+        }
+    }
+
+    impl Eq for BaseKey {}
+
+    impl PartialOrd for BaseKey {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for BaseKey {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other)
+        }
+    }
+
+    impl std::hash::Hash for BaseKey {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            omg_idl_rt::hash_key(self, state);
+        }
+    }
+
+    impl omg_idl_rt::Keyed for Base {
+        type Key = BaseKey;
+
+        fn key(&self) -> Self::Key {
+            BaseKey {
+                id: self.id,
+            }
+        }
+    }
+
+    /// Equal if the key members are equal, other members are ignored
+    impl PartialEq for Base {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+                .is_eq()
+        }
+    }
+
+    impl Eq for Base {}
+
+    impl PartialOrd for Base {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    /// Orders by the key members, other members are ignored
+    impl Ord for Base {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+        }
+    }
+
+    /// Hashes the key members only, consistent with `PartialEq`
+    impl std::hash::Hash for Base {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            let mut writer = omg_idl_rt::KeyHolderWriter::hashing(state);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.id, &mut writer);
+        }
+    }
+
+    impl omg_idl_rt::KeyHashEncode for Base {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<i32>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.id, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Base {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Mutable;
+    }
+
+    impl omg_idl_rt::TypeName for Base {
+        const TYPE_NAME: &'static str = "Cdr::Base";
+        const REPOSITORY_ID: &'static str = "IDL:Cdr/Base:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Base {
+        /// Member ID of `id`
+        pub const ID_MEMBER_ID: u32 = 0;
+    }
+
+    impl omg_cdr::CdrEncode for Base {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Base {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Base {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_member(Self::ID_MEMBER_ID, true, &self.id, omg_cdr::CdrEncode::encode);
+        }
+
+        fn decode_member(&mut self, id: u32, reader: &mut omg_cdr::CdrReader<'_>) -> Result<bool, omg_cdr::CdrError> {
+            match id {
+                Self::ID_MEMBER_ID => self.id = omg_cdr::CdrDecode::decode(reader)?,
+                _ => return Ok(false),
+            }
+            Ok(true)
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Base {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(12);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(12);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Derived {
+        #[serde(flatten)]
+        pub base: Base,
+        #[allow(non_snake_case)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub radius: Option<f64>,
+        #[allow(non_snake_case)]
+        pub history: Vec<i32>,
+    }
+
+    #[allow(dead_code)]
+    impl Derived {
+
+        pub fn new(base: Base, history: Vec<i32>, ) -> Self {
+            Self {
+                base,
+                radius: None,
+                history,
+            }
+        }
+
+        pub fn base(&self) -> &Base {
+            &self.base
+        }
+
+        pub fn set_base(&mut self, value: Base) {
+            self.base = value;
+        }
+
+        pub fn radius(&self) -> Option<&f64> {
+            self.radius.as_ref()
+        }
+
+        pub fn set_radius(&mut self, value: Option<f64>) {
+            self.radius = value;
+        }
+
+        pub fn history(&self) -> &Vec<i32> {
+            &self.history
+        }
+
+        pub fn set_history(&mut self, value: Vec<i32>) {
+            self.history = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Derived {
+        fn default() -> Self {
+            Self {
+                base: Default::default(),
+                radius: None,
+                history: Vec::new(),
+            }
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug)]
+    pub struct DerivedKey {
+        pub base: Base,
+    }
+
+    impl omg_idl_rt::KeyHashEncode for DerivedKey {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<Base>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.base, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.base, &other.base)
+        }
+    }
+
+    impl PartialEq for DerivedKey {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other).is_eq()
+        }
+    }
+
+    impl Eq for DerivedKey {}
+
+    impl PartialOrd for DerivedKey {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for DerivedKey {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other)
+        }
+    }
+
+    impl std::hash::Hash for DerivedKey {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            omg_idl_rt::hash_key(self, state);
+        }
+    }
+
+    impl omg_idl_rt::Keyed for Derived {
+        type Key = DerivedKey;
+
+        fn key(&self) -> Self::Key {
+            DerivedKey {
+                base: self.base.clone(),
+            }
+        }
+    }
+
+    /// Equal if the key members are equal, other members are ignored
+    impl PartialEq for Derived {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.base, &other.base)
+                .is_eq()
+        }
+    }
+
+    impl Eq for Derived {}
+
+    impl PartialOrd for Derived {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    /// Orders by the key members, other members are ignored
+    impl Ord for Derived {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.base, &other.base)
+        }
+    }
+
+    /// Hashes the key members only, consistent with `PartialEq`
+    impl std::hash::Hash for Derived {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            let mut writer = omg_idl_rt::KeyHolderWriter::hashing(state);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.base, &mut writer);
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Derived {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Mutable;
+    }
+
+    impl omg_idl_rt::TypeName for Derived {
+        const TYPE_NAME: &'static str = "Cdr::Derived";
+        const REPOSITORY_ID: &'static str = "IDL:Cdr/Derived:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Derived {
+        /// Member ID of `radius`
+        pub const RADIUS_MEMBER_ID: u32 = 1;
+        /// Member ID of `history`
+        pub const HISTORY_MEMBER_ID: u32 = 2;
+    }
+
+    impl omg_cdr::CdrEncode for Derived {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Derived {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Derived {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrStruct::encode_members(&self.base, writer);
+            if let Some(value) = &self.radius {
+                writer.write_member(Self::RADIUS_MEMBER_ID, false, value, omg_cdr::CdrEncode::encode);
+            }
+            writer.write_member(Self::HISTORY_MEMBER_ID, false, &self.history, omg_cdr::CdrEncode::encode);
+        }
+
+        fn decode_member(&mut self, id: u32, reader: &mut omg_cdr::CdrReader<'_>) -> Result<bool, omg_cdr::CdrError> {
+            match id {
+                Self::RADIUS_MEMBER_ID => self.radius = Some(omg_cdr::CdrDecode::decode(reader)?),
+                Self::HISTORY_MEMBER_ID => self.history = omg_cdr::CdrDecode::decode(reader)?,
+                _ => return omg_cdr::CdrStruct::decode_member(&mut self.base, id, reader),
+            }
+            Ok(true)
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Derived {
+        const IS_BOUNDED: bool = false;
+        const MAX_XCDR1_SIZE: Option<usize> = None;
+        const MAX_XCDR2_SIZE: Option<usize> = None;
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub enum Shape {
+        POINT{ x: i32, },
+        CIRCLE{ #[serde(default, skip_serializing_if = "Option::is_none")] radius: Option<f64>, },
+        default{ label: String, },
+    }
+
+    impl Default for Shape {
+        fn default() -> Self {
+            Shape::default { label: String::new() }
+        }
+    }
+//
+// TODO custom de-/serializer
+//
+
+    impl omg_idl_rt::Extensible for Shape {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Shape {
+        const TYPE_NAME: &'static str = "Cdr::Shape";
+        const REPOSITORY_ID: &'static str = "IDL:Cdr/Shape:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Shape {
+        /// Member ID of `x`
+        pub const X_MEMBER_ID: u32 = 1;
+        /// Member ID of `radius`
+        pub const RADIUS_MEMBER_ID: u32 = 2;
+        /// Member ID of `label`
+        pub const LABEL_MEMBER_ID: u32 = 3;
+    }
+
+    impl omg_cdr::CdrEncode for Shape {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            match self {
+                Shape::POINT { x } => writer.write_union(extensibility, &Kind::POINT, Self::X_MEMBER_ID, x, omg_cdr::CdrEncode::encode),
+                Shape::CIRCLE { radius } => writer.write_union(extensibility, &Kind::CIRCLE, Self::RADIUS_MEMBER_ID, radius, |value, writer| writer.write_optional(Self::RADIUS_MEMBER_ID, value, omg_cdr::CdrEncode::encode)),
+                Shape::default { label } => writer.write_union(extensibility, &Kind::LABEL, Self::LABEL_MEMBER_ID, label, <omg_cdr::WideString as omg_cdr::Codec<_>>::encode),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Shape {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            reader.read_union(extensibility, |discriminator: Kind, reader| {
+                Ok(match discriminator {
+                    Kind::POINT => Shape::POINT { x: reader.read_branch(extensibility, Self::X_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    Kind::CIRCLE => Shape::CIRCLE { radius: reader.read_branch(extensibility, Self::RADIUS_MEMBER_ID, |reader| reader.read_optional(Self::RADIUS_MEMBER_ID, omg_cdr::CdrDecode::decode))? },
+                    #[allow(unreachable_patterns)]
+                    _ => Shape::default { label: reader.read_branch(extensibility, Self::LABEL_MEMBER_ID, <omg_cdr::WideString as omg_cdr::Codec<_>>::decode)? },
+                })
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Shape {
+        const IS_BOUNDED: bool = false;
+        const MAX_XCDR1_SIZE: Option<usize> = None;
+        const MAX_XCDR2_SIZE: Option<usize> = None;
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub enum Flag {
+        ENABLED{ enabled: bool, },
+        LEVEL{ level: u8, },
+    }
+
+    impl Default for Flag {
+        fn default() -> Self {
+            Flag::ENABLED { enabled: false }
+        }
+    }
+//
+// TODO custom de-/serializer
+//
+
+    impl omg_idl_rt::Extensible for Flag {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Mutable;
+    }
+
+    impl omg_idl_rt::TypeName for Flag {
+        const TYPE_NAME: &'static str = "Cdr::Flag";
+        const REPOSITORY_ID: &'static str = "IDL:Cdr/Flag:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Flag {
+        /// Member ID of `enabled`
+        pub const ENABLED_MEMBER_ID: u32 = 1;
+        /// Member ID of `level`
+        pub const LEVEL_MEMBER_ID: u32 = 2;
+    }
+
+    impl omg_cdr::CdrEncode for Flag {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            match self {
+                Flag::ENABLED { enabled } => writer.write_union(extensibility, &'a', Self::ENABLED_MEMBER_ID, enabled, omg_cdr::CdrEncode::encode),
+                Flag::LEVEL { level } => writer.write_union(extensibility, &'b', Self::LEVEL_MEMBER_ID, level, omg_cdr::CdrEncode::encode),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Flag {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            reader.read_union(extensibility, |discriminator: char, reader| {
+                Ok(match discriminator {
+                    'a' => Flag::ENABLED { enabled: reader.read_branch(extensibility, Self::ENABLED_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    'b' => Flag::LEVEL { level: reader.read_branch(extensibility, Self::LEVEL_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    #[allow(unreachable_patterns)]
+                    _ => return Err(omg_cdr::CdrError::InvalidDiscriminator),
+                })
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Flag {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(20);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(17);
+    }
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const ENABLED: char = 'a';
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const LEVEL: char = 'b';
+
+}
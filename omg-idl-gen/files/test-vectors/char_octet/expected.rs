@@ -0,0 +1,682 @@
+
+#[allow(non_snake_case)]
+pub mod Text {
+    use serde_derive::{Serialize, Deserialize};
+
+    #[allow(dead_code, non_camel_case_types)]
+    pub type Letter = u8;
+
+    // No C layout: a string
+    #[allow(dead_code, non_camel_case_types)]
+    pub type Caption = omg_idl_rt::WString;
+
+    // Not plain old data: 1 bytes of padding before member `unit`
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Copy)]
+    #[repr(C)]
+    pub struct Glyph {
+        #[allow(non_snake_case)]
+        pub code: u8,
+        #[allow(non_snake_case)]
+        pub unit: u16,
+    }
+
+    #[allow(dead_code)]
+    impl Glyph {
+
+        pub fn new(code: u8, unit: u16, ) -> Self {
+            Self {
+                code,
+                unit,
+            }
+        }
+
+        pub fn code(&self) -> &u8 {
+            &self.code
+        }
+
+        pub fn set_code(&mut self, value: u8) {
+            self.code = value;
+        }
+
+        pub fn unit(&self) -> &u16 {
+            &self.unit
+        }
+
+        pub fn set_unit(&mut self, value: u16) {
+            self.unit = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Glyph {
+        fn default() -> Self {
+            Self {
+                code: 0x00,
+                unit: 0x0000,
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Glyph {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Final;
+    }
+
+    impl omg_idl_rt::TypeName for Glyph {
+        const TYPE_NAME: &'static str = "Text::Glyph";
+        const REPOSITORY_ID: &'static str = "IDL:Text/Glyph:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Glyph {
+        /// Member ID of `code`
+        pub const CODE_MEMBER_ID: u32 = 0;
+        /// Member ID of `unit`
+        pub const UNIT_MEMBER_ID: u32 = 1;
+    }
+
+    impl omg_cdr::CdrEncode for Glyph {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Glyph {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Glyph {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.code, writer);
+            <omg_cdr::WideChar as omg_cdr::Codec<_>>::encode(&self.unit, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                code: omg_cdr::CdrDecode::decode(reader)?,
+                unit: <omg_cdr::WideChar as omg_cdr::Codec<_>>::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Glyph {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(4);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(4);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Clone)]
+    pub struct GlyphRef<'a> {
+        reader: omg_cdr::CdrReader<'a>,
+        #[allow(non_snake_case)]
+        code: u8,
+        #[allow(non_snake_case)]
+        unit: u16,
+    }
+
+    #[allow(non_snake_case)]
+    impl<'a> GlyphRef<'a> {
+        pub fn code(&self) -> u8 {
+            self.code
+        }
+
+        pub fn unit(&self) -> u16 {
+            self.unit
+        }
+
+        /// Decode the viewed `Glyph`
+        pub fn to_owned(&self) -> Result<Glyph, omg_cdr::CdrError> {
+            omg_cdr::CdrStruct::decode_members(&mut self.reader.clone())
+        }
+    }
+
+    impl<'a> omg_cdr::CdrStructView<'a> for GlyphRef<'a> {
+        type Owned = Glyph;
+
+        fn view_members(reader: &mut omg_cdr::CdrReader<'a>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                reader: reader.clone(),
+                code: omg_cdr::CdrView::view(reader)?,
+                unit: omg_cdr::CdrView::view(reader)?,
+            })
+        }
+    }
+
+    impl std::fmt::Debug for GlyphRef<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct("GlyphRef")
+                .field("code", &self.code)
+                .field("unit", &self.unit)
+                .finish()
+        }
+    }
+
+    // No C layout: member `note` is a string
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Label {
+        #[allow(non_snake_case)]
+        pub prefix: u8,
+        #[allow(non_snake_case)]
+        pub wide_prefix: u16,
+        #[allow(non_snake_case)]
+        pub marker: u8,
+        #[allow(non_snake_case)]
+        pub accent: u16,
+        #[allow(non_snake_case)]
+        pub note: omg_idl_rt::WString,
+        #[allow(non_snake_case)]
+        pub letter: Letter,
+        #[allow(non_snake_case)]
+        pub caption: Caption,
+        #[allow(non_snake_case)]
+        pub units: Vec<u16>,
+        #[allow(non_snake_case)]
+        #[serde(with = "serde_arrays")]
+        pub pair: [u16;2_usize],
+        #[allow(non_snake_case)]
+        pub lines: Vec<omg_idl_rt::WString>,
+    }
+
+    #[allow(dead_code)]
+    impl Label {
+        #[allow(clippy::too_many_arguments)]
+        pub fn new(prefix: u8, wide_prefix: u16, marker: u8, accent: u16, note: omg_idl_rt::WString, letter: Letter, caption: Caption, units: Vec<u16>, pair: [u16;2_usize], lines: Vec<omg_idl_rt::WString>, ) -> Self {
+            Self {
+                prefix,
+                wide_prefix,
+                marker,
+                accent,
+                note,
+                letter,
+                caption,
+                units,
+                pair,
+                lines,
+            }
+        }
+
+        pub fn prefix(&self) -> &u8 {
+            &self.prefix
+        }
+
+        pub fn set_prefix(&mut self, value: u8) {
+            self.prefix = value;
+        }
+
+        pub fn wide_prefix(&self) -> &u16 {
+            &self.wide_prefix
+        }
+
+        pub fn set_wide_prefix(&mut self, value: u16) {
+            self.wide_prefix = value;
+        }
+
+        pub fn marker(&self) -> &u8 {
+            &self.marker
+        }
+
+        pub fn set_marker(&mut self, value: u8) {
+            self.marker = value;
+        }
+
+        pub fn accent(&self) -> &u16 {
+            &self.accent
+        }
+
+        pub fn set_accent(&mut self, value: u16) {
+            self.accent = value;
+        }
+
+        pub fn note(&self) -> &omg_idl_rt::WString {
+            &self.note
+        }
+
+        pub fn set_note(&mut self, value: omg_idl_rt::WString) {
+            self.note = value;
+        }
+
+        pub fn letter(&self) -> &Letter {
+            &self.letter
+        }
+
+        pub fn set_letter(&mut self, value: Letter) {
+            self.letter = value;
+        }
+
+        pub fn caption(&self) -> &Caption {
+            &self.caption
+        }
+
+        pub fn set_caption(&mut self, value: Caption) {
+            self.caption = value;
+        }
+
+        pub fn units(&self) -> &Vec<u16> {
+            &self.units
+        }
+
+        pub fn set_units(&mut self, value: Vec<u16>) {
+            self.units = value;
+        }
+
+        pub fn pair(&self) -> &[u16;2_usize] {
+            &self.pair
+        }
+
+        pub fn set_pair(&mut self, value: [u16;2_usize]) {
+            self.pair = value;
+        }
+
+        pub fn lines(&self) -> &Vec<omg_idl_rt::WString> {
+            &self.lines
+        }
+
+        pub fn set_lines(&mut self, value: Vec<omg_idl_rt::WString>) {
+            self.lines = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Label {
+        fn default() -> Self {
+            Self {
+                prefix: 0x00,
+                wide_prefix: 0x0000,
+                marker: b'x',
+                accent: 0x00E9,
+                note: omg_idl_rt::WString::from("none"),
+                letter: 0x00,
+                caption: omg_idl_rt::WString::new(),
+                units: Vec::new(),
+                pair: std::array::from_fn(|_| 0x0000),
+                lines: Vec::new(),
+            }
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug)]
+    pub struct LabelKey {
+        pub prefix: u8,
+        pub wide_prefix: u16,
+    }
+
+    impl omg_idl_rt::KeyHashEncode for LabelKey {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<u8>().field::<u16>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.prefix, writer);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.wide_prefix, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.prefix, &other.prefix)
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.wide_prefix, &other.wide_prefix))
+        }
+    }
+
+    impl PartialEq for LabelKey {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other).is_eq()
+        }
+    }
+
+    impl Eq for LabelKey {}
+
+    impl PartialOrd for LabelKey {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for LabelKey {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other)
+        }
+    }
+
+    impl std::hash::Hash for LabelKey {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            omg_idl_rt::hash_key(self, state);
+        }
+    }
+
+    impl omg_idl_rt::Keyed for Label {
+        type Key = LabelKey;
+
+        fn key(&self) -> Self::Key {
+            LabelKey {
+                prefix: self.prefix,
+                wide_prefix: self.wide_prefix,
+            }
+        }
+    }
+
+    /// Equal if the key members are equal, other members are ignored
+    impl PartialEq for Label {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.prefix, &other.prefix)
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.wide_prefix, &other.wide_prefix))
+                .is_eq()
+        }
+    }
+
+    impl Eq for Label {}
+
+    impl PartialOrd for Label {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    /// Orders by the key members, other members are ignored
+    impl Ord for Label {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.prefix, &other.prefix)
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.wide_prefix, &other.wide_prefix))
+        }
+    }
+
+    /// Hashes the key members only, consistent with `PartialEq`
+    impl std::hash::Hash for Label {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            let mut writer = omg_idl_rt::KeyHolderWriter::hashing(state);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.prefix, &mut writer);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.wide_prefix, &mut writer);
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Label {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Label {
+        const TYPE_NAME: &'static str = "Text::Label";
+        const REPOSITORY_ID: &'static str = "IDL:Text/Label:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Label {
+        /// Member ID of `prefix`
+        pub const PREFIX_MEMBER_ID: u32 = 0;
+        /// Member ID of `wide_prefix`
+        pub const WIDE_PREFIX_MEMBER_ID: u32 = 1;
+        /// Member ID of `marker`
+        pub const MARKER_MEMBER_ID: u32 = 2;
+        /// Member ID of `accent`
+        pub const ACCENT_MEMBER_ID: u32 = 3;
+        /// Member ID of `note`
+        pub const NOTE_MEMBER_ID: u32 = 4;
+        /// Member ID of `letter`
+        pub const LETTER_MEMBER_ID: u32 = 5;
+        /// Member ID of `caption`
+        pub const CAPTION_MEMBER_ID: u32 = 6;
+        /// Member ID of `units`
+        pub const UNITS_MEMBER_ID: u32 = 7;
+        /// Member ID of `pair`
+        pub const PAIR_MEMBER_ID: u32 = 8;
+        /// Member ID of `lines`
+        pub const LINES_MEMBER_ID: u32 = 9;
+    }
+
+    impl omg_cdr::CdrEncode for Label {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Label {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Label {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.prefix, writer);
+            <omg_cdr::WideChar as omg_cdr::Codec<_>>::encode(&self.wide_prefix, writer);
+            omg_cdr::CdrEncode::encode(&self.marker, writer);
+            <omg_cdr::WideChar as omg_cdr::Codec<_>>::encode(&self.accent, writer);
+            <omg_cdr::WideString as omg_cdr::Codec<_>>::encode(&self.note, writer);
+            omg_cdr::CdrEncode::encode(&self.letter, writer);
+            <omg_cdr::WideString as omg_cdr::Codec<_>>::encode(&self.caption, writer);
+            <omg_cdr::Sequence<omg_cdr::WideChar> as omg_cdr::Codec<_>>::encode(&self.units, writer);
+            <omg_cdr::Array<omg_cdr::WideChar> as omg_cdr::Codec<_>>::encode(&self.pair, writer);
+            <omg_cdr::Sequence<omg_cdr::WideString> as omg_cdr::Codec<_>>::encode(&self.lines, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                prefix: omg_cdr::CdrDecode::decode(reader)?,
+                wide_prefix: <omg_cdr::WideChar as omg_cdr::Codec<_>>::decode(reader)?,
+                marker: omg_cdr::CdrDecode::decode(reader)?,
+                accent: <omg_cdr::WideChar as omg_cdr::Codec<_>>::decode(reader)?,
+                note: <omg_cdr::WideString as omg_cdr::Codec<_>>::decode(reader)?,
+                letter: omg_cdr::CdrDecode::decode(reader)?,
+                caption: <omg_cdr::WideString as omg_cdr::Codec<_>>::decode(reader)?,
+                units: <omg_cdr::Sequence<omg_cdr::WideChar> as omg_cdr::Codec<_>>::decode(reader)?,
+                pair: <omg_cdr::Array<omg_cdr::WideChar> as omg_cdr::Codec<_>>::decode(reader)?,
+                lines: <omg_cdr::Sequence<omg_cdr::WideString> as omg_cdr::Codec<_>>::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Label {
+        const IS_BOUNDED: bool = false;
+        const MAX_XCDR1_SIZE: Option<usize> = None;
+        const MAX_XCDR2_SIZE: Option<usize> = None;
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Clone)]
+    pub struct LabelRef<'a> {
+        reader: omg_cdr::CdrReader<'a>,
+        #[allow(non_snake_case)]
+        prefix: u8,
+        #[allow(non_snake_case)]
+        wide_prefix: u16,
+        #[allow(non_snake_case)]
+        marker: u8,
+        #[allow(non_snake_case)]
+        accent: u16,
+        #[allow(non_snake_case)]
+        note: omg_cdr::Lazy<'a, omg_idl_rt::WString>,
+        #[allow(non_snake_case)]
+        letter: u8,
+        #[allow(non_snake_case)]
+        caption: omg_cdr::Lazy<'a, Caption>,
+        #[allow(non_snake_case)]
+        units: omg_cdr::CdrSlice<'a, u16>,
+        #[allow(non_snake_case)]
+        pair: omg_cdr::CdrSlice<'a, u16>,
+        #[allow(non_snake_case)]
+        lines: omg_cdr::Lazy<'a, Vec<omg_idl_rt::WString>>,
+    }
+
+    #[allow(non_snake_case)]
+    impl<'a> LabelRef<'a> {
+        pub fn prefix(&self) -> u8 {
+            self.prefix
+        }
+
+        pub fn wide_prefix(&self) -> u16 {
+            self.wide_prefix
+        }
+
+        pub fn marker(&self) -> u8 {
+            self.marker
+        }
+
+        pub fn accent(&self) -> u16 {
+            self.accent
+        }
+
+        pub fn note(&self) -> Result<omg_idl_rt::WString, omg_cdr::CdrError> {
+            self.note.get()
+        }
+
+        pub fn letter(&self) -> u8 {
+            self.letter
+        }
+
+        pub fn caption(&self) -> Result<Caption, omg_cdr::CdrError> {
+            self.caption.get()
+        }
+
+        pub fn units(&self) -> omg_cdr::CdrSlice<'a, u16> {
+            self.units
+        }
+
+        pub fn pair(&self) -> omg_cdr::CdrSlice<'a, u16> {
+            self.pair
+        }
+
+        pub fn lines(&self) -> Result<Vec<omg_idl_rt::WString>, omg_cdr::CdrError> {
+            self.lines.get()
+        }
+
+        /// Decode the viewed `Label`
+        pub fn to_owned(&self) -> Result<Label, omg_cdr::CdrError> {
+            omg_cdr::CdrStruct::decode_members(&mut self.reader.clone())
+        }
+    }
+
+    impl<'a> omg_cdr::CdrStructView<'a> for LabelRef<'a> {
+        type Owned = Label;
+
+        fn view_members(reader: &mut omg_cdr::CdrReader<'a>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                reader: reader.clone(),
+                prefix: omg_cdr::CdrView::view(reader)?,
+                wide_prefix: omg_cdr::CdrView::view(reader)?,
+                marker: omg_cdr::CdrView::view(reader)?,
+                accent: omg_cdr::CdrView::view(reader)?,
+                note: omg_cdr::Lazy::read(reader, <omg_cdr::WideString as omg_cdr::Codec<_>>::decode)?,
+                letter: omg_cdr::CdrView::view(reader)?,
+                caption: omg_cdr::Lazy::read(reader, <omg_cdr::WideString as omg_cdr::Codec<_>>::decode)?,
+                units: omg_cdr::CdrSlice::read_sequence(reader)?,
+                pair: omg_cdr::CdrSlice::read_array::<2>(reader)?,
+                lines: omg_cdr::Lazy::read(reader, <omg_cdr::Sequence<omg_cdr::WideString> as omg_cdr::Codec<_>>::decode)?,
+            })
+        }
+    }
+
+    impl std::fmt::Debug for LabelRef<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct("LabelRef")
+                .field("prefix", &self.prefix)
+                .field("wide_prefix", &self.wide_prefix)
+                .field("marker", &self.marker)
+                .field("accent", &self.accent)
+                .field("note", &self.note)
+                .field("letter", &self.letter)
+                .field("caption", &self.caption)
+                .field("units", &self.units)
+                .field("pair", &self.pair)
+                .field("lines", &self.lines)
+                .finish()
+        }
+    }
+
+    // No C layout: a union, which maps to a Rust enum with data
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub enum Choice {
+        KIND_NUMBER{ number: i32, },
+        KIND_TEXT{ text: omg_idl_rt::WString, },
+        KIND_ACCENT{ text: omg_idl_rt::WString, },
+        default{ unit: u16, },
+    }
+
+    impl Default for Choice {
+        fn default() -> Self {
+            Choice::default { unit: 0x0000 }
+        }
+    }
+//
+// TODO custom de-/serializer
+//
+
+    impl omg_idl_rt::Extensible for Choice {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Choice {
+        const TYPE_NAME: &'static str = "Text::Choice";
+        const REPOSITORY_ID: &'static str = "IDL:Text/Choice:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Choice {
+        /// Member ID of `number`
+        pub const NUMBER_MEMBER_ID: u32 = 1;
+        /// Member ID of `text`
+        pub const TEXT_MEMBER_ID: u32 = 2;
+        /// Member ID of `unit`
+        pub const UNIT_MEMBER_ID: u32 = 3;
+    }
+
+    impl omg_cdr::CdrEncode for Choice {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            match self {
+                Choice::KIND_NUMBER { number } => writer.write_union(extensibility, &b'a', Self::NUMBER_MEMBER_ID, number, omg_cdr::CdrEncode::encode),
+                Choice::KIND_TEXT { text } => writer.write_union(extensibility, &b'b', Self::TEXT_MEMBER_ID, text, <omg_cdr::WideString as omg_cdr::Codec<_>>::encode),
+                Choice::KIND_ACCENT { text } => writer.write_union(extensibility, &0xE9, Self::TEXT_MEMBER_ID, text, <omg_cdr::WideString as omg_cdr::Codec<_>>::encode),
+                Choice::default { unit } => writer.write_union(extensibility, &0x00, Self::UNIT_MEMBER_ID, unit, <omg_cdr::WideChar as omg_cdr::Codec<_>>::encode),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Choice {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            reader.read_union(extensibility, |discriminator: u8, reader| {
+                Ok(match discriminator {
+                    b'a' => Choice::KIND_NUMBER { number: reader.read_branch(extensibility, Self::NUMBER_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    b'b' => Choice::KIND_TEXT { text: reader.read_branch(extensibility, Self::TEXT_MEMBER_ID, <omg_cdr::WideString as omg_cdr::Codec<_>>::decode)? },
+                    0xE9 => Choice::KIND_ACCENT { text: reader.read_branch(extensibility, Self::TEXT_MEMBER_ID, <omg_cdr::WideString as omg_cdr::Codec<_>>::decode)? },
+                    #[allow(unreachable_patterns)]
+                    _ => Choice::default { unit: reader.read_branch(extensibility, Self::UNIT_MEMBER_ID, <omg_cdr::WideChar as omg_cdr::Codec<_>>::decode)? },
+                })
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Choice {
+        const IS_BOUNDED: bool = false;
+        const MAX_XCDR1_SIZE: Option<usize> = None;
+        const MAX_XCDR2_SIZE: Option<usize> = None;
+    }
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const SEPARATOR: u8 = b';';
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const DEGREE: u8 = 0xB0;
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const FIRST: Letter = b'A';
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const OMEGA: u16 = 0x03A9;
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const GREETING: &[u16] = &[0x0047, 0x0072, 0x00FC, 0x00DF, 0x0065];
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const KIND_NUMBER: u8 = b'a';
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const KIND_TEXT: u8 = b'b';
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const KIND_ACCENT: u8 = 0xE9;
+
+}
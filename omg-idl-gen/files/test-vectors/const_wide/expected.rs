@@ -0,0 +1,115 @@
+use serde_derive::{Serialize, Deserialize};
+
+#[allow(dead_code, non_camel_case_types)]
+pub type Caption = String;
+
+#[allow(dead_code, non_camel_case_types)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Note {
+    #[allow(non_snake_case)]
+    pub text: String,
+    #[allow(non_snake_case)]
+    pub accent: char,
+}
+
+#[allow(dead_code)]
+impl Note {
+
+    pub fn new(text: String, accent: char, ) -> Self {
+        Self {
+            text,
+            accent,
+        }
+    }
+
+    pub fn text(&self) -> &String {
+        &self.text
+    }
+
+    pub fn set_text(&mut self, value: String) {
+        self.text = value;
+    }
+
+    pub fn accent(&self) -> &char {
+        &self.accent
+    }
+
+    pub fn set_accent(&mut self, value: char) {
+        self.accent = value;
+    }
+
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for Note {
+    fn default() -> Self {
+        Self {
+            text: "none".to_owned(),
+            accent: 'é',
+        }
+    }
+}
+
+impl omg_idl_rt::Extensible for Note {
+    const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+}
+
+impl omg_idl_rt::TypeName for Note {
+    const TYPE_NAME: &'static str = "Note";
+    const REPOSITORY_ID: &'static str = "IDL:Note:1.0";
+}
+
+#[allow(dead_code)]
+impl Note {
+    /// Member ID of `text`
+    pub const TEXT_MEMBER_ID: u32 = 0;
+    /// Member ID of `accent`
+    pub const ACCENT_MEMBER_ID: u32 = 1;
+}
+
+impl omg_cdr::CdrEncode for Note {
+    fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+        writer.write_struct(self);
+    }
+}
+
+impl omg_cdr::CdrDecode for Note {
+    fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+        reader.read_struct()
+    }
+}
+
+impl omg_cdr::CdrStruct for Note {
+    fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+        <omg_cdr::WideString as omg_cdr::Codec<_>>::encode(&self.text, writer);
+        <omg_cdr::WideChar as omg_cdr::Codec<_>>::encode(&self.accent, writer);
+    }
+
+    fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+        Ok(Self {
+            text: <omg_cdr::WideString as omg_cdr::Codec<_>>::decode(reader)?,
+            accent: <omg_cdr::WideChar as omg_cdr::Codec<_>>::decode(reader)?,
+        })
+    }
+}
+
+impl omg_cdr::CdrBounds for Note {
+    const IS_BOUNDED: bool = false;
+    const MAX_XCDR1_SIZE: Option<usize> = None;
+    const MAX_XCDR2_SIZE: Option<usize> = None;
+}
+
+#[allow(dead_code, non_upper_case_globals)]
+pub const GREETING: &str = "Grüße";
+
+#[allow(dead_code, non_upper_case_globals)]
+pub const TITLE: &str = "hi";
+
+#[allow(dead_code, non_upper_case_globals)]
+pub const PLAIN: &str = "plain";
+
+#[allow(dead_code, non_upper_case_globals)]
+pub const OMEGA: char = 'Ω';
+
+#[allow(dead_code, non_upper_case_globals)]
+pub const LETTER: char = 'w';
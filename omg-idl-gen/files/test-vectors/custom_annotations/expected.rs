@@ -0,0 +1,425 @@
+
+#[allow(non_snake_case)]
+pub mod Mapped {
+    use serde_derive::{Serialize, Deserialize};
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Hash, PartialEq, Eq)]
+    #[serde(rename = "point")]
+    pub struct Point {
+        #[serde(rename = "x_pos")]
+        #[allow(non_snake_case)]
+        pub x: i32,
+        /// Since version 1.2
+        #[allow(non_snake_case)]
+        pub y: i32,
+    }
+
+    #[allow(dead_code)]
+    impl Point {
+
+        pub fn new(x: i32, y: i32, ) -> Self {
+            Self {
+                x,
+                y,
+            }
+        }
+
+        pub fn x(&self) -> &i32 {
+            &self.x
+        }
+
+        pub fn set_x(&mut self, value: i32) {
+            self.x = value;
+        }
+
+        pub fn y(&self) -> &i32 {
+            &self.y
+        }
+
+        pub fn set_y(&mut self, value: i32) {
+            self.y = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Point {
+        fn default() -> Self {
+            Self {
+                x: 0,
+                y: 0,
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Point {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Point {
+        const TYPE_NAME: &'static str = "Mapped::Point";
+        const REPOSITORY_ID: &'static str = "IDL:Mapped/Point:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Point {
+        /// Member ID of `x`
+        pub const X_MEMBER_ID: u32 = 0;
+        /// Member ID of `y`
+        pub const Y_MEMBER_ID: u32 = 1;
+    }
+
+    impl omg_cdr::CdrEncode for Point {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Point {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Point {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.x, writer);
+            omg_cdr::CdrEncode::encode(&self.y, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                x: omg_cdr::CdrDecode::decode(reader)?,
+                y: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Point {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(8);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(12);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Reading {
+        #[allow(non_snake_case)]
+        pub id: i32,
+        #[allow(non_snake_case)]
+        pub value: f64,
+    }
+
+    #[allow(dead_code)]
+    impl Reading {
+
+        pub fn new(id: i32, value: f64, ) -> Self {
+            Self {
+                id,
+                value,
+            }
+        }
+
+        pub fn id(&self) -> &i32 {
+            &self.id
+        }
+
+        pub fn set_id(&mut self, value: i32) {
+            self.id = value;
+        }
+
+        pub fn value(&self) -> &f64 {
+            &self.value
+        }
+
+        pub fn set_value(&mut self, value: f64) {
+            self.value = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Reading {
+        fn default() -> Self {
+            Self {
+                id: 0,
+                value: 0.0,
+            }
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug)]
+    pub struct ReadingKey {
+        pub id: i32,
+    }
+
+    impl omg_idl_rt::KeyHashEncode for ReadingKey {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<i32>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.id, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+        }
+    }
+
+    impl PartialEq for ReadingKey {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other).is_eq()
+        }
+    }
+
+    impl Eq for ReadingKey {}
+
+    impl PartialOrd for ReadingKey {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for ReadingKey {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other)
+        }
+    }
+
+    impl std::hash::Hash for ReadingKey {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            omg_idl_rt::hash_key(self, state);
+        }
+    }
+
+    impl omg_idl_rt::Keyed for Reading {
+        type Key = ReadingKey;
+
+        fn key(&self) -> Self::Key {
+            ReadingKey {
+                id: self.id,
+            }
+        }
+    }
+
+    /// Equal if the key members are equal, other members are ignored
+    impl PartialEq for Reading {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+                .is_eq()
+        }
+    }
+
+    impl Eq for Reading {}
+
+    impl PartialOrd for Reading {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    /// Orders by the key members, other members are ignored
+    impl Ord for Reading {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+        }
+    }
+
+    /// Hashes the key members only, consistent with `PartialEq`
+    impl std::hash::Hash for Reading {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            let mut writer = omg_idl_rt::KeyHolderWriter::hashing(state);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.id, &mut writer);
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Reading {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Reading {
+        const TYPE_NAME: &'static str = "Mapped::Reading";
+        const REPOSITORY_ID: &'static str = "IDL:Mapped/Reading:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Reading {
+        /// Member ID of `id`
+        pub const ID_MEMBER_ID: u32 = 0;
+        /// Member ID of `value`
+        pub const VALUE_MEMBER_ID: u32 = 1;
+    }
+
+    impl omg_cdr::CdrEncode for Reading {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Reading {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Reading {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.id, writer);
+            omg_cdr::CdrEncode::encode(&self.value, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                id: omg_cdr::CdrDecode::decode(reader)?,
+                value: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Reading {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(16);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(16);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, PartialOrd, Eq, Ord, Hash)]
+    pub enum Color {
+        #[default]
+        RED,
+        /// Since version 2.0
+        GREEN,
+    }
+
+    #[allow(non_camel_case_types)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct ColorError;
+
+    impl std::str::FromStr for Color {
+        type Err = ColorError;
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            match value {
+                "RED" => Ok(Color::RED),
+                "GREEN" => Ok(Color::GREEN),
+                _ => Err(ColorError),
+            }
+        }
+    }
+
+    impl std::fmt::Display for Color {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let enum_str = match self {
+                    Color::RED => "RED",
+                    Color::GREEN => "GREEN",
+            };
+            write!(f, "{enum_str}")
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Color {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Color {
+        const TYPE_NAME: &'static str = "Mapped::Color";
+        const REPOSITORY_ID: &'static str = "IDL:Mapped/Color:1.0";
+    }
+
+    impl omg_cdr::CdrEncode for Color {
+        const PRIMITIVE: bool = true;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let value: u32 = match self {
+                Color::RED => 0,
+                Color::GREEN => 1,
+            };
+            omg_cdr::CdrEncode::encode(&value, writer);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Color {
+        const PRIMITIVE: bool = true;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            match <u32 as omg_cdr::CdrDecode>::decode(reader)? {
+                0 => Ok(Color::RED),
+                1 => Ok(Color::GREEN),
+                value => Err(omg_cdr::CdrError::InvalidEnum(value)),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Color {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(4);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(4);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+    pub enum Shape {
+        #[serde(rename = "edge")]
+        ONE{ side: i32, },
+    }
+
+    impl Default for Shape {
+        fn default() -> Self {
+            Shape::ONE { side: 0 }
+        }
+    }
+//
+// TODO custom de-/serializer
+//
+
+    impl omg_idl_rt::Extensible for Shape {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Shape {
+        const TYPE_NAME: &'static str = "Mapped::Shape";
+        const REPOSITORY_ID: &'static str = "IDL:Mapped/Shape:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Shape {
+        /// Member ID of `side`
+        pub const SIDE_MEMBER_ID: u32 = 1;
+    }
+
+    impl omg_cdr::CdrEncode for Shape {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            match self {
+                Shape::ONE { side } => writer.write_union(extensibility, &1_i32, Self::SIDE_MEMBER_ID, side, omg_cdr::CdrEncode::encode),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Shape {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            reader.read_union(extensibility, |discriminator: i32, reader| {
+                Ok(match discriminator {
+                    1_i32 => Shape::ONE { side: reader.read_branch(extensibility, Self::SIDE_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    #[allow(unreachable_patterns)]
+                    _ => return Err(omg_cdr::CdrError::InvalidDiscriminator),
+                })
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Shape {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(8);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(12);
+    }
+
+    /// Since version 1.0
+    #[allow(dead_code, non_camel_case_types)]
+    pub type Count = i32;
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const ONE: i32 = 1;
+
+}
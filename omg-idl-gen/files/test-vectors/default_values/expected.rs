@@ -0,0 +1,567 @@
+use serde_derive::{Serialize, Deserialize};
+
+#[allow(dead_code, non_camel_case_types)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, PartialOrd, Eq, Ord)]
+pub enum Color {
+    RED,
+    #[default]
+    GREEN,
+    BLUE,
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct ColorError;
+
+impl std::str::FromStr for Color {
+    type Err = ColorError;
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "RED" => Ok(Color::RED),
+            "GREEN" => Ok(Color::GREEN),
+            "BLUE" => Ok(Color::BLUE),
+            _ => Err(ColorError),
+        }
+    }
+}
+
+impl std::fmt::Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let enum_str = match self {
+            Color::RED => "RED",
+            Color::GREEN => "GREEN",
+            Color::BLUE => "BLUE",
+        };
+        write!(f, "{enum_str}")
+    }
+}
+
+impl omg_idl_rt::Extensible for Color {
+    const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+}
+
+impl omg_idl_rt::TypeName for Color {
+    const TYPE_NAME: &'static str = "Color";
+    const REPOSITORY_ID: &'static str = "IDL:Color:1.0";
+}
+
+impl omg_cdr::CdrEncode for Color {
+    const PRIMITIVE: bool = true;
+
+    fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+        let value: u32 = match self {
+            Color::RED => 0,
+            Color::GREEN => 1,
+            Color::BLUE => 2,
+        };
+        omg_cdr::CdrEncode::encode(&value, writer);
+    }
+}
+
+impl omg_cdr::CdrDecode for Color {
+    const PRIMITIVE: bool = true;
+
+    fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+        match <u32 as omg_cdr::CdrDecode>::decode(reader)? {
+            0 => Ok(Color::RED),
+            1 => Ok(Color::GREEN),
+            2 => Ok(Color::BLUE),
+            value => Err(omg_cdr::CdrError::InvalidEnum(value)),
+        }
+    }
+}
+
+impl omg_cdr::CdrBounds for Color {
+    const IS_BOUNDED: bool = true;
+    const MAX_XCDR1_SIZE: Option<usize> = Some(4);
+    const MAX_XCDR2_SIZE: Option<usize> = Some(4);
+}
+
+#[allow(dead_code, non_camel_case_types)]
+pub type Percent = i32;
+
+#[allow(dead_code, non_camel_case_types)]
+pub type Month = u8;
+
+#[allow(dead_code, non_camel_case_types)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Settings {
+    #[allow(non_snake_case)]
+    pub count: i32,
+    #[allow(non_snake_case)]
+    pub ratio: f64,
+    #[allow(non_snake_case)]
+    pub enabled: bool,
+    #[allow(non_snake_case)]
+    pub mark: char,
+    #[allow(non_snake_case)]
+    pub name: String,
+    #[allow(non_snake_case)]
+    pub color: Color,
+    #[allow(non_snake_case)]
+    pub tint: Color,
+    #[allow(non_snake_case)]
+    pub temperature: f64,
+    #[allow(non_snake_case)]
+    pub level: Percent,
+    #[allow(non_snake_case)]
+    pub month: Month,
+    #[allow(non_snake_case)]
+    pub port: u16,
+    #[allow(non_snake_case)]
+    pub offset: i32,
+    #[allow(non_snake_case)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retries: Option<i16>,
+    #[allow(non_snake_case)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attempts: Option<i32>,
+    #[allow(non_snake_case)]
+    #[serde(with = "serde_arrays")]
+    pub raw: [u8;4_usize],
+    #[allow(non_snake_case)]
+    pub history: Vec<i32>,
+}
+
+#[allow(dead_code)]
+impl Settings {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(count: i32, ratio: f64, enabled: bool, mark: char, name: String, color: Color, tint: Color, temperature: f64, level: Percent, month: Month, port: u16, offset: i32, raw: [u8;4_usize], history: Vec<i32>, ) -> Self {
+        Self {
+            count,
+            ratio,
+            enabled,
+            mark,
+            name,
+            color,
+            tint,
+            temperature,
+            level,
+            month,
+            port,
+            offset,
+            retries: None,
+            attempts: None,
+            raw,
+            history,
+        }
+    }
+
+    pub fn count(&self) -> &i32 {
+        &self.count
+    }
+
+    pub fn set_count(&mut self, value: i32) {
+        self.count = value;
+    }
+
+    pub fn ratio(&self) -> &f64 {
+        &self.ratio
+    }
+
+    pub fn set_ratio(&mut self, value: f64) {
+        self.ratio = value;
+    }
+
+    pub fn enabled(&self) -> &bool {
+        &self.enabled
+    }
+
+    pub fn set_enabled(&mut self, value: bool) {
+        self.enabled = value;
+    }
+
+    pub fn mark(&self) -> &char {
+        &self.mark
+    }
+
+    pub fn set_mark(&mut self, value: char) {
+        self.mark = value;
+    }
+
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn set_name(&mut self, value: String) {
+        self.name = value;
+    }
+
+    pub fn color(&self) -> &Color {
+        &self.color
+    }
+
+    pub fn set_color(&mut self, value: Color) {
+        self.color = value;
+    }
+
+    pub fn tint(&self) -> &Color {
+        &self.tint
+    }
+
+    pub fn set_tint(&mut self, value: Color) {
+        self.tint = value;
+    }
+
+    pub fn temperature(&self) -> &f64 {
+        &self.temperature
+    }
+
+    pub fn set_temperature(&mut self, value: f64) -> Result<(), omg_idl_rt::ValidationError> {
+        Self::validate_temperature(&value)?;
+        self.temperature = value;
+        Ok(())
+    }
+
+    fn validate_temperature(value: &f64) -> Result<(), omg_idl_rt::ValidationError> {
+        if *value < -40.0 {
+            return Err(omg_idl_rt::ValidationError::new("temperature", omg_idl_rt::Bound::Min, "-40"));
+        }
+        if *value > 85.5 {
+            return Err(omg_idl_rt::ValidationError::new("temperature", omg_idl_rt::Bound::Max, "85.5"));
+        }
+        Ok(())
+    }
+
+    pub fn level(&self) -> &Percent {
+        &self.level
+    }
+
+    pub fn set_level(&mut self, value: Percent) -> Result<(), omg_idl_rt::ValidationError> {
+        Self::validate_level(&value)?;
+        self.level = value;
+        Ok(())
+    }
+
+    fn validate_level(value: &Percent) -> Result<(), omg_idl_rt::ValidationError> {
+        if *value < 0 {
+            return Err(omg_idl_rt::ValidationError::new("level", omg_idl_rt::Bound::Min, "0"));
+        }
+        if *value > 100 {
+            return Err(omg_idl_rt::ValidationError::new("level", omg_idl_rt::Bound::Max, "100"));
+        }
+        Ok(())
+    }
+
+    pub fn month(&self) -> &Month {
+        &self.month
+    }
+
+    pub fn set_month(&mut self, value: Month) -> Result<(), omg_idl_rt::ValidationError> {
+        Self::validate_month(&value)?;
+        self.month = value;
+        Ok(())
+    }
+
+    fn validate_month(value: &Month) -> Result<(), omg_idl_rt::ValidationError> {
+        if *value < 1 {
+            return Err(omg_idl_rt::ValidationError::new("month", omg_idl_rt::Bound::Min, "1"));
+        }
+        if *value > 12 {
+            return Err(omg_idl_rt::ValidationError::new("month", omg_idl_rt::Bound::Max, "12"));
+        }
+        Ok(())
+    }
+
+    pub fn port(&self) -> &u16 {
+        &self.port
+    }
+
+    pub fn set_port(&mut self, value: u16) -> Result<(), omg_idl_rt::ValidationError> {
+        Self::validate_port(&value)?;
+        self.port = value;
+        Ok(())
+    }
+
+    fn validate_port(value: &u16) -> Result<(), omg_idl_rt::ValidationError> {
+        if *value < 5 {
+            return Err(omg_idl_rt::ValidationError::new("port", omg_idl_rt::Bound::Min, "5"));
+        }
+        Ok(())
+    }
+
+    pub fn offset(&self) -> &i32 {
+        &self.offset
+    }
+
+    pub fn set_offset(&mut self, value: i32) -> Result<(), omg_idl_rt::ValidationError> {
+        Self::validate_offset(&value)?;
+        self.offset = value;
+        Ok(())
+    }
+
+    fn validate_offset(value: &i32) -> Result<(), omg_idl_rt::ValidationError> {
+        if *value < -10 {
+            return Err(omg_idl_rt::ValidationError::new("offset", omg_idl_rt::Bound::Min, "-10"));
+        }
+        if *value > -1 {
+            return Err(omg_idl_rt::ValidationError::new("offset", omg_idl_rt::Bound::Max, "-1"));
+        }
+        Ok(())
+    }
+
+    pub fn retries(&self) -> Option<&i16> {
+        self.retries.as_ref()
+    }
+
+    pub fn set_retries(&mut self, value: Option<i16>) -> Result<(), omg_idl_rt::ValidationError> {
+        if let Some(value) = &value {
+            Self::validate_retries(value)?;
+        }
+        self.retries = value;
+        Ok(())
+    }
+
+    fn validate_retries(value: &i16) -> Result<(), omg_idl_rt::ValidationError> {
+        if *value < 1 {
+            return Err(omg_idl_rt::ValidationError::new("retries", omg_idl_rt::Bound::Min, "1"));
+        }
+        if *value > 10 {
+            return Err(omg_idl_rt::ValidationError::new("retries", omg_idl_rt::Bound::Max, "10"));
+        }
+        Ok(())
+    }
+
+    pub fn attempts(&self) -> Option<&i32> {
+        self.attempts.as_ref()
+    }
+
+    pub fn set_attempts(&mut self, value: Option<i32>) {
+        self.attempts = value;
+    }
+
+    pub fn raw(&self) -> &[u8;4_usize] {
+        &self.raw
+    }
+
+    pub fn set_raw(&mut self, value: [u8;4_usize]) {
+        self.raw = value;
+    }
+
+    pub fn history(&self) -> &Vec<i32> {
+        &self.history
+    }
+
+    pub fn set_history(&mut self, value: Vec<i32>) {
+        self.history = value;
+    }
+
+    /// Check all members against their `@range`, `@min` and `@max` bounds
+    pub fn validate(&self) -> Result<(), omg_idl_rt::ValidationError> {
+        Self::validate_temperature(&self.temperature)?;
+        Self::validate_level(&self.level)?;
+        Self::validate_month(&self.month)?;
+        Self::validate_port(&self.port)?;
+        Self::validate_offset(&self.offset)?;
+        if let Some(value) = &self.retries {
+            Self::validate_retries(value)?;
+        }
+        Ok(())
+    }
+
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            count: 42,
+            ratio: 1.5,
+            enabled: true,
+            mark: 'x',
+            name: "plain".to_owned(),
+            color: Color::BLUE,
+            tint: Default::default(),
+            temperature: 0.0,
+            level: 0,
+            month: 1,
+            port: 5,
+            offset: -1,
+            retries: None,
+            attempts: Some(3),
+            raw: std::array::from_fn(|_| 0),
+            history: Vec::new(),
+        }
+    }
+}
+
+impl omg_idl_rt::Extensible for Settings {
+    const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+}
+
+impl omg_idl_rt::TypeName for Settings {
+    const TYPE_NAME: &'static str = "Settings";
+    const REPOSITORY_ID: &'static str = "IDL:Settings:1.0";
+}
+
+#[allow(dead_code)]
+impl Settings {
+    /// Member ID of `count`
+    pub const COUNT_MEMBER_ID: u32 = 0;
+    /// Member ID of `ratio`
+    pub const RATIO_MEMBER_ID: u32 = 1;
+    /// Member ID of `enabled`
+    pub const ENABLED_MEMBER_ID: u32 = 2;
+    /// Member ID of `mark`
+    pub const MARK_MEMBER_ID: u32 = 3;
+    /// Member ID of `name`
+    pub const NAME_MEMBER_ID: u32 = 4;
+    /// Member ID of `color`
+    pub const COLOR_MEMBER_ID: u32 = 5;
+    /// Member ID of `tint`
+    pub const TINT_MEMBER_ID: u32 = 6;
+    /// Member ID of `temperature`
+    pub const TEMPERATURE_MEMBER_ID: u32 = 7;
+    /// Member ID of `level`
+    pub const LEVEL_MEMBER_ID: u32 = 8;
+    /// Member ID of `month`
+    pub const MONTH_MEMBER_ID: u32 = 9;
+    /// Member ID of `port`
+    pub const PORT_MEMBER_ID: u32 = 10;
+    /// Member ID of `offset`
+    pub const OFFSET_MEMBER_ID: u32 = 11;
+    /// Member ID of `retries`
+    pub const RETRIES_MEMBER_ID: u32 = 12;
+    /// Member ID of `attempts`
+    pub const ATTEMPTS_MEMBER_ID: u32 = 13;
+    /// Member ID of `raw`
+    pub const RAW_MEMBER_ID: u32 = 14;
+    /// Member ID of `history`
+    pub const HISTORY_MEMBER_ID: u32 = 15;
+}
+
+impl omg_cdr::CdrEncode for Settings {
+    fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+        writer.write_struct(self);
+    }
+}
+
+impl omg_cdr::CdrDecode for Settings {
+    fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+        reader.read_struct()
+    }
+}
+
+impl omg_cdr::CdrStruct for Settings {
+    fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+        omg_cdr::CdrEncode::encode(&self.count, writer);
+        omg_cdr::CdrEncode::encode(&self.ratio, writer);
+        omg_cdr::CdrEncode::encode(&self.enabled, writer);
+        omg_cdr::CdrEncode::encode(&self.mark, writer);
+        omg_cdr::CdrEncode::encode(&self.name, writer);
+        omg_cdr::CdrEncode::encode(&self.color, writer);
+        omg_cdr::CdrEncode::encode(&self.tint, writer);
+        omg_cdr::CdrEncode::encode(&self.temperature, writer);
+        omg_cdr::CdrEncode::encode(&self.level, writer);
+        omg_cdr::CdrEncode::encode(&self.month, writer);
+        omg_cdr::CdrEncode::encode(&self.port, writer);
+        omg_cdr::CdrEncode::encode(&self.offset, writer);
+        writer.write_optional(Self::RETRIES_MEMBER_ID, &self.retries, omg_cdr::CdrEncode::encode);
+        writer.write_optional(Self::ATTEMPTS_MEMBER_ID, &self.attempts, omg_cdr::CdrEncode::encode);
+        omg_cdr::CdrEncode::encode(&self.raw, writer);
+        omg_cdr::CdrEncode::encode(&self.history, writer);
+    }
+
+    fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+        Ok(Self {
+            count: omg_cdr::CdrDecode::decode(reader)?,
+            ratio: omg_cdr::CdrDecode::decode(reader)?,
+            enabled: omg_cdr::CdrDecode::decode(reader)?,
+            mark: omg_cdr::CdrDecode::decode(reader)?,
+            name: omg_cdr::CdrDecode::decode(reader)?,
+            color: omg_cdr::CdrDecode::decode(reader)?,
+            tint: omg_cdr::CdrDecode::decode(reader)?,
+            temperature: omg_cdr::CdrDecode::decode(reader)?,
+            level: omg_cdr::CdrDecode::decode(reader)?,
+            month: omg_cdr::CdrDecode::decode(reader)?,
+            port: omg_cdr::CdrDecode::decode(reader)?,
+            offset: omg_cdr::CdrDecode::decode(reader)?,
+            retries: reader.read_optional(Self::RETRIES_MEMBER_ID, omg_cdr::CdrDecode::decode)?,
+            attempts: reader.read_optional(Self::ATTEMPTS_MEMBER_ID, omg_cdr::CdrDecode::decode)?,
+            raw: omg_cdr::CdrDecode::decode(reader)?,
+            history: omg_cdr::CdrDecode::decode(reader)?,
+        })
+    }
+}
+
+impl omg_cdr::CdrBounds for Settings {
+    const IS_BOUNDED: bool = false;
+    const MAX_XCDR1_SIZE: Option<usize> = None;
+    const MAX_XCDR2_SIZE: Option<usize> = None;
+}
+
+#[allow(dead_code, non_camel_case_types)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum Value {
+    LABEL0{ l: i32, },
+    LABEL1{ s: String, },
+    default{ d: i16, },
+}
+
+impl Default for Value {
+    fn default() -> Self {
+        Value::default { d: 7 }
+    }
+}
+//
+// TODO custom de-/serializer
+//
+
+impl omg_idl_rt::Extensible for Value {
+    const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+}
+
+impl omg_idl_rt::TypeName for Value {
+    const TYPE_NAME: &'static str = "Value";
+    const REPOSITORY_ID: &'static str = "IDL:Value:1.0";
+}
+
+#[allow(dead_code)]
+impl Value {
+    /// Member ID of `l`
+    pub const L_MEMBER_ID: u32 = 1;
+    /// Member ID of `s`
+    pub const S_MEMBER_ID: u32 = 2;
+    /// Member ID of `d`
+    pub const D_MEMBER_ID: u32 = 3;
+}
+
+impl omg_cdr::CdrEncode for Value {
+    fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+        let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+        match self {
+            Value::LABEL0 { l } => writer.write_union(extensibility, &0_i32, Self::L_MEMBER_ID, l, omg_cdr::CdrEncode::encode),
+            Value::LABEL1 { s } => writer.write_union(extensibility, &1_i32, Self::S_MEMBER_ID, s, omg_cdr::CdrEncode::encode),
+            Value::default { d } => writer.write_union(extensibility, &2_i32, Self::D_MEMBER_ID, d, omg_cdr::CdrEncode::encode),
+        }
+    }
+}
+
+impl omg_cdr::CdrDecode for Value {
+    fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+        let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+        reader.read_union(extensibility, |discriminator: i32, reader| {
+            Ok(match discriminator {
+                0_i32 => Value::LABEL0 { l: reader.read_branch(extensibility, Self::L_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                1_i32 => Value::LABEL1 { s: reader.read_branch(extensibility, Self::S_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                #[allow(unreachable_patterns)]
+                _ => Value::default { d: reader.read_branch(extensibility, Self::D_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+            })
+        })
+    }
+}
+
+impl omg_cdr::CdrBounds for Value {
+    const IS_BOUNDED: bool = false;
+    const MAX_XCDR1_SIZE: Option<usize> = None;
+    const MAX_XCDR2_SIZE: Option<usize> = None;
+}
+
+#[allow(dead_code, non_upper_case_globals)]
+pub const MAX_TEMP: f64 = 85.5;
+
+#[allow(dead_code, non_upper_case_globals)]
+pub const LABEL0: i32 = 0;
+
+#[allow(dead_code, non_upper_case_globals)]
+pub const LABEL1: i32 = 1;
@@ -0,0 +1,754 @@
+
+#[allow(non_snake_case)]
+pub mod Geometry {
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Clone, Debug, Default, PartialEq, PartialOrd, Eq, Ord, Copy, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+    pub enum Axis {
+        #[default]
+        X,
+        Y,
+        Z,
+    }
+
+    #[allow(non_camel_case_types)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct AxisError;
+
+    impl std::str::FromStr for Axis {
+        type Err = AxisError;
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            match value {
+                "X" => Ok(Axis::X),
+                "Y" => Ok(Axis::Y),
+                "Z" => Ok(Axis::Z),
+                _ => Err(AxisError),
+            }
+        }
+    }
+
+    impl std::fmt::Display for Axis {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let enum_str = match self {
+                    Axis::X => "X",
+                    Axis::Y => "Y",
+                    Axis::Z => "Z",
+            };
+            write!(f, "{enum_str}")
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Axis {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Axis {
+        const TYPE_NAME: &'static str = "Geometry::Axis";
+        const REPOSITORY_ID: &'static str = "IDL:Geometry/Axis:1.0";
+    }
+
+    impl omg_cdr::CdrEncode for Axis {
+        const PRIMITIVE: bool = true;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let value: u32 = match self {
+                Axis::X => 0,
+                Axis::Y => 1,
+                Axis::Z => 2,
+            };
+            omg_cdr::CdrEncode::encode(&value, writer);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Axis {
+        const PRIMITIVE: bool = true;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            match <u32 as omg_cdr::CdrDecode>::decode(reader)? {
+                0 => Ok(Axis::X),
+                1 => Ok(Axis::Y),
+                2 => Ok(Axis::Z),
+                value => Err(omg_cdr::CdrError::InvalidEnum(value)),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Axis {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(4);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(4);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Clone, Debug, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    #[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+    pub struct Cell {
+        #[allow(non_snake_case)]
+        pub row: i32,
+        #[allow(non_snake_case)]
+        pub column: i32,
+        #[allow(non_snake_case)]
+        pub axis: Axis,
+    }
+
+    #[allow(dead_code)]
+    impl Cell {
+
+        pub fn new(row: i32, column: i32, axis: Axis, ) -> Self {
+            Self {
+                row,
+                column,
+                axis,
+            }
+        }
+
+        pub fn row(&self) -> &i32 {
+            &self.row
+        }
+
+        pub fn set_row(&mut self, value: i32) {
+            self.row = value;
+        }
+
+        pub fn column(&self) -> &i32 {
+            &self.column
+        }
+
+        pub fn set_column(&mut self, value: i32) {
+            self.column = value;
+        }
+
+        pub fn axis(&self) -> &Axis {
+            &self.axis
+        }
+
+        pub fn set_axis(&mut self, value: Axis) {
+            self.axis = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Cell {
+        fn default() -> Self {
+            Self {
+                row: 0,
+                column: 0,
+                axis: Default::default(),
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Cell {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Cell {
+        const TYPE_NAME: &'static str = "Geometry::Cell";
+        const REPOSITORY_ID: &'static str = "IDL:Geometry/Cell:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Cell {
+        /// Member ID of `row`
+        pub const ROW_MEMBER_ID: u32 = 0;
+        /// Member ID of `column`
+        pub const COLUMN_MEMBER_ID: u32 = 1;
+        /// Member ID of `axis`
+        pub const AXIS_MEMBER_ID: u32 = 2;
+    }
+
+    impl omg_cdr::CdrEncode for Cell {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Cell {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Cell {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.row, writer);
+            omg_cdr::CdrEncode::encode(&self.column, writer);
+            omg_cdr::CdrEncode::encode(&self.axis, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                row: omg_cdr::CdrDecode::decode(reader)?,
+                column: omg_cdr::CdrDecode::decode(reader)?,
+                axis: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Cell {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(12);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(16);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Clone, Debug, Copy, PartialEq, PartialOrd)]
+    #[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+    pub struct Point {
+        #[allow(non_snake_case)]
+        pub x: f64,
+        #[allow(non_snake_case)]
+        pub y: f64,
+    }
+
+    #[allow(dead_code)]
+    impl Point {
+
+        pub fn new(x: f64, y: f64, ) -> Self {
+            Self {
+                x,
+                y,
+            }
+        }
+
+        pub fn x(&self) -> &f64 {
+            &self.x
+        }
+
+        pub fn set_x(&mut self, value: f64) {
+            self.x = value;
+        }
+
+        pub fn y(&self) -> &f64 {
+            &self.y
+        }
+
+        pub fn set_y(&mut self, value: f64) {
+            self.y = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Point {
+        fn default() -> Self {
+            Self {
+                x: 0.0,
+                y: 0.0,
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Point {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Point {
+        const TYPE_NAME: &'static str = "Geometry::Point";
+        const REPOSITORY_ID: &'static str = "IDL:Geometry/Point:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Point {
+        /// Member ID of `x`
+        pub const X_MEMBER_ID: u32 = 0;
+        /// Member ID of `y`
+        pub const Y_MEMBER_ID: u32 = 1;
+    }
+
+    impl omg_cdr::CdrEncode for Point {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Point {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Point {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.x, writer);
+            omg_cdr::CdrEncode::encode(&self.y, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                x: omg_cdr::CdrDecode::decode(reader)?,
+                y: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Point {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(16);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(20);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Clone, Debug, Copy, PartialEq, PartialOrd)]
+    #[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+    pub struct Segment {
+        #[allow(non_snake_case)]
+        pub start: Point,
+        #[allow(non_snake_case)]
+        pub end: Point,
+    }
+
+    #[allow(dead_code)]
+    impl Segment {
+
+        pub fn new(start: Point, end: Point, ) -> Self {
+            Self {
+                start,
+                end,
+            }
+        }
+
+        pub fn start(&self) -> &Point {
+            &self.start
+        }
+
+        pub fn set_start(&mut self, value: Point) {
+            self.start = value;
+        }
+
+        pub fn end(&self) -> &Point {
+            &self.end
+        }
+
+        pub fn set_end(&mut self, value: Point) {
+            self.end = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Segment {
+        fn default() -> Self {
+            Self {
+                start: Default::default(),
+                end: Default::default(),
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Segment {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Segment {
+        const TYPE_NAME: &'static str = "Geometry::Segment";
+        const REPOSITORY_ID: &'static str = "IDL:Geometry/Segment:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Segment {
+        /// Member ID of `start`
+        pub const START_MEMBER_ID: u32 = 0;
+        /// Member ID of `end`
+        pub const END_MEMBER_ID: u32 = 1;
+    }
+
+    impl omg_cdr::CdrEncode for Segment {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Segment {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Segment {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.start, writer);
+            omg_cdr::CdrEncode::encode(&self.end, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                start: omg_cdr::CdrDecode::decode(reader)?,
+                end: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Segment {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(32);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(44);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    #[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+    pub struct Shape {
+        #[allow(non_snake_case)]
+        pub name: String,
+        #[allow(non_snake_case)]
+        pub cells: Vec<Cell>,
+        #[allow(non_snake_case)]
+        #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+        pub origin: Option<Cell>,
+    }
+
+    #[allow(dead_code)]
+    impl Shape {
+
+        pub fn new(name: String, cells: Vec<Cell>, ) -> Self {
+            Self {
+                name,
+                cells,
+                origin: None,
+            }
+        }
+
+        pub fn name(&self) -> &String {
+            &self.name
+        }
+
+        pub fn set_name(&mut self, value: String) {
+            self.name = value;
+        }
+
+        pub fn cells(&self) -> &Vec<Cell> {
+            &self.cells
+        }
+
+        pub fn set_cells(&mut self, value: Vec<Cell>) {
+            self.cells = value;
+        }
+
+        pub fn origin(&self) -> Option<&Cell> {
+            self.origin.as_ref()
+        }
+
+        pub fn set_origin(&mut self, value: Option<Cell>) {
+            self.origin = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Shape {
+        fn default() -> Self {
+            Self {
+                name: String::new(),
+                cells: Vec::new(),
+                origin: None,
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Shape {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Shape {
+        const TYPE_NAME: &'static str = "Geometry::Shape";
+        const REPOSITORY_ID: &'static str = "IDL:Geometry/Shape:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Shape {
+        /// Member ID of `name`
+        pub const NAME_MEMBER_ID: u32 = 0;
+        /// Member ID of `cells`
+        pub const CELLS_MEMBER_ID: u32 = 1;
+        /// Member ID of `origin`
+        pub const ORIGIN_MEMBER_ID: u32 = 2;
+    }
+
+    impl omg_cdr::CdrEncode for Shape {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Shape {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Shape {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.name, writer);
+            omg_cdr::CdrEncode::encode(&self.cells, writer);
+            writer.write_optional(Self::ORIGIN_MEMBER_ID, &self.origin, omg_cdr::CdrEncode::encode);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                name: omg_cdr::CdrDecode::decode(reader)?,
+                cells: omg_cdr::CdrDecode::decode(reader)?,
+                origin: reader.read_optional(Self::ORIGIN_MEMBER_ID, omg_cdr::CdrDecode::decode)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Shape {
+        const IS_BOUNDED: bool = false;
+        const MAX_XCDR1_SIZE: Option<usize> = None;
+        const MAX_XCDR2_SIZE: Option<usize> = None;
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Clone, Debug, Copy)]
+    #[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+    pub struct Marker {
+        #[allow(non_snake_case)]
+        pub id: i32,
+        #[allow(non_snake_case)]
+        pub cell: Cell,
+    }
+
+    #[allow(dead_code)]
+    impl Marker {
+
+        pub fn new(id: i32, cell: Cell, ) -> Self {
+            Self {
+                id,
+                cell,
+            }
+        }
+
+        pub fn id(&self) -> &i32 {
+            &self.id
+        }
+
+        pub fn set_id(&mut self, value: i32) {
+            self.id = value;
+        }
+
+        pub fn cell(&self) -> &Cell {
+            &self.cell
+        }
+
+        pub fn set_cell(&mut self, value: Cell) {
+            self.cell = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Marker {
+        fn default() -> Self {
+            Self {
+                id: 0,
+                cell: Default::default(),
+            }
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug)]
+    pub struct MarkerKey {
+        pub id: i32,
+    }
+
+    impl omg_idl_rt::KeyHashEncode for MarkerKey {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<i32>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.id, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+        }
+    }
+
+    impl PartialEq for MarkerKey {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other).is_eq()
+        }
+    }
+
+    impl Eq for MarkerKey {}
+
+    impl PartialOrd for MarkerKey {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for MarkerKey {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other)
+        }
+    }
+
+    impl std::hash::Hash for MarkerKey {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            omg_idl_rt::hash_key(self, state);
+        }
+    }
+
+    impl omg_idl_rt::Keyed for Marker {
+        type Key = MarkerKey;
+
+        fn key(&self) -> Self::Key {
+            MarkerKey {
+                id: self.id,
+            }
+        }
+    }
+
+    /// Equal if the key members are equal, other members are ignored
+    impl PartialEq for Marker {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+                .is_eq()
+        }
+    }
+
+    impl Eq for Marker {}
+
+    impl PartialOrd for Marker {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    /// Orders by the key members, other members are ignored
+    impl Ord for Marker {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+        }
+    }
+
+    /// Hashes the key members only, consistent with `PartialEq`
+    impl std::hash::Hash for Marker {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            let mut writer = omg_idl_rt::KeyHolderWriter::hashing(state);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.id, &mut writer);
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Marker {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Marker {
+        const TYPE_NAME: &'static str = "Geometry::Marker";
+        const REPOSITORY_ID: &'static str = "IDL:Geometry/Marker:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Marker {
+        /// Member ID of `id`
+        pub const ID_MEMBER_ID: u32 = 0;
+        /// Member ID of `cell`
+        pub const CELL_MEMBER_ID: u32 = 1;
+    }
+
+    impl omg_cdr::CdrEncode for Marker {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Marker {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Marker {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.id, writer);
+            omg_cdr::CdrEncode::encode(&self.cell, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                id: omg_cdr::CdrDecode::decode(reader)?,
+                cell: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Marker {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(16);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(24);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Clone, Debug, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    #[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+    pub enum Extent {
+        X{ width: i32, },
+        Y{ height: i32, },
+        default{ cell: Cell, },
+    }
+
+    impl Default for Extent {
+        fn default() -> Self {
+            Extent::default { cell: Default::default() }
+        }
+    }
+//
+// TODO custom de-/serializer
+//
+
+    impl omg_idl_rt::Extensible for Extent {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Extent {
+        const TYPE_NAME: &'static str = "Geometry::Extent";
+        const REPOSITORY_ID: &'static str = "IDL:Geometry/Extent:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Extent {
+        /// Member ID of `width`
+        pub const WIDTH_MEMBER_ID: u32 = 1;
+        /// Member ID of `height`
+        pub const HEIGHT_MEMBER_ID: u32 = 2;
+        /// Member ID of `cell`
+        pub const CELL_MEMBER_ID: u32 = 3;
+    }
+
+    impl omg_cdr::CdrEncode for Extent {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            match self {
+                Extent::X { width } => writer.write_union(extensibility, &Axis::X, Self::WIDTH_MEMBER_ID, width, omg_cdr::CdrEncode::encode),
+                Extent::Y { height } => writer.write_union(extensibility, &Axis::Y, Self::HEIGHT_MEMBER_ID, height, omg_cdr::CdrEncode::encode),
+                Extent::default { cell } => writer.write_union(extensibility, &Axis::Z, Self::CELL_MEMBER_ID, cell, omg_cdr::CdrEncode::encode),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Extent {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            reader.read_union(extensibility, |discriminator: Axis, reader| {
+                Ok(match discriminator {
+                    Axis::X => Extent::X { width: reader.read_branch(extensibility, Self::WIDTH_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    Axis::Y => Extent::Y { height: reader.read_branch(extensibility, Self::HEIGHT_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    #[allow(unreachable_patterns)]
+                    _ => Extent::default { cell: reader.read_branch(extensibility, Self::CELL_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                })
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Extent {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(16);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(24);
+    }
+
+}
@@ -54,6 +54,70 @@ pub mod A {
 
         }
 
+        #[allow(clippy::derivable_impls)]
+        impl Default for Foo {
+            fn default() -> Self {
+                Self {
+                    m_l1: 0,
+                    m_l2: 0,
+                    m_d: 0.0,
+                }
+            }
+        }
+
+        impl omg_idl_rt::Extensible for Foo {
+            const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+        }
+
+        impl omg_idl_rt::TypeName for Foo {
+            const TYPE_NAME: &'static str = "A::B::Foo";
+            const REPOSITORY_ID: &'static str = "IDL:A/B/Foo:1.0";
+        }
+
+        #[allow(dead_code)]
+        impl Foo {
+            /// Member ID of `m_l1`
+            pub const M_L1_MEMBER_ID: u32 = 0;
+            /// Member ID of `m_l2`
+            pub const M_L2_MEMBER_ID: u32 = 1;
+            /// Member ID of `m_d`
+            pub const M_D_MEMBER_ID: u32 = 2;
+        }
+
+        impl omg_cdr::CdrEncode for Foo {
+            fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+                writer.write_struct(self);
+            }
+        }
+
+        impl omg_cdr::CdrDecode for Foo {
+            fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+                reader.read_struct()
+            }
+        }
+
+        impl omg_cdr::CdrStruct for Foo {
+            fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+                omg_cdr::CdrEncode::encode(&self.m_l1, writer);
+                omg_cdr::CdrEncode::encode(&self.m_l2, writer);
+                omg_cdr::CdrEncode::encode(&self.m_d, writer);
+            }
+
+            fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+                Ok(Self {
+                    m_l1: omg_cdr::CdrDecode::decode(reader)?,
+                    m_l2: omg_cdr::CdrDecode::decode(reader)?,
+                    m_d: omg_cdr::CdrDecode::decode(reader)?,
+                })
+            }
+        }
+
+        impl omg_cdr::CdrBounds for Foo {
+            const IS_BOUNDED: bool = true;
+            const MAX_XCDR1_SIZE: Option<usize> = Some(16);
+            const MAX_XCDR2_SIZE: Option<usize> = Some(20);
+        }
+
     }
 
 }
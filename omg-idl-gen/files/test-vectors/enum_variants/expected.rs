@@ -1,8 +1,9 @@
 use serde_derive::{Serialize, Deserialize};
 
 #[allow(dead_code, non_camel_case_types)]
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, PartialOrd, Eq, Ord)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, PartialOrd, Eq, Ord)]
 pub enum Foo {
+    #[default]
     VARIANT0,
     VARIANT1,
     VARIANT2,
@@ -34,3 +35,44 @@ impl std::fmt::Display for Foo {
         write!(f, "{enum_str}")
     }
 }
+
+impl omg_idl_rt::Extensible for Foo {
+    const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+}
+
+impl omg_idl_rt::TypeName for Foo {
+    const TYPE_NAME: &'static str = "Foo";
+    const REPOSITORY_ID: &'static str = "IDL:Foo:1.0";
+}
+
+impl omg_cdr::CdrEncode for Foo {
+    const PRIMITIVE: bool = true;
+
+    fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+        let value: u32 = match self {
+            Foo::VARIANT0 => 0,
+            Foo::VARIANT1 => 1,
+            Foo::VARIANT2 => 2,
+        };
+        omg_cdr::CdrEncode::encode(&value, writer);
+    }
+}
+
+impl omg_cdr::CdrDecode for Foo {
+    const PRIMITIVE: bool = true;
+
+    fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+        match <u32 as omg_cdr::CdrDecode>::decode(reader)? {
+            0 => Ok(Foo::VARIANT0),
+            1 => Ok(Foo::VARIANT1),
+            2 => Ok(Foo::VARIANT2),
+            value => Err(omg_cdr::CdrError::InvalidEnum(value)),
+        }
+    }
+}
+
+impl omg_cdr::CdrBounds for Foo {
+    const IS_BOUNDED: bool = true;
+    const MAX_XCDR1_SIZE: Option<usize> = Some(4);
+    const MAX_XCDR2_SIZE: Option<usize> = Some(4);
+}
@@ -0,0 +1,716 @@
+use serde_derive::{Serialize, Deserialize};
+
+#[allow(dead_code, non_camel_case_types)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Circle {
+    #[serde(flatten)]
+    pub base: Shapes::ShapeAlias,
+    #[allow(non_snake_case)]
+    pub radius: f64,
+}
+
+#[allow(dead_code)]
+impl Circle {
+
+    pub fn new(base: Shapes::ShapeAlias, radius: f64, ) -> Self {
+        Self {
+            base,
+            radius,
+        }
+    }
+
+    pub fn base(&self) -> &Shapes::ShapeAlias {
+        &self.base
+    }
+
+    pub fn set_base(&mut self, value: Shapes::ShapeAlias) {
+        self.base = value;
+    }
+
+    pub fn radius(&self) -> &f64 {
+        &self.radius
+    }
+
+    pub fn set_radius(&mut self, value: f64) {
+        self.radius = value;
+    }
+
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for Circle {
+    fn default() -> Self {
+        Self {
+            base: Default::default(),
+            radius: 0.0,
+        }
+    }
+}
+
+#[allow(dead_code, non_camel_case_types, non_snake_case)]
+#[derive(Clone, Debug)]
+pub struct CircleKey {
+    pub base: Shapes::ShapeAlias,
+}
+
+impl omg_idl_rt::KeyHashEncode for CircleKey {
+    const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<Shapes::ShapeAlias>();
+
+    fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+        omg_idl_rt::KeyHashEncode::encode_key(&self.base, writer);
+    }
+
+    fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+        omg_idl_rt::KeyHashEncode::key_cmp(&self.base, &other.base)
+    }
+}
+
+impl PartialEq for CircleKey {
+    fn eq(&self, other: &Self) -> bool {
+        omg_idl_rt::KeyHashEncode::key_cmp(self, other).is_eq()
+    }
+}
+
+impl Eq for CircleKey {}
+
+impl PartialOrd for CircleKey {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CircleKey {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        omg_idl_rt::KeyHashEncode::key_cmp(self, other)
+    }
+}
+
+impl std::hash::Hash for CircleKey {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        omg_idl_rt::hash_key(self, state);
+    }
+}
+
+impl omg_idl_rt::Keyed for Circle {
+    type Key = CircleKey;
+
+    fn key(&self) -> Self::Key {
+        CircleKey {
+            base: self.base.clone(),
+        }
+    }
+}
+
+/// Equal if the key members are equal, other members are ignored
+impl PartialEq for Circle {
+    fn eq(&self, other: &Self) -> bool {
+        omg_idl_rt::KeyHashEncode::key_cmp(&self.base, &other.base)
+            .is_eq()
+    }
+}
+
+impl Eq for Circle {}
+
+impl PartialOrd for Circle {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders by the key members, other members are ignored
+impl Ord for Circle {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        omg_idl_rt::KeyHashEncode::key_cmp(&self.base, &other.base)
+    }
+}
+
+/// Hashes the key members only, consistent with `PartialEq`
+impl std::hash::Hash for Circle {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let mut writer = omg_idl_rt::KeyHolderWriter::hashing(state);
+        omg_idl_rt::KeyHashEncode::encode_key(&self.base, &mut writer);
+    }
+}
+
+impl omg_idl_rt::Extensible for Circle {
+    const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Mutable;
+}
+
+impl omg_idl_rt::TypeName for Circle {
+    const TYPE_NAME: &'static str = "Circle";
+    const REPOSITORY_ID: &'static str = "IDL:Circle:1.0";
+}
+
+#[allow(dead_code)]
+impl Circle {
+    /// Member ID of `radius`
+    pub const RADIUS_MEMBER_ID: u32 = 2;
+}
+
+impl omg_cdr::CdrEncode for Circle {
+    fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+        writer.write_struct(self);
+    }
+}
+
+impl omg_cdr::CdrDecode for Circle {
+    fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+        reader.read_struct()
+    }
+}
+
+impl omg_cdr::CdrStruct for Circle {
+    fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+        omg_cdr::CdrStruct::encode_members(&self.base, writer);
+        writer.write_member(Self::RADIUS_MEMBER_ID, false, &self.radius, omg_cdr::CdrEncode::encode);
+    }
+
+    fn decode_member(&mut self, id: u32, reader: &mut omg_cdr::CdrReader<'_>) -> Result<bool, omg_cdr::CdrError> {
+        match id {
+            Self::RADIUS_MEMBER_ID => self.radius = omg_cdr::CdrDecode::decode(reader)?,
+            _ => return omg_cdr::CdrStruct::decode_member(&mut self.base, id, reader),
+        }
+        Ok(true)
+    }
+}
+
+impl omg_cdr::CdrBounds for Circle {
+    const IS_BOUNDED: bool = true;
+    const MAX_XCDR1_SIZE: Option<usize> = Some(32);
+    const MAX_XCDR2_SIZE: Option<usize> = Some(32);
+}
+
+#[allow(dead_code, non_camel_case_types)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Point {
+    #[allow(non_snake_case)]
+    pub x: i32,
+    #[allow(non_snake_case)]
+    pub y: i32,
+}
+
+#[allow(dead_code)]
+impl Point {
+
+    pub fn new(x: i32, y: i32, ) -> Self {
+        Self {
+            x,
+            y,
+        }
+    }
+
+    pub fn x(&self) -> &i32 {
+        &self.x
+    }
+
+    pub fn set_x(&mut self, value: i32) {
+        self.x = value;
+    }
+
+    pub fn y(&self) -> &i32 {
+        &self.y
+    }
+
+    pub fn set_y(&mut self, value: i32) {
+        self.y = value;
+    }
+
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for Point {
+    fn default() -> Self {
+        Self {
+            x: 0,
+            y: 0,
+        }
+    }
+}
+
+impl omg_idl_rt::Extensible for Point {
+    const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+}
+
+impl omg_idl_rt::TypeName for Point {
+    const TYPE_NAME: &'static str = "Point";
+    const REPOSITORY_ID: &'static str = "IDL:Point:1.0";
+}
+
+#[allow(dead_code)]
+impl Point {
+    /// Member ID of `x`
+    pub const X_MEMBER_ID: u32 = 0;
+    /// Member ID of `y`
+    pub const Y_MEMBER_ID: u32 = 1;
+}
+
+impl omg_cdr::CdrEncode for Point {
+    fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+        writer.write_struct(self);
+    }
+}
+
+impl omg_cdr::CdrDecode for Point {
+    fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+        reader.read_struct()
+    }
+}
+
+impl omg_cdr::CdrStruct for Point {
+    fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+        omg_cdr::CdrEncode::encode(&self.x, writer);
+        omg_cdr::CdrEncode::encode(&self.y, writer);
+    }
+
+    fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+        Ok(Self {
+            x: omg_cdr::CdrDecode::decode(reader)?,
+            y: omg_cdr::CdrDecode::decode(reader)?,
+        })
+    }
+}
+
+impl omg_cdr::CdrBounds for Point {
+    const IS_BOUNDED: bool = true;
+    const MAX_XCDR1_SIZE: Option<usize> = Some(8);
+    const MAX_XCDR2_SIZE: Option<usize> = Some(12);
+}
+
+#[allow(dead_code, non_camel_case_types)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Point3D {
+    #[serde(flatten)]
+    pub base: Point,
+    #[allow(non_snake_case)]
+    pub z: i32,
+}
+
+#[allow(dead_code)]
+impl Point3D {
+
+    pub fn new(base: Point, z: i32, ) -> Self {
+        Self {
+            base,
+            z,
+        }
+    }
+
+    pub fn base(&self) -> &Point {
+        &self.base
+    }
+
+    pub fn set_base(&mut self, value: Point) {
+        self.base = value;
+    }
+
+    pub fn z(&self) -> &i32 {
+        &self.z
+    }
+
+    pub fn set_z(&mut self, value: i32) {
+        self.z = value;
+    }
+
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for Point3D {
+    fn default() -> Self {
+        Self {
+            base: Default::default(),
+            z: 0,
+        }
+    }
+}
+
+impl omg_idl_rt::Extensible for Point3D {
+    const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+}
+
+impl omg_idl_rt::TypeName for Point3D {
+    const TYPE_NAME: &'static str = "Point3D";
+    const REPOSITORY_ID: &'static str = "IDL:Point3D:1.0";
+}
+
+#[allow(dead_code)]
+impl Point3D {
+    /// Member ID of `z`
+    pub const Z_MEMBER_ID: u32 = 2;
+}
+
+impl omg_cdr::CdrEncode for Point3D {
+    fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+        writer.write_struct(self);
+    }
+}
+
+impl omg_cdr::CdrDecode for Point3D {
+    fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+        reader.read_struct()
+    }
+}
+
+impl omg_cdr::CdrStruct for Point3D {
+    fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+        omg_cdr::CdrStruct::encode_members(&self.base, writer);
+        omg_cdr::CdrEncode::encode(&self.z, writer);
+    }
+
+    fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+        Ok(Self {
+            base: omg_cdr::CdrStruct::decode_members(reader)?,
+            z: omg_cdr::CdrDecode::decode(reader)?,
+        })
+    }
+}
+
+impl omg_cdr::CdrBounds for Point3D {
+    const IS_BOUNDED: bool = true;
+    const MAX_XCDR1_SIZE: Option<usize> = Some(12);
+    const MAX_XCDR2_SIZE: Option<usize> = Some(16);
+}
+
+#[allow(dead_code, non_camel_case_types)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum Position {
+    FLAT{ flat: Point, },
+    SPATIAL{ spatial: Point3D, },
+}
+
+impl Default for Position {
+    fn default() -> Self {
+        Position::FLAT { flat: Default::default() }
+    }
+}
+//
+// TODO custom de-/serializer
+//
+
+impl omg_idl_rt::Extensible for Position {
+    const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Final;
+}
+
+impl omg_idl_rt::TypeName for Position {
+    const TYPE_NAME: &'static str = "Position";
+    const REPOSITORY_ID: &'static str = "IDL:Position:1.0";
+}
+
+#[allow(dead_code)]
+impl Position {
+    /// Member ID of `flat`
+    pub const FLAT_MEMBER_ID: u32 = 1;
+    /// Member ID of `spatial`
+    pub const SPATIAL_MEMBER_ID: u32 = 2;
+}
+
+impl omg_cdr::CdrEncode for Position {
+    fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+        let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+        match self {
+            Position::FLAT { flat } => writer.write_union(extensibility, &2_i32, Self::FLAT_MEMBER_ID, flat, omg_cdr::CdrEncode::encode),
+            Position::SPATIAL { spatial } => writer.write_union(extensibility, &3_i32, Self::SPATIAL_MEMBER_ID, spatial, omg_cdr::CdrEncode::encode),
+        }
+    }
+}
+
+impl omg_cdr::CdrDecode for Position {
+    fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+        let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+        reader.read_union(extensibility, |discriminator: i32, reader| {
+            Ok(match discriminator {
+                2_i32 => Position::FLAT { flat: reader.read_branch(extensibility, Self::FLAT_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                3_i32 => Position::SPATIAL { spatial: reader.read_branch(extensibility, Self::SPATIAL_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                #[allow(unreachable_patterns)]
+                _ => return Err(omg_cdr::CdrError::InvalidDiscriminator),
+            })
+        })
+    }
+}
+
+impl omg_cdr::CdrBounds for Position {
+    const IS_BOUNDED: bool = true;
+    const MAX_XCDR1_SIZE: Option<usize> = Some(16);
+    const MAX_XCDR2_SIZE: Option<usize> = Some(20);
+}
+
+#[allow(non_snake_case)]
+pub mod Shapes {
+    use serde_derive::{Serialize, Deserialize};
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, PartialOrd, Eq, Ord)]
+    pub enum Kind {
+        #[default]
+        CIRCLE,
+        SQUARE,
+    }
+
+    #[allow(non_camel_case_types)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct KindError;
+
+    impl std::str::FromStr for Kind {
+        type Err = KindError;
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            match value {
+                "CIRCLE" => Ok(Kind::CIRCLE),
+                "SQUARE" => Ok(Kind::SQUARE),
+                _ => Err(KindError),
+            }
+        }
+    }
+
+    impl std::fmt::Display for Kind {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let enum_str = match self {
+                    Kind::CIRCLE => "CIRCLE",
+                    Kind::SQUARE => "SQUARE",
+            };
+            write!(f, "{enum_str}")
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Kind {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Final;
+    }
+
+    impl omg_idl_rt::TypeName for Kind {
+        const TYPE_NAME: &'static str = "Shapes::Kind";
+        const REPOSITORY_ID: &'static str = "IDL:Shapes/Kind:1.0";
+    }
+
+    impl omg_cdr::CdrEncode for Kind {
+        const PRIMITIVE: bool = true;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let value: u32 = match self {
+                Kind::CIRCLE => 0,
+                Kind::SQUARE => 1,
+            };
+            omg_cdr::CdrEncode::encode(&value, writer);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Kind {
+        const PRIMITIVE: bool = true;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            match <u32 as omg_cdr::CdrDecode>::decode(reader)? {
+                0 => Ok(Kind::CIRCLE),
+                1 => Ok(Kind::SQUARE),
+                value => Err(omg_cdr::CdrError::InvalidEnum(value)),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Kind {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(4);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(4);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Shape {
+        #[allow(non_snake_case)]
+        pub id: i32,
+        #[allow(non_snake_case)]
+        pub kind: Kind,
+    }
+
+    #[allow(dead_code)]
+    impl Shape {
+
+        pub fn new(id: i32, kind: Kind, ) -> Self {
+            Self {
+                id,
+                kind,
+            }
+        }
+
+        pub fn id(&self) -> &i32 {
+            &self.id
+        }
+
+        pub fn set_id(&mut self, value: i32) {
+            self.id = value;
+        }
+
+        pub fn kind(&self) -> &Kind {
+            &self.kind
+        }
+
+        pub fn set_kind(&mut self, value: Kind) {
+            self.kind = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Shape {
+        fn default() -> Self {
+            Self {
+                id: 0,
+                kind: Default::default(),
+            }
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug)]
+    pub struct ShapeKey {
+        pub id: i32,
+    }
+
+    impl omg_idl_rt::KeyHashEncode for ShapeKey {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<i32>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.id, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+        }
+    }
+
+    impl PartialEq for ShapeKey {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other).is_eq()
+        }
+    }
+
+    impl Eq for ShapeKey {}
+
+    impl PartialOrd for ShapeKey {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for ShapeKey {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other)
+        }
+    }
+
+    impl std::hash::Hash for ShapeKey {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            omg_idl_rt::hash_key(self, state);
+        }
+    }
+
+    impl omg_idl_rt::Keyed for Shape {
+        type Key = ShapeKey;
+
+        fn key(&self) -> Self::Key {
+            ShapeKey {
+                id: self.id,
+            }
+        }
+    }
+
+    /// Equal if the key members are equal, other members are ignored
+    impl PartialEq for Shape {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+                .is_eq()
+        }
+    }
+
+    impl Eq for Shape {}
+
+    impl PartialOrd for Shape {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    /// Orders by the key members, other members are ignored
+    impl Ord for Shape {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+        }
+    }
+
+    /// Hashes the key members only, consistent with `PartialEq`
+    impl std::hash::Hash for Shape {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            let mut writer = omg_idl_rt::KeyHolderWriter::hashing(state);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.id, &mut writer);
+        }
+    }
+
+    impl omg_idl_rt::KeyHashEncode for Shape {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<i32>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.id, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Shape {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Mutable;
+    }
+
+    impl omg_idl_rt::TypeName for Shape {
+        const TYPE_NAME: &'static str = "Shapes::Shape";
+        const REPOSITORY_ID: &'static str = "IDL:Shapes/Shape:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Shape {
+        /// Member ID of `id`
+        pub const ID_MEMBER_ID: u32 = 0;
+        /// Member ID of `kind`
+        pub const KIND_MEMBER_ID: u32 = 1;
+    }
+
+    impl omg_cdr::CdrEncode for Shape {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Shape {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Shape {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_member(Self::ID_MEMBER_ID, true, &self.id, omg_cdr::CdrEncode::encode);
+            writer.write_member(Self::KIND_MEMBER_ID, false, &self.kind, omg_cdr::CdrEncode::encode);
+        }
+
+        fn decode_member(&mut self, id: u32, reader: &mut omg_cdr::CdrReader<'_>) -> Result<bool, omg_cdr::CdrError> {
+            match id {
+                Self::ID_MEMBER_ID => self.id = omg_cdr::CdrDecode::decode(reader)?,
+                Self::KIND_MEMBER_ID => self.kind = omg_cdr::CdrDecode::decode(reader)?,
+                _ => return Ok(false),
+            }
+            Ok(true)
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Shape {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(20);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(20);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    pub type ShapeAlias = Shape;
+
+}
+
+#[allow(dead_code, non_upper_case_globals)]
+pub const FLAT: i32 = 2;
+
+#[allow(dead_code, non_upper_case_globals)]
+pub const SPATIAL: i32 = 3;
@@ -0,0 +1,518 @@
+
+#[allow(non_snake_case)]
+pub mod Sensor {
+    use serde_derive::{Serialize, Deserialize};
+
+    #[allow(dead_code, non_camel_case_types)]
+    pub type Name = heapless::String<{ MAX_NAME as usize }>;
+
+    #[allow(dead_code, non_camel_case_types)]
+    pub type Samples = heapless::Vec<f32, 8>;
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, PartialOrd, Eq, Ord)]
+    pub enum Unit {
+        CELSIUS,
+        #[default]
+        KELVIN,
+    }
+
+    #[allow(non_camel_case_types)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct UnitError;
+
+    impl core::str::FromStr for Unit {
+        type Err = UnitError;
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            match value {
+                "CELSIUS" => Ok(Unit::CELSIUS),
+                "KELVIN" => Ok(Unit::KELVIN),
+                _ => Err(UnitError),
+            }
+        }
+    }
+
+    impl core::fmt::Display for Unit {
+        fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+            let enum_str = match self {
+                    Unit::CELSIUS => "CELSIUS",
+                    Unit::KELVIN => "KELVIN",
+            };
+            write!(f, "{enum_str}")
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Unit {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Unit {
+        const TYPE_NAME: &'static str = "Sensor::Unit";
+        const REPOSITORY_ID: &'static str = "IDL:Sensor/Unit:1.0";
+    }
+
+    impl omg_cdr::CdrEncode for Unit {
+        const PRIMITIVE: bool = true;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let value: u32 = match self {
+                Unit::CELSIUS => 0,
+                Unit::KELVIN => 1,
+            };
+            omg_cdr::CdrEncode::encode(&value, writer);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Unit {
+        const PRIMITIVE: bool = true;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            match <u32 as omg_cdr::CdrDecode>::decode(reader)? {
+                0 => Ok(Unit::CELSIUS),
+                1 => Ok(Unit::KELVIN),
+                value => Err(omg_cdr::CdrError::InvalidEnum(value)),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Unit {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(4);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(4);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Reading {
+        #[allow(non_snake_case)]
+        pub id: Name,
+        #[allow(non_snake_case)]
+        pub kind: heapless::String<8>,
+        #[allow(non_snake_case)]
+        pub unit: Unit,
+        #[allow(non_snake_case)]
+        pub samples: Samples,
+        #[allow(non_snake_case)]
+        pub aliases: heapless::Vec<Name, 4>,
+        #[allow(non_snake_case)]
+        #[serde(with = "serde_arrays")]
+        pub labels: [heapless::String<{ MAX_NAME as usize }>;2_usize],
+        #[allow(non_snake_case)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub note: Option<heapless::String<32>>,
+    }
+
+    #[allow(dead_code)]
+    impl Reading {
+
+        pub fn new(id: Name, kind: heapless::String<8>, unit: Unit, samples: Samples, aliases: heapless::Vec<Name, 4>, labels: [heapless::String<{ MAX_NAME as usize }>;2_usize], ) -> Self {
+            Self {
+                id,
+                kind,
+                unit,
+                samples,
+                aliases,
+                labels,
+                note: None,
+            }
+        }
+
+        pub fn id(&self) -> &Name {
+            &self.id
+        }
+
+        pub fn set_id(&mut self, value: Name) {
+            self.id = value;
+        }
+
+        pub fn kind(&self) -> &heapless::String<8> {
+            &self.kind
+        }
+
+        pub fn set_kind(&mut self, value: heapless::String<8>) {
+            self.kind = value;
+        }
+
+        pub fn unit(&self) -> &Unit {
+            &self.unit
+        }
+
+        pub fn set_unit(&mut self, value: Unit) {
+            self.unit = value;
+        }
+
+        pub fn samples(&self) -> &Samples {
+            &self.samples
+        }
+
+        pub fn set_samples(&mut self, value: Samples) {
+            self.samples = value;
+        }
+
+        pub fn aliases(&self) -> &heapless::Vec<Name, 4> {
+            &self.aliases
+        }
+
+        pub fn set_aliases(&mut self, value: heapless::Vec<Name, 4>) {
+            self.aliases = value;
+        }
+
+        pub fn labels(&self) -> &[heapless::String<{ MAX_NAME as usize }>;2_usize] {
+            &self.labels
+        }
+
+        pub fn set_labels(&mut self, value: [heapless::String<{ MAX_NAME as usize }>;2_usize]) {
+            self.labels = value;
+        }
+
+        pub fn note(&self) -> Option<&heapless::String<32>> {
+            self.note.as_ref()
+        }
+
+        pub fn set_note(&mut self, value: Option<heapless::String<32>>) {
+            self.note = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Reading {
+        fn default() -> Self {
+            Self {
+                id: heapless::String::new(),
+                kind: "probe".try_into().unwrap(),
+                unit: Unit::KELVIN,
+                samples: heapless::Vec::new(),
+                aliases: heapless::Vec::new(),
+                labels: core::array::from_fn(|_| heapless::String::new()),
+                note: None,
+            }
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug)]
+    pub struct ReadingKey {
+        pub id: Name,
+    }
+
+    impl omg_idl_rt::KeyHashEncode for ReadingKey {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().bounded_string(16);
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.id, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> core::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+        }
+    }
+
+    impl PartialEq for ReadingKey {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other).is_eq()
+        }
+    }
+
+    impl Eq for ReadingKey {}
+
+    impl PartialOrd for ReadingKey {
+        fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for ReadingKey {
+        fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other)
+        }
+    }
+
+    impl core::hash::Hash for ReadingKey {
+        fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+            omg_idl_rt::hash_key(self, state);
+        }
+    }
+
+    impl omg_idl_rt::Keyed for Reading {
+        type Key = ReadingKey;
+
+        fn key(&self) -> Self::Key {
+            ReadingKey {
+                id: self.id.clone(),
+            }
+        }
+    }
+
+    /// Equal if the key members are equal, other members are ignored
+    impl PartialEq for Reading {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+                .is_eq()
+        }
+    }
+
+    impl Eq for Reading {}
+
+    impl PartialOrd for Reading {
+        fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    /// Orders by the key members, other members are ignored
+    impl Ord for Reading {
+        fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+        }
+    }
+
+    /// Hashes the key members only, consistent with `PartialEq`
+    impl core::hash::Hash for Reading {
+        fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+            let mut writer = omg_idl_rt::KeyHolderWriter::hashing(state);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.id, &mut writer);
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Reading {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Reading {
+        const TYPE_NAME: &'static str = "Sensor::Reading";
+        const REPOSITORY_ID: &'static str = "IDL:Sensor/Reading:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Reading {
+        /// Member ID of `id`
+        pub const ID_MEMBER_ID: u32 = 0;
+        /// Member ID of `kind`
+        pub const KIND_MEMBER_ID: u32 = 1;
+        /// Member ID of `unit`
+        pub const UNIT_MEMBER_ID: u32 = 2;
+        /// Member ID of `samples`
+        pub const SAMPLES_MEMBER_ID: u32 = 3;
+        /// Member ID of `aliases`
+        pub const ALIASES_MEMBER_ID: u32 = 4;
+        /// Member ID of `labels`
+        pub const LABELS_MEMBER_ID: u32 = 5;
+        /// Member ID of `note`
+        pub const NOTE_MEMBER_ID: u32 = 6;
+    }
+
+    impl omg_cdr::CdrEncode for Reading {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Reading {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Reading {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.id, writer);
+            omg_cdr::CdrEncode::encode(&self.kind, writer);
+            omg_cdr::CdrEncode::encode(&self.unit, writer);
+            omg_cdr::CdrEncode::encode(&self.samples, writer);
+            omg_cdr::CdrEncode::encode(&self.aliases, writer);
+            omg_cdr::CdrEncode::encode(&self.labels, writer);
+            writer.write_optional(Self::NOTE_MEMBER_ID, &self.note, omg_cdr::CdrEncode::encode);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                id: omg_cdr::CdrDecode::decode(reader)?,
+                kind: omg_cdr::CdrDecode::decode(reader)?,
+                unit: omg_cdr::CdrDecode::decode(reader)?,
+                samples: omg_cdr::CdrDecode::decode(reader)?,
+                aliases: omg_cdr::CdrDecode::decode(reader)?,
+                labels: omg_cdr::CdrDecode::decode(reader)?,
+                note: reader.read_optional(Self::NOTE_MEMBER_ID, omg_cdr::CdrDecode::decode)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Reading {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(272);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(277);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub enum Calibration {
+        CELSIUS{ table: heapless::String<12>, },
+        KELVIN{ factors: heapless::Vec<f64, 3>, },
+    }
+
+    impl Default for Calibration {
+        fn default() -> Self {
+            Calibration::CELSIUS { table: heapless::String::new() }
+        }
+    }
+//
+// TODO custom de-/serializer
+//
+
+    impl omg_idl_rt::Extensible for Calibration {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Calibration {
+        const TYPE_NAME: &'static str = "Sensor::Calibration";
+        const REPOSITORY_ID: &'static str = "IDL:Sensor/Calibration:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Calibration {
+        /// Member ID of `table`
+        pub const TABLE_MEMBER_ID: u32 = 1;
+        /// Member ID of `factors`
+        pub const FACTORS_MEMBER_ID: u32 = 2;
+    }
+
+    impl omg_cdr::CdrEncode for Calibration {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            match self {
+                Calibration::CELSIUS { table } => writer.write_union(extensibility, &Unit::CELSIUS, Self::TABLE_MEMBER_ID, table, omg_cdr::CdrEncode::encode),
+                Calibration::KELVIN { factors } => writer.write_union(extensibility, &Unit::KELVIN, Self::FACTORS_MEMBER_ID, factors, omg_cdr::CdrEncode::encode),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Calibration {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            reader.read_union(extensibility, |discriminator: Unit, reader| {
+                Ok(match discriminator {
+                    Unit::CELSIUS => Calibration::CELSIUS { table: reader.read_branch(extensibility, Self::TABLE_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    Unit::KELVIN => Calibration::KELVIN { factors: reader.read_branch(extensibility, Self::FACTORS_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    #[allow(unreachable_patterns)]
+                    _ => return Err(omg_cdr::CdrError::InvalidDiscriminator),
+                })
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Calibration {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(32);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(36);
+    }
+
+    #[allow(non_snake_case)]
+    pub mod Bus {
+        use serde_derive::{Serialize, Deserialize};
+
+        #[allow(dead_code, non_camel_case_types)]
+        pub type Frame = heapless::Vec<u8, 32>;
+
+        #[allow(dead_code, non_camel_case_types)]
+        #[derive(Serialize, Deserialize, Clone, Debug)]
+        pub struct Packet {
+            #[allow(non_snake_case)]
+            pub address: u16,
+            #[allow(non_snake_case)]
+            pub frame: Frame,
+        }
+
+        #[allow(dead_code)]
+        impl Packet {
+
+            pub fn new(address: u16, frame: Frame, ) -> Self {
+                Self {
+                    address,
+                    frame,
+                }
+            }
+
+            pub fn address(&self) -> &u16 {
+                &self.address
+            }
+
+            pub fn set_address(&mut self, value: u16) {
+                self.address = value;
+            }
+
+            pub fn frame(&self) -> &Frame {
+                &self.frame
+            }
+
+            pub fn set_frame(&mut self, value: Frame) {
+                self.frame = value;
+            }
+
+        }
+
+        #[allow(clippy::derivable_impls)]
+        impl Default for Packet {
+            fn default() -> Self {
+                Self {
+                    address: 0,
+                    frame: heapless::Vec::new(),
+                }
+            }
+        }
+
+        impl omg_idl_rt::Extensible for Packet {
+            const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+        }
+
+        impl omg_idl_rt::TypeName for Packet {
+            const TYPE_NAME: &'static str = "Sensor::Bus::Packet";
+            const REPOSITORY_ID: &'static str = "IDL:Sensor/Bus/Packet:1.0";
+        }
+
+        #[allow(dead_code)]
+        impl Packet {
+            /// Member ID of `address`
+            pub const ADDRESS_MEMBER_ID: u32 = 0;
+            /// Member ID of `frame`
+            pub const FRAME_MEMBER_ID: u32 = 1;
+        }
+
+        impl omg_cdr::CdrEncode for Packet {
+            fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+                writer.write_struct(self);
+            }
+        }
+
+        impl omg_cdr::CdrDecode for Packet {
+            fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+                reader.read_struct()
+            }
+        }
+
+        impl omg_cdr::CdrStruct for Packet {
+            fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+                omg_cdr::CdrEncode::encode(&self.address, writer);
+                omg_cdr::CdrEncode::encode(&self.frame, writer);
+            }
+
+            fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+                Ok(Self {
+                    address: omg_cdr::CdrDecode::decode(reader)?,
+                    frame: omg_cdr::CdrDecode::decode(reader)?,
+                })
+            }
+        }
+
+        impl omg_cdr::CdrBounds for Packet {
+            const IS_BOUNDED: bool = true;
+            const MAX_XCDR1_SIZE: Option<usize> = Some(40);
+            const MAX_XCDR2_SIZE: Option<usize> = Some(44);
+        }
+
+    }
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const MAX_NAME: i32 = 16;
+
+}
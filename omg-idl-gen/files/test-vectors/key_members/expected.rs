@@ -0,0 +1,964 @@
+
+#[allow(non_snake_case)]
+pub mod Sensors {
+    use serde_derive::{Serialize, Deserialize};
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, PartialOrd, Eq, Ord)]
+    pub enum Kind {
+        #[default]
+        TEMPERATURE,
+        PRESSURE,
+    }
+
+    #[allow(non_camel_case_types)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct KindError;
+
+    impl std::str::FromStr for Kind {
+        type Err = KindError;
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            match value {
+                "TEMPERATURE" => Ok(Kind::TEMPERATURE),
+                "PRESSURE" => Ok(Kind::PRESSURE),
+                _ => Err(KindError),
+            }
+        }
+    }
+
+    impl std::fmt::Display for Kind {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let enum_str = match self {
+                    Kind::TEMPERATURE => "TEMPERATURE",
+                    Kind::PRESSURE => "PRESSURE",
+            };
+            write!(f, "{enum_str}")
+        }
+    }
+
+    impl omg_idl_rt::KeyHashEncode for Kind {
+        const KEY_SIZE: omg_idl_rt::KeySize = <u32 as omg_idl_rt::KeyHashEncode>::KEY_SIZE;
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&(self.clone() as u32), writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(
+                &(self.clone() as u32),
+                &(other.clone() as u32),
+            )
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Kind {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Kind {
+        const TYPE_NAME: &'static str = "Sensors::Kind";
+        const REPOSITORY_ID: &'static str = "IDL:Sensors/Kind:1.0";
+    }
+
+    impl omg_cdr::CdrEncode for Kind {
+        const PRIMITIVE: bool = true;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let value: u32 = match self {
+                Kind::TEMPERATURE => 0,
+                Kind::PRESSURE => 1,
+            };
+            omg_cdr::CdrEncode::encode(&value, writer);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Kind {
+        const PRIMITIVE: bool = true;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            match <u32 as omg_cdr::CdrDecode>::decode(reader)? {
+                0 => Ok(Kind::TEMPERATURE),
+                1 => Ok(Kind::PRESSURE),
+                value => Err(omg_cdr::CdrError::InvalidEnum(value)),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Kind {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(4);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(4);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, PartialOrd, Eq, Ord)]
+    pub enum Priority {
+        #[default]
+        LOW,
+        HIGH,
+    }
+
+    #[allow(non_camel_case_types)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct PriorityError;
+
+    impl std::str::FromStr for Priority {
+        type Err = PriorityError;
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            match value {
+                "LOW" => Ok(Priority::LOW),
+                "HIGH" => Ok(Priority::HIGH),
+                _ => Err(PriorityError),
+            }
+        }
+    }
+
+    impl std::fmt::Display for Priority {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let enum_str = match self {
+                    Priority::LOW => "LOW",
+                    Priority::HIGH => "HIGH",
+            };
+            write!(f, "{enum_str}")
+        }
+    }
+
+    impl omg_idl_rt::KeyHashEncode for Priority {
+        const KEY_SIZE: omg_idl_rt::KeySize = <u8 as omg_idl_rt::KeyHashEncode>::KEY_SIZE;
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&(self.clone() as u8), writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(
+                &(self.clone() as u8),
+                &(other.clone() as u8),
+            )
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Priority {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Priority {
+        const TYPE_NAME: &'static str = "Sensors::Priority";
+        const REPOSITORY_ID: &'static str = "IDL:Sensors/Priority:1.0";
+    }
+
+    impl omg_cdr::CdrEncode for Priority {
+        const PRIMITIVE: bool = true;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let value: u8 = match self {
+                Priority::LOW => 0,
+                Priority::HIGH => 1,
+            };
+            omg_cdr::CdrEncode::encode(&value, writer);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Priority {
+        const PRIMITIVE: bool = true;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            match <u8 as omg_cdr::CdrDecode>::decode(reader)? {
+                0 => Ok(Priority::LOW),
+                1 => Ok(Priority::HIGH),
+                value => Err(omg_cdr::CdrError::InvalidEnum(value.into())),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Priority {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(1);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(1);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Location {
+        #[allow(non_snake_case)]
+        pub floor: i16,
+        #[allow(non_snake_case)]
+        pub room: String,
+    }
+
+    #[allow(dead_code)]
+    impl Location {
+
+        pub fn new(floor: i16, room: String, ) -> Self {
+            Self {
+                floor,
+                room,
+            }
+        }
+
+        pub fn floor(&self) -> &i16 {
+            &self.floor
+        }
+
+        pub fn set_floor(&mut self, value: i16) {
+            self.floor = value;
+        }
+
+        pub fn room(&self) -> &String {
+            &self.room
+        }
+
+        pub fn set_room(&mut self, value: String) {
+            self.room = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Location {
+        fn default() -> Self {
+            Self {
+                floor: 0,
+                room: String::new(),
+            }
+        }
+    }
+
+    impl omg_idl_rt::KeyHashEncode for Location {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<i16>().bounded_string(16);
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.floor, writer);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.room, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.floor, &other.floor)
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.room, &other.room))
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Location {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Location {
+        const TYPE_NAME: &'static str = "Sensors::Location";
+        const REPOSITORY_ID: &'static str = "IDL:Sensors/Location:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Location {
+        /// Member ID of `floor`
+        pub const FLOOR_MEMBER_ID: u32 = 0;
+        /// Member ID of `room`
+        pub const ROOM_MEMBER_ID: u32 = 1;
+    }
+
+    impl omg_cdr::CdrEncode for Location {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Location {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Location {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.floor, writer);
+            omg_cdr::CdrEncode::encode(&self.room, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                floor: omg_cdr::CdrDecode::decode(reader)?,
+                room: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Location {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(25);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(29);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Device {
+        #[allow(non_snake_case)]
+        pub id: i32,
+        #[allow(non_snake_case)]
+        pub vendor: String,
+    }
+
+    #[allow(dead_code)]
+    impl Device {
+
+        pub fn new(id: i32, vendor: String, ) -> Self {
+            Self {
+                id,
+                vendor,
+            }
+        }
+
+        pub fn id(&self) -> &i32 {
+            &self.id
+        }
+
+        pub fn set_id(&mut self, value: i32) {
+            self.id = value;
+        }
+
+        pub fn vendor(&self) -> &String {
+            &self.vendor
+        }
+
+        pub fn set_vendor(&mut self, value: String) {
+            self.vendor = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Device {
+        fn default() -> Self {
+            Self {
+                id: 0,
+                vendor: String::new(),
+            }
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug)]
+    pub struct DeviceKey {
+        pub id: i32,
+    }
+
+    impl omg_idl_rt::KeyHashEncode for DeviceKey {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<i32>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.id, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+        }
+    }
+
+    impl PartialEq for DeviceKey {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other).is_eq()
+        }
+    }
+
+    impl Eq for DeviceKey {}
+
+    impl PartialOrd for DeviceKey {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for DeviceKey {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other)
+        }
+    }
+
+    impl std::hash::Hash for DeviceKey {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            omg_idl_rt::hash_key(self, state);
+        }
+    }
+
+    impl omg_idl_rt::Keyed for Device {
+        type Key = DeviceKey;
+
+        fn key(&self) -> Self::Key {
+            DeviceKey {
+                id: self.id,
+            }
+        }
+    }
+
+    /// Equal if the key members are equal, other members are ignored
+    impl PartialEq for Device {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+                .is_eq()
+        }
+    }
+
+    impl Eq for Device {}
+
+    impl PartialOrd for Device {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    /// Orders by the key members, other members are ignored
+    impl Ord for Device {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+        }
+    }
+
+    /// Hashes the key members only, consistent with `PartialEq`
+    impl std::hash::Hash for Device {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            let mut writer = omg_idl_rt::KeyHolderWriter::hashing(state);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.id, &mut writer);
+        }
+    }
+
+    impl omg_idl_rt::KeyHashEncode for Device {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<i32>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.id, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Device {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Device {
+        const TYPE_NAME: &'static str = "Sensors::Device";
+        const REPOSITORY_ID: &'static str = "IDL:Sensors/Device:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Device {
+        /// Member ID of `id`
+        pub const ID_MEMBER_ID: u32 = 0;
+        /// Member ID of `vendor`
+        pub const VENDOR_MEMBER_ID: u32 = 1;
+    }
+
+    impl omg_cdr::CdrEncode for Device {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Device {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Device {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.id, writer);
+            omg_cdr::CdrEncode::encode(&self.vendor, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                id: omg_cdr::CdrDecode::decode(reader)?,
+                vendor: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Device {
+        const IS_BOUNDED: bool = false;
+        const MAX_XCDR1_SIZE: Option<usize> = None;
+        const MAX_XCDR2_SIZE: Option<usize> = None;
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Reading {
+        #[allow(non_snake_case)]
+        pub kind: Kind,
+        #[allow(non_snake_case)]
+        pub location: Location,
+        #[allow(non_snake_case)]
+        pub device: Device,
+        #[allow(non_snake_case)]
+        #[serde(with = "serde_arrays")]
+        pub channel: [u8;2_usize],
+        #[allow(non_snake_case)]
+        pub priority: Priority,
+        #[allow(non_snake_case)]
+        pub value: f64,
+    }
+
+    #[allow(dead_code)]
+    impl Reading {
+
+        pub fn new(kind: Kind, location: Location, device: Device, channel: [u8;2_usize], priority: Priority, value: f64, ) -> Self {
+            Self {
+                kind,
+                location,
+                device,
+                channel,
+                priority,
+                value,
+            }
+        }
+
+        pub fn kind(&self) -> &Kind {
+            &self.kind
+        }
+
+        pub fn set_kind(&mut self, value: Kind) {
+            self.kind = value;
+        }
+
+        pub fn location(&self) -> &Location {
+            &self.location
+        }
+
+        pub fn set_location(&mut self, value: Location) {
+            self.location = value;
+        }
+
+        pub fn device(&self) -> &Device {
+            &self.device
+        }
+
+        pub fn set_device(&mut self, value: Device) {
+            self.device = value;
+        }
+
+        pub fn channel(&self) -> &[u8;2_usize] {
+            &self.channel
+        }
+
+        pub fn set_channel(&mut self, value: [u8;2_usize]) {
+            self.channel = value;
+        }
+
+        pub fn priority(&self) -> &Priority {
+            &self.priority
+        }
+
+        pub fn set_priority(&mut self, value: Priority) {
+            self.priority = value;
+        }
+
+        pub fn value(&self) -> &f64 {
+            &self.value
+        }
+
+        pub fn set_value(&mut self, value: f64) {
+            self.value = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Reading {
+        fn default() -> Self {
+            Self {
+                kind: Default::default(),
+                location: Default::default(),
+                device: Default::default(),
+                channel: std::array::from_fn(|_| 0),
+                priority: Default::default(),
+                value: 0.0,
+            }
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug)]
+    pub struct ReadingKey {
+        pub kind: Kind,
+        pub location: Location,
+        pub device: Device,
+        pub channel: [u8;2_usize],
+        pub priority: Priority,
+    }
+
+    impl omg_idl_rt::KeyHashEncode for ReadingKey {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<Kind>().field::<Location>().field::<Device>().field::<[u8;2_usize]>().field::<Priority>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.kind, writer);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.location, writer);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.device, writer);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.channel, writer);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.priority, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.kind, &other.kind)
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.location, &other.location))
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.device, &other.device))
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.channel, &other.channel))
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.priority, &other.priority))
+        }
+    }
+
+    impl PartialEq for ReadingKey {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other).is_eq()
+        }
+    }
+
+    impl Eq for ReadingKey {}
+
+    impl PartialOrd for ReadingKey {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for ReadingKey {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other)
+        }
+    }
+
+    impl std::hash::Hash for ReadingKey {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            omg_idl_rt::hash_key(self, state);
+        }
+    }
+
+    impl omg_idl_rt::Keyed for Reading {
+        type Key = ReadingKey;
+
+        fn key(&self) -> Self::Key {
+            ReadingKey {
+                kind: self.kind.clone(),
+                location: self.location.clone(),
+                device: self.device.clone(),
+                channel: self.channel,
+                priority: self.priority.clone(),
+            }
+        }
+    }
+
+    /// Equal if the key members are equal, other members are ignored
+    impl PartialEq for Reading {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.kind, &other.kind)
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.location, &other.location))
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.device, &other.device))
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.channel, &other.channel))
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.priority, &other.priority))
+                .is_eq()
+        }
+    }
+
+    impl Eq for Reading {}
+
+    impl PartialOrd for Reading {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    /// Orders by the key members, other members are ignored
+    impl Ord for Reading {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.kind, &other.kind)
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.location, &other.location))
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.device, &other.device))
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.channel, &other.channel))
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.priority, &other.priority))
+        }
+    }
+
+    /// Hashes the key members only, consistent with `PartialEq`
+    impl std::hash::Hash for Reading {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            let mut writer = omg_idl_rt::KeyHolderWriter::hashing(state);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.kind, &mut writer);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.location, &mut writer);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.device, &mut writer);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.channel, &mut writer);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.priority, &mut writer);
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Reading {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Reading {
+        const TYPE_NAME: &'static str = "Sensors::Reading";
+        const REPOSITORY_ID: &'static str = "IDL:Sensors/Reading:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Reading {
+        /// Member ID of `kind`
+        pub const KIND_MEMBER_ID: u32 = 0;
+        /// Member ID of `location`
+        pub const LOCATION_MEMBER_ID: u32 = 1;
+        /// Member ID of `device`
+        pub const DEVICE_MEMBER_ID: u32 = 2;
+        /// Member ID of `channel`
+        pub const CHANNEL_MEMBER_ID: u32 = 3;
+        /// Member ID of `priority`
+        pub const PRIORITY_MEMBER_ID: u32 = 4;
+        /// Member ID of `value`
+        pub const VALUE_MEMBER_ID: u32 = 5;
+    }
+
+    impl omg_cdr::CdrEncode for Reading {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Reading {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Reading {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.kind, writer);
+            omg_cdr::CdrEncode::encode(&self.location, writer);
+            omg_cdr::CdrEncode::encode(&self.device, writer);
+            omg_cdr::CdrEncode::encode(&self.channel, writer);
+            omg_cdr::CdrEncode::encode(&self.priority, writer);
+            omg_cdr::CdrEncode::encode(&self.value, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                kind: omg_cdr::CdrDecode::decode(reader)?,
+                location: omg_cdr::CdrDecode::decode(reader)?,
+                device: omg_cdr::CdrDecode::decode(reader)?,
+                channel: omg_cdr::CdrDecode::decode(reader)?,
+                priority: omg_cdr::CdrDecode::decode(reader)?,
+                value: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Reading {
+        const IS_BOUNDED: bool = false;
+        const MAX_XCDR1_SIZE: Option<usize> = None;
+        const MAX_XCDR2_SIZE: Option<usize> = None;
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    pub type Cells = [[i32;3_usize];2_usize];
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Sample {
+        #[allow(non_snake_case)]
+        pub source: Device,
+        #[allow(non_snake_case)]
+        pub cells: Cells,
+        #[allow(non_snake_case)]
+        pub stamp: i64,
+    }
+
+    #[allow(dead_code)]
+    impl Sample {
+
+        pub fn new(source: Device, cells: Cells, stamp: i64, ) -> Self {
+            Self {
+                source,
+                cells,
+                stamp,
+            }
+        }
+
+        pub fn source(&self) -> &Device {
+            &self.source
+        }
+
+        pub fn set_source(&mut self, value: Device) {
+            self.source = value;
+        }
+
+        pub fn cells(&self) -> &Cells {
+            &self.cells
+        }
+
+        pub fn set_cells(&mut self, value: Cells) {
+            self.cells = value;
+        }
+
+        pub fn stamp(&self) -> &i64 {
+            &self.stamp
+        }
+
+        pub fn set_stamp(&mut self, value: i64) {
+            self.stamp = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Sample {
+        fn default() -> Self {
+            Self {
+                source: Default::default(),
+                cells: std::array::from_fn(|_| std::array::from_fn(|_| 0)),
+                stamp: 0,
+            }
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug)]
+    pub struct SampleKey {
+        pub source_id: i32,
+        pub cells_1_2: i32,
+    }
+
+    impl omg_idl_rt::KeyHashEncode for SampleKey {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<i32>().field::<i32>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.source_id, writer);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.cells_1_2, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.source_id, &other.source_id)
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.cells_1_2, &other.cells_1_2))
+        }
+    }
+
+    impl PartialEq for SampleKey {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other).is_eq()
+        }
+    }
+
+    impl Eq for SampleKey {}
+
+    impl PartialOrd for SampleKey {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for SampleKey {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other)
+        }
+    }
+
+    impl std::hash::Hash for SampleKey {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            omg_idl_rt::hash_key(self, state);
+        }
+    }
+
+    impl omg_idl_rt::Keyed for Sample {
+        type Key = SampleKey;
+
+        fn key(&self) -> Self::Key {
+            SampleKey {
+                source_id: self.source.id,
+                cells_1_2: self.cells[1][2],
+            }
+        }
+    }
+
+    /// Equal if the key members are equal, other members are ignored
+    impl PartialEq for Sample {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.source.id, &other.source.id)
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.cells[1][2], &other.cells[1][2]))
+                .is_eq()
+        }
+    }
+
+    impl Eq for Sample {}
+
+    impl PartialOrd for Sample {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    /// Orders by the key members, other members are ignored
+    impl Ord for Sample {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.source.id, &other.source.id)
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.cells[1][2], &other.cells[1][2]))
+        }
+    }
+
+    /// Hashes the key members only, consistent with `PartialEq`
+    impl std::hash::Hash for Sample {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            let mut writer = omg_idl_rt::KeyHolderWriter::hashing(state);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.source.id, &mut writer);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.cells[1][2], &mut writer);
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Sample {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Sample {
+        const TYPE_NAME: &'static str = "Sensors::Sample";
+        const REPOSITORY_ID: &'static str = "IDL:Sensors/Sample:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Sample {
+        /// Member ID of `source`
+        pub const SOURCE_MEMBER_ID: u32 = 0;
+        /// Member ID of `cells`
+        pub const CELLS_MEMBER_ID: u32 = 1;
+        /// Member ID of `stamp`
+        pub const STAMP_MEMBER_ID: u32 = 2;
+    }
+
+    impl omg_cdr::CdrEncode for Sample {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Sample {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Sample {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.source, writer);
+            omg_cdr::CdrEncode::encode(&self.cells, writer);
+            omg_cdr::CdrEncode::encode(&self.stamp, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                source: omg_cdr::CdrDecode::decode(reader)?,
+                cells: omg_cdr::CdrDecode::decode(reader)?,
+                stamp: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Sample {
+        const IS_BOUNDED: bool = false;
+        const MAX_XCDR1_SIZE: Option<usize> = None;
+        const MAX_XCDR2_SIZE: Option<usize> = None;
+    }
+
+}
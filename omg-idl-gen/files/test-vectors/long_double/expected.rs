@@ -0,0 +1,334 @@
+
+#[allow(non_snake_case)]
+pub mod Physics {
+    use serde_derive::{Serialize, Deserialize};
+
+    #[allow(dead_code, non_camel_case_types)]
+    pub type Quad = omg_idl_rt::F128;
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Measurement {
+        #[allow(non_snake_case)]
+        pub id: i32,
+        #[allow(non_snake_case)]
+        pub value: omg_idl_rt::F128,
+        #[allow(non_snake_case)]
+        pub scale: Quad,
+        #[allow(non_snake_case)]
+        pub samples: Vec<omg_idl_rt::F128>,
+        #[allow(non_snake_case)]
+        #[serde(with = "serde_arrays")]
+        pub corners: [omg_idl_rt::F128;4_usize],
+        #[allow(non_snake_case)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub error: Option<omg_idl_rt::F128>,
+    }
+
+    #[allow(dead_code)]
+    impl Measurement {
+
+        pub fn new(id: i32, value: omg_idl_rt::F128, scale: Quad, samples: Vec<omg_idl_rt::F128>, corners: [omg_idl_rt::F128;4_usize], ) -> Self {
+            Self {
+                id,
+                value,
+                scale,
+                samples,
+                corners,
+                error: None,
+            }
+        }
+
+        pub fn id(&self) -> &i32 {
+            &self.id
+        }
+
+        pub fn set_id(&mut self, value: i32) {
+            self.id = value;
+        }
+
+        pub fn value(&self) -> &omg_idl_rt::F128 {
+            &self.value
+        }
+
+        pub fn set_value(&mut self, value: omg_idl_rt::F128) {
+            self.value = value;
+        }
+
+        pub fn scale(&self) -> &Quad {
+            &self.scale
+        }
+
+        pub fn set_scale(&mut self, value: Quad) {
+            self.scale = value;
+        }
+
+        pub fn samples(&self) -> &Vec<omg_idl_rt::F128> {
+            &self.samples
+        }
+
+        pub fn set_samples(&mut self, value: Vec<omg_idl_rt::F128>) {
+            self.samples = value;
+        }
+
+        pub fn corners(&self) -> &[omg_idl_rt::F128;4_usize] {
+            &self.corners
+        }
+
+        pub fn set_corners(&mut self, value: [omg_idl_rt::F128;4_usize]) {
+            self.corners = value;
+        }
+
+        pub fn error(&self) -> Option<&omg_idl_rt::F128> {
+            self.error.as_ref()
+        }
+
+        pub fn set_error(&mut self, value: Option<omg_idl_rt::F128>) {
+            self.error = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Measurement {
+        fn default() -> Self {
+            Self {
+                id: 0,
+                value: omg_idl_rt::F128::ZERO,
+                scale: omg_idl_rt::F128::from_f64(1.5),
+                samples: Vec::new(),
+                corners: std::array::from_fn(|_| omg_idl_rt::F128::ZERO),
+                error: None,
+            }
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug)]
+    pub struct MeasurementKey {
+        pub id: i32,
+    }
+
+    impl omg_idl_rt::KeyHashEncode for MeasurementKey {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<i32>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.id, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+        }
+    }
+
+    impl PartialEq for MeasurementKey {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other).is_eq()
+        }
+    }
+
+    impl Eq for MeasurementKey {}
+
+    impl PartialOrd for MeasurementKey {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for MeasurementKey {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other)
+        }
+    }
+
+    impl std::hash::Hash for MeasurementKey {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            omg_idl_rt::hash_key(self, state);
+        }
+    }
+
+    impl omg_idl_rt::Keyed for Measurement {
+        type Key = MeasurementKey;
+
+        fn key(&self) -> Self::Key {
+            MeasurementKey {
+                id: self.id,
+            }
+        }
+    }
+
+    /// Equal if the key members are equal, other members are ignored
+    impl PartialEq for Measurement {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+                .is_eq()
+        }
+    }
+
+    impl Eq for Measurement {}
+
+    impl PartialOrd for Measurement {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    /// Orders by the key members, other members are ignored
+    impl Ord for Measurement {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+        }
+    }
+
+    /// Hashes the key members only, consistent with `PartialEq`
+    impl std::hash::Hash for Measurement {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            let mut writer = omg_idl_rt::KeyHolderWriter::hashing(state);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.id, &mut writer);
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Measurement {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Measurement {
+        const TYPE_NAME: &'static str = "Physics::Measurement";
+        const REPOSITORY_ID: &'static str = "IDL:Physics/Measurement:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Measurement {
+        /// Member ID of `id`
+        pub const ID_MEMBER_ID: u32 = 0;
+        /// Member ID of `value`
+        pub const VALUE_MEMBER_ID: u32 = 1;
+        /// Member ID of `scale`
+        pub const SCALE_MEMBER_ID: u32 = 2;
+        /// Member ID of `samples`
+        pub const SAMPLES_MEMBER_ID: u32 = 3;
+        /// Member ID of `corners`
+        pub const CORNERS_MEMBER_ID: u32 = 4;
+        /// Member ID of `error`
+        pub const ERROR_MEMBER_ID: u32 = 5;
+    }
+
+    impl omg_cdr::CdrEncode for Measurement {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Measurement {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Measurement {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.id, writer);
+            omg_cdr::CdrEncode::encode(&self.value, writer);
+            omg_cdr::CdrEncode::encode(&self.scale, writer);
+            omg_cdr::CdrEncode::encode(&self.samples, writer);
+            omg_cdr::CdrEncode::encode(&self.corners, writer);
+            writer.write_optional(Self::ERROR_MEMBER_ID, &self.error, omg_cdr::CdrEncode::encode);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                id: omg_cdr::CdrDecode::decode(reader)?,
+                value: omg_cdr::CdrDecode::decode(reader)?,
+                scale: omg_cdr::CdrDecode::decode(reader)?,
+                samples: omg_cdr::CdrDecode::decode(reader)?,
+                corners: omg_cdr::CdrDecode::decode(reader)?,
+                error: reader.read_optional(Self::ERROR_MEMBER_ID, omg_cdr::CdrDecode::decode)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Measurement {
+        const IS_BOUNDED: bool = false;
+        const MAX_XCDR1_SIZE: Option<usize> = None;
+        const MAX_XCDR2_SIZE: Option<usize> = None;
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub enum Reading {
+        PRECISE{ precise: omg_idl_rt::F128, },
+        APPROXIMATE{ approximate: f64, },
+    }
+
+    impl Default for Reading {
+        fn default() -> Self {
+            Reading::PRECISE { precise: omg_idl_rt::F128::ZERO }
+        }
+    }
+//
+// TODO custom de-/serializer
+//
+
+    impl omg_idl_rt::Extensible for Reading {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Reading {
+        const TYPE_NAME: &'static str = "Physics::Reading";
+        const REPOSITORY_ID: &'static str = "IDL:Physics/Reading:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Reading {
+        /// Member ID of `precise`
+        pub const PRECISE_MEMBER_ID: u32 = 1;
+        /// Member ID of `approximate`
+        pub const APPROXIMATE_MEMBER_ID: u32 = 2;
+    }
+
+    impl omg_cdr::CdrEncode for Reading {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            match self {
+                Reading::PRECISE { precise } => writer.write_union(extensibility, &1_i32, Self::PRECISE_MEMBER_ID, precise, omg_cdr::CdrEncode::encode),
+                Reading::APPROXIMATE { approximate } => writer.write_union(extensibility, &2_i32, Self::APPROXIMATE_MEMBER_ID, approximate, omg_cdr::CdrEncode::encode),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Reading {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            reader.read_union(extensibility, |discriminator: i32, reader| {
+                Ok(match discriminator {
+                    1_i32 => Reading::PRECISE { precise: reader.read_branch(extensibility, Self::PRECISE_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    2_i32 => Reading::APPROXIMATE { approximate: reader.read_branch(extensibility, Self::APPROXIMATE_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    #[allow(unreachable_patterns)]
+                    _ => return Err(omg_cdr::CdrError::InvalidDiscriminator),
+                })
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Reading {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(24);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(24);
+    }
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const PLANCK: omg_idl_rt::F128 = omg_idl_rt::F128::from_f64(6.62607015e-34);
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const TWICE_PLANCK: Quad = omg_idl_rt::F128::from_f64(1.32521403e-33);
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const LIMIT: omg_idl_rt::F128 = omg_idl_rt::F128::from_f64(100.0);
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const PRECISE: i32 = 1;
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const APPROXIMATE: i32 = 2;
+
+}
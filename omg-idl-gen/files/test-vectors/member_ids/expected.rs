@@ -0,0 +1,510 @@
+use serde_derive::{Serialize, Deserialize};
+
+#[allow(dead_code, non_camel_case_types)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Sequential {
+    #[allow(non_snake_case)]
+    pub a: i32,
+    #[allow(non_snake_case)]
+    pub b: i32,
+    #[allow(non_snake_case)]
+    pub c: i32,
+}
+
+#[allow(dead_code)]
+impl Sequential {
+
+    pub fn new(a: i32, b: i32, c: i32, ) -> Self {
+        Self {
+            a,
+            b,
+            c,
+        }
+    }
+
+    pub fn a(&self) -> &i32 {
+        &self.a
+    }
+
+    pub fn set_a(&mut self, value: i32) {
+        self.a = value;
+    }
+
+    pub fn b(&self) -> &i32 {
+        &self.b
+    }
+
+    pub fn set_b(&mut self, value: i32) {
+        self.b = value;
+    }
+
+    pub fn c(&self) -> &i32 {
+        &self.c
+    }
+
+    pub fn set_c(&mut self, value: i32) {
+        self.c = value;
+    }
+
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for Sequential {
+    fn default() -> Self {
+        Self {
+            a: 0,
+            b: 0,
+            c: 0,
+        }
+    }
+}
+
+impl omg_idl_rt::Extensible for Sequential {
+    const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+}
+
+impl omg_idl_rt::TypeName for Sequential {
+    const TYPE_NAME: &'static str = "Sequential";
+    const REPOSITORY_ID: &'static str = "IDL:Sequential:1.0";
+}
+
+#[allow(dead_code)]
+impl Sequential {
+    /// Member ID of `a`
+    pub const A_MEMBER_ID: u32 = 0;
+    /// Member ID of `b`
+    pub const B_MEMBER_ID: u32 = 10;
+    /// Member ID of `c`
+    pub const C_MEMBER_ID: u32 = 11;
+}
+
+impl omg_cdr::CdrEncode for Sequential {
+    fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+        writer.write_struct(self);
+    }
+}
+
+impl omg_cdr::CdrDecode for Sequential {
+    fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+        reader.read_struct()
+    }
+}
+
+impl omg_cdr::CdrStruct for Sequential {
+    fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+        omg_cdr::CdrEncode::encode(&self.a, writer);
+        omg_cdr::CdrEncode::encode(&self.b, writer);
+        omg_cdr::CdrEncode::encode(&self.c, writer);
+    }
+
+    fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+        Ok(Self {
+            a: omg_cdr::CdrDecode::decode(reader)?,
+            b: omg_cdr::CdrDecode::decode(reader)?,
+            c: omg_cdr::CdrDecode::decode(reader)?,
+        })
+    }
+}
+
+impl omg_cdr::CdrBounds for Sequential {
+    const IS_BOUNDED: bool = true;
+    const MAX_XCDR1_SIZE: Option<usize> = Some(12);
+    const MAX_XCDR2_SIZE: Option<usize> = Some(16);
+}
+
+#[allow(dead_code, non_camel_case_types)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Derived {
+    #[serde(flatten)]
+    pub base: Sequential,
+    #[allow(non_snake_case)]
+    pub d: i32,
+}
+
+#[allow(dead_code)]
+impl Derived {
+
+    pub fn new(base: Sequential, d: i32, ) -> Self {
+        Self {
+            base,
+            d,
+        }
+    }
+
+    pub fn base(&self) -> &Sequential {
+        &self.base
+    }
+
+    pub fn set_base(&mut self, value: Sequential) {
+        self.base = value;
+    }
+
+    pub fn d(&self) -> &i32 {
+        &self.d
+    }
+
+    pub fn set_d(&mut self, value: i32) {
+        self.d = value;
+    }
+
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for Derived {
+    fn default() -> Self {
+        Self {
+            base: Default::default(),
+            d: 0,
+        }
+    }
+}
+
+impl omg_idl_rt::Extensible for Derived {
+    const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+}
+
+impl omg_idl_rt::TypeName for Derived {
+    const TYPE_NAME: &'static str = "Derived";
+    const REPOSITORY_ID: &'static str = "IDL:Derived:1.0";
+}
+
+#[allow(dead_code)]
+impl Derived {
+    /// Member ID of `d`
+    pub const D_MEMBER_ID: u32 = 12;
+}
+
+impl omg_cdr::CdrEncode for Derived {
+    fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+        writer.write_struct(self);
+    }
+}
+
+impl omg_cdr::CdrDecode for Derived {
+    fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+        reader.read_struct()
+    }
+}
+
+impl omg_cdr::CdrStruct for Derived {
+    fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+        omg_cdr::CdrStruct::encode_members(&self.base, writer);
+        omg_cdr::CdrEncode::encode(&self.d, writer);
+    }
+
+    fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+        Ok(Self {
+            base: omg_cdr::CdrStruct::decode_members(reader)?,
+            d: omg_cdr::CdrDecode::decode(reader)?,
+        })
+    }
+}
+
+impl omg_cdr::CdrBounds for Derived {
+    const IS_BOUNDED: bool = true;
+    const MAX_XCDR1_SIZE: Option<usize> = Some(16);
+    const MAX_XCDR2_SIZE: Option<usize> = Some(20);
+}
+
+#[allow(dead_code, non_camel_case_types)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum Choice {
+    ONE{ x: i32, },
+    TWO{ y: i16, },
+    default{ z: u8, },
+}
+
+impl Default for Choice {
+    fn default() -> Self {
+        Choice::default { z: 0 }
+    }
+}
+//
+// TODO custom de-/serializer
+//
+
+impl omg_idl_rt::Extensible for Choice {
+    const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+}
+
+impl omg_idl_rt::TypeName for Choice {
+    const TYPE_NAME: &'static str = "Choice";
+    const REPOSITORY_ID: &'static str = "IDL:Choice:1.0";
+}
+
+#[allow(dead_code)]
+impl Choice {
+    /// Member ID of `x`
+    pub const X_MEMBER_ID: u32 = 1;
+    /// Member ID of `y`
+    pub const Y_MEMBER_ID: u32 = 7;
+    /// Member ID of `z`
+    pub const Z_MEMBER_ID: u32 = 8;
+}
+
+impl omg_cdr::CdrEncode for Choice {
+    fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+        let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+        match self {
+            Choice::ONE { x } => writer.write_union(extensibility, &1_i32, Self::X_MEMBER_ID, x, omg_cdr::CdrEncode::encode),
+            Choice::TWO { y } => writer.write_union(extensibility, &2_i32, Self::Y_MEMBER_ID, y, omg_cdr::CdrEncode::encode),
+            Choice::default { z } => writer.write_union(extensibility, &0_i32, Self::Z_MEMBER_ID, z, omg_cdr::CdrEncode::encode),
+        }
+    }
+}
+
+impl omg_cdr::CdrDecode for Choice {
+    fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+        let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+        reader.read_union(extensibility, |discriminator: i32, reader| {
+            Ok(match discriminator {
+                1_i32 => Choice::ONE { x: reader.read_branch(extensibility, Self::X_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                2_i32 => Choice::TWO { y: reader.read_branch(extensibility, Self::Y_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                #[allow(unreachable_patterns)]
+                _ => Choice::default { z: reader.read_branch(extensibility, Self::Z_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+            })
+        })
+    }
+}
+
+impl omg_cdr::CdrBounds for Choice {
+    const IS_BOUNDED: bool = true;
+    const MAX_XCDR1_SIZE: Option<usize> = Some(8);
+    const MAX_XCDR2_SIZE: Option<usize> = Some(12);
+}
+
+#[allow(non_snake_case)]
+pub mod Hashed {
+    use serde_derive::{Serialize, Deserialize};
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Sample {
+        #[allow(non_snake_case)]
+        pub a: i32,
+        #[allow(non_snake_case)]
+        pub b: i32,
+        #[allow(non_snake_case)]
+        pub c: i32,
+        #[allow(non_snake_case)]
+        pub d: i32,
+    }
+
+    #[allow(dead_code)]
+    impl Sample {
+
+        pub fn new(a: i32, b: i32, c: i32, d: i32, ) -> Self {
+            Self {
+                a,
+                b,
+                c,
+                d,
+            }
+        }
+
+        pub fn a(&self) -> &i32 {
+            &self.a
+        }
+
+        pub fn set_a(&mut self, value: i32) {
+            self.a = value;
+        }
+
+        pub fn b(&self) -> &i32 {
+            &self.b
+        }
+
+        pub fn set_b(&mut self, value: i32) {
+            self.b = value;
+        }
+
+        pub fn c(&self) -> &i32 {
+            &self.c
+        }
+
+        pub fn set_c(&mut self, value: i32) {
+            self.c = value;
+        }
+
+        pub fn d(&self) -> &i32 {
+            &self.d
+        }
+
+        pub fn set_d(&mut self, value: i32) {
+            self.d = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Sample {
+        fn default() -> Self {
+            Self {
+                a: 0,
+                b: 0,
+                c: 0,
+                d: 0,
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Sample {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Sample {
+        const TYPE_NAME: &'static str = "Hashed::Sample";
+        const REPOSITORY_ID: &'static str = "IDL:Hashed/Sample:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Sample {
+        /// Member ID of `a`
+        pub const A_MEMBER_ID: u32 = 158712076;
+        /// Member ID of `b`
+        pub const B_MEMBER_ID: u32 = 5;
+        /// Member ID of `c`
+        pub const C_MEMBER_ID: u32 = 559690;
+        /// Member ID of `d`
+        pub const D_MEMBER_ID: u32 = 36855673;
+    }
+
+    impl omg_cdr::CdrEncode for Sample {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Sample {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Sample {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.a, writer);
+            omg_cdr::CdrEncode::encode(&self.b, writer);
+            omg_cdr::CdrEncode::encode(&self.c, writer);
+            omg_cdr::CdrEncode::encode(&self.d, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                a: omg_cdr::CdrDecode::decode(reader)?,
+                b: omg_cdr::CdrDecode::decode(reader)?,
+                c: omg_cdr::CdrDecode::decode(reader)?,
+                d: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Sample {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(16);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(20);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Counted {
+        #[allow(non_snake_case)]
+        pub first: i32,
+        #[allow(non_snake_case)]
+        pub second: i32,
+    }
+
+    #[allow(dead_code)]
+    impl Counted {
+
+        pub fn new(first: i32, second: i32, ) -> Self {
+            Self {
+                first,
+                second,
+            }
+        }
+
+        pub fn first(&self) -> &i32 {
+            &self.first
+        }
+
+        pub fn set_first(&mut self, value: i32) {
+            self.first = value;
+        }
+
+        pub fn second(&self) -> &i32 {
+            &self.second
+        }
+
+        pub fn set_second(&mut self, value: i32) {
+            self.second = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Counted {
+        fn default() -> Self {
+            Self {
+                first: 0,
+                second: 0,
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Counted {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Counted {
+        const TYPE_NAME: &'static str = "Hashed::Counted";
+        const REPOSITORY_ID: &'static str = "IDL:Hashed/Counted:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Counted {
+        /// Member ID of `first`
+        pub const FIRST_MEMBER_ID: u32 = 0;
+        /// Member ID of `second`
+        pub const SECOND_MEMBER_ID: u32 = 1;
+    }
+
+    impl omg_cdr::CdrEncode for Counted {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Counted {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Counted {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.first, writer);
+            omg_cdr::CdrEncode::encode(&self.second, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                first: omg_cdr::CdrDecode::decode(reader)?,
+                second: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Counted {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(8);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(12);
+    }
+
+}
+
+#[allow(dead_code, non_upper_case_globals)]
+pub const ONE: i32 = 1;
+
+#[allow(dead_code, non_upper_case_globals)]
+pub const TWO: i32 = 2;
@@ -0,0 +1,1088 @@
+
+#[allow(non_snake_case)]
+pub mod DDS {
+    use serde_derive::{Serialize, Deserialize};
+    use std::vec::Vec;
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    #[serde(transparent)]
+    #[repr(transparent)]
+    pub struct DomainId_t(pub i32);
+
+    impl Default for DomainId_t {
+        fn default() -> Self {
+            Self(0)
+        }
+    }
+
+    impl std::ops::Deref for DomainId_t {
+        type Target = i32;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl From<i32> for DomainId_t {
+        fn from(value: i32) -> Self {
+            Self(value)
+        }
+    }
+
+    impl From<DomainId_t> for i32 {
+        fn from(value: DomainId_t) -> Self {
+            value.0
+        }
+    }
+
+    impl omg_cdr::CdrEncode for DomainId_t {
+        const PRIMITIVE: bool = <i32 as omg_cdr::CdrEncode>::PRIMITIVE;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.0, writer)
+        }
+    }
+
+    impl omg_cdr::CdrDecode for DomainId_t {
+        const PRIMITIVE: bool = <i32 as omg_cdr::CdrDecode>::PRIMITIVE;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            omg_cdr::CdrDecode::decode(reader).map(Self)
+        }
+    }
+
+    impl omg_idl_rt::KeyHashEncode for DomainId_t {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<i32>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.0, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.0, &other.0)
+        }
+    }
+
+    impl omg_idl_rt::TypeName for DomainId_t {
+        const TYPE_NAME: &'static str = "DDS::DomainId_t";
+        const REPOSITORY_ID: &'static str = "IDL:DDS/DomainId_t:1.0";
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    #[serde(transparent)]
+    #[repr(transparent)]
+    pub struct StatusKind(pub i32);
+
+    impl Default for StatusKind {
+        fn default() -> Self {
+            Self(0)
+        }
+    }
+
+    impl std::ops::Deref for StatusKind {
+        type Target = i32;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl From<i32> for StatusKind {
+        fn from(value: i32) -> Self {
+            Self(value)
+        }
+    }
+
+    impl From<StatusKind> for i32 {
+        fn from(value: StatusKind) -> Self {
+            value.0
+        }
+    }
+
+    impl omg_cdr::CdrEncode for StatusKind {
+        const PRIMITIVE: bool = <i32 as omg_cdr::CdrEncode>::PRIMITIVE;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.0, writer)
+        }
+    }
+
+    impl omg_cdr::CdrDecode for StatusKind {
+        const PRIMITIVE: bool = <i32 as omg_cdr::CdrDecode>::PRIMITIVE;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            omg_cdr::CdrDecode::decode(reader).map(Self)
+        }
+    }
+
+    impl omg_idl_rt::TypeName for StatusKind {
+        const TYPE_NAME: &'static str = "DDS::StatusKind";
+        const REPOSITORY_ID: &'static str = "IDL:DDS/StatusKind:1.0";
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    pub type DefaultDomain_t = DomainId_t;
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    #[serde(transparent)]
+    #[repr(transparent)]
+    pub struct TopicName_t(pub String);
+
+    impl Default for TopicName_t {
+        fn default() -> Self {
+            Self(String::new())
+        }
+    }
+
+    impl std::ops::Deref for TopicName_t {
+        type Target = String;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl From<String> for TopicName_t {
+        fn from(value: String) -> Self {
+            Self(value)
+        }
+    }
+
+    impl From<TopicName_t> for String {
+        fn from(value: TopicName_t) -> Self {
+            value.0
+        }
+    }
+
+    impl omg_cdr::CdrEncode for TopicName_t {
+        const PRIMITIVE: bool = <String as omg_cdr::CdrEncode>::PRIMITIVE;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.0, writer)
+        }
+    }
+
+    impl omg_cdr::CdrDecode for TopicName_t {
+        const PRIMITIVE: bool = <String as omg_cdr::CdrDecode>::PRIMITIVE;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            omg_cdr::CdrDecode::decode(reader).map(Self)
+        }
+    }
+
+    impl omg_idl_rt::TypeName for TopicName_t {
+        const TYPE_NAME: &'static str = "DDS::TopicName_t";
+        const REPOSITORY_ID: &'static str = "IDL:DDS/TopicName_t:1.0";
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    #[serde(transparent)]
+    #[repr(transparent)]
+    pub struct Label_t(pub String);
+
+    impl Default for Label_t {
+        fn default() -> Self {
+            Self(String::new())
+        }
+    }
+
+    impl std::ops::Deref for Label_t {
+        type Target = String;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl From<String> for Label_t {
+        fn from(value: String) -> Self {
+            Self(value)
+        }
+    }
+
+    impl From<Label_t> for String {
+        fn from(value: Label_t) -> Self {
+            value.0
+        }
+    }
+
+    impl omg_cdr::CdrEncode for Label_t {
+        const PRIMITIVE: bool = <omg_cdr::WideString as omg_cdr::Codec<String>>::PRIMITIVE;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            <omg_cdr::WideString as omg_cdr::Codec<_>>::encode(&self.0, writer)
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Label_t {
+        const PRIMITIVE: bool = <omg_cdr::WideString as omg_cdr::Codec<String>>::PRIMITIVE;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            <omg_cdr::WideString as omg_cdr::Codec<_>>::decode(reader).map(Self)
+        }
+    }
+
+    impl omg_idl_rt::TypeName for Label_t {
+        const TYPE_NAME: &'static str = "DDS::Label_t";
+        const REPOSITORY_ID: &'static str = "IDL:DDS/Label_t:1.0";
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    #[serde(transparent)]
+    #[repr(transparent)]
+    pub struct StatusKindSeq(pub Vec<StatusKind>);
+
+    impl Default for StatusKindSeq {
+        fn default() -> Self {
+            Self(Vec::new())
+        }
+    }
+
+    impl std::ops::Deref for StatusKindSeq {
+        type Target = Vec<StatusKind>;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl From<Vec<StatusKind>> for StatusKindSeq {
+        fn from(value: Vec<StatusKind>) -> Self {
+            Self(value)
+        }
+    }
+
+    impl From<StatusKindSeq> for Vec<StatusKind> {
+        fn from(value: StatusKindSeq) -> Self {
+            value.0
+        }
+    }
+
+    impl omg_cdr::CdrEncode for StatusKindSeq {
+        const PRIMITIVE: bool = <Vec<StatusKind> as omg_cdr::CdrEncode>::PRIMITIVE;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.0, writer)
+        }
+    }
+
+    impl omg_cdr::CdrDecode for StatusKindSeq {
+        const PRIMITIVE: bool = <Vec<StatusKind> as omg_cdr::CdrDecode>::PRIMITIVE;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            omg_cdr::CdrDecode::decode(reader).map(Self)
+        }
+    }
+
+    impl omg_idl_rt::TypeName for StatusKindSeq {
+        const TYPE_NAME: &'static str = "DDS::StatusKindSeq";
+        const REPOSITORY_ID: &'static str = "IDL:DDS/StatusKindSeq:1.0";
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    #[serde(transparent)]
+    #[repr(transparent)]
+    pub struct GuidPrefix_t(#[serde(with = "serde_arrays")] pub [u8;40_usize]);
+
+    impl Default for GuidPrefix_t {
+        fn default() -> Self {
+            Self(std::array::from_fn(|_| 0))
+        }
+    }
+
+    impl std::ops::Deref for GuidPrefix_t {
+        type Target = [u8;40_usize];
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl From<[u8;40_usize]> for GuidPrefix_t {
+        fn from(value: [u8;40_usize]) -> Self {
+            Self(value)
+        }
+    }
+
+    impl From<GuidPrefix_t> for [u8;40_usize] {
+        fn from(value: GuidPrefix_t) -> Self {
+            value.0
+        }
+    }
+
+    impl omg_cdr::CdrEncode for GuidPrefix_t {
+        const PRIMITIVE: bool = <[u8;40_usize] as omg_cdr::CdrEncode>::PRIMITIVE;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.0, writer)
+        }
+    }
+
+    impl omg_cdr::CdrDecode for GuidPrefix_t {
+        const PRIMITIVE: bool = <[u8;40_usize] as omg_cdr::CdrDecode>::PRIMITIVE;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            omg_cdr::CdrDecode::decode(reader).map(Self)
+        }
+    }
+
+    impl omg_idl_rt::KeyHashEncode for GuidPrefix_t {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<[u8;40_usize]>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.0, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.0, &other.0)
+        }
+    }
+
+    impl omg_idl_rt::TypeName for GuidPrefix_t {
+        const TYPE_NAME: &'static str = "DDS::GuidPrefix_t";
+        const REPOSITORY_ID: &'static str = "IDL:DDS/GuidPrefix_t:1.0";
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, PartialOrd, Eq, Ord)]
+    pub enum Reliability {
+        #[default]
+        BEST_EFFORT,
+        RELIABLE,
+    }
+
+    #[allow(non_camel_case_types)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct ReliabilityError;
+
+    impl std::str::FromStr for Reliability {
+        type Err = ReliabilityError;
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            match value {
+                "BEST_EFFORT" => Ok(Reliability::BEST_EFFORT),
+                "RELIABLE" => Ok(Reliability::RELIABLE),
+                _ => Err(ReliabilityError),
+            }
+        }
+    }
+
+    impl std::fmt::Display for Reliability {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let enum_str = match self {
+                    Reliability::BEST_EFFORT => "BEST_EFFORT",
+                    Reliability::RELIABLE => "RELIABLE",
+            };
+            write!(f, "{enum_str}")
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Reliability {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Reliability {
+        const TYPE_NAME: &'static str = "DDS::Reliability";
+        const REPOSITORY_ID: &'static str = "IDL:DDS/Reliability:1.0";
+    }
+
+    impl omg_cdr::CdrEncode for Reliability {
+        const PRIMITIVE: bool = true;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let value: u32 = match self {
+                Reliability::BEST_EFFORT => 0,
+                Reliability::RELIABLE => 1,
+            };
+            omg_cdr::CdrEncode::encode(&value, writer);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Reliability {
+        const PRIMITIVE: bool = true;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            match <u32 as omg_cdr::CdrDecode>::decode(reader)? {
+                0 => Ok(Reliability::BEST_EFFORT),
+                1 => Ok(Reliability::RELIABLE),
+                value => Err(omg_cdr::CdrError::InvalidEnum(value)),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Reliability {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(4);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(4);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    #[serde(transparent)]
+    #[repr(transparent)]
+    pub struct ReliabilityKind_t(pub Reliability);
+
+    impl Default for ReliabilityKind_t {
+        fn default() -> Self {
+            Self(Reliability::RELIABLE)
+        }
+    }
+
+    impl std::ops::Deref for ReliabilityKind_t {
+        type Target = Reliability;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl From<Reliability> for ReliabilityKind_t {
+        fn from(value: Reliability) -> Self {
+            Self(value)
+        }
+    }
+
+    impl From<ReliabilityKind_t> for Reliability {
+        fn from(value: ReliabilityKind_t) -> Self {
+            value.0
+        }
+    }
+
+    impl omg_cdr::CdrEncode for ReliabilityKind_t {
+        const PRIMITIVE: bool = <Reliability as omg_cdr::CdrEncode>::PRIMITIVE;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.0, writer)
+        }
+    }
+
+    impl omg_cdr::CdrDecode for ReliabilityKind_t {
+        const PRIMITIVE: bool = <Reliability as omg_cdr::CdrDecode>::PRIMITIVE;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            omg_cdr::CdrDecode::decode(reader).map(Self)
+        }
+    }
+
+    impl omg_idl_rt::TypeName for ReliabilityKind_t {
+        const TYPE_NAME: &'static str = "DDS::ReliabilityKind_t";
+        const REPOSITORY_ID: &'static str = "IDL:DDS/ReliabilityKind_t:1.0";
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Time_t {
+        #[allow(non_snake_case)]
+        pub sec: i32,
+        #[allow(non_snake_case)]
+        pub nanosec: u32,
+    }
+
+    #[allow(dead_code)]
+    impl Time_t {
+
+        pub fn new(sec: i32, nanosec: u32, ) -> Self {
+            Self {
+                sec,
+                nanosec,
+            }
+        }
+
+        pub fn sec(&self) -> &i32 {
+            &self.sec
+        }
+
+        pub fn set_sec(&mut self, value: i32) {
+            self.sec = value;
+        }
+
+        pub fn nanosec(&self) -> &u32 {
+            &self.nanosec
+        }
+
+        pub fn set_nanosec(&mut self, value: u32) {
+            self.nanosec = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Time_t {
+        fn default() -> Self {
+            Self {
+                sec: 0,
+                nanosec: 0,
+            }
+        }
+    }
+
+    impl omg_idl_rt::KeyHashEncode for Time_t {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<i32>().field::<u32>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.sec, writer);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.nanosec, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.sec, &other.sec)
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.nanosec, &other.nanosec))
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Time_t {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Time_t {
+        const TYPE_NAME: &'static str = "DDS::Time_t";
+        const REPOSITORY_ID: &'static str = "IDL:DDS/Time_t:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Time_t {
+        /// Member ID of `sec`
+        pub const SEC_MEMBER_ID: u32 = 0;
+        /// Member ID of `nanosec`
+        pub const NANOSEC_MEMBER_ID: u32 = 1;
+    }
+
+    impl omg_cdr::CdrEncode for Time_t {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Time_t {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Time_t {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.sec, writer);
+            omg_cdr::CdrEncode::encode(&self.nanosec, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                sec: omg_cdr::CdrDecode::decode(reader)?,
+                nanosec: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Time_t {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(8);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(12);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    #[serde(transparent)]
+    #[repr(transparent)]
+    pub struct Timestamp_t(pub Time_t);
+
+    impl Default for Timestamp_t {
+        fn default() -> Self {
+            Self(Default::default())
+        }
+    }
+
+    impl std::ops::Deref for Timestamp_t {
+        type Target = Time_t;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl From<Time_t> for Timestamp_t {
+        fn from(value: Time_t) -> Self {
+            Self(value)
+        }
+    }
+
+    impl From<Timestamp_t> for Time_t {
+        fn from(value: Timestamp_t) -> Self {
+            value.0
+        }
+    }
+
+    impl omg_cdr::CdrEncode for Timestamp_t {
+        const PRIMITIVE: bool = <Time_t as omg_cdr::CdrEncode>::PRIMITIVE;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.0, writer)
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Timestamp_t {
+        const PRIMITIVE: bool = <Time_t as omg_cdr::CdrDecode>::PRIMITIVE;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            omg_cdr::CdrDecode::decode(reader).map(Self)
+        }
+    }
+
+    impl omg_idl_rt::KeyHashEncode for Timestamp_t {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<Time_t>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.0, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.0, &other.0)
+        }
+    }
+
+    impl omg_idl_rt::TypeName for Timestamp_t {
+        const TYPE_NAME: &'static str = "DDS::Timestamp_t";
+        const REPOSITORY_ID: &'static str = "IDL:DDS/Timestamp_t:1.0";
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Participant {
+        #[allow(non_snake_case)]
+        pub domain: DomainId_t,
+        #[allow(non_snake_case)]
+        pub prefix: GuidPrefix_t,
+        #[allow(non_snake_case)]
+        pub created: Timestamp_t,
+        #[allow(non_snake_case)]
+        pub other_domain: DomainId_t,
+        #[allow(non_snake_case)]
+        pub status: StatusKind,
+        #[allow(non_snake_case)]
+        pub default_domain: DefaultDomain_t,
+        #[allow(non_snake_case)]
+        pub topic: TopicName_t,
+        #[allow(non_snake_case)]
+        pub label: Label_t,
+        #[allow(non_snake_case)]
+        pub statuses: StatusKindSeq,
+        #[allow(non_snake_case)]
+        pub reliability: ReliabilityKind_t,
+        #[allow(non_snake_case)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub fallback: Option<DomainId_t>,
+    }
+
+    #[allow(dead_code)]
+    impl Participant {
+        #[allow(clippy::too_many_arguments)]
+        pub fn new(domain: DomainId_t, prefix: GuidPrefix_t, created: Timestamp_t, other_domain: DomainId_t, status: StatusKind, default_domain: DefaultDomain_t, topic: TopicName_t, label: Label_t, statuses: StatusKindSeq, reliability: ReliabilityKind_t, ) -> Self {
+            Self {
+                domain,
+                prefix,
+                created,
+                other_domain,
+                status,
+                default_domain,
+                topic,
+                label,
+                statuses,
+                reliability,
+                fallback: None,
+            }
+        }
+
+        pub fn domain(&self) -> &DomainId_t {
+            &self.domain
+        }
+
+        pub fn set_domain(&mut self, value: DomainId_t) {
+            self.domain = value;
+        }
+
+        pub fn prefix(&self) -> &GuidPrefix_t {
+            &self.prefix
+        }
+
+        pub fn set_prefix(&mut self, value: GuidPrefix_t) {
+            self.prefix = value;
+        }
+
+        pub fn created(&self) -> &Timestamp_t {
+            &self.created
+        }
+
+        pub fn set_created(&mut self, value: Timestamp_t) {
+            self.created = value;
+        }
+
+        pub fn other_domain(&self) -> &DomainId_t {
+            &self.other_domain
+        }
+
+        pub fn set_other_domain(&mut self, value: DomainId_t) -> Result<(), omg_idl_rt::ValidationError> {
+            Self::validate_other_domain(&value)?;
+            self.other_domain = value;
+            Ok(())
+        }
+
+        fn validate_other_domain(value: &DomainId_t) -> Result<(), omg_idl_rt::ValidationError> {
+            if *value < DomainId_t(0) {
+                return Err(omg_idl_rt::ValidationError::new("other_domain", omg_idl_rt::Bound::Min, "0"));
+            }
+            if *value > DomainId_t(232) {
+                return Err(omg_idl_rt::ValidationError::new("other_domain", omg_idl_rt::Bound::Max, "232"));
+            }
+            Ok(())
+        }
+
+        pub fn status(&self) -> &StatusKind {
+            &self.status
+        }
+
+        pub fn set_status(&mut self, value: StatusKind) {
+            self.status = value;
+        }
+
+        pub fn default_domain(&self) -> &DefaultDomain_t {
+            &self.default_domain
+        }
+
+        pub fn set_default_domain(&mut self, value: DefaultDomain_t) {
+            self.default_domain = value;
+        }
+
+        pub fn topic(&self) -> &TopicName_t {
+            &self.topic
+        }
+
+        pub fn set_topic(&mut self, value: TopicName_t) {
+            self.topic = value;
+        }
+
+        pub fn label(&self) -> &Label_t {
+            &self.label
+        }
+
+        pub fn set_label(&mut self, value: Label_t) {
+            self.label = value;
+        }
+
+        pub fn statuses(&self) -> &StatusKindSeq {
+            &self.statuses
+        }
+
+        pub fn set_statuses(&mut self, value: StatusKindSeq) {
+            self.statuses = value;
+        }
+
+        pub fn reliability(&self) -> &ReliabilityKind_t {
+            &self.reliability
+        }
+
+        pub fn set_reliability(&mut self, value: ReliabilityKind_t) {
+            self.reliability = value;
+        }
+
+        pub fn fallback(&self) -> Option<&DomainId_t> {
+            self.fallback.as_ref()
+        }
+
+        pub fn set_fallback(&mut self, value: Option<DomainId_t>) {
+            self.fallback = value;
+        }
+
+        /// Check all members against their `@range`, `@min` and `@max` bounds
+        pub fn validate(&self) -> Result<(), omg_idl_rt::ValidationError> {
+            Self::validate_other_domain(&self.other_domain)?;
+            Ok(())
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Participant {
+        fn default() -> Self {
+            Self {
+                domain: Default::default(),
+                prefix: Default::default(),
+                created: Default::default(),
+                other_domain: Default::default(),
+                status: StatusKind(42),
+                default_domain: Default::default(),
+                topic: Default::default(),
+                label: Default::default(),
+                statuses: Default::default(),
+                reliability: ReliabilityKind_t(Reliability::RELIABLE),
+                fallback: None,
+            }
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug)]
+    pub struct ParticipantKey {
+        pub domain: DomainId_t,
+        pub prefix: GuidPrefix_t,
+        pub created: Timestamp_t,
+    }
+
+    impl omg_idl_rt::KeyHashEncode for ParticipantKey {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<DomainId_t>().field::<GuidPrefix_t>().field::<Timestamp_t>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.domain, writer);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.prefix, writer);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.created, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.domain, &other.domain)
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.prefix, &other.prefix))
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.created, &other.created))
+        }
+    }
+
+    impl PartialEq for ParticipantKey {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other).is_eq()
+        }
+    }
+
+    impl Eq for ParticipantKey {}
+
+    impl PartialOrd for ParticipantKey {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for ParticipantKey {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other)
+        }
+    }
+
+    impl std::hash::Hash for ParticipantKey {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            omg_idl_rt::hash_key(self, state);
+        }
+    }
+
+    impl omg_idl_rt::Keyed for Participant {
+        type Key = ParticipantKey;
+
+        fn key(&self) -> Self::Key {
+            ParticipantKey {
+                domain: self.domain,
+                prefix: self.prefix,
+                created: self.created.clone(),
+            }
+        }
+    }
+
+    /// Equal if the key members are equal, other members are ignored
+    impl PartialEq for Participant {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.domain, &other.domain)
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.prefix, &other.prefix))
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.created, &other.created))
+                .is_eq()
+        }
+    }
+
+    impl Eq for Participant {}
+
+    impl PartialOrd for Participant {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    /// Orders by the key members, other members are ignored
+    impl Ord for Participant {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.domain, &other.domain)
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.prefix, &other.prefix))
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.created, &other.created))
+        }
+    }
+
+    /// Hashes the key members only, consistent with `PartialEq`
+    impl std::hash::Hash for Participant {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            let mut writer = omg_idl_rt::KeyHolderWriter::hashing(state);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.domain, &mut writer);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.prefix, &mut writer);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.created, &mut writer);
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Participant {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Participant {
+        const TYPE_NAME: &'static str = "DDS::Participant";
+        const REPOSITORY_ID: &'static str = "IDL:DDS/Participant:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Participant {
+        /// Member ID of `domain`
+        pub const DOMAIN_MEMBER_ID: u32 = 0;
+        /// Member ID of `prefix`
+        pub const PREFIX_MEMBER_ID: u32 = 1;
+        /// Member ID of `created`
+        pub const CREATED_MEMBER_ID: u32 = 2;
+        /// Member ID of `other_domain`
+        pub const OTHER_DOMAIN_MEMBER_ID: u32 = 3;
+        /// Member ID of `status`
+        pub const STATUS_MEMBER_ID: u32 = 4;
+        /// Member ID of `default_domain`
+        pub const DEFAULT_DOMAIN_MEMBER_ID: u32 = 5;
+        /// Member ID of `topic`
+        pub const TOPIC_MEMBER_ID: u32 = 6;
+        /// Member ID of `label`
+        pub const LABEL_MEMBER_ID: u32 = 7;
+        /// Member ID of `statuses`
+        pub const STATUSES_MEMBER_ID: u32 = 8;
+        /// Member ID of `reliability`
+        pub const RELIABILITY_MEMBER_ID: u32 = 9;
+        /// Member ID of `fallback`
+        pub const FALLBACK_MEMBER_ID: u32 = 10;
+    }
+
+    impl omg_cdr::CdrEncode for Participant {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Participant {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Participant {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.domain, writer);
+            omg_cdr::CdrEncode::encode(&self.prefix, writer);
+            omg_cdr::CdrEncode::encode(&self.created, writer);
+            omg_cdr::CdrEncode::encode(&self.other_domain, writer);
+            omg_cdr::CdrEncode::encode(&self.status, writer);
+            omg_cdr::CdrEncode::encode(&self.default_domain, writer);
+            omg_cdr::CdrEncode::encode(&self.topic, writer);
+            omg_cdr::CdrEncode::encode(&self.label, writer);
+            omg_cdr::CdrEncode::encode(&self.statuses, writer);
+            omg_cdr::CdrEncode::encode(&self.reliability, writer);
+            writer.write_optional(Self::FALLBACK_MEMBER_ID, &self.fallback, omg_cdr::CdrEncode::encode);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                domain: omg_cdr::CdrDecode::decode(reader)?,
+                prefix: omg_cdr::CdrDecode::decode(reader)?,
+                created: omg_cdr::CdrDecode::decode(reader)?,
+                other_domain: omg_cdr::CdrDecode::decode(reader)?,
+                status: omg_cdr::CdrDecode::decode(reader)?,
+                default_domain: omg_cdr::CdrDecode::decode(reader)?,
+                topic: omg_cdr::CdrDecode::decode(reader)?,
+                label: omg_cdr::CdrDecode::decode(reader)?,
+                statuses: omg_cdr::CdrDecode::decode(reader)?,
+                reliability: omg_cdr::CdrDecode::decode(reader)?,
+                fallback: reader.read_optional(Self::FALLBACK_MEMBER_ID, omg_cdr::CdrDecode::decode)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Participant {
+        const IS_BOUNDED: bool = false;
+        const MAX_XCDR1_SIZE: Option<usize> = None;
+        const MAX_XCDR2_SIZE: Option<usize> = None;
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub enum Policy {
+        BEST_EFFORT{ best_effort: StatusKind, },
+        RELIABLE{ reliable: Timestamp_t, },
+    }
+
+    impl Default for Policy {
+        fn default() -> Self {
+            Policy::BEST_EFFORT { best_effort: Default::default() }
+        }
+    }
+//
+// TODO custom de-/serializer
+//
+
+    impl omg_idl_rt::Extensible for Policy {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Policy {
+        const TYPE_NAME: &'static str = "DDS::Policy";
+        const REPOSITORY_ID: &'static str = "IDL:DDS/Policy:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Policy {
+        /// Member ID of `best_effort`
+        pub const BEST_EFFORT_MEMBER_ID: u32 = 1;
+        /// Member ID of `reliable`
+        pub const RELIABLE_MEMBER_ID: u32 = 2;
+    }
+
+    impl omg_cdr::CdrEncode for Policy {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            match self {
+                Policy::BEST_EFFORT { best_effort } => writer.write_union(extensibility, &ReliabilityKind_t(Reliability::BEST_EFFORT), Self::BEST_EFFORT_MEMBER_ID, best_effort, omg_cdr::CdrEncode::encode),
+                Policy::RELIABLE { reliable } => writer.write_union(extensibility, &ReliabilityKind_t(Reliability::RELIABLE), Self::RELIABLE_MEMBER_ID, reliable, omg_cdr::CdrEncode::encode),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Policy {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            reader.read_union(extensibility, |discriminator: ReliabilityKind_t, reader| {
+                Ok(match discriminator {
+                    ReliabilityKind_t(Reliability::BEST_EFFORT) => Policy::BEST_EFFORT { best_effort: reader.read_branch(extensibility, Self::BEST_EFFORT_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    ReliabilityKind_t(Reliability::RELIABLE) => Policy::RELIABLE { reliable: reader.read_branch(extensibility, Self::RELIABLE_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    #[allow(unreachable_patterns)]
+                    _ => return Err(omg_cdr::CdrError::InvalidDiscriminator),
+                })
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Policy {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(12);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(20);
+    }
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const DOMAIN_ID_DEFAULT: DomainId_t = DomainId_t(0);
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const DOMAIN_ID_MAX: DomainId_t = DomainId_t(DOMAIN_ID_DEFAULT.0+232);
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const DOMAIN_ID_TEST: DefaultDomain_t = DomainId_t(7);
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const DOMAIN_COUNT: i32 = DOMAIN_ID_MAX.0+1;
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const TOPIC_DEFAULT: &str = "default";
+
+}
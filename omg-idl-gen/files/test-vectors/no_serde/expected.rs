@@ -0,0 +1,355 @@
+
+#[allow(non_snake_case)]
+pub mod Inventory {
+
+    #[allow(dead_code)]
+    #[derive(Clone, Debug, Default, PartialEq, PartialOrd, Eq, Ord)]
+    pub enum Grade {
+        #[default]
+        Low,
+        High,
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct GradeError;
+
+    impl std::str::FromStr for Grade {
+        type Err = GradeError;
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            match value {
+                "LOW" => Ok(Grade::Low),
+                "HIGH" => Ok(Grade::High),
+                _ => Err(GradeError),
+            }
+        }
+    }
+
+    impl std::fmt::Display for Grade {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let enum_str = match self {
+                    Grade::Low => "LOW",
+                    Grade::High => "HIGH",
+            };
+            write!(f, "{enum_str}")
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Grade {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Grade {
+        const TYPE_NAME: &'static str = "Inventory::Grade";
+        const REPOSITORY_ID: &'static str = "IDL:Inventory/Grade:1.0";
+    }
+
+    impl omg_cdr::CdrEncode for Grade {
+        const PRIMITIVE: bool = true;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let value: u32 = match self {
+                Grade::Low => 0,
+                Grade::High => 1,
+            };
+            omg_cdr::CdrEncode::encode(&value, writer);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Grade {
+        const PRIMITIVE: bool = true;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            match <u32 as omg_cdr::CdrDecode>::decode(reader)? {
+                0 => Ok(Grade::Low),
+                1 => Ok(Grade::High),
+                value => Err(omg_cdr::CdrError::InvalidEnum(value)),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Grade {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(4);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(4);
+    }
+
+    #[allow(dead_code)]
+    #[derive(Clone, Debug)]
+    pub struct Base {
+        pub id: i32,
+    }
+
+    #[allow(dead_code)]
+    impl Base {
+
+        pub fn new(id: i32, ) -> Self {
+            Self {
+                id,
+            }
+        }
+
+        pub fn id(&self) -> &i32 {
+            &self.id
+        }
+
+        pub fn set_id(&mut self, value: i32) {
+            self.id = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Base {
+        fn default() -> Self {
+            Self {
+                id: 0,
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Base {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Base {
+        const TYPE_NAME: &'static str = "Inventory::Base";
+        const REPOSITORY_ID: &'static str = "IDL:Inventory/Base:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Base {
+        /// Member ID of `id`
+        pub const ID_MEMBER_ID: u32 = 0;
+    }
+
+    impl omg_cdr::CdrEncode for Base {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Base {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Base {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.id, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                id: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Base {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(4);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(8);
+    }
+
+    #[allow(dead_code)]
+    #[derive(Clone, Debug)]
+    pub struct Item {
+        pub base: Base,
+        pub item_name: String,
+        pub code: [u8;40_usize],
+        pub count: Option<i32>,
+        pub grade: Grade,
+    }
+
+    #[allow(dead_code)]
+    impl Item {
+
+        pub fn new(base: Base, item_name: String, code: [u8;40_usize], grade: Grade, ) -> Self {
+            Self {
+                base,
+                item_name,
+                code,
+                count: None,
+                grade,
+            }
+        }
+
+        pub fn base(&self) -> &Base {
+            &self.base
+        }
+
+        pub fn set_base(&mut self, value: Base) {
+            self.base = value;
+        }
+
+        pub fn item_name(&self) -> &String {
+            &self.item_name
+        }
+
+        pub fn set_item_name(&mut self, value: String) {
+            self.item_name = value;
+        }
+
+        pub fn code(&self) -> &[u8;40_usize] {
+            &self.code
+        }
+
+        pub fn set_code(&mut self, value: [u8;40_usize]) {
+            self.code = value;
+        }
+
+        pub fn count(&self) -> Option<&i32> {
+            self.count.as_ref()
+        }
+
+        pub fn set_count(&mut self, value: Option<i32>) {
+            self.count = value;
+        }
+
+        pub fn grade(&self) -> &Grade {
+            &self.grade
+        }
+
+        pub fn set_grade(&mut self, value: Grade) {
+            self.grade = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Item {
+        fn default() -> Self {
+            Self {
+                base: Default::default(),
+                item_name: String::new(),
+                code: std::array::from_fn(|_| 0),
+                count: None,
+                grade: Default::default(),
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Item {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Item {
+        const TYPE_NAME: &'static str = "Inventory::Item";
+        const REPOSITORY_ID: &'static str = "IDL:Inventory/Item:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Item {
+        /// Member ID of `itemName`
+        pub const ITEM_NAME_MEMBER_ID: u32 = 1;
+        /// Member ID of `code`
+        pub const CODE_MEMBER_ID: u32 = 2;
+        /// Member ID of `count`
+        pub const COUNT_MEMBER_ID: u32 = 3;
+        /// Member ID of `grade`
+        pub const GRADE_MEMBER_ID: u32 = 4;
+    }
+
+    impl omg_cdr::CdrEncode for Item {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Item {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Item {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrStruct::encode_members(&self.base, writer);
+            omg_cdr::CdrEncode::encode(&self.item_name, writer);
+            omg_cdr::CdrEncode::encode(&self.code, writer);
+            writer.write_optional(Self::COUNT_MEMBER_ID, &self.count, omg_cdr::CdrEncode::encode);
+            omg_cdr::CdrEncode::encode(&self.grade, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                base: omg_cdr::CdrStruct::decode_members(reader)?,
+                item_name: omg_cdr::CdrDecode::decode(reader)?,
+                code: omg_cdr::CdrDecode::decode(reader)?,
+                count: reader.read_optional(Self::COUNT_MEMBER_ID, omg_cdr::CdrDecode::decode)?,
+                grade: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Item {
+        const IS_BOUNDED: bool = false;
+        const MAX_XCDR1_SIZE: Option<usize> = None;
+        const MAX_XCDR2_SIZE: Option<usize> = None;
+    }
+
+    #[allow(dead_code)]
+    #[derive(Clone, Debug)]
+    pub enum Tag {
+        Low{ label: String, },
+        High{ level: Option<i32>, },
+    }
+
+    impl Default for Tag {
+        fn default() -> Self {
+            Tag::Low { label: String::new() }
+        }
+    }
+//
+// TODO custom de-/serializer
+//
+
+    impl omg_idl_rt::Extensible for Tag {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Tag {
+        const TYPE_NAME: &'static str = "Inventory::Tag";
+        const REPOSITORY_ID: &'static str = "IDL:Inventory/Tag:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Tag {
+        /// Member ID of `label`
+        pub const LABEL_MEMBER_ID: u32 = 1;
+        /// Member ID of `level`
+        pub const LEVEL_MEMBER_ID: u32 = 2;
+    }
+
+    impl omg_cdr::CdrEncode for Tag {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            match self {
+                Tag::Low { label } => writer.write_union(extensibility, &Grade::Low, Self::LABEL_MEMBER_ID, label, omg_cdr::CdrEncode::encode),
+                Tag::High { level } => writer.write_union(extensibility, &Grade::High, Self::LEVEL_MEMBER_ID, level, |value, writer| writer.write_optional(Self::LEVEL_MEMBER_ID, value, omg_cdr::CdrEncode::encode)),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Tag {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            reader.read_union(extensibility, |discriminator: Grade, reader| {
+                Ok(match discriminator {
+                    Grade::Low => Tag::Low { label: reader.read_branch(extensibility, Self::LABEL_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    Grade::High => Tag::High { level: reader.read_branch(extensibility, Self::LEVEL_MEMBER_ID, |reader| reader.read_optional(Self::LEVEL_MEMBER_ID, omg_cdr::CdrDecode::decode))? },
+                    #[allow(unreachable_patterns)]
+                    _ => return Err(omg_cdr::CdrError::InvalidDiscriminator),
+                })
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Tag {
+        const IS_BOUNDED: bool = false;
+        const MAX_XCDR1_SIZE: Option<usize> = None;
+        const MAX_XCDR2_SIZE: Option<usize> = None;
+    }
+
+}
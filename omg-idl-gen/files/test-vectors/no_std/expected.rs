@@ -0,0 +1,676 @@
+
+#[allow(non_snake_case)]
+pub mod Fleet {
+    use serde_derive::{Serialize, Deserialize};
+
+    #[allow(dead_code, non_camel_case_types)]
+    pub type Route = alloc::vec::Vec<i32>;
+
+    #[allow(dead_code, non_camel_case_types)]
+    pub type Label = alloc::string::String;
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, PartialOrd, Eq, Ord)]
+    pub enum Status {
+        DOCKED,
+        #[default]
+        UNDERWAY,
+        ADRIFT,
+    }
+
+    #[allow(non_camel_case_types)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct StatusError;
+
+    impl core::str::FromStr for Status {
+        type Err = StatusError;
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            match value {
+                "DOCKED" => Ok(Status::DOCKED),
+                "UNDERWAY" => Ok(Status::UNDERWAY),
+                "ADRIFT" => Ok(Status::ADRIFT),
+                _ => Err(StatusError),
+            }
+        }
+    }
+
+    impl core::fmt::Display for Status {
+        fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+            let enum_str = match self {
+                    Status::DOCKED => "DOCKED",
+                    Status::UNDERWAY => "UNDERWAY",
+                    Status::ADRIFT => "ADRIFT",
+            };
+            write!(f, "{enum_str}")
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Status {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Status {
+        const TYPE_NAME: &'static str = "Fleet::Status";
+        const REPOSITORY_ID: &'static str = "IDL:Fleet/Status:1.0";
+    }
+
+    impl omg_cdr::CdrEncode for Status {
+        const PRIMITIVE: bool = true;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let value: u32 = match self {
+                Status::DOCKED => 0,
+                Status::UNDERWAY => 1,
+                Status::ADRIFT => 2,
+            };
+            omg_cdr::CdrEncode::encode(&value, writer);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Status {
+        const PRIMITIVE: bool = true;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            match <u32 as omg_cdr::CdrDecode>::decode(reader)? {
+                0 => Ok(Status::DOCKED),
+                1 => Ok(Status::UNDERWAY),
+                2 => Ok(Status::ADRIFT),
+                value => Err(omg_cdr::CdrError::InvalidEnum(value)),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Status {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(4);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(4);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Position {
+        #[allow(non_snake_case)]
+        pub latitude: f64,
+        #[allow(non_snake_case)]
+        pub longitude: f64,
+    }
+
+    #[allow(dead_code)]
+    impl Position {
+
+        pub fn new(latitude: f64, longitude: f64, ) -> Self {
+            Self {
+                latitude,
+                longitude,
+            }
+        }
+
+        pub fn latitude(&self) -> &f64 {
+            &self.latitude
+        }
+
+        pub fn set_latitude(&mut self, value: f64) {
+            self.latitude = value;
+        }
+
+        pub fn longitude(&self) -> &f64 {
+            &self.longitude
+        }
+
+        pub fn set_longitude(&mut self, value: f64) {
+            self.longitude = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Position {
+        fn default() -> Self {
+            Self {
+                latitude: 0.0,
+                longitude: 0.0,
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Position {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Final;
+    }
+
+    impl omg_idl_rt::TypeName for Position {
+        const TYPE_NAME: &'static str = "Fleet::Position";
+        const REPOSITORY_ID: &'static str = "IDL:Fleet/Position:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Position {
+        /// Member ID of `latitude`
+        pub const LATITUDE_MEMBER_ID: u32 = 0;
+        /// Member ID of `longitude`
+        pub const LONGITUDE_MEMBER_ID: u32 = 1;
+    }
+
+    impl omg_cdr::CdrEncode for Position {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Position {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Position {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.latitude, writer);
+            omg_cdr::CdrEncode::encode(&self.longitude, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                latitude: omg_cdr::CdrDecode::decode(reader)?,
+                longitude: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Position {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(16);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(16);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Clone)]
+    pub struct PositionRef<'a> {
+        reader: omg_cdr::CdrReader<'a>,
+        #[allow(non_snake_case)]
+        latitude: f64,
+        #[allow(non_snake_case)]
+        longitude: f64,
+    }
+
+    #[allow(non_snake_case)]
+    impl<'a> PositionRef<'a> {
+        pub fn latitude(&self) -> f64 {
+            self.latitude
+        }
+
+        pub fn longitude(&self) -> f64 {
+            self.longitude
+        }
+
+        /// Decode the viewed `Position`
+        pub fn to_owned(&self) -> Result<Position, omg_cdr::CdrError> {
+            omg_cdr::CdrStruct::decode_members(&mut self.reader.clone())
+        }
+    }
+
+    impl<'a> omg_cdr::CdrStructView<'a> for PositionRef<'a> {
+        type Owned = Position;
+
+        fn view_members(reader: &mut omg_cdr::CdrReader<'a>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                reader: reader.clone(),
+                latitude: omg_cdr::CdrView::view(reader)?,
+                longitude: omg_cdr::CdrView::view(reader)?,
+            })
+        }
+    }
+
+    impl core::fmt::Debug for PositionRef<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+            f.debug_struct("PositionRef")
+                .field("latitude", &self.latitude)
+                .field("longitude", &self.longitude)
+                .finish()
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Vessel {
+        #[allow(non_snake_case)]
+        pub name: alloc::string::String,
+        #[allow(non_snake_case)]
+        pub captain: alloc::string::String,
+        #[allow(non_snake_case)]
+        pub status: Status,
+        #[allow(non_snake_case)]
+        pub flag: alloc::string::String,
+        #[allow(non_snake_case)]
+        pub route: Route,
+        #[allow(non_snake_case)]
+        pub track: alloc::vec::Vec<Position>,
+        #[allow(non_snake_case)]
+        #[serde(with = "serde_arrays")]
+        pub labels: [Label;2_usize],
+        #[allow(non_snake_case)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub note: Option<alloc::string::String>,
+    }
+
+    #[allow(dead_code)]
+    impl Vessel {
+
+        pub fn new(name: alloc::string::String, captain: alloc::string::String, status: Status, flag: alloc::string::String, route: Route, track: alloc::vec::Vec<Position>, labels: [Label;2_usize], ) -> Self {
+            Self {
+                name,
+                captain,
+                status,
+                flag,
+                route,
+                track,
+                labels,
+                note: None,
+            }
+        }
+
+        pub fn name(&self) -> &alloc::string::String {
+            &self.name
+        }
+
+        pub fn set_name(&mut self, value: alloc::string::String) {
+            self.name = value;
+        }
+
+        pub fn captain(&self) -> &alloc::string::String {
+            &self.captain
+        }
+
+        pub fn set_captain(&mut self, value: alloc::string::String) {
+            self.captain = value;
+        }
+
+        pub fn status(&self) -> &Status {
+            &self.status
+        }
+
+        pub fn set_status(&mut self, value: Status) {
+            self.status = value;
+        }
+
+        pub fn flag(&self) -> &alloc::string::String {
+            &self.flag
+        }
+
+        pub fn set_flag(&mut self, value: alloc::string::String) {
+            self.flag = value;
+        }
+
+        pub fn route(&self) -> &Route {
+            &self.route
+        }
+
+        pub fn set_route(&mut self, value: Route) {
+            self.route = value;
+        }
+
+        pub fn track(&self) -> &alloc::vec::Vec<Position> {
+            &self.track
+        }
+
+        pub fn set_track(&mut self, value: alloc::vec::Vec<Position>) {
+            self.track = value;
+        }
+
+        pub fn labels(&self) -> &[Label;2_usize] {
+            &self.labels
+        }
+
+        pub fn set_labels(&mut self, value: [Label;2_usize]) {
+            self.labels = value;
+        }
+
+        pub fn note(&self) -> Option<&alloc::string::String> {
+            self.note.as_ref()
+        }
+
+        pub fn set_note(&mut self, value: Option<alloc::string::String>) {
+            self.note = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Vessel {
+        fn default() -> Self {
+            Self {
+                name: alloc::string::String::new(),
+                captain: "unnamed".into(),
+                status: Status::UNDERWAY,
+                flag: alloc::string::String::new(),
+                route: alloc::vec::Vec::new(),
+                track: alloc::vec::Vec::new(),
+                labels: core::array::from_fn(|_| alloc::string::String::new()),
+                note: None,
+            }
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug)]
+    pub struct VesselKey {
+        pub name: alloc::string::String,
+    }
+
+    impl omg_idl_rt::KeyHashEncode for VesselKey {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<alloc::string::String>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.name, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> core::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.name, &other.name)
+        }
+    }
+
+    impl PartialEq for VesselKey {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other).is_eq()
+        }
+    }
+
+    impl Eq for VesselKey {}
+
+    impl PartialOrd for VesselKey {
+        fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for VesselKey {
+        fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other)
+        }
+    }
+
+    impl core::hash::Hash for VesselKey {
+        fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+            omg_idl_rt::hash_key(self, state);
+        }
+    }
+
+    impl omg_idl_rt::Keyed for Vessel {
+        type Key = VesselKey;
+
+        fn key(&self) -> Self::Key {
+            VesselKey {
+                name: self.name.clone(),
+            }
+        }
+    }
+
+    /// Equal if the key members are equal, other members are ignored
+    impl PartialEq for Vessel {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.name, &other.name)
+                .is_eq()
+        }
+    }
+
+    impl Eq for Vessel {}
+
+    impl PartialOrd for Vessel {
+        fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    /// Orders by the key members, other members are ignored
+    impl Ord for Vessel {
+        fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.name, &other.name)
+        }
+    }
+
+    /// Hashes the key members only, consistent with `PartialEq`
+    impl core::hash::Hash for Vessel {
+        fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+            let mut writer = omg_idl_rt::KeyHolderWriter::hashing(state);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.name, &mut writer);
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Vessel {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Vessel {
+        const TYPE_NAME: &'static str = "Fleet::Vessel";
+        const REPOSITORY_ID: &'static str = "IDL:Fleet/Vessel:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Vessel {
+        /// Member ID of `name`
+        pub const NAME_MEMBER_ID: u32 = 0;
+        /// Member ID of `captain`
+        pub const CAPTAIN_MEMBER_ID: u32 = 1;
+        /// Member ID of `status`
+        pub const STATUS_MEMBER_ID: u32 = 2;
+        /// Member ID of `flag`
+        pub const FLAG_MEMBER_ID: u32 = 3;
+        /// Member ID of `route`
+        pub const ROUTE_MEMBER_ID: u32 = 4;
+        /// Member ID of `track`
+        pub const TRACK_MEMBER_ID: u32 = 5;
+        /// Member ID of `labels`
+        pub const LABELS_MEMBER_ID: u32 = 6;
+        /// Member ID of `note`
+        pub const NOTE_MEMBER_ID: u32 = 7;
+    }
+
+    impl omg_cdr::CdrEncode for Vessel {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Vessel {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Vessel {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.name, writer);
+            omg_cdr::CdrEncode::encode(&self.captain, writer);
+            omg_cdr::CdrEncode::encode(&self.status, writer);
+            <omg_cdr::WideString as omg_cdr::Codec<_>>::encode(&self.flag, writer);
+            omg_cdr::CdrEncode::encode(&self.route, writer);
+            omg_cdr::CdrEncode::encode(&self.track, writer);
+            omg_cdr::CdrEncode::encode(&self.labels, writer);
+            writer.write_optional(Self::NOTE_MEMBER_ID, &self.note, omg_cdr::CdrEncode::encode);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                name: omg_cdr::CdrDecode::decode(reader)?,
+                captain: omg_cdr::CdrDecode::decode(reader)?,
+                status: omg_cdr::CdrDecode::decode(reader)?,
+                flag: <omg_cdr::WideString as omg_cdr::Codec<_>>::decode(reader)?,
+                route: omg_cdr::CdrDecode::decode(reader)?,
+                track: omg_cdr::CdrDecode::decode(reader)?,
+                labels: omg_cdr::CdrDecode::decode(reader)?,
+                note: reader.read_optional(Self::NOTE_MEMBER_ID, omg_cdr::CdrDecode::decode)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Vessel {
+        const IS_BOUNDED: bool = false;
+        const MAX_XCDR1_SIZE: Option<usize> = None;
+        const MAX_XCDR2_SIZE: Option<usize> = None;
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Clone)]
+    pub struct VesselRef<'a> {
+        reader: omg_cdr::CdrReader<'a>,
+        #[allow(non_snake_case)]
+        name: &'a str,
+        #[allow(non_snake_case)]
+        captain: &'a str,
+        #[allow(non_snake_case)]
+        status: omg_cdr::Lazy<'a, Status>,
+        #[allow(non_snake_case)]
+        flag: omg_cdr::Lazy<'a, alloc::string::String>,
+        #[allow(non_snake_case)]
+        route: omg_cdr::CdrSlice<'a, i32>,
+        #[allow(non_snake_case)]
+        track: omg_cdr::Lazy<'a, alloc::vec::Vec<Position>>,
+        #[allow(non_snake_case)]
+        labels: omg_cdr::Lazy<'a, [Label;2_usize]>,
+        #[allow(non_snake_case)]
+        note: Option<&'a str>,
+    }
+
+    #[allow(non_snake_case)]
+    impl<'a> VesselRef<'a> {
+        pub fn name(&self) -> &'a str {
+            self.name
+        }
+
+        pub fn captain(&self) -> &'a str {
+            self.captain
+        }
+
+        pub fn status(&self) -> Result<Status, omg_cdr::CdrError> {
+            self.status.get()
+        }
+
+        pub fn flag(&self) -> Result<alloc::string::String, omg_cdr::CdrError> {
+            self.flag.get()
+        }
+
+        pub fn route(&self) -> omg_cdr::CdrSlice<'a, i32> {
+            self.route
+        }
+
+        pub fn track(&self) -> Result<alloc::vec::Vec<Position>, omg_cdr::CdrError> {
+            self.track.get()
+        }
+
+        pub fn labels(&self) -> Result<[Label;2_usize], omg_cdr::CdrError> {
+            self.labels.get()
+        }
+
+        pub fn note(&self) -> Option<&'a str> {
+            self.note
+        }
+
+        /// Decode the viewed `Vessel`
+        pub fn to_owned(&self) -> Result<Vessel, omg_cdr::CdrError> {
+            omg_cdr::CdrStruct::decode_members(&mut self.reader.clone())
+        }
+    }
+
+    impl<'a> omg_cdr::CdrStructView<'a> for VesselRef<'a> {
+        type Owned = Vessel;
+
+        fn view_members(reader: &mut omg_cdr::CdrReader<'a>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                reader: reader.clone(),
+                name: omg_cdr::CdrView::view(reader)?,
+                captain: omg_cdr::CdrView::view(reader)?,
+                status: omg_cdr::Lazy::read(reader, omg_cdr::CdrDecode::decode)?,
+                flag: omg_cdr::Lazy::read(reader, <omg_cdr::WideString as omg_cdr::Codec<_>>::decode)?,
+                route: omg_cdr::CdrSlice::read_sequence(reader)?,
+                track: omg_cdr::Lazy::read(reader, omg_cdr::CdrDecode::decode)?,
+                labels: omg_cdr::Lazy::read(reader, omg_cdr::CdrDecode::decode)?,
+                note: reader.read_optional(Vessel::NOTE_MEMBER_ID, omg_cdr::CdrView::view)?,
+            })
+        }
+    }
+
+    impl core::fmt::Debug for VesselRef<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+            f.debug_struct("VesselRef")
+                .field("name", &self.name)
+                .field("captain", &self.captain)
+                .field("status", &self.status)
+                .field("flag", &self.flag)
+                .field("route", &self.route)
+                .field("track", &self.track)
+                .field("labels", &self.labels)
+                .field("note", &self.note)
+                .finish()
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub enum Signal {
+        DOCKED{ berth: alloc::string::String, },
+        UNDERWAY{ heading: Position, },
+        default{ raw: alloc::vec::Vec<u8>, },
+    }
+
+    impl Default for Signal {
+        fn default() -> Self {
+            Signal::default { raw: alloc::vec::Vec::new() }
+        }
+    }
+//
+// TODO custom de-/serializer
+//
+
+    impl omg_idl_rt::Extensible for Signal {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Signal {
+        const TYPE_NAME: &'static str = "Fleet::Signal";
+        const REPOSITORY_ID: &'static str = "IDL:Fleet/Signal:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Signal {
+        /// Member ID of `berth`
+        pub const BERTH_MEMBER_ID: u32 = 1;
+        /// Member ID of `heading`
+        pub const HEADING_MEMBER_ID: u32 = 2;
+        /// Member ID of `raw`
+        pub const RAW_MEMBER_ID: u32 = 3;
+    }
+
+    impl omg_cdr::CdrEncode for Signal {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            match self {
+                Signal::DOCKED { berth } => writer.write_union(extensibility, &Status::DOCKED, Self::BERTH_MEMBER_ID, berth, omg_cdr::CdrEncode::encode),
+                Signal::UNDERWAY { heading } => writer.write_union(extensibility, &Status::UNDERWAY, Self::HEADING_MEMBER_ID, heading, omg_cdr::CdrEncode::encode),
+                Signal::default { raw } => writer.write_union(extensibility, &Status::ADRIFT, Self::RAW_MEMBER_ID, raw, omg_cdr::CdrEncode::encode),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Signal {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            reader.read_union(extensibility, |discriminator: Status, reader| {
+                Ok(match discriminator {
+                    Status::DOCKED => Signal::DOCKED { berth: reader.read_branch(extensibility, Self::BERTH_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    Status::UNDERWAY => Signal::UNDERWAY { heading: reader.read_branch(extensibility, Self::HEADING_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    #[allow(unreachable_patterns)]
+                    _ => Signal::default { raw: reader.read_branch(extensibility, Self::RAW_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                })
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Signal {
+        const IS_BOUNDED: bool = false;
+        const MAX_XCDR1_SIZE: Option<usize> = None;
+        const MAX_XCDR2_SIZE: Option<usize> = None;
+    }
+
+}
+
+#[allow(dead_code, non_upper_case_globals)]
+pub const FLEET: &str = "blue";
@@ -0,0 +1,292 @@
+use serde_derive::{Serialize, Deserialize};
+
+#[allow(dead_code, non_camel_case_types)]
+pub type Samples = [i32;40_usize];
+
+#[allow(dead_code, non_camel_case_types)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Foo {
+    #[allow(non_snake_case)]
+    pub m_required: i32,
+    #[allow(non_snake_case)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub m_long: Option<i32>,
+    #[allow(non_snake_case)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub m_str: Option<String>,
+    #[allow(non_snake_case)]
+    pub m_double: f64,
+    #[allow(non_snake_case)]
+    #[serde(with = "omg_idl_rt::serde_option_array")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub m_arr: Option<[u8;4_usize]>,
+    #[allow(non_snake_case)]
+    #[serde(with = "omg_idl_rt::serde_option_array")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub m_big: Option<[u8;64_usize]>,
+    #[allow(non_snake_case)]
+    #[serde(with = "omg_idl_rt::serde_option_array")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub m_many: Option<[i32;40_usize]>,
+    #[allow(non_snake_case)]
+    #[serde(with = "serde_arrays")]
+    pub m_samples: Samples,
+    #[allow(non_snake_case)]
+    #[serde(with = "omg_idl_rt::serde_option_array")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub m_more: Option<Samples>,
+}
+
+#[allow(dead_code)]
+impl Foo {
+
+    pub fn new(m_required: i32, m_double: f64, m_samples: Samples, ) -> Self {
+        Self {
+            m_required,
+            m_long: None,
+            m_str: None,
+            m_double,
+            m_arr: None,
+            m_big: None,
+            m_many: None,
+            m_samples,
+            m_more: None,
+        }
+    }
+
+    pub fn m_required(&self) -> &i32 {
+        &self.m_required
+    }
+
+    pub fn set_m_required(&mut self, value: i32) {
+        self.m_required = value;
+    }
+
+    pub fn m_long(&self) -> Option<&i32> {
+        self.m_long.as_ref()
+    }
+
+    pub fn set_m_long(&mut self, value: Option<i32>) {
+        self.m_long = value;
+    }
+
+    pub fn m_str(&self) -> Option<&String> {
+        self.m_str.as_ref()
+    }
+
+    pub fn set_m_str(&mut self, value: Option<String>) {
+        self.m_str = value;
+    }
+
+    pub fn m_double(&self) -> &f64 {
+        &self.m_double
+    }
+
+    pub fn set_m_double(&mut self, value: f64) {
+        self.m_double = value;
+    }
+
+    pub fn m_arr(&self) -> Option<&[u8;4_usize]> {
+        self.m_arr.as_ref()
+    }
+
+    pub fn set_m_arr(&mut self, value: Option<[u8;4_usize]>) {
+        self.m_arr = value;
+    }
+
+    pub fn m_big(&self) -> Option<&[u8;64_usize]> {
+        self.m_big.as_ref()
+    }
+
+    pub fn set_m_big(&mut self, value: Option<[u8;64_usize]>) {
+        self.m_big = value;
+    }
+
+    pub fn m_many(&self) -> Option<&[i32;40_usize]> {
+        self.m_many.as_ref()
+    }
+
+    pub fn set_m_many(&mut self, value: Option<[i32;40_usize]>) {
+        self.m_many = value;
+    }
+
+    pub fn m_samples(&self) -> &Samples {
+        &self.m_samples
+    }
+
+    pub fn set_m_samples(&mut self, value: Samples) {
+        self.m_samples = value;
+    }
+
+    pub fn m_more(&self) -> Option<&Samples> {
+        self.m_more.as_ref()
+    }
+
+    pub fn set_m_more(&mut self, value: Option<Samples>) {
+        self.m_more = value;
+    }
+
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for Foo {
+    fn default() -> Self {
+        Self {
+            m_required: 0,
+            m_long: None,
+            m_str: None,
+            m_double: 0.0,
+            m_arr: None,
+            m_big: None,
+            m_many: None,
+            m_samples: std::array::from_fn(|_| 0),
+            m_more: None,
+        }
+    }
+}
+
+impl omg_idl_rt::Extensible for Foo {
+    const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+}
+
+impl omg_idl_rt::TypeName for Foo {
+    const TYPE_NAME: &'static str = "Foo";
+    const REPOSITORY_ID: &'static str = "IDL:Foo:1.0";
+}
+
+#[allow(dead_code)]
+impl Foo {
+    /// Member ID of `m_required`
+    pub const M_REQUIRED_MEMBER_ID: u32 = 0;
+    /// Member ID of `m_long`
+    pub const M_LONG_MEMBER_ID: u32 = 1;
+    /// Member ID of `m_str`
+    pub const M_STR_MEMBER_ID: u32 = 2;
+    /// Member ID of `m_double`
+    pub const M_DOUBLE_MEMBER_ID: u32 = 3;
+    /// Member ID of `m_arr`
+    pub const M_ARR_MEMBER_ID: u32 = 4;
+    /// Member ID of `m_big`
+    pub const M_BIG_MEMBER_ID: u32 = 5;
+    /// Member ID of `m_many`
+    pub const M_MANY_MEMBER_ID: u32 = 6;
+    /// Member ID of `m_samples`
+    pub const M_SAMPLES_MEMBER_ID: u32 = 7;
+    /// Member ID of `m_more`
+    pub const M_MORE_MEMBER_ID: u32 = 8;
+}
+
+impl omg_cdr::CdrEncode for Foo {
+    fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+        writer.write_struct(self);
+    }
+}
+
+impl omg_cdr::CdrDecode for Foo {
+    fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+        reader.read_struct()
+    }
+}
+
+impl omg_cdr::CdrStruct for Foo {
+    fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+        omg_cdr::CdrEncode::encode(&self.m_required, writer);
+        writer.write_optional(Self::M_LONG_MEMBER_ID, &self.m_long, omg_cdr::CdrEncode::encode);
+        writer.write_optional(Self::M_STR_MEMBER_ID, &self.m_str, omg_cdr::CdrEncode::encode);
+        omg_cdr::CdrEncode::encode(&self.m_double, writer);
+        writer.write_optional(Self::M_ARR_MEMBER_ID, &self.m_arr, omg_cdr::CdrEncode::encode);
+        writer.write_optional(Self::M_BIG_MEMBER_ID, &self.m_big, omg_cdr::CdrEncode::encode);
+        writer.write_optional(Self::M_MANY_MEMBER_ID, &self.m_many, omg_cdr::CdrEncode::encode);
+        omg_cdr::CdrEncode::encode(&self.m_samples, writer);
+        writer.write_optional(Self::M_MORE_MEMBER_ID, &self.m_more, omg_cdr::CdrEncode::encode);
+    }
+
+    fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+        Ok(Self {
+            m_required: omg_cdr::CdrDecode::decode(reader)?,
+            m_long: reader.read_optional(Self::M_LONG_MEMBER_ID, omg_cdr::CdrDecode::decode)?,
+            m_str: reader.read_optional(Self::M_STR_MEMBER_ID, omg_cdr::CdrDecode::decode)?,
+            m_double: omg_cdr::CdrDecode::decode(reader)?,
+            m_arr: reader.read_optional(Self::M_ARR_MEMBER_ID, omg_cdr::CdrDecode::decode)?,
+            m_big: reader.read_optional(Self::M_BIG_MEMBER_ID, omg_cdr::CdrDecode::decode)?,
+            m_many: reader.read_optional(Self::M_MANY_MEMBER_ID, omg_cdr::CdrDecode::decode)?,
+            m_samples: omg_cdr::CdrDecode::decode(reader)?,
+            m_more: reader.read_optional(Self::M_MORE_MEMBER_ID, omg_cdr::CdrDecode::decode)?,
+        })
+    }
+}
+
+impl omg_cdr::CdrBounds for Foo {
+    const IS_BOUNDED: bool = false;
+    const MAX_XCDR1_SIZE: Option<usize> = None;
+    const MAX_XCDR2_SIZE: Option<usize> = None;
+}
+
+#[allow(dead_code, non_camel_case_types)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum Bar {
+    LABEL0{ #[serde(default, skip_serializing_if = "Option::is_none")] l: Option<i32>, },
+    LABEL1{ s: i16, },
+}
+
+impl Default for Bar {
+    fn default() -> Self {
+        Bar::LABEL0 { l: None }
+    }
+}
+//
+// TODO custom de-/serializer
+//
+
+impl omg_idl_rt::Extensible for Bar {
+    const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+}
+
+impl omg_idl_rt::TypeName for Bar {
+    const TYPE_NAME: &'static str = "Bar";
+    const REPOSITORY_ID: &'static str = "IDL:Bar:1.0";
+}
+
+#[allow(dead_code)]
+impl Bar {
+    /// Member ID of `l`
+    pub const L_MEMBER_ID: u32 = 1;
+    /// Member ID of `s`
+    pub const S_MEMBER_ID: u32 = 2;
+}
+
+impl omg_cdr::CdrEncode for Bar {
+    fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+        let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+        match self {
+            Bar::LABEL0 { l } => writer.write_union(extensibility, &0_i32, Self::L_MEMBER_ID, l, |value, writer| writer.write_optional(Self::L_MEMBER_ID, value, omg_cdr::CdrEncode::encode)),
+            Bar::LABEL1 { s } => writer.write_union(extensibility, &1_i32, Self::S_MEMBER_ID, s, omg_cdr::CdrEncode::encode),
+        }
+    }
+}
+
+impl omg_cdr::CdrDecode for Bar {
+    fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+        let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+        reader.read_union(extensibility, |discriminator: i32, reader| {
+            Ok(match discriminator {
+                0_i32 => Bar::LABEL0 { l: reader.read_branch(extensibility, Self::L_MEMBER_ID, |reader| reader.read_optional(Self::L_MEMBER_ID, omg_cdr::CdrDecode::decode))? },
+                1_i32 => Bar::LABEL1 { s: reader.read_branch(extensibility, Self::S_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                #[allow(unreachable_patterns)]
+                _ => return Err(omg_cdr::CdrError::InvalidDiscriminator),
+            })
+        })
+    }
+}
+
+impl omg_cdr::CdrBounds for Bar {
+    const IS_BOUNDED: bool = true;
+    const MAX_XCDR1_SIZE: Option<usize> = Some(12);
+    const MAX_XCDR2_SIZE: Option<usize> = Some(16);
+}
+
+#[allow(dead_code, non_upper_case_globals)]
+pub const LABEL0: i32 = 0;
+
+#[allow(dead_code, non_upper_case_globals)]
+pub const LABEL1: i32 = 1;
@@ -0,0 +1,591 @@
+
+#[allow(non_snake_case)]
+pub mod Discovery {
+    use serde_derive::{Serialize, Deserialize};
+    use std::vec::Vec;
+
+    #[allow(dead_code, non_camel_case_types)]
+    pub type ParameterId_t = u16;
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Guid {
+        #[allow(non_snake_case)]
+        #[serde(with = "serde_arrays")]
+        pub value: [u8;16_usize],
+    }
+
+    #[allow(dead_code)]
+    impl Guid {
+
+        pub fn new(value: [u8;16_usize], ) -> Self {
+            Self {
+                value,
+            }
+        }
+
+        pub fn value(&self) -> &[u8;16_usize] {
+            &self.value
+        }
+
+        pub fn set_value(&mut self, value: [u8;16_usize]) {
+            self.value = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Guid {
+        fn default() -> Self {
+            Self {
+                value: std::array::from_fn(|_| 0),
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Guid {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Guid {
+        const TYPE_NAME: &'static str = "Discovery::Guid";
+        const REPOSITORY_ID: &'static str = "IDL:Discovery/Guid:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Guid {
+        /// Member ID of `value`
+        pub const VALUE_MEMBER_ID: u32 = 0;
+    }
+
+    impl omg_cdr::CdrEncode for Guid {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Guid {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Guid {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.value, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                value: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Guid {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(16);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(20);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct UserData {
+        #[allow(non_snake_case)]
+        pub value: Vec<u8>,
+    }
+
+    #[allow(dead_code)]
+    impl UserData {
+
+        pub fn new(value: Vec<u8>, ) -> Self {
+            Self {
+                value,
+            }
+        }
+
+        pub fn value(&self) -> &Vec<u8> {
+            &self.value
+        }
+
+        pub fn set_value(&mut self, value: Vec<u8>) {
+            self.value = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for UserData {
+        fn default() -> Self {
+            Self {
+                value: Vec::new(),
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for UserData {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for UserData {
+        const TYPE_NAME: &'static str = "Discovery::UserData";
+        const REPOSITORY_ID: &'static str = "IDL:Discovery/UserData:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl UserData {
+        /// Member ID of `value`
+        pub const VALUE_MEMBER_ID: u32 = 0;
+    }
+
+    impl omg_cdr::CdrEncode for UserData {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for UserData {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for UserData {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.value, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                value: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for UserData {
+        const IS_BOUNDED: bool = false;
+        const MAX_XCDR1_SIZE: Option<usize> = None;
+        const MAX_XCDR2_SIZE: Option<usize> = None;
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct EntityData {
+        #[allow(non_snake_case)]
+        pub guid: Guid,
+    }
+
+    #[allow(dead_code)]
+    impl EntityData {
+
+        pub fn new(guid: Guid, ) -> Self {
+            Self {
+                guid,
+            }
+        }
+
+        pub fn guid(&self) -> &Guid {
+            &self.guid
+        }
+
+        pub fn set_guid(&mut self, value: Guid) {
+            self.guid = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for EntityData {
+        fn default() -> Self {
+            Self {
+                guid: Default::default(),
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for EntityData {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for EntityData {
+        const TYPE_NAME: &'static str = "Discovery::EntityData";
+        const REPOSITORY_ID: &'static str = "IDL:Discovery/EntityData:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl EntityData {
+        /// Member ID of `guid`
+        pub const GUID_MEMBER_ID: u32 = 0;
+    }
+
+    impl omg_cdr::CdrEncode for EntityData {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_parameter_list(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for EntityData {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_parameter_list()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for EntityData {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.guid, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                guid: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrParameterList for EntityData {
+        fn encode_parameters(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_pl_parameter(0x0050, &self.guid, omg_cdr::CdrEncode::encode);
+        }
+
+        fn decode_parameter(&mut self, id: u16, reader: &mut omg_cdr::CdrReader<'_>) -> Result<bool, omg_cdr::CdrError> {
+            match id {
+                0x0050 => self.guid = omg_cdr::CdrDecode::decode(reader)?,
+                _ => return Ok(false),
+            }
+            Ok(true)
+        }
+    }
+
+    impl omg_cdr::CdrBounds for EntityData {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(24);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(28);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct ParticipantData {
+        #[serde(flatten)]
+        pub base: EntityData,
+        #[allow(non_snake_case)]
+        pub user_data: UserData,
+        #[allow(non_snake_case)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub lease_duration: Option<u32>,
+    }
+
+    #[allow(dead_code)]
+    impl ParticipantData {
+
+        pub fn new(base: EntityData, user_data: UserData, ) -> Self {
+            Self {
+                base,
+                user_data,
+                lease_duration: None,
+            }
+        }
+
+        pub fn base(&self) -> &EntityData {
+            &self.base
+        }
+
+        pub fn set_base(&mut self, value: EntityData) {
+            self.base = value;
+        }
+
+        pub fn user_data(&self) -> &UserData {
+            &self.user_data
+        }
+
+        pub fn set_user_data(&mut self, value: UserData) {
+            self.user_data = value;
+        }
+
+        pub fn lease_duration(&self) -> Option<&u32> {
+            self.lease_duration.as_ref()
+        }
+
+        pub fn set_lease_duration(&mut self, value: Option<u32>) {
+            self.lease_duration = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for ParticipantData {
+        fn default() -> Self {
+            Self {
+                base: Default::default(),
+                user_data: Default::default(),
+                lease_duration: None,
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for ParticipantData {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for ParticipantData {
+        const TYPE_NAME: &'static str = "Discovery::ParticipantData";
+        const REPOSITORY_ID: &'static str = "IDL:Discovery/ParticipantData:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl ParticipantData {
+        /// Member ID of `user_data`
+        pub const USER_DATA_MEMBER_ID: u32 = 1;
+        /// Member ID of `lease_duration`
+        pub const LEASE_DURATION_MEMBER_ID: u32 = 2;
+    }
+
+    impl omg_cdr::CdrEncode for ParticipantData {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_parameter_list(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for ParticipantData {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_parameter_list()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for ParticipantData {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrStruct::encode_members(&self.base, writer);
+            omg_cdr::CdrEncode::encode(&self.user_data, writer);
+            writer.write_optional(Self::LEASE_DURATION_MEMBER_ID, &self.lease_duration, omg_cdr::CdrEncode::encode);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                base: omg_cdr::CdrStruct::decode_members(reader)?,
+                user_data: omg_cdr::CdrDecode::decode(reader)?,
+                lease_duration: reader.read_optional(Self::LEASE_DURATION_MEMBER_ID, omg_cdr::CdrDecode::decode)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrParameterList for ParticipantData {
+        fn encode_parameters(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrParameterList::encode_parameters(&self.base, writer);
+            writer.write_pl_parameter(0x002c, &self.user_data, omg_cdr::CdrEncode::encode);
+            if let Some(value) = &self.lease_duration {
+                writer.write_pl_parameter(0x0002, value, omg_cdr::CdrEncode::encode);
+            }
+        }
+
+        fn decode_parameter(&mut self, id: u16, reader: &mut omg_cdr::CdrReader<'_>) -> Result<bool, omg_cdr::CdrError> {
+            match id {
+                0x002c => self.user_data = omg_cdr::CdrDecode::decode(reader)?,
+                0x0002 => self.lease_duration = Some(omg_cdr::CdrDecode::decode(reader)?),
+                _ => return omg_cdr::CdrParameterList::decode_parameter(&mut self.base, id, reader),
+            }
+            Ok(true)
+        }
+    }
+
+    impl omg_cdr::CdrBounds for ParticipantData {
+        const IS_BOUNDED: bool = false;
+        const MAX_XCDR1_SIZE: Option<usize> = None;
+        const MAX_XCDR2_SIZE: Option<usize> = None;
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub enum Parameter {
+        PID_TOPIC_NAME{ topic_name: String, },
+        PID_PARTICIPANT_GUID{ guid: Guid, },
+        PID_ENDPOINT_GUID{ guid: Guid, },
+    }
+
+    impl Default for Parameter {
+        fn default() -> Self {
+            Parameter::PID_TOPIC_NAME { topic_name: String::new() }
+        }
+    }
+//
+// TODO custom de-/serializer
+//
+
+    impl omg_idl_rt::Extensible for Parameter {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Parameter {
+        const TYPE_NAME: &'static str = "Discovery::Parameter";
+        const REPOSITORY_ID: &'static str = "IDL:Discovery/Parameter:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Parameter {
+        /// Member ID of `topic_name`
+        pub const TOPIC_NAME_MEMBER_ID: u32 = 1;
+        /// Member ID of `guid`
+        pub const GUID_MEMBER_ID: u32 = 2;
+    }
+
+    impl omg_cdr::CdrEncode for Parameter {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            match self {
+                Parameter::PID_TOPIC_NAME { topic_name } => writer.write_union(extensibility, &5_u16, Self::TOPIC_NAME_MEMBER_ID, topic_name, omg_cdr::CdrEncode::encode),
+                Parameter::PID_PARTICIPANT_GUID { guid } => writer.write_union(extensibility, &80_u16, Self::GUID_MEMBER_ID, guid, omg_cdr::CdrEncode::encode),
+                Parameter::PID_ENDPOINT_GUID { guid } => writer.write_union(extensibility, &90_u16, Self::GUID_MEMBER_ID, guid, omg_cdr::CdrEncode::encode),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Parameter {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            reader.read_union(extensibility, |discriminator: ParameterId_t, reader| {
+                Ok(match discriminator {
+                    5_u16 => Parameter::PID_TOPIC_NAME { topic_name: reader.read_branch(extensibility, Self::TOPIC_NAME_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    80_u16 => Parameter::PID_PARTICIPANT_GUID { guid: reader.read_branch(extensibility, Self::GUID_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    90_u16 => Parameter::PID_ENDPOINT_GUID { guid: reader.read_branch(extensibility, Self::GUID_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    #[allow(unreachable_patterns)]
+                    _ => return Err(omg_cdr::CdrError::InvalidDiscriminator),
+                })
+            })
+        }
+    }
+
+    impl omg_cdr::CdrParameter for Parameter {
+        fn encode_parameter(&self, writer: &mut omg_cdr::CdrWriter) {
+            match self {
+                Parameter::PID_TOPIC_NAME { topic_name } => writer.write_pl_parameter(0x0005, topic_name, omg_cdr::CdrEncode::encode),
+                Parameter::PID_PARTICIPANT_GUID { guid } => writer.write_pl_parameter(0x0050, guid, omg_cdr::CdrEncode::encode),
+                Parameter::PID_ENDPOINT_GUID { guid } => writer.write_pl_parameter(0x005a, guid, omg_cdr::CdrEncode::encode),
+            }
+        }
+
+        fn decode_parameter(id: u16, reader: &mut omg_cdr::CdrReader<'_>) -> Result<Option<Self>, omg_cdr::CdrError> {
+            Ok(Some(match id {
+                0x0005 => Parameter::PID_TOPIC_NAME { topic_name: omg_cdr::CdrDecode::decode(reader)? },
+                0x0050 => Parameter::PID_PARTICIPANT_GUID { guid: omg_cdr::CdrDecode::decode(reader)? },
+                0x005a => Parameter::PID_ENDPOINT_GUID { guid: omg_cdr::CdrDecode::decode(reader)? },
+                _ => return Ok(None),
+            }))
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Parameter {
+        const IS_BOUNDED: bool = false;
+        const MAX_XCDR1_SIZE: Option<usize> = None;
+        const MAX_XCDR2_SIZE: Option<usize> = None;
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    pub type ParameterList = Vec<Parameter>;
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Message {
+        #[allow(non_snake_case)]
+        pub sequence_number: i32,
+        #[allow(non_snake_case)]
+        pub inline_qos: ParameterList,
+    }
+
+    #[allow(dead_code)]
+    impl Message {
+
+        pub fn new(sequence_number: i32, inline_qos: ParameterList, ) -> Self {
+            Self {
+                sequence_number,
+                inline_qos,
+            }
+        }
+
+        pub fn sequence_number(&self) -> &i32 {
+            &self.sequence_number
+        }
+
+        pub fn set_sequence_number(&mut self, value: i32) {
+            self.sequence_number = value;
+        }
+
+        pub fn inline_qos(&self) -> &ParameterList {
+            &self.inline_qos
+        }
+
+        pub fn set_inline_qos(&mut self, value: ParameterList) {
+            self.inline_qos = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Message {
+        fn default() -> Self {
+            Self {
+                sequence_number: 0,
+                inline_qos: Vec::new(),
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Message {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Message {
+        const TYPE_NAME: &'static str = "Discovery::Message";
+        const REPOSITORY_ID: &'static str = "IDL:Discovery/Message:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Message {
+        /// Member ID of `sequence_number`
+        pub const SEQUENCE_NUMBER_MEMBER_ID: u32 = 0;
+        /// Member ID of `inline_qos`
+        pub const INLINE_QOS_MEMBER_ID: u32 = 1;
+    }
+
+    impl omg_cdr::CdrEncode for Message {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Message {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Message {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.sequence_number, writer);
+            <omg_cdr::ParameterSequence as omg_cdr::Codec<_>>::encode(&self.inline_qos, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                sequence_number: omg_cdr::CdrDecode::decode(reader)?,
+                inline_qos: <omg_cdr::ParameterSequence as omg_cdr::Codec<_>>::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Message {
+        const IS_BOUNDED: bool = false;
+        const MAX_XCDR1_SIZE: Option<usize> = None;
+        const MAX_XCDR2_SIZE: Option<usize> = None;
+    }
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const PID_TOPIC_NAME: ParameterId_t = 0x0005;
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const PID_USER_DATA: ParameterId_t = 0x002c;
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const PID_PARTICIPANT_GUID: ParameterId_t = 0x0050;
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const PID_ENDPOINT_GUID: ParameterId_t = 0x005a;
+
+}
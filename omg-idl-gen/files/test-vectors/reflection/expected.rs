@@ -0,0 +1,1372 @@
+
+#[allow(non_snake_case)]
+pub mod DDS {
+    use serde_derive::{Serialize, Deserialize};
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    #[serde(transparent)]
+    #[repr(transparent)]
+    pub struct KeyValue_t(#[serde(with = "serde_arrays")] pub [i32;KEY_LENGTH as usize]);
+
+    impl Default for KeyValue_t {
+        fn default() -> Self {
+            Self(std::array::from_fn(|_| 0))
+        }
+    }
+
+    impl std::ops::Deref for KeyValue_t {
+        type Target = [i32;KEY_LENGTH as usize];
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl From<[i32;KEY_LENGTH as usize]> for KeyValue_t {
+        fn from(value: [i32;KEY_LENGTH as usize]) -> Self {
+            Self(value)
+        }
+    }
+
+    impl From<KeyValue_t> for [i32;KEY_LENGTH as usize] {
+        fn from(value: KeyValue_t) -> Self {
+            value.0
+        }
+    }
+
+    impl omg_cdr::CdrEncode for KeyValue_t {
+        const PRIMITIVE: bool = <[i32;KEY_LENGTH as usize] as omg_cdr::CdrEncode>::PRIMITIVE;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.0, writer)
+        }
+    }
+
+    impl omg_cdr::CdrDecode for KeyValue_t {
+        const PRIMITIVE: bool = <[i32;KEY_LENGTH as usize] as omg_cdr::CdrDecode>::PRIMITIVE;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            omg_cdr::CdrDecode::decode(reader).map(Self)
+        }
+    }
+
+    impl omg_idl_rt::KeyHashEncode for KeyValue_t {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<[i32;KEY_LENGTH as usize]>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.0, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.0, &other.0)
+        }
+    }
+
+    impl omg_idl_rt::TypeName for KeyValue_t {
+        const TYPE_NAME: &'static str = "DDS::KeyValue_t";
+        const REPOSITORY_ID: &'static str = "IDL:DDS/KeyValue_t:1.0";
+    }
+
+    impl omg_idl_rt::Reflect for KeyValue_t {
+        fn type_name(&self) -> Option<&'static str> {
+            Some(<Self as omg_idl_rt::TypeName>::TYPE_NAME)
+        }
+
+        fn to_value(&self) -> omg_idl_rt::Value {
+            omg_idl_rt::Reflect::to_value(&self.0)
+        }
+
+        fn set_value(&mut self, value: omg_idl_rt::Value) -> Result<(), omg_idl_rt::ReflectError> {
+            omg_idl_rt::Reflect::set_value(&mut self.0, value)
+        }
+
+        fn from_value(value: omg_idl_rt::Value) -> Result<Self, omg_idl_rt::ReflectError> {
+            omg_idl_rt::Reflect::from_value(value).map(Self)
+        }
+
+        fn field(&self, segment: omg_idl_rt::PathSegment) -> Result<&dyn omg_idl_rt::Reflect, omg_idl_rt::ReflectError> {
+            omg_idl_rt::Reflect::field(&self.0, segment)
+        }
+
+        fn field_mut(&mut self, segment: omg_idl_rt::PathSegment) -> Result<&mut dyn omg_idl_rt::Reflect, omg_idl_rt::ReflectError> {
+            omg_idl_rt::Reflect::field_mut(&mut self.0, segment)
+        }
+
+        fn set_member(&mut self, segment: omg_idl_rt::PathSegment, value: omg_idl_rt::Value) -> Result<(), omg_idl_rt::ReflectError> {
+            omg_idl_rt::Reflect::set_member(&mut self.0, segment, value)
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct BuiltinTopicKey_t {
+        #[allow(non_snake_case)]
+        pub value: KeyValue_t,
+    }
+
+    #[allow(dead_code)]
+    impl BuiltinTopicKey_t {
+
+        pub fn new(value: KeyValue_t, ) -> Self {
+            Self {
+                value,
+            }
+        }
+
+        pub fn value(&self) -> &KeyValue_t {
+            &self.value
+        }
+
+        pub fn set_value(&mut self, value: KeyValue_t) {
+            self.value = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for BuiltinTopicKey_t {
+        fn default() -> Self {
+            Self {
+                value: Default::default(),
+            }
+        }
+    }
+
+    impl omg_idl_rt::KeyHashEncode for BuiltinTopicKey_t {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<KeyValue_t>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.value, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.value, &other.value)
+        }
+    }
+
+    impl omg_idl_rt::Extensible for BuiltinTopicKey_t {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for BuiltinTopicKey_t {
+        const TYPE_NAME: &'static str = "DDS::BuiltinTopicKey_t";
+        const REPOSITORY_ID: &'static str = "IDL:DDS/BuiltinTopicKey_t:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl BuiltinTopicKey_t {
+        /// Member ID of `value`
+        pub const VALUE_MEMBER_ID: u32 = 0;
+    }
+
+    impl omg_cdr::CdrEncode for BuiltinTopicKey_t {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for BuiltinTopicKey_t {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for BuiltinTopicKey_t {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.value, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                value: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for BuiltinTopicKey_t {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(16);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(20);
+    }
+
+    impl omg_idl_rt::Reflect for BuiltinTopicKey_t {
+        fn type_name(&self) -> Option<&'static str> {
+            Some(<Self as omg_idl_rt::TypeName>::TYPE_NAME)
+        }
+
+        fn members(&self) -> &'static [omg_idl_rt::MemberDescriptor] {
+            &[
+                omg_idl_rt::MemberDescriptor { name: "value", id: 0, type_descriptor: omg_idl_rt::TypeDescriptor::Named("DDS::KeyValue_t"), key: false, optional: false },
+            ]
+        }
+
+        fn to_value(&self) -> omg_idl_rt::Value {
+            omg_idl_rt::struct_value(None, [("value", &self.value)])
+        }
+
+        fn set_value(&mut self, value: omg_idl_rt::Value) -> Result<(), omg_idl_rt::ReflectError> {
+            omg_idl_rt::set_struct_value(self, value)
+        }
+
+        fn from_value(value: omg_idl_rt::Value) -> Result<Self, omg_idl_rt::ReflectError> {
+            let mut result = Self::default();
+            omg_idl_rt::Reflect::set_value(&mut result, value)?;
+            Ok(result)
+        }
+
+        fn field(&self, segment: omg_idl_rt::PathSegment) -> Result<&dyn omg_idl_rt::Reflect, omg_idl_rt::ReflectError> {
+            match segment {
+                omg_idl_rt::PathSegment::Member("value") => Ok(&self.value),
+                segment => Err(omg_idl_rt::segment_error(segment)),
+            }
+        }
+
+        fn field_mut(&mut self, segment: omg_idl_rt::PathSegment) -> Result<&mut dyn omg_idl_rt::Reflect, omg_idl_rt::ReflectError> {
+            match segment {
+                omg_idl_rt::PathSegment::Member("value") => Ok(&mut self.value),
+                segment => Err(omg_idl_rt::segment_error(segment)),
+            }
+        }
+
+        fn set_member(&mut self, segment: omg_idl_rt::PathSegment, value: omg_idl_rt::Value) -> Result<(), omg_idl_rt::ReflectError> {
+            match segment {
+                omg_idl_rt::PathSegment::Member("value") => omg_idl_rt::Reflect::set_value(&mut self.value, value),
+                segment => Err(omg_idl_rt::segment_error(segment)),
+            }
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, PartialOrd, Eq, Ord)]
+    pub enum Kind {
+        #[default]
+        AUTOMATIC,
+        MANUAL,
+    }
+
+    #[allow(non_camel_case_types)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct KindError;
+
+    impl std::str::FromStr for Kind {
+        type Err = KindError;
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            match value {
+                "AUTOMATIC" => Ok(Kind::AUTOMATIC),
+                "MANUAL" => Ok(Kind::MANUAL),
+                _ => Err(KindError),
+            }
+        }
+    }
+
+    impl std::fmt::Display for Kind {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let enum_str = match self {
+                    Kind::AUTOMATIC => "AUTOMATIC",
+                    Kind::MANUAL => "MANUAL",
+            };
+            write!(f, "{enum_str}")
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Kind {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Kind {
+        const TYPE_NAME: &'static str = "DDS::Kind";
+        const REPOSITORY_ID: &'static str = "IDL:DDS/Kind:1.0";
+    }
+
+    impl omg_cdr::CdrEncode for Kind {
+        const PRIMITIVE: bool = true;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let value: u32 = match self {
+                Kind::AUTOMATIC => 0,
+                Kind::MANUAL => 1,
+            };
+            omg_cdr::CdrEncode::encode(&value, writer);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Kind {
+        const PRIMITIVE: bool = true;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            match <u32 as omg_cdr::CdrDecode>::decode(reader)? {
+                0 => Ok(Kind::AUTOMATIC),
+                1 => Ok(Kind::MANUAL),
+                value => Err(omg_cdr::CdrError::InvalidEnum(value)),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Kind {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(4);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(4);
+    }
+
+    impl omg_idl_rt::Reflect for Kind {
+        fn type_name(&self) -> Option<&'static str> {
+            Some(<Self as omg_idl_rt::TypeName>::TYPE_NAME)
+        }
+
+        fn to_value(&self) -> omg_idl_rt::Value {
+            let enumerator = match self {
+                Kind::AUTOMATIC => "AUTOMATIC",
+                Kind::MANUAL => "MANUAL",
+            };
+            omg_idl_rt::Value::Enum(enumerator.into())
+        }
+
+        fn set_value(&mut self, value: omg_idl_rt::Value) -> Result<(), omg_idl_rt::ReflectError> {
+            *self = <Self as omg_idl_rt::Reflect>::from_value(value)?;
+            Ok(())
+        }
+
+        fn from_value(value: omg_idl_rt::Value) -> Result<Self, omg_idl_rt::ReflectError> {
+            let omg_idl_rt::Value::Enum(enumerator) = value else {
+                return Err(omg_idl_rt::ReflectError::TypeMismatch);
+            };
+            match enumerator.as_str() {
+                "AUTOMATIC" => Ok(Kind::AUTOMATIC),
+                "MANUAL" => Ok(Kind::MANUAL),
+                _ => Err(omg_idl_rt::ReflectError::TypeMismatch),
+            }
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub enum Policy {
+        AUTOMATIC{ lease: f64, },
+        MANUAL{ owner: String, },
+    }
+
+    impl Default for Policy {
+        fn default() -> Self {
+            Policy::AUTOMATIC { lease: 0.0 }
+        }
+    }
+//
+// TODO custom de-/serializer
+//
+
+    impl omg_idl_rt::Extensible for Policy {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Policy {
+        const TYPE_NAME: &'static str = "DDS::Policy";
+        const REPOSITORY_ID: &'static str = "IDL:DDS/Policy:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Policy {
+        /// Member ID of `lease`
+        pub const LEASE_MEMBER_ID: u32 = 1;
+        /// Member ID of `owner`
+        pub const OWNER_MEMBER_ID: u32 = 2;
+    }
+
+    impl omg_cdr::CdrEncode for Policy {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            match self {
+                Policy::AUTOMATIC { lease } => writer.write_union(extensibility, &Kind::AUTOMATIC, Self::LEASE_MEMBER_ID, lease, omg_cdr::CdrEncode::encode),
+                Policy::MANUAL { owner } => writer.write_union(extensibility, &Kind::MANUAL, Self::OWNER_MEMBER_ID, owner, omg_cdr::CdrEncode::encode),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Policy {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            reader.read_union(extensibility, |discriminator: Kind, reader| {
+                Ok(match discriminator {
+                    Kind::AUTOMATIC => Policy::AUTOMATIC { lease: reader.read_branch(extensibility, Self::LEASE_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    Kind::MANUAL => Policy::MANUAL { owner: reader.read_branch(extensibility, Self::OWNER_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    #[allow(unreachable_patterns)]
+                    _ => return Err(omg_cdr::CdrError::InvalidDiscriminator),
+                })
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Policy {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(25);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(29);
+    }
+
+    impl omg_idl_rt::Reflect for Policy {
+        fn type_name(&self) -> Option<&'static str> {
+            Some(<Self as omg_idl_rt::TypeName>::TYPE_NAME)
+        }
+
+        fn members(&self) -> &'static [omg_idl_rt::MemberDescriptor] {
+            &[
+                omg_idl_rt::MemberDescriptor { name: "lease", id: 1, type_descriptor: omg_idl_rt::TypeDescriptor::Double, key: false, optional: false },
+                omg_idl_rt::MemberDescriptor { name: "owner", id: 2, type_descriptor: omg_idl_rt::TypeDescriptor::String(Some(16)), key: false, optional: false },
+            ]
+        }
+
+        fn to_value(&self) -> omg_idl_rt::Value {
+            let (label, value) = match self {
+                Policy::AUTOMATIC { lease } => ("AUTOMATIC", omg_idl_rt::Reflect::to_value(lease)),
+                Policy::MANUAL { owner } => ("MANUAL", omg_idl_rt::Reflect::to_value(owner)),
+            };
+            omg_idl_rt::Value::Union(label.into(), value.into())
+        }
+
+        fn set_value(&mut self, value: omg_idl_rt::Value) -> Result<(), omg_idl_rt::ReflectError> {
+            *self = <Self as omg_idl_rt::Reflect>::from_value(value)?;
+            Ok(())
+        }
+
+        fn from_value(value: omg_idl_rt::Value) -> Result<Self, omg_idl_rt::ReflectError> {
+            let omg_idl_rt::Value::Union(label, value) = value else {
+                return Err(omg_idl_rt::ReflectError::TypeMismatch);
+            };
+            match label.as_str() {
+                "AUTOMATIC" => Ok(Policy::AUTOMATIC { lease: omg_idl_rt::Reflect::from_value(*value)? }),
+                "MANUAL" => {
+                    omg_idl_rt::check_length(&value, 16)?;
+                    Ok(Policy::MANUAL { owner: omg_idl_rt::Reflect::from_value(*value)? })
+                }
+                _ => Err(omg_idl_rt::ReflectError::TypeMismatch),
+            }
+        }
+
+        fn field(&self, segment: omg_idl_rt::PathSegment) -> Result<&dyn omg_idl_rt::Reflect, omg_idl_rt::ReflectError> {
+            match (self, segment) {
+                (Policy::AUTOMATIC { lease }, omg_idl_rt::PathSegment::Member("lease")) => Ok(lease),
+                (Policy::MANUAL { owner }, omg_idl_rt::PathSegment::Member("owner")) => Ok(owner),
+                (_, segment) => Err(omg_idl_rt::element_error(segment, &["lease", "owner"])),
+            }
+        }
+
+        fn field_mut(&mut self, segment: omg_idl_rt::PathSegment) -> Result<&mut dyn omg_idl_rt::Reflect, omg_idl_rt::ReflectError> {
+            match (self, segment) {
+                (Policy::AUTOMATIC { lease }, omg_idl_rt::PathSegment::Member("lease")) => Ok(lease),
+                (Policy::MANUAL { owner }, omg_idl_rt::PathSegment::Member("owner")) => Ok(owner),
+                (_, segment) => Err(omg_idl_rt::element_error(segment, &["lease", "owner"])),
+            }
+        }
+
+        fn set_member(&mut self, segment: omg_idl_rt::PathSegment, value: omg_idl_rt::Value) -> Result<(), omg_idl_rt::ReflectError> {
+            match (self, segment) {
+                (Policy::MANUAL { owner }, omg_idl_rt::PathSegment::Member("owner")) => {
+                    omg_idl_rt::check_length(&value, 16)?;
+                    omg_idl_rt::Reflect::set_value(owner, value)
+                }
+                (union, segment) => omg_idl_rt::Reflect::field_mut(union, segment)?.set_value(value),
+            }
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct EntityData {
+        #[allow(non_snake_case)]
+        pub key: BuiltinTopicKey_t,
+    }
+
+    #[allow(dead_code)]
+    impl EntityData {
+
+        pub fn new(key: BuiltinTopicKey_t, ) -> Self {
+            Self {
+                key,
+            }
+        }
+
+        pub fn key(&self) -> &BuiltinTopicKey_t {
+            &self.key
+        }
+
+        pub fn set_key(&mut self, value: BuiltinTopicKey_t) {
+            self.key = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for EntityData {
+        fn default() -> Self {
+            Self {
+                key: Default::default(),
+            }
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug)]
+    pub struct EntityDataKey {
+        pub key: BuiltinTopicKey_t,
+    }
+
+    impl omg_idl_rt::KeyHashEncode for EntityDataKey {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<BuiltinTopicKey_t>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.key, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.key, &other.key)
+        }
+    }
+
+    impl PartialEq for EntityDataKey {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other).is_eq()
+        }
+    }
+
+    impl Eq for EntityDataKey {}
+
+    impl PartialOrd for EntityDataKey {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for EntityDataKey {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other)
+        }
+    }
+
+    impl std::hash::Hash for EntityDataKey {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            omg_idl_rt::hash_key(self, state);
+        }
+    }
+
+    impl omg_idl_rt::Keyed for EntityData {
+        type Key = EntityDataKey;
+
+        fn key(&self) -> Self::Key {
+            EntityDataKey {
+                key: self.key.clone(),
+            }
+        }
+    }
+
+    /// Equal if the key members are equal, other members are ignored
+    impl PartialEq for EntityData {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.key, &other.key)
+                .is_eq()
+        }
+    }
+
+    impl Eq for EntityData {}
+
+    impl PartialOrd for EntityData {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    /// Orders by the key members, other members are ignored
+    impl Ord for EntityData {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.key, &other.key)
+        }
+    }
+
+    /// Hashes the key members only, consistent with `PartialEq`
+    impl std::hash::Hash for EntityData {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            let mut writer = omg_idl_rt::KeyHolderWriter::hashing(state);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.key, &mut writer);
+        }
+    }
+
+    impl omg_idl_rt::KeyHashEncode for EntityData {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<BuiltinTopicKey_t>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.key, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.key, &other.key)
+        }
+    }
+
+    impl omg_idl_rt::Extensible for EntityData {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for EntityData {
+        const TYPE_NAME: &'static str = "DDS::EntityData";
+        const REPOSITORY_ID: &'static str = "IDL:DDS/EntityData:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl EntityData {
+        /// Member ID of `key`
+        pub const KEY_MEMBER_ID: u32 = 0;
+    }
+
+    impl omg_cdr::CdrEncode for EntityData {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for EntityData {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for EntityData {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.key, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                key: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for EntityData {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(16);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(24);
+    }
+
+    impl omg_idl_rt::Reflect for EntityData {
+        fn type_name(&self) -> Option<&'static str> {
+            Some(<Self as omg_idl_rt::TypeName>::TYPE_NAME)
+        }
+
+        fn members(&self) -> &'static [omg_idl_rt::MemberDescriptor] {
+            &[
+                omg_idl_rt::MemberDescriptor { name: "key", id: 0, type_descriptor: omg_idl_rt::TypeDescriptor::Named("DDS::BuiltinTopicKey_t"), key: true, optional: false },
+            ]
+        }
+
+        fn to_value(&self) -> omg_idl_rt::Value {
+            omg_idl_rt::struct_value(None, [("key", &self.key)])
+        }
+
+        fn set_value(&mut self, value: omg_idl_rt::Value) -> Result<(), omg_idl_rt::ReflectError> {
+            omg_idl_rt::set_struct_value(self, value)
+        }
+
+        fn from_value(value: omg_idl_rt::Value) -> Result<Self, omg_idl_rt::ReflectError> {
+            let mut result = Self::default();
+            omg_idl_rt::Reflect::set_value(&mut result, value)?;
+            Ok(result)
+        }
+
+        fn field(&self, segment: omg_idl_rt::PathSegment) -> Result<&dyn omg_idl_rt::Reflect, omg_idl_rt::ReflectError> {
+            match segment {
+                omg_idl_rt::PathSegment::Member("key") => Ok(&self.key),
+                segment => Err(omg_idl_rt::segment_error(segment)),
+            }
+        }
+
+        fn field_mut(&mut self, segment: omg_idl_rt::PathSegment) -> Result<&mut dyn omg_idl_rt::Reflect, omg_idl_rt::ReflectError> {
+            match segment {
+                omg_idl_rt::PathSegment::Member("key") => Ok(&mut self.key),
+                segment => Err(omg_idl_rt::segment_error(segment)),
+            }
+        }
+
+        fn set_member(&mut self, segment: omg_idl_rt::PathSegment, value: omg_idl_rt::Value) -> Result<(), omg_idl_rt::ReflectError> {
+            match segment {
+                omg_idl_rt::PathSegment::Member("key") => omg_idl_rt::Reflect::set_value(&mut self.key, value),
+                segment => Err(omg_idl_rt::segment_error(segment)),
+            }
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct TopicData {
+        #[serde(flatten)]
+        pub base: EntityData,
+        #[allow(non_snake_case)]
+        pub name: String,
+        #[allow(non_snake_case)]
+        pub data: Vec<u8>,
+        #[allow(non_snake_case)]
+        pub matrix: Vec<Vec<i16>>,
+        #[allow(non_snake_case)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub policy: Option<Policy>,
+        #[allow(non_snake_case)]
+        pub kind: Kind,
+    }
+
+    #[allow(dead_code)]
+    impl TopicData {
+
+        pub fn new(base: EntityData, name: String, data: Vec<u8>, matrix: Vec<Vec<i16>>, kind: Kind, ) -> Self {
+            Self {
+                base,
+                name,
+                data,
+                matrix,
+                policy: None,
+                kind,
+            }
+        }
+
+        pub fn base(&self) -> &EntityData {
+            &self.base
+        }
+
+        pub fn set_base(&mut self, value: EntityData) {
+            self.base = value;
+        }
+
+        pub fn name(&self) -> &String {
+            &self.name
+        }
+
+        pub fn set_name(&mut self, value: String) {
+            self.name = value;
+        }
+
+        pub fn data(&self) -> &Vec<u8> {
+            &self.data
+        }
+
+        pub fn set_data(&mut self, value: Vec<u8>) {
+            self.data = value;
+        }
+
+        pub fn matrix(&self) -> &Vec<Vec<i16>> {
+            &self.matrix
+        }
+
+        pub fn set_matrix(&mut self, value: Vec<Vec<i16>>) {
+            self.matrix = value;
+        }
+
+        pub fn policy(&self) -> Option<&Policy> {
+            self.policy.as_ref()
+        }
+
+        pub fn set_policy(&mut self, value: Option<Policy>) {
+            self.policy = value;
+        }
+
+        pub fn kind(&self) -> &Kind {
+            &self.kind
+        }
+
+        pub fn set_kind(&mut self, value: Kind) {
+            self.kind = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for TopicData {
+        fn default() -> Self {
+            Self {
+                base: Default::default(),
+                name: String::new(),
+                data: Vec::new(),
+                matrix: Vec::new(),
+                policy: None,
+                kind: Default::default(),
+            }
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug)]
+    pub struct TopicDataKey {
+        pub base: EntityData,
+    }
+
+    impl omg_idl_rt::KeyHashEncode for TopicDataKey {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<EntityData>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.base, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.base, &other.base)
+        }
+    }
+
+    impl PartialEq for TopicDataKey {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other).is_eq()
+        }
+    }
+
+    impl Eq for TopicDataKey {}
+
+    impl PartialOrd for TopicDataKey {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for TopicDataKey {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other)
+        }
+    }
+
+    impl std::hash::Hash for TopicDataKey {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            omg_idl_rt::hash_key(self, state);
+        }
+    }
+
+    impl omg_idl_rt::Keyed for TopicData {
+        type Key = TopicDataKey;
+
+        fn key(&self) -> Self::Key {
+            TopicDataKey {
+                base: self.base.clone(),
+            }
+        }
+    }
+
+    /// Equal if the key members are equal, other members are ignored
+    impl PartialEq for TopicData {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.base, &other.base)
+                .is_eq()
+        }
+    }
+
+    impl Eq for TopicData {}
+
+    impl PartialOrd for TopicData {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    /// Orders by the key members, other members are ignored
+    impl Ord for TopicData {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.base, &other.base)
+        }
+    }
+
+    /// Hashes the key members only, consistent with `PartialEq`
+    impl std::hash::Hash for TopicData {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            let mut writer = omg_idl_rt::KeyHolderWriter::hashing(state);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.base, &mut writer);
+        }
+    }
+
+    impl omg_idl_rt::Extensible for TopicData {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for TopicData {
+        const TYPE_NAME: &'static str = "DDS::TopicData";
+        const REPOSITORY_ID: &'static str = "IDL:DDS/TopicData:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl TopicData {
+        /// Member ID of `name`
+        pub const NAME_MEMBER_ID: u32 = 1;
+        /// Member ID of `data`
+        pub const DATA_MEMBER_ID: u32 = 2;
+        /// Member ID of `matrix`
+        pub const MATRIX_MEMBER_ID: u32 = 3;
+        /// Member ID of `policy`
+        pub const POLICY_MEMBER_ID: u32 = 4;
+        /// Member ID of `kind`
+        pub const KIND_MEMBER_ID: u32 = 5;
+    }
+
+    impl omg_cdr::CdrEncode for TopicData {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for TopicData {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for TopicData {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrStruct::encode_members(&self.base, writer);
+            omg_cdr::CdrEncode::encode(&self.name, writer);
+            omg_cdr::CdrEncode::encode(&self.data, writer);
+            omg_cdr::CdrEncode::encode(&self.matrix, writer);
+            writer.write_optional(Self::POLICY_MEMBER_ID, &self.policy, omg_cdr::CdrEncode::encode);
+            omg_cdr::CdrEncode::encode(&self.kind, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                base: omg_cdr::CdrStruct::decode_members(reader)?,
+                name: omg_cdr::CdrDecode::decode(reader)?,
+                data: omg_cdr::CdrDecode::decode(reader)?,
+                matrix: omg_cdr::CdrDecode::decode(reader)?,
+                policy: reader.read_optional(Self::POLICY_MEMBER_ID, omg_cdr::CdrDecode::decode)?,
+                kind: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for TopicData {
+        const IS_BOUNDED: bool = false;
+        const MAX_XCDR1_SIZE: Option<usize> = None;
+        const MAX_XCDR2_SIZE: Option<usize> = None;
+    }
+
+    impl omg_idl_rt::Reflect for TopicData {
+        fn type_name(&self) -> Option<&'static str> {
+            Some(<Self as omg_idl_rt::TypeName>::TYPE_NAME)
+        }
+
+        fn members(&self) -> &'static [omg_idl_rt::MemberDescriptor] {
+            &[
+                omg_idl_rt::MemberDescriptor { name: "key", id: 0, type_descriptor: omg_idl_rt::TypeDescriptor::Named("DDS::BuiltinTopicKey_t"), key: true, optional: false },
+                omg_idl_rt::MemberDescriptor { name: "name", id: 1, type_descriptor: omg_idl_rt::TypeDescriptor::String(None), key: false, optional: false },
+                omg_idl_rt::MemberDescriptor { name: "data", id: 2, type_descriptor: omg_idl_rt::TypeDescriptor::Sequence(&omg_idl_rt::TypeDescriptor::Octet, Some(8)), key: false, optional: false },
+                omg_idl_rt::MemberDescriptor { name: "matrix", id: 3, type_descriptor: omg_idl_rt::TypeDescriptor::Sequence(&omg_idl_rt::TypeDescriptor::Sequence(&omg_idl_rt::TypeDescriptor::Short, None), None), key: false, optional: false },
+                omg_idl_rt::MemberDescriptor { name: "policy", id: 4, type_descriptor: omg_idl_rt::TypeDescriptor::Named("DDS::Policy"), key: false, optional: true },
+                omg_idl_rt::MemberDescriptor { name: "kind", id: 5, type_descriptor: omg_idl_rt::TypeDescriptor::Named("DDS::Kind"), key: false, optional: false },
+            ]
+        }
+
+        fn to_value(&self) -> omg_idl_rt::Value {
+            omg_idl_rt::struct_value(Some(&self.base), [("name", &self.name), ("data", &self.data), ("matrix", &self.matrix), ("policy", &self.policy), ("kind", &self.kind)])
+        }
+
+        fn set_value(&mut self, value: omg_idl_rt::Value) -> Result<(), omg_idl_rt::ReflectError> {
+            omg_idl_rt::set_struct_value(self, value)
+        }
+
+        fn from_value(value: omg_idl_rt::Value) -> Result<Self, omg_idl_rt::ReflectError> {
+            let mut result = Self::default();
+            omg_idl_rt::Reflect::set_value(&mut result, value)?;
+            Ok(result)
+        }
+
+        fn field(&self, segment: omg_idl_rt::PathSegment) -> Result<&dyn omg_idl_rt::Reflect, omg_idl_rt::ReflectError> {
+            match segment {
+                omg_idl_rt::PathSegment::Member("name") => Ok(&self.name),
+                omg_idl_rt::PathSegment::Member("data") => Ok(&self.data),
+                omg_idl_rt::PathSegment::Member("matrix") => Ok(&self.matrix),
+                omg_idl_rt::PathSegment::Member("policy") => Ok(&self.policy),
+                omg_idl_rt::PathSegment::Member("kind") => Ok(&self.kind),
+                segment => omg_idl_rt::Reflect::field(&self.base, segment),
+            }
+        }
+
+        fn field_mut(&mut self, segment: omg_idl_rt::PathSegment) -> Result<&mut dyn omg_idl_rt::Reflect, omg_idl_rt::ReflectError> {
+            match segment {
+                omg_idl_rt::PathSegment::Member("name") => Ok(&mut self.name),
+                omg_idl_rt::PathSegment::Member("data") => Ok(&mut self.data),
+                omg_idl_rt::PathSegment::Member("matrix") => Ok(&mut self.matrix),
+                omg_idl_rt::PathSegment::Member("policy") => Ok(&mut self.policy),
+                omg_idl_rt::PathSegment::Member("kind") => Ok(&mut self.kind),
+                segment => omg_idl_rt::Reflect::field_mut(&mut self.base, segment),
+            }
+        }
+
+        fn set_member(&mut self, segment: omg_idl_rt::PathSegment, value: omg_idl_rt::Value) -> Result<(), omg_idl_rt::ReflectError> {
+            match segment {
+                omg_idl_rt::PathSegment::Member("name") => omg_idl_rt::Reflect::set_value(&mut self.name, value),
+                omg_idl_rt::PathSegment::Member("data") => {
+                    omg_idl_rt::check_length(&value, 8)?;
+                    omg_idl_rt::Reflect::set_value(&mut self.data, value)
+                }
+                omg_idl_rt::PathSegment::Member("matrix") => omg_idl_rt::Reflect::set_value(&mut self.matrix, value),
+                omg_idl_rt::PathSegment::Member("policy") => omg_idl_rt::Reflect::set_value(&mut self.policy, value),
+                omg_idl_rt::PathSegment::Member("kind") => omg_idl_rt::Reflect::set_value(&mut self.kind, value),
+                segment => omg_idl_rt::Reflect::set_member(&mut self.base, segment, value),
+            }
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    #[serde(transparent)]
+    #[repr(transparent)]
+    pub struct Label(pub String);
+
+    impl Default for Label {
+        fn default() -> Self {
+            Self(String::new())
+        }
+    }
+
+    impl std::ops::Deref for Label {
+        type Target = String;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl From<String> for Label {
+        fn from(value: String) -> Self {
+            Self(value)
+        }
+    }
+
+    impl From<Label> for String {
+        fn from(value: Label) -> Self {
+            value.0
+        }
+    }
+
+    impl omg_cdr::CdrEncode for Label {
+        const PRIMITIVE: bool = <String as omg_cdr::CdrEncode>::PRIMITIVE;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.0, writer)
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Label {
+        const PRIMITIVE: bool = <String as omg_cdr::CdrDecode>::PRIMITIVE;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            omg_cdr::CdrDecode::decode(reader).map(Self)
+        }
+    }
+
+    impl omg_idl_rt::TypeName for Label {
+        const TYPE_NAME: &'static str = "DDS::Label";
+        const REPOSITORY_ID: &'static str = "IDL:DDS/Label:1.0";
+    }
+
+    impl omg_idl_rt::Reflect for Label {
+        fn type_name(&self) -> Option<&'static str> {
+            Some(<Self as omg_idl_rt::TypeName>::TYPE_NAME)
+        }
+
+        fn to_value(&self) -> omg_idl_rt::Value {
+            omg_idl_rt::Reflect::to_value(&self.0)
+        }
+
+        fn set_value(&mut self, value: omg_idl_rt::Value) -> Result<(), omg_idl_rt::ReflectError> {
+            omg_idl_rt::check_length(&value, 8)?;
+            omg_idl_rt::Reflect::set_value(&mut self.0, value)
+        }
+
+        fn from_value(value: omg_idl_rt::Value) -> Result<Self, omg_idl_rt::ReflectError> {
+            omg_idl_rt::check_length(&value, 8)?;
+            omg_idl_rt::Reflect::from_value(value).map(Self)
+        }
+
+        fn field(&self, segment: omg_idl_rt::PathSegment) -> Result<&dyn omg_idl_rt::Reflect, omg_idl_rt::ReflectError> {
+            omg_idl_rt::Reflect::field(&self.0, segment)
+        }
+
+        fn field_mut(&mut self, segment: omg_idl_rt::PathSegment) -> Result<&mut dyn omg_idl_rt::Reflect, omg_idl_rt::ReflectError> {
+            omg_idl_rt::Reflect::field_mut(&mut self.0, segment)
+        }
+
+        fn set_member(&mut self, segment: omg_idl_rt::PathSegment, value: omg_idl_rt::Value) -> Result<(), omg_idl_rt::ReflectError> {
+            omg_idl_rt::Reflect::set_member(&mut self.0, segment, value)
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Limits {
+        #[allow(non_snake_case)]
+        pub code: String,
+        #[allow(non_snake_case)]
+        pub pair: Vec<i32>,
+        #[allow(non_snake_case)]
+        pub level: i32,
+        #[allow(non_snake_case)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub percent: Option<u16>,
+        #[allow(non_snake_case)]
+        pub label: Label,
+        #[allow(non_snake_case)]
+        pub grid: [[i32;3_usize];2_usize],
+    }
+
+    #[allow(dead_code)]
+    impl Limits {
+
+        pub fn new(code: String, pair: Vec<i32>, level: i32, label: Label, grid: [[i32;3_usize];2_usize], ) -> Self {
+            Self {
+                code,
+                pair,
+                level,
+                percent: None,
+                label,
+                grid,
+            }
+        }
+
+        pub fn code(&self) -> &String {
+            &self.code
+        }
+
+        pub fn set_code(&mut self, value: String) {
+            self.code = value;
+        }
+
+        pub fn pair(&self) -> &Vec<i32> {
+            &self.pair
+        }
+
+        pub fn set_pair(&mut self, value: Vec<i32>) {
+            self.pair = value;
+        }
+
+        pub fn level(&self) -> &i32 {
+            &self.level
+        }
+
+        pub fn set_level(&mut self, value: i32) -> Result<(), omg_idl_rt::ValidationError> {
+            Self::validate_level(&value)?;
+            self.level = value;
+            Ok(())
+        }
+
+        fn validate_level(value: &i32) -> Result<(), omg_idl_rt::ValidationError> {
+            if *value < 1 {
+                return Err(omg_idl_rt::ValidationError::new("level", omg_idl_rt::Bound::Min, "1"));
+            }
+            if *value > 10 {
+                return Err(omg_idl_rt::ValidationError::new("level", omg_idl_rt::Bound::Max, "10"));
+            }
+            Ok(())
+        }
+
+        pub fn percent(&self) -> Option<&u16> {
+            self.percent.as_ref()
+        }
+
+        pub fn set_percent(&mut self, value: Option<u16>) -> Result<(), omg_idl_rt::ValidationError> {
+            if let Some(value) = &value {
+                Self::validate_percent(value)?;
+            }
+            self.percent = value;
+            Ok(())
+        }
+
+        fn validate_percent(value: &u16) -> Result<(), omg_idl_rt::ValidationError> {
+            if *value > 100 {
+                return Err(omg_idl_rt::ValidationError::new("percent", omg_idl_rt::Bound::Max, "100"));
+            }
+            Ok(())
+        }
+
+        pub fn label(&self) -> &Label {
+            &self.label
+        }
+
+        pub fn set_label(&mut self, value: Label) {
+            self.label = value;
+        }
+
+        pub fn grid(&self) -> &[[i32;3_usize];2_usize] {
+            &self.grid
+        }
+
+        pub fn set_grid(&mut self, value: [[i32;3_usize];2_usize]) {
+            self.grid = value;
+        }
+
+        /// Check all members against their `@range`, `@min` and `@max` bounds
+        pub fn validate(&self) -> Result<(), omg_idl_rt::ValidationError> {
+            Self::validate_level(&self.level)?;
+            if let Some(value) = &self.percent {
+                Self::validate_percent(value)?;
+            }
+            Ok(())
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Limits {
+        fn default() -> Self {
+            Self {
+                code: String::new(),
+                pair: Vec::new(),
+                level: 1,
+                percent: None,
+                label: Default::default(),
+                grid: std::array::from_fn(|_| std::array::from_fn(|_| 0)),
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Limits {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Limits {
+        const TYPE_NAME: &'static str = "DDS::Limits";
+        const REPOSITORY_ID: &'static str = "IDL:DDS/Limits:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Limits {
+        /// Member ID of `code`
+        pub const CODE_MEMBER_ID: u32 = 0;
+        /// Member ID of `pair`
+        pub const PAIR_MEMBER_ID: u32 = 1;
+        /// Member ID of `level`
+        pub const LEVEL_MEMBER_ID: u32 = 2;
+        /// Member ID of `percent`
+        pub const PERCENT_MEMBER_ID: u32 = 3;
+        /// Member ID of `label`
+        pub const LABEL_MEMBER_ID: u32 = 4;
+        /// Member ID of `grid`
+        pub const GRID_MEMBER_ID: u32 = 5;
+    }
+
+    impl omg_cdr::CdrEncode for Limits {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Limits {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Limits {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.code, writer);
+            omg_cdr::CdrEncode::encode(&self.pair, writer);
+            omg_cdr::CdrEncode::encode(&self.level, writer);
+            writer.write_optional(Self::PERCENT_MEMBER_ID, &self.percent, omg_cdr::CdrEncode::encode);
+            omg_cdr::CdrEncode::encode(&self.label, writer);
+            omg_cdr::CdrEncode::encode(&self.grid, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                code: omg_cdr::CdrDecode::decode(reader)?,
+                pair: omg_cdr::CdrDecode::decode(reader)?,
+                level: omg_cdr::CdrDecode::decode(reader)?,
+                percent: reader.read_optional(Self::PERCENT_MEMBER_ID, omg_cdr::CdrDecode::decode)?,
+                label: omg_cdr::CdrDecode::decode(reader)?,
+                grid: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Limits {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(76);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(76);
+    }
+
+    impl omg_idl_rt::Reflect for Limits {
+        fn type_name(&self) -> Option<&'static str> {
+            Some(<Self as omg_idl_rt::TypeName>::TYPE_NAME)
+        }
+
+        fn members(&self) -> &'static [omg_idl_rt::MemberDescriptor] {
+            &[
+                omg_idl_rt::MemberDescriptor { name: "code", id: 0, type_descriptor: omg_idl_rt::TypeDescriptor::String(Some(5)), key: false, optional: false },
+                omg_idl_rt::MemberDescriptor { name: "pair", id: 1, type_descriptor: omg_idl_rt::TypeDescriptor::Sequence(&omg_idl_rt::TypeDescriptor::Long, Some(2)), key: false, optional: false },
+                omg_idl_rt::MemberDescriptor { name: "level", id: 2, type_descriptor: omg_idl_rt::TypeDescriptor::Long, key: false, optional: false },
+                omg_idl_rt::MemberDescriptor { name: "percent", id: 3, type_descriptor: omg_idl_rt::TypeDescriptor::UShort, key: false, optional: true },
+                omg_idl_rt::MemberDescriptor { name: "label", id: 4, type_descriptor: omg_idl_rt::TypeDescriptor::Named("DDS::Label"), key: false, optional: false },
+                omg_idl_rt::MemberDescriptor { name: "grid", id: 5, type_descriptor: omg_idl_rt::TypeDescriptor::Array(&omg_idl_rt::TypeDescriptor::Long, &[2, 3]), key: false, optional: false },
+            ]
+        }
+
+        fn to_value(&self) -> omg_idl_rt::Value {
+            omg_idl_rt::struct_value(None, [("code", &self.code), ("pair", &self.pair), ("level", &self.level), ("percent", &self.percent), ("label", &self.label), ("grid", &self.grid)])
+        }
+
+        fn set_value(&mut self, value: omg_idl_rt::Value) -> Result<(), omg_idl_rt::ReflectError> {
+            omg_idl_rt::set_struct_value(self, value)
+        }
+
+        fn from_value(value: omg_idl_rt::Value) -> Result<Self, omg_idl_rt::ReflectError> {
+            let mut result = Self::default();
+            omg_idl_rt::Reflect::set_value(&mut result, value)?;
+            Ok(result)
+        }
+
+        fn field(&self, segment: omg_idl_rt::PathSegment) -> Result<&dyn omg_idl_rt::Reflect, omg_idl_rt::ReflectError> {
+            match segment {
+                omg_idl_rt::PathSegment::Member("code") => Ok(&self.code),
+                omg_idl_rt::PathSegment::Member("pair") => Ok(&self.pair),
+                omg_idl_rt::PathSegment::Member("level") => Ok(&self.level),
+                omg_idl_rt::PathSegment::Member("percent") => Ok(&self.percent),
+                omg_idl_rt::PathSegment::Member("label") => Ok(&self.label),
+                omg_idl_rt::PathSegment::Member("grid") => Ok(&self.grid),
+                segment => Err(omg_idl_rt::segment_error(segment)),
+            }
+        }
+
+        fn field_mut(&mut self, segment: omg_idl_rt::PathSegment) -> Result<&mut dyn omg_idl_rt::Reflect, omg_idl_rt::ReflectError> {
+            match segment {
+                omg_idl_rt::PathSegment::Member("code") => Ok(&mut self.code),
+                omg_idl_rt::PathSegment::Member("pair") => Ok(&mut self.pair),
+                omg_idl_rt::PathSegment::Member("level") => Ok(&mut self.level),
+                omg_idl_rt::PathSegment::Member("percent") => Ok(&mut self.percent),
+                omg_idl_rt::PathSegment::Member("label") => Ok(&mut self.label),
+                omg_idl_rt::PathSegment::Member("grid") => Ok(&mut self.grid),
+                segment => Err(omg_idl_rt::segment_error(segment)),
+            }
+        }
+
+        fn set_member(&mut self, segment: omg_idl_rt::PathSegment, value: omg_idl_rt::Value) -> Result<(), omg_idl_rt::ReflectError> {
+            match segment {
+                omg_idl_rt::PathSegment::Member("code") => {
+                    omg_idl_rt::check_length(&value, 5)?;
+                    omg_idl_rt::Reflect::set_value(&mut self.code, value)
+                }
+                omg_idl_rt::PathSegment::Member("pair") => {
+                    omg_idl_rt::check_length(&value, 2)?;
+                    omg_idl_rt::Reflect::set_value(&mut self.pair, value)
+                }
+                omg_idl_rt::PathSegment::Member("level") => {
+                    let value: i32 = omg_idl_rt::Reflect::from_value(value)?;
+                    Self::validate_level(&value)?;
+                    self.level = value;
+                    Ok(())
+                }
+                omg_idl_rt::PathSegment::Member("percent") => {
+                    let value: Option<u16> = omg_idl_rt::Reflect::from_value(value)?;
+                    if let Some(value) = &value {
+                        Self::validate_percent(value)?;
+                    }
+                    self.percent = value;
+                    Ok(())
+                }
+                omg_idl_rt::PathSegment::Member("label") => omg_idl_rt::Reflect::set_value(&mut self.label, value),
+                omg_idl_rt::PathSegment::Member("grid") => omg_idl_rt::Reflect::set_value(&mut self.grid, value),
+                segment => Err(omg_idl_rt::segment_error(segment)),
+            }
+        }
+    }
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const KEY_LENGTH: i32 = 4;
+
+}
@@ -0,0 +1,512 @@
+use serde_derive::{Serialize, Deserialize};
+
+#[allow(dead_code, non_camel_case_types)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Point {
+    #[allow(non_snake_case)]
+    pub x: i32,
+}
+
+#[allow(dead_code)]
+impl Point {
+
+    pub fn new(x: i32, ) -> Self {
+        Self {
+            x,
+        }
+    }
+
+    pub fn x(&self) -> &i32 {
+        &self.x
+    }
+
+    pub fn set_x(&mut self, value: i32) {
+        self.x = value;
+    }
+
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for Point {
+    fn default() -> Self {
+        Self {
+            x: 0,
+        }
+    }
+}
+
+impl omg_idl_rt::Extensible for Point {
+    const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+}
+
+impl omg_idl_rt::TypeName for Point {
+    const TYPE_NAME: &'static str = "Point";
+    const REPOSITORY_ID: &'static str = "DCE:700dc518-0110-11ce-ac8f-0800090b5d3e:1";
+}
+
+#[allow(dead_code)]
+impl Point {
+    /// Member ID of `x`
+    pub const X_MEMBER_ID: u32 = 0;
+}
+
+impl omg_cdr::CdrEncode for Point {
+    fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+        writer.write_struct(self);
+    }
+}
+
+impl omg_cdr::CdrDecode for Point {
+    fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+        reader.read_struct()
+    }
+}
+
+impl omg_cdr::CdrStruct for Point {
+    fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+        omg_cdr::CdrEncode::encode(&self.x, writer);
+    }
+
+    fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+        Ok(Self {
+            x: omg_cdr::CdrDecode::decode(reader)?,
+        })
+    }
+}
+
+impl omg_cdr::CdrBounds for Point {
+    const IS_BOUNDED: bool = true;
+    const MAX_XCDR1_SIZE: Option<usize> = Some(4);
+    const MAX_XCDR2_SIZE: Option<usize> = Some(8);
+}
+
+#[allow(non_snake_case)]
+pub mod DDS {
+    use serde_derive::{Serialize, Deserialize};
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Duration_t {
+        #[allow(non_snake_case)]
+        pub sec: i32,
+        #[allow(non_snake_case)]
+        pub nanosec: u32,
+    }
+
+    #[allow(dead_code)]
+    impl Duration_t {
+
+        pub fn new(sec: i32, nanosec: u32, ) -> Self {
+            Self {
+                sec,
+                nanosec,
+            }
+        }
+
+        pub fn sec(&self) -> &i32 {
+            &self.sec
+        }
+
+        pub fn set_sec(&mut self, value: i32) {
+            self.sec = value;
+        }
+
+        pub fn nanosec(&self) -> &u32 {
+            &self.nanosec
+        }
+
+        pub fn set_nanosec(&mut self, value: u32) {
+            self.nanosec = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Duration_t {
+        fn default() -> Self {
+            Self {
+                sec: 0,
+                nanosec: 0,
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Duration_t {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Duration_t {
+        const TYPE_NAME: &'static str = "DDS::Duration_t";
+        const REPOSITORY_ID: &'static str = "IDL:omg.org/DDS/Duration_t:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Duration_t {
+        /// Member ID of `sec`
+        pub const SEC_MEMBER_ID: u32 = 0;
+        /// Member ID of `nanosec`
+        pub const NANOSEC_MEMBER_ID: u32 = 1;
+    }
+
+    impl omg_cdr::CdrEncode for Duration_t {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Duration_t {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Duration_t {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.sec, writer);
+            omg_cdr::CdrEncode::encode(&self.nanosec, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                sec: omg_cdr::CdrDecode::decode(reader)?,
+                nanosec: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Duration_t {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(8);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(12);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, PartialOrd, Eq, Ord)]
+    pub enum Kind {
+        #[default]
+        AUTOMATIC,
+        MANUAL,
+    }
+
+    #[allow(non_camel_case_types)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct KindError;
+
+    impl std::str::FromStr for Kind {
+        type Err = KindError;
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            match value {
+                "AUTOMATIC" => Ok(Kind::AUTOMATIC),
+                "MANUAL" => Ok(Kind::MANUAL),
+                _ => Err(KindError),
+            }
+        }
+    }
+
+    impl std::fmt::Display for Kind {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let enum_str = match self {
+                    Kind::AUTOMATIC => "AUTOMATIC",
+                    Kind::MANUAL => "MANUAL",
+            };
+            write!(f, "{enum_str}")
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Kind {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Kind {
+        const TYPE_NAME: &'static str = "DDS::Kind";
+        const REPOSITORY_ID: &'static str = "IDL:omg.org/DDS/Kind:2.1";
+    }
+
+    impl omg_cdr::CdrEncode for Kind {
+        const PRIMITIVE: bool = true;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let value: u32 = match self {
+                Kind::AUTOMATIC => 0,
+                Kind::MANUAL => 1,
+            };
+            omg_cdr::CdrEncode::encode(&value, writer);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Kind {
+        const PRIMITIVE: bool = true;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            match <u32 as omg_cdr::CdrDecode>::decode(reader)? {
+                0 => Ok(Kind::AUTOMATIC),
+                1 => Ok(Kind::MANUAL),
+                value => Err(omg_cdr::CdrError::InvalidEnum(value)),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Kind {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(4);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(4);
+    }
+
+    #[allow(non_snake_case)]
+    pub mod Core {
+        use serde_derive::{Serialize, Deserialize};
+
+        #[allow(dead_code, non_camel_case_types)]
+        #[derive(Serialize, Deserialize, Clone, Debug)]
+        pub struct Sample {
+            #[allow(non_snake_case)]
+            pub id: i32,
+        }
+
+        #[allow(dead_code)]
+        impl Sample {
+
+            pub fn new(id: i32, ) -> Self {
+                Self {
+                    id,
+                }
+            }
+
+            pub fn id(&self) -> &i32 {
+                &self.id
+            }
+
+            pub fn set_id(&mut self, value: i32) {
+                self.id = value;
+            }
+
+        }
+
+        #[allow(clippy::derivable_impls)]
+        impl Default for Sample {
+            fn default() -> Self {
+                Self {
+                    id: 0,
+                }
+            }
+        }
+
+        impl omg_idl_rt::Extensible for Sample {
+            const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+        }
+
+        impl omg_idl_rt::TypeName for Sample {
+            const TYPE_NAME: &'static str = "DDS::Core::Sample";
+            const REPOSITORY_ID: &'static str = "IDL:example.com/Sample:1.0";
+        }
+
+        #[allow(dead_code)]
+        impl Sample {
+            /// Member ID of `id`
+            pub const ID_MEMBER_ID: u32 = 0;
+        }
+
+        impl omg_cdr::CdrEncode for Sample {
+            fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+                writer.write_struct(self);
+            }
+        }
+
+        impl omg_cdr::CdrDecode for Sample {
+            fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+                reader.read_struct()
+            }
+        }
+
+        impl omg_cdr::CdrStruct for Sample {
+            fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+                omg_cdr::CdrEncode::encode(&self.id, writer);
+            }
+
+            fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+                Ok(Self {
+                    id: omg_cdr::CdrDecode::decode(reader)?,
+                })
+            }
+        }
+
+        impl omg_cdr::CdrBounds for Sample {
+            const IS_BOUNDED: bool = true;
+            const MAX_XCDR1_SIZE: Option<usize> = Some(4);
+            const MAX_XCDR2_SIZE: Option<usize> = Some(8);
+        }
+
+    }
+
+}
+
+#[allow(non_snake_case)]
+pub mod Shapes {
+    use serde_derive::{Serialize, Deserialize};
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Shape {
+        #[allow(non_snake_case)]
+        pub x: i32,
+        #[allow(non_snake_case)]
+        pub y: i32,
+    }
+
+    #[allow(dead_code)]
+    impl Shape {
+
+        pub fn new(x: i32, y: i32, ) -> Self {
+            Self {
+                x,
+                y,
+            }
+        }
+
+        pub fn x(&self) -> &i32 {
+            &self.x
+        }
+
+        pub fn set_x(&mut self, value: i32) {
+            self.x = value;
+        }
+
+        pub fn y(&self) -> &i32 {
+            &self.y
+        }
+
+        pub fn set_y(&mut self, value: i32) {
+            self.y = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Shape {
+        fn default() -> Self {
+            Self {
+                x: 0,
+                y: 0,
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Shape {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Shape {
+        const TYPE_NAME: &'static str = "Shapes::Shape";
+        const REPOSITORY_ID: &'static str = "IDL:shapes.org/Shapes/Shape:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Shape {
+        /// Member ID of `x`
+        pub const X_MEMBER_ID: u32 = 0;
+        /// Member ID of `y`
+        pub const Y_MEMBER_ID: u32 = 1;
+    }
+
+    impl omg_cdr::CdrEncode for Shape {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Shape {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Shape {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.x, writer);
+            omg_cdr::CdrEncode::encode(&self.y, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                x: omg_cdr::CdrDecode::decode(reader)?,
+                y: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Shape {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(8);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(12);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub enum Choice {
+        SQUARE{ side: i32, },
+        CIRCLE{ radius: f64, },
+    }
+
+    impl Default for Choice {
+        fn default() -> Self {
+            Choice::SQUARE { side: 0 }
+        }
+    }
+//
+// TODO custom de-/serializer
+//
+
+    impl omg_idl_rt::Extensible for Choice {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Choice {
+        const TYPE_NAME: &'static str = "Shapes::Choice";
+        const REPOSITORY_ID: &'static str = "IDL:custom/Choice:3.0";
+    }
+
+    #[allow(dead_code)]
+    impl Choice {
+        /// Member ID of `side`
+        pub const SIDE_MEMBER_ID: u32 = 1;
+        /// Member ID of `radius`
+        pub const RADIUS_MEMBER_ID: u32 = 2;
+    }
+
+    impl omg_cdr::CdrEncode for Choice {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            match self {
+                Choice::SQUARE { side } => writer.write_union(extensibility, &1_i32, Self::SIDE_MEMBER_ID, side, omg_cdr::CdrEncode::encode),
+                Choice::CIRCLE { radius } => writer.write_union(extensibility, &2_i32, Self::RADIUS_MEMBER_ID, radius, omg_cdr::CdrEncode::encode),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Choice {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            reader.read_union(extensibility, |discriminator: i32, reader| {
+                Ok(match discriminator {
+                    1_i32 => Choice::SQUARE { side: reader.read_branch(extensibility, Self::SIDE_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    2_i32 => Choice::CIRCLE { radius: reader.read_branch(extensibility, Self::RADIUS_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    #[allow(unreachable_patterns)]
+                    _ => return Err(omg_cdr::CdrError::InvalidDiscriminator),
+                })
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Choice {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(16);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(16);
+    }
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const SQUARE: i32 = 1;
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const CIRCLE: i32 = 2;
+
+}
@@ -0,0 +1,89 @@
+/* Generated from input.idl, layouts of the #[repr(C)] Rust types */
+#ifndef INPUT_H
+#define INPUT_H
+
+#include <stdbool.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+#define IDL_STATIC_ASSERT static_assert
+#else
+#define IDL_STATIC_ASSERT _Static_assert
+#endif
+
+typedef uint8_t RTPS_GuidPrefix_t[12];
+
+typedef uint8_t RTPS_EntityKey_t[3];
+
+typedef struct RTPS_EntityId_t {
+    RTPS_EntityKey_t entityKey;
+    uint8_t entityKind;
+} RTPS_EntityId_t;
+IDL_STATIC_ASSERT(sizeof(RTPS_EntityId_t) == 4, "layout of RTPS::EntityId_t");
+
+typedef struct RTPS_GUID_t {
+    RTPS_GuidPrefix_t guidPrefix;
+    RTPS_EntityId_t entityId;
+} RTPS_GUID_t;
+IDL_STATIC_ASSERT(sizeof(RTPS_GUID_t) == 16, "layout of RTPS::GUID_t");
+
+typedef struct RTPS_Time_t {
+    int32_t seconds;
+    uint32_t fraction;
+} RTPS_Time_t;
+IDL_STATIC_ASSERT(sizeof(RTPS_Time_t) == 8, "layout of RTPS::Time_t");
+
+typedef RTPS_Time_t RTPS_Duration_t;
+
+typedef uint8_t RTPS_ChangeKind;
+enum {
+    RTPS_ChangeKind_ALIVE = 0,
+    RTPS_ChangeKind_NOT_ALIVE_DISPOSED = 4,
+    RTPS_ChangeKind_NOT_ALIVE_UNREGISTERED = 5
+};
+
+typedef int32_t RTPS_Grid[2][3];
+
+typedef struct RTPS_Sample {
+    RTPS_GUID_t writer;
+    RTPS_Duration_t lease;
+    RTPS_Grid grid;
+} RTPS_Sample;
+IDL_STATIC_ASSERT(sizeof(RTPS_Sample) == 48, "layout of RTPS::Sample");
+
+typedef struct RTPS_Change {
+    RTPS_ChangeKind kind;
+    bool valid;
+    uint8_t reserved[2];
+    int32_t count;
+} RTPS_Change;
+IDL_STATIC_ASSERT(sizeof(RTPS_Change) == 8, "layout of RTPS::Change");
+
+/* RTPS::Padded: Not plain old data: 7 bytes of padding before member `value` */
+typedef struct RTPS_Padded {
+    uint8_t flag;
+    int64_t value;
+} RTPS_Padded;
+IDL_STATIC_ASSERT(sizeof(RTPS_Padded) == 16, "layout of RTPS::Padded");
+
+typedef struct RTPS_Derived {
+    RTPS_Time_t base;
+    uint64_t sequence;
+} RTPS_Derived;
+IDL_STATIC_ASSERT(sizeof(RTPS_Derived) == 16, "layout of RTPS::Derived");
+
+/* RTPS::Flags: Not plain old data: member `bits` is an array of `bool`, bytemuck cannot check arrays */
+typedef struct RTPS_Flags {
+    bool bits[4];
+} RTPS_Flags;
+IDL_STATIC_ASSERT(sizeof(RTPS_Flags) == 4, "layout of RTPS::Flags");
+
+/* RTPS::Named has no C layout: member `name` is a string */
+
+/* RTPS::Holder has no C layout: member `named` is the type `RTPS::Named`, which is not fixed size */
+
+/* RTPS::Maybe has no C layout: member `value` is optional */
+
+/* RTPS::Choice has no C layout: a union, which maps to a Rust enum with data */
+
+#endif /* INPUT_H */
@@ -0,0 +1,1445 @@
+
+#[allow(non_snake_case)]
+pub mod RTPS {
+    use serde_derive::{Serialize, Deserialize};
+
+    #[allow(dead_code, non_camel_case_types)]
+    pub type GuidPrefix_t = [u8;12_usize];
+
+    #[allow(dead_code, non_camel_case_types)]
+    pub type EntityKey_t = [u8;3_usize];
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+    #[repr(C)]
+    pub struct EntityId_t {
+        #[allow(non_snake_case)]
+        #[serde(with = "serde_arrays")]
+        pub entityKey: EntityKey_t,
+        #[allow(non_snake_case)]
+        pub entityKind: u8,
+    }
+
+    #[allow(dead_code)]
+    impl EntityId_t {
+
+        pub fn new(entityKey: EntityKey_t, entityKind: u8, ) -> Self {
+            Self {
+                entityKey,
+                entityKind,
+            }
+        }
+
+        pub fn entityKey(&self) -> &EntityKey_t {
+            &self.entityKey
+        }
+
+        pub fn set_entityKey(&mut self, value: EntityKey_t) {
+            self.entityKey = value;
+        }
+
+        pub fn entityKind(&self) -> &u8 {
+            &self.entityKind
+        }
+
+        pub fn set_entityKind(&mut self, value: u8) {
+            self.entityKind = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for EntityId_t {
+        fn default() -> Self {
+            Self {
+                entityKey: std::array::from_fn(|_| 0),
+                entityKind: 0,
+            }
+        }
+    }
+
+    impl omg_idl_rt::KeyHashEncode for EntityId_t {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<EntityKey_t>().field::<u8>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.entityKey, writer);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.entityKind, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.entityKey, &other.entityKey)
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.entityKind, &other.entityKind))
+        }
+    }
+
+    impl omg_idl_rt::Extensible for EntityId_t {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for EntityId_t {
+        const TYPE_NAME: &'static str = "RTPS::EntityId_t";
+        const REPOSITORY_ID: &'static str = "IDL:RTPS/EntityId_t:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl EntityId_t {
+        /// Member ID of `entityKey`
+        pub const ENTITY_KEY_MEMBER_ID: u32 = 0;
+        /// Member ID of `entityKind`
+        pub const ENTITY_KIND_MEMBER_ID: u32 = 1;
+    }
+
+    impl omg_cdr::CdrEncode for EntityId_t {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for EntityId_t {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for EntityId_t {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.entityKey, writer);
+            omg_cdr::CdrEncode::encode(&self.entityKind, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                entityKey: omg_cdr::CdrDecode::decode(reader)?,
+                entityKind: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for EntityId_t {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(4);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(8);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+    #[repr(C)]
+    pub struct GUID_t {
+        #[allow(non_snake_case)]
+        #[serde(with = "serde_arrays")]
+        pub guidPrefix: GuidPrefix_t,
+        #[allow(non_snake_case)]
+        pub entityId: EntityId_t,
+    }
+
+    #[allow(dead_code)]
+    impl GUID_t {
+
+        pub fn new(guidPrefix: GuidPrefix_t, entityId: EntityId_t, ) -> Self {
+            Self {
+                guidPrefix,
+                entityId,
+            }
+        }
+
+        pub fn guidPrefix(&self) -> &GuidPrefix_t {
+            &self.guidPrefix
+        }
+
+        pub fn set_guidPrefix(&mut self, value: GuidPrefix_t) {
+            self.guidPrefix = value;
+        }
+
+        pub fn entityId(&self) -> &EntityId_t {
+            &self.entityId
+        }
+
+        pub fn set_entityId(&mut self, value: EntityId_t) {
+            self.entityId = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for GUID_t {
+        fn default() -> Self {
+            Self {
+                guidPrefix: std::array::from_fn(|_| 0),
+                entityId: Default::default(),
+            }
+        }
+    }
+
+    impl omg_idl_rt::KeyHashEncode for GUID_t {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<GuidPrefix_t>().field::<EntityId_t>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.guidPrefix, writer);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.entityId, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.guidPrefix, &other.guidPrefix)
+                .then_with(|| omg_idl_rt::KeyHashEncode::key_cmp(&self.entityId, &other.entityId))
+        }
+    }
+
+    impl omg_idl_rt::Extensible for GUID_t {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for GUID_t {
+        const TYPE_NAME: &'static str = "RTPS::GUID_t";
+        const REPOSITORY_ID: &'static str = "IDL:RTPS/GUID_t:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl GUID_t {
+        /// Member ID of `guidPrefix`
+        pub const GUID_PREFIX_MEMBER_ID: u32 = 0;
+        /// Member ID of `entityId`
+        pub const ENTITY_ID_MEMBER_ID: u32 = 1;
+    }
+
+    impl omg_cdr::CdrEncode for GUID_t {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for GUID_t {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for GUID_t {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.guidPrefix, writer);
+            omg_cdr::CdrEncode::encode(&self.entityId, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                guidPrefix: omg_cdr::CdrDecode::decode(reader)?,
+                entityId: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for GUID_t {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(16);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(24);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+    #[repr(C)]
+    pub struct Time_t {
+        #[allow(non_snake_case)]
+        pub seconds: i32,
+        #[allow(non_snake_case)]
+        pub fraction: u32,
+    }
+
+    #[allow(dead_code)]
+    impl Time_t {
+
+        pub fn new(seconds: i32, fraction: u32, ) -> Self {
+            Self {
+                seconds,
+                fraction,
+            }
+        }
+
+        pub fn seconds(&self) -> &i32 {
+            &self.seconds
+        }
+
+        pub fn set_seconds(&mut self, value: i32) {
+            self.seconds = value;
+        }
+
+        pub fn fraction(&self) -> &u32 {
+            &self.fraction
+        }
+
+        pub fn set_fraction(&mut self, value: u32) {
+            self.fraction = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Time_t {
+        fn default() -> Self {
+            Self {
+                seconds: 0,
+                fraction: 0,
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Time_t {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Time_t {
+        const TYPE_NAME: &'static str = "RTPS::Time_t";
+        const REPOSITORY_ID: &'static str = "IDL:RTPS/Time_t:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Time_t {
+        /// Member ID of `seconds`
+        pub const SECONDS_MEMBER_ID: u32 = 0;
+        /// Member ID of `fraction`
+        pub const FRACTION_MEMBER_ID: u32 = 1;
+    }
+
+    impl omg_cdr::CdrEncode for Time_t {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Time_t {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Time_t {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.seconds, writer);
+            omg_cdr::CdrEncode::encode(&self.fraction, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                seconds: omg_cdr::CdrDecode::decode(reader)?,
+                fraction: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Time_t {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(8);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(12);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    pub type Duration_t = Time_t;
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, PartialOrd, Eq, Ord, Copy, bytemuck::NoUninit, bytemuck::CheckedBitPattern)]
+    #[repr(u8)]
+    pub enum ChangeKind {
+        #[default]
+        ALIVE = 0,
+        NOT_ALIVE_DISPOSED = 4,
+        NOT_ALIVE_UNREGISTERED = 5,
+    }
+
+    #[allow(non_camel_case_types)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct ChangeKindError;
+
+    impl std::str::FromStr for ChangeKind {
+        type Err = ChangeKindError;
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            match value {
+                "ALIVE" => Ok(ChangeKind::ALIVE),
+                "NOT_ALIVE_DISPOSED" => Ok(ChangeKind::NOT_ALIVE_DISPOSED),
+                "NOT_ALIVE_UNREGISTERED" => Ok(ChangeKind::NOT_ALIVE_UNREGISTERED),
+                _ => Err(ChangeKindError),
+            }
+        }
+    }
+
+    impl std::fmt::Display for ChangeKind {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let enum_str = match self {
+                    ChangeKind::ALIVE => "ALIVE",
+                    ChangeKind::NOT_ALIVE_DISPOSED => "NOT_ALIVE_DISPOSED",
+                    ChangeKind::NOT_ALIVE_UNREGISTERED => "NOT_ALIVE_UNREGISTERED",
+            };
+            write!(f, "{enum_str}")
+        }
+    }
+
+    impl omg_idl_rt::KeyHashEncode for ChangeKind {
+        const KEY_SIZE: omg_idl_rt::KeySize = <u8 as omg_idl_rt::KeyHashEncode>::KEY_SIZE;
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&(*self as u8), writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(
+                &(*self as u8),
+                &(*other as u8),
+            )
+        }
+    }
+
+    impl omg_idl_rt::Extensible for ChangeKind {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for ChangeKind {
+        const TYPE_NAME: &'static str = "RTPS::ChangeKind";
+        const REPOSITORY_ID: &'static str = "IDL:RTPS/ChangeKind:1.0";
+    }
+
+    impl omg_cdr::CdrEncode for ChangeKind {
+        const PRIMITIVE: bool = true;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let value: u8 = match self {
+                ChangeKind::ALIVE => 0,
+                ChangeKind::NOT_ALIVE_DISPOSED => 4,
+                ChangeKind::NOT_ALIVE_UNREGISTERED => 5,
+            };
+            omg_cdr::CdrEncode::encode(&value, writer);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for ChangeKind {
+        const PRIMITIVE: bool = true;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            match <u8 as omg_cdr::CdrDecode>::decode(reader)? {
+                0 => Ok(ChangeKind::ALIVE),
+                4 => Ok(ChangeKind::NOT_ALIVE_DISPOSED),
+                5 => Ok(ChangeKind::NOT_ALIVE_UNREGISTERED),
+                value => Err(omg_cdr::CdrError::InvalidEnum(value.into())),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrBounds for ChangeKind {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(1);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(1);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    pub type Grid = [[i32;3_usize];2_usize];
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+    #[repr(C)]
+    pub struct Sample {
+        #[allow(non_snake_case)]
+        pub writer: GUID_t,
+        #[allow(non_snake_case)]
+        pub lease: Duration_t,
+        #[allow(non_snake_case)]
+        pub grid: Grid,
+    }
+
+    #[allow(dead_code)]
+    impl Sample {
+
+        pub fn new(writer: GUID_t, lease: Duration_t, grid: Grid, ) -> Self {
+            Self {
+                writer,
+                lease,
+                grid,
+            }
+        }
+
+        pub fn writer(&self) -> &GUID_t {
+            &self.writer
+        }
+
+        pub fn set_writer(&mut self, value: GUID_t) {
+            self.writer = value;
+        }
+
+        pub fn lease(&self) -> &Duration_t {
+            &self.lease
+        }
+
+        pub fn set_lease(&mut self, value: Duration_t) {
+            self.lease = value;
+        }
+
+        pub fn grid(&self) -> &Grid {
+            &self.grid
+        }
+
+        pub fn set_grid(&mut self, value: Grid) {
+            self.grid = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Sample {
+        fn default() -> Self {
+            Self {
+                writer: Default::default(),
+                lease: Default::default(),
+                grid: std::array::from_fn(|_| std::array::from_fn(|_| 0)),
+            }
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug)]
+    pub struct SampleKey {
+        pub writer: GUID_t,
+    }
+
+    impl omg_idl_rt::KeyHashEncode for SampleKey {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<GUID_t>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.writer, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.writer, &other.writer)
+        }
+    }
+
+    impl PartialEq for SampleKey {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other).is_eq()
+        }
+    }
+
+    impl Eq for SampleKey {}
+
+    impl PartialOrd for SampleKey {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for SampleKey {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other)
+        }
+    }
+
+    impl std::hash::Hash for SampleKey {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            omg_idl_rt::hash_key(self, state);
+        }
+    }
+
+    impl omg_idl_rt::Keyed for Sample {
+        type Key = SampleKey;
+
+        fn key(&self) -> Self::Key {
+            SampleKey {
+                writer: self.writer,
+            }
+        }
+    }
+
+    /// Equal if the key members are equal, other members are ignored
+    impl PartialEq for Sample {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.writer, &other.writer)
+                .is_eq()
+        }
+    }
+
+    impl Eq for Sample {}
+
+    impl PartialOrd for Sample {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    /// Orders by the key members, other members are ignored
+    impl Ord for Sample {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.writer, &other.writer)
+        }
+    }
+
+    /// Hashes the key members only, consistent with `PartialEq`
+    impl std::hash::Hash for Sample {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            let mut writer = omg_idl_rt::KeyHolderWriter::hashing(state);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.writer, &mut writer);
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Sample {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Sample {
+        const TYPE_NAME: &'static str = "RTPS::Sample";
+        const REPOSITORY_ID: &'static str = "IDL:RTPS/Sample:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Sample {
+        /// Member ID of `writer`
+        pub const WRITER_MEMBER_ID: u32 = 0;
+        /// Member ID of `lease`
+        pub const LEASE_MEMBER_ID: u32 = 1;
+        /// Member ID of `grid`
+        pub const GRID_MEMBER_ID: u32 = 2;
+    }
+
+    impl omg_cdr::CdrEncode for Sample {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Sample {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Sample {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.writer, writer);
+            omg_cdr::CdrEncode::encode(&self.lease, writer);
+            omg_cdr::CdrEncode::encode(&self.grid, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                writer: omg_cdr::CdrDecode::decode(reader)?,
+                lease: omg_cdr::CdrDecode::decode(reader)?,
+                grid: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Sample {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(48);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(64);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Copy, bytemuck::NoUninit, bytemuck::CheckedBitPattern)]
+    #[repr(C)]
+    pub struct Change {
+        #[allow(non_snake_case)]
+        pub kind: ChangeKind,
+        #[allow(non_snake_case)]
+        pub valid: bool,
+        #[allow(non_snake_case)]
+        #[serde(with = "serde_arrays")]
+        pub reserved: [u8;2_usize],
+        #[allow(non_snake_case)]
+        pub count: i32,
+    }
+
+    #[allow(dead_code)]
+    impl Change {
+
+        pub fn new(kind: ChangeKind, valid: bool, reserved: [u8;2_usize], count: i32, ) -> Self {
+            Self {
+                kind,
+                valid,
+                reserved,
+                count,
+            }
+        }
+
+        pub fn kind(&self) -> &ChangeKind {
+            &self.kind
+        }
+
+        pub fn set_kind(&mut self, value: ChangeKind) {
+            self.kind = value;
+        }
+
+        pub fn valid(&self) -> &bool {
+            &self.valid
+        }
+
+        pub fn set_valid(&mut self, value: bool) {
+            self.valid = value;
+        }
+
+        pub fn reserved(&self) -> &[u8;2_usize] {
+            &self.reserved
+        }
+
+        pub fn set_reserved(&mut self, value: [u8;2_usize]) {
+            self.reserved = value;
+        }
+
+        pub fn count(&self) -> &i32 {
+            &self.count
+        }
+
+        pub fn set_count(&mut self, value: i32) {
+            self.count = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Change {
+        fn default() -> Self {
+            Self {
+                kind: Default::default(),
+                valid: false,
+                reserved: std::array::from_fn(|_| 0),
+                count: 0,
+            }
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug)]
+    pub struct ChangeKey {
+        pub kind: ChangeKind,
+    }
+
+    impl omg_idl_rt::KeyHashEncode for ChangeKey {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<ChangeKind>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.kind, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.kind, &other.kind)
+        }
+    }
+
+    impl PartialEq for ChangeKey {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other).is_eq()
+        }
+    }
+
+    impl Eq for ChangeKey {}
+
+    impl PartialOrd for ChangeKey {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for ChangeKey {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other)
+        }
+    }
+
+    impl std::hash::Hash for ChangeKey {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            omg_idl_rt::hash_key(self, state);
+        }
+    }
+
+    impl omg_idl_rt::Keyed for Change {
+        type Key = ChangeKey;
+
+        fn key(&self) -> Self::Key {
+            ChangeKey {
+                kind: self.kind,
+            }
+        }
+    }
+
+    /// Equal if the key members are equal, other members are ignored
+    impl PartialEq for Change {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.kind, &other.kind)
+                .is_eq()
+        }
+    }
+
+    impl Eq for Change {}
+
+    impl PartialOrd for Change {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    /// Orders by the key members, other members are ignored
+    impl Ord for Change {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.kind, &other.kind)
+        }
+    }
+
+    /// Hashes the key members only, consistent with `PartialEq`
+    impl std::hash::Hash for Change {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            let mut writer = omg_idl_rt::KeyHolderWriter::hashing(state);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.kind, &mut writer);
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Change {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Change {
+        const TYPE_NAME: &'static str = "RTPS::Change";
+        const REPOSITORY_ID: &'static str = "IDL:RTPS/Change:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Change {
+        /// Member ID of `kind`
+        pub const KIND_MEMBER_ID: u32 = 0;
+        /// Member ID of `valid`
+        pub const VALID_MEMBER_ID: u32 = 1;
+        /// Member ID of `reserved`
+        pub const RESERVED_MEMBER_ID: u32 = 2;
+        /// Member ID of `count`
+        pub const COUNT_MEMBER_ID: u32 = 3;
+    }
+
+    impl omg_cdr::CdrEncode for Change {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Change {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Change {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.kind, writer);
+            omg_cdr::CdrEncode::encode(&self.valid, writer);
+            omg_cdr::CdrEncode::encode(&self.reserved, writer);
+            omg_cdr::CdrEncode::encode(&self.count, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                kind: omg_cdr::CdrDecode::decode(reader)?,
+                valid: omg_cdr::CdrDecode::decode(reader)?,
+                reserved: omg_cdr::CdrDecode::decode(reader)?,
+                count: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Change {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(8);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(12);
+    }
+
+    // Not plain old data: 7 bytes of padding before member `value`
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Copy)]
+    #[repr(C)]
+    pub struct Padded {
+        #[allow(non_snake_case)]
+        pub flag: u8,
+        #[allow(non_snake_case)]
+        pub value: i64,
+    }
+
+    #[allow(dead_code)]
+    impl Padded {
+
+        pub fn new(flag: u8, value: i64, ) -> Self {
+            Self {
+                flag,
+                value,
+            }
+        }
+
+        pub fn flag(&self) -> &u8 {
+            &self.flag
+        }
+
+        pub fn set_flag(&mut self, value: u8) {
+            self.flag = value;
+        }
+
+        pub fn value(&self) -> &i64 {
+            &self.value
+        }
+
+        pub fn set_value(&mut self, value: i64) {
+            self.value = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Padded {
+        fn default() -> Self {
+            Self {
+                flag: 0,
+                value: 0,
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Padded {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Padded {
+        const TYPE_NAME: &'static str = "RTPS::Padded";
+        const REPOSITORY_ID: &'static str = "IDL:RTPS/Padded:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Padded {
+        /// Member ID of `flag`
+        pub const FLAG_MEMBER_ID: u32 = 0;
+        /// Member ID of `value`
+        pub const VALUE_MEMBER_ID: u32 = 1;
+    }
+
+    impl omg_cdr::CdrEncode for Padded {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Padded {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Padded {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.flag, writer);
+            omg_cdr::CdrEncode::encode(&self.value, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                flag: omg_cdr::CdrDecode::decode(reader)?,
+                value: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Padded {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(16);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(16);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+    #[repr(C)]
+    pub struct Derived {
+        #[serde(flatten)]
+        pub base: Time_t,
+        #[allow(non_snake_case)]
+        pub sequence: u64,
+    }
+
+    #[allow(dead_code)]
+    impl Derived {
+
+        pub fn new(base: Time_t, sequence: u64, ) -> Self {
+            Self {
+                base,
+                sequence,
+            }
+        }
+
+        pub fn base(&self) -> &Time_t {
+            &self.base
+        }
+
+        pub fn set_base(&mut self, value: Time_t) {
+            self.base = value;
+        }
+
+        pub fn sequence(&self) -> &u64 {
+            &self.sequence
+        }
+
+        pub fn set_sequence(&mut self, value: u64) {
+            self.sequence = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Derived {
+        fn default() -> Self {
+            Self {
+                base: Default::default(),
+                sequence: 0,
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Derived {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Derived {
+        const TYPE_NAME: &'static str = "RTPS::Derived";
+        const REPOSITORY_ID: &'static str = "IDL:RTPS/Derived:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Derived {
+        /// Member ID of `sequence`
+        pub const SEQUENCE_MEMBER_ID: u32 = 2;
+    }
+
+    impl omg_cdr::CdrEncode for Derived {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Derived {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Derived {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrStruct::encode_members(&self.base, writer);
+            omg_cdr::CdrEncode::encode(&self.sequence, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                base: omg_cdr::CdrStruct::decode_members(reader)?,
+                sequence: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Derived {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(16);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(20);
+    }
+
+    // Not plain old data: member `bits` is an array of `bool`, bytemuck cannot check arrays
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Copy)]
+    #[repr(C)]
+    pub struct Flags {
+        #[allow(non_snake_case)]
+        #[serde(with = "serde_arrays")]
+        pub bits: [bool;4_usize],
+    }
+
+    #[allow(dead_code)]
+    impl Flags {
+
+        pub fn new(bits: [bool;4_usize], ) -> Self {
+            Self {
+                bits,
+            }
+        }
+
+        pub fn bits(&self) -> &[bool;4_usize] {
+            &self.bits
+        }
+
+        pub fn set_bits(&mut self, value: [bool;4_usize]) {
+            self.bits = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Flags {
+        fn default() -> Self {
+            Self {
+                bits: std::array::from_fn(|_| false),
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Flags {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Flags {
+        const TYPE_NAME: &'static str = "RTPS::Flags";
+        const REPOSITORY_ID: &'static str = "IDL:RTPS/Flags:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Flags {
+        /// Member ID of `bits`
+        pub const BITS_MEMBER_ID: u32 = 0;
+    }
+
+    impl omg_cdr::CdrEncode for Flags {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Flags {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Flags {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.bits, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                bits: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Flags {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(4);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(8);
+    }
+
+    // No C layout: member `name` is a string
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Named {
+        #[allow(non_snake_case)]
+        pub guid: GUID_t,
+        #[allow(non_snake_case)]
+        pub name: String,
+    }
+
+    #[allow(dead_code)]
+    impl Named {
+
+        pub fn new(guid: GUID_t, name: String, ) -> Self {
+            Self {
+                guid,
+                name,
+            }
+        }
+
+        pub fn guid(&self) -> &GUID_t {
+            &self.guid
+        }
+
+        pub fn set_guid(&mut self, value: GUID_t) {
+            self.guid = value;
+        }
+
+        pub fn name(&self) -> &String {
+            &self.name
+        }
+
+        pub fn set_name(&mut self, value: String) {
+            self.name = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Named {
+        fn default() -> Self {
+            Self {
+                guid: Default::default(),
+                name: String::new(),
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Named {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Named {
+        const TYPE_NAME: &'static str = "RTPS::Named";
+        const REPOSITORY_ID: &'static str = "IDL:RTPS/Named:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Named {
+        /// Member ID of `guid`
+        pub const GUID_MEMBER_ID: u32 = 0;
+        /// Member ID of `name`
+        pub const NAME_MEMBER_ID: u32 = 1;
+    }
+
+    impl omg_cdr::CdrEncode for Named {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Named {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Named {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.guid, writer);
+            omg_cdr::CdrEncode::encode(&self.name, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                guid: omg_cdr::CdrDecode::decode(reader)?,
+                name: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Named {
+        const IS_BOUNDED: bool = false;
+        const MAX_XCDR1_SIZE: Option<usize> = None;
+        const MAX_XCDR2_SIZE: Option<usize> = None;
+    }
+
+    // No C layout: member `named` is the type `RTPS::Named`, which is not fixed size
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Holder {
+        #[allow(non_snake_case)]
+        pub named: Named,
+    }
+
+    #[allow(dead_code)]
+    impl Holder {
+
+        pub fn new(named: Named, ) -> Self {
+            Self {
+                named,
+            }
+        }
+
+        pub fn named(&self) -> &Named {
+            &self.named
+        }
+
+        pub fn set_named(&mut self, value: Named) {
+            self.named = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Holder {
+        fn default() -> Self {
+            Self {
+                named: Default::default(),
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Holder {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Holder {
+        const TYPE_NAME: &'static str = "RTPS::Holder";
+        const REPOSITORY_ID: &'static str = "IDL:RTPS/Holder:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Holder {
+        /// Member ID of `named`
+        pub const NAMED_MEMBER_ID: u32 = 0;
+    }
+
+    impl omg_cdr::CdrEncode for Holder {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Holder {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Holder {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.named, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                named: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Holder {
+        const IS_BOUNDED: bool = false;
+        const MAX_XCDR1_SIZE: Option<usize> = None;
+        const MAX_XCDR2_SIZE: Option<usize> = None;
+    }
+
+    // No C layout: member `value` is optional
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Maybe {
+        #[allow(non_snake_case)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub value: Option<i32>,
+    }
+
+    #[allow(dead_code)]
+    impl Maybe {
+
+        pub fn new() -> Self {
+            Self {
+                value: None,
+            }
+        }
+
+        pub fn value(&self) -> Option<&i32> {
+            self.value.as_ref()
+        }
+
+        pub fn set_value(&mut self, value: Option<i32>) {
+            self.value = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Maybe {
+        fn default() -> Self {
+            Self {
+                value: None,
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Maybe {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Maybe {
+        const TYPE_NAME: &'static str = "RTPS::Maybe";
+        const REPOSITORY_ID: &'static str = "IDL:RTPS/Maybe:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Maybe {
+        /// Member ID of `value`
+        pub const VALUE_MEMBER_ID: u32 = 0;
+    }
+
+    impl omg_cdr::CdrEncode for Maybe {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Maybe {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Maybe {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_optional(Self::VALUE_MEMBER_ID, &self.value, omg_cdr::CdrEncode::encode);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                value: reader.read_optional(Self::VALUE_MEMBER_ID, omg_cdr::CdrDecode::decode)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Maybe {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(8);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(12);
+    }
+
+    // No C layout: a union, which maps to a Rust enum with data
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub enum Choice {
+        ALIVE{ number: i32, },
+        NOT_ALIVE_DISPOSED{ real: f64, },
+    }
+
+    impl Default for Choice {
+        fn default() -> Self {
+            Choice::ALIVE { number: 0 }
+        }
+    }
+//
+// TODO custom de-/serializer
+//
+
+    impl omg_idl_rt::Extensible for Choice {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Choice {
+        const TYPE_NAME: &'static str = "RTPS::Choice";
+        const REPOSITORY_ID: &'static str = "IDL:RTPS/Choice:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Choice {
+        /// Member ID of `number`
+        pub const NUMBER_MEMBER_ID: u32 = 1;
+        /// Member ID of `real`
+        pub const REAL_MEMBER_ID: u32 = 2;
+    }
+
+    impl omg_cdr::CdrEncode for Choice {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            match self {
+                Choice::ALIVE { number } => writer.write_union(extensibility, &ChangeKind::ALIVE, Self::NUMBER_MEMBER_ID, number, omg_cdr::CdrEncode::encode),
+                Choice::NOT_ALIVE_DISPOSED { real } => writer.write_union(extensibility, &ChangeKind::NOT_ALIVE_DISPOSED, Self::REAL_MEMBER_ID, real, omg_cdr::CdrEncode::encode),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Choice {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            reader.read_union(extensibility, |discriminator: ChangeKind, reader| {
+                Ok(match discriminator {
+                    ChangeKind::ALIVE => Choice::ALIVE { number: reader.read_branch(extensibility, Self::NUMBER_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    ChangeKind::NOT_ALIVE_DISPOSED => Choice::NOT_ALIVE_DISPOSED { real: reader.read_branch(extensibility, Self::REAL_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    #[allow(unreachable_patterns)]
+                    _ => return Err(omg_cdr::CdrError::InvalidDiscriminator),
+                })
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Choice {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(16);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(16);
+    }
+
+}
@@ -0,0 +1,267 @@
+
+#[allow(non_snake_case)]
+pub mod DDS {
+    use serde_derive::{Serialize, Deserialize};
+
+    #[allow(dead_code)]
+    pub type GuidPrefixT = [u8;MAX_LENGTH as usize];
+
+    #[allow(dead_code)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, PartialOrd, Eq, Ord)]
+    pub enum StatusKind {
+        #[serde(rename = "INCONSISTENT_TOPIC")]
+        #[default]
+        InconsistentTopic,
+        #[serde(rename = "SAMPLE_LOST")]
+        SampleLost,
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct StatusKindError;
+
+    impl std::str::FromStr for StatusKind {
+        type Err = StatusKindError;
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            match value {
+                "INCONSISTENT_TOPIC" => Ok(StatusKind::InconsistentTopic),
+                "SAMPLE_LOST" => Ok(StatusKind::SampleLost),
+                _ => Err(StatusKindError),
+            }
+        }
+    }
+
+    impl std::fmt::Display for StatusKind {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let enum_str = match self {
+                    StatusKind::InconsistentTopic => "INCONSISTENT_TOPIC",
+                    StatusKind::SampleLost => "SAMPLE_LOST",
+            };
+            write!(f, "{enum_str}")
+        }
+    }
+
+    impl omg_idl_rt::Extensible for StatusKind {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for StatusKind {
+        const TYPE_NAME: &'static str = "DDS::StatusKind";
+        const REPOSITORY_ID: &'static str = "IDL:DDS/StatusKind:1.0";
+    }
+
+    impl omg_cdr::CdrEncode for StatusKind {
+        const PRIMITIVE: bool = true;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let value: u32 = match self {
+                StatusKind::InconsistentTopic => 0,
+                StatusKind::SampleLost => 1,
+            };
+            omg_cdr::CdrEncode::encode(&value, writer);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for StatusKind {
+        const PRIMITIVE: bool = true;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            match <u32 as omg_cdr::CdrDecode>::decode(reader)? {
+                0 => Ok(StatusKind::InconsistentTopic),
+                1 => Ok(StatusKind::SampleLost),
+                value => Err(omg_cdr::CdrError::InvalidEnum(value)),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrBounds for StatusKind {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(4);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(4);
+    }
+
+    #[allow(dead_code)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    #[serde(rename = "GUID_t")]
+    pub struct GuidT {
+        #[serde(with = "serde_arrays")]
+        #[serde(rename = "guidPrefix")]
+        pub guid_prefix: GuidPrefixT,
+        #[serde(rename = "entityKind")]
+        pub entity_kind: i32,
+        #[serde(rename = "lastStatus")]
+        pub last_status: StatusKind,
+    }
+
+    #[allow(dead_code)]
+    impl GuidT {
+
+        pub fn new(guid_prefix: GuidPrefixT, entity_kind: i32, last_status: StatusKind, ) -> Self {
+            Self {
+                guid_prefix,
+                entity_kind,
+                last_status,
+            }
+        }
+
+        pub fn guid_prefix(&self) -> &GuidPrefixT {
+            &self.guid_prefix
+        }
+
+        pub fn set_guid_prefix(&mut self, value: GuidPrefixT) {
+            self.guid_prefix = value;
+        }
+
+        pub fn entity_kind(&self) -> &i32 {
+            &self.entity_kind
+        }
+
+        pub fn set_entity_kind(&mut self, value: i32) {
+            self.entity_kind = value;
+        }
+
+        pub fn last_status(&self) -> &StatusKind {
+            &self.last_status
+        }
+
+        pub fn set_last_status(&mut self, value: StatusKind) {
+            self.last_status = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for GuidT {
+        fn default() -> Self {
+            Self {
+                guid_prefix: std::array::from_fn(|_| 0),
+                entity_kind: 0,
+                last_status: Default::default(),
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for GuidT {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for GuidT {
+        const TYPE_NAME: &'static str = "DDS::GUID_t";
+        const REPOSITORY_ID: &'static str = "IDL:DDS/GUID_t:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl GuidT {
+        /// Member ID of `guidPrefix`
+        pub const GUID_PREFIX_MEMBER_ID: u32 = 0;
+        /// Member ID of `entityKind`
+        pub const ENTITY_KIND_MEMBER_ID: u32 = 1;
+        /// Member ID of `lastStatus`
+        pub const LAST_STATUS_MEMBER_ID: u32 = 2;
+    }
+
+    impl omg_cdr::CdrEncode for GuidT {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for GuidT {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for GuidT {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.guid_prefix, writer);
+            omg_cdr::CdrEncode::encode(&self.entity_kind, writer);
+            omg_cdr::CdrEncode::encode(&self.last_status, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                guid_prefix: omg_cdr::CdrDecode::decode(reader)?,
+                entity_kind: omg_cdr::CdrDecode::decode(reader)?,
+                last_status: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for GuidT {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(16);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(20);
+    }
+
+    #[allow(dead_code)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    #[serde(rename = "Payload_t")]
+    pub enum PayloadT {
+        #[serde(rename = "LABEL0")]
+        Label0{ #[serde(rename = "someValue")] some_value: i32, },
+        #[serde(rename = "default")]
+        Default{ #[serde(rename = "rawData")] raw_data: u8, },
+    }
+
+    impl Default for PayloadT {
+        fn default() -> Self {
+            PayloadT::Default { raw_data: 0 }
+        }
+    }
+//
+// TODO custom de-/serializer
+//
+
+    impl omg_idl_rt::Extensible for PayloadT {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for PayloadT {
+        const TYPE_NAME: &'static str = "DDS::Payload_t";
+        const REPOSITORY_ID: &'static str = "IDL:DDS/Payload_t:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl PayloadT {
+        /// Member ID of `someValue`
+        pub const SOME_VALUE_MEMBER_ID: u32 = 1;
+        /// Member ID of `rawData`
+        pub const RAW_DATA_MEMBER_ID: u32 = 2;
+    }
+
+    impl omg_cdr::CdrEncode for PayloadT {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            match self {
+                PayloadT::Label0 { some_value } => writer.write_union(extensibility, &0_i32, Self::SOME_VALUE_MEMBER_ID, some_value, omg_cdr::CdrEncode::encode),
+                PayloadT::Default { raw_data } => writer.write_union(extensibility, &1_i32, Self::RAW_DATA_MEMBER_ID, raw_data, omg_cdr::CdrEncode::encode),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrDecode for PayloadT {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            reader.read_union(extensibility, |discriminator: i32, reader| {
+                Ok(match discriminator {
+                    0_i32 => PayloadT::Label0 { some_value: reader.read_branch(extensibility, Self::SOME_VALUE_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    #[allow(unreachable_patterns)]
+                    _ => PayloadT::Default { raw_data: reader.read_branch(extensibility, Self::RAW_DATA_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                })
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for PayloadT {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(8);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(12);
+    }
+
+    #[allow(dead_code)]
+    pub const MAX_LENGTH: i32 = 8;
+
+    #[allow(dead_code)]
+    pub const LABEL0: i32 = 0;
+
+}
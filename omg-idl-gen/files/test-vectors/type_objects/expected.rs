@@ -0,0 +1,895 @@
+
+#[allow(non_snake_case)]
+pub mod Objects {
+    use serde_derive::{Serialize, Deserialize};
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    #[serde(transparent)]
+    #[repr(transparent)]
+    pub struct Count(pub u32);
+
+    impl Default for Count {
+        fn default() -> Self {
+            Self(0)
+        }
+    }
+
+    impl std::ops::Deref for Count {
+        type Target = u32;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl From<u32> for Count {
+        fn from(value: u32) -> Self {
+            Self(value)
+        }
+    }
+
+    impl From<Count> for u32 {
+        fn from(value: Count) -> Self {
+            value.0
+        }
+    }
+
+    impl omg_cdr::CdrEncode for Count {
+        const PRIMITIVE: bool = <u32 as omg_cdr::CdrEncode>::PRIMITIVE;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.0, writer)
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Count {
+        const PRIMITIVE: bool = <u32 as omg_cdr::CdrDecode>::PRIMITIVE;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            omg_cdr::CdrDecode::decode(reader).map(Self)
+        }
+    }
+
+    impl omg_idl_rt::TypeName for Count {
+        const TYPE_NAME: &'static str = "Objects::Count";
+        const REPOSITORY_ID: &'static str = "IDL:Objects/Count:1.0";
+    }
+
+    impl omg_idl_rt::TypeObjects for Count {
+        const MINIMAL: omg_idl_rt::TypeIdentifierTypeObjectPair = omg_idl_rt::TypeIdentifierTypeObjectPair { type_identifier: omg_idl_rt::TypeIdentifier::EquivalenceHash(omg_idl_rt::EquivalenceKind::Minimal, [0x6b, 0x01, 0xd2, 0x0c, 0x3e, 0xfd, 0xfb, 0x7d, 0xc7, 0xa3, 0x6c, 0x9a, 0x79, 0xcf]), type_object: b"\x0f\x00\x00\x00\xf1\x30\x00\x00\x00\x00\x00\x00\x03\x00\x00\x00\x00\x00\x07" };
+        const COMPLETE: omg_idl_rt::TypeIdentifierTypeObjectPair = omg_idl_rt::TypeIdentifierTypeObjectPair { type_identifier: omg_idl_rt::TypeIdentifier::EquivalenceHash(omg_idl_rt::EquivalenceKind::Complete, [0xbf, 0x71, 0x04, 0xb9, 0x2b, 0x0d, 0x47, 0xec, 0xed, 0x92, 0xf4, 0x53, 0xcb, 0x5f]), type_object: b"\x29\x00\x00\x00\xf2\x30\x00\x00\x17\x00\x00\x00\x00\x00\x00\x00\x0f\x00\x00\x00\x4f\x62\x6a\x65\x63\x74\x73\x3a\x3a\x43\x6f\x75\x6e\x74\x00\x00\x05\x00\x00\x00\x00\x00\x07\x00\x00" };
+        const DEPENDENCIES: &'static [omg_idl_rt::TypeIdentifierTypeObjectPair] = &[];
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    pub type Label = String;
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, PartialOrd, Eq, Ord)]
+    pub enum Color {
+        RED,
+        #[default]
+        GREEN,
+        BLUE,
+    }
+
+    #[allow(non_camel_case_types)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct ColorError;
+
+    impl std::str::FromStr for Color {
+        type Err = ColorError;
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            match value {
+                "RED" => Ok(Color::RED),
+                "GREEN" => Ok(Color::GREEN),
+                "BLUE" => Ok(Color::BLUE),
+                _ => Err(ColorError),
+            }
+        }
+    }
+
+    impl std::fmt::Display for Color {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let enum_str = match self {
+                    Color::RED => "RED",
+                    Color::GREEN => "GREEN",
+                    Color::BLUE => "BLUE",
+            };
+            write!(f, "{enum_str}")
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Color {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Color {
+        const TYPE_NAME: &'static str = "Objects::Color";
+        const REPOSITORY_ID: &'static str = "IDL:Objects/Color:1.0";
+    }
+
+    impl omg_idl_rt::TypeObjects for Color {
+        const MINIMAL: omg_idl_rt::TypeIdentifierTypeObjectPair = omg_idl_rt::TypeIdentifierTypeObjectPair { type_identifier: omg_idl_rt::TypeIdentifier::EquivalenceHash(omg_idl_rt::EquivalenceKind::Minimal, [0x23, 0x13, 0xb7, 0xaf, 0x39, 0x2f, 0x07, 0x09, 0x42, 0x91, 0x19, 0xf5, 0x15, 0x51]), type_object: b"\x42\x00\x00\x00\xf1\x40\x00\x00\x02\x00\x00\x00\x08\x00\x00\x00\x32\x00\x00\x00\x03\x00\x00\x00\x0a\x00\x00\x00\x00\x00\x00\x00\x00\x00\xa2\xd9\x54\x7b\x00\x00\x0a\x00\x00\x00\x01\x00\x00\x00\x00\x00\x9d\xe0\xe5\xdd\x00\x00\x0a\x00\x00\x00\x04\x00\x00\x00\x00\x00\x1b\x3e\x1e\xe9" };
+        const COMPLETE: omg_idl_rt::TypeIdentifierTypeObjectPair = omg_idl_rt::TypeIdentifierTypeObjectPair { type_identifier: omg_idl_rt::TypeIdentifier::EquivalenceHash(omg_idl_rt::EquivalenceKind::Complete, [0x42, 0x46, 0xe3, 0xba, 0x98, 0xbe, 0x12, 0x61, 0x8e, 0xdd, 0xd9, 0x06, 0x27, 0x41]), type_object: b"\x6f\x00\x00\x00\xf2\x40\x00\x00\x17\x00\x00\x00\x08\x00\x00\x00\x0f\x00\x00\x00\x4f\x62\x6a\x65\x63\x74\x73\x3a\x3a\x43\x6f\x6c\x6f\x72\x00\x00\x4b\x00\x00\x00\x03\x00\x00\x00\x12\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x04\x00\x00\x00\x52\x45\x44\x00\x00\x00\x00\x00\x14\x00\x00\x00\x01\x00\x00\x00\x40\x00\x00\x00\x06\x00\x00\x00\x47\x52\x45\x45\x4e\x00\x00\x00\x13\x00\x00\x00\x04\x00\x00\x00\x00\x00\x00\x00\x05\x00\x00\x00\x42\x4c\x55\x45\x00\x00\x00" };
+        const DEPENDENCIES: &'static [omg_idl_rt::TypeIdentifierTypeObjectPair] = &[];
+    }
+
+    impl omg_cdr::CdrEncode for Color {
+        const PRIMITIVE: bool = true;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let value: u8 = match self {
+                Color::RED => 0,
+                Color::GREEN => 1,
+                Color::BLUE => 4,
+            };
+            omg_cdr::CdrEncode::encode(&value, writer);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Color {
+        const PRIMITIVE: bool = true;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            match <u8 as omg_cdr::CdrDecode>::decode(reader)? {
+                0 => Ok(Color::RED),
+                1 => Ok(Color::GREEN),
+                4 => Ok(Color::BLUE),
+                value => Err(omg_cdr::CdrError::InvalidEnum(value.into())),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Color {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(1);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(1);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub enum Shape {
+        SQUARE{ side: f64, },
+        CIRCLE{ radius: f64, },
+        default{ name: Label, },
+    }
+
+    impl Default for Shape {
+        fn default() -> Self {
+            Shape::default { name: String::new() }
+        }
+    }
+//
+// TODO custom de-/serializer
+//
+
+    impl omg_idl_rt::Extensible for Shape {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Shape {
+        const TYPE_NAME: &'static str = "Objects::Shape";
+        const REPOSITORY_ID: &'static str = "IDL:Objects/Shape:1.0";
+    }
+
+    impl omg_idl_rt::TypeObjects for Shape {
+        const MINIMAL: omg_idl_rt::TypeIdentifierTypeObjectPair = omg_idl_rt::TypeIdentifierTypeObjectPair { type_identifier: omg_idl_rt::TypeIdentifier::EquivalenceHash(omg_idl_rt::EquivalenceKind::Minimal, [0xca, 0x0c, 0xe4, 0xbe, 0xe6, 0xa1, 0x56, 0x1c, 0x6c, 0x0b, 0xdc, 0x89, 0x71, 0xc5]), type_object: b"\x6c\x00\x00\x00\xf1\x52\x02\x00\x00\x00\x00\x00\x03\x00\x00\x00\x01\x00\x04\x00\x58\x00\x00\x00\x03\x00\x00\x00\x14\x00\x00\x00\x01\x00\x00\x00\x01\x00\x0a\x00\x01\x00\x00\x00\x01\x00\x00\x00\x9c\x62\xad\x1f\x14\x00\x00\x00\x02\x00\x00\x00\x01\x00\x0a\x00\x01\x00\x00\x00\x02\x00\x00\x00\xfc\x02\x1d\x54\x20\x00\x00\x00\x03\x00\x00\x00\x01\x00\xf1\x9f\x6a\x14\x8e\x52\x85\x6a\x80\xa1\x5d\x47\xc7\xa1\x6a\x00\x00\x00\x00\x00\x00\x00\xb0\x68\x93\x1c" };
+        const COMPLETE: omg_idl_rt::TypeIdentifierTypeObjectPair = omg_idl_rt::TypeIdentifierTypeObjectPair { type_identifier: omg_idl_rt::TypeIdentifier::EquivalenceHash(omg_idl_rt::EquivalenceKind::Complete, [0xdc, 0xa9, 0x43, 0x10, 0x21, 0x46, 0x81, 0x09, 0xfa, 0x0e, 0x21, 0xee, 0x3e, 0x20]), type_object: b"\xa3\x00\x00\x00\xf2\x52\x02\x00\x17\x00\x00\x00\x00\x00\x00\x00\x0f\x00\x00\x00\x4f\x62\x6a\x65\x63\x74\x73\x3a\x3a\x53\x68\x61\x70\x65\x00\x00\x05\x00\x00\x00\x01\x00\x04\x00\x00\x00\x00\x00\x73\x00\x00\x00\x03\x00\x00\x00\x1b\x00\x00\x00\x01\x00\x00\x00\x01\x00\x0a\x00\x01\x00\x00\x00\x01\x00\x00\x00\x05\x00\x00\x00\x73\x69\x64\x65\x00\x00\x00\x00\x1d\x00\x00\x00\x02\x00\x00\x00\x01\x00\x0a\x00\x01\x00\x00\x00\x02\x00\x00\x00\x07\x00\x00\x00\x72\x61\x64\x69\x75\x73\x00\x00\x00\x00\x00\x00\x27\x00\x00\x00\x03\x00\x00\x00\x41\x00\xf2\x6b\xbc\xde\x44\x02\x1d\xef\xa9\x6c\x66\xb0\x83\xfd\x75\x00\x00\x00\x00\x00\x00\x00\x05\x00\x00\x00\x6e\x61\x6d\x65\x00\x00\x00" };
+        const DEPENDENCIES: &'static [omg_idl_rt::TypeIdentifierTypeObjectPair] = &[
+            omg_idl_rt::TypeIdentifierTypeObjectPair { type_identifier: omg_idl_rt::TypeIdentifier::EquivalenceHash(omg_idl_rt::EquivalenceKind::Minimal, [0x9f, 0x6a, 0x14, 0x8e, 0x52, 0x85, 0x6a, 0x80, 0xa1, 0x5d, 0x47, 0xc7, 0xa1, 0x6a]), type_object: b"\x10\x00\x00\x00\xf1\x30\x00\x00\x00\x00\x00\x00\x04\x00\x00\x00\x00\x00\x70\x10" },
+            omg_idl_rt::TypeIdentifierTypeObjectPair { type_identifier: omg_idl_rt::TypeIdentifier::EquivalenceHash(omg_idl_rt::EquivalenceKind::Complete, [0x6b, 0xbc, 0xde, 0x44, 0x02, 0x1d, 0xef, 0xa9, 0x6c, 0x66, 0xb0, 0x83, 0xfd, 0x75]), type_object: b"\x2a\x00\x00\x00\xf2\x30\x00\x00\x17\x00\x00\x00\x00\x00\x00\x00\x0f\x00\x00\x00\x4f\x62\x6a\x65\x63\x74\x73\x3a\x3a\x4c\x61\x62\x65\x6c\x00\x00\x06\x00\x00\x00\x00\x00\x70\x10\x00\x00" },
+        ];
+    }
+
+    #[allow(dead_code)]
+    impl Shape {
+        /// Member ID of `side`
+        pub const SIDE_MEMBER_ID: u32 = 1;
+        /// Member ID of `radius`
+        pub const RADIUS_MEMBER_ID: u32 = 2;
+        /// Member ID of `name`
+        pub const NAME_MEMBER_ID: u32 = 3;
+    }
+
+    impl omg_cdr::CdrEncode for Shape {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            match self {
+                Shape::SQUARE { side } => writer.write_union(extensibility, &1_i32, Self::SIDE_MEMBER_ID, side, omg_cdr::CdrEncode::encode),
+                Shape::CIRCLE { radius } => writer.write_union(extensibility, &2_i32, Self::RADIUS_MEMBER_ID, radius, omg_cdr::CdrEncode::encode),
+                Shape::default { name } => writer.write_union(extensibility, &0_i32, Self::NAME_MEMBER_ID, name, omg_cdr::CdrEncode::encode),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Shape {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            reader.read_union(extensibility, |discriminator: i32, reader| {
+                Ok(match discriminator {
+                    1_i32 => Shape::SQUARE { side: reader.read_branch(extensibility, Self::SIDE_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    2_i32 => Shape::CIRCLE { radius: reader.read_branch(extensibility, Self::RADIUS_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    #[allow(unreachable_patterns)]
+                    _ => Shape::default { name: reader.read_branch(extensibility, Self::NAME_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                })
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Shape {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(25);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(29);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Base {
+        #[allow(non_snake_case)]
+        pub id: i32,
+    }
+
+    #[allow(dead_code)]
+    impl Base {
+
+        pub fn new(id: i32, ) -> Self {
+            Self {
+                id,
+            }
+        }
+
+        pub fn id(&self) -> &i32 {
+            &self.id
+        }
+
+        pub fn set_id(&mut self, value: i32) {
+            self.id = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Base {
+        fn default() -> Self {
+            Self {
+                id: 0,
+            }
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug)]
+    pub struct BaseKey {
+        pub id: i32,
+    }
+
+    impl omg_idl_rt::KeyHashEncode for BaseKey {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<i32>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.id, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+        }
+    }
+
+    impl PartialEq for BaseKey {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other).is_eq()
+        }
+    }
+
+    impl Eq for BaseKey {}
+
+    impl PartialOrd for BaseKey {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for BaseKey {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other)
+        }
+    }
+
+    impl std::hash::Hash for BaseKey {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            omg_idl_rt::hash_key(self, state);
+        }
+    }
+
+    impl omg_idl_rt::Keyed for Base {
+        type Key = BaseKey;
+
+        fn key(&self) -> Self::Key {
+            BaseKey {
+                id: self.id,
+            }
+        }
+    }
+
+    /// Equal if the key members are equal, other members are ignored
+    impl PartialEq for Base {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+                .is_eq()
+        }
+    }
+
+    impl Eq for Base {}
+
+    impl PartialOrd for Base {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    /// Orders by the key members, other members are ignored
+    impl Ord for Base {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+        }
+    }
+
+    /// Hashes the key members only, consistent with `PartialEq`
+    impl std::hash::Hash for Base {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            let mut writer = omg_idl_rt::KeyHolderWriter::hashing(state);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.id, &mut writer);
+        }
+    }
+
+    impl omg_idl_rt::KeyHashEncode for Base {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<i32>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.id, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Base {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Mutable;
+    }
+
+    impl omg_idl_rt::TypeName for Base {
+        const TYPE_NAME: &'static str = "Objects::Base";
+        const REPOSITORY_ID: &'static str = "IDL:Objects/Base:1.0";
+    }
+
+    impl omg_idl_rt::TypeObjects for Base {
+        const MINIMAL: omg_idl_rt::TypeIdentifierTypeObjectPair = omg_idl_rt::TypeIdentifierTypeObjectPair { type_identifier: omg_idl_rt::TypeIdentifier::EquivalenceHash(omg_idl_rt::EquivalenceKind::Minimal, [0xfe, 0x4f, 0x42, 0x42, 0x16, 0x71, 0xee, 0x7a, 0xa5, 0x56, 0x23, 0x6a, 0xb7, 0x98]), type_object: b"\x23\x00\x00\x00\xf1\x51\x04\x00\x01\x00\x00\x00\x00\x00\x00\x00\x13\x00\x00\x00\x01\x00\x00\x00\x0b\x00\x00\x00\x00\x00\x00\x00\x21\x00\x04\xb8\x0b\xb7\x74" };
+        const COMPLETE: omg_idl_rt::TypeIdentifierTypeObjectPair = omg_idl_rt::TypeIdentifierTypeObjectPair { type_identifier: omg_idl_rt::TypeIdentifier::EquivalenceHash(omg_idl_rt::EquivalenceKind::Complete, [0x3c, 0xa2, 0x97, 0x73, 0xd7, 0x57, 0xb0, 0xb3, 0x54, 0x7d, 0x80, 0x66, 0xf2, 0x98]), type_object: b"\x3d\x00\x00\x00\xf2\x51\x04\x00\x16\x00\x00\x00\x00\x00\x00\x00\x0e\x00\x00\x00\x4f\x62\x6a\x65\x63\x74\x73\x3a\x3a\x42\x61\x73\x65\x00\x00\x00\x19\x00\x00\x00\x01\x00\x00\x00\x11\x00\x00\x00\x00\x00\x00\x00\x21\x00\x04\x00\x03\x00\x00\x00\x69\x64\x00\x00\x00" };
+        const DEPENDENCIES: &'static [omg_idl_rt::TypeIdentifierTypeObjectPair] = &[];
+    }
+
+    #[allow(dead_code)]
+    impl Base {
+        /// Member ID of `id`
+        pub const ID_MEMBER_ID: u32 = 0;
+    }
+
+    impl omg_cdr::CdrEncode for Base {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Base {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Base {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_member(Self::ID_MEMBER_ID, true, &self.id, omg_cdr::CdrEncode::encode);
+        }
+
+        fn decode_member(&mut self, id: u32, reader: &mut omg_cdr::CdrReader<'_>) -> Result<bool, omg_cdr::CdrError> {
+            match id {
+                Self::ID_MEMBER_ID => self.id = omg_cdr::CdrDecode::decode(reader)?,
+                _ => return Ok(false),
+            }
+            Ok(true)
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Base {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(12);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(12);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Sample {
+        #[serde(flatten)]
+        pub base: Base,
+        /// Unit: m
+        #[allow(non_snake_case)]
+        pub distance: f64,
+        #[allow(non_snake_case)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub label: Option<Label>,
+        #[allow(non_snake_case)]
+        pub color: Color,
+        #[allow(non_snake_case)]
+        pub count: Count,
+        #[allow(non_snake_case)]
+        pub shapes: Vec<Shape>,
+        #[allow(non_snake_case)]
+        #[serde(with = "serde_arrays")]
+        pub weights: [f32;3_usize],
+    }
+
+    #[allow(dead_code)]
+    impl Sample {
+
+        pub fn new(base: Base, distance: f64, color: Color, count: Count, shapes: Vec<Shape>, weights: [f32;3_usize], ) -> Self {
+            Self {
+                base,
+                distance,
+                label: None,
+                color,
+                count,
+                shapes,
+                weights,
+            }
+        }
+
+        pub fn base(&self) -> &Base {
+            &self.base
+        }
+
+        pub fn set_base(&mut self, value: Base) {
+            self.base = value;
+        }
+
+        pub fn distance(&self) -> &f64 {
+            &self.distance
+        }
+
+        pub fn set_distance(&mut self, value: f64) -> Result<(), omg_idl_rt::ValidationError> {
+            Self::validate_distance(&value)?;
+            self.distance = value;
+            Ok(())
+        }
+
+        fn validate_distance(value: &f64) -> Result<(), omg_idl_rt::ValidationError> {
+            if *value < 0.0 {
+                return Err(omg_idl_rt::ValidationError::new("distance", omg_idl_rt::Bound::Min, "0"));
+            }
+            if *value > 100.0 {
+                return Err(omg_idl_rt::ValidationError::new("distance", omg_idl_rt::Bound::Max, "100"));
+            }
+            Ok(())
+        }
+
+        pub fn label(&self) -> Option<&Label> {
+            self.label.as_ref()
+        }
+
+        pub fn set_label(&mut self, value: Option<Label>) {
+            self.label = value;
+        }
+
+        pub fn color(&self) -> &Color {
+            &self.color
+        }
+
+        pub fn set_color(&mut self, value: Color) {
+            self.color = value;
+        }
+
+        pub fn count(&self) -> &Count {
+            &self.count
+        }
+
+        pub fn set_count(&mut self, value: Count) {
+            self.count = value;
+        }
+
+        pub fn shapes(&self) -> &Vec<Shape> {
+            &self.shapes
+        }
+
+        pub fn set_shapes(&mut self, value: Vec<Shape>) {
+            self.shapes = value;
+        }
+
+        pub fn weights(&self) -> &[f32;3_usize] {
+            &self.weights
+        }
+
+        pub fn set_weights(&mut self, value: [f32;3_usize]) {
+            self.weights = value;
+        }
+
+        /// Check all members against their `@range`, `@min` and `@max` bounds
+        pub fn validate(&self) -> Result<(), omg_idl_rt::ValidationError> {
+            Self::validate_distance(&self.distance)?;
+            Ok(())
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Sample {
+        fn default() -> Self {
+            Self {
+                base: Default::default(),
+                distance: 0.0,
+                label: None,
+                color: Default::default(),
+                count: Default::default(),
+                shapes: Vec::new(),
+                weights: std::array::from_fn(|_| 0.0),
+            }
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug)]
+    pub struct SampleKey {
+        pub base: Base,
+    }
+
+    impl omg_idl_rt::KeyHashEncode for SampleKey {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<Base>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.base, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.base, &other.base)
+        }
+    }
+
+    impl PartialEq for SampleKey {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other).is_eq()
+        }
+    }
+
+    impl Eq for SampleKey {}
+
+    impl PartialOrd for SampleKey {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for SampleKey {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other)
+        }
+    }
+
+    impl std::hash::Hash for SampleKey {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            omg_idl_rt::hash_key(self, state);
+        }
+    }
+
+    impl omg_idl_rt::Keyed for Sample {
+        type Key = SampleKey;
+
+        fn key(&self) -> Self::Key {
+            SampleKey {
+                base: self.base.clone(),
+            }
+        }
+    }
+
+    /// Equal if the key members are equal, other members are ignored
+    impl PartialEq for Sample {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.base, &other.base)
+                .is_eq()
+        }
+    }
+
+    impl Eq for Sample {}
+
+    impl PartialOrd for Sample {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    /// Orders by the key members, other members are ignored
+    impl Ord for Sample {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.base, &other.base)
+        }
+    }
+
+    /// Hashes the key members only, consistent with `PartialEq`
+    impl std::hash::Hash for Sample {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            let mut writer = omg_idl_rt::KeyHolderWriter::hashing(state);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.base, &mut writer);
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Sample {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Mutable;
+    }
+
+    impl omg_idl_rt::TypeName for Sample {
+        const TYPE_NAME: &'static str = "Objects::Sample";
+        const REPOSITORY_ID: &'static str = "IDL:Objects/Sample:1.0";
+    }
+
+    impl omg_idl_rt::TypeObjects for Sample {
+        const MINIMAL: omg_idl_rt::TypeIdentifierTypeObjectPair = omg_idl_rt::TypeIdentifierTypeObjectPair { type_identifier: omg_idl_rt::TypeIdentifier::EquivalenceHash(omg_idl_rt::EquivalenceKind::Minimal, [0xac, 0x67, 0xe6, 0x05, 0x8c, 0xb2, 0xa6, 0x43, 0xed, 0xde, 0xd3, 0x8f, 0x44, 0xf6]), type_object: b"\xcd\x00\x00\x00\xf1\x51\x04\x00\x0f\x00\x00\x00\xf1\xfe\x4f\x42\x42\x16\x71\xee\x7a\xa5\x56\x23\x6a\xb7\x98\x00\xb1\x00\x00\x00\x06\x00\x00\x00\x19\x00\x00\x00\xd3\x04\xba\x00\x09\x00\xf1\x9f\x6a\x14\x8e\x52\x85\x6a\x80\xa1\x5d\x47\xc7\xa1\x6a\xd3\x04\xba\x20\x00\x00\x00\x19\x00\x00\x00\xe2\x94\x2a\x04\x01\x00\xf1\x6b\x01\xd2\x0c\x3e\xfd\xfb\x7d\xc7\xa3\x6c\x9a\x79\xcf\xe2\x94\x2a\x04\x00\x00\x00\x0b\x00\x00\x00\xa7\x4e\xc9\x05\x01\x00\x0a\xa7\x4e\xc9\xc5\x00\x1e\x00\x00\x00\x8a\x99\x5d\x07\x01\x00\x80\xf1\x01\x00\x04\xf1\xca\x0c\xe4\xbe\xe6\xa1\x56\x1c\x6c\x0b\xdc\x89\x71\xc5\x8a\x99\x5d\x27\x00\x00\x16\x00\x00\x00\x63\xf4\xf1\x09\x01\x00\x90\xf3\x01\x00\x00\x00\x01\x00\x00\x00\x03\x09\x63\xf4\xf1\xe9\x00\x00\x19\x00\x00\x00\x70\xdd\xa5\x0f\x01\x00\xf1\x23\x13\xb7\xaf\x39\x2f\x07\x09\x42\x91\x19\xf5\x15\x51\x70\xdd\xa5\xdf" };
+        const COMPLETE: omg_idl_rt::TypeIdentifierTypeObjectPair = omg_idl_rt::TypeIdentifierTypeObjectPair { type_identifier: omg_idl_rt::TypeIdentifier::EquivalenceHash(omg_idl_rt::EquivalenceKind::Complete, [0x22, 0xcc, 0x73, 0xb5, 0x46, 0xf8, 0x82, 0x1c, 0xe7, 0xc1, 0x14, 0xac, 0xb3, 0xc2]), type_object: b"\x4a\x01\x00\x00\xf2\x51\x14\x00\x28\x00\x00\x00\xf2\x3c\xa2\x97\x73\xd7\x57\xb0\xb3\x54\x7d\x80\x66\xf2\x98\x00\x00\x00\x00\x00\x10\x00\x00\x00\x4f\x62\x6a\x65\x63\x74\x73\x3a\x3a\x53\x61\x6d\x70\x6c\x65\x00\x16\x01\x00\x00\x06\x00\x00\x00\x3e\x00\x00\x00\xa7\x4e\xc9\x05\x01\x00\x0a\x00\x09\x00\x00\x00\x64\x69\x73\x74\x61\x6e\x63\x65\x00\x01\x00\x00\x21\x00\x00\x00\x01\x00\x00\x00\x02\x00\x00\x00\x6d\x00\x01\x0a\x00\x00\x00\x00\x00\x00\x00\x00\x01\x0a\x00\x00\x00\x00\x00\x00\x00\x00\x59\x40\x00\x00\x00\x00\x24\x00\x00\x00\xd3\x04\xba\x00\x09\x00\xf2\x6b\xbc\xde\x44\x02\x1d\xef\xa9\x6c\x66\xb0\x83\xfd\x75\x00\x00\x00\x06\x00\x00\x00\x6c\x61\x62\x65\x6c\x00\x00\x00\x24\x00\x00\x00\x70\xdd\xa5\x0f\x01\x00\xf2\x42\x46\xe3\xba\x98\xbe\x12\x61\x8e\xdd\xd9\x06\x27\x41\x00\x00\x00\x06\x00\x00\x00\x63\x6f\x6c\x6f\x72\x00\x00\x00\x24\x00\x00\x00\xe2\x94\x2a\x04\x01\x00\xf2\xbf\x71\x04\xb9\x2b\x0d\x47\xec\xed\x92\xf4\x53\xcb\x5f\x00\x00\x00\x06\x00\x00\x00\x63\x6f\x75\x6e\x74\x00\x00\x00\x29\x00\x00\x00\x8a\x99\x5d\x07\x01\x00\x80\xf2\x01\x00\x04\xf2\xdc\xa9\x43\x10\x21\x46\x81\x09\xfa\x0e\x21\xee\x3e\x20\x00\x00\x07\x00\x00\x00\x73\x68\x61\x70\x65\x73\x00\x00\x00\x00\x00\x00\x22\x00\x00\x00\x63\xf4\xf1\x09\x01\x00\x90\xf3\x01\x00\x00\x00\x01\x00\x00\x00\x03\x09\x00\x00\x08\x00\x00\x00\x77\x65\x69\x67\x68\x74\x73\x00\x00\x00" };
+        const DEPENDENCIES: &'static [omg_idl_rt::TypeIdentifierTypeObjectPair] = &[
+            <Count as omg_idl_rt::TypeObjects>::MINIMAL,
+            <Count as omg_idl_rt::TypeObjects>::COMPLETE,
+            omg_idl_rt::TypeIdentifierTypeObjectPair { type_identifier: omg_idl_rt::TypeIdentifier::EquivalenceHash(omg_idl_rt::EquivalenceKind::Minimal, [0x9f, 0x6a, 0x14, 0x8e, 0x52, 0x85, 0x6a, 0x80, 0xa1, 0x5d, 0x47, 0xc7, 0xa1, 0x6a]), type_object: b"\x10\x00\x00\x00\xf1\x30\x00\x00\x00\x00\x00\x00\x04\x00\x00\x00\x00\x00\x70\x10" },
+            omg_idl_rt::TypeIdentifierTypeObjectPair { type_identifier: omg_idl_rt::TypeIdentifier::EquivalenceHash(omg_idl_rt::EquivalenceKind::Complete, [0x6b, 0xbc, 0xde, 0x44, 0x02, 0x1d, 0xef, 0xa9, 0x6c, 0x66, 0xb0, 0x83, 0xfd, 0x75]), type_object: b"\x2a\x00\x00\x00\xf2\x30\x00\x00\x17\x00\x00\x00\x00\x00\x00\x00\x0f\x00\x00\x00\x4f\x62\x6a\x65\x63\x74\x73\x3a\x3a\x4c\x61\x62\x65\x6c\x00\x00\x06\x00\x00\x00\x00\x00\x70\x10\x00\x00" },
+            <Color as omg_idl_rt::TypeObjects>::MINIMAL,
+            <Color as omg_idl_rt::TypeObjects>::COMPLETE,
+            <Shape as omg_idl_rt::TypeObjects>::MINIMAL,
+            <Shape as omg_idl_rt::TypeObjects>::COMPLETE,
+            <Base as omg_idl_rt::TypeObjects>::MINIMAL,
+            <Base as omg_idl_rt::TypeObjects>::COMPLETE,
+        ];
+    }
+
+    #[allow(dead_code)]
+    impl Sample {
+        /// Member ID of `distance`
+        pub const DISTANCE_MEMBER_ID: u32 = 97078951;
+        /// Member ID of `label`
+        pub const LABEL_MEMBER_ID: u32 = 12190931;
+        /// Member ID of `color`
+        pub const COLOR_MEMBER_ID: u32 = 262528368;
+        /// Member ID of `count`
+        pub const COUNT_MEMBER_ID: u32 = 69899490;
+        /// Member ID of `shapes`
+        pub const SHAPES_MEMBER_ID: u32 = 123574666;
+        /// Member ID of `weights`
+        pub const WEIGHTS_MEMBER_ID: u32 = 166851683;
+    }
+
+    impl omg_cdr::CdrEncode for Sample {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Sample {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Sample {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrStruct::encode_members(&self.base, writer);
+            writer.write_member(Self::DISTANCE_MEMBER_ID, false, &self.distance, omg_cdr::CdrEncode::encode);
+            if let Some(value) = &self.label {
+                writer.write_member(Self::LABEL_MEMBER_ID, false, value, omg_cdr::CdrEncode::encode);
+            }
+            writer.write_member(Self::COLOR_MEMBER_ID, false, &self.color, omg_cdr::CdrEncode::encode);
+            writer.write_member(Self::COUNT_MEMBER_ID, false, &self.count, omg_cdr::CdrEncode::encode);
+            writer.write_member(Self::SHAPES_MEMBER_ID, false, &self.shapes, omg_cdr::CdrEncode::encode);
+            writer.write_member(Self::WEIGHTS_MEMBER_ID, false, &self.weights, omg_cdr::CdrEncode::encode);
+        }
+
+        fn decode_member(&mut self, id: u32, reader: &mut omg_cdr::CdrReader<'_>) -> Result<bool, omg_cdr::CdrError> {
+            match id {
+                Self::DISTANCE_MEMBER_ID => self.distance = omg_cdr::CdrDecode::decode(reader)?,
+                Self::LABEL_MEMBER_ID => self.label = Some(omg_cdr::CdrDecode::decode(reader)?),
+                Self::COLOR_MEMBER_ID => self.color = omg_cdr::CdrDecode::decode(reader)?,
+                Self::COUNT_MEMBER_ID => self.count = omg_cdr::CdrDecode::decode(reader)?,
+                Self::SHAPES_MEMBER_ID => self.shapes = omg_cdr::CdrDecode::decode(reader)?,
+                Self::WEIGHTS_MEMBER_ID => self.weights = omg_cdr::CdrDecode::decode(reader)?,
+                _ => return omg_cdr::CdrStruct::decode_member(&mut self.base, id, reader),
+            }
+            Ok(true)
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Sample {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(252);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(236);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub enum Selection {
+        RED{ red: i32, },
+        GREEN{ other: String, },
+        BLUE{ other: String, },
+    }
+
+    impl Default for Selection {
+        fn default() -> Self {
+            Selection::RED { red: 0 }
+        }
+    }
+//
+// TODO custom de-/serializer
+//
+
+    impl omg_idl_rt::Extensible for Selection {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Selection {
+        const TYPE_NAME: &'static str = "Objects::Selection";
+        const REPOSITORY_ID: &'static str = "IDL:Objects/Selection:1.0";
+    }
+
+    impl omg_idl_rt::TypeObjects for Selection {
+        const MINIMAL: omg_idl_rt::TypeIdentifierTypeObjectPair = omg_idl_rt::TypeIdentifierTypeObjectPair { type_identifier: omg_idl_rt::TypeIdentifier::EquivalenceHash(omg_idl_rt::EquivalenceKind::Minimal, [0x5b, 0xd0, 0x13, 0x03, 0x33, 0xda, 0xa6, 0x3d, 0x7f, 0x60, 0xed, 0x51, 0x68, 0x8c]), type_object: b"\x5c\x00\x00\x00\xf1\x52\x02\x00\x00\x00\x00\x00\x11\x00\x00\x00\x01\x00\xf1\x23\x13\xb7\xaf\x39\x2f\x07\x09\x42\x91\x19\xf5\x15\x51\x00\x00\x00\x38\x00\x00\x00\x02\x00\x00\x00\x14\x00\x00\x00\x01\x00\x00\x00\x01\x00\x04\x00\x01\x00\x00\x00\x00\x00\x00\x00\xbd\xa9\x64\x3a\x18\x00\x00\x00\x02\x00\x00\x00\x01\x00\x72\x00\x02\x00\x00\x00\x01\x00\x00\x00\x04\x00\x00\x00\x79\x5f\x32\x02" };
+        const COMPLETE: omg_idl_rt::TypeIdentifierTypeObjectPair = omg_idl_rt::TypeIdentifierTypeObjectPair { type_identifier: omg_idl_rt::TypeIdentifier::EquivalenceHash(omg_idl_rt::EquivalenceKind::Complete, [0x94, 0xe7, 0xb1, 0xca, 0x5b, 0x2a, 0x1a, 0x1c, 0x2d, 0x58, 0xe4, 0x01, 0x35, 0x52]), type_object: b"\x88\x00\x00\x00\xf2\x52\x02\x00\x1b\x00\x00\x00\x00\x00\x00\x00\x13\x00\x00\x00\x4f\x62\x6a\x65\x63\x74\x73\x3a\x3a\x53\x65\x6c\x65\x63\x74\x69\x6f\x6e\x00\x00\x13\x00\x00\x00\x01\x00\xf2\x42\x46\xe3\xba\x98\xbe\x12\x61\x8e\xdd\xd9\x06\x27\x41\x00\x00\x00\x48\x00\x00\x00\x02\x00\x00\x00\x1a\x00\x00\x00\x01\x00\x00\x00\x01\x00\x04\x00\x01\x00\x00\x00\x00\x00\x00\x00\x04\x00\x00\x00\x72\x65\x64\x00\x00\x00\x00\x00\x20\x00\x00\x00\x02\x00\x00\x00\x01\x00\x72\x00\x02\x00\x00\x00\x01\x00\x00\x00\x04\x00\x00\x00\x06\x00\x00\x00\x6f\x74\x68\x65\x72\x00\x00\x00" };
+        const DEPENDENCIES: &'static [omg_idl_rt::TypeIdentifierTypeObjectPair] = &[
+            <Color as omg_idl_rt::TypeObjects>::MINIMAL,
+            <Color as omg_idl_rt::TypeObjects>::COMPLETE,
+        ];
+    }
+
+    #[allow(dead_code)]
+    impl Selection {
+        /// Member ID of `red`
+        pub const RED_MEMBER_ID: u32 = 1;
+        /// Member ID of `other`
+        pub const OTHER_MEMBER_ID: u32 = 2;
+    }
+
+    impl omg_cdr::CdrEncode for Selection {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            match self {
+                Selection::RED { red } => writer.write_union(extensibility, &Color::RED, Self::RED_MEMBER_ID, red, omg_cdr::CdrEncode::encode),
+                Selection::GREEN { other } => writer.write_union(extensibility, &Color::GREEN, Self::OTHER_MEMBER_ID, other, <omg_cdr::WideString as omg_cdr::Codec<_>>::encode),
+                Selection::BLUE { other } => writer.write_union(extensibility, &Color::BLUE, Self::OTHER_MEMBER_ID, other, <omg_cdr::WideString as omg_cdr::Codec<_>>::encode),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Selection {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            reader.read_union(extensibility, |discriminator: Color, reader| {
+                Ok(match discriminator {
+                    Color::RED => Selection::RED { red: reader.read_branch(extensibility, Self::RED_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    Color::GREEN => Selection::GREEN { other: reader.read_branch(extensibility, Self::OTHER_MEMBER_ID, <omg_cdr::WideString as omg_cdr::Codec<_>>::decode)? },
+                    Color::BLUE => Selection::BLUE { other: reader.read_branch(extensibility, Self::OTHER_MEMBER_ID, <omg_cdr::WideString as omg_cdr::Codec<_>>::decode)? },
+                    #[allow(unreachable_patterns)]
+                    _ => return Err(omg_cdr::CdrError::InvalidDiscriminator),
+                })
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Selection {
+        const IS_BOUNDED: bool = false;
+        const MAX_XCDR1_SIZE: Option<usize> = None;
+        const MAX_XCDR2_SIZE: Option<usize> = None;
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Node {
+        #[allow(non_snake_case)]
+        pub name: String,
+        #[allow(non_snake_case)]
+        pub children: Vec<Node>,
+    }
+
+    #[allow(dead_code)]
+    impl Node {
+
+        pub fn new(name: String, children: Vec<Node>, ) -> Self {
+            Self {
+                name,
+                children,
+            }
+        }
+
+        pub fn name(&self) -> &String {
+            &self.name
+        }
+
+        pub fn set_name(&mut self, value: String) {
+            self.name = value;
+        }
+
+        pub fn children(&self) -> &Vec<Node> {
+            &self.children
+        }
+
+        pub fn set_children(&mut self, value: Vec<Node>) {
+            self.children = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Node {
+        fn default() -> Self {
+            Self {
+                name: String::new(),
+                children: Vec::new(),
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Node {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Node {
+        const TYPE_NAME: &'static str = "Objects::Node";
+        const REPOSITORY_ID: &'static str = "IDL:Objects/Node:1.0";
+    }
+
+    impl omg_idl_rt::TypeObjects for Node {
+        const MINIMAL: omg_idl_rt::TypeIdentifierTypeObjectPair = omg_idl_rt::TypeIdentifierTypeObjectPair { type_identifier: omg_idl_rt::TypeIdentifier::StronglyConnectedComponent(omg_idl_rt::StronglyConnectedComponentId { kind: omg_idl_rt::EquivalenceKind::Minimal, hash: [0x51, 0x43, 0xca, 0xf6, 0x4b, 0xae, 0xf8, 0x2d, 0xe3, 0xe4, 0xe0, 0x7e, 0x7e, 0xfc], scc_length: 1, scc_index: 1 }), type_object: b"\x50\x00\x00\x00\xf1\x51\x02\x00\x01\x00\x00\x00\x00\x00\x00\x00\x40\x00\x00\x00\x02\x00\x00\x00\x0c\x00\x00\x00\x00\x00\x00\x00\x01\x00\x70\x00\xb0\x68\x93\x1c\x28\x00\x00\x00\x01\x00\x00\x00\x01\x00\x80\xf1\x01\x00\x00\xb0\xf1\x51\x43\xca\xf6\x4b\xae\xf8\x2d\xe3\xe4\xe0\x7e\x7e\xfc\x00\x01\x00\x00\x00\x01\x00\x00\x00\x26\x81\x84\xc1" };
+        const COMPLETE: omg_idl_rt::TypeIdentifierTypeObjectPair = omg_idl_rt::TypeIdentifierTypeObjectPair { type_identifier: omg_idl_rt::TypeIdentifier::StronglyConnectedComponent(omg_idl_rt::StronglyConnectedComponentId { kind: omg_idl_rt::EquivalenceKind::Complete, hash: [0xec, 0xdb, 0x21, 0x18, 0xf3, 0xc1, 0xf4, 0xff, 0xe0, 0x7d, 0x37, 0x9f, 0xd9, 0x41], scc_length: 1, scc_index: 1 }), type_object: b"\x77\x00\x00\x00\xf2\x51\x0a\x00\x16\x00\x00\x00\x00\x00\x00\x00\x0e\x00\x00\x00\x4f\x62\x6a\x65\x63\x74\x73\x3a\x3a\x4e\x6f\x64\x65\x00\x00\x00\x53\x00\x00\x00\x02\x00\x00\x00\x13\x00\x00\x00\x00\x00\x00\x00\x01\x00\x70\x00\x05\x00\x00\x00\x6e\x61\x6d\x65\x00\x00\x00\x00\x33\x00\x00\x00\x01\x00\x00\x00\x01\x00\x80\xf2\x01\x00\x00\xb0\xf2\xec\xdb\x21\x18\xf3\xc1\xf4\xff\xe0\x7d\x37\x9f\xd9\x41\x00\x01\x00\x00\x00\x01\x00\x00\x00\x09\x00\x00\x00\x63\x68\x69\x6c\x64\x72\x65\x6e\x00\x00\x00" };
+        const DEPENDENCIES: &'static [omg_idl_rt::TypeIdentifierTypeObjectPair] = &[];
+    }
+
+    #[allow(dead_code)]
+    impl Node {
+        /// Member ID of `name`
+        pub const NAME_MEMBER_ID: u32 = 0;
+        /// Member ID of `children`
+        pub const CHILDREN_MEMBER_ID: u32 = 1;
+    }
+
+    impl omg_cdr::CdrEncode for Node {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Node {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Node {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.name, writer);
+            omg_cdr::CdrEncode::encode(&self.children, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                name: omg_cdr::CdrDecode::decode(reader)?,
+                children: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Node {
+        const IS_BOUNDED: bool = false;
+        const MAX_XCDR1_SIZE: Option<usize> = None;
+        const MAX_XCDR2_SIZE: Option<usize> = None;
+    }
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const SQUARE: i32 = 1;
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const CIRCLE: i32 = 2;
+
+}
@@ -1,3 +1,3 @@
 
 #[allow(dead_code, non_camel_case_types)]
-pub type Foo = [[u8;2_usize];3+3_usize];
+pub type Foo = [[u8;3+3_usize];2_usize];
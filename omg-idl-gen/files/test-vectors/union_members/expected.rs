@@ -8,6 +8,142 @@ pub enum Foo {
     LABEL2{ s: i16, },
     default{ o: u8, },
 }
+
+impl Default for Foo {
+    fn default() -> Self {
+        Foo::default { o: 0 }
+    }
+}
+//
+// TODO custom de-/serializer
+//
+
+impl omg_idl_rt::Extensible for Foo {
+    const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+}
+
+impl omg_idl_rt::TypeName for Foo {
+    const TYPE_NAME: &'static str = "Foo";
+    const REPOSITORY_ID: &'static str = "IDL:Foo:1.0";
+}
+
+#[allow(dead_code)]
+impl Foo {
+    /// Member ID of `l`
+    pub const L_MEMBER_ID: u32 = 1;
+    /// Member ID of `s`
+    pub const S_MEMBER_ID: u32 = 2;
+    /// Member ID of `o`
+    pub const O_MEMBER_ID: u32 = 3;
+}
+
+impl omg_cdr::CdrEncode for Foo {
+    fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+        let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+        match self {
+            Foo::LABEL0 { l } => writer.write_union(extensibility, &0_i32, Self::L_MEMBER_ID, l, omg_cdr::CdrEncode::encode),
+            Foo::LABEL1 { s } => writer.write_union(extensibility, &1_i32, Self::S_MEMBER_ID, s, omg_cdr::CdrEncode::encode),
+            Foo::LABEL2 { s } => writer.write_union(extensibility, &2_i32, Self::S_MEMBER_ID, s, omg_cdr::CdrEncode::encode),
+            Foo::default { o } => writer.write_union(extensibility, &3_i32, Self::O_MEMBER_ID, o, omg_cdr::CdrEncode::encode),
+        }
+    }
+}
+
+impl omg_cdr::CdrDecode for Foo {
+    fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+        let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+        reader.read_union(extensibility, |discriminator: i32, reader| {
+            Ok(match discriminator {
+                0_i32 => Foo::LABEL0 { l: reader.read_branch(extensibility, Self::L_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                1_i32 => Foo::LABEL1 { s: reader.read_branch(extensibility, Self::S_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                2_i32 => Foo::LABEL2 { s: reader.read_branch(extensibility, Self::S_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                #[allow(unreachable_patterns)]
+                _ => Foo::default { o: reader.read_branch(extensibility, Self::O_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+            })
+        })
+    }
+}
+
+impl omg_cdr::CdrBounds for Foo {
+    const IS_BOUNDED: bool = true;
+    const MAX_XCDR1_SIZE: Option<usize> = Some(8);
+    const MAX_XCDR2_SIZE: Option<usize> = Some(12);
+}
+
+#[allow(dead_code, non_camel_case_types)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum Bar {
+    #[serde(rename = "1")]
+    Case1{ a: i32, },
+    #[serde(rename = "2")]
+    Case2{ b: String, },
+    #[serde(rename = "-3")]
+    CaseMinus3{ b: String, },
+}
+
+impl Default for Bar {
+    fn default() -> Self {
+        Bar::Case1 { a: 0 }
+    }
+}
 //
 // TODO custom de-/serializer
 //
+
+impl omg_idl_rt::Extensible for Bar {
+    const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+}
+
+impl omg_idl_rt::TypeName for Bar {
+    const TYPE_NAME: &'static str = "Bar";
+    const REPOSITORY_ID: &'static str = "IDL:Bar:1.0";
+}
+
+#[allow(dead_code)]
+impl Bar {
+    /// Member ID of `a`
+    pub const A_MEMBER_ID: u32 = 1;
+    /// Member ID of `b`
+    pub const B_MEMBER_ID: u32 = 2;
+}
+
+impl omg_cdr::CdrEncode for Bar {
+    fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+        let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+        match self {
+            Bar::Case1 { a } => writer.write_union(extensibility, &1_i16, Self::A_MEMBER_ID, a, omg_cdr::CdrEncode::encode),
+            Bar::Case2 { b } => writer.write_union(extensibility, &2_i16, Self::B_MEMBER_ID, b, omg_cdr::CdrEncode::encode),
+            Bar::CaseMinus3 { b } => writer.write_union(extensibility, &-3_i16, Self::B_MEMBER_ID, b, omg_cdr::CdrEncode::encode),
+        }
+    }
+}
+
+impl omg_cdr::CdrDecode for Bar {
+    fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+        let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+        reader.read_union(extensibility, |discriminator: i16, reader| {
+            Ok(match discriminator {
+                1_i16 => Bar::Case1 { a: reader.read_branch(extensibility, Self::A_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                2_i16 => Bar::Case2 { b: reader.read_branch(extensibility, Self::B_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                -3_i16 => Bar::CaseMinus3 { b: reader.read_branch(extensibility, Self::B_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                #[allow(unreachable_patterns)]
+                _ => return Err(omg_cdr::CdrError::InvalidDiscriminator),
+            })
+        })
+    }
+}
+
+impl omg_cdr::CdrBounds for Bar {
+    const IS_BOUNDED: bool = false;
+    const MAX_XCDR1_SIZE: Option<usize> = None;
+    const MAX_XCDR2_SIZE: Option<usize> = None;
+}
+
+#[allow(dead_code, non_upper_case_globals)]
+pub const LABEL0: i32 = 0;
+
+#[allow(dead_code, non_upper_case_globals)]
+pub const LABEL1: i32 = 1;
+
+#[allow(dead_code, non_upper_case_globals)]
+pub const LABEL2: i32 = 2;
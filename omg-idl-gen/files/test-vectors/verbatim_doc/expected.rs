@@ -0,0 +1,501 @@
+// generated from verbatim_doc/input.idl
+
+/// Sensor data types
+#[allow(non_snake_case)]
+pub mod Sensors {
+    pub const MODULE_VERSION: u32 = 2;
+    use serde_derive::{Serialize, Deserialize};
+
+    /// Velocity in meters per second
+    ///
+    /// Unit: m/s
+    #[allow(dead_code, non_camel_case_types)]
+    pub type Speed = f64;
+
+    /// Legacy reading
+    #[deprecated(note = "use Reading")]
+    #[allow(dead_code, non_camel_case_types, deprecated)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct OldReading {
+        #[allow(non_snake_case)]
+        pub id: i32,
+        #[allow(non_snake_case)]
+        pub value: f64,
+    }
+
+    #[allow(dead_code, deprecated)]
+    impl OldReading {
+
+        pub fn new(id: i32, value: f64, ) -> Self {
+            Self {
+                id,
+                value,
+            }
+        }
+
+        pub fn id(&self) -> &i32 {
+            &self.id
+        }
+
+        pub fn set_id(&mut self, value: i32) {
+            self.id = value;
+        }
+
+        pub fn value(&self) -> &f64 {
+            &self.value
+        }
+
+        pub fn set_value(&mut self, value: f64) {
+            self.value = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls, deprecated)]
+    impl Default for OldReading {
+        fn default() -> Self {
+            Self {
+                id: 0,
+                value: 0.0,
+            }
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types, non_snake_case)]
+    #[derive(Clone, Debug)]
+    pub struct OldReadingKey {
+        pub id: i32,
+    }
+
+    impl omg_idl_rt::KeyHashEncode for OldReadingKey {
+        const KEY_SIZE: omg_idl_rt::KeySize = omg_idl_rt::KeySize::new().field::<i32>();
+
+        fn encode_key(&self, writer: &mut omg_idl_rt::KeyHolderWriter) {
+            omg_idl_rt::KeyHashEncode::encode_key(&self.id, writer);
+        }
+
+        fn key_cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+        }
+    }
+
+    impl PartialEq for OldReadingKey {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other).is_eq()
+        }
+    }
+
+    impl Eq for OldReadingKey {}
+
+    impl PartialOrd for OldReadingKey {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for OldReadingKey {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(self, other)
+        }
+    }
+
+    impl std::hash::Hash for OldReadingKey {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            omg_idl_rt::hash_key(self, state);
+        }
+    }
+
+    #[allow(deprecated)]
+    impl omg_idl_rt::Keyed for OldReading {
+        type Key = OldReadingKey;
+
+        fn key(&self) -> Self::Key {
+            OldReadingKey {
+                id: self.id,
+            }
+        }
+    }
+
+    #[allow(deprecated)]
+    /// Equal if the key members are equal, other members are ignored
+    impl PartialEq for OldReading {
+        fn eq(&self, other: &Self) -> bool {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+                .is_eq()
+        }
+    }
+
+    #[allow(deprecated)]
+    impl Eq for OldReading {}
+
+    #[allow(deprecated)]
+    impl PartialOrd for OldReading {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    #[allow(deprecated)]
+    /// Orders by the key members, other members are ignored
+    impl Ord for OldReading {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            omg_idl_rt::KeyHashEncode::key_cmp(&self.id, &other.id)
+        }
+    }
+
+    #[allow(deprecated)]
+    /// Hashes the key members only, consistent with `PartialEq`
+    impl std::hash::Hash for OldReading {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            let mut writer = omg_idl_rt::KeyHolderWriter::hashing(state);
+            omg_idl_rt::KeyHashEncode::encode_key(&self.id, &mut writer);
+        }
+    }
+
+    #[allow(deprecated)]
+    impl omg_idl_rt::Extensible for OldReading {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    #[allow(deprecated)]
+    impl omg_idl_rt::TypeName for OldReading {
+        const TYPE_NAME: &'static str = "Sensors::OldReading";
+        const REPOSITORY_ID: &'static str = "IDL:Sensors/OldReading:1.0";
+    }
+
+    #[allow(dead_code, deprecated)]
+    impl OldReading {
+        /// Member ID of `id`
+        pub const ID_MEMBER_ID: u32 = 0;
+        /// Member ID of `value`
+        pub const VALUE_MEMBER_ID: u32 = 1;
+    }
+
+    #[allow(deprecated)]
+    impl omg_cdr::CdrEncode for OldReading {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    #[allow(deprecated)]
+    impl omg_cdr::CdrDecode for OldReading {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    #[allow(deprecated)]
+    impl omg_cdr::CdrStruct for OldReading {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.id, writer);
+            omg_cdr::CdrEncode::encode(&self.value, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                id: omg_cdr::CdrDecode::decode(reader)?,
+                value: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    #[allow(deprecated)]
+    impl omg_cdr::CdrBounds for OldReading {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(16);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(16);
+    }
+
+    /// A single measurement
+    /// of one sensor
+    #[derive(PartialEq)]
+    #[allow(dead_code, non_camel_case_types, deprecated)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Reading {
+        /// Sensor index
+        #[allow(non_snake_case)]
+        pub sensor: i32,
+        /// Unit: m/s
+        #[allow(non_snake_case)]
+        pub speed: Speed,
+        #[deprecated]
+        #[allow(non_snake_case)]
+        pub legacy: f64,
+    }
+
+    #[allow(dead_code, deprecated)]
+    impl Reading {
+
+        pub fn new(sensor: i32, speed: Speed, legacy: f64, ) -> Self {
+            Self {
+                sensor,
+                speed,
+                legacy,
+            }
+        }
+
+        pub fn sensor(&self) -> &i32 {
+            &self.sensor
+        }
+
+        pub fn set_sensor(&mut self, value: i32) {
+            self.sensor = value;
+        }
+
+        pub fn speed(&self) -> &Speed {
+            &self.speed
+        }
+
+        pub fn set_speed(&mut self, value: Speed) {
+            self.speed = value;
+        }
+
+        pub fn legacy(&self) -> &f64 {
+            &self.legacy
+        }
+
+        pub fn set_legacy(&mut self, value: f64) {
+            self.legacy = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls, deprecated)]
+    impl Default for Reading {
+        fn default() -> Self {
+            Self {
+                sensor: 0,
+                speed: 0.0,
+                legacy: 0.0,
+            }
+        }
+    }
+
+    #[allow(deprecated)]
+    impl omg_idl_rt::Extensible for Reading {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    #[allow(deprecated)]
+    impl omg_idl_rt::TypeName for Reading {
+        const TYPE_NAME: &'static str = "Sensors::Reading";
+        const REPOSITORY_ID: &'static str = "IDL:Sensors/Reading:1.0";
+    }
+
+    #[allow(dead_code, deprecated)]
+    impl Reading {
+        /// Member ID of `sensor`
+        pub const SENSOR_MEMBER_ID: u32 = 0;
+        /// Member ID of `speed`
+        pub const SPEED_MEMBER_ID: u32 = 1;
+        /// Member ID of `legacy`
+        pub const LEGACY_MEMBER_ID: u32 = 2;
+    }
+
+    #[allow(deprecated)]
+    impl omg_cdr::CdrEncode for Reading {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    #[allow(deprecated)]
+    impl omg_cdr::CdrDecode for Reading {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    #[allow(deprecated)]
+    impl omg_cdr::CdrStruct for Reading {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.sensor, writer);
+            omg_cdr::CdrEncode::encode(&self.speed, writer);
+            omg_cdr::CdrEncode::encode(&self.legacy, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                sensor: omg_cdr::CdrDecode::decode(reader)?,
+                speed: omg_cdr::CdrDecode::decode(reader)?,
+                legacy: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    #[allow(deprecated)]
+    impl omg_cdr::CdrBounds for Reading {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(24);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(24);
+    }
+    impl Reading {
+        pub fn is_fast(&self) -> bool {
+            self.speed > 10.0
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types, deprecated)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, PartialOrd, Eq, Ord)]
+    pub enum Mode {
+        #[default]
+        ACTIVE,
+        #[deprecated(note = "use ACTIVE")]
+        IDLE,
+    }
+
+    #[allow(non_camel_case_types)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct ModeError;
+
+    #[allow(deprecated)]
+    impl std::str::FromStr for Mode {
+        type Err = ModeError;
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            match value {
+                "ACTIVE" => Ok(Mode::ACTIVE),
+                "IDLE" => Ok(Mode::IDLE),
+                _ => Err(ModeError),
+            }
+        }
+    }
+
+    #[allow(deprecated)]
+    impl std::fmt::Display for Mode {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let enum_str = match self {
+                    Mode::ACTIVE => "ACTIVE",
+                    Mode::IDLE => "IDLE",
+            };
+            write!(f, "{enum_str}")
+        }
+    }
+
+    #[allow(deprecated)]
+    impl omg_idl_rt::Extensible for Mode {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    #[allow(deprecated)]
+    impl omg_idl_rt::TypeName for Mode {
+        const TYPE_NAME: &'static str = "Sensors::Mode";
+        const REPOSITORY_ID: &'static str = "IDL:Sensors/Mode:1.0";
+    }
+
+    #[allow(deprecated)]
+    impl omg_cdr::CdrEncode for Mode {
+        const PRIMITIVE: bool = true;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let value: u32 = match self {
+                Mode::ACTIVE => 0,
+                Mode::IDLE => 1,
+            };
+            omg_cdr::CdrEncode::encode(&value, writer);
+        }
+    }
+
+    #[allow(deprecated)]
+    impl omg_cdr::CdrDecode for Mode {
+        const PRIMITIVE: bool = true;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            match <u32 as omg_cdr::CdrDecode>::decode(reader)? {
+                0 => Ok(Mode::ACTIVE),
+                1 => Ok(Mode::IDLE),
+                value => Err(omg_cdr::CdrError::InvalidEnum(value)),
+            }
+        }
+    }
+
+    #[allow(deprecated)]
+    impl omg_cdr::CdrBounds for Mode {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(4);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(4);
+    }
+
+    #[allow(dead_code, non_camel_case_types, deprecated)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub enum Payload {
+        /// Numeric value
+        NUMERIC{ number: f64, },
+        #[deprecated]
+        TEXT{ text: String, },
+    }
+
+    #[allow(deprecated)]
+    impl Default for Payload {
+        fn default() -> Self {
+            Payload::NUMERIC { number: 0.0 }
+        }
+    }
+//
+// TODO custom de-/serializer
+//
+
+    #[allow(deprecated)]
+    impl omg_idl_rt::Extensible for Payload {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    #[allow(deprecated)]
+    impl omg_idl_rt::TypeName for Payload {
+        const TYPE_NAME: &'static str = "Sensors::Payload";
+        const REPOSITORY_ID: &'static str = "IDL:Sensors/Payload:1.0";
+    }
+
+    #[allow(dead_code, deprecated)]
+    impl Payload {
+        /// Member ID of `number`
+        pub const NUMBER_MEMBER_ID: u32 = 1;
+        /// Member ID of `text`
+        pub const TEXT_MEMBER_ID: u32 = 2;
+    }
+
+    #[allow(deprecated)]
+    impl omg_cdr::CdrEncode for Payload {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            match self {
+                Payload::NUMERIC { number } => writer.write_union(extensibility, &1_i32, Self::NUMBER_MEMBER_ID, number, omg_cdr::CdrEncode::encode),
+                Payload::TEXT { text } => writer.write_union(extensibility, &2_i32, Self::TEXT_MEMBER_ID, text, omg_cdr::CdrEncode::encode),
+            }
+        }
+    }
+
+    #[allow(deprecated)]
+    impl omg_cdr::CdrDecode for Payload {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            let extensibility = <Self as omg_idl_rt::Extensible>::EXTENSIBILITY;
+            reader.read_union(extensibility, |discriminator: i32, reader| {
+                Ok(match discriminator {
+                    1_i32 => Payload::NUMERIC { number: reader.read_branch(extensibility, Self::NUMBER_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    2_i32 => Payload::TEXT { text: reader.read_branch(extensibility, Self::TEXT_MEMBER_ID, omg_cdr::CdrDecode::decode)? },
+                    #[allow(unreachable_patterns)]
+                    _ => return Err(omg_cdr::CdrError::InvalidDiscriminator),
+                })
+            })
+        }
+    }
+
+    #[allow(deprecated)]
+    impl omg_cdr::CdrBounds for Payload {
+        const IS_BOUNDED: bool = false;
+        const MAX_XCDR1_SIZE: Option<usize> = None;
+        const MAX_XCDR2_SIZE: Option<usize> = None;
+    }
+
+    /// Highest sensor index
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const MAX_SENSOR: i32 = 8;
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const NUMERIC: i32 = 1;
+
+    #[allow(dead_code, non_upper_case_globals)]
+    pub const TEXT: i32 = 2;
+
+}
+// end of file
@@ -0,0 +1,956 @@
+
+#[allow(non_snake_case)]
+pub mod Telemetry {
+    use serde_derive::{Serialize, Deserialize};
+    use std::vec::Vec;
+
+    #[allow(dead_code, non_camel_case_types)]
+    pub type Samples = Vec<f64>;
+
+    #[allow(dead_code, non_camel_case_types)]
+    pub type Matrix = [[i32;3_usize];2_usize];
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, PartialOrd, Eq, Ord)]
+    pub enum Mode {
+        #[default]
+        IDLE,
+        ACTIVE,
+    }
+
+    #[allow(non_camel_case_types)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct ModeError;
+
+    impl std::str::FromStr for Mode {
+        type Err = ModeError;
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            match value {
+                "IDLE" => Ok(Mode::IDLE),
+                "ACTIVE" => Ok(Mode::ACTIVE),
+                _ => Err(ModeError),
+            }
+        }
+    }
+
+    impl std::fmt::Display for Mode {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let enum_str = match self {
+                    Mode::IDLE => "IDLE",
+                    Mode::ACTIVE => "ACTIVE",
+            };
+            write!(f, "{enum_str}")
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Mode {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Mode {
+        const TYPE_NAME: &'static str = "Telemetry::Mode";
+        const REPOSITORY_ID: &'static str = "IDL:Telemetry/Mode:1.0";
+    }
+
+    impl omg_cdr::CdrEncode for Mode {
+        const PRIMITIVE: bool = true;
+
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            let value: u32 = match self {
+                Mode::IDLE => 0,
+                Mode::ACTIVE => 1,
+            };
+            omg_cdr::CdrEncode::encode(&value, writer);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Mode {
+        const PRIMITIVE: bool = true;
+
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            match <u32 as omg_cdr::CdrDecode>::decode(reader)? {
+                0 => Ok(Mode::IDLE),
+                1 => Ok(Mode::ACTIVE),
+                value => Err(omg_cdr::CdrError::InvalidEnum(value)),
+            }
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Mode {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(4);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(4);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Header {
+        #[allow(non_snake_case)]
+        pub stamp: u64,
+        #[allow(non_snake_case)]
+        pub source: String,
+    }
+
+    #[allow(dead_code)]
+    impl Header {
+
+        pub fn new(stamp: u64, source: String, ) -> Self {
+            Self {
+                stamp,
+                source,
+            }
+        }
+
+        pub fn stamp(&self) -> &u64 {
+            &self.stamp
+        }
+
+        pub fn set_stamp(&mut self, value: u64) {
+            self.stamp = value;
+        }
+
+        pub fn source(&self) -> &String {
+            &self.source
+        }
+
+        pub fn set_source(&mut self, value: String) {
+            self.source = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Header {
+        fn default() -> Self {
+            Self {
+                stamp: 0,
+                source: String::new(),
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Header {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Final;
+    }
+
+    impl omg_idl_rt::TypeName for Header {
+        const TYPE_NAME: &'static str = "Telemetry::Header";
+        const REPOSITORY_ID: &'static str = "IDL:Telemetry/Header:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Header {
+        /// Member ID of `stamp`
+        pub const STAMP_MEMBER_ID: u32 = 0;
+        /// Member ID of `source`
+        pub const SOURCE_MEMBER_ID: u32 = 1;
+    }
+
+    impl omg_cdr::CdrEncode for Header {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Header {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Header {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.stamp, writer);
+            omg_cdr::CdrEncode::encode(&self.source, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                stamp: omg_cdr::CdrDecode::decode(reader)?,
+                source: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Header {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(29);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(29);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Clone)]
+    pub struct HeaderRef<'a> {
+        reader: omg_cdr::CdrReader<'a>,
+        #[allow(non_snake_case)]
+        stamp: u64,
+        #[allow(non_snake_case)]
+        source: &'a str,
+    }
+
+    #[allow(non_snake_case)]
+    impl<'a> HeaderRef<'a> {
+        pub fn stamp(&self) -> u64 {
+            self.stamp
+        }
+
+        pub fn source(&self) -> &'a str {
+            self.source
+        }
+
+        /// Decode the viewed `Header`
+        pub fn to_owned(&self) -> Result<Header, omg_cdr::CdrError> {
+            omg_cdr::CdrStruct::decode_members(&mut self.reader.clone())
+        }
+    }
+
+    impl<'a> omg_cdr::CdrStructView<'a> for HeaderRef<'a> {
+        type Owned = Header;
+
+        fn view_members(reader: &mut omg_cdr::CdrReader<'a>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                reader: reader.clone(),
+                stamp: omg_cdr::CdrView::view(reader)?,
+                source: omg_cdr::CdrView::view(reader)?,
+            })
+        }
+    }
+
+    impl std::fmt::Debug for HeaderRef<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct("HeaderRef")
+                .field("stamp", &self.stamp)
+                .field("source", &self.source)
+                .finish()
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Base {
+        #[allow(non_snake_case)]
+        pub version: i16,
+    }
+
+    #[allow(dead_code)]
+    impl Base {
+
+        pub fn new(version: i16, ) -> Self {
+            Self {
+                version,
+            }
+        }
+
+        pub fn version(&self) -> &i16 {
+            &self.version
+        }
+
+        pub fn set_version(&mut self, value: i16) {
+            self.version = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Base {
+        fn default() -> Self {
+            Self {
+                version: 0,
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Base {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Base {
+        const TYPE_NAME: &'static str = "Telemetry::Base";
+        const REPOSITORY_ID: &'static str = "IDL:Telemetry/Base:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Base {
+        /// Member ID of `version`
+        pub const VERSION_MEMBER_ID: u32 = 0;
+    }
+
+    impl omg_cdr::CdrEncode for Base {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Base {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Base {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.version, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                version: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Base {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(2);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(6);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Clone)]
+    pub struct BaseRef<'a> {
+        reader: omg_cdr::CdrReader<'a>,
+        #[allow(non_snake_case)]
+        version: i16,
+    }
+
+    #[allow(non_snake_case)]
+    impl<'a> BaseRef<'a> {
+        pub fn version(&self) -> i16 {
+            self.version
+        }
+
+        /// Decode the viewed `Base`
+        pub fn to_owned(&self) -> Result<Base, omg_cdr::CdrError> {
+            omg_cdr::CdrStruct::decode_members(&mut self.reader.clone())
+        }
+    }
+
+    impl<'a> omg_cdr::CdrStructView<'a> for BaseRef<'a> {
+        type Owned = Base;
+
+        fn view_members(reader: &mut omg_cdr::CdrReader<'a>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                reader: reader.clone(),
+                version: omg_cdr::CdrView::view(reader)?,
+            })
+        }
+    }
+
+    impl std::fmt::Debug for BaseRef<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct("BaseRef")
+                .field("version", &self.version)
+                .finish()
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Reading {
+        #[serde(flatten)]
+        pub base: Base,
+        #[allow(non_snake_case)]
+        pub header: Header,
+        #[allow(non_snake_case)]
+        pub flags: u8,
+        #[allow(non_snake_case)]
+        pub name: String,
+        #[allow(non_snake_case)]
+        pub samples: Samples,
+        #[allow(non_snake_case)]
+        pub payload: Vec<u8>,
+        #[allow(non_snake_case)]
+        pub matrix: Matrix,
+        #[allow(non_snake_case)]
+        pub mode: Mode,
+        #[allow(non_snake_case)]
+        pub label: String,
+        #[allow(non_snake_case)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub note: Option<String>,
+        #[allow(non_snake_case)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub origin: Option<Header>,
+        #[allow(non_snake_case)]
+        pub history: Vec<Header>,
+    }
+
+    #[allow(dead_code)]
+    impl Reading {
+        #[allow(clippy::too_many_arguments)]
+        pub fn new(base: Base, header: Header, flags: u8, name: String, samples: Samples, payload: Vec<u8>, matrix: Matrix, mode: Mode, label: String, history: Vec<Header>, ) -> Self {
+            Self {
+                base,
+                header,
+                flags,
+                name,
+                samples,
+                payload,
+                matrix,
+                mode,
+                label,
+                note: None,
+                origin: None,
+                history,
+            }
+        }
+
+        pub fn base(&self) -> &Base {
+            &self.base
+        }
+
+        pub fn set_base(&mut self, value: Base) {
+            self.base = value;
+        }
+
+        pub fn header(&self) -> &Header {
+            &self.header
+        }
+
+        pub fn set_header(&mut self, value: Header) {
+            self.header = value;
+        }
+
+        pub fn flags(&self) -> &u8 {
+            &self.flags
+        }
+
+        pub fn set_flags(&mut self, value: u8) {
+            self.flags = value;
+        }
+
+        pub fn name(&self) -> &String {
+            &self.name
+        }
+
+        pub fn set_name(&mut self, value: String) {
+            self.name = value;
+        }
+
+        pub fn samples(&self) -> &Samples {
+            &self.samples
+        }
+
+        pub fn set_samples(&mut self, value: Samples) {
+            self.samples = value;
+        }
+
+        pub fn payload(&self) -> &Vec<u8> {
+            &self.payload
+        }
+
+        pub fn set_payload(&mut self, value: Vec<u8>) {
+            self.payload = value;
+        }
+
+        pub fn matrix(&self) -> &Matrix {
+            &self.matrix
+        }
+
+        pub fn set_matrix(&mut self, value: Matrix) {
+            self.matrix = value;
+        }
+
+        pub fn mode(&self) -> &Mode {
+            &self.mode
+        }
+
+        pub fn set_mode(&mut self, value: Mode) {
+            self.mode = value;
+        }
+
+        pub fn label(&self) -> &String {
+            &self.label
+        }
+
+        pub fn set_label(&mut self, value: String) {
+            self.label = value;
+        }
+
+        pub fn note(&self) -> Option<&String> {
+            self.note.as_ref()
+        }
+
+        pub fn set_note(&mut self, value: Option<String>) {
+            self.note = value;
+        }
+
+        pub fn origin(&self) -> Option<&Header> {
+            self.origin.as_ref()
+        }
+
+        pub fn set_origin(&mut self, value: Option<Header>) {
+            self.origin = value;
+        }
+
+        pub fn history(&self) -> &Vec<Header> {
+            &self.history
+        }
+
+        pub fn set_history(&mut self, value: Vec<Header>) {
+            self.history = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Reading {
+        fn default() -> Self {
+            Self {
+                base: Default::default(),
+                header: Default::default(),
+                flags: 0,
+                name: String::new(),
+                samples: Vec::new(),
+                payload: Vec::new(),
+                matrix: std::array::from_fn(|_| std::array::from_fn(|_| 0)),
+                mode: Default::default(),
+                label: String::new(),
+                note: None,
+                origin: None,
+                history: Vec::new(),
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Reading {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Reading {
+        const TYPE_NAME: &'static str = "Telemetry::Reading";
+        const REPOSITORY_ID: &'static str = "IDL:Telemetry/Reading:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Reading {
+        /// Member ID of `header`
+        pub const HEADER_MEMBER_ID: u32 = 1;
+        /// Member ID of `flags`
+        pub const FLAGS_MEMBER_ID: u32 = 2;
+        /// Member ID of `name`
+        pub const NAME_MEMBER_ID: u32 = 3;
+        /// Member ID of `samples`
+        pub const SAMPLES_MEMBER_ID: u32 = 4;
+        /// Member ID of `payload`
+        pub const PAYLOAD_MEMBER_ID: u32 = 5;
+        /// Member ID of `matrix`
+        pub const MATRIX_MEMBER_ID: u32 = 6;
+        /// Member ID of `mode`
+        pub const MODE_MEMBER_ID: u32 = 7;
+        /// Member ID of `label`
+        pub const LABEL_MEMBER_ID: u32 = 8;
+        /// Member ID of `note`
+        pub const NOTE_MEMBER_ID: u32 = 9;
+        /// Member ID of `origin`
+        pub const ORIGIN_MEMBER_ID: u32 = 10;
+        /// Member ID of `history`
+        pub const HISTORY_MEMBER_ID: u32 = 11;
+    }
+
+    impl omg_cdr::CdrEncode for Reading {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Reading {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Reading {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrStruct::encode_members(&self.base, writer);
+            omg_cdr::CdrEncode::encode(&self.header, writer);
+            omg_cdr::CdrEncode::encode(&self.flags, writer);
+            omg_cdr::CdrEncode::encode(&self.name, writer);
+            omg_cdr::CdrEncode::encode(&self.samples, writer);
+            omg_cdr::CdrEncode::encode(&self.payload, writer);
+            omg_cdr::CdrEncode::encode(&self.matrix, writer);
+            omg_cdr::CdrEncode::encode(&self.mode, writer);
+            <omg_cdr::WideString as omg_cdr::Codec<_>>::encode(&self.label, writer);
+            writer.write_optional(Self::NOTE_MEMBER_ID, &self.note, omg_cdr::CdrEncode::encode);
+            writer.write_optional(Self::ORIGIN_MEMBER_ID, &self.origin, omg_cdr::CdrEncode::encode);
+            omg_cdr::CdrEncode::encode(&self.history, writer);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                base: omg_cdr::CdrStruct::decode_members(reader)?,
+                header: omg_cdr::CdrDecode::decode(reader)?,
+                flags: omg_cdr::CdrDecode::decode(reader)?,
+                name: omg_cdr::CdrDecode::decode(reader)?,
+                samples: omg_cdr::CdrDecode::decode(reader)?,
+                payload: omg_cdr::CdrDecode::decode(reader)?,
+                matrix: omg_cdr::CdrDecode::decode(reader)?,
+                mode: omg_cdr::CdrDecode::decode(reader)?,
+                label: <omg_cdr::WideString as omg_cdr::Codec<_>>::decode(reader)?,
+                note: reader.read_optional(Self::NOTE_MEMBER_ID, omg_cdr::CdrDecode::decode)?,
+                origin: reader.read_optional(Self::ORIGIN_MEMBER_ID, omg_cdr::CdrDecode::decode)?,
+                history: omg_cdr::CdrDecode::decode(reader)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Reading {
+        const IS_BOUNDED: bool = false;
+        const MAX_XCDR1_SIZE: Option<usize> = None;
+        const MAX_XCDR2_SIZE: Option<usize> = None;
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Clone)]
+    pub struct ReadingRef<'a> {
+        reader: omg_cdr::CdrReader<'a>,
+        #[allow(non_snake_case)]
+        base: BaseRef<'a>,
+        #[allow(non_snake_case)]
+        header: HeaderRef<'a>,
+        #[allow(non_snake_case)]
+        flags: u8,
+        #[allow(non_snake_case)]
+        name: &'a str,
+        #[allow(non_snake_case)]
+        samples: omg_cdr::CdrSlice<'a, f64>,
+        #[allow(non_snake_case)]
+        payload: omg_cdr::CdrSlice<'a, u8>,
+        #[allow(non_snake_case)]
+        matrix: omg_cdr::CdrSlice<'a, i32>,
+        #[allow(non_snake_case)]
+        mode: omg_cdr::Lazy<'a, Mode>,
+        #[allow(non_snake_case)]
+        label: omg_cdr::Lazy<'a, String>,
+        #[allow(non_snake_case)]
+        note: Option<&'a str>,
+        #[allow(non_snake_case)]
+        origin: Option<HeaderRef<'a>>,
+        #[allow(non_snake_case)]
+        history: omg_cdr::Lazy<'a, Vec<Header>>,
+    }
+
+    #[allow(non_snake_case)]
+    impl<'a> ReadingRef<'a> {
+        pub fn base(&self) -> &BaseRef<'a> {
+            &self.base
+        }
+
+        pub fn header(&self) -> &HeaderRef<'a> {
+            &self.header
+        }
+
+        pub fn flags(&self) -> u8 {
+            self.flags
+        }
+
+        pub fn name(&self) -> &'a str {
+            self.name
+        }
+
+        pub fn samples(&self) -> omg_cdr::CdrSlice<'a, f64> {
+            self.samples
+        }
+
+        pub fn payload(&self) -> omg_cdr::CdrSlice<'a, u8> {
+            self.payload
+        }
+
+        pub fn matrix(&self) -> omg_cdr::CdrSlice<'a, i32> {
+            self.matrix
+        }
+
+        pub fn mode(&self) -> Result<Mode, omg_cdr::CdrError> {
+            self.mode.get()
+        }
+
+        pub fn label(&self) -> Result<String, omg_cdr::CdrError> {
+            self.label.get()
+        }
+
+        pub fn note(&self) -> Option<&'a str> {
+            self.note
+        }
+
+        pub fn origin(&self) -> Option<&HeaderRef<'a>> {
+            self.origin.as_ref()
+        }
+
+        pub fn history(&self) -> Result<Vec<Header>, omg_cdr::CdrError> {
+            self.history.get()
+        }
+
+        /// Decode the viewed `Reading`
+        pub fn to_owned(&self) -> Result<Reading, omg_cdr::CdrError> {
+            omg_cdr::CdrStruct::decode_members(&mut self.reader.clone())
+        }
+    }
+
+    impl<'a> omg_cdr::CdrStructView<'a> for ReadingRef<'a> {
+        type Owned = Reading;
+
+        fn view_members(reader: &mut omg_cdr::CdrReader<'a>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                reader: reader.clone(),
+                base: omg_cdr::CdrStructView::view_members(reader)?,
+                header: omg_cdr::CdrView::view(reader)?,
+                flags: omg_cdr::CdrView::view(reader)?,
+                name: omg_cdr::CdrView::view(reader)?,
+                samples: omg_cdr::CdrSlice::read_sequence(reader)?,
+                payload: omg_cdr::CdrSlice::read_sequence(reader)?,
+                matrix: omg_cdr::CdrSlice::read_array::<6>(reader)?,
+                mode: omg_cdr::Lazy::read(reader, omg_cdr::CdrDecode::decode)?,
+                label: omg_cdr::Lazy::read(reader, <omg_cdr::WideString as omg_cdr::Codec<_>>::decode)?,
+                note: reader.read_optional(Reading::NOTE_MEMBER_ID, omg_cdr::CdrView::view)?,
+                origin: reader.read_optional(Reading::ORIGIN_MEMBER_ID, omg_cdr::CdrView::view)?,
+                history: omg_cdr::Lazy::read(reader, omg_cdr::CdrDecode::decode)?,
+            })
+        }
+    }
+
+    impl std::fmt::Debug for ReadingRef<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct("ReadingRef")
+                .field("base", &self.base)
+                .field("header", &self.header)
+                .field("flags", &self.flags)
+                .field("name", &self.name)
+                .field("samples", &self.samples)
+                .field("payload", &self.payload)
+                .field("matrix", &self.matrix)
+                .field("mode", &self.mode)
+                .field("label", &self.label)
+                .field("note", &self.note)
+                .field("origin", &self.origin)
+                .field("history", &self.history)
+                .finish()
+        }
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Settings {
+        #[allow(non_snake_case)]
+        pub rate: i32,
+    }
+
+    #[allow(dead_code)]
+    impl Settings {
+
+        pub fn new(rate: i32, ) -> Self {
+            Self {
+                rate,
+            }
+        }
+
+        pub fn rate(&self) -> &i32 {
+            &self.rate
+        }
+
+        pub fn set_rate(&mut self, value: i32) {
+            self.rate = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Settings {
+        fn default() -> Self {
+            Self {
+                rate: 0,
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Settings {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Mutable;
+    }
+
+    impl omg_idl_rt::TypeName for Settings {
+        const TYPE_NAME: &'static str = "Telemetry::Settings";
+        const REPOSITORY_ID: &'static str = "IDL:Telemetry/Settings:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Settings {
+        /// Member ID of `rate`
+        pub const RATE_MEMBER_ID: u32 = 0;
+    }
+
+    impl omg_cdr::CdrEncode for Settings {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Settings {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Settings {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_member(Self::RATE_MEMBER_ID, false, &self.rate, omg_cdr::CdrEncode::encode);
+        }
+
+        fn decode_member(&mut self, id: u32, reader: &mut omg_cdr::CdrReader<'_>) -> Result<bool, omg_cdr::CdrError> {
+            match id {
+                Self::RATE_MEMBER_ID => self.rate = omg_cdr::CdrDecode::decode(reader)?,
+                _ => return Ok(false),
+            }
+            Ok(true)
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Settings {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(12);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(12);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct Config {
+        #[allow(non_snake_case)]
+        pub settings: Settings,
+        #[allow(non_snake_case)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub mode: Option<Mode>,
+    }
+
+    #[allow(dead_code)]
+    impl Config {
+
+        pub fn new(settings: Settings, ) -> Self {
+            Self {
+                settings,
+                mode: None,
+            }
+        }
+
+        pub fn settings(&self) -> &Settings {
+            &self.settings
+        }
+
+        pub fn set_settings(&mut self, value: Settings) {
+            self.settings = value;
+        }
+
+        pub fn mode(&self) -> Option<&Mode> {
+            self.mode.as_ref()
+        }
+
+        pub fn set_mode(&mut self, value: Option<Mode>) {
+            self.mode = value;
+        }
+
+    }
+
+    #[allow(clippy::derivable_impls)]
+    impl Default for Config {
+        fn default() -> Self {
+            Self {
+                settings: Default::default(),
+                mode: None,
+            }
+        }
+    }
+
+    impl omg_idl_rt::Extensible for Config {
+        const EXTENSIBILITY: omg_idl_rt::Extensibility = omg_idl_rt::Extensibility::Appendable;
+    }
+
+    impl omg_idl_rt::TypeName for Config {
+        const TYPE_NAME: &'static str = "Telemetry::Config";
+        const REPOSITORY_ID: &'static str = "IDL:Telemetry/Config:1.0";
+    }
+
+    #[allow(dead_code)]
+    impl Config {
+        /// Member ID of `settings`
+        pub const SETTINGS_MEMBER_ID: u32 = 0;
+        /// Member ID of `mode`
+        pub const MODE_MEMBER_ID: u32 = 1;
+    }
+
+    impl omg_cdr::CdrEncode for Config {
+        fn encode(&self, writer: &mut omg_cdr::CdrWriter) {
+            writer.write_struct(self);
+        }
+    }
+
+    impl omg_cdr::CdrDecode for Config {
+        fn decode(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            reader.read_struct()
+        }
+    }
+
+    impl omg_cdr::CdrStruct for Config {
+        fn encode_members(&self, writer: &mut omg_cdr::CdrWriter) {
+            omg_cdr::CdrEncode::encode(&self.settings, writer);
+            writer.write_optional(Self::MODE_MEMBER_ID, &self.mode, omg_cdr::CdrEncode::encode);
+        }
+
+        fn decode_members(reader: &mut omg_cdr::CdrReader<'_>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                settings: omg_cdr::CdrDecode::decode(reader)?,
+                mode: reader.read_optional(Self::MODE_MEMBER_ID, omg_cdr::CdrDecode::decode)?,
+            })
+        }
+    }
+
+    impl omg_cdr::CdrBounds for Config {
+        const IS_BOUNDED: bool = true;
+        const MAX_XCDR1_SIZE: Option<usize> = Some(20);
+        const MAX_XCDR2_SIZE: Option<usize> = Some(24);
+    }
+
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Clone)]
+    pub struct ConfigRef<'a> {
+        reader: omg_cdr::CdrReader<'a>,
+        #[allow(non_snake_case)]
+        settings: omg_cdr::Lazy<'a, Settings>,
+        #[allow(non_snake_case)]
+        mode: Option<omg_cdr::Lazy<'a, Mode>>,
+    }
+
+    #[allow(non_snake_case)]
+    impl<'a> ConfigRef<'a> {
+        pub fn settings(&self) -> Result<Settings, omg_cdr::CdrError> {
+            self.settings.get()
+        }
+
+        pub fn mode(&self) -> Result<Option<Mode>, omg_cdr::CdrError> {
+            self.mode.as_ref().map(omg_cdr::Lazy::get).transpose()
+        }
+
+        /// Decode the viewed `Config`
+        pub fn to_owned(&self) -> Result<Config, omg_cdr::CdrError> {
+            omg_cdr::CdrStruct::decode_members(&mut self.reader.clone())
+        }
+    }
+
+    impl<'a> omg_cdr::CdrStructView<'a> for ConfigRef<'a> {
+        type Owned = Config;
+
+        fn view_members(reader: &mut omg_cdr::CdrReader<'a>) -> Result<Self, omg_cdr::CdrError> {
+            Ok(Self {
+                reader: reader.clone(),
+                settings: omg_cdr::Lazy::read(reader, omg_cdr::CdrDecode::decode)?,
+                mode: reader.read_optional(Config::MODE_MEMBER_ID, |reader| omg_cdr::Lazy::read(reader, omg_cdr::CdrDecode::decode))?,
+            })
+        }
+    }
+
+    impl std::fmt::Debug for ConfigRef<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct("ConfigRef")
+                .field("settings", &self.settings)
+                .field("mode", &self.mode)
+                .finish()
+        }
+    }
+
+}
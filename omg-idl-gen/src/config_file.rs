@@ -0,0 +1,81 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+use omg_idl_code_gen::{AnnotationMapping, ByteMapping, Configuration, ParameterId};
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{Error, ErrorKind},
+    path::Path,
+};
+
+/// Settings read from a TOML configuration file, i.e.
+///
+/// ```toml
+/// newtypes = ["DDS::DomainId_t"]
+///
+/// [annotations]
+/// rust_derive = { derive = "{value}" }
+/// serde_rename = { attribute = "#[serde(rename = \"{value}\")]" }
+///
+/// [parameter_lists."Discovery::ParticipantData"]
+/// guid = "PID_PARTICIPANT_GUID"
+/// user_data = 0x2c
+///
+/// [parameter_lists."Discovery::Parameter"]
+///
+/// [bytes]
+/// "RTPS::Data::serializedData" = "bytes"
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConfigFile {
+    /// Rust attributes and derives of user-defined annotations, by annotation name
+    #[serde(default)]
+    annotations: BTreeMap<String, AnnotationMapping>,
+    /// Parameter IDs of the members of types encoded as RTPS parameter list, by fully
+    /// qualified type name. Unions are listed without IDs.
+    #[serde(default)]
+    parameter_lists: BTreeMap<String, BTreeMap<String, ParameterId>>,
+    /// Fully qualified names of the typedefs generated as newtype structs
+    #[serde(default)]
+    newtypes: Vec<String>,
+    /// Byte mappings of the octet sequences and arrays of single struct members and union
+    /// elements, by fully qualified member name
+    #[serde(default)]
+    bytes: BTreeMap<String, ByteMapping>,
+}
+
+impl ConfigFile {
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let text = fs::read_to_string(path)?;
+        toml::from_str(&text).map_err(|err| Error::new(ErrorKind::InvalidData, err))
+    }
+
+    /// Add the settings of the file to `config`
+    pub fn apply(self, config: Configuration) -> Configuration {
+        let config = self
+            .annotations
+            .iter()
+            .fold(config, |config, (name, mapping)| {
+                config.with_annotation_mapping(name, mapping.clone())
+            });
+        let config = self
+            .parameter_lists
+            .into_iter()
+            .fold(config, |config, (type_name, ids)| {
+                config.with_parameter_list(&type_name, ids.into_iter().collect())
+            });
+        let config = self
+            .newtypes
+            .iter()
+            .fold(config, |config, type_name| config.with_newtype(type_name));
+        self.bytes
+            .into_iter()
+            .fold(config, |config, (member, mapping)| {
+                config.with_member_byte_mapping(&member, mapping)
+            })
+    }
+}
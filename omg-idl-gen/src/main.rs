@@ -1,5 +1,11 @@
+mod config_file;
+
 use clap::{arg, command, value_parser, ArgAction};
-use omg_idl_code_gen::{generate_with_search_path, Configuration};
+use config_file::ConfigFile;
+use omg_idl_code_gen::{
+    generate_c_header_with_search_path, generate_with_search_path, ByteMapping, CharMapping,
+    Configuration, Derive, SerdeSupport, WideCharMapping,
+};
 use std::{
     fs::File,
     io::{stdout, Error, ErrorKind},
@@ -30,6 +36,169 @@ fn main() -> Result<(), std::io::Error> {
         .required(false)
         .value_parser(value_parser!(PathBuf)),
     )
+    .arg(
+        arg!(
+            -c --config <FILE> "Read settings like annotation mappings from a TOML 'file'."
+        )
+        .required(false)
+        .value_parser(value_parser!(PathBuf)),
+    )
+    .arg(
+        arg!(
+            --snake_case_members "Convert struct members and union elements to snake_case"
+        )
+        .required(false)
+        .action(ArgAction::SetTrue)
+    )
+    .arg(
+        arg!(
+            --camel_case_types "Convert types and enum/union variants to UpperCamelCase"
+        )
+        .required(false)
+        .action(ArgAction::SetTrue)
+    )
+    .arg(
+        arg!(
+            --screaming_case_constants "Convert constants to SCREAMING_SNAKE_CASE"
+        )
+        .required(false)
+        .action(ArgAction::SetTrue)
+    )
+    .arg(
+        arg!(
+            --rust_naming "Apply all Rust naming conventions, IDL names are kept on the wire"
+        )
+        .required(false)
+        .action(ArgAction::SetTrue)
+    )
+    .arg(
+        arg!(
+            --zero_copy_views "Generate a borrowed view FooRef<'a> of every final and appendable struct Foo"
+        )
+        .required(false)
+        .action(ArgAction::SetTrue)
+    )
+    .arg(
+        arg!(
+            --repr_c "Generate #[repr(C)] types with bytemuck derives for all fixed-size types"
+        )
+        .required(false)
+        .action(ArgAction::SetTrue)
+    )
+    .arg(
+        arg!(
+            --c_header <FILE> "Write a C header with the layouts of the #[repr(C)] types to 'file', implies --repr_c"
+        )
+        .required(false)
+        .value_parser(value_parser!(PathBuf)),
+    )
+    .arg(
+        arg!(
+            --no_std "Generate core and alloc paths only, for #![no_std] crates declaring extern crate alloc"
+        )
+        .required(false)
+        .action(ArgAction::SetTrue)
+    )
+    .arg(
+        arg!(
+            --heapless "Map bounded strings and sequences to heapless::String and heapless::Vec"
+        )
+        .required(false)
+        .action(ArgAction::SetTrue)
+    )
+    .arg(
+        arg!(
+            --serde_feature <FEATURE> "Derive Serialize and Deserialize only if the cargo feature 'feature' is enabled"
+        )
+        .required(false)
+        .conflicts_with("no_serde"),
+    )
+    .arg(
+        arg!(
+            --no_serde "Generate no serde derives and attributes"
+        )
+        .required(false)
+        .action(ArgAction::SetTrue)
+    )
+    .arg(
+        arg!(
+            --derive <DERIVES> "Comma separated derives among Copy, PartialEq, Eq, Hash, PartialOrd and Ord, added to all types supporting them"
+        )
+        .required(false)
+        .value_delimiter(',')
+        .action(ArgAction::Append)
+        .value_parser(value_parser!(Derive)),
+    )
+    .arg(
+        arg!(
+            --newtypes "Generate every typedef as newtype struct instead of a type alias"
+        )
+        .required(false)
+        .action(ArgAction::SetTrue)
+    )
+    .arg(
+        arg!(
+            --newtype <TYPE> "Generate the typedef 'type' (fully qualified, i.e. DDS::DomainId_t) as newtype struct"
+        )
+        .required(false)
+        .action(ArgAction::Append),
+    )
+    .arg(
+        arg!(
+            --char <MAPPING> "Rust type of char: char, u8 or latin1 (omg_idl_rt::Latin1)"
+        )
+        .required(false)
+        .value_parser(value_parser!(CharMapping)),
+    )
+    .arg(
+        arg!(
+            --wchar <MAPPING> "Rust types of wchar and wstring: char (char and String) or utf16 (u16 and omg_idl_rt::WString)"
+        )
+        .required(false)
+        .value_parser(value_parser!(WideCharMapping)),
+    )
+    .arg(
+        arg!(
+            --native_f128 "Map long double to the unstable f128 instead of omg_idl_rt::F128, requires a nightly toolchain"
+        )
+        .required(false)
+        .action(ArgAction::SetTrue)
+    )
+    .arg(
+        arg!(
+            --bytes <MAPPING> "Rust type of sequence<octet>: vec (Vec<u8>), bytebuf (omg_idl_rt::ByteBuf) or bytes (bytes::Bytes). Octet arrays are serialized by serde_bytes unless vec"
+        )
+        .required(false)
+        .value_parser(value_parser!(ByteMapping)),
+    )
+    .arg(
+        arg!(
+            --builders "Generate a builder FooBuilder with chainable setters per struct Foo"
+        )
+        .required(false)
+        .action(ArgAction::SetTrue)
+    )
+    .arg(
+        arg!(
+            --no_accessors "Generate no getters and setters of struct members"
+        )
+        .required(false)
+        .action(ArgAction::SetTrue)
+    )
+    .arg(
+        arg!(
+            --reflection "Implement omg_idl_rt::Reflect, type names, member descriptors and field access by path"
+        )
+        .required(false)
+        .action(ArgAction::SetTrue)
+    )
+    .arg(
+        arg!(
+            --type_objects "Implement omg_idl_rt::TypeObjects, the minimal and complete XTypes TypeObjects"
+        )
+        .required(false)
+        .action(ArgAction::SetTrue)
+    )
     .arg(
         arg!(
             [idl_file] "IDL File to parse"
@@ -46,7 +215,60 @@ fn main() -> Result<(), std::io::Error> {
         .get_one::<PathBuf>("idl_file")
         .expect("idl_file is required");
 
-    let config = Configuration::new(search_path, idl_file, matches.get_flag("verbose"));
+    let rust_naming = matches.get_flag("rust_naming");
+    let config = Configuration::new(search_path, idl_file, matches.get_flag("verbose"))
+        .with_snake_case_members(rust_naming || matches.get_flag("snake_case_members"))
+        .with_camel_case_types(rust_naming || matches.get_flag("camel_case_types"))
+        .with_screaming_case_constants(rust_naming || matches.get_flag("screaming_case_constants"))
+        .with_zero_copy_views(matches.get_flag("zero_copy_views"))
+        .with_repr_c(matches.get_flag("repr_c") || matches.contains_id("c_header"))
+        .with_no_std(matches.get_flag("no_std"))
+        .with_heapless(matches.get_flag("heapless"))
+        .with_serde(match matches.get_one::<String>("serde_feature") {
+            Some(feature) => SerdeSupport::Feature(feature.clone()),
+            None if matches.get_flag("no_serde") => SerdeSupport::Never,
+            None => SerdeSupport::Always,
+        })
+        .with_derives(
+            matches
+                .get_many::<Derive>("derive")
+                .into_iter()
+                .flatten()
+                .copied(),
+        )
+        .with_newtype_typedefs(matches.get_flag("newtypes"))
+        .with_native_f128(matches.get_flag("native_f128"))
+        .with_char_mapping(
+            matches
+                .get_one::<CharMapping>("char")
+                .copied()
+                .unwrap_or_default(),
+        )
+        .with_wide_char_mapping(
+            matches
+                .get_one::<WideCharMapping>("wchar")
+                .copied()
+                .unwrap_or_default(),
+        )
+        .with_byte_mapping(
+            matches
+                .get_one::<ByteMapping>("bytes")
+                .copied()
+                .unwrap_or_default(),
+        )
+        .with_builders(matches.get_flag("builders"))
+        .with_accessors(!matches.get_flag("no_accessors"))
+        .with_reflection(matches.get_flag("reflection"))
+        .with_type_objects(matches.get_flag("type_objects"));
+    let config = matches
+        .get_many::<String>("newtype")
+        .into_iter()
+        .flatten()
+        .fold(config, |config, type_name| config.with_newtype(type_name));
+    let config = match matches.get_one::<PathBuf>("config") {
+        Some(config_file) => ConfigFile::load(config_file)?.apply(config),
+        None => config,
+    };
 
     let result = match matches.get_one::<PathBuf>("output_file") {
         Some(outfile) => {
@@ -55,6 +277,13 @@ fn main() -> Result<(), std::io::Error> {
         }
         _ => generate_with_search_path(&mut stdout(), &config),
     };
+    let result = match (result, matches.get_one::<PathBuf>("c_header")) {
+        (Ok(_), Some(header_file)) => {
+            let mut hf = File::create(header_file)?;
+            generate_c_header_with_search_path(&mut hf, &config)
+        }
+        (result, _) => result,
+    };
 
     match result {
         Ok(_) => Ok(()),
@@ -67,20 +296,28 @@ fn main() -> Result<(), std::io::Error> {
 
 #[cfg(test)]
 mod tests {
-    use omg_idl_code_gen::{generate_with_search_path, Configuration};
+    use super::ConfigFile;
+    use omg_idl_code_gen::{
+        generate_c_header_with_search_path, generate_with_search_path, ByteMapping, CharMapping,
+        Configuration, Derive, ParameterId, SerdeSupport, WideCharMapping,
+    };
     use std::{
+        collections::HashMap,
         fs::File,
         io::{Read, Seek, SeekFrom, Write},
         path::Path,
         str,
     };
     use tempfile::{Builder, NamedTempFile};
-    use trybuild;
+
+    /// Adjusts the default configuration of a test vector
+    type Configure = fn(Configuration) -> Configuration;
 
     #[test]
     fn expected_mappings() {
         let test_dirs = [
             "files/test-vectors/const_str/",
+            "files/test-vectors/const_wide/",
             "files/test-vectors/double_module_depth/",
             "files/test-vectors/module_use_diff_module/",
             "files/test-vectors/typedef_long/",
@@ -114,6 +351,96 @@ mod tests {
             "files/test-vectors/const_op_mod",
             "files/test-vectors/include_directive/",
             "files/test-vectors/union_members",
+            "files/test-vectors/annotations",
+            "files/test-vectors/optional_members",
+            "files/test-vectors/key_members",
+            "files/test-vectors/default_values",
+            "files/test-vectors/extensibility",
+            "files/test-vectors/member_ids",
+            "files/test-vectors/verbatim_doc",
+            "files/test-vectors/cdr",
+            "files/test-vectors/bounds",
+            "files/test-vectors/zero_copy",
+            "files/test-vectors/long_double",
+            "files/test-vectors/repository_ids",
+        ];
+
+        // Test vectors requiring a non default configuration
+        let configured_test_dirs: [(&str, Configure); 13] = [
+            ("files/test-vectors/rust_naming/", |config| {
+                config.with_rust_naming(true)
+            }),
+            ("files/test-vectors/custom_annotations/", |config| {
+                ConfigFile::load(Path::new(
+                    "files/test-vectors/custom_annotations/config.toml",
+                ))
+                .unwrap()
+                .apply(config)
+            }),
+            ("files/test-vectors/parameter_list/", |config| {
+                ConfigFile::load(Path::new("files/test-vectors/parameter_list/config.toml"))
+                    .unwrap()
+                    .apply(config)
+            }),
+            ("files/test-vectors/repr_c/", |config| {
+                config.with_repr_c(true)
+            }),
+            ("files/test-vectors/derives/", |config| {
+                config
+                    .with_derives(Derive::ALL)
+                    .with_serde(SerdeSupport::Feature("serde".to_owned()))
+            }),
+            ("files/test-vectors/no_serde/", |config| {
+                config
+                    .with_rust_naming(true)
+                    .with_serde(SerdeSupport::Never)
+            }),
+            ("files/test-vectors/newtypes/", |config| {
+                ConfigFile::load(Path::new("files/test-vectors/newtypes/config.toml"))
+                    .unwrap()
+                    .apply(config)
+            }),
+            ("files/test-vectors/char_octet/", |config| {
+                config
+                    .with_char_mapping(CharMapping::Octet)
+                    .with_wide_char_mapping(WideCharMapping::Utf16)
+                    .with_repr_c(true)
+            }),
+            ("files/test-vectors/char_latin1/", |config| {
+                config
+                    .with_char_mapping(CharMapping::Latin1)
+                    .with_wide_char_mapping(WideCharMapping::Utf16)
+            }),
+            ("files/test-vectors/bytes/", |config| {
+                ConfigFile::load(Path::new("files/test-vectors/bytes/config.toml"))
+                    .unwrap()
+                    .apply(config.with_byte_mapping(ByteMapping::ByteBuf))
+            }),
+            ("files/test-vectors/builders/", |config| {
+                config.with_builders(true).with_accessors(false)
+            }),
+            ("files/test-vectors/reflection/", |config| {
+                config.with_reflection(true).with_newtype_typedefs(true)
+            }),
+            ("files/test-vectors/type_objects/", |config| {
+                config
+                    .with_type_objects(true)
+                    .with_newtype("Objects::Count")
+            }),
+        ];
+
+        // Test vectors of #![no_std] crates, the heapless one without alloc
+        let no_std_test_dirs: [(&str, Configure, &str); 2] = [
+            (
+                "files/test-vectors/no_std/",
+                |config| config.with_no_std(true),
+                "#![no_std]\nextern crate alloc;\nextern crate std;\n",
+            ),
+            (
+                "files/test-vectors/heapless/",
+                |config| config.with_no_std(true).with_heapless(true),
+                "#![no_std]\nextern crate std;\n",
+            ),
         ];
 
         // TestCases must go out of scope before tmp_file goes out of scope
@@ -121,17 +448,331 @@ mod tests {
         let mut test_files: Vec<NamedTempFile> = Vec::new();
         {
             let t = trybuild::TestCases::new();
-            for test_dir in test_dirs {
+            let test_dirs = test_dirs
+                .into_iter()
+                .map(|test_dir| (test_dir, (|config| config) as Configure))
+                .chain(configured_test_dirs)
+                .map(|(test_dir, configure)| (test_dir, configure, ""))
+                .chain(no_std_test_dirs);
+            for (test_dir, configure, crate_header) in test_dirs {
                 println!("Testing directory: {test_dir}");
                 let mut tmp_file = Builder::new().suffix(".rs").tempfile().unwrap();
-                testvector_verify(test_dir, tmp_file.as_file_mut());
+                testvector_verify(test_dir, configure, crate_header, tmp_file.as_file_mut());
                 t.pass(tmp_file.path());
                 test_files.push(tmp_file);
             }
         }
     }
 
-    fn testvector_verify(testvector: &str, tmp_file: &mut File) {
+    #[test]
+    fn rust_naming_collision() {
+        let config = Configuration::new(
+            Path::new("files/test-vectors/rust_naming_collision/"),
+            Path::new("input.idl"),
+            false,
+        );
+        let mut generated = Vec::new();
+        assert!(generate_with_search_path(&mut generated, &config).is_ok());
+
+        let config = config.with_rust_naming(true);
+        let mut generated = Vec::new();
+        assert!(generate_with_search_path(&mut generated, &config).is_err());
+    }
+
+    #[test]
+    fn invalid_annotations() {
+        for idl_file in [
+            "target.idl",
+            "parameter.idl",
+            "value.idl",
+            "missing.idl",
+            "declared.idl",
+        ] {
+            let config = Configuration::new(
+                Path::new("files/test-vectors/annotation_invalid/"),
+                Path::new(idl_file),
+                false,
+            );
+            let mut generated = Vec::new();
+            assert!(
+                generate_with_search_path(&mut generated, &config).is_err(),
+                "{idl_file} must be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_annotation_mappings() {
+        let test_dir = Path::new("files/test-vectors/annotation_mapping_invalid/");
+        for idl_file in ["derive.idl", "parameter.idl"] {
+            let config = ConfigFile::load(&test_dir.join("config.toml"))
+                .unwrap()
+                .apply(Configuration::new(test_dir, Path::new(idl_file), false));
+            let mut generated = Vec::new();
+            assert!(
+                generate_with_search_path(&mut generated, &config).is_err(),
+                "{idl_file} must be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_keys() {
+        for idl_file in ["both.idl", "union.idl", "path.idl", "optional.idl"] {
+            let config = Configuration::new(
+                Path::new("files/test-vectors/key_invalid/"),
+                Path::new(idl_file),
+                false,
+            );
+            let mut generated = Vec::new();
+            assert!(
+                generate_with_search_path(&mut generated, &config).is_err(),
+                "{idl_file} must be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_newtypes() {
+        let generate = |type_name| {
+            let config = Configuration::new(
+                Path::new("files/test-vectors/newtype_invalid/"),
+                Path::new("input.idl"),
+                false,
+            )
+            .with_newtype(type_name);
+            generate_with_search_path(&mut Vec::new(), &config)
+        };
+        assert!(generate("Foo::Id_t").is_ok());
+        for type_name in ["Foo::Bar", "Foo::BarAlias", "Foo::Missing"] {
+            assert!(generate(type_name).is_err(), "{type_name} must be rejected");
+        }
+    }
+
+    #[test]
+    fn invalid_byte_mappings() {
+        let generate = |member| {
+            let config = Configuration::new(
+                Path::new("files/test-vectors/byte_mapping_invalid/"),
+                Path::new("input.idl"),
+                false,
+            )
+            .with_member_byte_mapping(member, ByteMapping::Bytes);
+            generate_with_search_path(&mut Vec::new(), &config)
+        };
+        for member in ["Foo::Bar::payload", "Foo::Bar::hash"] {
+            assert!(generate(member).is_ok(), "{member} must be accepted");
+        }
+        for member in [
+            "Foo::Bar::count",
+            "Foo::Bar::matrix",
+            "Foo::Bar::fragments",
+            "Foo::Bar::missing",
+            "Foo::Bar",
+        ] {
+            assert!(generate(member).is_err(), "{member} must be rejected");
+        }
+    }
+
+    #[test]
+    fn invalid_extensibility() {
+        for idl_file in [
+            "derived.idl",
+            "enum.idl",
+            "multiple.idl",
+            "base.idl",
+            "cycle.idl",
+        ] {
+            let config = Configuration::new(
+                Path::new("files/test-vectors/extensibility_invalid/"),
+                Path::new(idl_file),
+                false,
+            );
+            let mut generated = Vec::new();
+            assert!(
+                generate_with_search_path(&mut generated, &config).is_err(),
+                "{idl_file} must be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_member_ids() {
+        for idl_file in [
+            "collision.idl",
+            "both.idl",
+            "derived.idl",
+            "range.idl",
+            "discriminator.idl",
+            "constant.idl",
+            "union_constant.idl",
+        ] {
+            let config = Configuration::new(
+                Path::new("files/test-vectors/member_id_invalid/"),
+                Path::new(idl_file),
+                false,
+            );
+            let mut generated = Vec::new();
+            assert!(
+                generate_with_search_path(&mut generated, &config).is_err(),
+                "{idl_file} must be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_repository_ids() {
+        for idl_file in [
+            "conflict.idl",
+            "unknown.idl",
+            "version.idl",
+            "both.idl",
+            "unquoted.idl",
+        ] {
+            let config = Configuration::new(
+                Path::new("files/test-vectors/repository_id_invalid/"),
+                Path::new(idl_file),
+                false,
+            );
+            let mut generated = Vec::new();
+            assert!(
+                generate_with_search_path(&mut generated, &config).is_err(),
+                "{idl_file} must be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_parameter_lists() {
+        let data = |ids: &[(&str, ParameterId)]| {
+            ids.iter()
+                .map(|(member, id)| (member.to_string(), id.clone()))
+                .collect::<HashMap<_, _>>()
+        };
+        let both = || {
+            data(&[
+                ("first", ParameterId::Value(0x10)),
+                ("second", ParameterId::Value(0x11)),
+            ])
+        };
+        let cases = [
+            (
+                "unmapped",
+                "Invalid::Data",
+                data(&[("first", ParameterId::Value(0x10))]),
+            ),
+            ("unknown type", "Invalid::Missing", HashMap::new()),
+            ("unknown member", "Invalid::Data", {
+                let mut ids = both();
+                ids.insert("third".to_owned(), ParameterId::Value(0x12));
+                ids
+            }),
+            (
+                "repeated",
+                "Invalid::Data",
+                data(&[
+                    ("first", ParameterId::Value(0x10)),
+                    ("second", ParameterId::Value(0x10)),
+                ]),
+            ),
+            (
+                "sentinel",
+                "Invalid::Data",
+                data(&[
+                    ("first", ParameterId::Value(0x01)),
+                    ("second", ParameterId::Value(0x11)),
+                ]),
+            ),
+            (
+                "string constant",
+                "Invalid::Data",
+                data(&[
+                    ("first", ParameterId::Constant("NAME".to_owned())),
+                    ("second", ParameterId::Value(0x11)),
+                ]),
+            ),
+            (
+                "large constant",
+                "Invalid::Data",
+                data(&[
+                    ("first", ParameterId::Constant("LARGE".to_owned())),
+                    ("second", ParameterId::Value(0x11)),
+                ]),
+            ),
+            (
+                "base",
+                "Invalid::Derived",
+                data(&[("third", ParameterId::Value(0x12))]),
+            ),
+            ("default branch", "Invalid::Parameter", HashMap::new()),
+        ];
+        for (case, type_name, ids) in cases {
+            let config = Configuration::new(
+                Path::new("files/test-vectors/parameter_list_invalid/"),
+                Path::new("input.idl"),
+                false,
+            )
+            .with_parameter_list(type_name, ids);
+            let mut generated = Vec::new();
+            assert!(
+                generate_with_search_path(&mut generated, &config).is_err(),
+                "{case} must be rejected"
+            );
+        }
+
+        // the struct itself is valid
+        let config = Configuration::new(
+            Path::new("files/test-vectors/parameter_list_invalid/"),
+            Path::new("input.idl"),
+            false,
+        )
+        .with_parameter_list("Invalid::Data", both());
+        let mut generated = Vec::new();
+        assert!(generate_with_search_path(&mut generated, &config).is_ok());
+    }
+
+    #[test]
+    fn invalid_zero_copy_views() {
+        for idl_file in ["reserved.idl", "collision.idl"] {
+            let config = Configuration::new(
+                Path::new("files/test-vectors/zero_copy_invalid/"),
+                Path::new(idl_file),
+                false,
+            );
+            // the names only matter if views are generated
+            let mut generated = Vec::new();
+            assert!(generate_with_search_path(&mut generated, &config).is_ok());
+
+            let config = config.with_zero_copy_views(true);
+            let mut generated = Vec::new();
+            assert!(
+                generate_with_search_path(&mut generated, &config).is_err(),
+                "{idl_file} must be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn c_header() {
+        let test_dir = Path::new("files/test-vectors/repr_c/");
+        let expected = std::fs::read_to_string(test_dir.join("expected.h")).unwrap();
+        let config = Configuration::new(test_dir, Path::new("input.idl"), false).with_repr_c(true);
+        let mut generated = Vec::new();
+        generate_c_header_with_search_path(&mut generated, &config).unwrap();
+        assert_eq!(
+            expected.replace('\r', ""),
+            str::from_utf8(&generated).unwrap().replace('\r', "")
+        );
+    }
+
+    /// Compare the code generated for `testvector` with its expected.rs, `tmp_file` is
+    /// the crate compiled from `crate_header` and the generated code.
+    fn testvector_verify(
+        testvector: &str,
+        configure: Configure,
+        crate_header: &str,
+        tmp_file: &mut File,
+    ) {
         let expected = {
             let expected_path = Path::new(testvector).join("expected.rs");
             let mut expected_file = match File::open(expected_path) {
@@ -147,7 +788,12 @@ mod tests {
         };
 
         let generated = {
-            let config = Configuration::new(Path::new(testvector), Path::new("input.idl"), false);
+            let config = configure(Configuration::new(
+                Path::new(testvector),
+                Path::new("input.idl"),
+                false,
+            ));
+            let _ = write!(tmp_file, "{crate_header}");
             match generate_with_search_path(tmp_file, &config) {
                 Ok(_) => (),
                 Err(err) => {
@@ -156,7 +802,7 @@ mod tests {
                 }
             };
 
-            let _ = tmp_file.seek(SeekFrom::Start(0));
+            let _ = tmp_file.seek(SeekFrom::Start(crate_header.len() as u64));
             let mut generated = String::new();
             assert!(tmp_file.read_to_string(&mut generated).is_ok());
 
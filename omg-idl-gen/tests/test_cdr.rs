@@ -0,0 +1,279 @@
+#[allow(clippy::upper_case_acronyms)]
+mod bounds {
+    include!("../files/test-vectors/bounds/expected.rs");
+}
+
+#[allow(clippy::upper_case_acronyms)]
+mod cdr {
+    include!("../files/test-vectors/cdr/expected.rs");
+}
+
+use bounds::Bounds::{Choice, Color, Entry, Label, Point, Shape};
+use cdr::Cdr::{Flag, Shape as CdrShape};
+use omg_cdr::{from_bytes, to_bytes, CdrDecode, CdrEncode, CdrVersion, Endianness};
+use omg_idl_rt::Extensible;
+
+/// Serialize the generated `value` and compare it against the bytes of the specification,
+/// decoding `expected` must give back the same bytes
+fn assert_golden<T: CdrEncode + CdrDecode + Extensible>(
+    value: &T,
+    version: CdrVersion,
+    endianness: Endianness,
+    expected: &[u8],
+) {
+    assert_eq!(to_bytes(value, version, endianness), expected);
+    let decoded: T = from_bytes(expected).unwrap();
+    assert_eq!(to_bytes(&decoded, version, endianness), expected);
+}
+
+#[test]
+fn final_struct() {
+    let point = Point { tag: 7, x: 1.5 };
+    #[rustfmt::skip]
+    assert_golden(&point, CdrVersion::Xcdr1, Endianness::Little, &[
+        0x00, 0x01, 0x00, 0x00,
+        7, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0xF8, 0x3F,
+    ]);
+    #[rustfmt::skip]
+    assert_golden(&point, CdrVersion::Xcdr1, Endianness::Big, &[
+        0x00, 0x00, 0x00, 0x00,
+        7, 0, 0, 0, 0, 0, 0, 0,
+        0x3F, 0xF8, 0, 0, 0, 0, 0, 0,
+    ]);
+    // XCDR2 aligns to at most 4 bytes
+    #[rustfmt::skip]
+    assert_golden(&point, CdrVersion::Xcdr2, Endianness::Little, &[
+        0x00, 0x07, 0x00, 0x00,
+        7, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0xF8, 0x3F,
+    ]);
+}
+
+#[test]
+fn appendable_struct_with_optional() {
+    let label = Label {
+        name: "ab".to_owned(),
+        note: Some("x".to_owned()),
+        points: vec![Point { tag: 1, x: 2.0 }],
+        color: Color::GREEN,
+    };
+    // the optional member is a parameter holding the UTF-16 string
+    #[rustfmt::skip]
+    assert_golden(&label, CdrVersion::Xcdr1, Endianness::Little, &[
+        0x00, 0x01, 0x00, 0x00,
+        3, 0, 0, 0, b'a', b'b', 0, 0,
+        1, 0, 8, 0, 2, 0, 0, 0, b'x', 0, 0, 0,
+        1, 0, 0, 0,
+        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x40,
+        1, 0, 0, 0,
+    ]);
+    // a DHEADER delimits the struct and the sequence of structs, a flag the optional
+    #[rustfmt::skip]
+    assert_golden(&label, CdrVersion::Xcdr2, Endianness::Little, &[
+        0x00, 0x09, 0x00, 0x00,
+        40, 0, 0, 0,
+        3, 0, 0, 0, b'a', b'b', 0,
+        1, 2, 0, 0, 0, b'x', 0, 0, 0,
+        16, 0, 0, 0, 1, 0, 0, 0,
+        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x40,
+        1, 0, 0, 0,
+    ]);
+
+    let absent = Label {
+        note: None,
+        ..label
+    };
+    #[rustfmt::skip]
+    assert_golden(&absent, CdrVersion::Xcdr1, Endianness::Little, &[
+        0x00, 0x01, 0x00, 0x00,
+        3, 0, 0, 0, b'a', b'b', 0, 0,
+        1, 0, 0, 0,
+        1, 0, 0, 0,
+        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x40,
+        1, 0, 0, 0,
+    ]);
+    #[rustfmt::skip]
+    assert_golden(&absent, CdrVersion::Xcdr2, Endianness::Little, &[
+        0x00, 0x09, 0x00, 0x00,
+        32, 0, 0, 0,
+        3, 0, 0, 0, b'a', b'b', 0,
+        0,
+        16, 0, 0, 0, 1, 0, 0, 0,
+        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x40,
+        1, 0, 0, 0,
+    ]);
+}
+
+#[test]
+fn mutable_struct() {
+    let entry = Entry {
+        id: 5,
+        label: Some(Label {
+            name: "ab".to_owned(),
+            note: None,
+            points: Vec::new(),
+            color: Color::RED,
+        }),
+        values: vec![1, 2],
+    };
+    // the key is must-understand, the member list ends with PID_LIST_END
+    #[rustfmt::skip]
+    assert_golden(&entry, CdrVersion::Xcdr1, Endianness::Little, &[
+        0x00, 0x03, 0x00, 0x00,
+        0x00, 0x40, 4, 0, 5, 0, 0, 0,
+        1, 0, 20, 0,
+        3, 0, 0, 0, b'a', b'b', 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 0, 8, 0, 2, 0, 0, 0, 1, 0, 2, 0,
+        0x02, 0x3F, 0, 0,
+    ]);
+    // EMHEADERs with the length codes 2 (4 bytes), 4 (NEXTINT) and 3 (8 bytes)
+    #[rustfmt::skip]
+    assert_golden(&entry, CdrVersion::Xcdr2, Endianness::Little, &[
+        0x00, 0x0B, 0x00, 0x00,
+        52, 0, 0, 0,
+        0, 0, 0, 0xA0, 5, 0, 0, 0,
+        1, 0, 0, 0x40, 24, 0, 0, 0,
+        20, 0, 0, 0, 3, 0, 0, 0, b'a', b'b', 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 0, 0, 0x30, 2, 0, 0, 0, 1, 0, 2, 0,
+    ]);
+
+    let absent = Entry {
+        label: None,
+        ..entry
+    };
+    // absent optional members of mutable types are omitted
+    #[rustfmt::skip]
+    assert_golden(&absent, CdrVersion::Xcdr1, Endianness::Big, &[
+        0x00, 0x02, 0x00, 0x00,
+        0x40, 0x00, 0, 4, 0, 0, 0, 5,
+        0, 2, 0, 8, 0, 0, 0, 2, 0, 1, 0, 2,
+        0x3F, 0x02, 0, 0,
+    ]);
+    #[rustfmt::skip]
+    assert_golden(&absent, CdrVersion::Xcdr2, Endianness::Big, &[
+        0x00, 0x0A, 0x00, 0x00,
+        0, 0, 0, 20,
+        0xA0, 0, 0, 0, 0, 0, 0, 5,
+        0x30, 0, 0, 2, 0, 0, 0, 2, 0, 1, 0, 2,
+    ]);
+}
+
+#[test]
+fn final_union_with_bit_bound_discriminator() {
+    // the discriminator of @bit_bound(8) is a single octet
+    let small = Choice::SMALL { value: 2.5 };
+    #[rustfmt::skip]
+    assert_golden(&small, CdrVersion::Xcdr1, Endianness::Little, &[
+        0x00, 0x01, 0x00, 0x00,
+        0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0x04, 0x40,
+    ]);
+    #[rustfmt::skip]
+    assert_golden(&small, CdrVersion::Xcdr2, Endianness::Little, &[
+        0x00, 0x07, 0x00, 0x00,
+        0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0x04, 0x40,
+    ]);
+
+    let large = Choice::LARGE {
+        name: "ab".to_owned(),
+    };
+    #[rustfmt::skip]
+    assert_golden(&large, CdrVersion::Xcdr1, Endianness::Little, &[
+        0x00, 0x01, 0x00, 0x00,
+        1, 0, 0, 0, 3, 0, 0, 0, b'a', b'b', 0,
+    ]);
+    // XCDR2 data is padded to 4 bytes, the options hold the padding
+    #[rustfmt::skip]
+    assert_golden(&large, CdrVersion::Xcdr2, Endianness::Little, &[
+        0x00, 0x07, 0x00, 0x01,
+        1, 0, 0, 0, 3, 0, 0, 0, b'a', b'b', 0, 0,
+    ]);
+}
+
+#[test]
+fn appendable_union_with_optional() {
+    let point = CdrShape::POINT { x: 3 };
+    #[rustfmt::skip]
+    assert_golden(&point, CdrVersion::Xcdr1, Endianness::Little, &[
+        0x00, 0x01, 0x00, 0x00,
+        0, 0, 0, 0, 3, 0, 0, 0,
+    ]);
+    #[rustfmt::skip]
+    assert_golden(&point, CdrVersion::Xcdr2, Endianness::Little, &[
+        0x00, 0x09, 0x00, 0x00,
+        8, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0,
+    ]);
+
+    let circle = CdrShape::CIRCLE { radius: Some(1.0) };
+    #[rustfmt::skip]
+    assert_golden(&circle, CdrVersion::Xcdr1, Endianness::Little, &[
+        0x00, 0x01, 0x00, 0x00,
+        1, 0, 0, 0,
+        2, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0xF0, 0x3F,
+    ]);
+    #[rustfmt::skip]
+    assert_golden(&circle, CdrVersion::Xcdr2, Endianness::Little, &[
+        0x00, 0x09, 0x00, 0x00,
+        16, 0, 0, 0, 1, 0, 0, 0,
+        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xF0, 0x3F,
+    ]);
+
+    let empty = CdrShape::CIRCLE { radius: None };
+    #[rustfmt::skip]
+    assert_golden(&empty, CdrVersion::Xcdr2, Endianness::Little, &[
+        0x00, 0x09, 0x00, 0x03,
+        5, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+    ]);
+}
+
+#[test]
+fn mutable_unions() {
+    let square = Shape::SQUARE { side: 9 };
+    #[rustfmt::skip]
+    assert_golden(&square, CdrVersion::Xcdr1, Endianness::Little, &[
+        0x00, 0x03, 0x00, 0x00,
+        0x00, 0x40, 4, 0, 2, 0, 0, 0,
+        2, 0, 8, 0, 9, 0, 0, 0, 0, 0, 0, 0,
+        0x02, 0x3F, 0, 0,
+    ]);
+    #[rustfmt::skip]
+    assert_golden(&square, CdrVersion::Xcdr2, Endianness::Little, &[
+        0x00, 0x0B, 0x00, 0x00,
+        20, 0, 0, 0,
+        0, 0, 0, 0x90, 2, 0, 0, 0,
+        2, 0, 0, 0x30, 9, 0, 0, 0, 0, 0, 0, 0,
+    ]);
+
+    let flag = Flag::ENABLED { enabled: true };
+    #[rustfmt::skip]
+    assert_golden(&flag, CdrVersion::Xcdr1, Endianness::Little, &[
+        0x00, 0x03, 0x00, 0x00,
+        0x00, 0x40, 4, 0, b'a', 0, 0, 0,
+        1, 0, 4, 0, 1, 0, 0, 0,
+        0x02, 0x3F, 0, 0,
+    ]);
+    #[rustfmt::skip]
+    assert_golden(&flag, CdrVersion::Xcdr2, Endianness::Little, &[
+        0x00, 0x0B, 0x00, 0x03,
+        13, 0, 0, 0,
+        0, 0, 0, 0x80, b'a', 0, 0, 0,
+        1, 0, 0, 0, 1, 0, 0, 0,
+    ]);
+}
+
+#[test]
+fn mutable_union_members_are_found_by_id() {
+    // an unknown member between the discriminator and the selected member is skipped
+    #[rustfmt::skip]
+    let bytes = [
+        0x00, 0x03, 0x00, 0x00,
+        0x00, 0x40, 4, 0, 2, 0, 0, 0,
+        7, 0, 4, 0, 0, 0, 0, 0,
+        2, 0, 8, 0, 9, 0, 0, 0, 0, 0, 0, 0,
+        0x02, 0x3F, 0, 0,
+    ];
+    let shape: Shape = from_bytes(&bytes).unwrap();
+    assert!(matches!(shape, Shape::SQUARE { side: 9 }));
+}
@@ -0,0 +1,18 @@
+#[allow(clippy::upper_case_acronyms)]
+mod default_values {
+    include!("../files/test-vectors/default_values/expected.rs");
+}
+
+use default_values::Settings;
+
+#[test]
+fn defaults_pass_validation() {
+    let settings = Settings::default();
+    assert_eq!(settings.validate(), Ok(()));
+    // zero values outside of @range, @min or @max default to the nearest bound
+    assert_eq!(settings.temperature, 0.0);
+    assert_eq!(settings.level, 0);
+    assert_eq!(settings.month, 1);
+    assert_eq!(settings.port, 5);
+    assert_eq!(settings.offset, -1);
+}
@@ -0,0 +1,118 @@
+#[allow(clippy::upper_case_acronyms, clippy::derivable_impls)]
+mod reflection {
+    include!("../files/test-vectors/reflection/expected.rs");
+}
+
+use omg_idl_rt::{Bound, Reflect, ReflectError, TypeDescriptor, ValidationError, Value};
+use reflection::DDS::{Limits, Policy, TopicData};
+
+fn longs(values: &[i32]) -> Value {
+    Value::Sequence(values.iter().copied().map(Value::Long).collect())
+}
+
+#[test]
+fn writes_exceeding_bounds_fail() {
+    let mut limits = Limits::default();
+    assert_eq!(
+        limits.set_field("code", Value::String("toolongstring".into())),
+        Err(ReflectError::OutOfRange(13))
+    );
+    assert_eq!(
+        limits.set_field("pair", longs(&[1, 2, 3, 4, 5])),
+        Err(ReflectError::OutOfRange(5))
+    );
+    assert_eq!(
+        limits.set_field("label", Value::String("ninechars".into())),
+        Err(ReflectError::OutOfRange(9))
+    );
+    assert_eq!(limits.code, "");
+    assert!(limits.pair.is_empty());
+
+    limits
+        .set_field("code", Value::String("short".into()))
+        .unwrap();
+    limits.set_field("pair", longs(&[1, 2])).unwrap();
+    assert_eq!(limits.code, "short");
+    assert_eq!(limits.pair, vec![1, 2]);
+
+    let mut topic = TopicData {
+        policy: Some(Policy::MANUAL {
+            owner: String::new(),
+        }),
+        ..Default::default()
+    };
+    assert_eq!(
+        topic.set_field("policy.owner", Value::String("x".repeat(17))),
+        Err(ReflectError::OutOfRange(17))
+    );
+    assert_eq!(
+        topic.set_field("data", Value::Sequence(vec![Value::Octet(0); 9])),
+        Err(ReflectError::OutOfRange(9))
+    );
+}
+
+#[test]
+fn writes_violating_ranges_fail() {
+    let mut limits = Limits::default();
+    assert_eq!(
+        limits.set_field("level", Value::Long(100)),
+        Err(ReflectError::Invalid(ValidationError::new(
+            "level",
+            Bound::Max,
+            "10"
+        )))
+    );
+    assert_eq!(
+        limits.set_field("percent", Value::UShort(101)),
+        Err(ReflectError::Invalid(ValidationError::new(
+            "percent",
+            Bound::Max,
+            "100"
+        )))
+    );
+    assert_eq!(limits.level, 1);
+    assert_eq!(limits.percent, None);
+
+    // whole values are checked member by member
+    let value = Value::Struct(vec![("level".into(), Value::Long(0))]);
+    assert_eq!(
+        limits.set_field("", value),
+        Err(ReflectError::Invalid(ValidationError::new(
+            "level",
+            Bound::Min,
+            "1"
+        )))
+    );
+
+    limits.set_field("level", Value::Long(10)).unwrap();
+    limits.set_field("percent", Value::UShort(100)).unwrap();
+    assert_eq!(limits.level, 10);
+    assert_eq!(limits.percent, Some(100));
+}
+
+#[test]
+fn array_dimensions_match_indices() {
+    let mut limits = Limits::default();
+    let grid = limits
+        .members()
+        .iter()
+        .find(|member| member.name == "grid")
+        .unwrap();
+    assert_eq!(
+        grid.type_descriptor,
+        TypeDescriptor::Array(&TypeDescriptor::Long, &[2, 3])
+    );
+
+    limits.set_field("grid[1][2]", Value::Long(7)).unwrap();
+    assert_eq!(limits.grid[1][2], 7);
+    assert_eq!(limits.get_field("grid[1][2]"), Ok(Value::Long(7)));
+    assert_eq!(limits.get_field("grid[1]"), Ok(longs(&[0, 0, 7])));
+    assert_eq!(
+        limits.get_field("grid[2][0]"),
+        Err(ReflectError::OutOfRange(2))
+    );
+    assert_eq!(
+        limits.get_field("grid[0][3]"),
+        Err(ReflectError::OutOfRange(3))
+    );
+}
@@ -38,6 +38,34 @@ fn character_literal() {
     };
 }
 
+#[test]
+fn annotation_appl() {
+    parses_to! {
+        parser: IdlParser,
+        input: "@range(min = 0, max = 10)",
+        rule: Rule::annotation_appl,
+        tokens: [
+            annotation_appl(0, 25, [
+                scoped_name(1, 6, [identifier(1, 6)]),
+                annotation_appl_params(7, 24, [
+                    annotation_appl_param(7, 14, [
+                        identifier(7, 10),
+                        const_expr(13, 14, [unary_expr(13, 14, [primary_expr(13, 14, [
+                            literal(13, 14, [integer_literal(13, 14, [decimal_integer_literal(13, 14)])])
+                        ])])])
+                    ]),
+                    annotation_appl_param(16, 24, [
+                        identifier(16, 19),
+                        const_expr(22, 24, [unary_expr(22, 24, [primary_expr(22, 24, [
+                            literal(22, 24, [integer_literal(22, 24, [decimal_integer_literal(22, 24)])])
+                        ])])])
+                    ])
+                ])
+            ])
+        ]
+    };
+}
+
 // #[test]
 // fn floating_pt_literal() {
 //     parses_to! {
@@ -0,0 +1,23 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+/// Extensibility kind of a type as defined by DDS-XTypes, selecting how the type is
+/// encoded on the wire
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Extensibility {
+    /// Members are fixed, no headers are written
+    Final,
+    /// Members may be appended to later versions of the type
+    Appendable,
+    /// Members may be added, removed or reordered, each member carries its id
+    Mutable,
+}
+
+/// Implemented by all generated structs, unions and enums
+pub trait Extensible {
+    /// Kind declared via `@final`, `@appendable`, `@mutable` or `@extensibility`,
+    /// `Appendable` if no annotation is given
+    const EXTENSIBILITY: Extensibility;
+}
@@ -0,0 +1,166 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+use core::{cmp::Ordering, fmt};
+
+const SIGN: u128 = 1 << 127;
+const FRACTION_BITS: u32 = 112;
+const FRACTION: u128 = (1 << FRACTION_BITS) - 1;
+const EXPONENT_MAX: u32 = 0x7FFF;
+const EXPONENT_BIAS: i32 = 16383;
+/// Fraction bits of binary128 not present in f64
+const EXTRA_BITS: u32 = FRACTION_BITS - 52;
+
+/// IDL `long double`, an IEEE 754 binary128 number stored as its bits. Stable Rust has no
+/// arithmetic on binary128, values convert to and from `f64`. Comparisons follow IEEE
+/// 754, NaN is unordered and -0 equals +0.
+#[derive(Clone, Copy, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+#[repr(transparent)]
+pub struct F128(u128);
+
+impl F128 {
+    pub const ZERO: F128 = F128(0);
+
+    pub const fn from_bits(bits: u128) -> Self {
+        Self(bits)
+    }
+
+    pub const fn to_bits(self) -> u128 {
+        self.0
+    }
+
+    /// The exact binary128 value of `value`
+    pub const fn from_f64(value: f64) -> Self {
+        let bits = value.to_bits();
+        let sign = ((bits >> 63) as u128) << 127;
+        let exponent = ((bits >> 52) & 0x7FF) as i32;
+        let fraction = (bits & ((1 << 52) - 1)) as u128;
+        let magnitude = if exponent == 0x7FF {
+            // infinity and NaN, keeping the payload
+            ((EXPONENT_MAX as u128) << FRACTION_BITS) | (fraction << EXTRA_BITS)
+        } else if exponent != 0 {
+            (((exponent - 1023 + EXPONENT_BIAS) as u128) << FRACTION_BITS)
+                | (fraction << EXTRA_BITS)
+        } else if fraction == 0 {
+            0
+        } else {
+            // subnormal f64 are normal binary128 numbers
+            let highest = 127 - fraction.leading_zeros();
+            let exponent = highest as i32 - 1074 + EXPONENT_BIAS;
+            ((exponent as u128) << FRACTION_BITS)
+                | ((fraction << (FRACTION_BITS - highest)) & FRACTION)
+        };
+        Self(sign | magnitude)
+    }
+
+    /// The nearest `f64`, ties round to even
+    pub const fn to_f64(self) -> f64 {
+        let sign = ((self.0 >> 127) as u64) << 63;
+        let exponent = ((self.0 >> FRACTION_BITS) as u32 & EXPONENT_MAX) as i32;
+        let fraction = self.0 & FRACTION;
+        if exponent == EXPONENT_MAX as i32 {
+            let payload = (fraction >> EXTRA_BITS) as u64;
+            // a NaN whose payload is in the dropped bits must not become infinity
+            let payload = match fraction != 0 && payload == 0 {
+                true => 1 << 51,
+                false => payload,
+            };
+            return f64::from_bits(sign | (0x7FF << 52) | payload);
+        }
+        let unbiased = exponent - EXPONENT_BIAS;
+        if exponent == 0 {
+            // binary128 subnormals are far below the smallest f64
+            return f64::from_bits(sign);
+        }
+        if unbiased > 1023 {
+            return f64::from_bits(sign | (0x7FF << 52));
+        }
+        // f64 subnormals keep fewer bits
+        let shift = match unbiased >= -1022 {
+            true => EXTRA_BITS,
+            false => (-962 - unbiased) as u32,
+        };
+        if shift > FRACTION_BITS + 1 {
+            return f64::from_bits(sign);
+        }
+        let significand = fraction | (1 << FRACTION_BITS);
+        let mut mantissa = (significand >> shift) as u64;
+        let remainder = significand & ((1 << shift) - 1);
+        let half = 1 << (shift - 1);
+        if remainder > half || (remainder == half && mantissa & 1 == 1) {
+            mantissa += 1;
+        }
+        // the implicit bit of the mantissa increments the exponent, as does a carry
+        let magnitude = match unbiased >= -1022 {
+            true => (((unbiased + 1022) as u64) << 52) + mantissa,
+            false => mantissa,
+        };
+        f64::from_bits(sign | magnitude)
+    }
+
+    pub const fn is_nan(self) -> bool {
+        (self.0 >> FRACTION_BITS) as u32 & EXPONENT_MAX == EXPONENT_MAX && self.0 & FRACTION != 0
+    }
+
+    /// Position on the number line as integer, -0 and +0 coincide
+    fn ordinal(self) -> i128 {
+        let magnitude = (self.0 & !SIGN) as i128;
+        match self.0 & SIGN {
+            0 => magnitude,
+            _ => -magnitude,
+        }
+    }
+}
+
+impl From<f64> for F128 {
+    fn from(value: f64) -> Self {
+        Self::from_f64(value)
+    }
+}
+
+impl From<f32> for F128 {
+    fn from(value: f32) -> Self {
+        Self::from_f64(value.into())
+    }
+}
+
+impl From<F128> for f64 {
+    fn from(value: F128) -> Self {
+        value.to_f64()
+    }
+}
+
+impl PartialEq for F128 {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for F128 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.is_nan() || other.is_nan() {
+            true => None,
+            false => Some(self.ordinal().cmp(&other.ordinal())),
+        }
+    }
+}
+
+/// Shows the nearest `f64`
+impl fmt::Debug for F128 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_f64(), f)
+    }
+}
+
+/// Shows the nearest `f64`
+impl fmt::Display for F128 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.to_f64(), f)
+    }
+}
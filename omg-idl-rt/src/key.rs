@@ -0,0 +1,460 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+#[cfg(feature = "alloc")]
+use crate::ByteBuf;
+use crate::Latin1;
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use core::{cmp::Ordering, fmt, hash::Hasher, mem::size_of};
+use md5::{Digest, Md5};
+
+/// XCDR2 aligns primitives to their size, but at most to 4 bytes
+const MAX_ALIGN: usize = 4;
+
+/// Size of a key hash, longer key holders are hashed via MD5
+const KEY_HASH_SIZE: usize = 16;
+
+/// Destination of the serialized key holder
+enum Sink<'a> {
+    #[cfg(feature = "alloc")]
+    Buffer(Vec<u8>),
+    /// Key holders of at most 16 bytes are their own key hash
+    Padded([u8; KEY_HASH_SIZE]),
+    Digest(Md5),
+    Hasher(&'a mut dyn Hasher),
+}
+
+/// Serializes the key holder of a type, big endian XCDR2 without encapsulation header
+/// as required for the DDS key hash.
+pub struct KeyHolderWriter<'a> {
+    len: usize,
+    sink: Sink<'a>,
+}
+
+#[cfg(feature = "alloc")]
+impl KeyHolderWriter<'_> {
+    pub fn new() -> Self {
+        Self {
+            len: 0,
+            sink: Sink::Buffer(Vec::new()),
+        }
+    }
+
+    /// The serialized key holder, empty for writers created by `hashing`
+    pub fn into_bytes(self) -> Vec<u8> {
+        match self.sink {
+            Sink::Buffer(buffer) => buffer,
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl<'a> KeyHolderWriter<'a> {
+    /// Feed the key holder to `state` instead of collecting it, hashing a key without
+    /// allocating
+    pub fn hashing(state: &'a mut dyn Hasher) -> Self {
+        Self {
+            len: 0,
+            sink: Sink::Hasher(state),
+        }
+    }
+
+    /// Append `bytes` after padding the buffer to the alignment of a primitive of `size`
+    pub fn write_aligned(&mut self, size: usize, bytes: &[u8]) {
+        let align = size.clamp(1, MAX_ALIGN);
+        let padding = self.len.next_multiple_of(align) - self.len;
+        let start = self.len + padding;
+        self.len = start + bytes.len();
+        match self.sink {
+            #[cfg(feature = "alloc")]
+            Sink::Buffer(ref mut buffer) => {
+                buffer.resize(start, 0);
+                buffer.extend_from_slice(bytes);
+            }
+            Sink::Padded(ref mut hash) => {
+                // the key size bounds the key holder, longer ones are cut off
+                let tail = hash.get_mut(start..).unwrap_or_default();
+                let count = tail.len().min(bytes.len());
+                tail[..count].copy_from_slice(&bytes[..count]);
+            }
+            Sink::Digest(ref mut digest) => {
+                digest.update(&[0; MAX_ALIGN][..padding]);
+                digest.update(bytes);
+            }
+            Sink::Hasher(ref mut state) => {
+                state.write(&[0; MAX_ALIGN][..padding]);
+                state.write(bytes);
+            }
+        }
+    }
+
+    /// Append the length of a string or sequence
+    pub fn write_length(&mut self, length: usize) {
+        let length = u32::try_from(length).unwrap_or(u32::MAX);
+        self.write_aligned(size_of::<u32>(), &length.to_be_bytes());
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Default for KeyHolderWriter<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for KeyHolderWriter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyHolderWriter")
+            .field("len", &self.len)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Maximum size and alignment of a serialized key holder, `size` is `None` if the key
+/// contains unbounded strings or sequences.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KeySize {
+    size: Option<usize>,
+    align: usize,
+}
+
+impl KeySize {
+    /// Size of a key without any members
+    pub const fn new() -> Self {
+        Self {
+            size: Some(0),
+            align: 1,
+        }
+    }
+
+    /// Size of a primitive type
+    pub const fn primitive(size: usize) -> Self {
+        Self {
+            size: Some(size),
+            align: if size > MAX_ALIGN { MAX_ALIGN } else { size },
+        }
+    }
+
+    /// Size of a type of unbounded length, aligned like its length prefix
+    pub const fn unbounded() -> Self {
+        Self {
+            size: None,
+            align: MAX_ALIGN,
+        }
+    }
+
+    /// Append a member of type `T`
+    pub const fn field<T: KeyHashEncode + ?Sized>(self) -> Self {
+        self.append(T::KEY_SIZE)
+    }
+
+    /// Append a string holding at most `bound` characters
+    pub const fn bounded_string(self, bound: usize) -> Self {
+        // length, characters and the terminating NUL
+        self.append(Self::primitive(size_of::<u32>() + bound + 1).with_align(MAX_ALIGN))
+    }
+
+    /// Append `count` elements of type `T`
+    pub const fn repeat<T: KeyHashEncode + ?Sized>(self, count: usize) -> Self {
+        let mut size = self;
+        let mut idx = 0;
+        while idx < count {
+            size = size.field::<T>();
+            idx += 1;
+        }
+        size
+    }
+
+    const fn with_align(self, align: usize) -> Self {
+        Self {
+            size: self.size,
+            align,
+        }
+    }
+
+    const fn append(self, other: Self) -> Self {
+        let size = match (self.size, other.size) {
+            (Some(size), Some(other_size)) => Some(size.next_multiple_of(other.align) + other_size),
+            _ => None,
+        };
+        Self {
+            size,
+            align: if other.align > self.align {
+                other.align
+            } else {
+                self.align
+            },
+        }
+    }
+
+    /// Maximum number of bytes of the serialized key holder
+    pub const fn size(self) -> Option<usize> {
+        self.size
+    }
+
+    /// Alignment of the first member of the key holder
+    pub const fn align(self) -> usize {
+        self.align
+    }
+}
+
+impl Default for KeySize {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Types which can be part of a key holder
+pub trait KeyHashEncode {
+    /// Maximum size of the serialized key holder
+    const KEY_SIZE: KeySize;
+
+    /// Serialize the key holder of the value
+    fn encode_key(&self, writer: &mut KeyHolderWriter);
+
+    /// Order of the keys of two values, compared without serializing them. Numbers
+    /// compare by value, floats by `total_cmp`, strings and sequences lexicographically.
+    fn key_cmp(&self, other: &Self) -> Ordering;
+}
+
+/// Strings and sequences compare element by element, a prefix orders first
+fn sequence_cmp<T: KeyHashEncode>(value: &[T], other: &[T]) -> Ordering {
+    value
+        .iter()
+        .zip(other)
+        .map(|(element, other)| element.key_cmp(other))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or_else(|| value.len().cmp(&other.len()))
+}
+
+/// Octet sequences compare like `sequence_cmp`
+#[cfg(any(feature = "alloc", feature = "bytes", feature = "heapless"))]
+fn bytes_cmp(value: &[u8], other: &[u8]) -> Ordering {
+    value.cmp(other)
+}
+
+macro_rules! impl_key_hash_encode_number {
+    ($cmp:ident: $($typ:ty),*) => {
+        $(
+            impl KeyHashEncode for $typ {
+                const KEY_SIZE: KeySize = KeySize::primitive(size_of::<$typ>());
+
+                fn encode_key(&self, writer: &mut KeyHolderWriter) {
+                    writer.write_aligned(size_of::<$typ>(), &self.to_be_bytes());
+                }
+
+                fn key_cmp(&self, other: &Self) -> Ordering {
+                    self.$cmp(other)
+                }
+            }
+        )*
+    };
+}
+
+impl_key_hash_encode_number!(cmp: u8, i8, i16, u16, i32, u32, i64, u64);
+impl_key_hash_encode_number!(total_cmp: f32, f64);
+
+impl KeyHashEncode for bool {
+    const KEY_SIZE: KeySize = KeySize::primitive(1);
+
+    fn encode_key(&self, writer: &mut KeyHolderWriter) {
+        writer.write_aligned(1, &[u8::from(*self)]);
+    }
+
+    fn key_cmp(&self, other: &Self) -> Ordering {
+        self.cmp(other)
+    }
+}
+
+/// IDL characters are 8 bit, characters outside of Latin-1 are replaced by '?'
+impl KeyHashEncode for char {
+    const KEY_SIZE: KeySize = KeySize::primitive(1);
+
+    fn encode_key(&self, writer: &mut KeyHolderWriter) {
+        writer.write_aligned(1, &[u8::try_from(*self).unwrap_or(b'?')]);
+    }
+
+    fn key_cmp(&self, other: &Self) -> Ordering {
+        u8::try_from(*self)
+            .unwrap_or(b'?')
+            .cmp(&u8::try_from(*other).unwrap_or(b'?'))
+    }
+}
+
+impl KeyHashEncode for Latin1 {
+    const KEY_SIZE: KeySize = KeySize::primitive(1);
+
+    fn encode_key(&self, writer: &mut KeyHolderWriter) {
+        writer.write_aligned(1, &[self.0]);
+    }
+
+    fn key_cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl KeyHashEncode for String {
+    const KEY_SIZE: KeySize = KeySize::unbounded();
+
+    fn encode_key(&self, writer: &mut KeyHolderWriter) {
+        writer.write_length(self.len() + 1);
+        writer.write_aligned(1, self.as_bytes());
+        writer.write_aligned(1, &[0]);
+    }
+
+    fn key_cmp(&self, other: &Self) -> Ordering {
+        bytes_cmp(self.as_bytes(), other.as_bytes())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: KeyHashEncode> KeyHashEncode for Vec<T> {
+    const KEY_SIZE: KeySize = KeySize::unbounded();
+
+    fn encode_key(&self, writer: &mut KeyHolderWriter) {
+        writer.write_length(self.len());
+        for element in self {
+            element.encode_key(writer);
+        }
+    }
+
+    fn key_cmp(&self, other: &Self) -> Ordering {
+        sequence_cmp(self, other)
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Octet sequences of the `bytebuf` byte mapping, encoded like `Vec<u8>`
+impl KeyHashEncode for ByteBuf {
+    const KEY_SIZE: KeySize = KeySize::unbounded();
+
+    fn encode_key(&self, writer: &mut KeyHolderWriter) {
+        writer.write_length(self.len());
+        writer.write_aligned(1, self);
+    }
+
+    fn key_cmp(&self, other: &Self) -> Ordering {
+        bytes_cmp(self, other)
+    }
+}
+
+/// Octet sequences of the `bytes` byte mapping, encoded like `Vec<u8>`
+#[cfg(feature = "bytes")]
+impl KeyHashEncode for bytes::Bytes {
+    const KEY_SIZE: KeySize = KeySize::unbounded();
+
+    fn encode_key(&self, writer: &mut KeyHolderWriter) {
+        writer.write_length(self.len());
+        writer.write_aligned(1, self);
+    }
+
+    fn key_cmp(&self, other: &Self) -> Ordering {
+        bytes_cmp(self, other)
+    }
+}
+
+/// Bounded strings of the heapless mapping, `N` is the bound
+#[cfg(feature = "heapless")]
+impl<const N: usize> KeyHashEncode for heapless::String<N> {
+    const KEY_SIZE: KeySize = KeySize::new().bounded_string(N);
+
+    fn encode_key(&self, writer: &mut KeyHolderWriter) {
+        writer.write_length(self.len() + 1);
+        writer.write_aligned(1, self.as_bytes());
+        writer.write_aligned(1, &[0]);
+    }
+
+    fn key_cmp(&self, other: &Self) -> Ordering {
+        bytes_cmp(self.as_bytes(), other.as_bytes())
+    }
+}
+
+/// Bounded sequences of the heapless mapping, `N` is the bound
+#[cfg(feature = "heapless")]
+impl<T: KeyHashEncode, const N: usize> KeyHashEncode for heapless::Vec<T, N> {
+    const KEY_SIZE: KeySize = KeySize::primitive(size_of::<u32>())
+        .with_align(MAX_ALIGN)
+        .repeat::<T>(N);
+
+    fn encode_key(&self, writer: &mut KeyHolderWriter) {
+        writer.write_length(self.len());
+        for element in self {
+            element.encode_key(writer);
+        }
+    }
+
+    fn key_cmp(&self, other: &Self) -> Ordering {
+        sequence_cmp(self, other)
+    }
+}
+
+impl<T: KeyHashEncode, const N: usize> KeyHashEncode for [T; N] {
+    const KEY_SIZE: KeySize = KeySize::new().repeat::<T>(N);
+
+    fn encode_key(&self, writer: &mut KeyHolderWriter) {
+        for element in self {
+            element.encode_key(writer);
+        }
+    }
+
+    fn key_cmp(&self, other: &Self) -> Ordering {
+        sequence_cmp(self, other)
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Serialized key holder of `key`, two keys are equal if their key holders are equal
+pub fn key_holder<K: KeyHashEncode + ?Sized>(key: &K) -> Vec<u8> {
+    let mut writer = KeyHolderWriter::new();
+    key.encode_key(&mut writer);
+    writer.into_bytes()
+}
+
+/// Feed the key holder of `key` to `state`, the `Hash` of generated keys
+pub fn hash_key<K: KeyHashEncode + ?Sized, H: Hasher>(key: &K, state: &mut H) {
+    key.encode_key(&mut KeyHolderWriter::hashing(state));
+}
+
+/// DDS key hash identifying an instance (DDS-XTypes 7.6.8). Key holders which may exceed
+/// 16 bytes are hashed via MD5, shorter ones are zero padded.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct KeyHash(pub [u8; KEY_HASH_SIZE]);
+
+impl KeyHash {
+    pub fn from_key<K: KeyHashEncode + ?Sized>(key: &K) -> Self {
+        let sink = match K::KEY_SIZE.size() {
+            Some(size) if size <= KEY_HASH_SIZE => Sink::Padded([0; KEY_HASH_SIZE]),
+            _ => Sink::Digest(Md5::new()),
+        };
+        let mut writer = KeyHolderWriter { len: 0, sink };
+        key.encode_key(&mut writer);
+        match writer.sink {
+            Sink::Padded(hash) => KeyHash(hash),
+            Sink::Digest(digest) => KeyHash(digest.finalize().into()),
+            _ => unreachable!("the writer keeps its sink"),
+        }
+    }
+}
+
+/// Types with key members, generated for structs with `@key` members or
+/// `#pragma DCPS_DATA_KEY` declarations.
+///
+/// **The generated `PartialEq`, `Eq`, `PartialOrd`, `Ord` and `Hash` of these types
+/// consider the key members only.** Two samples of the same instance are `==` even if
+/// their other members differ, compare the members themselves to detect changed
+/// samples.
+pub trait Keyed {
+    /// Holds the key members of the type
+    type Key: KeyHashEncode;
+
+    /// Extract the key members
+    fn key(&self) -> Self::Key;
+
+    /// DDS key hash of the instance
+    fn key_hash(&self) -> KeyHash {
+        KeyHash::from_key(&self.key())
+    }
+}
@@ -0,0 +1,48 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Runtime support for the Rust code generated from OMG IDL.
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod extensibility;
+mod float;
+mod key;
+#[cfg(feature = "alloc")]
+mod octets;
+#[cfg(feature = "alloc")]
+mod reflect;
+#[cfg(feature = "serde")]
+pub mod serde_option_array;
+mod text;
+mod type_name;
+mod type_object;
+mod validation;
+#[cfg(feature = "alloc")]
+mod wstring;
+
+pub use extensibility::{Extensibility, Extensible};
+pub use float::F128;
+#[cfg(feature = "alloc")]
+pub use key::key_holder;
+pub use key::{hash_key, KeyHash, KeyHashEncode, KeyHolderWriter, KeySize, Keyed};
+#[cfg(feature = "alloc")]
+pub use octets::ByteBuf;
+#[cfg(feature = "alloc")]
+pub use reflect::{
+    check_length, element_error, parse_path, segment_error, set_struct_value, struct_value,
+    MemberDescriptor, PathSegment, Reflect, ReflectError, TypeDescriptor, Value,
+};
+pub use text::Latin1;
+pub use type_name::TypeName;
+pub use type_object::{
+    EquivalenceHash, EquivalenceKind, StronglyConnectedComponentId, TypeIdentifier,
+    TypeIdentifierTypeObjectPair, TypeObjects,
+};
+pub use validation::{Bound, BuildError, ValidationError};
+#[cfg(feature = "alloc")]
+pub use wstring::WString;
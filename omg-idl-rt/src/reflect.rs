@@ -0,0 +1,588 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+use crate::{ByteBuf, Latin1, ValidationError, WString, F128};
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::fmt;
+
+/// IDL type of a struct member or union element
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TypeDescriptor {
+    Boolean,
+    Octet,
+    Short,
+    UShort,
+    Long,
+    ULong,
+    LongLong,
+    ULongLong,
+    Float,
+    Double,
+    LongDouble,
+    Char,
+    WChar,
+    /// `string`, bounded strings hold the bound
+    String(Option<usize>),
+    /// `wstring`, bounded strings hold the bound
+    WString(Option<usize>),
+    /// `sequence` of the element type, bounded sequences hold the bound
+    Sequence(&'static TypeDescriptor, Option<usize>),
+    /// Array of the element type with the dimensions
+    Array(&'static TypeDescriptor, &'static [usize]),
+    /// Struct, union, enum or newtype typedef by fully qualified name, i.e.
+    /// `DDS::Time_t`. Other typedefs are described by the type they alias.
+    Named(&'static str),
+}
+
+/// Struct member or union element as declared in IDL
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MemberDescriptor {
+    pub name: &'static str,
+    pub id: u32,
+    pub type_descriptor: TypeDescriptor,
+    pub key: bool,
+    pub optional: bool,
+}
+
+/// Value of any reflected type
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Boolean(bool),
+    Octet(u8),
+    Short(i16),
+    UShort(u16),
+    Long(i32),
+    ULong(u32),
+    LongLong(i64),
+    ULongLong(u64),
+    Float(f32),
+    Double(f64),
+    LongDouble(F128),
+    /// `char` of all character mappings but `u8`, `wchar` of the `char` mapping
+    Char(char),
+    String(String),
+    /// `wstring` of the `utf16` mapping
+    WString(WString),
+    /// Enumerator by IDL name
+    Enum(String),
+    /// Elements of a sequence or array, nested arrays are sequences of sequences
+    Sequence(Vec<Value>),
+    /// Members by IDL name, members of a base struct are listed as members of the
+    /// derived struct
+    Struct(Vec<(String, Value)>),
+    /// IDL name of the case label and value of the selected element
+    Union(String, Box<Value>),
+    /// Absent optional member
+    Absent,
+}
+
+/// Why a field could not be read or written
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReflectError {
+    /// The path is malformed, holds the position of the error
+    InvalidPath(usize),
+    /// The type has no member of the name
+    UnknownMember(String),
+    /// The index exceeds the sequence or array, or the length of a written string or
+    /// sequence exceeds its bound
+    OutOfRange(usize),
+    /// The optional member or union element is absent
+    Absent(String),
+    /// The value does not match the type
+    TypeMismatch,
+    /// The written value violates a `@range`, `@min` or `@max` bound of the member
+    Invalid(ValidationError),
+}
+
+impl From<ValidationError> for ReflectError {
+    fn from(err: ValidationError) -> Self {
+        ReflectError::Invalid(err)
+    }
+}
+
+impl fmt::Display for ReflectError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReflectError::InvalidPath(position) => write!(f, "invalid path at {position}"),
+            ReflectError::UnknownMember(name) => write!(f, "unknown member {name}"),
+            ReflectError::OutOfRange(index) => write!(f, "index {index} out of range"),
+            ReflectError::Absent(name) => write!(f, "member {name} is absent"),
+            ReflectError::TypeMismatch => f.write_str("value does not match the type"),
+            ReflectError::Invalid(err) => fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+impl core::error::Error for ReflectError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ReflectError::Invalid(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Step of a field path, a member name or an index into a sequence or array
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PathSegment<'a> {
+    Member(&'a str),
+    Index(usize),
+}
+
+/// Segments of a path like `key.value[0]`, members are separated by `.` and indices
+/// enclosed in brackets
+pub fn parse_path(path: &str) -> Result<Vec<PathSegment<'_>>, ReflectError> {
+    let mut segments = Vec::new();
+    let mut rest = path;
+    while !rest.is_empty() {
+        let position = path.len() - rest.len();
+        if let Some(index) = rest.strip_prefix('[') {
+            let end = index.find(']').ok_or(ReflectError::InvalidPath(position))?;
+            let index = index[..end]
+                .parse()
+                .map_err(|_| ReflectError::InvalidPath(position + 1))?;
+            segments.push(PathSegment::Index(index));
+            rest = &rest[end + 2..];
+        } else {
+            // members but the first one follow a `.`
+            let member = match rest.strip_prefix('.') {
+                Some(member) if position > 0 => member,
+                None if position == 0 => rest,
+                _ => return Err(ReflectError::InvalidPath(position)),
+            };
+            let end = member.find(['.', '[']).unwrap_or(member.len());
+            if end == 0 {
+                return Err(ReflectError::InvalidPath(position));
+            }
+            segments.push(PathSegment::Member(&member[..end]));
+            rest = &member[end..];
+        }
+    }
+    Ok(segments)
+}
+
+/// Generated for structs, unions, enums and newtype typedefs, implemented for the Rust
+/// types of their members. Fields are addressed by paths like `key.value[0]`.
+pub trait Reflect {
+    /// Fully qualified IDL name of structs, unions, enums and newtypes, i.e.
+    /// `DDS::Time_t`, `None` for anonymous types
+    fn type_name(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Members of structs including the members of their bases, elements of unions,
+    /// empty for other types
+    fn members(&self) -> &'static [MemberDescriptor] {
+        &[]
+    }
+
+    /// The whole value
+    fn to_value(&self) -> Value;
+
+    /// Replace the whole value
+    fn set_value(&mut self, value: Value) -> Result<(), ReflectError>;
+
+    fn from_value(value: Value) -> Result<Self, ReflectError>
+    where
+        Self: Sized;
+
+    /// The member or element a single step away
+    fn field(&self, segment: PathSegment) -> Result<&dyn Reflect, ReflectError> {
+        Err(segment_error(segment))
+    }
+
+    fn field_mut(&mut self, segment: PathSegment) -> Result<&mut dyn Reflect, ReflectError> {
+        Err(segment_error(segment))
+    }
+
+    /// Replace the member or element a single step away. Structs and unions check the
+    /// bounds of strings and sequences as well as `@range`, `@min` and `@max` here.
+    fn set_member(&mut self, segment: PathSegment, value: Value) -> Result<(), ReflectError> {
+        self.field_mut(segment)?.set_value(value)
+    }
+
+    /// Value of the field at `path`, the empty path is the whole value
+    fn get_field(&self, path: &str) -> Result<Value, ReflectError>
+    where
+        Self: Sized,
+    {
+        let mut field: &dyn Reflect = self;
+        for segment in parse_path(path)? {
+            field = field.field(segment)?;
+        }
+        Ok(field.to_value())
+    }
+
+    /// Replace the value of the field at `path`, the empty path is the whole value. The
+    /// bounds of the field are checked by its struct or union, bounds of elements nested
+    /// in a sequence or array, i.e. of `sequence<string<8>>`, are not.
+    fn set_field(&mut self, path: &str, value: Value) -> Result<(), ReflectError>
+    where
+        Self: Sized,
+    {
+        let segments = parse_path(path)?;
+        let Some((last, segments)) = segments.split_last() else {
+            return self.set_value(value);
+        };
+        let mut field: &mut dyn Reflect = self;
+        for segment in segments {
+            field = field.field_mut(*segment)?;
+        }
+        field.set_member(*last, value)
+    }
+}
+
+/// Error of a path segment a type does not have
+pub fn segment_error(segment: PathSegment) -> ReflectError {
+    match segment {
+        PathSegment::Member(name) => ReflectError::UnknownMember(name.into()),
+        PathSegment::Index(index) => ReflectError::OutOfRange(index),
+    }
+}
+
+/// Error of a path segment not selecting the active element of a union with the
+/// `elements`, absent if the segment names another element
+pub fn element_error(segment: PathSegment, elements: &[&str]) -> ReflectError {
+    match segment {
+        PathSegment::Member(name) if elements.contains(&name) => ReflectError::Absent(name.into()),
+        segment => segment_error(segment),
+    }
+}
+
+/// `Value::Struct` of the members of the base struct, if any, followed by `members`
+pub fn struct_value<const N: usize>(
+    base: Option<&dyn Reflect>,
+    members: [(&str, &dyn Reflect); N],
+) -> Value {
+    let mut values = match base.map(Reflect::to_value) {
+        Some(Value::Struct(values)) => values,
+        _ => Vec::new(),
+    };
+    values.extend(
+        members
+            .into_iter()
+            .map(|(name, member)| (name.into(), member.to_value())),
+    );
+    Value::Struct(values)
+}
+
+/// Set the members listed by the `Value::Struct` via `Reflect::set_member` of the struct,
+/// which passes members of the base struct on to the base. Members not listed keep their
+/// value, on error the members before are set.
+pub fn set_struct_value(target: &mut dyn Reflect, value: Value) -> Result<(), ReflectError> {
+    let Value::Struct(values) = value else {
+        return Err(ReflectError::TypeMismatch);
+    };
+    for (name, value) in values {
+        target.set_member(PathSegment::Member(&name), value)?;
+    }
+    Ok(())
+}
+
+/// Fails with the length of a string or sequence value exceeding the `bound`, strings
+/// are measured in characters
+pub fn check_length(value: &Value, bound: usize) -> Result<(), ReflectError> {
+    let length = match value {
+        Value::String(value) => value.chars().count(),
+        Value::WString(value) => value.len(),
+        Value::Sequence(values) => values.len(),
+        _ => return Ok(()),
+    };
+    if length > bound {
+        return Err(ReflectError::OutOfRange(length));
+    }
+    Ok(())
+}
+
+macro_rules! impl_reflect_primitive {
+    ($($typ:ty => $variant:ident),*) => {
+        $(
+            impl Reflect for $typ {
+                fn to_value(&self) -> Value {
+                    Value::$variant(<$typ>::clone(self))
+                }
+
+                fn set_value(&mut self, value: Value) -> Result<(), ReflectError> {
+                    *self = Self::from_value(value)?;
+                    Ok(())
+                }
+
+                fn from_value(value: Value) -> Result<Self, ReflectError> {
+                    match value {
+                        Value::$variant(value) => Ok(value),
+                        _ => Err(ReflectError::TypeMismatch),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_reflect_primitive!(
+    bool => Boolean, u8 => Octet, i16 => Short, u16 => UShort, i32 => Long, u32 => ULong,
+    i64 => LongLong, u64 => ULongLong, f32 => Float, f64 => Double, F128 => LongDouble,
+    char => Char, String => String, WString => WString
+);
+
+/// Fails for characters outside of Latin-1
+impl Reflect for Latin1 {
+    fn to_value(&self) -> Value {
+        Value::Char(self.to_char())
+    }
+
+    fn set_value(&mut self, value: Value) -> Result<(), ReflectError> {
+        *self = Self::from_value(value)?;
+        Ok(())
+    }
+
+    fn from_value(value: Value) -> Result<Self, ReflectError> {
+        match value {
+            Value::Char(value) => Latin1::try_from(value).map_err(|_| ReflectError::TypeMismatch),
+            _ => Err(ReflectError::TypeMismatch),
+        }
+    }
+}
+
+/// Absent if `None`, paths pass through to the value if present
+impl<T: Reflect> Reflect for Option<T> {
+    fn type_name(&self) -> Option<&'static str> {
+        self.as_ref().and_then(T::type_name)
+    }
+
+    fn members(&self) -> &'static [MemberDescriptor] {
+        self.as_ref().map(T::members).unwrap_or_default()
+    }
+
+    fn to_value(&self) -> Value {
+        self.as_ref().map_or(Value::Absent, T::to_value)
+    }
+
+    fn set_value(&mut self, value: Value) -> Result<(), ReflectError> {
+        *self = Self::from_value(value)?;
+        Ok(())
+    }
+
+    fn from_value(value: Value) -> Result<Self, ReflectError> {
+        match value {
+            Value::Absent => Ok(None),
+            value => T::from_value(value).map(Some),
+        }
+    }
+
+    fn field(&self, segment: PathSegment) -> Result<&dyn Reflect, ReflectError> {
+        match self {
+            Some(value) => value.field(segment),
+            None => Err(ReflectError::Absent(segment_name(segment))),
+        }
+    }
+
+    fn field_mut(&mut self, segment: PathSegment) -> Result<&mut dyn Reflect, ReflectError> {
+        match self {
+            Some(value) => value.field_mut(segment),
+            None => Err(ReflectError::Absent(segment_name(segment))),
+        }
+    }
+
+    fn set_member(&mut self, segment: PathSegment, value: Value) -> Result<(), ReflectError> {
+        match self {
+            Some(field) => field.set_member(segment, value),
+            None => Err(ReflectError::Absent(segment_name(segment))),
+        }
+    }
+}
+
+fn segment_name(segment: PathSegment) -> String {
+    match segment {
+        PathSegment::Member(name) => name.into(),
+        PathSegment::Index(index) => alloc::format!("[{index}]"),
+    }
+}
+
+fn sequence_values<T: Reflect>(elements: &[T]) -> Value {
+    Value::Sequence(elements.iter().map(T::to_value).collect())
+}
+
+fn values_sequence<T: Reflect>(value: Value) -> Result<Vec<T>, ReflectError> {
+    match value {
+        Value::Sequence(values) => values.into_iter().map(T::from_value).collect(),
+        _ => Err(ReflectError::TypeMismatch),
+    }
+}
+
+fn element<'a, T: Reflect>(
+    elements: &'a [T],
+    segment: PathSegment,
+) -> Result<&'a dyn Reflect, ReflectError> {
+    match segment {
+        PathSegment::Index(index) => elements
+            .get(index)
+            .map(|element| element as &dyn Reflect)
+            .ok_or(ReflectError::OutOfRange(index)),
+        segment => Err(segment_error(segment)),
+    }
+}
+
+fn element_mut<'a, T: Reflect>(
+    elements: &'a mut [T],
+    segment: PathSegment,
+) -> Result<&'a mut dyn Reflect, ReflectError> {
+    match segment {
+        PathSegment::Index(index) => elements
+            .get_mut(index)
+            .map(|element| element as &mut dyn Reflect)
+            .ok_or(ReflectError::OutOfRange(index)),
+        segment => Err(segment_error(segment)),
+    }
+}
+
+impl<T: Reflect> Reflect for Vec<T> {
+    fn to_value(&self) -> Value {
+        sequence_values(self)
+    }
+
+    fn set_value(&mut self, value: Value) -> Result<(), ReflectError> {
+        *self = Self::from_value(value)?;
+        Ok(())
+    }
+
+    fn from_value(value: Value) -> Result<Self, ReflectError> {
+        values_sequence(value)
+    }
+
+    fn field(&self, segment: PathSegment) -> Result<&dyn Reflect, ReflectError> {
+        element(self, segment)
+    }
+
+    fn field_mut(&mut self, segment: PathSegment) -> Result<&mut dyn Reflect, ReflectError> {
+        element_mut(self, segment)
+    }
+}
+
+/// Fails unless the value has exactly `N` elements
+impl<T: Reflect, const N: usize> Reflect for [T; N] {
+    fn to_value(&self) -> Value {
+        sequence_values(self)
+    }
+
+    fn set_value(&mut self, value: Value) -> Result<(), ReflectError> {
+        *self = Self::from_value(value)?;
+        Ok(())
+    }
+
+    fn from_value(value: Value) -> Result<Self, ReflectError> {
+        values_sequence(value)?
+            .try_into()
+            .map_err(|_| ReflectError::TypeMismatch)
+    }
+
+    fn field(&self, segment: PathSegment) -> Result<&dyn Reflect, ReflectError> {
+        element(self, segment)
+    }
+
+    fn field_mut(&mut self, segment: PathSegment) -> Result<&mut dyn Reflect, ReflectError> {
+        element_mut(self, segment)
+    }
+}
+
+/// Octet sequences of the `bytebuf` byte mapping, reflected like `Vec<u8>`
+impl Reflect for ByteBuf {
+    fn to_value(&self) -> Value {
+        sequence_values(self)
+    }
+
+    fn set_value(&mut self, value: Value) -> Result<(), ReflectError> {
+        *self = Self::from_value(value)?;
+        Ok(())
+    }
+
+    fn from_value(value: Value) -> Result<Self, ReflectError> {
+        values_sequence(value).map(ByteBuf::from)
+    }
+
+    fn field(&self, segment: PathSegment) -> Result<&dyn Reflect, ReflectError> {
+        element(self, segment)
+    }
+
+    fn field_mut(&mut self, segment: PathSegment) -> Result<&mut dyn Reflect, ReflectError> {
+        element_mut(self, segment)
+    }
+}
+
+/// Octet sequences of the `bytes` byte mapping, reflected like `Vec<u8>`. The bytes are
+/// immutable, only the whole sequence can be replaced.
+#[cfg(feature = "bytes")]
+impl Reflect for bytes::Bytes {
+    fn to_value(&self) -> Value {
+        sequence_values(self)
+    }
+
+    fn set_value(&mut self, value: Value) -> Result<(), ReflectError> {
+        *self = Self::from_value(value)?;
+        Ok(())
+    }
+
+    fn from_value(value: Value) -> Result<Self, ReflectError> {
+        values_sequence::<u8>(value).map(bytes::Bytes::from)
+    }
+
+    fn field(&self, segment: PathSegment) -> Result<&dyn Reflect, ReflectError> {
+        element(self, segment)
+    }
+}
+
+/// Bounded strings of the heapless mapping, fails for strings exceeding the bound `N`
+#[cfg(feature = "heapless")]
+impl<const N: usize> Reflect for heapless::String<N> {
+    fn to_value(&self) -> Value {
+        Value::String(self.as_str().into())
+    }
+
+    fn set_value(&mut self, value: Value) -> Result<(), ReflectError> {
+        *self = Self::from_value(value)?;
+        Ok(())
+    }
+
+    fn from_value(value: Value) -> Result<Self, ReflectError> {
+        match value {
+            Value::String(value) => value
+                .as_str()
+                .try_into()
+                .map_err(|_| ReflectError::OutOfRange(value.len())),
+            _ => Err(ReflectError::TypeMismatch),
+        }
+    }
+}
+
+/// Bounded sequences of the heapless mapping, fails for sequences exceeding the bound `N`
+#[cfg(feature = "heapless")]
+impl<T: Reflect, const N: usize> Reflect for heapless::Vec<T, N> {
+    fn to_value(&self) -> Value {
+        sequence_values(self)
+    }
+
+    fn set_value(&mut self, value: Value) -> Result<(), ReflectError> {
+        *self = Self::from_value(value)?;
+        Ok(())
+    }
+
+    fn from_value(value: Value) -> Result<Self, ReflectError> {
+        let values = values_sequence::<T>(value)?;
+        let length = values.len();
+        values
+            .into_iter()
+            .try_fold(heapless::Vec::new(), |mut sequence, value| {
+                sequence.push(value).ok()?;
+                Some(sequence)
+            })
+            .ok_or(ReflectError::OutOfRange(length))
+    }
+
+    fn field(&self, segment: PathSegment) -> Result<&dyn Reflect, ReflectError> {
+        element(self, segment)
+    }
+
+    fn field_mut(&mut self, segment: PathSegment) -> Result<&mut dyn Reflect, ReflectError> {
+        element_mut(self, segment)
+    }
+}
@@ -0,0 +1,54 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+use core::{char::TryFromCharError, fmt};
+
+/// IDL `char` as its 8 bit code unit, interpreted as ISO 8859-1 (Latin-1) character
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+#[repr(transparent)]
+pub struct Latin1(pub u8);
+
+impl Latin1 {
+    pub const fn to_char(self) -> char {
+        self.0 as char
+    }
+}
+
+impl From<u8> for Latin1 {
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Latin1> for u8 {
+    fn from(value: Latin1) -> Self {
+        value.0
+    }
+}
+
+impl From<Latin1> for char {
+    fn from(value: Latin1) -> Self {
+        value.to_char()
+    }
+}
+
+/// Fails for characters outside of Latin-1
+impl TryFrom<char> for Latin1 {
+    type Error = TryFromCharError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        u8::try_from(value).map(Self)
+    }
+}
+
+impl fmt::Display for Latin1 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.to_char(), f)
+    }
+}
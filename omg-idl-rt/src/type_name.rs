@@ -0,0 +1,15 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+/// Names a type is registered by, implemented by all generated structs, unions, enums
+/// and newtypes
+pub trait TypeName {
+    /// Fully qualified IDL name, i.e. `DDS::ParticipantBuiltinTopicData`
+    const TYPE_NAME: &'static str;
+    /// Repository ID, i.e. `IDL:omg.org/DDS/ParticipantBuiltinTopicData:1.0`, derived
+    /// from the name unless given via `typeid` or `#pragma ID`. The prefix is set via
+    /// `typeprefix` or `#pragma prefix` and the version via `#pragma version`.
+    const REPOSITORY_ID: &'static str;
+}
@@ -0,0 +1,68 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+/// The first 14 bytes of the MD5 hash of an XCDR2 serialized TypeObject
+pub type EquivalenceHash = [u8; 14];
+
+/// Whether a TypeIdentifier refers to a minimal or a complete TypeObject
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EquivalenceKind {
+    /// `EK_MINIMAL`, the TypeObject holds what is needed to match types
+    Minimal,
+    /// `EK_COMPLETE`, the TypeObject holds all names and annotations as well
+    Complete,
+}
+
+/// Identifies a type within a strongly connected component, a group of types referring
+/// to each other
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StronglyConnectedComponentId {
+    pub kind: EquivalenceKind,
+    /// Hash of the TypeObjects of all types of the component
+    pub hash: EquivalenceHash,
+    /// Number of types of the component
+    pub scc_length: i32,
+    /// Position of the type within the component, starting at 1
+    pub scc_index: i32,
+}
+
+/// DDS-XTypes TypeIdentifier of a generated type
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TypeIdentifier {
+    /// `EK_MINIMAL` or `EK_COMPLETE`, the hash of the TypeObject of the type
+    EquivalenceHash(EquivalenceKind, EquivalenceHash),
+    /// `TI_STRONGLY_CONNECTED_COMPONENT`, a type referring to itself, directly or via
+    /// other types
+    StronglyConnectedComponent(StronglyConnectedComponentId),
+}
+
+impl TypeIdentifier {
+    /// Kind of the TypeObject the identifier refers to
+    pub fn kind(&self) -> EquivalenceKind {
+        match self {
+            TypeIdentifier::EquivalenceHash(kind, _) => *kind,
+            TypeIdentifier::StronglyConnectedComponent(id) => id.kind,
+        }
+    }
+}
+
+/// A TypeObject, XCDR2 serialized in little endian, together with its TypeIdentifier
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TypeIdentifierTypeObjectPair {
+    pub type_identifier: TypeIdentifier,
+    pub type_object: &'static [u8],
+}
+
+/// DDS-XTypes TypeObjects of a generated type, exchanged during discovery to match the
+/// types of remote entities
+pub trait TypeObjects {
+    /// The minimal TypeObject of the type
+    const MINIMAL: TypeIdentifierTypeObjectPair;
+    /// The complete TypeObject of the type
+    const COMPLETE: TypeIdentifierTypeObjectPair;
+    /// Minimal and complete TypeObjects of all types the TypeObjects refer to, directly
+    /// or indirectly
+    const DEPENDENCIES: &'static [TypeIdentifierTypeObjectPair];
+}
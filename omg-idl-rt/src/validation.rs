@@ -0,0 +1,81 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+use core::fmt;
+
+/// Bound of a member declared via `@range`, `@min` or `@max`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Bound {
+    Min,
+    Max,
+}
+
+/// A member value violating a bound declared in IDL
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ValidationError {
+    /// IDL name of the member
+    pub member: &'static str,
+    pub bound: Bound,
+    /// The bound as written in IDL, i.e. "0" or "10.5"
+    pub limit: &'static str,
+}
+
+impl ValidationError {
+    pub const fn new(member: &'static str, bound: Bound, limit: &'static str) -> Self {
+        Self {
+            member,
+            bound,
+            limit,
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (bound, relation) = match self.bound {
+            Bound::Min => ("minimum", "below"),
+            Bound::Max => ("maximum", "above"),
+        };
+        write!(
+            f,
+            "member {} is {relation} its {bound} {}",
+            self.member, self.limit
+        )
+    }
+}
+
+impl core::error::Error for ValidationError {}
+
+/// A struct builder failing to build its struct
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BuildError {
+    /// A required member was not set, holds the IDL name of the member
+    Missing(&'static str),
+    /// A member value violates a bound declared in IDL
+    Invalid(ValidationError),
+}
+
+impl From<ValidationError> for BuildError {
+    fn from(err: ValidationError) -> Self {
+        BuildError::Invalid(err)
+    }
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BuildError::Missing(member) => write!(f, "required member {member} is not set"),
+            BuildError::Invalid(err) => fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+impl core::error::Error for BuildError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            BuildError::Missing(_) => None,
+            BuildError::Invalid(err) => Some(err),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright (C) 2025  Bryan Conn
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+use alloc::{
+    string::{FromUtf16Error, String},
+    vec::Vec,
+};
+use core::fmt;
+
+/// IDL `wstring` as its UTF-16 code units, which need not be valid UTF-16, so that any
+/// received string is kept as is
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct WString(Vec<u16>);
+
+impl WString {
+    pub const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    pub fn from_units(units: Vec<u16>) -> Self {
+        Self(units)
+    }
+
+    pub fn as_units(&self) -> &[u16] {
+        &self.0
+    }
+
+    pub fn into_units(self) -> Vec<u16> {
+        self.0
+    }
+
+    /// Number of code units
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Decode the string, unpaired surrogates are replaced by U+FFFD
+    pub fn to_string_lossy(&self) -> String {
+        String::from_utf16_lossy(&self.0)
+    }
+}
+
+impl From<&str> for WString {
+    fn from(value: &str) -> Self {
+        Self(value.encode_utf16().collect())
+    }
+}
+
+impl From<String> for WString {
+    fn from(value: String) -> Self {
+        value.as_str().into()
+    }
+}
+
+impl From<&[u16]> for WString {
+    fn from(value: &[u16]) -> Self {
+        Self(value.to_vec())
+    }
+}
+
+/// Fails for unpaired surrogates
+impl TryFrom<&WString> for String {
+    type Error = FromUtf16Error;
+
+    fn try_from(value: &WString) -> Result<Self, Self::Error> {
+        String::from_utf16(&value.0)
+    }
+}
+
+impl fmt::Display for WString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        char::decode_utf16(self.0.iter().copied())
+            .map(|unit| unit.unwrap_or(char::REPLACEMENT_CHARACTER))
+            .try_for_each(|c| fmt::Write::write_char(f, c))
+    }
+}
@@ -0,0 +1,53 @@
+use omg_idl_rt::F128;
+
+const ONE: u128 = 0x3FFF << 112;
+
+#[test]
+fn f64_converts_exactly() {
+    assert_eq!(F128::from_f64(1.0).to_bits(), ONE);
+    assert_eq!(F128::from_f64(-2.0).to_bits(), 0xC000 << 112);
+    // the smallest f64 subnormal, 2^-1074
+    assert_eq!(F128::from_bits(0x3BCD << 112).to_f64(), f64::from_bits(1));
+    for value in [
+        0.0,
+        -0.0,
+        1.5,
+        -1e300,
+        f64::MAX,
+        f64::MIN_POSITIVE,
+        f64::from_bits(0x000F_FFFF_FFFF_FFFF),
+        f64::from_bits(1),
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+    ] {
+        let converted = F128::from_f64(value).to_f64();
+        assert_eq!(converted.to_bits(), value.to_bits(), "{value:e}");
+    }
+    assert!(F128::from_f64(f64::NAN).is_nan());
+    assert!(F128::from_f64(f64::NAN).to_f64().is_nan());
+}
+
+#[test]
+fn narrowing_rounds_to_nearest_even() {
+    // 1 + 2^-53 is halfway between 1 and the next f64
+    assert_eq!(F128::from_bits(ONE | 1 << 59).to_f64(), 1.0);
+    assert_eq!(
+        F128::from_bits(ONE | 1 << 59 | 1).to_f64(),
+        1.0 + f64::EPSILON
+    );
+    assert_eq!(
+        F128::from_bits(ONE | 3 << 59).to_f64(),
+        1.0 + 2.0 * f64::EPSILON
+    );
+    // out of range
+    assert_eq!(F128::from_bits(0x7FFE << 112).to_f64(), f64::INFINITY);
+    assert_eq!(F128::from_bits(1).to_f64(), 0.0);
+}
+
+#[test]
+fn comparisons_follow_ieee_754() {
+    assert_eq!(F128::from_f64(0.0), F128::from_f64(-0.0));
+    assert!(F128::from_f64(-1.0) < F128::from_f64(-0.5));
+    assert!(F128::from_f64(f64::NAN) != F128::from_f64(f64::NAN));
+    assert_eq!(F128::default(), F128::ZERO);
+}
@@ -0,0 +1,137 @@
+use omg_idl_rt::{
+    hash_key, key_holder, ByteBuf, KeyHash, KeyHashEncode, KeyHolderWriter, KeySize, Keyed,
+};
+use std::{
+    cmp::Ordering,
+    hash::{DefaultHasher, Hasher},
+};
+
+/// Key holder as generated for `struct Sensor { @key octet kind; @key long id; string name; }`
+struct SensorKey {
+    kind: u8,
+    id: i32,
+}
+
+impl KeyHashEncode for SensorKey {
+    const KEY_SIZE: KeySize = KeySize::new().field::<u8>().field::<i32>();
+
+    fn encode_key(&self, writer: &mut KeyHolderWriter) {
+        self.kind.encode_key(writer);
+        self.id.encode_key(writer);
+    }
+
+    fn key_cmp(&self, other: &Self) -> Ordering {
+        self.kind
+            .key_cmp(&other.kind)
+            .then_with(|| self.id.key_cmp(&other.id))
+    }
+}
+
+struct Sensor {
+    kind: u8,
+    id: i32,
+    #[allow(dead_code)]
+    name: String,
+}
+
+impl Keyed for Sensor {
+    type Key = SensorKey;
+
+    fn key(&self) -> SensorKey {
+        SensorKey {
+            kind: self.kind,
+            id: self.id,
+        }
+    }
+}
+
+#[test]
+fn key_holder_is_aligned_big_endian() {
+    let key = SensorKey {
+        kind: 7,
+        id: 0x0102,
+    };
+    assert_eq!(key_holder(&key), vec![7, 0, 0, 0, 0, 0, 1, 2]);
+    assert_eq!(SensorKey::KEY_SIZE.size(), Some(8));
+}
+
+#[test]
+fn short_keys_are_zero_padded() {
+    let sensor = Sensor {
+        kind: 7,
+        id: 0x0102,
+        name: "front".to_owned(),
+    };
+    let mut expected = [0; 16];
+    expected[..8].copy_from_slice(&[7, 0, 0, 0, 0, 0, 1, 2]);
+    assert_eq!(sensor.key_hash(), KeyHash(expected));
+}
+
+#[test]
+fn unbounded_keys_are_hashed() {
+    let key = "abc".to_owned();
+    assert_eq!(key_holder(&key), vec![0, 0, 0, 4, b'a', b'b', b'c', 0]);
+    assert_eq!(
+        KeyHash::from_key(&key),
+        KeyHash([26, 105, 116, 202, 224, 186, 33, 191, 21, 248, 141, 117, 156, 49, 234, 248])
+    );
+}
+
+#[test]
+fn byte_buffers_are_hashed_like_octet_sequences() {
+    let octets = vec![1u8, 2, 3];
+    assert_eq!(
+        key_holder(&ByteBuf::from(octets.clone())),
+        key_holder(&octets)
+    );
+    assert_eq!(ByteBuf::KEY_SIZE.size(), None);
+}
+
+#[test]
+fn array_sizes() {
+    assert_eq!(<[u8; 16]>::KEY_SIZE.size(), Some(16));
+    assert_eq!(<[i64; 2]>::KEY_SIZE.size(), Some(16));
+    assert_eq!(
+        KeySize::new().field::<u8>().bounded_string(10).size(),
+        Some(4 + 4 + 10 + 1)
+    );
+}
+
+#[test]
+fn keys_compare_by_value() {
+    let mut values = [5, -1, 3, i32::MIN, 0x0100];
+    values.sort_by(KeyHashEncode::key_cmp);
+    assert_eq!(values, [i32::MIN, -1, 3, 5, 0x0100]);
+
+    let mut floats = [2.0f64, -0.0, -1.5, 0.0, f64::NEG_INFINITY];
+    floats.sort_by(KeyHashEncode::key_cmp);
+    assert_eq!(
+        floats.map(f64::to_bits),
+        [f64::NEG_INFINITY, -1.5, -0.0, 0.0, 2.0].map(f64::to_bits)
+    );
+    assert_eq!(f64::NAN.key_cmp(&f64::NAN), Ordering::Equal);
+
+    let mut strings = ["b", "abd", "", "abc", "ab"].map(str::to_owned);
+    strings.sort_by(KeyHashEncode::key_cmp);
+    assert_eq!(strings, ["", "ab", "abc", "abd", "b"]);
+
+    let mut keys = [(7, -2), (1, 5), (7, -3)].map(|(kind, id)| SensorKey { kind, id });
+    keys.sort_by(KeyHashEncode::key_cmp);
+    assert_eq!(
+        keys.map(|key| (key.kind, key.id)),
+        [(1, 5), (7, -3), (7, -2)]
+    );
+}
+
+#[test]
+fn keys_hash_their_key_holders() {
+    let key = SensorKey {
+        kind: 7,
+        id: 0x0102,
+    };
+    let mut hasher = DefaultHasher::new();
+    hash_key(&key, &mut hasher);
+    let mut expected = DefaultHasher::new();
+    expected.write(&key_holder(&key));
+    assert_eq!(hasher.finish(), expected.finish());
+}
@@ -0,0 +1,71 @@
+use omg_idl_rt::{parse_path, PathSegment, Reflect, ReflectError, Value};
+
+#[test]
+fn paths_are_split_into_members_and_indices() {
+    assert_eq!(
+        parse_path("key.value[0]"),
+        Ok(vec![
+            PathSegment::Member("key"),
+            PathSegment::Member("value"),
+            PathSegment::Index(0),
+        ])
+    );
+    assert_eq!(
+        parse_path("[1][2].x"),
+        Ok(vec![
+            PathSegment::Index(1),
+            PathSegment::Index(2),
+            PathSegment::Member("x"),
+        ])
+    );
+    assert_eq!(parse_path(""), Ok(vec![]));
+    assert_eq!(parse_path(".key"), Err(ReflectError::InvalidPath(0)));
+    assert_eq!(parse_path("key..value"), Err(ReflectError::InvalidPath(3)));
+    assert_eq!(parse_path("value[0]x"), Err(ReflectError::InvalidPath(8)));
+    assert_eq!(parse_path("value[x]"), Err(ReflectError::InvalidPath(6)));
+    assert_eq!(parse_path("value[0"), Err(ReflectError::InvalidPath(5)));
+}
+
+#[test]
+fn sequences_and_arrays_are_indexed() {
+    let mut matrix = vec![[1_i32, 2], [3, 4]];
+    assert_eq!(matrix.get_field("[1][0]"), Ok(Value::Long(3)));
+    assert_eq!(
+        matrix.get_field("[0]"),
+        Ok(Value::Sequence(vec![Value::Long(1), Value::Long(2)]))
+    );
+    matrix.set_field("[0][1]", Value::Long(7)).unwrap();
+    assert_eq!(matrix, vec![[1, 7], [3, 4]]);
+
+    assert_eq!(matrix.get_field("[2]"), Err(ReflectError::OutOfRange(2)));
+    assert_eq!(
+        matrix.get_field("[0].x"),
+        Err(ReflectError::UnknownMember("x".to_owned()))
+    );
+    assert_eq!(
+        matrix.set_field("[0][0]", Value::Short(1)),
+        Err(ReflectError::TypeMismatch)
+    );
+    // arrays keep their length
+    assert_eq!(
+        matrix.set_field("[0]", Value::Sequence(vec![Value::Long(1)])),
+        Err(ReflectError::TypeMismatch)
+    );
+}
+
+#[test]
+fn absent_optionals_have_no_fields() {
+    let mut optional: Option<Vec<u8>> = None;
+    assert_eq!(optional.get_field(""), Ok(Value::Absent));
+    assert_eq!(
+        optional.get_field("[0]"),
+        Err(ReflectError::Absent("[0]".to_owned()))
+    );
+
+    optional
+        .set_field("", Value::Sequence(vec![Value::Octet(5)]))
+        .unwrap();
+    assert_eq!(optional.get_field("[0]"), Ok(Value::Octet(5)));
+    optional.set_field("", Value::Absent).unwrap();
+    assert_eq!(optional, None);
+}
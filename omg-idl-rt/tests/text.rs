@@ -0,0 +1,21 @@
+use omg_idl_rt::{Latin1, WString};
+
+#[test]
+fn latin1_converts_from_and_to_char() {
+    assert_eq!(Latin1::try_from('é'), Ok(Latin1(0xE9)));
+    assert!(Latin1::try_from('Ω').is_err());
+    assert_eq!(char::from(Latin1(0xB0)), '°');
+    assert_eq!(Latin1(b'a').to_string(), "a");
+}
+
+#[test]
+fn wstring_keeps_invalid_utf16() {
+    let text = WString::from("Grüße");
+    assert_eq!(text.len(), 5);
+    assert_eq!(String::try_from(&text).unwrap(), "Grüße");
+
+    let unpaired = WString::from_units(vec![u16::from(b'a'), 0xD800]);
+    assert!(String::try_from(&unpaired).is_err());
+    assert_eq!(unpaired.to_string_lossy(), "a\u{FFFD}");
+    assert_eq!(unpaired.to_string(), "a\u{FFFD}");
+}
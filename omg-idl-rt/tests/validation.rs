@@ -0,0 +1,27 @@
+use omg_idl_rt::{Bound, BuildError, ValidationError};
+use std::error::Error;
+
+#[test]
+fn validation_error_names_member_and_bound() {
+    let err = ValidationError::new("level", Bound::Max, "100");
+    assert_eq!(err.member, "level");
+    assert_eq!(err.bound, Bound::Max);
+    assert_eq!(err.to_string(), "member level is above its maximum 100");
+
+    let err = ValidationError::new("temperature", Bound::Min, "-40");
+    assert_eq!(
+        err.to_string(),
+        "member temperature is below its minimum -40"
+    );
+}
+
+#[test]
+fn build_error_names_missing_member() {
+    let err = BuildError::Missing("id");
+    assert_eq!(err.to_string(), "required member id is not set");
+    assert!(err.source().is_none());
+
+    let err = BuildError::from(ValidationError::new("level", Bound::Max, "100"));
+    assert_eq!(err.to_string(), "member level is above its maximum 100");
+    assert!(err.source().is_some());
+}